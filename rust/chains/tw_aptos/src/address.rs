@@ -14,6 +14,7 @@ use tw_memory::Data;
 #[repr(u8)]
 pub enum Scheme {
     Ed25519 = 0,
+    MultiEd25519 = 1,
 }
 
 #[derive(Clone)]
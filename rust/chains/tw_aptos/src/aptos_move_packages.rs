@@ -236,3 +236,63 @@ pub fn fungible_asset_transfer(
         ]),
     )))
 }
+
+/// Transfers a Digital Asset (token v2) object, i.e. `0x1::object::transfer<0x4::token::Token>`.
+pub fn object_transfer(
+    object_address: AccountAddress,
+    to: AccountAddress,
+) -> SigningResult<TransactionPayload> {
+    Ok(TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("object").to_owned(),
+        ),
+        ident_str!("transfer").to_owned(),
+        vec![TypeTag::from_str("0x4::token::Token").tw_err(SigningErrorType::Error_internal)?],
+        vec![bcs::encode(&object_address)?, bcs::encode(&to)?],
+        json!([object_address.to_hex_literal(), to.to_hex_literal()]),
+    )))
+}
+
+/// Returns the argument types of an entry function supported by [`SigningInput`] in the format
+/// expected by [`EntryFunction::parse_with_abi`], or `None` if the function is unknown.
+///
+/// [`SigningInput`]: tw_proto::Aptos::Proto::SigningInput
+pub fn known_entry_function_abi(function_id: &str) -> Option<&'static [&'static str]> {
+    let abi: &'static [&'static str] = match function_id {
+        "0x1::aptos_account::transfer"
+        | "0x1::aptos_account::transfer_coins"
+        | "0x1::coin::transfer" => &["address", "u64"],
+        "0x1::aptos_account::create_account" => &["address"],
+        "0x1::managed_coin::register" => &[],
+        "0x1::primary_fungible_store::transfer" => &[
+            "0x1::object::Object<0x1::fungible_asset::Metadata>",
+            "address",
+            "u64",
+        ],
+        "0x1::object::transfer" => &["0x1::object::Object<0x4::token::Token>", "address"],
+        "0x1::delegation_pool::add_stake"
+        | "0x1::delegation_pool::unlock"
+        | "0x1::delegation_pool::withdraw" => &["address", "u64"],
+        "0x3::token_transfers::offer_script" => &[
+            "address",
+            "address",
+            "0x1::string::String",
+            "0x1::string::String",
+            "u64",
+            "u64",
+        ],
+        "0x3::token_transfers::cancel_offer_script" | "0x3::token_transfers::claim_script" => &[
+            "address",
+            "address",
+            "0x1::string::String",
+            "0x1::string::String",
+            "u64",
+        ],
+        _ => return None,
+    };
+    Some(abi)
+}
@@ -1,5 +1,6 @@
 use crate::address::Address;
 use crate::transaction_builder;
+use crate::transaction_signers::TransactionSigners;
 use std::str::FromStr;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
@@ -21,15 +22,17 @@ impl Compiler {
     fn preimage_hashes_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let signers = TransactionSigners::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
         let sender = Address::from_str(&input.sender)
             .into_tw()
             .context("Invalid sender address")?;
-        let signed_tx = builder
+        let raw_tx = builder
             .sender(sender.inner())
             .sequence_number(input.sequence_number as u64)
-            .build()?
-            .pre_image()?;
+            .build()?;
+        // The same message is signed by every signer of the transaction.
+        let signed_tx = signers.pre_image(&raw_tx)?;
         Ok(CompilerProto::PreSigningOutput {
             data: signed_tx.into(),
             ..CompilerProto::PreSigningOutput::default()
@@ -51,20 +54,16 @@ impl Compiler {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let signers = TransactionSigners::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
         let sender = Address::from_str(&input.sender)?;
-        let signature = signatures
-            .first()
-            .or_tw_err(SigningErrorType::Error_signatures_count)?;
-        let public_key = public_keys
-            .first()
-            .or_tw_err(SigningErrorType::Error_signatures_count)?;
+        let authenticator = signers.authenticator_from_signatures(signatures, public_keys)?;
 
         let signed_tx = builder
             .sender(sender.inner())
             .sequence_number(input.sequence_number as u64)
             .build()?
-            .compile(signature.to_vec(), public_key.to_vec())?;
+            .compile_with_authenticator(authenticator)?;
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
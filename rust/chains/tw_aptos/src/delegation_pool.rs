@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::from_account_error;
+use crate::transaction_payload::{EntryFunction, TransactionPayload};
+use move_core_types::identifier::IdentStr;
+use move_core_types::{account_address::AccountAddress, ident_str, language_storage::ModuleId};
+use serde_json::json;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::bcs;
+use tw_proto::{
+    Aptos::Proto::mod_DelegationPoolMessage::OneOfdelegation_pool_transaction_payload,
+    Aptos::Proto::{
+        DelegationPoolAddStake, DelegationPoolMessage, DelegationPoolUnlock, DelegationPoolWithdraw,
+    },
+};
+
+fn delegation_pool_entry_function(
+    function: &IdentStr,
+    pool_address: AccountAddress,
+    amount: u64,
+) -> SigningResult<TransactionPayload> {
+    Ok(TransactionPayload::EntryFunction(EntryFunction::new(
+        ModuleId::new(
+            AccountAddress::new([
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 1,
+            ]),
+            ident_str!("delegation_pool").to_owned(),
+        ),
+        function.to_owned(),
+        vec![],
+        vec![bcs::encode(&pool_address)?, bcs::encode(&amount)?],
+        json!([pool_address.to_hex_literal(), amount.to_string()]),
+    )))
+}
+
+pub fn delegation_pool_add_stake(
+    pool_address: AccountAddress,
+    amount: u64,
+) -> SigningResult<TransactionPayload> {
+    delegation_pool_entry_function(ident_str!("add_stake"), pool_address, amount)
+}
+
+pub fn delegation_pool_unlock(
+    pool_address: AccountAddress,
+    amount: u64,
+) -> SigningResult<TransactionPayload> {
+    delegation_pool_entry_function(ident_str!("unlock"), pool_address, amount)
+}
+
+pub fn delegation_pool_withdraw(
+    pool_address: AccountAddress,
+    amount: u64,
+) -> SigningResult<TransactionPayload> {
+    delegation_pool_entry_function(ident_str!("withdraw"), pool_address, amount)
+}
+
+pub struct AddStake {
+    pub amount: u64,
+    pub pool_address: AccountAddress,
+}
+
+pub struct Unlock {
+    pub amount: u64,
+    pub pool_address: AccountAddress,
+}
+
+pub struct Withdraw {
+    pub amount: u64,
+    pub pool_address: AccountAddress,
+}
+
+pub enum DelegationPoolOperation {
+    AddStake(AddStake),
+    Unlock(Unlock),
+    Withdraw(Withdraw),
+}
+
+impl TryFrom<DelegationPoolMessage<'_>> for DelegationPoolOperation {
+    type Error = SigningError;
+
+    fn try_from(value: DelegationPoolMessage) -> SigningResult<Self> {
+        let pool_address = AccountAddress::from_str(&value.pool_address)
+            .map_err(from_account_error)
+            .into_tw()
+            .context("Invalid delegation pool address")?;
+        match value.delegation_pool_transaction_payload {
+            OneOfdelegation_pool_transaction_payload::add_stake(add_stake) => {
+                Ok(DelegationPoolOperation::AddStake(AddStake {
+                    amount: add_stake.amount,
+                    pool_address,
+                }))
+            },
+            OneOfdelegation_pool_transaction_payload::unlock(unlock) => {
+                Ok(DelegationPoolOperation::Unlock(Unlock {
+                    amount: unlock.amount,
+                    pool_address,
+                }))
+            },
+            OneOfdelegation_pool_transaction_payload::withdraw(withdraw) => {
+                Ok(DelegationPoolOperation::Withdraw(Withdraw {
+                    amount: withdraw.amount,
+                    pool_address,
+                }))
+            },
+            OneOfdelegation_pool_transaction_payload::None => {
+                SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No delegation pool operation provided")
+            },
+        }
+    }
+}
+
+impl From<DelegationPoolOperation> for DelegationPoolMessage<'_> {
+    fn from(value: DelegationPoolOperation) -> Self {
+        match value {
+            DelegationPoolOperation::AddStake(add_stake) => DelegationPoolMessage {
+                pool_address: add_stake.pool_address.to_hex_literal().into(),
+                delegation_pool_transaction_payload:
+                    OneOfdelegation_pool_transaction_payload::add_stake(DelegationPoolAddStake {
+                        amount: add_stake.amount,
+                    }),
+            },
+            DelegationPoolOperation::Unlock(unlock) => DelegationPoolMessage {
+                pool_address: unlock.pool_address.to_hex_literal().into(),
+                delegation_pool_transaction_payload:
+                    OneOfdelegation_pool_transaction_payload::unlock(DelegationPoolUnlock {
+                        amount: unlock.amount,
+                    }),
+            },
+            DelegationPoolOperation::Withdraw(withdraw) => DelegationPoolMessage {
+                pool_address: withdraw.pool_address.to_hex_literal().into(),
+                delegation_pool_transaction_payload:
+                    OneOfdelegation_pool_transaction_payload::withdraw(DelegationPoolWithdraw {
+                        amount: withdraw.amount,
+                    }),
+            },
+        }
+    }
+}
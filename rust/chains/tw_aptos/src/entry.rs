@@ -4,6 +4,7 @@
 
 use crate::address::Address;
 use crate::compiler::Compiler;
+use crate::modules::transaction_decoder::AptosTransactionDecoder;
 use crate::modules::transaction_util::AptosTransactionUtil;
 use crate::signer::Signer;
 use std::str::FromStr;
@@ -14,7 +15,6 @@ use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -35,7 +35,7 @@ impl CoinEntry for AptosEntry {
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = AptosTransactionDecoder;
     type TransactionUtil = AptosTransactionUtil;
 
     #[inline]
@@ -101,6 +101,11 @@ impl CoinEntry for AptosEntry {
         None
     }
 
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(AptosTransactionDecoder)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(AptosTransactionUtil)
@@ -6,6 +6,7 @@ pub mod address;
 pub mod aptos_move_packages;
 pub mod aptos_move_types;
 pub mod constants;
+pub mod delegation_pool;
 pub mod entry;
 mod serde_helper;
 
@@ -14,7 +15,9 @@ pub mod nft;
 pub mod compiler;
 pub mod liquid_staking;
 pub mod modules;
+pub mod multi_ed25519;
 pub mod signer;
 pub mod transaction;
 pub mod transaction_builder;
 pub mod transaction_payload;
+pub mod transaction_signers;
@@ -2,4 +2,5 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod transaction_decoder;
 pub mod transaction_util;
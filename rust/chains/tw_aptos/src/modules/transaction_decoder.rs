@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::{RawTransaction, TransactionAuthenticator};
+use crate::transaction_payload::{EntryFunction, TransactionPayload};
+use serde::Deserialize;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::bcs;
+use tw_proto::Aptos::Proto;
+
+/// BCS layout of `SignedTransaction`.
+#[derive(Deserialize)]
+struct BcsSignedTransaction {
+    raw_txn: RawTransaction,
+    authenticator: TransactionAuthenticator,
+}
+
+pub struct AptosTransactionDecoder;
+
+impl TransactionDecoder for AptosTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl AptosTransactionDecoder {
+    /// Decodes BCS encoded `SignedTransaction` (e.g. `SigningOutput.encoded`)
+    /// or `RawTransaction` (e.g. `SigningOutput.raw_txn`).
+    fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let (raw_txn, authenticator) = match bcs::decode::<BcsSignedTransaction>(tx) {
+            Ok(signed) => (signed.raw_txn, Some(signed.authenticator)),
+            Err(_) => {
+                let raw_txn: RawTransaction = bcs::decode(tx)
+                    .tw_err(SigningErrorType::Error_input_parse)
+                    .context("Error decoding SignedTransaction or RawTransaction")?;
+                (raw_txn, None)
+            },
+        };
+        let raw_txn = raw_txn.with_decoded_json_args();
+
+        let json = match authenticator {
+            Some(ref authenticator) => raw_txn
+                .compile_with_authenticator(authenticator.clone())?
+                .to_json(),
+            None => raw_txn.to_json(),
+        };
+
+        let entry_function = match raw_txn.payload() {
+            TransactionPayload::EntryFunction(entry) => Some(Self::entry_function(entry)),
+            TransactionPayload::Script | TransactionPayload::ModuleBundle => None,
+        };
+
+        let transaction = Proto::DecodedTransaction {
+            sender: raw_txn.sender().to_hex_literal().into(),
+            sequence_number: raw_txn.sequence_number(),
+            max_gas_amount: raw_txn.max_gas_amount(),
+            gas_unit_price: raw_txn.gas_unit_price(),
+            expiration_timestamp_secs: raw_txn.expiration_timestamp_secs(),
+            chain_id: raw_txn.chain_id() as u32,
+            entry_function,
+            authenticator: authenticator.map(Proto::TransactionAuthenticator::from),
+            json: json.to_string().into(),
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    fn entry_function(entry: &EntryFunction) -> Proto::DecodedEntryFunction<'static> {
+        Proto::DecodedEntryFunction {
+            function: entry.function_id().into(),
+            type_arguments: entry
+                .ty_args()
+                .iter()
+                .map(|tag| tag.to_string().into())
+                .collect(),
+            arguments: entry.args().iter().map(|arg| arg.clone().into()).collect(),
+            json_arguments: entry.json_args().to_string().into(),
+        }
+    }
+}
@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::{from_account_error, Scheme};
+use move_core_types::account_address::AccountAddress;
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Value};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::encode;
+use tw_hash::sha3::sha3_256;
+use tw_hash::H512;
+use tw_keypair::ed25519::sha512::PublicKey;
+use tw_memory::Data;
+use tw_proto::Aptos::Proto;
+
+/// Max number of the multi-ed25519 account members.
+pub const MAX_NUM_OF_KEYS: usize = 32;
+/// Length of the signatures bitmap in bytes.
+pub const BITMAP_LEN: usize = 4;
+
+/// Public key of a K-of-N multi-ed25519 account.
+/// https://github.com/aptos-labs/aptos-core/blob/main/crates/aptos-crypto/src/multi_ed25519.rs
+#[derive(Clone)]
+pub struct MultiEd25519PublicKey {
+    public_keys: Vec<PublicKey>,
+    threshold: u8,
+}
+
+impl MultiEd25519PublicKey {
+    pub fn new(public_keys: Vec<PublicKey>, threshold: u8) -> SigningResult<Self> {
+        if public_keys.is_empty() || public_keys.len() > MAX_NUM_OF_KEYS {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("Multi-ed25519 account must have from 1 to {MAX_NUM_OF_KEYS} public keys")
+            });
+        }
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multi-ed25519 threshold must be from 1 to the number of public keys");
+        }
+        Ok(MultiEd25519PublicKey {
+            public_keys,
+            threshold,
+        })
+    }
+
+    pub fn from_proto(public_key: &Proto::MultiEd25519PublicKey) -> SigningResult<Self> {
+        let public_keys = public_key
+            .public_keys
+            .iter()
+            .enumerate()
+            .map(|(i, pk)| {
+                PublicKey::try_from(pk.as_ref())
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Invalid multi-ed25519 public key #{i}"))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let threshold = u8::try_from(public_key.threshold)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Multi-ed25519 threshold is too large")?;
+        MultiEd25519PublicKey::new(public_keys, threshold)
+    }
+
+    /// Parses `public_key_1 || ... || public_key_N || threshold`.
+    pub fn from_bytes(bytes: &[u8]) -> SigningResult<Self> {
+        let (threshold, public_keys) = bytes
+            .split_last()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Empty multi-ed25519 public key")?;
+        if public_keys.len() % PublicKey::LEN != 0 {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Invalid multi-ed25519 public key length");
+        }
+        let public_keys = public_keys
+            .chunks(PublicKey::LEN)
+            .map(|pk| PublicKey::try_from(pk).tw_err(SigningErrorType::Error_invalid_params))
+            .collect::<SigningResult<Vec<_>>>()?;
+        MultiEd25519PublicKey::new(public_keys, *threshold)
+    }
+
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns an index of the given public key.
+    pub fn key_index(&self, public_key: &PublicKey) -> Option<usize> {
+        self.public_keys.iter().position(|pk| pk == public_key)
+    }
+
+    /// Returns `public_key_1 || ... || public_key_N || threshold`.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .public_keys
+            .iter()
+            .flat_map(|pk| pk.as_slice().to_vec())
+            .collect();
+        bytes.push(self.threshold);
+        bytes
+    }
+
+    /// Returns an authentication key, that is the account address unless the key has been rotated.
+    pub fn auth_key(&self) -> SigningResult<AccountAddress> {
+        let mut to_hash = self.to_bytes();
+        to_hash.push(Scheme::MultiEd25519 as u8);
+        AccountAddress::from_bytes(sha3_256(&to_hash))
+            .map_err(from_account_error)
+            .into_tw()
+    }
+
+    /// Combines signatures of the account members.
+    /// The number of signatures must reach the threshold.
+    pub fn combine(
+        &self,
+        signatures: Vec<(PublicKey, H512)>,
+    ) -> SigningResult<MultiEd25519Signature> {
+        let mut indexed_signatures = Vec::with_capacity(signatures.len());
+        let mut bitmap = [0_u8; BITMAP_LEN];
+
+        for (public_key, signature) in signatures {
+            let index = self
+                .key_index(&public_key)
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Signature public key is not a member of the multi-ed25519 account")?;
+
+            // Bits are numbered from the most significant bit of the first byte.
+            let (byte, bit) = (index / 8, 128_u8 >> (index % 8));
+            if bitmap[byte] & bit != 0 {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Duplicate signature of the member #{index}"));
+            }
+            bitmap[byte] |= bit;
+            indexed_signatures.push((index, signature));
+        }
+
+        if indexed_signatures.len() < self.threshold as usize {
+            return SigningError::err(SigningErrorType::Error_signatures_count).with_context(
+                || {
+                    format!(
+                        "Expected at least {} signatures, found {}",
+                        self.threshold,
+                        indexed_signatures.len()
+                    )
+                },
+            );
+        }
+
+        // Signatures must be ordered as the corresponding public keys.
+        indexed_signatures.sort_by_key(|(index, _)| *index);
+        Ok(MultiEd25519Signature {
+            signatures: indexed_signatures
+                .into_iter()
+                .map(|(_, signature)| signature)
+                .collect(),
+            bitmap,
+        })
+    }
+}
+
+/// Serialized as bytes.
+impl Serialize for MultiEd25519PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiEd25519PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = serde_bytes::ByteBuf::deserialize(deserializer)?;
+        MultiEd25519PublicKey::from_bytes(&bytes)
+            .map_err(|_| D::Error::custom("Invalid multi-ed25519 public key"))
+    }
+}
+
+/// Signatures of the multi-ed25519 account members along with the bitmap of the signers.
+#[derive(Clone)]
+pub struct MultiEd25519Signature {
+    signatures: Vec<H512>,
+    bitmap: [u8; BITMAP_LEN],
+}
+
+impl MultiEd25519Signature {
+    /// Parses `signature_1 || ... || signature_K || bitmap`.
+    pub fn from_bytes(bytes: &[u8]) -> SigningResult<Self> {
+        if bytes.len() < BITMAP_LEN || (bytes.len() - BITMAP_LEN) % H512::LEN != 0 {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Invalid multi-ed25519 signature length");
+        }
+        let (signatures, bitmap) = bytes.split_at(bytes.len() - BITMAP_LEN);
+        let signatures: Vec<_> = signatures
+            .chunks(H512::LEN)
+            .map(|signature| {
+                H512::try_from(signature).tw_err(SigningErrorType::Error_invalid_params)
+            })
+            .collect::<SigningResult<_>>()?;
+        let bitmap: [u8; BITMAP_LEN] = bitmap
+            .try_into()
+            .tw_err(SigningErrorType::Error_invalid_params)?;
+
+        let signers_count: u32 = bitmap.iter().map(|byte| byte.count_ones()).sum();
+        if signers_count as usize != signatures.len() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Number of multi-ed25519 signatures doesn't match the bitmap");
+        }
+        Ok(MultiEd25519Signature { signatures, bitmap })
+    }
+
+    /// Returns `signature_1 || ... || signature_K || bitmap`.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .signatures
+            .iter()
+            .flat_map(|signature| signature.as_slice().to_vec())
+            .collect();
+        bytes.extend_from_slice(&self.bitmap);
+        bytes
+    }
+
+    pub fn to_json(&self, public_key: &MultiEd25519PublicKey) -> Value {
+        let public_keys: Vec<_> = public_key
+            .public_keys
+            .iter()
+            .map(|pk| encode(pk.as_slice(), true))
+            .collect();
+        let signatures: Vec<_> = self
+            .signatures
+            .iter()
+            .map(|signature| encode(signature.as_slice(), true))
+            .collect();
+        json!({"public_keys": public_keys,
+               "signatures": signatures,
+               "threshold": public_key.threshold,
+               "bitmap": encode(&self.bitmap, true),
+               "type": "multi_ed25519_signature"})
+    }
+}
+
+/// Serialized as bytes.
+impl Serialize for MultiEd25519Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiEd25519Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = serde_bytes::ByteBuf::deserialize(deserializer)?;
+        MultiEd25519Signature::from_bytes(&bytes)
+            .map_err(|_| D::Error::custom("Invalid multi-ed25519 signature"))
+    }
+}
@@ -3,11 +3,14 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::Address;
+use crate::transaction::AccountAuthenticator;
 use crate::transaction_builder;
+use crate::transaction_signers::TransactionSigners;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
 use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_proto::Aptos::Proto;
 
 pub struct Signer;
@@ -22,16 +25,49 @@ impl Signer {
     fn sign_proto_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
-        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let signers = TransactionSigners::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
         let sender = Address::from_str(&input.sender)
             .into_tw()
             .context("Invalid sender address")?;
-        let signed_tx = builder
+        let raw_tx = builder
             .sender(sender.inner())
             .sequence_number(input.sequence_number as u64)
-            .build()?
-            .sign(key_pair)?;
+            .build()?;
+
+        let to_sign = signers.pre_image(&raw_tx)?;
+        let sender_signer = match signers.multi_ed25519 {
+            Some(ref multi_ed25519) => {
+                let signatures = input
+                    .multi_ed25519_private_keys
+                    .iter()
+                    .map(|private_key| -> SigningResult<_> {
+                        let key_pair = ed25519::sha512::KeyPair::try_from(private_key.as_ref())?;
+                        let signature = key_pair.private().sign(to_sign.clone())?;
+                        Ok((key_pair.public().clone(), signature.to_bytes()))
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+                AccountAuthenticator::MultiEd25519 {
+                    public_key: multi_ed25519.clone(),
+                    signature: multi_ed25519.combine(signatures)?,
+                }
+            },
+            None => Self::sign_ed25519(&input.private_key, &to_sign)?,
+        };
+        let secondary_signers = input
+            .secondary_signers
+            .iter()
+            .map(|signer| Self::sign_ed25519(&signer.private_key, &to_sign))
+            .collect::<SigningResult<Vec<_>>>()?;
+        let fee_payer_signer = input
+            .fee_payer
+            .as_ref()
+            .map(|fee_payer| Self::sign_ed25519(&fee_payer.private_key, &to_sign))
+            .transpose()?;
+
+        let authenticator =
+            signers.authenticator(sender_signer, secondary_signers, fee_payer_signer)?;
+        let signed_tx = raw_tx.compile_with_authenticator(authenticator)?;
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
@@ -40,4 +76,13 @@ impl Signer {
             ..Proto::SigningOutput::default()
         })
     }
+
+    fn sign_ed25519(private_key: &[u8], to_sign: &[u8]) -> SigningResult<AccountAuthenticator> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(private_key)?;
+        let signature = key_pair.private().sign(to_sign.to_vec())?;
+        Ok(AccountAuthenticator::Ed25519 {
+            public_key: key_pair.public().as_slice().to_vec(),
+            signature: signature.to_bytes().into_vec(),
+        })
+    }
 }
@@ -2,10 +2,11 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::constants::APTOS_SALT;
+use crate::constants::{APTOS_SALT, APTOS_WITH_DATA_SALT};
+use crate::multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature};
 use crate::transaction_payload::TransactionPayload;
 use move_core_types::account_address::AccountAddress;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::borrow::Cow;
 use tw_coin_entry::error::prelude::*;
@@ -16,13 +17,76 @@ use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_memory::Data;
 use tw_proto::Aptos::Proto;
 
-#[derive(Clone, Serialize)]
+/// Authenticates a single account, either the sender, a secondary signer or a fee payer.
+#[derive(Clone, Deserialize, Serialize)]
+pub enum AccountAuthenticator {
+    /// Single Ed25519 signature
+    Ed25519 {
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    /// K-of-N multi-ed25519 signature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
+}
+
+impl AccountAuthenticator {
+    pub fn get_signature(&self) -> Vec<u8> {
+        match self {
+            AccountAuthenticator::Ed25519 { signature, .. } => signature.clone(),
+            AccountAuthenticator::MultiEd25519 { signature, .. } => signature.to_bytes(),
+        }
+    }
+
+    pub fn get_public_key(&self) -> Vec<u8> {
+        match self {
+            AccountAuthenticator::Ed25519 { public_key, .. } => public_key.clone(),
+            AccountAuthenticator::MultiEd25519 { public_key, .. } => public_key.to_bytes(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => ed25519_json(public_key, signature),
+            AccountAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => signature.to_json(public_key),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
 pub enum TransactionAuthenticator {
     /// Single Ed25519 signature
     Ed25519 {
         public_key: Vec<u8>,
         signature: Vec<u8>,
     },
+    /// K-of-N multi-ed25519 signature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
+    /// Multi-agent transaction signed by the sender and the secondary signers
+    MultiAgent {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+    },
+    /// Sponsored transaction where the gas fee is paid by the fee payer
+    FeePayer {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_address: AccountAddress,
+        fee_payer_signer: AccountAuthenticator,
+    },
 }
 
 impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
@@ -35,21 +99,23 @@ impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
 }
 
 impl TransactionAuthenticator {
+    /// Returns the sender signature.
     pub fn get_signature(&self) -> Vec<u8> {
         match self {
-            TransactionAuthenticator::Ed25519 {
-                public_key: _public_key,
-                signature,
-            } => signature.clone(),
+            TransactionAuthenticator::Ed25519 { signature, .. } => signature.clone(),
+            TransactionAuthenticator::MultiEd25519 { signature, .. } => signature.to_bytes(),
+            TransactionAuthenticator::MultiAgent { sender, .. }
+            | TransactionAuthenticator::FeePayer { sender, .. } => sender.get_signature(),
         }
     }
 
+    /// Returns the sender public key.
     pub fn get_public_key(&self) -> Vec<u8> {
         match self {
-            TransactionAuthenticator::Ed25519 {
-                public_key,
-                signature: _signature,
-            } => public_key.clone(),
+            TransactionAuthenticator::Ed25519 { public_key, .. } => public_key.clone(),
+            TransactionAuthenticator::MultiEd25519 { public_key, .. } => public_key.to_bytes(),
+            TransactionAuthenticator::MultiAgent { sender, .. }
+            | TransactionAuthenticator::FeePayer { sender, .. } => sender.get_public_key(),
         }
     }
 
@@ -58,17 +124,76 @@ impl TransactionAuthenticator {
             TransactionAuthenticator::Ed25519 {
                 public_key,
                 signature,
+            } => ed25519_json(public_key, signature),
+            TransactionAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => signature.to_json(public_key),
+            TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            } => {
+                json!({"sender": sender.to_json(),
+                       "secondary_signer_addresses": addresses_json(secondary_signer_addresses),
+                       "secondary_signers": authenticators_json(secondary_signers),
+                       "type": "multi_agent_signature"})
+            },
+            TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
             } => {
-                json!({"public_key": encode(public_key, true),
-                       "signature": encode(signature, true),
-                       "type": "ed25519_signature"})
+                json!({"sender": sender.to_json(),
+                       "secondary_signer_addresses": addresses_json(secondary_signer_addresses),
+                       "secondary_signers": authenticators_json(secondary_signers),
+                       "fee_payer_address": fee_payer_address.to_hex_literal(),
+                       "fee_payer_signer": fee_payer_signer.to_json(),
+                       "type": "fee_payer_signature"})
             },
         }
     }
 }
 
+fn ed25519_json(public_key: &[u8], signature: &[u8]) -> Value {
+    json!({"public_key": encode(public_key, true),
+           "signature": encode(signature, true),
+           "type": "ed25519_signature"})
+}
+
+fn addresses_json(addresses: &[AccountAddress]) -> Value {
+    addresses
+        .iter()
+        .map(|address| Value::String(address.to_hex_literal()))
+        .collect()
+}
+
+fn authenticators_json(authenticators: &[AccountAuthenticator]) -> Value {
+    authenticators
+        .iter()
+        .map(AccountAuthenticator::to_json)
+        .collect()
+}
+
+/// Raw transaction along with the additional signers.
+/// Signed by every signer of a multi-agent or a sponsored transaction.
+#[derive(Serialize)]
+enum RawTransactionWithData<'a> {
+    MultiAgent {
+        raw_txn: &'a RawTransaction,
+        secondary_signer_addresses: &'a [AccountAddress],
+    },
+    MultiAgentWithFeePayer {
+        raw_txn: &'a RawTransaction,
+        secondary_signer_addresses: &'a [AccountAddress],
+        fee_payer_address: AccountAddress,
+    },
+}
+
 /// RawTransaction is the portion of a transaction that a client signs.
-#[derive(Clone, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct RawTransaction {
     /// Sender's address.
     sender: AccountAddress,
@@ -122,6 +247,41 @@ impl RawTransaction {
         }
     }
 
+    pub fn sender(&self) -> AccountAddress {
+        self.sender
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    pub fn payload(&self) -> &TransactionPayload {
+        &self.payload
+    }
+
+    pub fn max_gas_amount(&self) -> u64 {
+        self.max_gas_amount
+    }
+
+    pub fn gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+
+    pub fn expiration_timestamp_secs(&self) -> u64 {
+        self.expiration_timestamp_secs
+    }
+
+    pub fn chain_id(&self) -> u8 {
+        self.chain_id
+    }
+
+    /// Restores the JSON representation of the payload arguments
+    /// after the transaction is decoded from BCS.
+    pub fn with_decoded_json_args(mut self) -> Self {
+        self.payload = self.payload.with_decoded_json_args();
+        self
+    }
+
     /// Create a new `RawTransaction` with an entry function
     fn serialize(&self) -> EncodingResult<Data> {
         bcs::encode(&self)
@@ -141,16 +301,47 @@ impl RawTransaction {
         self.msg_to_sign()
     }
 
+    /// Returns a message to be signed by every signer of a multi-agent or a sponsored transaction.
+    pub fn pre_image_with_data(
+        &self,
+        secondary_signer_addresses: &[AccountAddress],
+        fee_payer_address: Option<AccountAddress>,
+    ) -> SigningResult<Vec<u8>> {
+        let with_data = match fee_payer_address {
+            Some(fee_payer_address) => RawTransactionWithData::MultiAgentWithFeePayer {
+                raw_txn: self,
+                secondary_signer_addresses,
+                fee_payer_address,
+            },
+            None => RawTransactionWithData::MultiAgent {
+                raw_txn: self,
+                secondary_signer_addresses,
+            },
+        };
+        let serialized = bcs::encode(&with_data)
+            .into_tw()
+            .context("Error serializing RawTransactionWithData")?;
+        let mut preimage = tw_hash::sha3::sha3_256(APTOS_WITH_DATA_SALT);
+        preimage.extend_from_slice(serialized.as_slice());
+        Ok(preimage)
+    }
+
     pub fn compile(
         &self,
         signature: Vec<u8>,
         public_key: Vec<u8>,
     ) -> SigningResult<SignedTransaction> {
-        let serialized = self.serialize()?;
-        let auth = TransactionAuthenticator::Ed25519 {
+        self.compile_with_authenticator(TransactionAuthenticator::Ed25519 {
             public_key,
             signature,
-        };
+        })
+    }
+
+    pub fn compile_with_authenticator(
+        &self,
+        auth: TransactionAuthenticator,
+    ) -> SigningResult<SignedTransaction> {
+        let serialized = self.serialize()?;
         let mut encoded = serialized.clone();
         encoded.extend_from_slice(bcs::encode(&auth)?.as_slice());
         Ok(SignedTransaction {
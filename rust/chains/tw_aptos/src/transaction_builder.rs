@@ -5,11 +5,15 @@
 use crate::address::from_account_error;
 use crate::aptos_move_packages::{
     aptos_account_create_account, aptos_account_transfer, aptos_account_transfer_coins,
-    coin_transfer, fungible_asset_transfer, managed_coin_register,
+    coin_transfer, fungible_asset_transfer, managed_coin_register, object_transfer,
     token_transfers_cancel_offer_script, token_transfers_claim_script,
     token_transfers_offer_script,
 };
 use crate::constants::{GAS_UNIT_PRICE, MAX_GAS_AMOUNT};
+use crate::delegation_pool::{
+    delegation_pool_add_stake, delegation_pool_unlock, delegation_pool_withdraw,
+    DelegationPoolOperation,
+};
 use crate::liquid_staking::{
     tortuga_claim, tortuga_stake, tortuga_unstake, LiquidStakingOperation,
 };
@@ -160,6 +164,20 @@ impl TransactionFactory {
                         .context("Invalid destination address")?,
                     fungible_asset_transfer.amount,
                 ),
+            OneOftransaction_payload::digital_asset_transfer(digital_asset_transfer) => factory
+                .digital_asset_transfer(
+                    AccountAddress::from_str(&digital_asset_transfer.object_address)
+                        .map_err(from_account_error)
+                        .into_tw()
+                        .context("Invalid object address")?,
+                    AccountAddress::from_str(&digital_asset_transfer.to)
+                        .map_err(from_account_error)
+                        .into_tw()
+                        .context("Invalid destination address")?,
+                ),
+            OneOftransaction_payload::delegation_pool_message(msg) => {
+                factory.delegation_pool_ops(DelegationPoolOperation::try_from(msg)?)
+            },
             OneOftransaction_payload::None => {
                 let is_blind_sign = !input.any_encoded.is_empty();
                 let v = serde_json::from_str::<Value>(&input.any_encoded)
@@ -249,6 +267,23 @@ impl TransactionFactory {
         }
     }
 
+    pub fn delegation_pool_ops(
+        &self,
+        operation: DelegationPoolOperation,
+    ) -> SigningResult<TransactionBuilder> {
+        match operation {
+            DelegationPoolOperation::AddStake(add_stake) => Ok(self.payload(
+                delegation_pool_add_stake(add_stake.pool_address, add_stake.amount)?,
+            )),
+            DelegationPoolOperation::Unlock(unlock) => {
+                Ok(self.payload(delegation_pool_unlock(unlock.pool_address, unlock.amount)?))
+            },
+            DelegationPoolOperation::Withdraw(withdraw) => Ok(self.payload(
+                delegation_pool_withdraw(withdraw.pool_address, withdraw.amount)?,
+            )),
+        }
+    }
+
     pub fn implicitly_create_user_account_and_transfer(
         &self,
         to: AccountAddress,
@@ -275,6 +310,14 @@ impl TransactionFactory {
         Ok(self.payload(fungible_asset_transfer(metadata_address, to, amount)?))
     }
 
+    pub fn digital_asset_transfer(
+        &self,
+        object_address: AccountAddress,
+        to: AccountAddress,
+    ) -> SigningResult<TransactionBuilder> {
+        Ok(self.payload(object_transfer(object_address, to)?))
+    }
+
     pub fn implicitly_create_user_and_coins_transfer(
         &self,
         to: AccountAddress,
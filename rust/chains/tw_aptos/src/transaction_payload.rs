@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::aptos_move_packages::known_entry_function_abi;
 use crate::aptos_move_types::MoveType;
 use crate::constants::{OBJECT_MODULE, OBJECT_STRUCT};
 use crate::serde_helper::vec_bytes;
@@ -17,7 +18,7 @@ use serde_json::{json, Value};
 use std::default::Default;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
-use tw_encoding::hex::DecodeHex;
+use tw_encoding::hex::{self, DecodeHex};
 use tw_encoding::{bcs, EncodingError, EncodingResult};
 use tw_memory::Data;
 use tw_proto::Aptos;
@@ -55,7 +56,9 @@ pub struct EntryFunction {
     ty_args: Vec<TypeTag>,
     #[serde(with = "vec_bytes")]
     args: Vec<Vec<u8>>,
-    #[serde(skip_serializing)]
+    /// Not a part of the BCS encoding.
+    /// Restored by [`EntryFunction::with_decoded_json_args`] if the function is decoded.
+    #[serde(skip)]
     json_args: Value,
 }
 
@@ -150,6 +153,40 @@ fn convert_to_move_value(abi_str: &str, element: Value) -> EntryFunctionResult<M
     parse_argument(&layout, element).map_err(|_| EntryFunctionError::InvalidArguments)
 }
 
+/// Converts a BCS encoded argument to JSON according to its ABI type.
+/// Returns `None` if the type is not supported or the argument doesn't match it.
+fn decode_argument(abi_str: &str, arg: &[u8]) -> Option<Value> {
+    let move_type: MoveType = abi_str.parse().ok()?;
+    let type_tag: TypeTag = move_type.try_into().ok()?;
+    let value = match type_tag {
+        TypeTag::Bool => json!(bcs::decode::<bool>(arg).ok()?),
+        TypeTag::U8 => json!(bcs::decode::<u8>(arg).ok()?),
+        TypeTag::U16 => json!(bcs::decode::<u16>(arg).ok()?),
+        TypeTag::U32 => json!(bcs::decode::<u32>(arg).ok()?),
+        // Large integers are represented as strings, see `EntryFunction::to_json`.
+        TypeTag::U64 => json!(bcs::decode::<u64>(arg).ok()?.to_string()),
+        TypeTag::U128 => json!(bcs::decode::<u128>(arg).ok()?.to_string()),
+        TypeTag::U256 => json!(bcs::decode::<u256::U256>(arg).ok()?.to_string()),
+        TypeTag::Address => json!(bcs::decode::<AccountAddress>(arg).ok()?.to_hex_literal()),
+        TypeTag::Vector(inner) if *inner == TypeTag::U8 => {
+            json!(hex::encode(bcs::decode::<Data>(arg).ok()?, true))
+        },
+        TypeTag::Struct(st) if is_utf8_string(&st) => {
+            json!(String::from_utf8(bcs::decode::<Data>(arg).ok()?).ok()?)
+        },
+        TypeTag::Struct(st)
+            if st.address == AccountAddress::ONE
+                && st.module.as_ident_str() == OBJECT_MODULE
+                && st.name.as_ident_str() == OBJECT_STRUCT =>
+        {
+            // Objects are just laid out as an address.
+            json!(bcs::decode::<AccountAddress>(arg).ok()?.to_hex_literal())
+        },
+        _ => return None,
+    };
+    Some(value)
+}
+
 fn build_type_layout(t: &TypeTag) -> EncodingResult<MoveTypeLayout> {
     use TypeTag::*;
     Ok(match t {
@@ -369,6 +406,45 @@ pub fn convert_type_tag_to_struct_tag(type_tag: TypeTag) -> Aptos::Proto::Struct
 }
 
 impl EntryFunction {
+    /// Returns the fully qualified function name, e.g. `0x1::aptos_account::transfer`.
+    pub fn function_id(&self) -> String {
+        format!(
+            "{}::{}",
+            self.module.short_str_lossless(),
+            self.function.as_str()
+        )
+    }
+
+    pub fn ty_args(&self) -> &[TypeTag] {
+        &self.ty_args
+    }
+
+    pub fn args(&self) -> &[Data] {
+        &self.args
+    }
+
+    pub fn json_args(&self) -> &Value {
+        &self.json_args
+    }
+
+    /// Restores the JSON arguments of an entry function decoded from BCS.
+    /// Arguments of the known functions are decoded according to their ABI,
+    /// the rest are represented as hex strings.
+    pub fn with_decoded_json_args(mut self) -> Self {
+        let abi = known_entry_function_abi(&self.function_id()).unwrap_or_default();
+        self.json_args = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(index, arg)| {
+                abi.get(index)
+                    .and_then(|abi_str| decode_argument(abi_str, arg))
+                    .unwrap_or_else(|| json!(hex::encode(arg, true)))
+            })
+            .collect();
+        self
+    }
+
     fn to_json(&self) -> Value {
         // Create a JSON array from the `ty_args` field by filtering and mapping
         // the items that match `TypeTag::Struct` to their string representation.
@@ -381,7 +457,7 @@ impl EntryFunction {
         // Construct the final JSON value
         json!({
             "type": "entry_function_payload",
-            "function": format!("{}::{}", self.module.short_str_lossless(), self.function.clone().into_string()),
+            "function": self.function_id(),
             "arguments": self.json_args,
             "type_arguments": type_arguments
         })
@@ -404,6 +480,16 @@ impl TransactionPayload {
             TransactionPayload::EntryFunction(entry) => entry.to_json(),
         }
     }
+
+    /// Restores the JSON arguments if the payload is an entry function decoded from BCS.
+    pub fn with_decoded_json_args(self) -> Self {
+        match self {
+            TransactionPayload::EntryFunction(entry) => {
+                TransactionPayload::EntryFunction(entry.with_decoded_json_args())
+            },
+            payload => payload,
+        }
+    }
 }
 
 impl EntryFunction {
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::multi_ed25519::MultiEd25519PublicKey;
+use crate::transaction::{AccountAuthenticator, RawTransaction, TransactionAuthenticator};
+use move_core_types::account_address::AccountAddress;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H512;
+use tw_keypair::ed25519::sha512::PublicKey;
+use tw_memory::Data;
+use tw_proto::Aptos::Proto;
+
+/// Accounts that authorize a transaction.
+#[derive(Clone, Default)]
+pub struct TransactionSigners {
+    /// Public key of the sender if it's a multi-ed25519 account.
+    pub multi_ed25519: Option<MultiEd25519PublicKey>,
+    pub secondary_signer_addresses: Vec<AccountAddress>,
+    pub fee_payer_address: Option<AccountAddress>,
+}
+
+impl TransactionSigners {
+    pub fn from_proto(input: &Proto::SigningInput) -> SigningResult<TransactionSigners> {
+        let multi_ed25519 = input
+            .multi_ed25519
+            .as_ref()
+            .map(MultiEd25519PublicKey::from_proto)
+            .transpose()?;
+        let secondary_signer_addresses = input
+            .secondary_signers
+            .iter()
+            .map(|signer| {
+                parse_address(&signer.address).context("Invalid secondary signer address")
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let fee_payer_address = input
+            .fee_payer
+            .as_ref()
+            .map(|fee_payer| parse_address(&fee_payer.address).context("Invalid fee payer address"))
+            .transpose()?;
+
+        Ok(TransactionSigners {
+            multi_ed25519,
+            secondary_signer_addresses,
+            fee_payer_address,
+        })
+    }
+
+    /// Whether the transaction is signed by more than one account.
+    pub fn is_multi_agent(&self) -> bool {
+        !self.secondary_signer_addresses.is_empty() || self.fee_payer_address.is_some()
+    }
+
+    /// Returns a message to be signed by every signer of the transaction.
+    pub fn pre_image(&self, raw_tx: &RawTransaction) -> SigningResult<Data> {
+        if self.is_multi_agent() {
+            raw_tx.pre_image_with_data(&self.secondary_signer_addresses, self.fee_payer_address)
+        } else {
+            raw_tx.pre_image()
+        }
+    }
+
+    /// Builds a transaction authenticator from the account authenticators of every signer.
+    pub fn authenticator(
+        &self,
+        sender: AccountAuthenticator,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_signer: Option<AccountAuthenticator>,
+    ) -> SigningResult<TransactionAuthenticator> {
+        if secondary_signers.len() != self.secondary_signer_addresses.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected a signature of every secondary signer");
+        }
+        let secondary_signer_addresses = self.secondary_signer_addresses.clone();
+
+        if let Some(fee_payer_address) = self.fee_payer_address {
+            let fee_payer_signer = fee_payer_signer
+                .or_tw_err(SigningErrorType::Error_signatures_count)
+                .context("Expected a signature of the fee payer")?;
+            return Ok(TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
+            });
+        }
+
+        if self.is_multi_agent() {
+            return Ok(TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            });
+        }
+
+        Ok(match sender {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            },
+            AccountAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => TransactionAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            },
+        })
+    }
+
+    /// Builds a transaction authenticator from the signatures produced externally.
+    ///
+    /// Signatures of the multi-ed25519 account members are combined into the sender authenticator.
+    /// The other signatures are expected in the following order:
+    /// the sender (unless it's a multi-ed25519 account), the secondary signers, the fee payer.
+    pub fn authenticator_from_signatures(
+        &self,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<TransactionAuthenticator> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Number of signatures and public keys must be equal");
+        }
+
+        let mut member_signatures = Vec::new();
+        let mut ed25519_signers = Vec::new();
+        for (signature, public_key) in signatures.into_iter().zip(public_keys) {
+            if let Some(ref multi_ed25519) = self.multi_ed25519 {
+                let member = PublicKey::try_from(public_key.as_slice())
+                    .ok()
+                    .filter(|pk| multi_ed25519.key_index(pk).is_some());
+                if let Some(member) = member {
+                    let signature = H512::try_from(signature.as_slice())
+                        .tw_err(SigningErrorType::Error_invalid_params)
+                        .context("Invalid multi-ed25519 member signature")?;
+                    member_signatures.push((member, signature));
+                    continue;
+                }
+            }
+            ed25519_signers.push(AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            });
+        }
+
+        let mut ed25519_signers = ed25519_signers.into_iter();
+        let mut next_signer = |name: &str| {
+            ed25519_signers
+                .next()
+                .or_tw_err(SigningErrorType::Error_signatures_count)
+                .with_context(|| format!("Expected a signature of the {name}"))
+        };
+
+        let sender = match self.multi_ed25519 {
+            Some(ref multi_ed25519) => AccountAuthenticator::MultiEd25519 {
+                public_key: multi_ed25519.clone(),
+                signature: multi_ed25519.combine(member_signatures)?,
+            },
+            None => next_signer("sender")?,
+        };
+        let secondary_signers = self
+            .secondary_signer_addresses
+            .iter()
+            .map(|_| next_signer("secondary signer"))
+            .collect::<SigningResult<Vec<_>>>()?;
+        let fee_payer_signer = self
+            .fee_payer_address
+            .map(|_| next_signer("fee payer"))
+            .transpose()?;
+
+        if ed25519_signers.next().is_some() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Too many signatures provided");
+        }
+
+        self.authenticator(sender, secondary_signers, fee_payer_signer)
+    }
+}
+
+fn parse_address(address: &str) -> SigningResult<AccountAddress> {
+    Address::from_str(address)
+        .map(|address| address.inner())
+        .into_tw()
+}
@@ -5,6 +5,7 @@
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
 use std::str::FromStr;
+use tw_aptos::delegation_pool::{AddStake, DelegationPoolOperation, Unlock, Withdraw};
 use tw_aptos::liquid_staking;
 use tw_aptos::liquid_staking::{LiquidStakingOperation, Stake, Unstake};
 use tw_aptos::nft::{Claim, NftOperation, Offer};
@@ -35,6 +36,11 @@ pub struct FungibleAssetTransfer {
     amount: u64,
 }
 
+pub struct DigitalAssetTransfer {
+    object_address: String,
+    to: String,
+}
+
 pub struct RegisterToken {
     coin_type: TypeTag,
 }
@@ -48,6 +54,8 @@ pub enum OpsDetails {
     ImplicitTokenTransfer(TokenTransfer),
     NftOps(NftOperation),
     FungibleAssetTransfer(FungibleAssetTransfer),
+    DigitalAssetTransfer(DigitalAssetTransfer),
+    DelegationPoolOps(DelegationPoolOperation),
 }
 
 fn setup_proto_transaction<'a>(
@@ -156,6 +164,27 @@ fn setup_proto_transaction<'a>(
                 panic!("Unsupported arguments")
             }
         },
+        "digital_asset_transfer" => {
+            if let OpsDetails::DigitalAssetTransfer(digital_asset_transfer) = ops_details.unwrap() {
+                Proto::mod_SigningInput::OneOftransaction_payload::digital_asset_transfer(
+                    Proto::DigitalAssetTransferMessage {
+                        object_address: digital_asset_transfer.object_address.into(),
+                        to: digital_asset_transfer.to.into(),
+                    },
+                )
+            } else {
+                panic!("Unsupported arguments")
+            }
+        },
+        "delegation_pool_ops" => {
+            if let OpsDetails::DelegationPoolOps(delegation_pool_ops) = ops_details.unwrap() {
+                Proto::mod_SigningInput::OneOftransaction_payload::delegation_pool_message(
+                    delegation_pool_ops.into(),
+                )
+            } else {
+                panic!("Unsupported arguments")
+            }
+        },
         "blind_sign_json" => Proto::mod_SigningInput::OneOftransaction_payload::None,
         _ => Proto::mod_SigningInput::OneOftransaction_payload::None,
     };
@@ -171,6 +200,7 @@ fn setup_proto_transaction<'a>(
         any_encoded: any_encoded.into(),
         transaction_payload: payload,
         abi: abi.into(),
+        ..SigningInput::default()
     };
 
     input
@@ -1014,3 +1044,191 @@ fn test_aptos_blind_sign_unstaking() {
                             }
                     }"#);
 }
+
+#[test]
+fn test_aptos_sign_digital_asset_transfer() {
+    let input = setup_proto_transaction(
+        "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", // Sender's address
+        "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",   // Keypair
+        "digital_asset_transfer",
+        75, // Sequence number
+        1,
+        20,
+        1736060099,
+        100,
+        "",
+        "",
+        Some(OpsDetails::DigitalAssetTransfer(DigitalAssetTransfer {
+            object_address: "0xf1a8b5b1a4c2f2e06c2fd1b3a5ac9a4e0ed1e1a3d1e6b1b8b5c0d2e3f4a5b6c7"
+                .to_string(),
+            to: "0x2d92d71078f11d923c2b703b95a288c0e2ae63c0d29154e6278bf8004f9b4e52".to_string(),
+        })),
+    );
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304b00000000000000020000000000000000000000000000000000000000000000000000000000000001066f626a656374087472616e736665720107000000000000000000000000000000000000000000000000000000000000000405746f6b656e05546f6b656e000220f1a8b5b1a4c2f2e06c2fd1b3a5ac9a4e0ed1e1a3d1e6b1b8b5c0d2e3f4a5b6c7202d92d71078f11d923c2b703b95a288c0e2ae63c0d29154e6278bf8004f9b4e5214000000000000006400000000000000c32c7a670000000001", // Expected raw transaction bytes
+                   "87701c2f67eace5fb35b671b0eb10f971df89812d5439fd761bd781822197c322b447643e6b5d85d6ea53130373c9969fde3f3f870165c95f94ab4c7ed18af04", // Expected signature
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304b00000000000000020000000000000000000000000000000000000000000000000000000000000001066f626a656374087472616e736665720107000000000000000000000000000000000000000000000000000000000000000405746f6b656e05546f6b656e000220f1a8b5b1a4c2f2e06c2fd1b3a5ac9a4e0ed1e1a3d1e6b1b8b5c0d2e3f4a5b6c7202d92d71078f11d923c2b703b95a288c0e2ae63c0d29154e6278bf8004f9b4e5214000000000000006400000000000000c32c7a6700000000010020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c4087701c2f67eace5fb35b671b0eb10f971df89812d5439fd761bd781822197c322b447643e6b5d85d6ea53130373c9969fde3f3f870165c95f94ab4c7ed18af04", // Expected encoded transaction
+                   r#"{
+                    "expiration_timestamp_secs": "1736060099",
+                    "gas_unit_price": "100",
+                    "max_gas_amount": "20",
+                    "payload": {
+                        "arguments": ["0xf1a8b5b1a4c2f2e06c2fd1b3a5ac9a4e0ed1e1a3d1e6b1b8b5c0d2e3f4a5b6c7", "0x2d92d71078f11d923c2b703b95a288c0e2ae63c0d29154e6278bf8004f9b4e52"],
+                        "function": "0x1::object::transfer",
+                        "type": "entry_function_payload",
+                        "type_arguments": ["0x4::token::Token"]
+                    },
+                    "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+                    "sequence_number": "75",
+                    "signature": {
+                        "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                        "signature": "0x87701c2f67eace5fb35b671b0eb10f971df89812d5439fd761bd781822197c322b447643e6b5d85d6ea53130373c9969fde3f3f870165c95f94ab4c7ed18af04",
+                        "type": "ed25519_signature"
+                    }
+                }"#);
+}
+
+#[test]
+fn test_aptos_delegation_pool_add_stake() {
+    let input = setup_proto_transaction(
+        "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", // Sender's address
+        "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",   // Keypair
+        "delegation_pool_ops",
+        76, // Sequence number
+        1,
+        20,
+        1736060099,
+        100,
+        "",
+        "",
+        Some(OpsDetails::DelegationPoolOps(
+            DelegationPoolOperation::AddStake(AddStake {
+                amount: 1100000000,
+                pool_address: AccountAddress::from_str(
+                    "0xdb5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e",
+                )
+                .unwrap(),
+            }),
+        )),
+    );
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304c000000000000000200000000000000000000000000000000000000000000000000000000000000010f64656c65676174696f6e5f706f6f6c096164645f7374616b65000220db5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e0800ab90410000000014000000000000006400000000000000c32c7a670000000001", // Expected raw transaction bytes
+                   "9f418fba089fc9d848648dc6f96f0edeebcd83e9b598f4eeb46de0a10b713e6167a5762423f85ebb9b359a44ac9ec24583ed94fc80926cd5dc4cda67d2787d07", // Expected signature
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304c000000000000000200000000000000000000000000000000000000000000000000000000000000010f64656c65676174696f6e5f706f6f6c096164645f7374616b65000220db5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e0800ab90410000000014000000000000006400000000000000c32c7a6700000000010020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c409f418fba089fc9d848648dc6f96f0edeebcd83e9b598f4eeb46de0a10b713e6167a5762423f85ebb9b359a44ac9ec24583ed94fc80926cd5dc4cda67d2787d07", // Expected encoded transaction
+                   r#"{
+                    "expiration_timestamp_secs": "1736060099",
+                    "gas_unit_price": "100",
+                    "max_gas_amount": "20",
+                    "payload": {
+                        "arguments": ["0xdb5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e", "1100000000"],
+                        "function": "0x1::delegation_pool::add_stake",
+                        "type": "entry_function_payload",
+                        "type_arguments": []
+                    },
+                    "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+                    "sequence_number": "76",
+                    "signature": {
+                        "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                        "signature": "0x9f418fba089fc9d848648dc6f96f0edeebcd83e9b598f4eeb46de0a10b713e6167a5762423f85ebb9b359a44ac9ec24583ed94fc80926cd5dc4cda67d2787d07",
+                        "type": "ed25519_signature"
+                    }
+                }"#);
+}
+
+#[test]
+fn test_aptos_delegation_pool_unlock() {
+    let input = setup_proto_transaction(
+        "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", // Sender's address
+        "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",   // Keypair
+        "delegation_pool_ops",
+        77, // Sequence number
+        1,
+        20,
+        1736060099,
+        100,
+        "",
+        "",
+        Some(OpsDetails::DelegationPoolOps(
+            DelegationPoolOperation::Unlock(Unlock {
+                amount: 1100000000,
+                pool_address: AccountAddress::from_str(
+                    "0xdb5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e",
+                )
+                .unwrap(),
+            }),
+        )),
+    );
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304d000000000000000200000000000000000000000000000000000000000000000000000000000000010f64656c65676174696f6e5f706f6f6c06756e6c6f636b000220db5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e0800ab90410000000014000000000000006400000000000000c32c7a670000000001", // Expected raw transaction bytes
+                   "fbcc28e1846056cba62a6a63f3f17681c0da7cd68b9ffe465ea0594d951404d727ef9955131254a41aa332d1927aa58a265e056206897ef2d351641928f6d203", // Expected signature
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304d000000000000000200000000000000000000000000000000000000000000000000000000000000010f64656c65676174696f6e5f706f6f6c06756e6c6f636b000220db5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e0800ab90410000000014000000000000006400000000000000c32c7a6700000000010020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40fbcc28e1846056cba62a6a63f3f17681c0da7cd68b9ffe465ea0594d951404d727ef9955131254a41aa332d1927aa58a265e056206897ef2d351641928f6d203", // Expected encoded transaction
+                   r#"{
+                    "expiration_timestamp_secs": "1736060099",
+                    "gas_unit_price": "100",
+                    "max_gas_amount": "20",
+                    "payload": {
+                        "arguments": ["0xdb5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e", "1100000000"],
+                        "function": "0x1::delegation_pool::unlock",
+                        "type": "entry_function_payload",
+                        "type_arguments": []
+                    },
+                    "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+                    "sequence_number": "77",
+                    "signature": {
+                        "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                        "signature": "0xfbcc28e1846056cba62a6a63f3f17681c0da7cd68b9ffe465ea0594d951404d727ef9955131254a41aa332d1927aa58a265e056206897ef2d351641928f6d203",
+                        "type": "ed25519_signature"
+                    }
+                }"#);
+}
+
+#[test]
+fn test_aptos_delegation_pool_withdraw() {
+    let input = setup_proto_transaction(
+        "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", // Sender's address
+        "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec",   // Keypair
+        "delegation_pool_ops",
+        78, // Sequence number
+        1,
+        20,
+        1736060099,
+        100,
+        "",
+        "",
+        Some(OpsDetails::DelegationPoolOps(
+            DelegationPoolOperation::Withdraw(Withdraw {
+                amount: 1100000000,
+                pool_address: AccountAddress::from_str(
+                    "0xdb5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e",
+                )
+                .unwrap(),
+            }),
+        )),
+    );
+    let output = Signer::sign_proto(input);
+    test_tx_result(output,
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304e000000000000000200000000000000000000000000000000000000000000000000000000000000010f64656c65676174696f6e5f706f6f6c087769746864726177000220db5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e0800ab90410000000014000000000000006400000000000000c32c7a670000000001", // Expected raw transaction bytes
+                   "3fc59908c985aca74008c862d8c6ff58556861c4583b4b1f91c674c4b6ddfa529ae2a9ad6ee62dfd20403e44b847feb2f5ae824c7c3b78c29c15cbeaa0883d0b", // Expected signature
+                   "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f304e000000000000000200000000000000000000000000000000000000000000000000000000000000010f64656c65676174696f6e5f706f6f6c087769746864726177000220db5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e0800ab90410000000014000000000000006400000000000000c32c7a6700000000010020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c403fc59908c985aca74008c862d8c6ff58556861c4583b4b1f91c674c4b6ddfa529ae2a9ad6ee62dfd20403e44b847feb2f5ae824c7c3b78c29c15cbeaa0883d0b", // Expected encoded transaction
+                   r#"{
+                    "expiration_timestamp_secs": "1736060099",
+                    "gas_unit_price": "100",
+                    "max_gas_amount": "20",
+                    "payload": {
+                        "arguments": ["0xdb5247f859ce63dbe8940cf8773be722a60dcc594a8be9aca4b76abceb251b8e", "1100000000"],
+                        "function": "0x1::delegation_pool::withdraw",
+                        "type": "entry_function_payload",
+                        "type_arguments": []
+                    },
+                    "sender": "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+                    "sequence_number": "78",
+                    "signature": {
+                        "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+                        "signature": "0x3fc59908c985aca74008c862d8c6ff58556861c4583b4b1f91c674c4b6ddfa529ae2a9ad6ee62dfd20403e44b847feb2f5ae824c7c3b78c29c15cbeaa0883d0b",
+                        "type": "ed25519_signature"
+                    }
+                }"#);
+}
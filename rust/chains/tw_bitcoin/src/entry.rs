@@ -3,6 +3,7 @@ use crate::modules::compiler::BitcoinCompiler;
 use crate::modules::planner::BitcoinPlanner;
 use crate::modules::signer::BitcoinSigner;
 use crate::modules::transaction_util::BitcoinTransactionUtil;
+use crate::modules::wallet_connect::connector::BitcoinWalletConnector;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
@@ -11,7 +12,6 @@ use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_keypair::tw::PublicKey;
 use tw_proto::BitcoinV2::Proto;
 use tw_utxo::address::standard_bitcoin::{StandardBitcoinAddress, StandardBitcoinPrefix};
@@ -29,7 +29,7 @@ impl CoinEntry for BitcoinEntry {
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = BitcoinPlanner<StandardBitcoinContext>;
     type MessageSigner = NoMessageSigner;
-    type WalletConnector = NoWalletConnector;
+    type WalletConnector = BitcoinWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = BitcoinTransactionUtil;
 
@@ -89,6 +89,11 @@ impl CoinEntry for BitcoinEntry {
         Some(BitcoinPlanner::<StandardBitcoinContext>::default())
     }
 
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(BitcoinWalletConnector)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(BitcoinTransactionUtil)
@@ -12,6 +12,7 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
 use tw_proto::BitcoinV2::Proto;
 use tw_proto::BitcoinV2::Proto::mod_PreSigningOutput::{
     SigningMethod as ProtoSigningMethod, TaprootTweak as ProtoTaprootTweak,
@@ -19,7 +20,7 @@ use tw_proto::BitcoinV2::Proto::mod_PreSigningOutput::{
 use tw_proto::BitcoinV2::Proto::mod_SigningInput::OneOftransaction as TransactionType;
 use tw_utxo::context::UtxoContext;
 use tw_utxo::encode::Encodable;
-use tw_utxo::modules::sighash_computer::{SighashComputer, TaprootTweak, TxPreimage};
+use tw_utxo::modules::sighash_computer::{SighashComputer, TaprootTweak, TxPreimage, UtxoSighash};
 use tw_utxo::modules::sighash_verifier::SighashVerifier;
 use tw_utxo::modules::tx_compiler::TxCompiler;
 use tw_utxo::modules::tx_planner::TxPlanner;
@@ -175,3 +176,37 @@ pub fn taproot_tweak(tweak: Option<TaprootTweak>) -> Option<ProtoTaprootTweak<'s
         }
     })
 }
+
+/// Converts a sighash returned by [`BitcoinCompiler::preimage_hashes`] back,
+/// so it can be signed by an external signer, e.g. via [`UtxoSighash::musig2_key_agg_ctx`].
+pub fn utxo_sighash_from_proto(
+    sighash: &Proto::mod_PreSigningOutput::Sighash,
+) -> SigningResult<UtxoSighash> {
+    let signing_method = match sighash.signing_method {
+        ProtoSigningMethod::Legacy => SigningMethod::Legacy,
+        ProtoSigningMethod::Segwit => SigningMethod::Segwit,
+        ProtoSigningMethod::Taproot => SigningMethod::Taproot,
+    };
+    let taproot_tweak = match sighash.tweak {
+        Some(ref tweak) if tweak.merkle_root.is_empty() => Some(TaprootTweak { merkle_root: None }),
+        Some(ref tweak) => {
+            let merkle_root = H256::try_from(tweak.merkle_root.as_ref())
+                .tw_err(SigningErrorType::Error_invalid_params)
+                .context("Expected exactly 32 bytes merkle root")?;
+            Some(TaprootTweak {
+                merkle_root: Some(merkle_root),
+            })
+        },
+        None => None,
+    };
+    let sighash_hash = H256::try_from(sighash.sighash.as_ref())
+        .tw_err(SigningErrorType::Error_invalid_params)
+        .context("Expected exactly 32 bytes sighash")?;
+
+    Ok(UtxoSighash {
+        signing_method,
+        sighash: sighash_hash,
+        signer_pubkey: sighash.public_key.to_vec(),
+        taproot_tweak,
+    })
+}
@@ -11,3 +11,4 @@ pub mod signer;
 pub mod signing_request;
 pub mod transaction_util;
 pub mod tx_builder;
+pub mod wallet_connect;
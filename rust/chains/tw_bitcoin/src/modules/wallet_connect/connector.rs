@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::wallet_connect::request::SignPsbtRequest;
+use bitcoin::psbt::Psbt;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct BitcoinWalletConnector;
+
+impl WalletConnector for BitcoinWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl BitcoinWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::BitcoinSignPsbt => Self::parse_sign_psbt_request(coin, request),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    /// Please note that `chain_info` is not set, so it's taken from the coin context on signing.
+    pub fn parse_sign_psbt_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let sign_request: SignPsbtRequest = serde_json::from_str(&request.payload)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error parsing WalletConnect signing request as JSON")?;
+        let psbt = sign_request.psbt.0;
+
+        // Fail early if the PSBT is malformed.
+        Psbt::deserialize(&psbt)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error deserializing PSBT")?;
+
+        let signing_input = Proto::SigningInput {
+            transaction: Proto::mod_SigningInput::OneOftransaction::psbt(Proto::Psbt {
+                psbt: psbt.into(),
+            }),
+            ..Proto::SigningInput::default()
+        };
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::bitcoin(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use tw_encoding::base64::Base64Encoded;
+
+/// `signPsbt` params.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/bitcoin-rpc#signpsbt
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignPsbtRequest {
+    /// Base64 encoded Partially Signed Bitcoin Transaction.
+    pub psbt: Base64Encoded,
+    pub account: Option<String>,
+    #[serde(default)]
+    pub broadcast: bool,
+}
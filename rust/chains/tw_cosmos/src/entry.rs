@@ -11,12 +11,12 @@ use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_cosmos_sdk::address::{Address, Bech32Prefix};
 use tw_cosmos_sdk::context::StandardCosmosContext;
 use tw_cosmos_sdk::modules::compiler::tw_compiler::TWTransactionCompiler;
 use tw_cosmos_sdk::modules::signer::tw_signer::TWSigner;
 use tw_cosmos_sdk::modules::transaction_util::CosmosTransactionUtil;
+use tw_cosmos_sdk::modules::wallet_connect::connector::CosmosWalletConnector;
 use tw_keypair::tw;
 use tw_proto::Cosmos::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -34,7 +34,7 @@ impl CoinEntry for CosmosEntry {
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
-    type WalletConnector = NoWalletConnector;
+    type WalletConnector = CosmosWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = CosmosTransactionUtil<StandardCosmosContext>;
 
@@ -94,6 +94,11 @@ impl CoinEntry for CosmosEntry {
         )
     }
 
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(CosmosWalletConnector)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(CosmosTransactionUtil::<StandardCosmosContext>::default())
@@ -10,7 +10,6 @@ use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_evm::address::Address;
 use tw_evm::evm_context::StandardEvmContext;
@@ -19,6 +18,7 @@ use tw_evm::modules::compiler::Compiler;
 use tw_evm::modules::message_signer::EthMessageSigner;
 use tw_evm::modules::signer::Signer;
 use tw_evm::modules::transaction_util::EvmTransactionUtil;
+use tw_evm::modules::wallet_connect::connector::EvmWalletConnector;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Ethereum::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -36,7 +36,7 @@ impl CoinEntry for EthereumEntry {
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = EthMessageSigner;
-    type WalletConnector = NoWalletConnector;
+    type WalletConnector = EvmWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = EvmTransactionUtil;
 
@@ -99,6 +99,11 @@ impl CoinEntry for EthereumEntry {
         Some(EthMessageSigner)
     }
 
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(EvmWalletConnector)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(EvmTransactionUtil)
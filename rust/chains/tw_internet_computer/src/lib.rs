@@ -5,6 +5,7 @@
 pub mod address;
 pub mod context;
 pub mod entry;
+pub mod modules;
 pub mod protocol;
 pub mod signer;
 pub mod transactions;
@@ -0,0 +1,394 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::protocol::principal::Principal;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_candid::{CandidType, CandidValue, Label, MAX_DEPTH};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_proto::InternetComputer::Proto;
+use Proto::mod_CandidType::{OneOftype_oneof as TypeEnum, Primitive};
+use Proto::mod_CandidValue::OneOfvalue_oneof as ValueEnum;
+
+/// Encodes and decodes Candid arguments represented as [`Proto::CandidValue`].
+pub struct CandidCodec;
+
+impl CandidCodec {
+    pub fn encode(input: Proto::CandidEncodingInput<'_>) -> Proto::CandidEncodingOutput<'static> {
+        Self::encode_impl(input)
+            .unwrap_or_else(|err| signing_output_error!(Proto::CandidEncodingOutput, err))
+    }
+
+    pub fn decode(input: Proto::CandidDecodingInput<'_>) -> Proto::CandidDecodingOutput<'static> {
+        Self::decode_impl(input)
+            .unwrap_or_else(|err| signing_output_error!(Proto::CandidDecodingOutput, err))
+    }
+
+    fn encode_impl(
+        input: Proto::CandidEncodingInput<'_>,
+    ) -> SigningResult<Proto::CandidEncodingOutput<'static>> {
+        let args = input
+            .args
+            .into_iter()
+            .map(|arg| Self::value_from_proto(0, arg))
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let encoded = tw_candid::encode_args(&args)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Error encoding Candid arguments")?;
+        Ok(Proto::CandidEncodingOutput {
+            encoded: Cow::from(encoded),
+            ..Proto::CandidEncodingOutput::default()
+        })
+    }
+
+    fn decode_impl(
+        input: Proto::CandidDecodingInput<'_>,
+    ) -> SigningResult<Proto::CandidDecodingOutput<'static>> {
+        let args = tw_candid::decode_args(&input.encoded)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error decoding Candid arguments")?;
+
+        let args = args
+            .into_iter()
+            .map(Self::value_to_proto)
+            .collect::<SigningResult<Vec<_>>>()?;
+        Ok(Proto::CandidDecodingOutput {
+            args,
+            ..Proto::CandidDecodingOutput::default()
+        })
+    }
+
+    fn check_depth(depth: usize) -> SigningResult<()> {
+        if depth >= MAX_DEPTH {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("Allowed composite types with the {MAX_DEPTH} maximum depth")
+            });
+        }
+        Ok(())
+    }
+
+    fn label_from_proto(name: &str, id: u32) -> Label {
+        if name.is_empty() {
+            Label::Id(id)
+        } else {
+            Label::from(name)
+        }
+    }
+
+    fn label_to_proto(label: &Label) -> (Cow<'static, str>, u32) {
+        match label {
+            Label::Named(name) => (Cow::from(name.clone()), label.id()),
+            Label::Id(id) => (Cow::default(), *id),
+        }
+    }
+
+    fn type_from_proto(depth: usize, ty: Proto::CandidType<'_>) -> SigningResult<CandidType> {
+        Self::check_depth(depth)?;
+
+        match ty.type_oneof {
+            TypeEnum::primitive(primitive) => Ok(Self::primitive_from_proto(primitive)),
+            TypeEnum::opt(opt) => {
+                let inner_type = opt
+                    .inner_type
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Missing 'opt' inner type")?;
+                Ok(CandidType::opt(Self::type_from_proto(
+                    depth + 1,
+                    *inner_type,
+                )?))
+            },
+            TypeEnum::vec(vec) => {
+                let element_type = vec
+                    .element_type
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Missing 'vec' element type")?;
+                Ok(CandidType::vec(Self::type_from_proto(
+                    depth + 1,
+                    *element_type,
+                )?))
+            },
+            TypeEnum::record(record) => Ok(CandidType::Record(Self::fields_from_proto(
+                depth + 1,
+                record.fields,
+            )?)),
+            TypeEnum::variant(variant) => Ok(CandidType::Variant(Self::fields_from_proto(
+                depth + 1,
+                variant.fields,
+            )?)),
+            TypeEnum::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Missing Candid type"),
+        }
+    }
+
+    fn fields_from_proto(
+        depth: usize,
+        fields: Vec<Proto::mod_CandidType::Field<'_>>,
+    ) -> SigningResult<Vec<(Label, CandidType)>> {
+        fields
+            .into_iter()
+            .map(|field| {
+                let label = Self::label_from_proto(&field.name, field.id);
+                let field_type = field
+                    .field_type
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Missing '{label}' field type"))?;
+                Ok((label, Self::type_from_proto(depth, field_type)?))
+            })
+            .collect()
+    }
+
+    fn primitive_from_proto(primitive: Primitive) -> CandidType {
+        match primitive {
+            Primitive::Null => CandidType::Null,
+            Primitive::Bool => CandidType::Bool,
+            Primitive::Nat => CandidType::Nat,
+            Primitive::Int => CandidType::Int,
+            Primitive::Nat8 => CandidType::Nat8,
+            Primitive::Nat16 => CandidType::Nat16,
+            Primitive::Nat32 => CandidType::Nat32,
+            Primitive::Nat64 => CandidType::Nat64,
+            Primitive::Int8 => CandidType::Int8,
+            Primitive::Int16 => CandidType::Int16,
+            Primitive::Int32 => CandidType::Int32,
+            Primitive::Int64 => CandidType::Int64,
+            Primitive::Float32 => CandidType::Float32,
+            Primitive::Float64 => CandidType::Float64,
+            Primitive::Text => CandidType::Text,
+            Primitive::Reserved => CandidType::Reserved,
+            Primitive::Empty => CandidType::Empty,
+            Primitive::Principal => CandidType::Principal,
+        }
+    }
+
+    fn type_to_proto(ty: &CandidType) -> Proto::CandidType<'static> {
+        let primitive = TypeEnum::primitive;
+        let type_oneof = match ty {
+            CandidType::Null => primitive(Primitive::Null),
+            CandidType::Bool => primitive(Primitive::Bool),
+            CandidType::Nat => primitive(Primitive::Nat),
+            CandidType::Int => primitive(Primitive::Int),
+            CandidType::Nat8 => primitive(Primitive::Nat8),
+            CandidType::Nat16 => primitive(Primitive::Nat16),
+            CandidType::Nat32 => primitive(Primitive::Nat32),
+            CandidType::Nat64 => primitive(Primitive::Nat64),
+            CandidType::Int8 => primitive(Primitive::Int8),
+            CandidType::Int16 => primitive(Primitive::Int16),
+            CandidType::Int32 => primitive(Primitive::Int32),
+            CandidType::Int64 => primitive(Primitive::Int64),
+            CandidType::Float32 => primitive(Primitive::Float32),
+            CandidType::Float64 => primitive(Primitive::Float64),
+            CandidType::Text => primitive(Primitive::Text),
+            CandidType::Reserved => primitive(Primitive::Reserved),
+            CandidType::Empty => primitive(Primitive::Empty),
+            CandidType::Principal => primitive(Primitive::Principal),
+            CandidType::Opt(inner) => TypeEnum::opt(Box::new(Proto::mod_CandidType::OptType {
+                inner_type: Some(Box::new(Self::type_to_proto(inner))),
+            })),
+            CandidType::Vec(element) => TypeEnum::vec(Box::new(Proto::mod_CandidType::VecType {
+                element_type: Some(Box::new(Self::type_to_proto(element))),
+            })),
+            CandidType::Record(fields) => TypeEnum::record(Self::fields_to_proto(fields)),
+            CandidType::Variant(fields) => TypeEnum::variant(Self::fields_to_proto(fields)),
+        };
+        Proto::CandidType { type_oneof }
+    }
+
+    fn fields_to_proto(fields: &[(Label, CandidType)]) -> Proto::mod_CandidType::Fields<'static> {
+        let fields = fields
+            .iter()
+            .map(|(label, field_type)| {
+                let (name, id) = Self::label_to_proto(label);
+                Proto::mod_CandidType::Field {
+                    name,
+                    id,
+                    field_type: Some(Self::type_to_proto(field_type)),
+                }
+            })
+            .collect();
+        Proto::mod_CandidType::Fields { fields }
+    }
+
+    fn value_from_proto(depth: usize, value: Proto::CandidValue<'_>) -> SigningResult<CandidValue> {
+        Self::check_depth(depth)?;
+
+        let value = match value.value_oneof {
+            ValueEnum::null_value(_) => CandidValue::Null,
+            ValueEnum::bool_value(value) => CandidValue::Bool(value),
+            ValueEnum::nat_value(value) => CandidValue::Nat(
+                u128::from_str(&value)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Invalid 'nat' value")?,
+            ),
+            ValueEnum::int_value(value) => CandidValue::Int(
+                i128::from_str(&value)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Invalid 'int' value")?,
+            ),
+            ValueEnum::nat8_value(value) => CandidValue::Nat8(Self::narrow(value, "nat8")?),
+            ValueEnum::nat16_value(value) => CandidValue::Nat16(Self::narrow(value, "nat16")?),
+            ValueEnum::nat32_value(value) => CandidValue::Nat32(value),
+            ValueEnum::nat64_value(value) => CandidValue::Nat64(value),
+            ValueEnum::int8_value(value) => CandidValue::Int8(Self::narrow(value, "int8")?),
+            ValueEnum::int16_value(value) => CandidValue::Int16(Self::narrow(value, "int16")?),
+            ValueEnum::int32_value(value) => CandidValue::Int32(value),
+            ValueEnum::int64_value(value) => CandidValue::Int64(value),
+            ValueEnum::float32_value(value) => CandidValue::Float32(value),
+            ValueEnum::float64_value(value) => CandidValue::Float64(value),
+            ValueEnum::text_value(text) => CandidValue::text(text),
+            ValueEnum::reserved_value(_) => CandidValue::Reserved,
+            ValueEnum::principal_value(principal) => {
+                let principal = Principal::from_str(&principal)
+                    .tw_err(SigningErrorType::Error_invalid_address)
+                    .context("Invalid principal")?;
+                CandidValue::Principal(principal.as_slice().to_vec())
+            },
+            ValueEnum::opt_value(opt) => {
+                let inner_type = opt
+                    .inner_type
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Missing 'opt' inner type")?;
+                let inner_type = Self::type_from_proto(depth + 1, inner_type)?;
+                let inner = opt
+                    .value
+                    .map(|inner| Self::value_from_proto(depth + 1, *inner))
+                    .transpose()?;
+                CandidValue::opt(inner_type, inner)
+            },
+            ValueEnum::vec_value(vec) => {
+                let element_type = vec
+                    .element_type
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Missing 'vec' element type")?;
+                let element_type = Self::type_from_proto(depth + 1, element_type)?;
+                let elements = vec
+                    .elements
+                    .into_iter()
+                    .map(|element| Self::value_from_proto(depth + 1, element))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                CandidValue::Vec(element_type, elements)
+            },
+            ValueEnum::blob_value(bytes) => CandidValue::Blob(bytes.to_vec()),
+            ValueEnum::record_value(record) => {
+                let fields = record
+                    .fields
+                    .into_iter()
+                    .map(|field| {
+                        let label = Self::label_from_proto(&field.name, field.id);
+                        let value = field
+                            .value
+                            .or_tw_err(SigningErrorType::Error_invalid_params)
+                            .with_context(|| format!("Missing '{label}' field value"))?;
+                        Ok((label, Self::value_from_proto(depth + 1, value)?))
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+                CandidValue::Record(fields)
+            },
+            ValueEnum::variant_value(variant) => {
+                let alternatives = Self::fields_from_proto(depth + 1, variant.alternatives)?;
+                let label = Self::label_from_proto(&variant.name, variant.id);
+                let value = variant
+                    .value
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Missing '{label}' variant value"))?;
+                CandidValue::Variant {
+                    alternatives,
+                    label,
+                    value: Box::new(Self::value_from_proto(depth + 1, *value)?),
+                }
+            },
+            ValueEnum::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Missing Candid value")
+            },
+        };
+        Ok(value)
+    }
+
+    fn value_to_proto(value: CandidValue) -> SigningResult<Proto::CandidValue<'static>> {
+        let value_oneof = match value {
+            CandidValue::Null => ValueEnum::null_value(true),
+            CandidValue::Bool(value) => ValueEnum::bool_value(value),
+            CandidValue::Nat(value) => ValueEnum::nat_value(Cow::from(value.to_string())),
+            CandidValue::Int(value) => ValueEnum::int_value(Cow::from(value.to_string())),
+            CandidValue::Nat8(value) => ValueEnum::nat8_value(value as u32),
+            CandidValue::Nat16(value) => ValueEnum::nat16_value(value as u32),
+            CandidValue::Nat32(value) => ValueEnum::nat32_value(value),
+            CandidValue::Nat64(value) => ValueEnum::nat64_value(value),
+            CandidValue::Int8(value) => ValueEnum::int8_value(value as i32),
+            CandidValue::Int16(value) => ValueEnum::int16_value(value as i32),
+            CandidValue::Int32(value) => ValueEnum::int32_value(value),
+            CandidValue::Int64(value) => ValueEnum::int64_value(value),
+            CandidValue::Float32(value) => ValueEnum::float32_value(value),
+            CandidValue::Float64(value) => ValueEnum::float64_value(value),
+            CandidValue::Text(text) => ValueEnum::text_value(Cow::from(text)),
+            CandidValue::Reserved => ValueEnum::reserved_value(true),
+            CandidValue::Principal(bytes) => {
+                let principal = Principal::try_from_slice(&bytes)
+                    .tw_err(SigningErrorType::Error_input_parse)
+                    .context("Invalid principal")?;
+                ValueEnum::principal_value(Cow::from(principal.to_text()))
+            },
+            CandidValue::Opt(inner_type, inner) => {
+                let value = inner
+                    .map(|inner| Self::value_to_proto(*inner).map(Box::new))
+                    .transpose()?;
+                ValueEnum::opt_value(Box::new(Proto::mod_CandidValue::OptValue {
+                    inner_type: Some(Self::type_to_proto(&inner_type)),
+                    value,
+                }))
+            },
+            CandidValue::Vec(element_type, elements) => {
+                let elements = elements
+                    .into_iter()
+                    .map(Self::value_to_proto)
+                    .collect::<SigningResult<Vec<_>>>()?;
+                ValueEnum::vec_value(Proto::mod_CandidValue::VecValue {
+                    element_type: Some(Self::type_to_proto(&element_type)),
+                    elements,
+                })
+            },
+            CandidValue::Blob(bytes) => ValueEnum::blob_value(Cow::from(bytes)),
+            CandidValue::Record(fields) => {
+                let fields = fields
+                    .into_iter()
+                    .map(|(label, value)| {
+                        let (name, id) = Self::label_to_proto(&label);
+                        Ok(Proto::mod_CandidValue::Field {
+                            name,
+                            id,
+                            value: Some(Self::value_to_proto(value)?),
+                        })
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+                ValueEnum::record_value(Proto::mod_CandidValue::RecordValue { fields })
+            },
+            CandidValue::Variant {
+                alternatives,
+                label,
+                value,
+            } => {
+                let (name, id) = Self::label_to_proto(&label);
+                ValueEnum::variant_value(Box::new(Proto::mod_CandidValue::VariantValue {
+                    alternatives: Self::fields_to_proto(&alternatives).fields,
+                    name,
+                    id,
+                    value: Some(Box::new(Self::value_to_proto(*value)?)),
+                }))
+            },
+        };
+        Ok(Proto::CandidValue { value_oneof })
+    }
+
+    fn narrow<T, U>(value: T, type_name: &str) -> SigningResult<U>
+    where
+        U: TryFrom<T>,
+        <U as TryFrom<T>>::Error: std::fmt::Debug,
+    {
+        U::try_from(value)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("'{type_name}' value is out of range"))
+    }
+}
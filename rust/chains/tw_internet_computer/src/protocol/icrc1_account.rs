@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+
+use tw_candid::CandidValue;
+use tw_encoding::hex;
+use tw_hash::crc32::crc32;
+
+use super::principal::{Principal, PrincipalError};
+
+pub const SUBACCOUNT_LEN: usize = 32;
+
+pub type Subaccount = [u8; SUBACCOUNT_LEN];
+
+/// An error happened while parsing the textual encoding of an [`Icrc1Account`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Icrc1AccountError {
+    InvalidPrincipal(PrincipalError),
+    MissingChecksum,
+    InvalidChecksum,
+    InvalidSubaccount,
+    NotCanonical,
+}
+
+/// ICRC-1 account that consists of an owner principal and an optional subaccount.
+/// See the specification: https://github.com/dfinity/ICRC-1/blob/main/standards/ICRC-1/TextualEncoding.md
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Icrc1Account {
+    pub owner: Principal,
+    pub subaccount: Option<Subaccount>,
+}
+
+impl Icrc1Account {
+    pub fn new(owner: Principal, subaccount: Option<Subaccount>) -> Self {
+        Icrc1Account { owner, subaccount }
+    }
+
+    /// Returns the subaccount unless it's the default (all-zero) one.
+    pub fn effective_subaccount(&self) -> Option<&Subaccount> {
+        self.subaccount
+            .as_ref()
+            .filter(|subaccount| **subaccount != [0; SUBACCOUNT_LEN])
+    }
+
+    /// Candid `record { owner : principal; subaccount : opt blob }`.
+    pub fn to_candid(&self) -> CandidValue {
+        let subaccount = self.effective_subaccount().map(|s| s.as_slice());
+        CandidValue::record([
+            (
+                "owner",
+                CandidValue::Principal(self.owner.as_slice().to_vec()),
+            ),
+            ("subaccount", CandidValue::opt_blob(subaccount)),
+        ])
+    }
+
+    /// CRC32 of the owner and subaccount bytes encoded as lowercase base32 without padding.
+    fn checksum(owner: &Principal, subaccount: &Subaccount) -> String {
+        let mut bytes = owner.as_slice().to_vec();
+        bytes.extend_from_slice(subaccount);
+        let checksum = crc32(&bytes).to_be_bytes();
+        tw_encoding::base32::encode(&checksum, None, false)
+            .expect("Default alphabet is always valid")
+            .to_ascii_lowercase()
+    }
+}
+
+impl fmt::Display for Icrc1Account {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.effective_subaccount() {
+            Some(subaccount) => {
+                let subaccount_hex = hex::encode(subaccount, false);
+                write!(
+                    f,
+                    "{}-{}.{}",
+                    self.owner,
+                    Self::checksum(&self.owner, subaccount),
+                    subaccount_hex.trim_start_matches('0')
+                )
+            },
+            None => write!(f, "{}", self.owner),
+        }
+    }
+}
+
+impl FromStr for Icrc1Account {
+    type Err = Icrc1AccountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((owner_and_checksum, subaccount_hex)) = s.rsplit_once('.') else {
+            let owner = Principal::from_text(s).map_err(Icrc1AccountError::InvalidPrincipal)?;
+            return Ok(Icrc1Account::new(owner, None));
+        };
+
+        let (owner_text, checksum) = owner_and_checksum
+            .rsplit_once('-')
+            .ok_or(Icrc1AccountError::MissingChecksum)?;
+        let owner =
+            Principal::from_text(owner_text).map_err(Icrc1AccountError::InvalidPrincipal)?;
+
+        // The subaccount must be encoded without leading zeros, and the default subaccount must be omitted.
+        if subaccount_hex.is_empty() || subaccount_hex.starts_with('0') {
+            return Err(Icrc1AccountError::NotCanonical);
+        }
+        if subaccount_hex.len() > SUBACCOUNT_LEN * 2 {
+            return Err(Icrc1AccountError::InvalidSubaccount);
+        }
+        let padded_hex = format!("{subaccount_hex:0>64}");
+        let subaccount_bytes =
+            hex::decode(&padded_hex).map_err(|_| Icrc1AccountError::InvalidSubaccount)?;
+        let subaccount = Subaccount::try_from(subaccount_bytes)
+            .map_err(|_| Icrc1AccountError::InvalidSubaccount)?;
+
+        if Self::checksum(&owner, &subaccount) != checksum {
+            return Err(Icrc1AccountError::InvalidChecksum);
+        }
+        Ok(Icrc1Account::new(owner, Some(subaccount)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: &str = "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae";
+
+    #[test]
+    fn test_default_subaccount_to_string() {
+        let owner = Principal::from_text(OWNER).unwrap();
+        assert_eq!(Icrc1Account::new(owner, None).to_string(), OWNER);
+        assert_eq!(
+            Icrc1Account::new(owner, Some([0; SUBACCOUNT_LEN])).to_string(),
+            OWNER
+        );
+    }
+
+    #[test]
+    fn test_subaccount_to_string() {
+        let owner = Principal::from_text(OWNER).unwrap();
+
+        let mut subaccount = [0; SUBACCOUNT_LEN];
+        subaccount[SUBACCOUNT_LEN - 1] = 1;
+        assert_eq!(
+            Icrc1Account::new(owner, Some(subaccount)).to_string(),
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1"
+        );
+
+        let subaccount: Subaccount = core::array::from_fn(|i| i as u8 + 1);
+        assert_eq!(
+            Icrc1Account::new(owner, Some(subaccount)).to_string(),
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-dfxgiyy.102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+        );
+    }
+
+    #[test]
+    fn test_from_str_roundtrip() {
+        let inputs = [
+            OWNER,
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1",
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-dfxgiyy.102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+        ];
+        for input in inputs {
+            let account = Icrc1Account::from_str(input).unwrap();
+            assert_eq!(account.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        let invalid = [
+            (
+                "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627j.1",
+                Icrc1AccountError::InvalidChecksum,
+            ),
+            (
+                "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.01",
+                Icrc1AccountError::NotCanonical,
+            ),
+            ("k2t6j.1", Icrc1AccountError::MissingChecksum),
+            (
+                "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1xyz",
+                Icrc1AccountError::InvalidSubaccount,
+            ),
+        ];
+        for (input, expected) in invalid {
+            assert_eq!(Icrc1Account::from_str(input), Err(expected), "{input}");
+        }
+    }
+}
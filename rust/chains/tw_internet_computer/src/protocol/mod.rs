@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod envelope;
+pub mod icrc1_account;
 pub mod identity;
 pub mod principal;
 pub mod request_id;
@@ -17,7 +17,8 @@ use crate::{
 impl From<transactions::SignTransactionError> for SigningError {
     fn from(error: transactions::SignTransactionError) -> Self {
         match error {
-            transactions::SignTransactionError::InvalidArguments => {
+            transactions::SignTransactionError::InvalidArguments
+            | transactions::SignTransactionError::InvalidCanisterId => {
                 SigningError::new(CommonError::Error_invalid_params)
             },
             transactions::SignTransactionError::Identity(identity_error) => match identity_error {
@@ -27,7 +28,8 @@ impl From<transactions::SignTransactionError> for SigningError {
             | transactions::SignTransactionError::EncodingArgsFailed => {
                 SigningError::new(CommonError::Error_internal)
             },
-            transactions::SignTransactionError::InvalidToAccountIdentifier => {
+            transactions::SignTransactionError::InvalidToAccountIdentifier
+            | transactions::SignTransactionError::InvalidAccount => {
                 SigningError::new(CommonError::Error_invalid_address)
             },
             transactions::SignTransactionError::InvalidAmount => {
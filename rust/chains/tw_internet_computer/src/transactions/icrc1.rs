@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_candid::{encode_args, CandidValue};
+use tw_keypair::ecdsa::secp256k1::PrivateKey;
+
+use crate::protocol::{
+    icrc1_account::{Icrc1Account, Subaccount},
+    principal::Principal,
+    rosetta,
+};
+
+use super::{
+    call::{call, CallArgs},
+    SignTransactionError,
+};
+
+/// The endpoint on an ICRC-1 ledger canister that is used to make transfers.
+const METHOD_NAME: &str = "icrc1_transfer";
+
+/// The maximum memo length accepted by ICRC ledgers by default.
+pub(crate) const MAX_MEMO_LEN: usize = 32;
+
+/// Arguments to be used with [icrc1_transfer] to create a signed transaction envelope pair.
+#[derive(Clone, Debug)]
+pub struct Icrc1TransferArgs {
+    /// The subaccount of the sender. The default subaccount is used if not provided.
+    pub from_subaccount: Option<Subaccount>,
+    /// The account to send the amount to.
+    pub to: Icrc1Account,
+    /// The amount of tokens to send in the smallest units.
+    pub amount: u64,
+    /// The fee expected by the ledger. If not provided, the ledger charges its current fee.
+    pub fee: Option<u64>,
+    /// An arbitrary memo up to 32 bytes.
+    pub memo: Option<Vec<u8>>,
+    /// The current timestamp in nanoseconds. It's also used as `created_at_time` to deduplicate transfers.
+    pub current_timestamp_nanos: u64,
+    /// The duration to tune up ingress expiry in seconds.
+    pub permitted_drift: Option<u64>,
+}
+
+impl Icrc1TransferArgs {
+    /// Candid-encoded `TransferArg` record.
+    pub fn encode_candid(&self) -> Result<Vec<u8>, SignTransactionError> {
+        encode_args(&[CandidValue::record([
+            (
+                "from_subaccount",
+                CandidValue::opt_blob(self.from_subaccount.as_ref().map(|s| s.as_slice())),
+            ),
+            ("to", self.to.to_candid()),
+            ("amount", CandidValue::Nat(self.amount as u128)),
+            ("fee", CandidValue::opt_nat(self.fee.map(u128::from))),
+            ("memo", CandidValue::opt_blob(self.memo.as_deref())),
+            (
+                "created_at_time",
+                CandidValue::opt_nat64(Some(self.current_timestamp_nanos)),
+            ),
+        ])])
+        .map_err(|_| SignTransactionError::EncodingArgsFailed)
+    }
+}
+
+/// Given a secp256k1 private key, the canister ID of an ICRC-1 ledger canister, and the actual transfer args,
+/// this function creates a signed `icrc1_transfer` call.
+pub fn icrc1_transfer(
+    private_key: PrivateKey,
+    canister_id: Principal,
+    args: Icrc1TransferArgs,
+) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+    if args.amount < 1 {
+        return Err(SignTransactionError::InvalidAmount);
+    }
+    validate_memo(args.memo.as_deref())?;
+
+    call(
+        private_key,
+        CallArgs {
+            canister_id,
+            method_name: METHOD_NAME.to_string(),
+            arg: args.encode_candid()?,
+            current_timestamp_nanos: args.current_timestamp_nanos,
+            permitted_drift: args.permitted_drift,
+        },
+    )
+}
+
+pub(crate) fn validate_memo(memo: Option<&[u8]>) -> Result<(), SignTransactionError> {
+    match memo {
+        Some(memo) if memo.len() > MAX_MEMO_LEN => Err(SignTransactionError::InvalidArguments),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tw_encoding::hex;
+
+    use super::*;
+
+    fn make_transfer_args() -> Icrc1TransferArgs {
+        let to = Icrc1Account::from_str(
+            "t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe",
+        )
+        .unwrap();
+
+        Icrc1TransferArgs {
+            from_subaccount: None,
+            to,
+            amount: 100_000_000,
+            fee: None,
+            memo: None,
+            current_timestamp_nanos: Duration::from_secs(1_691_709_940).as_nanos() as u64,
+            permitted_drift: None,
+        }
+    }
+
+    #[test]
+    fn test_encode_transfer_args() {
+        let args = make_transfer_args();
+        assert_eq!(
+            hex::encode(args.encode_candid().unwrap(), false),
+            "4449444c066c06fbca0101c6fcb60204ba89e5c20402a2de94eb060282f3f3910c05d8a38ca80d7d6c02b3b0dac30368ad86ca8305026e036d7b6e7d6e780100011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e487959370200000000010088b2343a297a1780c2d72f"
+        );
+    }
+
+    #[test]
+    fn test_encode_transfer_args_with_optional_fields() {
+        let mut args = make_transfer_args();
+        args.from_subaccount = Some([1; 32]);
+        args.to.subaccount = Some(core::array::from_fn(|i| i as u8));
+        args.fee = Some(10_000);
+        args.memo = Some(b"memo".to_vec());
+        assert_eq!(
+            hex::encode(args.encode_candid().unwrap(), false),
+            "4449444c066c06fbca0101c6fcb60204ba89e5c20402a2de94eb060282f3f3910c05d8a38ca80d7d6c02b3b0dac30368ad86ca8305026e036d7b6e7d6e780100011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e48795937020120000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f01904e01046d656d6f01200101010101010101010101010101010101010101010101010101010101010101010088b2343a297a1780c2d72f"
+        );
+    }
+
+    #[test]
+    fn test_icrc1_transfer_invalid_amount() {
+        let private_key = PrivateKey::try_from(
+            "227102911bb99ce7285a55f952800912b7d22ebeeeee59d77fc33a5d7c7080be",
+        )
+        .unwrap();
+        let canister_id = Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap();
+        let mut args = make_transfer_args();
+        args.amount = 0;
+
+        let result = icrc1_transfer(private_key, canister_id, args);
+        assert!(matches!(result, Err(SignTransactionError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_icrc1_transfer_memo_too_long() {
+        let private_key = PrivateKey::try_from(
+            "227102911bb99ce7285a55f952800912b7d22ebeeeee59d77fc33a5d7c7080be",
+        )
+        .unwrap();
+        let canister_id = Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap();
+        let mut args = make_transfer_args();
+        args.memo = Some(vec![0; MAX_MEMO_LEN + 1]);
+
+        let result = icrc1_transfer(private_key, canister_id, args);
+        assert!(matches!(
+            result,
+            Err(SignTransactionError::InvalidArguments)
+        ));
+    }
+}
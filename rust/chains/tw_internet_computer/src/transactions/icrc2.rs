@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_candid::{encode_args, CandidValue};
+use tw_keypair::ecdsa::secp256k1::PrivateKey;
+
+use crate::protocol::{
+    icrc1_account::{Icrc1Account, Subaccount},
+    principal::Principal,
+    rosetta,
+};
+
+use super::{
+    call::{call, CallArgs},
+    icrc1::validate_memo,
+    SignTransactionError,
+};
+
+/// The endpoint on an ICRC-2 ledger canister that is used to approve spending.
+const APPROVE_METHOD_NAME: &str = "icrc2_approve";
+/// The endpoint on an ICRC-2 ledger canister that is used to transfer approved tokens.
+const TRANSFER_FROM_METHOD_NAME: &str = "icrc2_transfer_from";
+
+/// Arguments to be used with [icrc2_approve] to create a signed transaction envelope pair.
+#[derive(Clone, Debug)]
+pub struct Icrc2ApproveArgs {
+    /// The subaccount of the approver. The default subaccount is used if not provided.
+    pub from_subaccount: Option<Subaccount>,
+    /// The account allowed to spend the tokens.
+    pub spender: Icrc1Account,
+    /// The new allowance in the smallest units.
+    pub amount: u64,
+    /// If provided, the ledger rejects the approval unless the current allowance matches the value.
+    pub expected_allowance: Option<u64>,
+    /// The timestamp in nanoseconds when the allowance expires.
+    pub expires_at: Option<u64>,
+    /// The fee expected by the ledger. If not provided, the ledger charges its current fee.
+    pub fee: Option<u64>,
+    /// An arbitrary memo up to 32 bytes.
+    pub memo: Option<Vec<u8>>,
+    /// The current timestamp in nanoseconds. It's also used as `created_at_time` to deduplicate approvals.
+    pub current_timestamp_nanos: u64,
+    /// The duration to tune up ingress expiry in seconds.
+    pub permitted_drift: Option<u64>,
+}
+
+impl Icrc2ApproveArgs {
+    /// Candid-encoded `ApproveArgs` record.
+    pub fn encode_candid(&self) -> Result<Vec<u8>, SignTransactionError> {
+        encode_args(&[CandidValue::record([
+            (
+                "from_subaccount",
+                CandidValue::opt_blob(self.from_subaccount.as_ref().map(|s| s.as_slice())),
+            ),
+            ("spender", self.spender.to_candid()),
+            ("amount", CandidValue::Nat(self.amount as u128)),
+            (
+                "expected_allowance",
+                CandidValue::opt_nat(self.expected_allowance.map(u128::from)),
+            ),
+            ("expires_at", CandidValue::opt_nat64(self.expires_at)),
+            ("fee", CandidValue::opt_nat(self.fee.map(u128::from))),
+            ("memo", CandidValue::opt_blob(self.memo.as_deref())),
+            (
+                "created_at_time",
+                CandidValue::opt_nat64(Some(self.current_timestamp_nanos)),
+            ),
+        ])])
+        .map_err(|_| SignTransactionError::EncodingArgsFailed)
+    }
+}
+
+/// Arguments to be used with [icrc2_transfer_from] to create a signed transaction envelope pair.
+#[derive(Clone, Debug)]
+pub struct Icrc2TransferFromArgs {
+    /// The subaccount of the spender. The default subaccount is used if not provided.
+    pub spender_subaccount: Option<Subaccount>,
+    /// The account to withdraw the amount from.
+    pub from: Icrc1Account,
+    /// The account to send the amount to.
+    pub to: Icrc1Account,
+    /// The amount of tokens to send in the smallest units.
+    pub amount: u64,
+    /// The fee expected by the ledger. If not provided, the ledger charges its current fee.
+    pub fee: Option<u64>,
+    /// An arbitrary memo up to 32 bytes.
+    pub memo: Option<Vec<u8>>,
+    /// The current timestamp in nanoseconds. It's also used as `created_at_time` to deduplicate transfers.
+    pub current_timestamp_nanos: u64,
+    /// The duration to tune up ingress expiry in seconds.
+    pub permitted_drift: Option<u64>,
+}
+
+impl Icrc2TransferFromArgs {
+    /// Candid-encoded `TransferFromArgs` record.
+    pub fn encode_candid(&self) -> Result<Vec<u8>, SignTransactionError> {
+        encode_args(&[CandidValue::record([
+            (
+                "spender_subaccount",
+                CandidValue::opt_blob(self.spender_subaccount.as_ref().map(|s| s.as_slice())),
+            ),
+            ("from", self.from.to_candid()),
+            ("to", self.to.to_candid()),
+            ("amount", CandidValue::Nat(self.amount as u128)),
+            ("fee", CandidValue::opt_nat(self.fee.map(u128::from))),
+            ("memo", CandidValue::opt_blob(self.memo.as_deref())),
+            (
+                "created_at_time",
+                CandidValue::opt_nat64(Some(self.current_timestamp_nanos)),
+            ),
+        ])])
+        .map_err(|_| SignTransactionError::EncodingArgsFailed)
+    }
+}
+
+/// Given a secp256k1 private key, the canister ID of an ICRC-2 ledger canister, and the approval args,
+/// this function creates a signed `icrc2_approve` call.
+/// Note that zero amount is allowed to revoke the allowance.
+pub fn icrc2_approve(
+    private_key: PrivateKey,
+    canister_id: Principal,
+    args: Icrc2ApproveArgs,
+) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+    validate_memo(args.memo.as_deref())?;
+
+    call(
+        private_key,
+        CallArgs {
+            canister_id,
+            method_name: APPROVE_METHOD_NAME.to_string(),
+            arg: args.encode_candid()?,
+            current_timestamp_nanos: args.current_timestamp_nanos,
+            permitted_drift: args.permitted_drift,
+        },
+    )
+}
+
+/// Given a secp256k1 private key of the spender, the canister ID of an ICRC-2 ledger canister, and the transfer args,
+/// this function creates a signed `icrc2_transfer_from` call.
+pub fn icrc2_transfer_from(
+    private_key: PrivateKey,
+    canister_id: Principal,
+    args: Icrc2TransferFromArgs,
+) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+    if args.amount < 1 {
+        return Err(SignTransactionError::InvalidAmount);
+    }
+    validate_memo(args.memo.as_deref())?;
+
+    call(
+        private_key,
+        CallArgs {
+            canister_id,
+            method_name: TRANSFER_FROM_METHOD_NAME.to_string(),
+            arg: args.encode_candid()?,
+            current_timestamp_nanos: args.current_timestamp_nanos,
+            permitted_drift: args.permitted_drift,
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tw_encoding::hex;
+
+    use super::*;
+
+    const TIMESTAMP_SECS: u64 = 1_691_709_940;
+
+    fn account(s: &str) -> Icrc1Account {
+        Icrc1Account::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_encode_approve_args() {
+        let args = Icrc2ApproveArgs {
+            from_subaccount: None,
+            spender: account("t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe"),
+            amount: 500_000_000,
+            expected_allowance: Some(0),
+            expires_at: Some(Duration::from_secs(TIMESTAMP_SECS + 86_400).as_nanos() as u64),
+            fee: Some(10_000),
+            memo: None,
+            current_timestamp_nanos: Duration::from_secs(TIMESTAMP_SECS).as_nanos() as u64,
+            permitted_drift: None,
+        };
+        assert_eq!(hex::encode(args.encode_candid().unwrap(), false), "4449444c066c08c6fcb60201ba89e5c20402a2de94eb060282f3f3910c04d8a38ca80d7d919c9cbf0d01dea7f7da0d04cb96dcb40e056e7d6e036d7b6e786c02b3b0dac30368ad86ca830502010001904e0000010088b2343a297a1780cab5ee01010001008801c6ce777a17011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e487959370200");
+    }
+
+    #[test]
+    fn test_encode_transfer_from_args() {
+        let args = Icrc2TransferFromArgs {
+            spender_subaccount: None,
+            from: account("hpikg-6exdt-jn33w-ndty3-fc7jc-tl2lr-buih3-cs3y7-tftkp-sfp62-gqe"),
+            to: account(
+                "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1",
+            ),
+            amount: 100_000_000,
+            fee: None,
+            memo: Some(vec![1, 2, 3]),
+            current_timestamp_nanos: Duration::from_secs(TIMESTAMP_SECS).as_nanos() as u64,
+            permitted_drift: None,
+        };
+        assert_eq!(hex::encode(args.encode_candid().unwrap(), false), "4449444c066c07fbca0101c6fcb60204e185c1940202eaca8a9e0401ba89e5c2040282f3f3910c05d8a38ca80d7d6c02b3b0dac30368ad86ca8305026e036d7b6e7d6e780100011db56bf994b37ae8e79f5ce000be1727a6060ae4eef24736b7cc999c3c02012000000000000000000000000000000000000000000000000000000000000000010000011d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d02000103010203010088b2343a297a1780c2d72f");
+    }
+
+    #[test]
+    fn test_icrc2_transfer_from_invalid_amount() {
+        let private_key = PrivateKey::try_from(
+            "227102911bb99ce7285a55f952800912b7d22ebeeeee59d77fc33a5d7c7080be",
+        )
+        .unwrap();
+        let canister_id = Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap();
+        let args = Icrc2TransferFromArgs {
+            spender_subaccount: None,
+            from: account("hpikg-6exdt-jn33w-ndty3-fc7jc-tl2lr-buih3-cs3y7-tftkp-sfp62-gqe"),
+            to: account("t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe"),
+            amount: 0,
+            fee: None,
+            memo: None,
+            current_timestamp_nanos: Duration::from_secs(TIMESTAMP_SECS).as_nanos() as u64,
+            permitted_drift: None,
+        };
+
+        let result = icrc2_transfer_from(private_key, canister_id, args);
+        assert!(matches!(result, Err(SignTransactionError::InvalidAmount)));
+    }
+}
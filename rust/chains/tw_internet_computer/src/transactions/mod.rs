@@ -2,16 +2,26 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod call;
+pub mod icrc1;
+pub mod icrc2;
 pub mod transfer;
 
 pub mod proto {
     include!(concat!(env!("OUT_DIR"), "/proto/mod.rs"));
 }
 
+use std::str::FromStr;
+
 use tw_keypair::ecdsa::secp256k1::PrivateKey;
 use tw_proto::InternetComputer::Proto::mod_Transaction::OneOftransaction_oneof as Tx;
 
-use crate::protocol::{identity, principal::Principal, rosetta};
+use crate::protocol::{
+    icrc1_account::{Icrc1Account, Subaccount},
+    identity,
+    principal::Principal,
+    rosetta,
+};
 
 #[derive(Debug)]
 pub enum SignTransactionError {
@@ -21,6 +31,8 @@ pub enum SignTransactionError {
     EncodingArgsFailed,
     InvalidToAccountIdentifier,
     InvalidEnvelopePair,
+    InvalidAccount,
+    InvalidCanisterId,
 }
 
 pub fn sign_transaction(
@@ -38,13 +50,94 @@ pub fn sign_transaction(
                 max_fee: None,
                 to: transfer_args.to_account_identifier.to_string(),
                 current_timestamp_nanos: transfer_args.current_timestamp_nanos,
-                permitted_drift: if transfer_args.permitted_drift > 0 {
-                    Some(transfer_args.permitted_drift)
-                } else {
-                    None
-                },
+                permitted_drift: non_zero(transfer_args.permitted_drift),
+            },
+        ),
+        Tx::icrc1_transfer(transfer_args) => icrc1::icrc1_transfer(
+            private_key,
+            canister_id_or_default(&transfer_args.canister_id, canister_id)?,
+            icrc1::Icrc1TransferArgs {
+                from_subaccount: optional_subaccount(&transfer_args.from_subaccount)?,
+                to: parse_account(&transfer_args.to_account)?,
+                amount: transfer_args.amount,
+                fee: non_zero(transfer_args.fee),
+                memo: optional_bytes(&transfer_args.memo),
+                current_timestamp_nanos: transfer_args.current_timestamp_nanos,
+                permitted_drift: non_zero(transfer_args.permitted_drift),
+            },
+        ),
+        Tx::icrc2_approve(approve_args) => icrc2::icrc2_approve(
+            private_key,
+            canister_id_or_default(&approve_args.canister_id, canister_id)?,
+            icrc2::Icrc2ApproveArgs {
+                from_subaccount: optional_subaccount(&approve_args.from_subaccount)?,
+                spender: parse_account(&approve_args.spender_account)?,
+                amount: approve_args.amount,
+                expected_allowance: non_zero(approve_args.expected_allowance),
+                expires_at: non_zero(approve_args.expires_at),
+                fee: non_zero(approve_args.fee),
+                memo: optional_bytes(&approve_args.memo),
+                current_timestamp_nanos: approve_args.current_timestamp_nanos,
+                permitted_drift: non_zero(approve_args.permitted_drift),
+            },
+        ),
+        Tx::icrc2_transfer_from(transfer_args) => icrc2::icrc2_transfer_from(
+            private_key,
+            canister_id_or_default(&transfer_args.canister_id, canister_id)?,
+            icrc2::Icrc2TransferFromArgs {
+                spender_subaccount: optional_subaccount(&transfer_args.spender_subaccount)?,
+                from: parse_account(&transfer_args.from_account)?,
+                to: parse_account(&transfer_args.to_account)?,
+                amount: transfer_args.amount,
+                fee: non_zero(transfer_args.fee),
+                memo: optional_bytes(&transfer_args.memo),
+                current_timestamp_nanos: transfer_args.current_timestamp_nanos,
+                permitted_drift: non_zero(transfer_args.permitted_drift),
+            },
+        ),
+        Tx::call(call_args) => call::call(
+            private_key,
+            call::CallArgs {
+                canister_id: Principal::from_text(&call_args.canister_id)
+                    .map_err(|_| SignTransactionError::InvalidCanisterId)?,
+                method_name: call_args.method_name.to_string(),
+                arg: call_args.arg.to_vec(),
+                current_timestamp_nanos: call_args.current_timestamp_nanos,
+                permitted_drift: non_zero(call_args.permitted_drift),
             },
         ),
         Tx::None => Err(SignTransactionError::InvalidArguments),
     }
 }
+
+/// Parses the given canister ID, or returns the default one if empty.
+fn canister_id_or_default(
+    canister_id: &str,
+    default: Principal,
+) -> Result<Principal, SignTransactionError> {
+    if canister_id.is_empty() {
+        return Ok(default);
+    }
+    Principal::from_text(canister_id).map_err(|_| SignTransactionError::InvalidCanisterId)
+}
+
+fn parse_account(account: &str) -> Result<Icrc1Account, SignTransactionError> {
+    Icrc1Account::from_str(account).map_err(|_| SignTransactionError::InvalidAccount)
+}
+
+fn optional_subaccount(subaccount: &[u8]) -> Result<Option<Subaccount>, SignTransactionError> {
+    if subaccount.is_empty() {
+        return Ok(None);
+    }
+    Subaccount::try_from(subaccount)
+        .map(Some)
+        .map_err(|_| SignTransactionError::InvalidArguments)
+}
+
+fn optional_bytes(bytes: &[u8]) -> Option<Vec<u8>> {
+    (!bytes.is_empty()).then(|| bytes.to_vec())
+}
+
+fn non_zero(value: u64) -> Option<u64> {
+    (value > 0).then_some(value)
+}
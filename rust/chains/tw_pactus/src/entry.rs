@@ -11,7 +11,6 @@ use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -19,6 +18,7 @@ use tw_proto::Pactus::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
 use crate::compiler::PactusCompiler;
+use crate::modules::transaction_decoder::PactusTransactionDecoder;
 use crate::modules::transaction_util::PactusTransactionUtil;
 use crate::signer::PactusSigner;
 use crate::types::Address;
@@ -38,7 +38,7 @@ impl CoinEntry for PactusEntry {
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = PactusTransactionDecoder;
 
     #[inline]
     fn parse_address(
@@ -94,6 +94,11 @@ impl CoinEntry for PactusEntry {
         PactusCompiler::compile(coin, input, signatures, public_keys)
     }
 
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(PactusTransactionDecoder)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(PactusTransactionUtil)
@@ -2,5 +2,6 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod transaction_decoder;
 pub mod transaction_util;
 pub mod tx_builder;
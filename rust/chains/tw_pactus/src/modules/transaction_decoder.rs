@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Pactus::Proto;
+
+use crate::transaction::Transaction;
+
+pub struct PactusTransactionDecoder;
+
+impl TransactionDecoder for PactusTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl PactusTransactionDecoder {
+    /// Decodes either a signed (e.g. `SigningOutput.signed_transaction_data`) or an unsigned transaction.
+    fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let trx = Transaction::from_bytes(tx)?;
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: trx.lock_time(),
+            fee: trx.fee().0,
+            memo: trx.memo().to_string().into(),
+            payload: trx.payload().to_proto(),
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            signer: trx.payload().signer().to_string().into(),
+            transaction_id: trx.id().into(),
+            signature: trx
+                .signature()
+                .map(|signature| signature.to_bytes().to_vec())
+                .unwrap_or_default()
+                .into(),
+            public_key: trx
+                .public_key()
+                .map(|public_key| public_key.to_bytes().to_vec())
+                .unwrap_or_default()
+                .into(),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
@@ -2,7 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::transaction::payload::{BondPayload, Payload, TransferPayload};
+use crate::transaction::payload::{
+    BatchTransferPayload, BondPayload, Payload, Recipient, TransferPayload, UnbondPayload,
+    WithdrawPayload,
+};
 use crate::transaction::Transaction;
 use crate::types::{Address, Amount, ValidatorPublicKey};
 use std::str::FromStr;
@@ -38,6 +41,34 @@ impl TxBuilder {
                             public_key,
                         ))
                     },
+                    Pactus::Proto::mod_TransactionMessage::OneOfpayload::unbond(pld) => {
+                        let validator = Address::from_str(&pld.validator)?;
+                        Box::new(UnbondPayload::new(validator))
+                    },
+                    Pactus::Proto::mod_TransactionMessage::OneOfpayload::withdraw(pld) => {
+                        let validator = Address::from_str(&pld.validator_address)?;
+                        let account = Address::from_str(&pld.account_address)?;
+                        Box::new(WithdrawPayload::new(validator, account, Amount(pld.amount)))
+                    },
+                    Pactus::Proto::mod_TransactionMessage::OneOfpayload::batch_transfer(pld) => {
+                        if pld.recipients.is_empty() {
+                            return SigningError::err(SigningErrorType::Error_invalid_params)
+                                .context("At least one recipient is required");
+                        }
+
+                        let sender = Address::from_str(&pld.sender)?;
+                        let recipients = pld
+                            .recipients
+                            .iter()
+                            .map(|recipient| -> SigningResult<Recipient> {
+                                Ok(Recipient {
+                                    receiver: Address::from_str(&recipient.receiver)?,
+                                    amount: Amount(recipient.amount),
+                                })
+                            })
+                            .collect::<SigningResult<Vec<_>>>()?;
+                        Box::new(BatchTransferPayload::new(sender, recipients))
+                    },
                     Pactus::Proto::mod_TransactionMessage::OneOfpayload::None => {
                         return SigningError::err(SigningErrorType::Error_invalid_params)
                     },
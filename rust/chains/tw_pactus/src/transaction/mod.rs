@@ -2,7 +2,10 @@ pub mod payload;
 
 use std::fmt::Debug;
 
-use payload::{BondPayload, Payload, PayloadType, TransferPayload};
+use payload::{
+    BatchTransferPayload, BondPayload, Payload, PayloadType, TransferPayload, UnbondPayload,
+    WithdrawPayload,
+};
 use tw_coin_entry::error::prelude::SigningResult;
 use tw_hash::blake2::blake2_b;
 use tw_keypair::ed25519::sha512::{PrivateKey, PublicKey};
@@ -63,6 +66,30 @@ impl Transaction {
         self.signature = Some(signature);
     }
 
+    pub fn lock_time(&self) -> u32 {
+        self.lock_time
+    }
+
+    pub fn fee(&self) -> &Amount {
+        &self.fee
+    }
+
+    pub fn memo(&self) -> &str {
+        &self.memo
+    }
+
+    pub fn payload(&self) -> &dyn Payload {
+        self.payload.as_ref()
+    }
+
+    pub fn signature(&self) -> Option<&Signature> {
+        self.signature.as_ref()
+    }
+
+    pub fn public_key(&self) -> Option<&PublicKey> {
+        self.public_key.as_ref()
+    }
+
     pub fn id(&self) -> Vec<u8> {
         blake2_b(&self.sign_bytes().unwrap_or_default(), 32).unwrap_or_default()
     }
@@ -148,6 +175,9 @@ impl Decodable for Transaction {
         let payload: Box<dyn Payload> = match payload_type {
             PayloadType::Transfer => Box::new(TransferPayload::decode(r)?),
             PayloadType::Bond => Box::new(BondPayload::decode(r)?),
+            PayloadType::Unbond => Box::new(UnbondPayload::decode(r)?),
+            PayloadType::Withdraw => Box::new(WithdrawPayload::decode(r)?),
+            PayloadType::BatchTransfer => Box::new(BatchTransferPayload::decode(r)?),
             _ => return Err(EncoderError::ParseFailed("Unsupported payload")),
         };
 
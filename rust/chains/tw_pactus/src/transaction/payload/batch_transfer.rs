@@ -0,0 +1,115 @@
+use crate::encoder::error::Error as EncoderError;
+use crate::encoder::var_int::VarInt;
+use crate::{
+    encoder::{Decodable, Encodable},
+    types::{Address, Amount},
+};
+
+use super::{Payload, PayloadType};
+use tw_proto::Pactus::Proto;
+use tw_proto::Pactus::Proto::mod_TransactionMessage::OneOfpayload;
+
+#[derive(Debug)]
+pub struct Recipient {
+    pub receiver: Address,
+    pub amount: Amount,
+}
+
+impl Encodable for Recipient {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
+        self.receiver.encode(w)?;
+        self.amount.encode(w)?;
+
+        Ok(())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.receiver.encoded_size() + self.amount.encoded_size()
+    }
+}
+
+impl Decodable for Recipient {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, EncoderError> {
+        let receiver = Address::decode(r)?;
+        let amount = Amount::decode(r)?;
+
+        Ok(Recipient { receiver, amount })
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchTransferPayload {
+    sender: Address,
+    recipients: Vec<Recipient>,
+}
+
+impl BatchTransferPayload {
+    pub fn new(sender: Address, recipients: Vec<Recipient>) -> Self {
+        BatchTransferPayload { sender, recipients }
+    }
+}
+
+impl Encodable for BatchTransferPayload {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
+        self.sender.encode(w)?;
+        VarInt::from(self.recipients.len()).encode(w)?;
+        for recipient in self.recipients.iter() {
+            recipient.encode(w)?;
+        }
+
+        Ok(())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.sender.encoded_size()
+            + VarInt::from(self.recipients.len()).encoded_size()
+            + self
+                .recipients
+                .iter()
+                .map(Encodable::encoded_size)
+                .sum::<usize>()
+    }
+}
+
+impl Decodable for BatchTransferPayload {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, EncoderError> {
+        let sender = Address::decode(r)?;
+        let count = *VarInt::decode(r)?;
+
+        // Don't preallocate the recipients as the count is not trusted.
+        let mut recipients = Vec::new();
+        for _ in 0..count {
+            recipients.push(Recipient::decode(r)?);
+        }
+
+        Ok(BatchTransferPayload { sender, recipients })
+    }
+}
+
+impl Payload for BatchTransferPayload {
+    fn signer(&self) -> &Address {
+        &self.sender
+    }
+    fn value(&self) -> Amount {
+        let total = self.recipients.iter().fold(0_i64, |total, recipient| {
+            total.saturating_add(recipient.amount.0)
+        });
+        Amount(total)
+    }
+    fn payload_type(&self) -> PayloadType {
+        PayloadType::BatchTransfer
+    }
+    fn to_proto(&self) -> OneOfpayload<'static> {
+        OneOfpayload::batch_transfer(Proto::BatchTransferPayload {
+            sender: self.sender.to_string().into(),
+            recipients: self
+                .recipients
+                .iter()
+                .map(|recipient| Proto::Recipient {
+                    receiver: recipient.receiver.to_string().into(),
+                    amount: recipient.amount.0,
+                })
+                .collect(),
+        })
+    }
+}
@@ -5,6 +5,8 @@ use crate::{
 };
 
 use super::{Payload, PayloadType};
+use tw_proto::Pactus::Proto;
+use tw_proto::Pactus::Proto::mod_TransactionMessage::OneOfpayload;
 
 pub const BLS_PUBLIC_KEY_SIZE: usize = 96;
 
@@ -97,4 +99,17 @@ impl Payload for BondPayload {
     fn payload_type(&self) -> PayloadType {
         PayloadType::Bond
     }
+    fn to_proto(&self) -> OneOfpayload<'static> {
+        OneOfpayload::bond(Proto::BondPayload {
+            sender: self.sender.to_string().into(),
+            receiver: self.receiver.to_string().into(),
+            stake: self.stake.0,
+            public_key: self
+                .public_key
+                .as_ref()
+                .map(|public_key| public_key.to_string())
+                .unwrap_or_default()
+                .into(),
+        })
+    }
 }
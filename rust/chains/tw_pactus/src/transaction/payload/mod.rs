@@ -1,10 +1,17 @@
+mod batch_transfer;
 mod bond;
 mod transfer;
+mod unbond;
+mod withdraw;
 
+pub use batch_transfer::{BatchTransferPayload, Recipient};
 pub use bond::BondPayload;
 pub use transfer::TransferPayload;
+pub use unbond::UnbondPayload;
+pub use withdraw::WithdrawPayload;
 
 use std::fmt::Debug;
+use tw_proto::Pactus::Proto::mod_TransactionMessage::OneOfpayload;
 
 use crate::encoder::error::Error as EncoderError;
 use crate::{
@@ -20,6 +27,7 @@ pub enum PayloadType {
     Sortition = 3,
     Unbond = 4,
     Withdraw = 5,
+    BatchTransfer = 6,
 }
 
 impl TryFrom<u8> for PayloadType {
@@ -32,6 +40,7 @@ impl TryFrom<u8> for PayloadType {
             3 => Ok(PayloadType::Sortition),
             4 => Ok(PayloadType::Unbond),
             5 => Ok(PayloadType::Withdraw),
+            6 => Ok(PayloadType::BatchTransfer),
             _ => Err(EncoderError::ParseFailed("Invalid PayloadType value")),
         }
     }
@@ -57,4 +66,5 @@ pub trait Payload: Debug + Encodable {
     fn signer(&self) -> &Address;
     fn value(&self) -> Amount;
     fn payload_type(&self) -> PayloadType;
+    fn to_proto(&self) -> OneOfpayload<'static>;
 }
@@ -5,6 +5,8 @@ use crate::{
 };
 
 use super::{Payload, PayloadType};
+use tw_proto::Pactus::Proto;
+use tw_proto::Pactus::Proto::mod_TransactionMessage::OneOfpayload;
 
 #[derive(Debug)]
 pub struct TransferPayload {
@@ -61,4 +63,11 @@ impl Payload for TransferPayload {
     fn payload_type(&self) -> PayloadType {
         PayloadType::Transfer
     }
+    fn to_proto(&self) -> OneOfpayload<'static> {
+        OneOfpayload::transfer(Proto::TransferPayload {
+            sender: self.sender.to_string().into(),
+            receiver: self.receiver.to_string().into(),
+            amount: self.amount.0,
+        })
+    }
 }
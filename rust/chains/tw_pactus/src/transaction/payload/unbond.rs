@@ -0,0 +1,55 @@
+use crate::encoder::error::Error as EncoderError;
+use crate::{
+    encoder::{Decodable, Encodable},
+    types::{Address, Amount},
+};
+
+use super::{Payload, PayloadType};
+use tw_proto::Pactus::Proto;
+use tw_proto::Pactus::Proto::mod_TransactionMessage::OneOfpayload;
+
+#[derive(Debug)]
+pub struct UnbondPayload {
+    validator: Address,
+}
+
+impl UnbondPayload {
+    pub fn new(validator: Address) -> Self {
+        UnbondPayload { validator }
+    }
+}
+
+impl Encodable for UnbondPayload {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
+        self.validator.encode(w)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.validator.encoded_size()
+    }
+}
+
+impl Decodable for UnbondPayload {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, EncoderError> {
+        let validator = Address::decode(r)?;
+
+        Ok(UnbondPayload { validator })
+    }
+}
+
+impl Payload for UnbondPayload {
+    fn signer(&self) -> &Address {
+        &self.validator
+    }
+    fn value(&self) -> Amount {
+        Amount(0)
+    }
+    fn payload_type(&self) -> PayloadType {
+        PayloadType::Unbond
+    }
+    fn to_proto(&self) -> OneOfpayload<'static> {
+        OneOfpayload::unbond(Proto::UnbondPayload {
+            validator: self.validator.to_string().into(),
+        })
+    }
+}
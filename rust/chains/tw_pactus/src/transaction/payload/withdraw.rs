@@ -0,0 +1,73 @@
+use crate::encoder::error::Error as EncoderError;
+use crate::{
+    encoder::{Decodable, Encodable},
+    types::{Address, Amount},
+};
+
+use super::{Payload, PayloadType};
+use tw_proto::Pactus::Proto;
+use tw_proto::Pactus::Proto::mod_TransactionMessage::OneOfpayload;
+
+#[derive(Debug)]
+pub struct WithdrawPayload {
+    validator: Address,
+    account: Address,
+    amount: Amount,
+}
+
+impl WithdrawPayload {
+    pub fn new(validator: Address, account: Address, amount: Amount) -> Self {
+        WithdrawPayload {
+            validator,
+            account,
+            amount,
+        }
+    }
+}
+
+impl Encodable for WithdrawPayload {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
+        self.validator.encode(w)?;
+        self.account.encode(w)?;
+        self.amount.encode(w)?;
+
+        Ok(())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.validator.encoded_size() + self.account.encoded_size() + self.amount.encoded_size()
+    }
+}
+
+impl Decodable for WithdrawPayload {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, EncoderError> {
+        let validator = Address::decode(r)?;
+        let account = Address::decode(r)?;
+        let amount = Amount::decode(r)?;
+
+        Ok(WithdrawPayload {
+            validator,
+            account,
+            amount,
+        })
+    }
+}
+
+impl Payload for WithdrawPayload {
+    fn signer(&self) -> &Address {
+        &self.validator
+    }
+    fn value(&self) -> Amount {
+        self.amount.clone()
+    }
+    fn payload_type(&self) -> PayloadType {
+        PayloadType::Withdraw
+    }
+    fn to_proto(&self) -> OneOfpayload<'static> {
+        OneOfpayload::withdraw(Proto::WithdrawPayload {
+            validator_address: self.validator.to_string().into(),
+            account_address: self.account.to_string().into(),
+            amount: self.amount.0,
+        })
+    }
+}
@@ -1,12 +1,15 @@
 use crate::encoder::error::Error;
 use crate::encoder::{decode::decode_fix_slice, encode::encode_fix_slice};
 use crate::encoder::{Decodable, Encodable};
-use bech32::FromBase32;
+use bech32::{FromBase32, ToBase32};
+use std::fmt;
 use std::str::FromStr;
 use tw_keypair::KeyPairError;
 
 pub const BLS_PUBLIC_KEY_SIZE: usize = 96;
 pub const PUBLIC_KEY_HRP: &str = "public";
+/// The public key type prepended to the Bech32m data.
+pub const BLS_PUBLIC_KEY_TYPE: u8 = 1;
 
 #[derive(Debug)]
 pub struct ValidatorPublicKey(pub [u8; BLS_PUBLIC_KEY_SIZE]);
@@ -45,6 +48,18 @@ impl FromStr for ValidatorPublicKey {
     }
 }
 
+// Validator public keys are encoded into a string format using the Bech32m encoding scheme.
+impl fmt::Display for ValidatorPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut b32 = Vec::with_capacity(1 + BLS_PUBLIC_KEY_SIZE * 8 / 5 + 1);
+        b32.push(bech32::u5::try_from_u8(BLS_PUBLIC_KEY_TYPE).map_err(|_| fmt::Error)?);
+        b32.extend_from_slice(&self.0.to_base32());
+
+        bech32::encode_to_fmt(f, PUBLIC_KEY_HRP, &b32, bech32::Variant::Bech32m)
+            .map_err(|_| fmt::Error)?
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
@@ -93,12 +108,14 @@ mod test {
                 assert!(test_result.is_err());
             } else {
                 assert!(test_result.is_ok());
+                let pub_key = test_result.unwrap();
                 assert_eq!(
-                    test_result.unwrap().0.to_vec(),
+                    pub_key.0.to_vec(),
                     pub_key_data,
                     "test {} failed",
                     case.name
                 );
+                assert_eq!(pub_key.to_string(), case.pub_key_str);
             }
         }
     }
@@ -3,8 +3,9 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::encode::{encode_tx, EncodeMode};
-use crate::modules::protobuf_builder::ProtobufBuilder;
+use crate::modules::protobuf_builder::{ProtobufBuilder, SigningRequest};
 use crate::modules::transaction_signer::TransactionSigner;
+use crate::transaction::RippleTransaction;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
@@ -32,7 +33,12 @@ impl RippleCompiler {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
         let unsigned_tx = ProtobufBuilder::new(&input).build()?;
-        let pre_image = TransactionSigner::pre_image(&unsigned_tx)?;
+        let pre_image = if unsigned_tx.common_types().is_multisign() {
+            let signer_public_key = ProtobufBuilder::new(&input).signing_public_key()?;
+            TransactionSigner::multisign_pre_image(&unsigned_tx, &signer_public_key)?
+        } else {
+            TransactionSigner::pre_image(&unsigned_tx)?
+        };
 
         Ok(CompilerProto::PreSigningOutput {
             data_hash: pre_image.hash_to_sign.to_vec().into(),
@@ -58,6 +64,11 @@ impl RippleCompiler {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let unsigned_tx = ProtobufBuilder::new(&input).build()?;
+        if unsigned_tx.common_types().is_multisign() {
+            return Self::compile_multisigned(unsigned_tx, signatures, public_keys);
+        }
+
         let SingleSignaturePubkey {
             signature,
             public_key,
@@ -70,7 +81,6 @@ impl RippleCompiler {
             .into_tw()
             .context("Invalid public key")?;
 
-        let unsigned_tx = ProtobufBuilder::new(&input).build()?;
         let signed_tx = TransactionSigner::compile(unsigned_tx, &signature, &public_key)?;
 
         let encoded = encode_tx(&signed_tx, EncodeMode::All)?.encoded;
@@ -79,4 +89,37 @@ impl RippleCompiler {
             ..Proto::SigningOutput::default()
         })
     }
+
+    fn compile_multisigned(
+        unsigned_tx: SigningRequest,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Number of signatures and public keys must be equal");
+        }
+
+        let signatures = signatures
+            .iter()
+            .zip(public_keys.iter())
+            .map(|(signature, public_key)| {
+                let signature = secp256k1::Signature::from_bytes(signature.as_slice())
+                    .into_tw()
+                    .context("Invalid signature")?;
+                let public_key = secp256k1::PublicKey::try_from(public_key.as_slice())
+                    .into_tw()
+                    .context("Invalid public key")?;
+                Ok((signature, public_key))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let signed_tx = TransactionSigner::compile_multisigned(unsigned_tx, signatures)?;
+
+        let encoded = encode_tx(&signed_tx, EncodeMode::All)?.encoded;
+        Ok(Proto::SigningOutput {
+            encoded: encoded.into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
 }
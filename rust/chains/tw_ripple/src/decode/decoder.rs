@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::Decodable;
+use crate::encode::encoder::{
+    MAX_DOUBLE_BYTE_LENGTH, MAX_LENGTH_VALUE, MAX_SECOND_BYTE_VALUE, MAX_SINGLE_BYTE_LENGTH,
+};
+use crate::encode::field_instance::FieldHeader;
+use tw_coin_entry::error::prelude::*;
+
+/// A cursor over XRPL binary serialized data.
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Decoder { bytes, pos: 0 }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    /// Returns an error if there are bytes left in the stream.
+    pub fn finish(&self) -> SigningResult<()> {
+        if self.is_finished() {
+            return Ok(());
+        }
+        SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+            format!(
+                "Unexpected {} bytes left after decoding",
+                self.bytes.len() - self.pos
+            )
+        })
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> SigningResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Unexpected end of the binary data")?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Reads all bytes left in the stream.
+    pub fn read_remaining(&mut self) -> &'a [u8] {
+        let bytes = &self.bytes[self.pos..];
+        self.pos = self.bytes.len();
+        bytes
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> SigningResult<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        Ok(bytes.try_into().expect("Expected exactly N bytes"))
+    }
+
+    /// Reads `N` bytes without advancing the cursor.
+    pub fn peek_array<const N: usize>(&self) -> SigningResult<[u8; N]> {
+        let bytes = self
+            .bytes
+            .get(self.pos..)
+            .and_then(|left| left.get(..N))
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Unexpected end of the binary data")?;
+        Ok(bytes.try_into().expect("Expected exactly N bytes"))
+    }
+
+    pub fn read_byte(&mut self) -> SigningResult<u8> {
+        self.read_array::<1>().map(|[byte]| byte)
+    }
+
+    pub fn read<T: Decodable>(&mut self) -> SigningResult<T> {
+        T::decode(self)
+    }
+
+    /// Reverse of `FieldHeader` encoding.
+    pub fn read_field_header(&mut self) -> SigningResult<FieldHeader> {
+        let first = self.read_byte()?;
+
+        let mut type_code = (first >> 4) as i16;
+        let mut field_code = (first & 0x0F) as i16;
+        if type_code == 0 {
+            type_code = self.read_byte()? as i16;
+        }
+        if field_code == 0 {
+            field_code = self.read_byte()? as i16;
+        }
+
+        Ok(FieldHeader {
+            type_code,
+            field_code,
+        })
+    }
+
+    /// Reverse of `Encoder::encode_variable_length`.
+    ///
+    /// See Length Prefixing https://xrpl.org/serialization.html#length-prefixing
+    pub fn read_variable_length(&mut self) -> SigningResult<usize> {
+        let byte_1 = self.read_byte()? as usize;
+        if byte_1 <= MAX_SINGLE_BYTE_LENGTH {
+            return Ok(byte_1);
+        }
+
+        if byte_1 <= MAX_SECOND_BYTE_VALUE {
+            let byte_2 = self.read_byte()? as usize;
+            return Ok(MAX_SINGLE_BYTE_LENGTH
+                + 1
+                + ((byte_1 - MAX_SINGLE_BYTE_LENGTH - 1) << 8)
+                + byte_2);
+        }
+
+        let [byte_2, byte_3] = self.read_array::<2>()?;
+        let len = MAX_DOUBLE_BYTE_LENGTH
+            + ((byte_1 - MAX_SECOND_BYTE_VALUE - 1) << 16)
+            + ((byte_2 as usize) << 8)
+            + byte_3 as usize;
+        if len > MAX_LENGTH_VALUE {
+            return SigningError::err(SigningErrorType::Error_input_parse).context(format!(
+                "Invalid variable length too large: max: {MAX_LENGTH_VALUE}"
+            ));
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    fn test_read_variable_length_impl(encoded: &str, expected: usize) {
+        let bytes = encoded.decode_hex().unwrap();
+        let mut decoder = Decoder::new(&bytes);
+        assert_eq!(decoder.read_variable_length().unwrap(), expected);
+        assert!(decoder.is_finished());
+    }
+
+    /// Reverse of `test_encode_variable_length`.
+    #[test]
+    fn test_read_variable_length() {
+        test_read_variable_length_impl("b4", 180);
+        test_read_variable_length_impl("c427", 1000);
+        test_read_variable_length_impl("ef6f", 12080);
+        test_read_variable_length_impl("f10063", 12580);
+        test_read_variable_length_impl("f11d5f", 20000);
+        test_read_variable_length_impl("f27cef", 110000);
+        test_read_variable_length_impl("fed417", 918744);
+    }
+
+    #[test]
+    fn test_read_field_header() {
+        for (encoded, type_code, field_code) in [
+            ("12", 1, 2),
+            ("2019", 2, 25),
+            ("0318", 24, 3),
+            ("001810", 24, 16),
+        ] {
+            let bytes = encoded.decode_hex().unwrap();
+            let header = Decoder::new(&bytes).read_field_header().unwrap();
+            assert_eq!(header.type_code, type_code);
+            assert_eq!(header.field_code, field_code);
+        }
+    }
+}
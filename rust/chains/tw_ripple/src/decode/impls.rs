@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
+use tw_coin_entry::error::prelude::SigningResult;
+use tw_hash::Hash;
+
+impl<const N: usize> Decodable for Hash<N> {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        src.read_array::<N>().map(Hash::from_array)
+    }
+}
+
+macro_rules! decodable_int {
+    ($int:ty) => {
+        impl Decodable for $int {
+            fn decode(src: &mut Decoder) -> SigningResult<Self> {
+                src.read_array().map(<$int>::from_be_bytes)
+            }
+        }
+    };
+}
+
+decodable_int!(u8);
+decodable_int!(u16);
+decodable_int!(u32);
+decodable_int!(u64);
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::decode::st_object::decode_st_object;
+use serde_json::Value as Json;
+use tw_coin_entry::error::prelude::*;
+
+pub mod decoder;
+pub mod impls;
+pub mod path_set;
+pub mod st_array;
+pub mod st_object;
+pub mod xrpl_types;
+
+/// The maximum nesting depth of objects and arrays.
+/// Transactions don't go deeper than a few levels, e.g. `Memos` -> `Memo` -> fields.
+pub const MAX_DEPTH: usize = 16;
+
+/// Reverse of [`Encodable`](crate::encode::Encodable).
+pub trait Decodable: Sized {
+    fn decode(src: &mut Decoder) -> SigningResult<Self>;
+}
+
+/// Decodes a binary serialized transaction (signed or not) into a transaction JSON.
+/// The result JSON can be encoded back via [`encode_tx`](crate::encode::encode_tx).
+pub fn decode_tx(encoded: &[u8]) -> SigningResult<Json> {
+    let mut decoder = Decoder::new(encoded);
+    let nested = false;
+    let depth = 0;
+    decode_st_object(&mut decoder, nested, depth)
+        .map(Json::Object)
+        .context("Error decoding an XRPL transaction")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::st_object::STObject;
+    use serde_json::json;
+
+    #[test]
+    fn test_decode_tx_encoded_back() {
+        let tx_json = json!({
+            "Account": "raD5qJMAShLeHZXf9wjUmo6vRK4arj9cF3",
+            "Fee": "10",
+            "Flags": 0,
+            "Sequence": 103929,
+            "SigningPubKey": "028472865AF4CB32AA285834B57576B7290AA8C31B459047DB27E16F418D6A7166",
+            "TakerGets": {
+                "value": "1694.768",
+                "currency": "ILS",
+                "issuer": "rNPRNzBB92BVpAhhZr4iXDTveCgV5Pofm9"
+            },
+            "TakerPays": "98957503520",
+            "TransactionType": "OfferCreate",
+            "TxnSignature": "304502202ABE08D5E78D1E74A4C18F2714F64E87B8BD57444AFA5733109EB3C077077520022100DB335EE97386E4C0591CAC024D50E9230D8F171EEB901B5E5E4BD6D1E0AEF98C"
+        });
+
+        let signing_only = false;
+        let encoded = STObject::try_from_value(tx_json.clone(), signing_only).unwrap();
+        assert_eq!(decode_tx(&encoded.0).unwrap(), tx_json);
+    }
+
+    #[test]
+    fn test_decode_tx_truncated() {
+        // `Payment` transaction type and `Flags` without the value.
+        let encoded = [0x12, 0x00, 0x00, 0x22, 0x00];
+        decode_tx(&encoded).unwrap_err();
+    }
+
+    #[test]
+    fn test_decode_tx_too_deep() {
+        // Nested `Memo` objects.
+        let encoded = [0xEA; 100_000];
+        let err = decode_tx(&encoded).unwrap_err();
+        assert_eq!(err.error_type(), &SigningErrorType::Error_input_parse);
+        assert!(format!("{err:?}").contains("nested too deep"));
+
+        // `Memos` arrays of `Memo` objects containing `Memos` again.
+        let encoded = [0xF9, 0xEA].repeat(MAX_DEPTH);
+        let err = decode_tx(&encoded).unwrap_err();
+        assert_eq!(err.error_type(), &SigningErrorType::Error_input_parse);
+        assert!(format!("{err:?}").contains("nested too deep"));
+    }
+}
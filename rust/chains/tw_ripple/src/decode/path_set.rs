@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::types::account_id::AccountId;
+use crate::types::currency::Currency;
+use serde_json::{Map as JsonMap, Value as Json};
+use tw_coin_entry::error::prelude::*;
+
+const PATHSET_END_BYTE: u8 = 0x00;
+const PATH_SEPARATOR_BYTE: u8 = 0xFF;
+
+const TYPE_ACCOUNT: u8 = 0x01;
+const TYPE_CURRENCY: u8 = 0x10;
+const TYPE_ISSUER: u8 = 0x20;
+
+/// Decodes a set of payment paths, where each path is an array of path steps.
+///
+/// See PathSet Fields:
+/// `<https://xrpl.org/serialization.html#pathset-fields>`
+pub fn decode_path_set(src: &mut Decoder) -> SigningResult<Vec<Json>> {
+    let mut paths = Vec::new();
+    let mut path = Vec::new();
+
+    loop {
+        match src.read_byte()? {
+            PATHSET_END_BYTE => {
+                paths.push(Json::Array(path));
+                break;
+            },
+            PATH_SEPARATOR_BYTE => paths.push(Json::Array(std::mem::take(&mut path))),
+            step_type => path.push(decode_path_step(src, step_type)?),
+        }
+    }
+
+    Ok(paths)
+}
+
+fn decode_path_step(src: &mut Decoder, step_type: u8) -> SigningResult<Json> {
+    if step_type & !(TYPE_ACCOUNT | TYPE_CURRENCY | TYPE_ISSUER) != 0 {
+        return SigningError::err(SigningErrorType::Error_input_parse)
+            .with_context(|| format!("Invalid path step type: {step_type:#x}"));
+    }
+
+    let mut step = JsonMap::new();
+    if step_type & TYPE_ACCOUNT != 0 {
+        let account: AccountId = src.read()?;
+        step.insert("account".to_string(), Json::String(account.to_string()));
+    }
+    if step_type & TYPE_CURRENCY != 0 {
+        let currency: Currency = src.read()?;
+        step.insert("currency".to_string(), Json::String(currency.to_string()));
+    }
+    if step_type & TYPE_ISSUER != 0 {
+        let issuer: AccountId = src.read()?;
+        step.insert("issuer".to_string(), Json::String(issuer.to_string()));
+    }
+    Ok(Json::Object(step))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tw_encoding::hex::DecodeHex;
+
+    #[test]
+    fn test_decode_path_set() {
+        let encoded = "01aa000c09c692ef1f82787e51e22833149941ea20ff3000000000000000000000000055534400000000004b4e9c06f24296074f7bc48f92a97916c6dc5ea900"
+            .decode_hex()
+            .unwrap();
+        let mut decoder = Decoder::new(&encoded);
+        let paths = decode_path_set(&mut decoder).unwrap();
+        assert!(decoder.is_finished());
+
+        let expected = json!([
+            [{"account": "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP"}],
+            [{"currency": "USD", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"}]
+        ]);
+        assert_eq!(Json::Array(paths), expected);
+    }
+
+    #[test]
+    fn test_decode_path_set_invalid_step() {
+        let encoded = "0200".decode_hex().unwrap();
+        decode_path_set(&mut Decoder::new(&encoded)).unwrap_err();
+    }
+}
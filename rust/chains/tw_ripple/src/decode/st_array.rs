@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::decode::st_object::decode_st_object;
+use crate::decode::MAX_DEPTH;
+use crate::encode::field_instance::FieldInstance;
+use serde_json::{json, Value as Json};
+use tw_coin_entry::error::prelude::*;
+
+const ARRAY_END_MARKER_FIELD: &str = "ArrayEndMarker";
+const ST_OBJECT_TYPE: &str = "STObject";
+
+/// Decodes an array of objects until the array end marker.
+/// Each item is an object wrapped into its field name, e.g. `{"Signer": {...}}`.
+/// `depth` is the number of objects and arrays the array is nested in.
+///
+/// See Array Fields:
+/// `<https://xrpl.org/serialization.html#array-fields>`
+pub fn decode_st_array(src: &mut Decoder, depth: usize) -> SigningResult<Vec<Json>> {
+    if depth > MAX_DEPTH {
+        return SigningError::err(SigningErrorType::Error_input_parse)
+            .context("Objects and arrays are nested too deep");
+    }
+
+    let mut items = Vec::new();
+
+    loop {
+        let field_instance = FieldInstance::load_by_header(&src.read_field_header()?)?;
+        if field_instance.name == ARRAY_END_MARKER_FIELD {
+            break;
+        }
+        if field_instance.associated_type != ST_OBJECT_TYPE {
+            return SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+                format!(
+                    "STArray is expected to contain objects only, found '{}'",
+                    field_instance.name
+                )
+            });
+        }
+
+        let nested = true;
+        let object = decode_st_object(src, nested, depth + 1)
+            .with_context(|| format!("Error decoding '{}' array item", field_instance.name))?;
+        items.push(json!({ field_instance.name: object }));
+    }
+
+    Ok(items)
+}
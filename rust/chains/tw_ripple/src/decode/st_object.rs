@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::decode::xrpl_types::decode_field_value;
+use crate::decode::MAX_DEPTH;
+use crate::definitions::DEFINITIONS;
+use crate::encode::field_instance::FieldInstance;
+use crate::encode::st_object::OBJECT_END_MARKER_BYTE;
+use serde_json::{Map as JsonMap, Value as Json};
+use tw_coin_entry::error::prelude::*;
+
+pub type DecodedObject = JsonMap<String, Json>;
+
+const OBJECT_END_MARKER_FIELD: &str = "ObjectEndMarker";
+
+/// Decodes fields of an object.
+/// A `nested` object must end with [`OBJECT_END_MARKER_BYTE`],
+/// otherwise the object spans until the end of the stream.
+/// `depth` is the number of objects and arrays the object is nested in.
+///
+/// See Object Fields:
+/// `<https://xrpl.org/serialization.html#object-fields>`
+pub fn decode_st_object(
+    src: &mut Decoder,
+    nested: bool,
+    depth: usize,
+) -> SigningResult<DecodedObject> {
+    if depth > MAX_DEPTH {
+        return SigningError::err(SigningErrorType::Error_input_parse)
+            .context("Objects and arrays are nested too deep");
+    }
+
+    let mut object = DecodedObject::new();
+
+    loop {
+        if !nested && src.is_finished() {
+            break;
+        }
+
+        let field_instance = FieldInstance::load_by_header(&src.read_field_header()?)?;
+        let field_name = field_instance.name.clone();
+        if field_name == OBJECT_END_MARKER_FIELD {
+            if nested {
+                break;
+            }
+            return SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+                format!("Unexpected object end marker {OBJECT_END_MARKER_BYTE:#x}")
+            });
+        }
+
+        let value = decode_field_value(&field_instance, src, depth).with_context(|| {
+            format!(
+                "Error decoding '{field_name}' field with '{}' type",
+                field_instance.associated_type
+            )
+        })?;
+        let value = post_process_value(&field_name, value);
+
+        if object.insert(field_name.clone(), value).is_some() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .with_context(|| format!("Duplicate '{field_name}' field"));
+        }
+    }
+
+    Ok(object)
+}
+
+/// Replaces the codes of enumeration fields with their names if known.
+fn post_process_value(field_name: &str, value: Json) -> Json {
+    let Some(code) = value.as_i64().and_then(|code| i16::try_from(code).ok()) else {
+        return value;
+    };
+
+    let name = match field_name {
+        "TransactionType" => DEFINITIONS.transaction_type_name(code),
+        "LedgerEntryType" => DEFINITIONS.ledger_entry_type_name(code),
+        "TransactionResult" => DEFINITIONS.transaction_result_name(code),
+        _ => None,
+    };
+    name.map(|name| Json::String(name.to_string()))
+        .unwrap_or(value)
+}
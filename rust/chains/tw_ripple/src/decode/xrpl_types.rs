@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::decode::path_set::decode_path_set;
+use crate::decode::st_array::decode_st_array;
+use crate::decode::st_object::decode_st_object;
+use crate::decode::Decodable;
+use crate::encode::field_instance::FieldInstance;
+use crate::types::account_id::AccountId;
+use crate::types::amount::Amount;
+use crate::types::currency::Currency;
+use crate::types::issue::Issue;
+use serde::Serialize;
+use serde_json::{json, Value as Json};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::ToHex;
+use tw_hash::{Hash, H128, H160, H256};
+
+/// Decodes a value of the given field into JSON in the format accepted by
+/// [`XRPLTypes::from_value`](crate::encode::xrpl_types::XRPLTypes::from_value).
+///
+/// Hashes and blobs are represented as uppercase hex strings, `UInt64` as a decimal string.
+/// `depth` is the number of objects and arrays the field belongs to.
+pub fn decode_field_value(
+    field_instance: &FieldInstance,
+    src: &mut Decoder,
+    depth: usize,
+) -> SigningResult<Json> {
+    let associated_type = field_instance.associated_type.as_str();
+    if !field_instance.is_vl_encoded {
+        return decode_value(associated_type, src, depth);
+    }
+
+    let len = src.read_variable_length()?;
+    let mut value_decoder = Decoder::new(src.read_bytes(len)?);
+    let value = decode_value(associated_type, &mut value_decoder, depth)?;
+    value_decoder.finish()?;
+    Ok(value)
+}
+
+fn decode_value(type_name: &str, src: &mut Decoder, depth: usize) -> SigningResult<Json> {
+    let value = match type_name {
+        "AccountID" => Json::String(src.read::<AccountId>()?.to_string()),
+        "Amount" => to_json(&src.read::<Amount>()?)?,
+        "Blob" => Json::String(src.read_remaining().to_hex().to_uppercase()),
+        "Currency" => Json::String(src.read::<Currency>()?.to_string()),
+        "Hash128" => hash_to_json(src.read::<H128>()?),
+        "Hash160" => hash_to_json(src.read::<H160>()?),
+        "Hash256" | "XChainClaimID" => hash_to_json(src.read::<H256>()?),
+        "Issue" => to_json(&src.read::<Issue>()?)?,
+        "PathSet" => Json::Array(decode_path_set(src)?),
+        "Vector256" => decode_vector256(src)?,
+        "STArray" => Json::Array(decode_st_array(src, depth + 1)?),
+        "STObject" => {
+            let nested = true;
+            Json::Object(decode_st_object(src, nested, depth + 1)?)
+        },
+        "UInt8" => Json::from(src.read::<u8>()?),
+        "UInt16" => Json::from(src.read::<u16>()?),
+        "UInt32" => Json::from(src.read::<u32>()?),
+        // Represent as a string as `u64` doesn't fit into a JavaScript number.
+        "UInt64" => Json::String(src.read::<u64>()?.to_string()),
+        "XChainBridge" => decode_xchain_bridge(src)?,
+        _ => {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .with_context(|| format!("Unknown/unsupported XRPL type '{type_name}'"))
+        },
+    };
+    Ok(value)
+}
+
+/// `Vector256` is always length-prefixed, so the decoder contains the hashes only.
+fn decode_vector256(src: &mut Decoder) -> SigningResult<Json> {
+    let mut hashes = Vec::new();
+    while !src.is_finished() {
+        hashes.push(hash_to_json(src.read::<H256>()?));
+    }
+    Ok(Json::Array(hashes))
+}
+
+/// See XChainBridge Fields:
+/// `<https://xrpl.org/docs/references/protocol/binary-format#xchainbridge-fields>`
+fn decode_xchain_bridge(src: &mut Decoder) -> SigningResult<Json> {
+    fn read_door(src: &mut Decoder) -> SigningResult<String> {
+        let len = src.read_variable_length()?;
+        let mut door_decoder = Decoder::new(src.read_bytes(len)?);
+        let door = AccountId::decode(&mut door_decoder)?;
+        door_decoder.finish()?;
+        Ok(door.to_string())
+    }
+
+    let locking_chain_door = read_door(src)?;
+    let locking_chain_issue = to_json(&src.read::<Issue>()?)?;
+    let issuing_chain_door = read_door(src)?;
+    let issuing_chain_issue = to_json(&src.read::<Issue>()?)?;
+
+    Ok(json!({
+        "LockingChainDoor": locking_chain_door,
+        "LockingChainIssue": locking_chain_issue,
+        "IssuingChainDoor": issuing_chain_door,
+        "IssuingChainIssue": issuing_chain_issue,
+    }))
+}
+
+fn hash_to_json<const N: usize>(hash: Hash<N>) -> Json {
+    Json::String(hash.to_hex().to_uppercase())
+}
+
+fn to_json<T: Serialize>(value: &T) -> SigningResult<Json> {
+    serde_json::to_value(value)
+        .tw_err(SigningErrorType::Error_internal)
+        .context("Error serializing a decoded value as JSON")
+}
@@ -11,6 +11,8 @@ pub const DEFINITIONS_JSON: &str = include_str!("../definitions/definitions.json
 
 lazy_static! {
     pub static ref DEFINITIONS: Definitions = parse_definitions_json();
+    /// Names of the serialized fields by their `(type_code, nth)` codes.
+    pub static ref FIELD_NAMES_BY_CODE: HashMap<(i16, i16), String> = field_names_by_code(&DEFINITIONS);
 }
 
 /// Model object for field info metadata from the "fields" section of `definitions.json`.
@@ -42,8 +44,43 @@ pub struct Definitions {
     #[serde(with = "hashmap_as_tupple_list")]
     pub fields: HashMap<String, FieldInfo>,
     pub transaction_types: HashMap<String, i16>,
+    pub ledger_entry_types: HashMap<String, i16>,
+    pub transaction_results: HashMap<String, i16>,
+}
+
+impl Definitions {
+    pub fn transaction_type_name(&self, code: i16) -> Option<&str> {
+        find_name_by_code(&self.transaction_types, code)
+    }
+
+    pub fn ledger_entry_type_name(&self, code: i16) -> Option<&str> {
+        find_name_by_code(&self.ledger_entry_types, code)
+    }
+
+    pub fn transaction_result_name(&self, code: i16) -> Option<&str> {
+        find_name_by_code(&self.transaction_results, code)
+    }
 }
 
 fn parse_definitions_json() -> Definitions {
     serde_json::from_str(DEFINITIONS_JSON).expect("definitions.json expected to be valid")
 }
+
+fn field_names_by_code(definitions: &Definitions) -> HashMap<(i16, i16), String> {
+    definitions
+        .fields
+        .iter()
+        .filter(|(_, info)| info.is_serialized)
+        .filter_map(|(name, info)| {
+            let type_code = *definitions.types.get(&info.r#type)?;
+            Some(((type_code, info.nth), name.clone()))
+        })
+        .collect()
+}
+
+fn find_name_by_code(names: &HashMap<String, i16>, code: i16) -> Option<&str> {
+    names
+        .iter()
+        .find(|(_, value)| **value == code)
+        .map(|(name, _)| name.as_str())
+}
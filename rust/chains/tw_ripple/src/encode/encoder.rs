@@ -8,11 +8,11 @@ use std::io::Write;
 use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 
-const MAX_SINGLE_BYTE_LENGTH: usize = 192;
-const MAX_DOUBLE_BYTE_LENGTH: usize = 12_481;
-const MAX_LENGTH_VALUE: usize = 918_744;
+pub(crate) const MAX_SINGLE_BYTE_LENGTH: usize = 192;
+pub(crate) const MAX_DOUBLE_BYTE_LENGTH: usize = 12_481;
+pub(crate) const MAX_LENGTH_VALUE: usize = 918_744;
 
-const MAX_SECOND_BYTE_VALUE: usize = 240;
+pub(crate) const MAX_SECOND_BYTE_VALUE: usize = 240;
 
 #[derive(Default)]
 pub struct Encoder {
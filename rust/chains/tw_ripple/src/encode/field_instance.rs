@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::definitions::DEFINITIONS;
+use crate::definitions::{DEFINITIONS, FIELD_NAMES_BY_CODE};
 use crate::encode::encoder::Encoder;
 use crate::encode::Encodable;
 use tw_coin_entry::error::prelude::*;
@@ -98,6 +98,20 @@ impl FieldInstance {
         })
     }
 
+    /// Loads a serialized field by its header.
+    pub fn load_by_header(header: &FieldHeader) -> SigningResult<FieldInstance> {
+        let field_name = FIELD_NAMES_BY_CODE
+            .get(&(header.type_code, header.field_code))
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .with_context(|| {
+                format!(
+                    "Unknown field: type code '{}', field code '{}'",
+                    header.type_code, header.field_code
+                )
+            })?;
+        FieldInstance::load(field_name.clone())
+    }
+
     pub fn field_header(&self) -> FieldHeader {
         FieldHeader {
             type_code: self.type_code,
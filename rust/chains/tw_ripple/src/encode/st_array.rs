@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
 use tw_coin_entry::error::prelude::*;
 
-const ARRAY_END_MARKER: u8 = 0xF1;
+pub(crate) const ARRAY_END_MARKER: u8 = 0xF1;
 
 /// Class for serializing and deserializing Lists of objects.
 ///
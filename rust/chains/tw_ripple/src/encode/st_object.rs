@@ -17,7 +17,7 @@ use tw_memory::Data;
 type PreProcessedObject = JsonMap<String, Json>;
 type TransactionJson = JsonMap<String, Json>;
 
-const OBJECT_END_MARKER_BYTE: u8 = 0xE1;
+pub(crate) const OBJECT_END_MARKER_BYTE: u8 = 0xE1;
 
 #[derive(Debug, strum_macros::Display)]
 enum SpecialField {
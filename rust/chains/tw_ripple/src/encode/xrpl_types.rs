@@ -10,6 +10,7 @@ use crate::types::account_id::AccountId;
 use crate::types::amount::Amount;
 use crate::types::blob::Blob;
 use crate::types::currency::Currency;
+use crate::types::issue::Issue;
 use crate::types::vector256::Vector256;
 use serde::de::DeserializeOwned;
 use serde_json::Value as Json;
@@ -20,7 +21,6 @@ use tw_hash::{Hash, H128, H160, H256};
 
 /// XRPL supported types.
 /// Missing types:
-/// * Issue
 /// * Path
 /// * PathSet
 /// * PathStep
@@ -35,6 +35,7 @@ pub enum XRPLTypes {
     Hash128(H128),
     Hash160(H160),
     Hash256(H256),
+    Issue(Issue),
     Vector256(Vector256),
     STArray(STArray),
     STObject(STObject),
@@ -77,6 +78,7 @@ impl XRPLTypes {
         } else if value.is_object() {
             match type_name {
                 "Amount" => Ok(XRPLTypes::Amount(Amount::try_from(value)?)),
+                "Issue" => Ok(XRPLTypes::Issue(Issue::try_from(value)?)),
                 "STObject" => Ok(XRPLTypes::STObject(STObject::try_from_value(value, false)?)),
                 // `XChainBridge` types isn't supported yet.
                 _ => unsupported_error(type_name),
@@ -103,6 +105,7 @@ impl Encodable for XRPLTypes {
             XRPLTypes::Hash128(ty) => ty.encode(dst),
             XRPLTypes::Hash160(ty) => ty.encode(dst),
             XRPLTypes::Hash256(ty) => ty.encode(dst),
+            XRPLTypes::Issue(ty) => ty.encode(dst),
             XRPLTypes::Vector256(ty) => ty.encode(dst),
             XRPLTypes::STArray(ty) => ty.encode(dst),
             XRPLTypes::STObject(ty) => ty.encode(dst),
@@ -5,6 +5,8 @@
 use crate::address::classic_address::ClassicAddress;
 use crate::address::RippleAddress;
 use crate::compiler::RippleCompiler;
+use crate::modules::message_signer::RippleMessageSigner;
+use crate::modules::transaction_decoder::RippleTransactionDecoder;
 use crate::signer::RippleSigner;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
@@ -12,9 +14,7 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::transaction_util::NoTransactionUtil;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
@@ -34,9 +34,9 @@ impl CoinEntry for RippleEntry {
     // Optional modules:
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = RippleMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = RippleTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
 
     #[inline]
@@ -92,4 +92,14 @@ impl CoinEntry for RippleEntry {
     ) -> Self::SigningOutput {
         RippleCompiler::compile(coin, input, signatures, public_keys)
     }
+
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(RippleMessageSigner)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(RippleTransactionDecoder)
+    }
 }
@@ -21,6 +21,10 @@
 //! - **Transaction Signing**: Sign transactions securely using your private key.
 //! - **Transaction Pre-Image Hashing and Compiling**: Hash and compile transaction pre-images,
 //!   allowing you to sign transactions externally and compile them with the provided signature.
+//! - **Multi-Signing**: Sign transactions on behalf of an account with a signer list,
+//!   and combine the signatures of several signers into the `Signers` array.
+//! - **Transaction Decoding**: Decode binary serialized transactions into JSON,
+//!   for example, to inspect a partially multi-signed transaction before co-signing it.
 //! - **Protobuf-Based Transaction Builder**: Utilize Protobuf for building transactions,
 //!   ensuring efficient and standardized transaction structures.
 //!
@@ -35,6 +39,13 @@
 //! - `NftokenCancelOffer`: Cancel an existing NFT offer.
 //! - `NftokenAcceptOffer`: Accept an offer to buy or sell an NFT.
 //! - `TrustSet`: Establish a trust line between two accounts for issued currencies.
+//! - `SignerListSet`: Create, replace, or remove a list of signers for multi-signing.
+//! - `AccountSet`, `AccountDelete`, `SetRegularKey`: Manage the account settings and keys.
+//! - `OfferCreate`, `OfferCancel`: Place or remove offers in the decentralized exchange.
+//! - `CheckCreate`, `CheckCash`, `CheckCancel`: Create and redeem deferred payments.
+//! - `PaymentChannelCreate`, `PaymentChannelFund`, `PaymentChannelClaim`: Manage payment channels.
+//!   Off-ledger channel claims are signed via the message signer.
+//! - `AMMCreate`, `AMMDeposit`, `AMMWithdraw`, `AMMVote`, `AMMBid`: Interact with Automated Market Makers.
 //!
 //! ## Rust Usage
 //!
@@ -102,6 +113,7 @@
 
 pub mod address;
 pub mod compiler;
+pub mod decode;
 pub mod definitions;
 pub mod encode;
 pub mod entry;
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::sha2::sha512;
+use tw_hash::H256;
+use tw_keypair::ecdsa::{der, secp256k1};
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+use tw_misc::try_or_false;
+use tw_proto::Ripple::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// `CLM\0` prefix of a payment channel claim.
+pub const CHANNEL_CLAIM_PREFIX: [u8; 4] = [0x43, 0x4C, 0x4D, 0x00];
+
+/// Signs off-ledger claims against a payment channel.
+/// The channel destination redeems a claim by submitting `PaymentChannelClaim` transaction.
+///
+/// See Payment Channels:
+/// `<https://xrpl.org/docs/concepts/payment-types/payment-channels>`
+pub struct RippleMessageSigner;
+
+impl RippleMessageSigner {
+    pub fn sign_message_impl(
+        input: Proto::MessageSigningInput,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())
+            .into_tw()
+            .context("Invalid private key")?;
+        let (_, hash) = Self::claim_pre_image(input.channel_claim.as_ref())?;
+
+        let signature = private_key
+            .sign(hash)
+            .into_tw()
+            .context("Error signing the channel claim")?;
+        let der_signature = signature
+            .to_der()
+            .into_tw()
+            .context("Error converting a secp256k1 signature to DER")?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: der_signature.der_bytes().to_hex().into(),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    /// Returns `CLM\0 || channel || amount` and its SHA-512Half hash.
+    pub fn claim_pre_image(claim: Option<&Proto::ChannelClaim>) -> SigningResult<(Data, H256)> {
+        let claim = claim
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("No 'channelClaim' provided")?;
+        let channel = H256::from_str(claim.channel.as_ref())
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid 'ChannelClaim.channel'")?;
+        let amount = u64::try_from(claim.amount)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("'ChannelClaim.amount' must not be negative")?;
+
+        let mut pre_image = CHANNEL_CLAIM_PREFIX.to_vec();
+        pre_image.extend_from_slice(channel.as_slice());
+        pre_image.extend_from_slice(&amount.to_be_bytes());
+
+        let hash =
+            H256::try_from(&sha512(&pre_image)[..H256::LEN]).expect("Expected a valid H512 length");
+        Ok((pre_image, hash))
+    }
+}
+
+impl MessageSigner for RippleMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        let (pre_image, hash) = match Self::claim_pre_image(input.channel_claim.as_ref()) {
+            Ok(pre_image) => pre_image,
+            Err(e) => return signing_output_error!(CompilerProto::PreSigningOutput, e),
+        };
+
+        CompilerProto::PreSigningOutput {
+            data: pre_image.into(),
+            data_hash: hash.to_vec().into(),
+            ..CompilerProto::PreSigningOutput::default()
+        }
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        let public_key = try_or_false!(secp256k1::PublicKey::try_from(input.public_key.as_ref()));
+        let der_signature = try_or_false!(input.signature.decode_hex());
+        let der_signature = try_or_false!(der::Signature::from_bytes(&der_signature));
+        let signature = try_or_false!(secp256k1::VerifySignature::from_der(der_signature));
+        let (_, hash) = try_or_false!(Self::claim_pre_image(input.channel_claim.as_ref()));
+
+        public_key.verify(signature, hash)
+    }
+}
@@ -2,5 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod message_signer;
 pub mod protobuf_builder;
+pub mod transaction_decoder;
 pub mod transaction_signer;
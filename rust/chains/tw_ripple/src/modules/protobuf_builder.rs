@@ -8,20 +8,23 @@ use crate::transaction::common_fields::CommonFields;
 use crate::transaction::json_transaction::JsonTransaction;
 use crate::transaction::transaction_builder::TransactionBuilder;
 use crate::transaction::transaction_type::TransactionType;
+use crate::transaction::transactions::signer_list_set::SignerEntry;
 use crate::transaction::RippleTransaction;
 use crate::types::account_id::AccountId;
 use crate::types::amount::issued_currency::IssuedCurrency;
 use crate::types::amount::native_amount::NativeAmount;
 use crate::types::amount::Amount;
 use crate::types::currency::Currency;
+use crate::types::issue::Issue;
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::hex::as_hex::AsHex;
 use tw_encoding::hex::DecodeHex;
-use tw_hash::H256;
+use tw_hash::{H128, H256};
 use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
 use tw_misc::traits::{OptionalEmpty, OptionalInt};
 use tw_proto::Ripple::Proto;
 use tw_proto::Ripple::Proto::mod_SigningInput::OneOfoperation_oneof as OperationType;
@@ -79,11 +82,20 @@ impl<'a> ProtobufBuilder<'a> {
         let expected_signing_pubkey = self.signing_public_key()?;
 
         // Check whether JSON transaction contains `SigningPubKey` field, otherwise set it.
-        if tx.common_fields.signing_pub_key.is_none() {
-            tx.common_fields.signing_pub_key = Some(AsHex(expected_signing_pubkey.compressed()));
+        // `SigningPubKey` must be empty if the transaction is multi-signed.
+        if self.input.multisign {
+            tx.common_fields.signing_pub_key = Some(AsHex(Data::default()));
+        } else if tx.common_fields.signing_pub_key.is_none() {
+            tx.common_fields.signing_pub_key =
+                Some(AsHex(expected_signing_pubkey.compressed().to_vec()));
         }
 
         // Check whether JSON transaction contains `Account` field, otherwise set it.
+        // The account of a multi-signed transaction cannot be derived from the signing public key.
+        if self.input.multisign && tx.common_fields.account.is_none() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("'Account' must be specified for a multi-signed transaction");
+        }
         if tx.common_fields.account.is_none() {
             let address = ClassicAddress::with_public_key(&expected_signing_pubkey)
                 .into_tw()
@@ -123,6 +135,25 @@ impl<'a> ProtobufBuilder<'a> {
             OperationType::op_nftoken_create_offer(ref create) => self.nftoken_create_offer(create),
             OperationType::op_nftoken_accept_offer(ref accept) => self.nftoken_accept_offer(accept),
             OperationType::op_nftoken_cancel_offer(ref cancel) => self.nftoken_cancel_offer(cancel),
+            OperationType::op_signer_list_set(ref signer_list) => self.signer_list_set(signer_list),
+            OperationType::op_account_set(ref account_set) => self.account_set(account_set),
+            OperationType::op_account_delete(ref delete) => self.account_delete(delete),
+            OperationType::op_set_regular_key(ref regular_key) => self.set_regular_key(regular_key),
+            OperationType::op_offer_create(ref offer) => self.offer_create(offer),
+            OperationType::op_offer_cancel(ref offer) => self.offer_cancel(offer),
+            OperationType::op_check_create(ref check) => self.check_create(check),
+            OperationType::op_check_cash(ref check) => self.check_cash(check),
+            OperationType::op_check_cancel(ref check) => self.check_cancel(check),
+            OperationType::op_payment_channel_create(ref create) => {
+                self.payment_channel_create(create)
+            },
+            OperationType::op_payment_channel_fund(ref fund) => self.payment_channel_fund(fund),
+            OperationType::op_payment_channel_claim(ref claim) => self.payment_channel_claim(claim),
+            OperationType::op_amm_create(ref create) => self.amm_create(create),
+            OperationType::op_amm_deposit(ref deposit) => self.amm_deposit(deposit),
+            OperationType::op_amm_withdraw(ref withdraw) => self.amm_withdraw(withdraw),
+            OperationType::op_amm_vote(ref vote) => self.amm_vote(vote),
+            OperationType::op_amm_bid(ref bid) => self.amm_bid(bid),
             OperationType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("`SigningInput.operation` is not provided"),
         }
@@ -316,6 +347,360 @@ impl<'a> ProtobufBuilder<'a> {
             .map(TransactionType::NFTokenCancelOffer)
     }
 
+    pub fn signer_list_set(
+        &self,
+        signer_list: &Proto::OperationSignerListSet,
+    ) -> SigningResult<TransactionType> {
+        let signer_entries = signer_list
+            .signer_entries
+            .iter()
+            .map(|entry| {
+                let account = ClassicAddress::from_str(entry.account.as_ref())
+                    .into_tw()
+                    .context("Invalid 'SignerEntry.account'")?;
+                let signer_weight = u16::try_from(entry.signer_weight)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("'SignerEntry.signerWeight' is too large")?;
+                Ok(SignerEntry {
+                    account,
+                    signer_weight,
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        self.prepare_builder()?
+            .signer_list_set(signer_list.signer_quorum, signer_entries)
+            .map(TransactionType::SignerListSet)
+    }
+
+    pub fn account_set(
+        &self,
+        account_set: &Proto::OperationAccountSet,
+    ) -> SigningResult<TransactionType> {
+        let domain = account_set.domain.as_bytes().to_vec().empty_or_some();
+        let email_hash = account_set
+            .email_hash
+            .to_string()
+            .empty_or_some()
+            .map(|hash| H128::from_str(&hash))
+            .transpose()
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid 'OperationAccountSet.emailHash'")?;
+        let message_key = account_set
+            .message_key
+            .decode_hex()
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid 'OperationAccountSet.messageKey'")?
+            .empty_or_some();
+        let tick_size = u8::try_from(account_set.tick_size)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("'OperationAccountSet.tickSize' is too large")?
+            .zero_or_some();
+        let nftoken_minter = Self::optional_classic_address(
+            account_set.nftoken_minter.as_ref(),
+            "OperationAccountSet.nftokenMinter",
+        )?;
+
+        self.prepare_builder()?
+            .account_set(
+                account_set.clear_flag.zero_or_some(),
+                account_set.set_flag.zero_or_some(),
+                domain,
+                email_hash,
+                message_key,
+                account_set.transfer_rate.zero_or_some(),
+                tick_size,
+                nftoken_minter,
+            )
+            .map(TransactionType::AccountSet)
+    }
+
+    pub fn account_delete(
+        &self,
+        delete: &Proto::OperationAccountDelete,
+    ) -> SigningResult<TransactionType> {
+        let destination = Self::classic_address(
+            delete.destination.as_ref(),
+            "OperationAccountDelete.destination",
+        )?;
+        self.prepare_builder()?
+            .account_delete(destination, delete.destination_tag.zero_or_some())
+            .map(TransactionType::AccountDelete)
+    }
+
+    pub fn set_regular_key(
+        &self,
+        set_regular_key: &Proto::OperationSetRegularKey,
+    ) -> SigningResult<TransactionType> {
+        let regular_key = Self::optional_classic_address(
+            set_regular_key.regular_key.as_ref(),
+            "OperationSetRegularKey.regularKey",
+        )?;
+        self.prepare_builder()?
+            .set_regular_key(regular_key)
+            .map(TransactionType::SetRegularKey)
+    }
+
+    pub fn offer_create(
+        &self,
+        offer: &Proto::OperationOfferCreate,
+    ) -> SigningResult<TransactionType> {
+        let taker_gets =
+            Self::required_amount(&offer.taker_gets, "OperationOfferCreate.takerGets")?;
+        let taker_pays =
+            Self::required_amount(&offer.taker_pays, "OperationOfferCreate.takerPays")?;
+        self.prepare_builder()?
+            .offer_create(
+                taker_gets,
+                taker_pays,
+                offer.expiration.zero_or_some(),
+                offer.offer_sequence.zero_or_some(),
+            )
+            .map(TransactionType::OfferCreate)
+    }
+
+    pub fn offer_cancel(
+        &self,
+        offer: &Proto::OperationOfferCancel,
+    ) -> SigningResult<TransactionType> {
+        self.prepare_builder()?
+            .offer_cancel(offer.offer_sequence)
+            .map(TransactionType::OfferCancel)
+    }
+
+    pub fn check_create(
+        &self,
+        check: &Proto::OperationCheckCreate,
+    ) -> SigningResult<TransactionType> {
+        let destination = Self::classic_address(
+            check.destination.as_ref(),
+            "OperationCheckCreate.destination",
+        )?;
+        let send_max = Self::required_amount(&check.send_max, "OperationCheckCreate.sendMax")?;
+        let invoice_id = check
+            .invoice_id
+            .to_string()
+            .empty_or_some()
+            .map(|invoice_id| Self::hash256(&invoice_id, "OperationCheckCreate.invoiceId"))
+            .transpose()?;
+
+        self.prepare_builder()?
+            .check_create(
+                destination,
+                send_max,
+                check.destination_tag.zero_or_some(),
+                check.expiration.zero_or_some(),
+                invoice_id,
+            )
+            .map(TransactionType::CheckCreate)
+    }
+
+    pub fn check_cash(&self, check: &Proto::OperationCheckCash) -> SigningResult<TransactionType> {
+        use tw_proto::Ripple::Proto::mod_OperationCheckCash::OneOfamount_oneof as CheckAmountType;
+
+        let check_id = Self::hash256(check.check_id.as_ref(), "OperationCheckCash.checkId")?;
+        let (amount, deliver_min) = match check.amount_oneof {
+            CheckAmountType::amount(ref amount) => (
+                Some(Self::amount(amount, "OperationCheckCash.amount")?),
+                None,
+            ),
+            CheckAmountType::deliver_min(ref deliver_min) => (
+                None,
+                Some(Self::amount(deliver_min, "OperationCheckCash.deliverMin")?),
+            ),
+            CheckAmountType::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params).context(
+                    "Either 'OperationCheckCash.amount' or 'deliverMin' must be specified",
+                );
+            },
+        };
+
+        self.prepare_builder()?
+            .check_cash(check_id, amount, deliver_min)
+            .map(TransactionType::CheckCash)
+    }
+
+    pub fn check_cancel(
+        &self,
+        check: &Proto::OperationCheckCancel,
+    ) -> SigningResult<TransactionType> {
+        let check_id = Self::hash256(check.check_id.as_ref(), "OperationCheckCancel.checkId")?;
+        self.prepare_builder()?
+            .check_cancel(check_id)
+            .map(TransactionType::CheckCancel)
+    }
+
+    pub fn payment_channel_create(
+        &self,
+        create: &Proto::OperationPaymentChannelCreate,
+    ) -> SigningResult<TransactionType> {
+        let amount = NativeAmount::new(create.amount)
+            .context("Invalid 'OperationPaymentChannelCreate.amount'")?;
+        let destination = Self::classic_address(
+            create.destination.as_ref(),
+            "OperationPaymentChannelCreate.destination",
+        )?;
+        let public_key = Self::public_key_hex(
+            create.public_key.as_ref(),
+            "OperationPaymentChannelCreate.publicKey",
+        )?;
+
+        self.prepare_builder()?
+            .payment_channel_create(
+                amount,
+                destination,
+                create.settle_delay,
+                &public_key,
+                create.cancel_after.zero_or_some(),
+                create.destination_tag.zero_or_some(),
+            )
+            .map(TransactionType::PaymentChannelCreate)
+    }
+
+    pub fn payment_channel_fund(
+        &self,
+        fund: &Proto::OperationPaymentChannelFund,
+    ) -> SigningResult<TransactionType> {
+        let channel = Self::hash256(fund.channel.as_ref(), "OperationPaymentChannelFund.channel")?;
+        let amount = NativeAmount::new(fund.amount)
+            .context("Invalid 'OperationPaymentChannelFund.amount'")?;
+
+        self.prepare_builder()?
+            .payment_channel_fund(channel, amount, fund.expiration.zero_or_some())
+            .map(TransactionType::PaymentChannelFund)
+    }
+
+    pub fn payment_channel_claim(
+        &self,
+        claim: &Proto::OperationPaymentChannelClaim,
+    ) -> SigningResult<TransactionType> {
+        let channel = Self::hash256(
+            claim.channel.as_ref(),
+            "OperationPaymentChannelClaim.channel",
+        )?;
+        let balance = claim
+            .balance
+            .zero_or_some()
+            .map(NativeAmount::new)
+            .transpose()
+            .context("Invalid 'OperationPaymentChannelClaim.balance'")?;
+        let amount = claim
+            .amount
+            .zero_or_some()
+            .map(NativeAmount::new)
+            .transpose()
+            .context("Invalid 'OperationPaymentChannelClaim.amount'")?;
+        let signature = claim
+            .signature
+            .decode_hex()
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid 'OperationPaymentChannelClaim.signature'")?
+            .empty_or_some();
+        let public_key = claim
+            .public_key
+            .to_string()
+            .empty_or_some()
+            .map(|public_key| {
+                Self::public_key_hex(&public_key, "OperationPaymentChannelClaim.publicKey")
+            })
+            .transpose()?;
+
+        self.prepare_builder()?
+            .payment_channel_claim(channel, balance, amount, signature, public_key)
+            .map(TransactionType::PaymentChannelClaim)
+    }
+
+    pub fn amm_create(&self, create: &Proto::OperationAMMCreate) -> SigningResult<TransactionType> {
+        let amount = Self::required_amount(&create.amount, "OperationAMMCreate.amount")?;
+        let amount2 = Self::required_amount(&create.amount2, "OperationAMMCreate.amount2")?;
+        let trading_fee = Self::trading_fee(create.trading_fee)?;
+
+        self.prepare_builder()?
+            .amm_create(amount, amount2, trading_fee)
+            .map(TransactionType::AMMCreate)
+    }
+
+    pub fn amm_deposit(
+        &self,
+        deposit: &Proto::OperationAMMDeposit,
+    ) -> SigningResult<TransactionType> {
+        let (asset, asset2) =
+            Self::amm_assets(&deposit.asset, &deposit.asset2, "OperationAMMDeposit")?;
+        let amount = Self::optional_amount(&deposit.amount, "OperationAMMDeposit.amount")?;
+        let amount2 = Self::optional_amount(&deposit.amount2, "OperationAMMDeposit.amount2")?;
+        let e_price = Self::optional_amount(&deposit.e_price, "OperationAMMDeposit.ePrice")?;
+        let lp_token_out = deposit
+            .lp_token_out
+            .as_ref()
+            .map(Self::issued_currency)
+            .transpose()?;
+        let trading_fee = Self::trading_fee(deposit.trading_fee)?.zero_or_some();
+
+        self.prepare_builder()?
+            .amm_deposit(
+                asset,
+                asset2,
+                amount,
+                amount2,
+                e_price,
+                lp_token_out,
+                trading_fee,
+            )
+            .map(TransactionType::AMMDeposit)
+    }
+
+    pub fn amm_withdraw(
+        &self,
+        withdraw: &Proto::OperationAMMWithdraw,
+    ) -> SigningResult<TransactionType> {
+        let (asset, asset2) =
+            Self::amm_assets(&withdraw.asset, &withdraw.asset2, "OperationAMMWithdraw")?;
+        let amount = Self::optional_amount(&withdraw.amount, "OperationAMMWithdraw.amount")?;
+        let amount2 = Self::optional_amount(&withdraw.amount2, "OperationAMMWithdraw.amount2")?;
+        let e_price = Self::optional_amount(&withdraw.e_price, "OperationAMMWithdraw.ePrice")?;
+        let lp_token_in = withdraw
+            .lp_token_in
+            .as_ref()
+            .map(Self::issued_currency)
+            .transpose()?;
+
+        self.prepare_builder()?
+            .amm_withdraw(asset, asset2, amount, amount2, e_price, lp_token_in)
+            .map(TransactionType::AMMWithdraw)
+    }
+
+    pub fn amm_vote(&self, vote: &Proto::OperationAMMVote) -> SigningResult<TransactionType> {
+        let (asset, asset2) = Self::amm_assets(&vote.asset, &vote.asset2, "OperationAMMVote")?;
+        let trading_fee = Self::trading_fee(vote.trading_fee)?;
+
+        self.prepare_builder()?
+            .amm_vote(asset, asset2, trading_fee)
+            .map(TransactionType::AMMVote)
+    }
+
+    pub fn amm_bid(&self, bid: &Proto::OperationAMMBid) -> SigningResult<TransactionType> {
+        let (asset, asset2) = Self::amm_assets(&bid.asset, &bid.asset2, "OperationAMMBid")?;
+        let bid_min = bid
+            .bid_min
+            .as_ref()
+            .map(Self::issued_currency)
+            .transpose()?;
+        let bid_max = bid
+            .bid_max
+            .as_ref()
+            .map(Self::issued_currency)
+            .transpose()?;
+        let auth_accounts = bid
+            .auth_accounts
+            .iter()
+            .map(|account| Self::classic_address(account.as_ref(), "OperationAMMBid.authAccounts"))
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        self.prepare_builder()?
+            .amm_bid(asset, asset2, bid_min, bid_max, auth_accounts)
+            .map(TransactionType::AMMBid)
+    }
+
     pub fn prepare_builder(&self) -> SigningResult<TransactionBuilder> {
         let signing_public_key = self.signing_public_key()?;
         let fee = self.fee()?;
@@ -326,15 +711,21 @@ impl<'a> ProtobufBuilder<'a> {
             .flags(self.input.flags)
             .sequence(self.input.sequence)
             .last_ledger_sequence(self.input.last_ledger_sequence)
-            .account_str(self.input.account.as_ref())?
-            .signing_pub_key(&signing_public_key);
+            .account_str(self.input.account.as_ref())?;
+        if self.input.multisign {
+            builder.multisign();
+        } else {
+            builder.signing_pub_key(&signing_public_key);
+        }
         if self.input.source_tag != 0 {
             builder.source_tag(self.input.source_tag);
         }
         Ok(builder)
     }
 
-    fn signing_public_key(&self) -> SigningResult<secp256k1::PublicKey> {
+    /// Returns a public key of the signer.
+    /// In case of a multi-signed transaction, it's a public key of one of the multi-signers.
+    pub fn signing_public_key(&self) -> SigningResult<secp256k1::PublicKey> {
         if !self.input.private_key.is_empty() {
             secp256k1::PrivateKey::try_from(self.input.private_key.as_ref())
                 .into_tw()
@@ -354,6 +745,109 @@ impl<'a> ProtobufBuilder<'a> {
         NativeAmount::new(self.input.fee).context("Invalid fee")
     }
 
+    fn amount(input: &Proto::Amount, field: &str) -> SigningResult<Amount> {
+        use tw_proto::Ripple::Proto::mod_Amount::OneOfamount_oneof as AmountType;
+
+        match input.amount_oneof {
+            AmountType::drops(drops) => NativeAmount::new(drops)
+                .with_context(|| format!("Invalid '{field}'"))
+                .map(Amount::NativeAmount),
+            AmountType::currency_amount(ref currency) => {
+                Self::issued_currency(currency).map(Amount::IssuedCurrency)
+            },
+            AmountType::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .with_context(|| format!("'{field}' isn't specified")),
+        }
+    }
+
+    fn required_amount(input: &Option<Proto::Amount>, field: &str) -> SigningResult<Amount> {
+        let input = input
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("No '{field}' provided"))?;
+        Self::amount(input, field)
+    }
+
+    fn optional_amount(
+        input: &Option<Proto::Amount>,
+        field: &str,
+    ) -> SigningResult<Option<Amount>> {
+        input
+            .as_ref()
+            .map(|amount| Self::amount(amount, field))
+            .transpose()
+    }
+
+    fn issue(input: &Proto::Issue) -> SigningResult<Issue> {
+        let currency =
+            Currency::from_str(input.currency.as_ref()).context("Invalid 'Issue.currency'")?;
+        let issuer = input
+            .issuer
+            .to_string()
+            .empty_or_some()
+            .map(|issuer| AccountId::from_str(&issuer))
+            .transpose()
+            .context("Invalid 'Issue.issuer'")?;
+        Issue::new(currency, issuer)
+    }
+
+    fn amm_assets(
+        asset: &Option<Proto::Issue>,
+        asset2: &Option<Proto::Issue>,
+        operation: &str,
+    ) -> SigningResult<(Issue, Issue)> {
+        let asset = asset
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("No '{operation}.asset' provided"))?;
+        let asset2 = asset2
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("No '{operation}.asset2' provided"))?;
+        Ok((Self::issue(asset)?, Self::issue(asset2)?))
+    }
+
+    fn trading_fee(trading_fee: u32) -> SigningResult<u16> {
+        u16::try_from(trading_fee)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("'tradingFee' is too large")
+    }
+
+    fn classic_address(address: &str, field: &str) -> SigningResult<ClassicAddress> {
+        RippleAddress::from_str(address)
+            .into_tw()
+            .with_context(|| format!("Invalid '{field}' address"))?
+            .to_classic_address()
+            .into_tw()
+            .with_context(|| format!("Error converting '{field}' to a Classic address"))
+    }
+
+    fn optional_classic_address(
+        address: &str,
+        field: &str,
+    ) -> SigningResult<Option<ClassicAddress>> {
+        if address.is_empty() {
+            return Ok(None);
+        }
+        Self::classic_address(address, field).map(Some)
+    }
+
+    fn hash256(hash: &str, field: &str) -> SigningResult<H256> {
+        H256::from_str(hash)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("Invalid '{field}'"))
+    }
+
+    fn public_key_hex(public_key: &str, field: &str) -> SigningResult<secp256k1::PublicKey> {
+        let bytes = public_key
+            .decode_hex()
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("Invalid '{field}'"))?;
+        secp256k1::PublicKey::try_from(bytes.as_slice())
+            .into_tw()
+            .with_context(|| format!("Invalid '{field}'"))
+    }
+
     fn issued_currency(input: &Proto::CurrencyAmount) -> SigningResult<IssuedCurrency> {
         let value = BigDecimal::from_str(input.value.as_ref())
             .tw_err(SigningErrorType::Error_invalid_requested_token_amount)
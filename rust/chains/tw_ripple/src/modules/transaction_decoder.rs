@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decode_tx;
+use crate::transaction::json_transaction::JsonTransaction;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Ripple::Proto;
+
+pub struct RippleTransactionDecoder;
+
+impl TransactionDecoder for RippleTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl RippleTransactionDecoder {
+    /// Decodes a binary serialized transaction, e.g. `SigningOutput.encoded`
+    /// or a partially multi-signed transaction.
+    fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let json = decode_tx(tx)?;
+
+        let transaction_type = json
+            .get("TransactionType")
+            .and_then(|tx_type| tx_type.as_str())
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Expected a transaction with known 'TransactionType'")?
+            .to_string();
+        let JsonTransaction { common_fields, .. } = serde_json::from_value(json.clone())
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error parsing transaction common fields")?;
+
+        let signers = common_fields
+            .signers
+            .iter()
+            .flatten()
+            .map(|wrapper| wrapper.signer.account.to_string().into())
+            .collect();
+
+        let transaction = Proto::DecodedTransaction {
+            transaction_type: transaction_type.into(),
+            account: common_fields
+                .account
+                .map(|account| account.to_string().into())
+                .unwrap_or_default(),
+            fee: common_fields.fee.map(|fee| fee.drops()).unwrap_or_default(),
+            sequence: common_fields.sequence.unwrap_or_default(),
+            last_ledger_sequence: common_fields.last_ledger_sequence.unwrap_or_default(),
+            flags: common_fields.flags.unwrap_or_default(),
+            signing_pub_key: common_fields
+                .signing_pub_key
+                .map(|pubkey| pubkey.0.into())
+                .unwrap_or_default(),
+            has_signature: common_fields.txn_signature.is_some(),
+            signers,
+            json: json.to_string().into(),
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
@@ -4,10 +4,12 @@
 
 use crate::address::classic_address::ClassicAddress;
 use crate::encode::{encode_tx, EncodeMode, TxEncoded};
+use crate::transaction::common_fields::{Signer, SignerWrapper};
 use crate::transaction::RippleTransaction;
 use serde_json::Value as Json;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::hex::as_hex::AsHex;
+use tw_encoding::hex::ToHex;
 use tw_hash::sha2::sha512;
 use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
@@ -15,6 +17,8 @@ use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
 use tw_memory::Data;
 
 pub const NETWORK_PREFIX: [u8; 4] = [0x53, 0x54, 0x58, 0x00];
+/// `SMT\0` prefix of a multi-signing pre-image.
+pub const MULTISIGN_PREFIX: [u8; 4] = [0x53, 0x4D, 0x54, 0x00];
 
 pub struct TxPreImage {
     pub unsigned_tx: Json,
@@ -29,6 +33,10 @@ impl TransactionSigner {
         tx: Transaction,
         private_key: &secp256k1::PrivateKey,
     ) -> SigningResult<Transaction> {
+        if tx.common_types().is_multisign() {
+            return Self::multisign(tx, private_key);
+        }
+
         let public_key = private_key.public();
         Self::check_signing_public_key(&tx, &public_key)?;
         Self::check_source_account(&tx, &public_key)?;
@@ -46,19 +54,61 @@ impl TransactionSigner {
         Self::compile_unchecked(tx, &signature)
     }
 
+    /// Signs the multi-signed `tx` as one of the signers.
+    /// The signature is added to the `Signers` array.
+    ///
+    /// Note the signer account is derived from the private key,
+    /// i.e. signing with a regular key of the signer account is not supported.
+    pub fn multisign<Transaction: RippleTransaction>(
+        tx: Transaction,
+        private_key: &secp256k1::PrivateKey,
+    ) -> SigningResult<Transaction> {
+        let public_key = private_key.public();
+        let TxPreImage { hash_to_sign, .. } = Self::multisign_pre_image(&tx, &public_key)?;
+        let signature = private_key
+            .sign(hash_to_sign)
+            .into_tw()
+            .context("Error signing transaction")?;
+        Self::add_signers_unchecked(tx, vec![(signature, public_key)])
+    }
+
     pub fn pre_image<Transaction: RippleTransaction>(
         tx: &Transaction,
     ) -> SigningResult<TxPreImage> {
         let TxEncoded { json, encoded } = encode_tx(tx, EncodeMode::SigningOnly)?;
         let pre_image: Data = NETWORK_PREFIX.iter().copied().chain(encoded).collect();
+        Ok(Self::tx_pre_image(json, pre_image))
+    }
+
+    /// Returns a pre-image of the multi-signed `tx` to be signed by the given signer:
+    /// `SMT\0 || tx || signer_account`.
+    pub fn multisign_pre_image<Transaction: RippleTransaction>(
+        tx: &Transaction,
+        signer_public_key: &secp256k1::PublicKey,
+    ) -> SigningResult<TxPreImage> {
+        Self::check_multisign(tx)?;
+        let signer_account = ClassicAddress::with_public_key(signer_public_key)
+            .into_tw()
+            .context("Error generating a signer account address")?;
 
+        let TxEncoded { json, encoded } = encode_tx(tx, EncodeMode::SigningOnly)?;
+        let pre_image: Data = MULTISIGN_PREFIX
+            .iter()
+            .copied()
+            .chain(encoded)
+            .chain(signer_account.bytes().iter().copied())
+            .collect();
+        Ok(Self::tx_pre_image(json, pre_image))
+    }
+
+    fn tx_pre_image(unsigned_tx: Json, pre_image: Data) -> TxPreImage {
         let hash512 = sha512(&pre_image);
         let hash256 = H256::try_from(&hash512[..H256::LEN]).expect("Expected a valid H512 length");
-        Ok(TxPreImage {
-            unsigned_tx: json,
+        TxPreImage {
+            unsigned_tx,
             pre_image_tx_data: pre_image,
             hash_to_sign: hash256,
-        })
+        }
     }
 
     /// Compiles `signature` into the `transaction` validating the signature.
@@ -79,6 +129,70 @@ impl TransactionSigner {
         Self::compile_unchecked(tx, signature)
     }
 
+    /// Compiles signatures of the multi-signers into the `transaction` validating the signatures.
+    pub fn compile_multisigned<Transaction: RippleTransaction>(
+        tx: Transaction,
+        signatures: Vec<(secp256k1::Signature, secp256k1::PublicKey)>,
+    ) -> SigningResult<Transaction> {
+        if signatures.is_empty() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected at least one signature");
+        }
+
+        for (signature, public_key) in signatures.iter() {
+            let TxPreImage { hash_to_sign, .. } = Self::multisign_pre_image(&tx, public_key)?;
+            if !public_key.verify(signature.to_verify_sig(), hash_to_sign) {
+                return SigningError::err(SigningErrorType::Error_signing).with_context(|| {
+                    format!(
+                        "Error verifying the signature of '{}'",
+                        public_key.compressed()
+                    )
+                });
+            }
+        }
+
+        Self::add_signers_unchecked(tx, signatures)
+    }
+
+    /// Adds signatures of the multi-signers into the `Signers` array without signature validation.
+    /// Keeps the array sorted by the signer accounts.
+    fn add_signers_unchecked<Transaction: RippleTransaction>(
+        mut tx: Transaction,
+        signatures: Vec<(secp256k1::Signature, secp256k1::PublicKey)>,
+    ) -> SigningResult<Transaction> {
+        let mut signers = tx.common_types_mut().signers.take().unwrap_or_default();
+
+        for (signature, public_key) in signatures {
+            let account = ClassicAddress::with_public_key(&public_key)
+                .into_tw()
+                .context("Error generating a signer account address")?;
+            if signers
+                .iter()
+                .any(|wrapper| wrapper.signer.account == account)
+            {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Transaction is signed by '{account}' already"));
+            }
+
+            let der_signature = signature
+                .to_der()
+                .into_tw()
+                .context("Error converting a secp256k1 signature to DER")?;
+            signers.push(SignerWrapper {
+                signer: Signer {
+                    account,
+                    txn_signature: AsHex(der_signature),
+                    signing_pub_key: AsHex(public_key.compressed()),
+                },
+            });
+        }
+
+        // Signers must be sorted by the numeric value of their account IDs.
+        signers.sort_by(|a, b| a.signer.account.bytes().cmp(b.signer.account.bytes()));
+        tx.common_types_mut().signers = Some(signers);
+        Ok(tx)
+    }
+
     /// Compiles `signature` into the `transaction` without signature validation.
     /// Should only be used at [`TransactionSigner::sign`].
     fn compile_unchecked<Transaction: RippleTransaction>(
@@ -137,13 +251,27 @@ impl TransactionSigner {
             .0;
 
         let actual_pubkey = public_key.compressed();
-        if *expected_signing_pubkey != actual_pubkey {
+        if expected_signing_pubkey.as_slice() != actual_pubkey.as_slice() {
             let error = format!(
-                "Public key mismatch: actual signing pubkey '{actual_pubkey}', expected '{expected_signing_pubkey}'"
+                "Public key mismatch: actual signing pubkey '{actual_pubkey}', expected '{}'",
+                expected_signing_pubkey.to_hex()
             );
             return SigningError::err(SigningErrorType::Error_missing_private_key).context(error);
         }
 
         Ok(())
     }
+
+    /// Checks whether the given transaction is expected to be multi-signed and not signed by a single key.
+    fn check_multisign<Transaction: RippleTransaction>(tx: &Transaction) -> SigningResult<()> {
+        if !tx.common_types().is_multisign() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multi-signed transaction must have an empty 'SigningPubKey'");
+        }
+        if tx.common_types().txn_signature.is_some() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multi-signed transaction must not have 'TxnSignature'");
+        }
+        Ok(())
+    }
 }
@@ -5,6 +5,18 @@
 use crate::address::classic_address::ClassicAddress;
 use crate::address::RippleAddress;
 use crate::transaction::common_fields::CommonFields;
+use crate::transaction::transactions::account_delete::AccountDelete;
+use crate::transaction::transactions::account_set::AccountSet;
+use crate::transaction::transactions::amm_bid::{
+    AMMBid, AuthAccount, AuthAccountWrapper, MAX_AUTH_ACCOUNTS,
+};
+use crate::transaction::transactions::amm_create::{AMMCreate, MAX_TRADING_FEE};
+use crate::transaction::transactions::amm_deposit::AMMDeposit;
+use crate::transaction::transactions::amm_vote::AMMVote;
+use crate::transaction::transactions::amm_withdraw::AMMWithdraw;
+use crate::transaction::transactions::check_cancel::CheckCancel;
+use crate::transaction::transactions::check_cash::CheckCash;
+use crate::transaction::transactions::check_create::CheckCreate;
 use crate::transaction::transactions::escrow_cancel::EscrowCancel;
 use crate::transaction::transactions::escrow_create::EscrowCreate;
 use crate::transaction::transactions::escrow_finish::EscrowFinish;
@@ -12,19 +24,38 @@ use crate::transaction::transactions::nftoken_accept_offer::NFTokenAcceptOffer;
 use crate::transaction::transactions::nftoken_burn::NFTokenBurn;
 use crate::transaction::transactions::nftoken_cancel_offer::NFTokenCancelOffer;
 use crate::transaction::transactions::nftoken_create_offer::NFTokenCreateOffer;
+use crate::transaction::transactions::offer_cancel::OfferCancel;
+use crate::transaction::transactions::offer_create::OfferCreate;
 use crate::transaction::transactions::payment::Payment;
+use crate::transaction::transactions::payment_channel_claim::PaymentChannelClaim;
+use crate::transaction::transactions::payment_channel_create::PaymentChannelCreate;
+use crate::transaction::transactions::payment_channel_fund::PaymentChannelFund;
+use crate::transaction::transactions::set_regular_key::SetRegularKey;
+use crate::transaction::transactions::signer_list_set::{
+    SignerEntry, SignerEntryWrapper, SignerListSet,
+};
 use crate::transaction::transactions::trust_set::TrustSet;
 use crate::types::amount::issued_currency::IssuedCurrency;
 use crate::types::amount::native_amount::NativeAmount;
 use crate::types::amount::Amount;
+use crate::types::issue::Issue;
 use crate::types::vector256::Vector256;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::hex::as_hex::AsHex;
-use tw_hash::H256;
+use tw_hash::{H128, H256};
 use tw_keypair::ecdsa::secp256k1;
 use tw_memory::Data;
 
+/// Max number of the signer list entries.
+pub const MAX_SIGNER_ENTRIES: usize = 32;
+/// Valid non-zero `TransferRate` range, in billionths of a unit.
+pub const MIN_TRANSFER_RATE: u32 = 1_000_000_000;
+pub const MAX_TRANSFER_RATE: u32 = 2_000_000_000;
+/// Valid non-zero `TickSize` range.
+pub const MIN_TICK_SIZE: u8 = 3;
+pub const MAX_TICK_SIZE: u8 = 15;
+
 #[derive(Default)]
 pub struct TransactionBuilder {
     common_fields: CommonFields,
@@ -64,7 +95,13 @@ impl TransactionBuilder {
     }
 
     pub fn signing_pub_key(&mut self, signing_pub_key: &secp256k1::PublicKey) -> &mut Self {
-        self.common_fields.signing_pub_key = Some(AsHex(signing_pub_key.compressed()));
+        self.common_fields.signing_pub_key = Some(AsHex(signing_pub_key.compressed().to_vec()));
+        self
+    }
+
+    /// Sets an empty `SigningPubKey`, so the transaction is expected to be multi-signed.
+    pub fn multisign(&mut self) -> &mut Self {
+        self.common_fields.signing_pub_key = Some(AsHex(Data::default()));
         self
     }
 
@@ -214,6 +251,415 @@ impl TransactionBuilder {
         })
     }
 
+    pub fn signer_list_set(
+        self,
+        signer_quorum: u32,
+        signer_entries: Vec<SignerEntry>,
+    ) -> SigningResult<SignerListSet> {
+        self.check_ready()?;
+
+        // The signer list is deleted.
+        if signer_quorum == 0 {
+            if !signer_entries.is_empty() {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("SignerEntries must be empty if SignerQuorum is 0");
+            }
+            return Ok(SignerListSet {
+                common_fields: self.common_fields,
+                signer_quorum,
+                signer_entries: None,
+            });
+        }
+
+        if signer_entries.is_empty() || signer_entries.len() > MAX_SIGNER_ENTRIES {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("SignerEntries must contain from 1 to {MAX_SIGNER_ENTRIES} signers")
+            });
+        }
+
+        let account = self.source_classic_address()?;
+
+        let mut weights_sum = 0_u32;
+        for (i, entry) in signer_entries.iter().enumerate() {
+            if entry.account == account {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("The account cannot be a member of its own signer list");
+            }
+            if signer_entries[..i]
+                .iter()
+                .any(|e| e.account == entry.account)
+            {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Duplicate signer entry '{}'", entry.account));
+            }
+            if entry.signer_weight == 0 {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Zero weight of the signer '{}'", entry.account));
+            }
+            weights_sum += entry.signer_weight as u32;
+        }
+
+        if weights_sum < signer_quorum {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("SignerQuorum cannot be greater than the sum of the signer weights");
+        }
+
+        let signer_entries = signer_entries
+            .into_iter()
+            .map(|signer_entry| SignerEntryWrapper { signer_entry })
+            .collect();
+        Ok(SignerListSet {
+            common_fields: self.common_fields,
+            signer_quorum,
+            signer_entries: Some(signer_entries),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn account_set(
+        self,
+        clear_flag: Option<u32>,
+        set_flag: Option<u32>,
+        domain: Option<Data>,
+        email_hash: Option<H128>,
+        message_key: Option<Data>,
+        transfer_rate: Option<u32>,
+        tick_size: Option<u8>,
+        nftoken_minter: Option<ClassicAddress>,
+    ) -> SigningResult<AccountSet> {
+        self.check_ready()?;
+
+        if set_flag.is_some() && set_flag == clear_flag {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("SetFlag and ClearFlag must not be the same");
+        }
+        if let Some(transfer_rate) = transfer_rate {
+            if transfer_rate != 0
+                && !(MIN_TRANSFER_RATE..=MAX_TRANSFER_RATE).contains(&transfer_rate)
+            {
+                return SigningError::err(SigningErrorType::Error_invalid_params).with_context(
+                    || format!("TransferRate must be 0 or from {MIN_TRANSFER_RATE} to {MAX_TRANSFER_RATE}"),
+                );
+            }
+        }
+        if let Some(tick_size) = tick_size {
+            if tick_size != 0 && !(MIN_TICK_SIZE..=MAX_TICK_SIZE).contains(&tick_size) {
+                return SigningError::err(SigningErrorType::Error_invalid_params).with_context(
+                    || format!("TickSize must be 0 or from {MIN_TICK_SIZE} to {MAX_TICK_SIZE}"),
+                );
+            }
+        }
+
+        Ok(AccountSet {
+            common_fields: self.common_fields,
+            clear_flag,
+            set_flag,
+            domain: domain.map(AsHex),
+            email_hash: email_hash.map(AsHex),
+            message_key: message_key.map(AsHex),
+            transfer_rate,
+            tick_size,
+            nftoken_minter,
+        })
+    }
+
+    pub fn account_delete(
+        self,
+        destination: ClassicAddress,
+        destination_tag: Option<u32>,
+    ) -> SigningResult<AccountDelete> {
+        self.check_ready()?;
+        if self.source_classic_address()? == destination {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Destination must be different from the deleted account");
+        }
+        Ok(AccountDelete {
+            common_fields: self.common_fields,
+            destination,
+            destination_tag,
+        })
+    }
+
+    pub fn set_regular_key(
+        self,
+        regular_key: Option<ClassicAddress>,
+    ) -> SigningResult<SetRegularKey> {
+        self.check_ready()?;
+        if regular_key.is_some() && regular_key == Some(self.source_classic_address()?) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("RegularKey must not match the account address");
+        }
+        Ok(SetRegularKey {
+            common_fields: self.common_fields,
+            regular_key,
+        })
+    }
+
+    pub fn offer_create(
+        self,
+        taker_gets: Amount,
+        taker_pays: Amount,
+        expiration: Option<u32>,
+        offer_sequence: Option<u32>,
+    ) -> SigningResult<OfferCreate> {
+        self.check_ready()?;
+        if matches!(
+            (&taker_gets, &taker_pays),
+            (Amount::NativeAmount(_), Amount::NativeAmount(_))
+        ) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Cannot exchange XRP for XRP");
+        }
+        Ok(OfferCreate {
+            common_fields: self.common_fields,
+            taker_gets,
+            taker_pays,
+            expiration,
+            offer_sequence,
+        })
+    }
+
+    pub fn offer_cancel(self, offer_sequence: u32) -> SigningResult<OfferCancel> {
+        self.check_ready()?;
+        Ok(OfferCancel {
+            common_fields: self.common_fields,
+            offer_sequence,
+        })
+    }
+
+    pub fn check_create(
+        self,
+        destination: ClassicAddress,
+        send_max: Amount,
+        destination_tag: Option<u32>,
+        expiration: Option<u32>,
+        invoice_id: Option<H256>,
+    ) -> SigningResult<CheckCreate> {
+        self.check_ready()?;
+        Ok(CheckCreate {
+            common_fields: self.common_fields,
+            destination,
+            send_max,
+            destination_tag,
+            expiration,
+            invoice_id: invoice_id.map(AsHex),
+        })
+    }
+
+    pub fn check_cash(
+        self,
+        check_id: H256,
+        amount: Option<Amount>,
+        deliver_min: Option<Amount>,
+    ) -> SigningResult<CheckCash> {
+        self.check_ready()?;
+        if amount.is_some() == deliver_min.is_some() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Exactly one of Amount or DeliverMin must be specified");
+        }
+        Ok(CheckCash {
+            common_fields: self.common_fields,
+            check_id: AsHex(check_id),
+            amount,
+            deliver_min,
+        })
+    }
+
+    pub fn check_cancel(self, check_id: H256) -> SigningResult<CheckCancel> {
+        self.check_ready()?;
+        Ok(CheckCancel {
+            common_fields: self.common_fields,
+            check_id: AsHex(check_id),
+        })
+    }
+
+    pub fn payment_channel_create(
+        self,
+        amount: NativeAmount,
+        destination: ClassicAddress,
+        settle_delay: u32,
+        public_key: &secp256k1::PublicKey,
+        cancel_after: Option<u32>,
+        destination_tag: Option<u32>,
+    ) -> SigningResult<PaymentChannelCreate> {
+        self.check_ready()?;
+        Ok(PaymentChannelCreate {
+            common_fields: self.common_fields,
+            amount,
+            destination,
+            settle_delay,
+            public_key: AsHex(public_key.compressed()),
+            cancel_after,
+            destination_tag,
+        })
+    }
+
+    pub fn payment_channel_fund(
+        self,
+        channel: H256,
+        amount: NativeAmount,
+        expiration: Option<u32>,
+    ) -> SigningResult<PaymentChannelFund> {
+        self.check_ready()?;
+        Ok(PaymentChannelFund {
+            common_fields: self.common_fields,
+            channel: AsHex(channel),
+            amount,
+            expiration,
+        })
+    }
+
+    pub fn payment_channel_claim(
+        self,
+        channel: H256,
+        balance: Option<NativeAmount>,
+        amount: Option<NativeAmount>,
+        signature: Option<Data>,
+        public_key: Option<secp256k1::PublicKey>,
+    ) -> SigningResult<PaymentChannelClaim> {
+        self.check_ready()?;
+        if signature.is_some() && (public_key.is_none() || amount.is_none()) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Claim Signature requires PublicKey and Amount to be specified");
+        }
+        Ok(PaymentChannelClaim {
+            common_fields: self.common_fields,
+            channel: AsHex(channel),
+            balance,
+            amount,
+            signature: signature.map(AsHex),
+            public_key: public_key.map(|public_key| AsHex(public_key.compressed())),
+        })
+    }
+
+    pub fn amm_create(
+        self,
+        amount: Amount,
+        amount2: Amount,
+        trading_fee: u16,
+    ) -> SigningResult<AMMCreate> {
+        self.check_ready()?;
+        check_trading_fee(trading_fee)?;
+        Ok(AMMCreate {
+            common_fields: self.common_fields,
+            amount,
+            amount2,
+            trading_fee,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn amm_deposit(
+        self,
+        asset: Issue,
+        asset2: Issue,
+        amount: Option<Amount>,
+        amount2: Option<Amount>,
+        e_price: Option<Amount>,
+        lp_token_out: Option<IssuedCurrency>,
+        trading_fee: Option<u16>,
+    ) -> SigningResult<AMMDeposit> {
+        self.check_ready()?;
+        check_amm_amounts(&amount, &amount2, &e_price)?;
+        if amount.is_none() && lp_token_out.is_none() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Either Amount or LPTokenOut must be specified");
+        }
+        if let Some(trading_fee) = trading_fee {
+            check_trading_fee(trading_fee)?;
+        }
+        Ok(AMMDeposit {
+            common_fields: self.common_fields,
+            asset,
+            asset2,
+            amount,
+            amount2,
+            e_price,
+            lp_token_out,
+            trading_fee,
+        })
+    }
+
+    pub fn amm_withdraw(
+        self,
+        asset: Issue,
+        asset2: Issue,
+        amount: Option<Amount>,
+        amount2: Option<Amount>,
+        e_price: Option<Amount>,
+        lp_token_in: Option<IssuedCurrency>,
+    ) -> SigningResult<AMMWithdraw> {
+        self.check_ready()?;
+        check_amm_amounts(&amount, &amount2, &e_price)?;
+        Ok(AMMWithdraw {
+            common_fields: self.common_fields,
+            asset,
+            asset2,
+            amount,
+            amount2,
+            e_price,
+            lp_token_in,
+        })
+    }
+
+    pub fn amm_vote(self, asset: Issue, asset2: Issue, trading_fee: u16) -> SigningResult<AMMVote> {
+        self.check_ready()?;
+        check_trading_fee(trading_fee)?;
+        Ok(AMMVote {
+            common_fields: self.common_fields,
+            asset,
+            asset2,
+            trading_fee,
+        })
+    }
+
+    pub fn amm_bid(
+        self,
+        asset: Issue,
+        asset2: Issue,
+        bid_min: Option<IssuedCurrency>,
+        bid_max: Option<IssuedCurrency>,
+        auth_accounts: Vec<ClassicAddress>,
+    ) -> SigningResult<AMMBid> {
+        self.check_ready()?;
+        if auth_accounts.len() > MAX_AUTH_ACCOUNTS {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("AuthAccounts must contain at most {MAX_AUTH_ACCOUNTS} accounts")
+            });
+        }
+
+        let auth_accounts = if auth_accounts.is_empty() {
+            None
+        } else {
+            let wrappers = auth_accounts
+                .into_iter()
+                .map(|account| AuthAccountWrapper {
+                    auth_account: AuthAccount { account },
+                })
+                .collect();
+            Some(wrappers)
+        };
+        Ok(AMMBid {
+            common_fields: self.common_fields,
+            asset,
+            asset2,
+            bid_min,
+            bid_max,
+            auth_accounts,
+        })
+    }
+
+    fn source_classic_address(&self) -> SigningResult<ClassicAddress> {
+        self.common_fields
+            .account
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_internal)
+            .context("'Account' isn't set")?
+            .to_classic_address()
+            .into_tw()
+            .context("Error converting 'Account' to a Classic address")
+    }
+
     fn check_ready(&self) -> SigningResult<()> {
         if self.common_fields.signing_pub_key.is_none() {
             return SigningError::err(SigningErrorType::Error_internal)
@@ -226,3 +672,28 @@ impl TransactionBuilder {
         Ok(())
     }
 }
+
+fn check_trading_fee(trading_fee: u16) -> SigningResult<()> {
+    if trading_fee > MAX_TRADING_FEE {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("TradingFee must not exceed {MAX_TRADING_FEE}"));
+    }
+    Ok(())
+}
+
+/// Checks whether the AMM deposit/withdrawal amounts are consistent.
+fn check_amm_amounts(
+    amount: &Option<Amount>,
+    amount2: &Option<Amount>,
+    e_price: &Option<Amount>,
+) -> SigningResult<()> {
+    if amount.is_none() && (amount2.is_some() || e_price.is_some()) {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Amount2 and EPrice require Amount to be specified");
+    }
+    if amount2.is_some() && e_price.is_some() {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Amount2 and EPrice cannot be specified together");
+    }
+    Ok(())
+}
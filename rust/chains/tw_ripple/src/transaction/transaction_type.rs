@@ -3,6 +3,16 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::transaction::common_fields::CommonFields;
+use crate::transaction::transactions::account_delete::AccountDelete;
+use crate::transaction::transactions::account_set::AccountSet;
+use crate::transaction::transactions::amm_bid::AMMBid;
+use crate::transaction::transactions::amm_create::AMMCreate;
+use crate::transaction::transactions::amm_deposit::AMMDeposit;
+use crate::transaction::transactions::amm_vote::AMMVote;
+use crate::transaction::transactions::amm_withdraw::AMMWithdraw;
+use crate::transaction::transactions::check_cancel::CheckCancel;
+use crate::transaction::transactions::check_cash::CheckCash;
+use crate::transaction::transactions::check_create::CheckCreate;
 use crate::transaction::transactions::escrow_cancel::EscrowCancel;
 use crate::transaction::transactions::escrow_create::EscrowCreate;
 use crate::transaction::transactions::escrow_finish::EscrowFinish;
@@ -10,7 +20,14 @@ use crate::transaction::transactions::nftoken_accept_offer::NFTokenAcceptOffer;
 use crate::transaction::transactions::nftoken_burn::NFTokenBurn;
 use crate::transaction::transactions::nftoken_cancel_offer::NFTokenCancelOffer;
 use crate::transaction::transactions::nftoken_create_offer::NFTokenCreateOffer;
+use crate::transaction::transactions::offer_cancel::OfferCancel;
+use crate::transaction::transactions::offer_create::OfferCreate;
 use crate::transaction::transactions::payment::Payment;
+use crate::transaction::transactions::payment_channel_claim::PaymentChannelClaim;
+use crate::transaction::transactions::payment_channel_create::PaymentChannelCreate;
+use crate::transaction::transactions::payment_channel_fund::PaymentChannelFund;
+use crate::transaction::transactions::set_regular_key::SetRegularKey;
+use crate::transaction::transactions::signer_list_set::SignerListSet;
 use crate::transaction::transactions::trust_set::TrustSet;
 use crate::transaction::RippleTransaction;
 use serde::{Deserialize, Serialize};
@@ -26,6 +43,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "TransactionType")]
+#[allow(clippy::large_enum_variant)]
 pub enum TransactionType {
     Payment(Payment),
     TrustSet(TrustSet),
@@ -36,6 +54,23 @@ pub enum TransactionType {
     NFTokenCreateOffer(NFTokenCreateOffer),
     NFTokenAcceptOffer(NFTokenAcceptOffer),
     NFTokenCancelOffer(NFTokenCancelOffer),
+    SignerListSet(SignerListSet),
+    AccountSet(AccountSet),
+    AccountDelete(AccountDelete),
+    SetRegularKey(SetRegularKey),
+    OfferCreate(OfferCreate),
+    OfferCancel(OfferCancel),
+    CheckCreate(CheckCreate),
+    CheckCash(CheckCash),
+    CheckCancel(CheckCancel),
+    PaymentChannelCreate(PaymentChannelCreate),
+    PaymentChannelFund(PaymentChannelFund),
+    PaymentChannelClaim(PaymentChannelClaim),
+    AMMCreate(AMMCreate),
+    AMMDeposit(AMMDeposit),
+    AMMWithdraw(AMMWithdraw),
+    AMMVote(AMMVote),
+    AMMBid(AMMBid),
 }
 
 impl RippleTransaction for TransactionType {
@@ -50,6 +85,23 @@ impl RippleTransaction for TransactionType {
             TransactionType::NFTokenCreateOffer(create) => create.common_types(),
             TransactionType::NFTokenAcceptOffer(accept) => accept.common_types(),
             TransactionType::NFTokenCancelOffer(cancel) => cancel.common_types(),
+            TransactionType::SignerListSet(signer_list) => signer_list.common_types(),
+            TransactionType::AccountSet(account_set) => account_set.common_types(),
+            TransactionType::AccountDelete(account_delete) => account_delete.common_types(),
+            TransactionType::SetRegularKey(set_regular_key) => set_regular_key.common_types(),
+            TransactionType::OfferCreate(offer_create) => offer_create.common_types(),
+            TransactionType::OfferCancel(offer_cancel) => offer_cancel.common_types(),
+            TransactionType::CheckCreate(check_create) => check_create.common_types(),
+            TransactionType::CheckCash(check_cash) => check_cash.common_types(),
+            TransactionType::CheckCancel(check_cancel) => check_cancel.common_types(),
+            TransactionType::PaymentChannelCreate(channel_create) => channel_create.common_types(),
+            TransactionType::PaymentChannelFund(channel_fund) => channel_fund.common_types(),
+            TransactionType::PaymentChannelClaim(channel_claim) => channel_claim.common_types(),
+            TransactionType::AMMCreate(amm_create) => amm_create.common_types(),
+            TransactionType::AMMDeposit(amm_deposit) => amm_deposit.common_types(),
+            TransactionType::AMMWithdraw(amm_withdraw) => amm_withdraw.common_types(),
+            TransactionType::AMMVote(amm_vote) => amm_vote.common_types(),
+            TransactionType::AMMBid(amm_bid) => amm_bid.common_types(),
         }
     }
 
@@ -58,12 +110,31 @@ impl RippleTransaction for TransactionType {
             TransactionType::Payment(payment) => payment.common_types_mut(),
             TransactionType::TrustSet(trust_set) => trust_set.common_types_mut(),
             TransactionType::EscrowCreate(escrow_create) => escrow_create.common_types_mut(),
-            TransactionType::EscrowCancel(escrow) => escrow.common_types_mut(),
+            TransactionType::EscrowCancel(escrow_cancel) => escrow_cancel.common_types_mut(),
             TransactionType::EscrowFinish(escrow_finish) => escrow_finish.common_types_mut(),
             TransactionType::NFTokenBurn(burn) => burn.common_types_mut(),
             TransactionType::NFTokenCreateOffer(create) => create.common_types_mut(),
             TransactionType::NFTokenAcceptOffer(accept) => accept.common_types_mut(),
             TransactionType::NFTokenCancelOffer(cancel) => cancel.common_types_mut(),
+            TransactionType::SignerListSet(signer_list) => signer_list.common_types_mut(),
+            TransactionType::AccountSet(account_set) => account_set.common_types_mut(),
+            TransactionType::AccountDelete(account_delete) => account_delete.common_types_mut(),
+            TransactionType::SetRegularKey(set_regular_key) => set_regular_key.common_types_mut(),
+            TransactionType::OfferCreate(offer_create) => offer_create.common_types_mut(),
+            TransactionType::OfferCancel(offer_cancel) => offer_cancel.common_types_mut(),
+            TransactionType::CheckCreate(check_create) => check_create.common_types_mut(),
+            TransactionType::CheckCash(check_cash) => check_cash.common_types_mut(),
+            TransactionType::CheckCancel(check_cancel) => check_cancel.common_types_mut(),
+            TransactionType::PaymentChannelCreate(channel_create) => {
+                channel_create.common_types_mut()
+            },
+            TransactionType::PaymentChannelFund(channel_fund) => channel_fund.common_types_mut(),
+            TransactionType::PaymentChannelClaim(channel_claim) => channel_claim.common_types_mut(),
+            TransactionType::AMMCreate(amm_create) => amm_create.common_types_mut(),
+            TransactionType::AMMDeposit(amm_deposit) => amm_deposit.common_types_mut(),
+            TransactionType::AMMWithdraw(amm_withdraw) => amm_withdraw.common_types_mut(),
+            TransactionType::AMMVote(amm_vote) => amm_vote.common_types_mut(),
+            TransactionType::AMMBid(amm_bid) => amm_bid.common_types_mut(),
         }
     }
 }
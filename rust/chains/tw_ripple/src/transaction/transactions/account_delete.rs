@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+
+/// Deletes an account and any objects it owns in the XRP Ledger,
+/// sending the account's remaining XRP balance to the destination.
+///
+/// See AccountDelete:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/accountdelete>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountDelete {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The address of an account to receive any leftover XRP after deleting the sending account.
+    pub destination: ClassicAddress,
+    /// Arbitrary destination tag that identifies a hosted recipient or other information
+    /// for the recipient of the deleted account's leftover XRP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<u32>,
+}
+
+ripple_tx!(AccountDelete);
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H128;
+use tw_memory::Data;
+
+/// Modifies the properties of an account in the XRP Ledger.
+///
+/// See AccountSet:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/accountset>`
+///
+/// Unsupported fields:
+/// * WalletLocator - Not used.
+/// * WalletSize - Not used.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountSet {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// Unique identifier of a flag to disable for this account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_flag: Option<u32>,
+    /// Unique identifier of a flag to enable for this account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_flag: Option<u32>,
+    /// The domain that owns this account, as ASCII bytes.
+    /// An empty value removes the domain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<AsHex<Data>>,
+    /// Hash of an email address to be used for generating an avatar image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_hash: Option<AsHex<H128>>,
+    /// Public key for sending encrypted messages to this account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_key: Option<AsHex<Data>>,
+    /// The fee to charge when users transfer this account's tokens, in billionths of a unit.
+    /// Cannot be more than 2000000000 or less than 1000000000, except for the special case 0 meaning no fee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_rate: Option<u32>,
+    /// Tick size to use for offers involving a currency issued by this address.
+    /// Valid values are 3 to 15 inclusive, or 0 to disable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tick_size: Option<u8>,
+    /// Another account that can mint NFTokens for this account.
+    #[serde(rename = "NFTokenMinter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nftoken_minter: Option<ClassicAddress>,
+}
+
+ripple_tx!(AccountSet);
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::issued_currency::IssuedCurrency;
+use crate::types::issue::Issue;
+use serde::{Deserialize, Serialize};
+
+/// Max number of the accounts allowed to trade at the discounted fee.
+pub const MAX_AUTH_ACCOUNTS: usize = 4;
+
+/// Bids on an Automated Market Maker's auction slot.
+/// If you win, you can trade against the AMM at a discounted fee until you are outbid or 24 hours have passed.
+///
+/// See AMMBid:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/ammbid>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMBid {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The definition for one of the assets in the AMM pool.
+    pub asset: Issue,
+    /// The definition for the other asset in the AMM pool.
+    pub asset2: Issue,
+    /// Pay at least this amount of LP Tokens for the slot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_min: Option<IssuedCurrency>,
+    /// Pay at most this amount of LP Tokens for the slot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_max: Option<IssuedCurrency>,
+    /// A list of up to 4 additional accounts that you allow to trade at the discounted fee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_accounts: Option<Vec<AuthAccountWrapper>>,
+}
+
+ripple_tx!(AMMBid);
+
+/// An account allowed to trade at the discounted fee.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthAccount {
+    pub account: ClassicAddress,
+}
+
+/// `AuthAccounts` array element is an object with the only `AuthAccount` field.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuthAccountWrapper {
+    pub auth_account: AuthAccount,
+}
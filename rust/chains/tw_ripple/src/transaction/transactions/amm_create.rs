@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::Amount;
+use serde::{Deserialize, Serialize};
+
+/// Max AMM trading fee in units of 1/100,000 (1%).
+pub const MAX_TRADING_FEE: u16 = 1000;
+
+/// Creates a new Automated Market Maker (AMM) instance for trading a pair of assets.
+///
+/// See AMMCreate:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/ammcreate>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMCreate {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The first of the two assets to fund this AMM with.
+    pub amount: Amount,
+    /// The second of the two assets to fund this AMM with.
+    pub amount2: Amount,
+    /// The fee to charge for trades against this AMM instance, in units of 1/100,000.
+    /// The maximum value is 1000, for a 1% fee.
+    pub trading_fee: u16,
+}
+
+ripple_tx!(AMMCreate);
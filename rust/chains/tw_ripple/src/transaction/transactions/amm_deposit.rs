@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::issued_currency::IssuedCurrency;
+use crate::types::amount::Amount;
+use crate::types::issue::Issue;
+use serde::{Deserialize, Serialize};
+
+/// Deposits funds into an AMM instance and receives the AMM's liquidity provider tokens (LP Tokens) in exchange.
+/// The deposit mode is specified by the transaction flags.
+///
+/// See AMMDeposit:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/ammdeposit>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMDeposit {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The definition for one of the assets in the AMM pool.
+    pub asset: Issue,
+    /// The definition for the other asset in the AMM pool.
+    pub asset2: Issue,
+    /// The amount of one asset to deposit to the AMM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// The amount of another asset to add to the AMM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount2: Option<Amount>,
+    /// The maximum effective price, in the deposit asset, to pay for each LP Token received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_price: Option<Amount>,
+    /// How many of the AMM's LP Tokens to buy.
+    #[serde(rename = "LPTokenOut")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lp_token_out: Option<IssuedCurrency>,
+    /// Submit a vote for the AMM's trading fee, in units of 1/100,000.
+    /// Can only be used with the `tfTwoAssetIfEmpty` flag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trading_fee: Option<u16>,
+}
+
+ripple_tx!(AMMDeposit);
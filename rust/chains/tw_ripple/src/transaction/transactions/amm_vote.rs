@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::issue::Issue;
+use serde::{Deserialize, Serialize};
+
+/// Votes on the trading fee for an Automated Market Maker instance.
+///
+/// See AMMVote:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/ammvote>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMVote {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The definition for one of the assets in the AMM pool.
+    pub asset: Issue,
+    /// The definition for the other asset in the AMM pool.
+    pub asset2: Issue,
+    /// The proposed fee to vote for, in units of 1/100,000.
+    /// The maximum value is 1000, for a 1% fee.
+    pub trading_fee: u16,
+}
+
+ripple_tx!(AMMVote);
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::issued_currency::IssuedCurrency;
+use crate::types::amount::Amount;
+use crate::types::issue::Issue;
+use serde::{Deserialize, Serialize};
+
+/// Withdraws assets from an AMM instance by returning the AMM's liquidity provider tokens (LP Tokens).
+/// The withdrawal mode is specified by the transaction flags.
+///
+/// See AMMWithdraw:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/ammwithdraw>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AMMWithdraw {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The definition for one of the assets in the AMM pool.
+    pub asset: Issue,
+    /// The definition for the other asset in the AMM pool.
+    pub asset2: Issue,
+    /// The amount of one asset to withdraw from the AMM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// The amount of another asset to withdraw from the AMM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount2: Option<Amount>,
+    /// The minimum effective price, in LP Token returned, to pay per unit of the asset to withdraw.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_price: Option<Amount>,
+    /// How many of the AMM's LP Tokens to redeem.
+    #[serde(rename = "LPTokenIn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lp_token_in: Option<IssuedCurrency>,
+}
+
+ripple_tx!(AMMWithdraw);
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H256;
+
+/// Cancels an unredeemed Check, removing it from the ledger without sending any money.
+///
+/// See CheckCancel:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/checkcancel>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckCancel {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The ID of the Check ledger object to cancel.
+    #[serde(rename = "CheckID")]
+    pub check_id: AsHex<H256>,
+}
+
+ripple_tx!(CheckCancel);
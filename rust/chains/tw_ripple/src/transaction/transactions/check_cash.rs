@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::Amount;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H256;
+
+/// Attempts to redeem a Check object in the ledger to receive up to the amount authorized by the corresponding CheckCreate transaction.
+/// Exactly one of `Amount` or `DeliverMin` must be provided.
+///
+/// See CheckCash:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/checkcash>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckCash {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The ID of the Check ledger object to cash.
+    #[serde(rename = "CheckID")]
+    pub check_id: AsHex<H256>,
+    /// Redeem the Check for exactly this amount, if possible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Amount>,
+    /// Redeem the Check for at least this amount and for as much as possible.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliver_min: Option<Amount>,
+}
+
+ripple_tx!(CheckCash);
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::Amount;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H256;
+
+/// Creates a Check object in the ledger, which is a deferred payment that can be cashed by its intended destination.
+///
+/// See CheckCreate:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/checkcreate>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckCreate {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The unique address of the account that can cash the Check.
+    pub destination: ClassicAddress,
+    /// Maximum amount of source currency the Check is allowed to debit the sender, including transfer fees.
+    pub send_max: Amount,
+    /// Arbitrary tag that identifies the reason for the Check, or a hosted recipient to pay.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<u32>,
+    /// Time after which the Check is no longer valid, in seconds since the Ripple Epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+    /// Arbitrary 256-bit hash representing a specific reason or identifier for this Check.
+    #[serde(rename = "InvoiceID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_id: Option<AsHex<H256>>,
+}
+
+ripple_tx!(CheckCreate);
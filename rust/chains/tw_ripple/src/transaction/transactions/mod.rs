@@ -2,6 +2,16 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod account_delete;
+pub mod account_set;
+pub mod amm_bid;
+pub mod amm_create;
+pub mod amm_deposit;
+pub mod amm_vote;
+pub mod amm_withdraw;
+pub mod check_cancel;
+pub mod check_cash;
+pub mod check_create;
 pub mod escrow_cancel;
 pub mod escrow_create;
 pub mod escrow_finish;
@@ -9,5 +19,12 @@ pub mod nftoken_accept_offer;
 pub mod nftoken_burn;
 pub mod nftoken_cancel_offer;
 pub mod nftoken_create_offer;
+pub mod offer_cancel;
+pub mod offer_create;
 pub mod payment;
+pub mod payment_channel_claim;
+pub mod payment_channel_create;
+pub mod payment_channel_fund;
+pub mod set_regular_key;
+pub mod signer_list_set;
 pub mod trust_set;
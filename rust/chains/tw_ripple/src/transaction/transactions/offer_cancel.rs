@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+
+/// Removes an Offer object from the decentralized exchange.
+///
+/// See OfferCancel:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/offercancel>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OfferCancel {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The sequence number (or Ticket number) of a previous OfferCreate transaction.
+    pub offer_sequence: u32,
+}
+
+ripple_tx!(OfferCancel);
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::Amount;
+use serde::{Deserialize, Serialize};
+
+/// Places an Offer in the decentralized exchange.
+///
+/// See OfferCreate:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/offercreate>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OfferCreate {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The amount and type of currency being sold.
+    pub taker_gets: Amount,
+    /// The amount and type of currency being bought.
+    pub taker_pays: Amount,
+    /// Time after which the Offer is no longer active, in seconds since the Ripple Epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+    /// An Offer to delete first, specified in the same way as [`super::offer_cancel::OfferCancel`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offer_sequence: Option<u32>,
+}
+
+ripple_tx!(OfferCreate);
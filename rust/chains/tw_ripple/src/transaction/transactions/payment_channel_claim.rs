@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::native_amount::NativeAmount;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H256;
+use tw_hash::H264;
+use tw_memory::Data;
+
+/// Claims XRP from a payment channel, adjusts the payment channel's expiration, or both.
+///
+/// See PaymentChannelClaim:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/paymentchannelclaim>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentChannelClaim {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The unique ID of the channel.
+    pub channel: AsHex<H256>,
+    /// Total amount of XRP, in drops, delivered by this channel after processing this claim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<NativeAmount>,
+    /// The amount of XRP, in drops, authorized by the `Signature`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<NativeAmount>,
+    /// The signature of the off-ledger claim, as a DER-encoded secp256k1 signature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<AsHex<Data>>,
+    /// The public key used for the signature. This must match the `PublicKey` stored in the ledger for the channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<AsHex<H264>>,
+}
+
+ripple_tx!(PaymentChannelClaim);
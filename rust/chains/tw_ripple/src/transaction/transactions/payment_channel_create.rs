@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::native_amount::NativeAmount;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H264;
+
+/// Creates a payment channel and funds it.
+///
+/// See PaymentChannelCreate:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/paymentchannelcreate>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentChannelCreate {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// Amount of XRP, in drops, to deduct from the sender's balance and set aside in this channel.
+    pub amount: NativeAmount,
+    /// Address to receive XRP claims against this channel.
+    pub destination: ClassicAddress,
+    /// Amount of time the source address must wait before closing the channel if it has unclaimed XRP.
+    pub settle_delay: u32,
+    /// The public key of the key pair the source will use to sign claims against this channel.
+    pub public_key: AsHex<H264>,
+    /// The time, in seconds since the Ripple Epoch, when this channel expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_after: Option<u32>,
+    /// Arbitrary tag to further specify the destination for this payment channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_tag: Option<u32>,
+}
+
+ripple_tx!(PaymentChannelCreate);
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use crate::types::amount::native_amount::NativeAmount;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex::AsHex;
+use tw_hash::H256;
+
+/// Adds additional XRP to an open payment channel, and optionally updates the expiration time of the channel.
+/// Only the source address of the channel can use this transaction.
+///
+/// See PaymentChannelFund:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/paymentchannelfund>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentChannelFund {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// The unique ID of the channel to fund.
+    pub channel: AsHex<H256>,
+    /// Amount of XRP, in drops, to add to the channel.
+    pub amount: NativeAmount,
+    /// New expiration time to set for the channel, in seconds since the Ripple Epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+}
+
+ripple_tx!(PaymentChannelFund);
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+
+/// Assigns, changes, or removes the regular key pair associated with an account.
+///
+/// See SetRegularKey:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/setregularkey>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetRegularKey {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// A base-58-encoded Address that indicates the regular key pair to be assigned to the account.
+    /// If omitted, removes any existing regular key pair from the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regular_key: Option<ClassicAddress>,
+}
+
+ripple_tx!(SetRegularKey);
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+
+/// Create, replace, or remove a list of signers that can be used to multi-sign a transaction.
+///
+/// See SignerListSet:
+/// `<https://xrpl.org/docs/references/protocol/transactions/types/signerlistset>`
+///
+/// Unsupported fields:
+/// * WalletLocator - Arbitrary hex data that can be associated with a signer.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerListSet {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Types:
+    /// `<https://xrpl.org/transaction-types.html>`
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    /// The type of transaction.
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// A target number for the signer weights. A multi-signature from this list is valid
+    /// only if the sum weights of the signatures provided is greater than or equal to this value.
+    /// To delete a signer list, use the value 0.
+    pub signer_quorum: u32,
+    /// Array of signers who can contribute to a multi-signature.
+    /// Must be omitted if the signer list is deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer_entries: Option<Vec<SignerEntryWrapper>>,
+}
+
+ripple_tx!(SignerListSet);
+
+/// A signer who can contribute to a multi-signature.
+///
+/// See SignerEntry:
+/// `<https://xrpl.org/docs/references/protocol/ledger-data/ledger-entry-types/signerlist#signer-entry-object>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerEntry {
+    /// An XRP Ledger address whose signature contributes to the multi-signature.
+    pub account: ClassicAddress,
+    /// The weight of a signature from this signer.
+    pub signer_weight: u16,
+}
+
+/// `SignerEntries` array element is an object with the only `SignerEntry` field.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerEntryWrapper {
+    pub signer_entry: SignerEntry,
+}
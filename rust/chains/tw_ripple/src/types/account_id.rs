@@ -4,6 +4,8 @@
 
 use crate::address::classic_address::ClassicAddress;
 use crate::address::RippleAddress;
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
 use crate::encode::encoder::Encoder;
 use crate::encode::Encodable;
 use std::fmt;
@@ -24,6 +26,16 @@ impl Encodable for AccountId {
     }
 }
 
+impl Decodable for AccountId {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        let bytes: H160 = src.read()?;
+        ClassicAddress::new(bytes.as_slice())
+            .into_tw()
+            .context("Invalid AccountId")
+            .map(AccountId)
+    }
+}
+
 impl FromStr for AccountId {
     type Err = SigningError;
 
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
 use crate::encode::encoder::Encoder;
 use crate::encode::Encodable;
 use crate::types::account_id::AccountId;
@@ -9,6 +11,7 @@ use crate::types::amount::POS_SIGN_BIT_MASK;
 use crate::types::currency::Currency;
 use bigdecimal::{BigDecimal, Signed, ToPrimitive, Zero};
 use serde::{Deserialize, Serialize, Serializer};
+use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_hash::H64;
 use tw_misc::serde::as_string;
@@ -17,6 +20,10 @@ const ZERO_IC_VALUE: u64 = 0x8000000000000000;
 const MIN_MANTISSA: u128 = u128::pow(10, 15);
 const MAX_MANTISSA: u128 = u128::pow(10, 16) - 1;
 const MIN_IOU_EXPONENT: i32 = -96;
+/// Exponent is stored with the offset to be always positive.
+const IOU_EXPONENT_OFFSET: i32 = 97;
+const IOU_EXPONENT_MASK: u64 = 0xFF;
+const IOU_MANTISSA_BITS: u32 = 54;
 pub const MAX_IOU_EXPONENT: i32 = 80;
 pub const MAX_IOU_PRECISION: usize = 16;
 
@@ -94,6 +101,39 @@ impl IssuedCurrency {
             Ok(H64::from_array(bytes))
         }
     }
+
+    /// Reverse of [`IssuedCurrency::serialize_issued_currency_value`].
+    fn deserialize_issued_currency_value(serial: u64) -> SigningResult<BigDecimal> {
+        if serial == ZERO_IC_VALUE {
+            return Ok(BigDecimal::zero());
+        }
+        if serial & ZERO_IC_VALUE == 0 {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Expected Issued Currency amount");
+        }
+
+        let is_positive = serial & POS_SIGN_BIT_MASK as u64 != 0;
+        let exp = ((serial >> IOU_MANTISSA_BITS) & IOU_EXPONENT_MASK) as i32 - IOU_EXPONENT_OFFSET;
+        let mantissa = serial & ((1 << IOU_MANTISSA_BITS) - 1);
+
+        let sign = if is_positive { "" } else { "-" };
+        let value = BigDecimal::from_str(&format!("{sign}{mantissa}e{exp}"))
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Invalid Issued Currency amount")?;
+        verify_valid_ic_value(&value)?;
+        Ok(value)
+    }
+}
+
+impl Decodable for IssuedCurrency {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        let serial: u64 = src.read()?;
+        Ok(IssuedCurrency {
+            value: Self::deserialize_issued_currency_value(serial)?,
+            currency: src.read()?,
+            issuer: src.read()?,
+        })
+    }
 }
 
 impl Encodable for IssuedCurrency {
@@ -153,7 +193,6 @@ fn verify_valid_ic_value(ic_value: &BigDecimal) -> SigningResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::str::FromStr;
 
     #[track_caller]
     fn test_calculate_precision_impl(number: &str, precision: usize) {
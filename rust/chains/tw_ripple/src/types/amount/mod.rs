@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
 use crate::encode::encoder::Encoder;
 use crate::encode::Encodable;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,8 @@ use issued_currency::IssuedCurrency;
 use native_amount::NativeAmount;
 
 pub(crate) const POS_SIGN_BIT_MASK: i64 = 0x4000000000000000;
+/// The first bit of an amount is set if the amount is not XRP.
+pub(crate) const NOT_XRP_BIT_MASK: u8 = 0x80;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(untagged)]
@@ -43,6 +47,17 @@ impl Encodable for Amount {
     }
 }
 
+impl Decodable for Amount {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        let [first_byte] = src.peek_array().context("Error decoding 'Amount' type")?;
+        if first_byte & NOT_XRP_BIT_MASK == 0 {
+            src.read().map(Amount::NativeAmount)
+        } else {
+            src.read().map(Amount::IssuedCurrency)
+        }
+    }
+}
+
 impl FromStr for Amount {
     type Err = SigningError;
 
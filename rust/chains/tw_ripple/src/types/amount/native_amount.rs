@@ -2,9 +2,11 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
 use crate::encode::encoder::Encoder;
 use crate::encode::Encodable;
-use crate::types::amount::POS_SIGN_BIT_MASK;
+use crate::types::amount::{NOT_XRP_BIT_MASK, POS_SIGN_BIT_MASK};
 use bigdecimal::{BigDecimal, ToPrimitive};
 use std::fmt;
 use std::str::FromStr;
@@ -25,6 +27,10 @@ impl NativeAmount {
         }
         Ok(NativeAmount(amount))
     }
+
+    pub fn drops(&self) -> i64 {
+        self.0
+    }
 }
 
 serde_as_string!(NativeAmount);
@@ -37,6 +43,24 @@ impl Encodable for NativeAmount {
     }
 }
 
+impl Decodable for NativeAmount {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        let bytes: [u8; 8] = src.read_array()?;
+        if bytes[0] & NOT_XRP_BIT_MASK != 0 {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Expected XRP native amount");
+        }
+
+        let value = i64::from_be_bytes(bytes);
+        let amount = value & !POS_SIGN_BIT_MASK;
+        if value & POS_SIGN_BIT_MASK == 0 {
+            NativeAmount::new(-amount)
+        } else {
+            NativeAmount::new(amount)
+        }
+    }
+}
+
 impl fmt::Display for NativeAmount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
 use crate::encode::encoder::Encoder;
 use crate::encode::Encodable;
 use std::fmt;
@@ -77,6 +79,28 @@ impl Encodable for Currency {
     }
 }
 
+impl Decodable for Currency {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        let bytes: H160 = src.read()?;
+        if bytes == NATIVE_CODE_BYTES {
+            return Ok(Currency::Native);
+        }
+
+        // ISO-4217 currency code is the only non-zero bytes in the standard format.
+        let is_iso_format = bytes
+            .iter()
+            .enumerate()
+            .all(|(i, byte)| ISO_CODE_RANGE.contains(&i) || *byte == 0);
+        let iso = &bytes[ISO_CODE_RANGE];
+        if is_iso_format && iso.iter().all(u8::is_ascii_graphic) && iso != NATIVE_CODE.as_bytes() {
+            let iso = String::from_utf8(iso.to_vec()).expect("Expected ASCII characters");
+            return Ok(Currency::ISO(iso));
+        }
+
+        Ok(Currency::Custom(bytes))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
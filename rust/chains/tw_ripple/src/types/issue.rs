@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::decode::decoder::Decoder;
+use crate::decode::Decodable;
+use crate::encode::encoder::Encoder;
+use crate::encode::Encodable;
+use crate::types::account_id::AccountId;
+use crate::types::currency::Currency;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
+use tw_coin_entry::error::prelude::*;
+
+/// An asset without an amount, for example, one of the assets of an AMM pool.
+///
+/// See Specifying Without Amounts:
+/// `<https://xrpl.org/docs/references/protocol/data-types/currency-formats#specifying-without-amounts>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Issue {
+    pub currency: Currency,
+    /// Must be omitted if the currency is XRP.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<AccountId>,
+}
+
+impl Issue {
+    pub fn new(currency: Currency, issuer: Option<AccountId>) -> SigningResult<Issue> {
+        match (&currency, &issuer) {
+            (Currency::Native, Some(_)) => {
+                SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("XRP Issue must not have an issuer")
+            },
+            (Currency::ISO(_) | Currency::Custom(_), None) => {
+                SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Issued currency must have an issuer")
+            },
+            _ => Ok(Issue { currency, issuer }),
+        }
+    }
+}
+
+impl TryFrom<Json> for Issue {
+    type Error = SigningError;
+
+    fn try_from(value: Json) -> Result<Self, Self::Error> {
+        let Issue { currency, issuer } = serde_json::from_value(value)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error parsing 'Issue' type")?;
+        Issue::new(currency, issuer)
+    }
+}
+
+impl Encodable for Issue {
+    fn encode(&self, dst: &mut Encoder) -> SigningResult<()> {
+        self.currency.encode(dst)?;
+        if let Some(ref issuer) = self.issuer {
+            issuer.encode(dst)?;
+        }
+        Ok(())
+    }
+}
+
+impl Decodable for Issue {
+    fn decode(src: &mut Decoder) -> SigningResult<Self> {
+        let currency: Currency = src.read()?;
+        let issuer = match currency {
+            Currency::Native => None,
+            Currency::ISO(_) | Currency::Custom(_) => Some(src.read()?),
+        };
+        Issue::new(currency, issuer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tw_encoding::hex::ToHex;
+
+    fn test_encode_issue_impl(input: Json, expected: &str) {
+        let mut encoder = Encoder::default();
+
+        let issue = Issue::try_from(input).unwrap();
+        issue.encode(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().to_hex(), expected);
+    }
+
+    #[test]
+    fn test_encode_issue() {
+        test_encode_issue_impl(
+            json!({"currency": "XRP"}),
+            "0000000000000000000000000000000000000000",
+        );
+        test_encode_issue_impl(
+            json!({"currency": "USD", "issuer": "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq"}),
+            "00000000000000000000000055534400000000004c55f5a78067206507580be7bb2686c8460adff9",
+        );
+    }
+
+    #[test]
+    fn test_issue_invalid() {
+        Issue::try_from(json!({"currency": "XRP", "issuer": "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq"}))
+            .unwrap_err();
+        Issue::try_from(json!({"currency": "USD"})).unwrap_err();
+    }
+}
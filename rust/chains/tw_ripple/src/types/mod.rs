@@ -6,4 +6,5 @@ pub mod account_id;
 pub mod amount;
 pub mod blob;
 pub mod currency;
+pub mod issue;
 pub mod vector256;
@@ -64,12 +64,24 @@ impl SolanaAddress {
         seeds: &[&[u8]],
         program_id: SolanaAddress,
     ) -> Option<SolanaAddress> {
+        Self::find_program_address_with_bump(seeds, program_id).map(|(address, _bump)| address)
+    }
+
+    /// Find a valid [program derived address][pda] like [`find_program_address`] does,
+    /// and return the bump seed along with the address.
+    ///
+    /// [pda]: https://solana.com/docs/core/cpi#program-derived-addresses
+    /// [`find_program_address`]: SolanaAddress::find_program_address
+    pub fn find_program_address_with_bump(
+        seeds: &[&[u8]],
+        program_id: SolanaAddress,
+    ) -> Option<(SolanaAddress, u8)> {
         let mut bump_seed = [u8::MAX];
         for _ in 0..u8::MAX {
             let mut seeds_with_bump = seeds.to_vec();
             seeds_with_bump.push(&bump_seed);
             match Self::create_program_address(&seeds_with_bump, program_id) {
-                Ok(Some(address)) => return Some(address),
+                Ok(Some(address)) => return Some((address, bump_seed[0])),
                 // Try to re-compute the program address with a different seed.
                 Ok(None) => (),
                 Err(_) => return None,
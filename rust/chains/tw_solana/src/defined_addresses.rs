@@ -26,3 +26,4 @@ define!(SYSVAR_STAKE_HISTORY_ID_ADDRESS = "SysvarStakeHistory1111111111111111111
 define!(MEMO_PROGRAM_ID_ADDRESS = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
 define!(SYSVAR_RECENT_BLOCKHASHS_ADDRESS = "SysvarRecentB1ockHashes11111111111111111111");
 define!(COMPUTE_BUDGET_ADDRESS = "ComputeBudget111111111111111111111111111111");
+define!(ADDRESS_LOOKUP_TABLE_PROGRAM_ID_ADDRESS = "AddressLookupTab1e1111111111111111111111111");
@@ -6,6 +6,9 @@
 
 use crate::address::SolanaAddress;
 use crate::instruction::Instruction;
+use crate::transaction::v0::{
+    AddressLookupTableAccount, LoadedAddresses, MessageAddressTableLookup,
+};
 use crate::transaction::MessageHeader;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -21,6 +24,7 @@ pub fn try_into_u8(num: usize) -> SigningResult<u8> {
 struct CompiledKeyMeta {
     is_signer: bool,
     is_writable: bool,
+    is_invoked: bool,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -38,6 +42,7 @@ impl CompiledKeys {
             CompiledKeyMeta {
                 is_signer: true,
                 is_writable: true,
+                is_invoked: false,
             },
         );
         // Fee payer must be the first account in the keys list.
@@ -66,12 +71,78 @@ impl CompiledKeys {
             if matches!(meta_entry, Entry::Vacant(_)) {
                 self.ordered_keys.push(ix.program_id);
             }
-            meta_entry.or_default();
+            meta_entry.or_default().is_invoked = true;
         }
 
         self
     }
 
+    /// Moves the accounts that can be loaded from the given lookup table out of the static account keys.
+    /// Returns `None` if no accounts can be loaded from the table.
+    ///
+    /// https://github.com/solana-labs/solana/blob/4b65cc8eef6ef79cb9b9cbc534a99b4900e58cf7/sdk/program/src/message/compiled_keys.rs#L80-L106
+    pub fn try_extract_table_lookup(
+        &mut self,
+        lookup_table_account: &AddressLookupTableAccount,
+    ) -> SigningResult<Option<(MessageAddressTableLookup, LoadedAddresses)>> {
+        let (writable_indexes, drained_writable_keys) = self
+            .try_drain_keys_found_in_lookup_table(&lookup_table_account.addresses, |meta| {
+                !meta.is_signer && !meta.is_invoked && meta.is_writable
+            })?;
+        let (readonly_indexes, drained_readonly_keys) = self
+            .try_drain_keys_found_in_lookup_table(&lookup_table_account.addresses, |meta| {
+                !meta.is_signer && !meta.is_invoked && !meta.is_writable
+            })?;
+
+        // Don't extract lookup if no keys were found
+        if writable_indexes.is_empty() && readonly_indexes.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some((
+            MessageAddressTableLookup {
+                account_key: lookup_table_account.key,
+                writable_indexes,
+                readonly_indexes,
+            },
+            LoadedAddresses {
+                writable: drained_writable_keys,
+                readonly: drained_readonly_keys,
+            },
+        )))
+    }
+
+    fn try_drain_keys_found_in_lookup_table<F>(
+        &mut self,
+        lookup_table_addresses: &[SolanaAddress],
+        key_meta_filter: F,
+    ) -> SigningResult<(Vec<u8>, Vec<SolanaAddress>)>
+    where
+        F: Fn(&CompiledKeyMeta) -> bool,
+    {
+        let mut lookup_table_indexes = Vec::new();
+        let mut drained_keys = Vec::new();
+        let mut retained_keys = Vec::with_capacity(self.ordered_keys.len());
+
+        for key in std::mem::take(&mut self.ordered_keys) {
+            let meta = self.key_meta_map.get(&key).copied().unwrap_or_default();
+            let lookup_index = lookup_table_addresses.iter().position(|addr| *addr == key);
+
+            match lookup_index {
+                Some(index) if key_meta_filter(&meta) => {
+                    let index = try_into_u8(index).context("Lookup table index overflow")?;
+                    lookup_table_indexes.push(index);
+                    self.key_meta_map.remove(&key);
+                    drained_keys.push(key);
+                },
+                _ => retained_keys.push(key),
+            }
+        }
+
+        self.ordered_keys = retained_keys;
+        Ok((lookup_table_indexes, drained_keys))
+    }
+
     pub fn try_into_message_components(self) -> SigningResult<(MessageHeader, Vec<SolanaAddress>)> {
         let Self {
             ordered_keys,
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::*;
+use crate::instruction::{AccountMeta, Instruction};
+use serde::{Deserialize, Serialize};
+
+/// Instructions supported by the address lookup table program.
+/// https://github.com/solana-labs/solana/blob/4b65cc8eef6ef79cb9b9cbc534a99b4900e58cf7/sdk/program/src/address_lookup_table/instruction.rs
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AddressLookupTableInstruction {
+    /// Create an address lookup table
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Uninitialized address lookup table account
+    ///   1. `[SIGNER]` Account used to derive and control the new address lookup table.
+    ///   2. `[SIGNER, WRITE]` Account that will fund the new address lookup table.
+    ///   3. `[]` System program for CPI.
+    CreateLookupTable {
+        /// A recent slot must be used in the derivation path
+        /// for each initialized table. When closing table accounts,
+        /// the initialization slot must no longer be "recent" to prevent
+        /// address tables from being recreated with reordered or
+        /// otherwise malicious addresses.
+        recent_slot: u64,
+        /// Address tables are always initialized at program-derived
+        /// addresses using the funding address, recent blockhash, and
+        /// the user-passed `bump_seed`.
+        bump_seed: u8,
+    },
+
+    /// Permanently freeze an address lookup table, making it immutable.
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Address lookup table account to freeze
+    ///   1. `[SIGNER]` Current authority
+    FreezeLookupTable,
+
+    /// Extend an address lookup table with new addresses. Funding account and
+    /// system program account references are only required if the lookup table
+    /// account requires additional lamports to cover the rent-exempt balance
+    /// after being extended.
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Address lookup table account to extend
+    ///   1. `[SIGNER]` Current authority
+    ///   2. `[SIGNER, WRITE, OPTIONAL]` Account that will fund the table reallocation
+    ///   3. `[OPTIONAL]` System program for CPI.
+    ExtendLookupTable { new_addresses: Vec<SolanaAddress> },
+
+    /// Deactivate an address lookup table, making it unusable and
+    /// eligible for closure after a short period of time.
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Address lookup table account to deactivate
+    ///   1. `[SIGNER]` Current authority
+    DeactivateLookupTable,
+
+    /// Close an address lookup table account
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Address lookup table account to close
+    ///   1. `[SIGNER]` Current authority
+    ///   2. `[WRITE]` Recipient of closed account lamports
+    CloseLookupTable,
+}
+
+pub struct AddressLookupTableInstructionBuilder;
+
+impl AddressLookupTableInstructionBuilder {
+    /// Please note the `lookup_table` address and `bump_seed` must be derived from the `authority` and `recent_slot`.
+    /// See [`AddressLookupTableProgram::derive_lookup_table_address`](crate::program::address_lookup_table_program::AddressLookupTableProgram::derive_lookup_table_address).
+    pub fn create_lookup_table(
+        lookup_table: SolanaAddress,
+        authority: SolanaAddress,
+        payer: SolanaAddress,
+        recent_slot: u64,
+        bump_seed: u8,
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(lookup_table, false),
+            // The authority is not required to sign since the `relax_authority_signer_check_for_lookup_table_creation` feature,
+            // but it's still signed for compatibility, and because the signer private key is always provided.
+            AccountMeta::readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::readonly(*SYSTEM_PROGRAM_ID_ADDRESS, false),
+        ];
+        Instruction::new_with_bincode(
+            *ADDRESS_LOOKUP_TABLE_PROGRAM_ID_ADDRESS,
+            AddressLookupTableInstruction::CreateLookupTable {
+                recent_slot,
+                bump_seed,
+            },
+            account_metas,
+        )
+    }
+
+    pub fn extend_lookup_table(
+        lookup_table: SolanaAddress,
+        authority: SolanaAddress,
+        payer: SolanaAddress,
+        new_addresses: Vec<SolanaAddress>,
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(lookup_table, false),
+            AccountMeta::readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::readonly(*SYSTEM_PROGRAM_ID_ADDRESS, false),
+        ];
+        Instruction::new_with_bincode(
+            *ADDRESS_LOOKUP_TABLE_PROGRAM_ID_ADDRESS,
+            AddressLookupTableInstruction::ExtendLookupTable { new_addresses },
+            account_metas,
+        )
+    }
+
+    pub fn deactivate_lookup_table(
+        lookup_table: SolanaAddress,
+        authority: SolanaAddress,
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(lookup_table, false),
+            AccountMeta::readonly(authority, true),
+        ];
+        Instruction::new_with_bincode(
+            *ADDRESS_LOOKUP_TABLE_PROGRAM_ID_ADDRESS,
+            AddressLookupTableInstruction::DeactivateLookupTable,
+            account_metas,
+        )
+    }
+
+    pub fn close_lookup_table(
+        lookup_table: SolanaAddress,
+        authority: SolanaAddress,
+        recipient: SolanaAddress,
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(lookup_table, false),
+            AccountMeta::readonly(authority, true),
+            AccountMeta::new(recipient, false),
+        ];
+        Instruction::new_with_bincode(
+            *ADDRESS_LOOKUP_TABLE_PROGRAM_ID_ADDRESS,
+            AddressLookupTableInstruction::CloseLookupTable,
+            account_metas,
+        )
+    }
+}
@@ -5,6 +5,7 @@
 use crate::address::SolanaAddress;
 use crate::instruction::Instruction;
 
+pub mod address_lookup_table_instruction;
 pub mod compute_budget_instruction;
 pub mod stake_instruction;
 pub mod system_instruction;
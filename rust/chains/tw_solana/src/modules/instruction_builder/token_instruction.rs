@@ -40,6 +40,19 @@ pub enum TokenInstruction {
         /// Expected number of base 10 digits to the right of the decimal place.
         decimals: u8,
     },
+    /// Transfer, providing expected mint information and fees.
+    /// Supported by the Token-2022 program only, for mints with the `TransferFeeConfig` extension.
+    ///
+    /// Accounts expected by this instruction are the same as for [`TokenInstruction::TransferChecked`].
+    TransferCheckedWithFee {
+        /// The amount of tokens to transfer.
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// Expected fee assessed on this transfer, calculated off-chain based
+        /// on the transfer_fee_basis_points and maximum_fee of the mint.
+        fee: u64,
+    },
 }
 
 impl TokenInstruction {
@@ -60,6 +73,19 @@ impl TokenInstruction {
                 buf.extend_from_slice(&amount.to_le_bytes());
                 buf.push(decimals);
             },
+            &Self::TransferCheckedWithFee {
+                amount,
+                decimals,
+                fee,
+            } => {
+                // https://github.com/solana-labs/solana-program-library/blob/5418cf9b90d5c9ff5bff9f55fd17651f66c98902/token/program-2022/src/extension/transfer_fee/instruction.rs#L146-L151
+                // `TransferFeeExtension` instruction followed by the `TransferCheckedWithFee` sub-instruction.
+                buf.push(26);
+                buf.push(1);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.push(decimals);
+                buf.extend_from_slice(&fee.to_le_bytes());
+            },
         };
         buf
     }
@@ -109,4 +135,32 @@ impl TokenInstructionBuilder {
         let data = TokenInstruction::TransferChecked { amount, decimals }.pack();
         Instruction::new(token_program_id, data, account_metas)
     }
+
+    /// transfer_checked_with_fee() solana-program-library/token/program-2022/src/extension/transfer_fee/instruction.rs
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_checked_with_fee(
+        sender_token_pubkey: SolanaAddress,
+        token_mint_pubkey: SolanaAddress,
+        recipient_token_pubkey: SolanaAddress,
+        signer: SolanaAddress,
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+        token_program_id: SolanaAddress,
+    ) -> Instruction {
+        let account_metas = vec![
+            AccountMeta::new(sender_token_pubkey, false),
+            AccountMeta::readonly(token_mint_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(signer, true),
+        ];
+
+        let data = TokenInstruction::TransferCheckedWithFee {
+            amount,
+            decimals,
+            fee,
+        }
+        .pack();
+        Instruction::new(token_program_id, data, account_metas)
+    }
 }
@@ -8,6 +8,7 @@ use crate::defined_addresses::{TOKEN_2022_PROGRAM_ID_ADDRESS, TOKEN_PROGRAM_ID_A
 use crate::instruction::Instruction;
 use crate::modules::compiled_instructions::compile_instructions;
 use crate::modules::compiled_keys::CompiledKeys;
+use crate::modules::instruction_builder::address_lookup_table_instruction::AddressLookupTableInstructionBuilder;
 use crate::modules::instruction_builder::compute_budget_instruction::{UnitLimit, UnitPrice};
 use crate::modules::instruction_builder::stake_instruction::{
     DepositStakeArgs, StakeInstructionBuilder,
@@ -16,6 +17,8 @@ use crate::modules::instruction_builder::system_instruction::SystemInstructionBu
 use crate::modules::instruction_builder::token_instruction::TokenInstructionBuilder;
 use crate::modules::instruction_builder::InstructionBuilder;
 use crate::modules::PubkeySignatureMap;
+use crate::program::address_lookup_table_program::AddressLookupTableProgram;
+use crate::transaction::v0::{AddressLookupTableAccount, LoadedAddresses};
 use crate::transaction::versioned::VersionedMessage;
 use crate::transaction::{legacy, v0, CompiledInstruction, MessageHeader, Signature};
 use std::borrow::Cow;
@@ -76,23 +79,53 @@ impl<'a> MessageBuilder<'a> {
         }
 
         let instructions = self.build_instructions()?;
+        let lookup_tables = self.address_lookup_tables()?;
 
         // Please note the fee payer can be different from the actual signer.
-        let (message_header, account_keys) = CompiledKeys::with_fee_payer(self.fee_payer()?)
-            .compile(&instructions)
-            .try_into_message_components()?;
-
-        let compiled_instructions = compile_instructions(&instructions, &account_keys)?;
+        let mut compiled_keys =
+            CompiledKeys::with_fee_payer(self.fee_payer()?).compile(&instructions);
 
         if self.input.v0_msg {
+            let mut address_table_lookups = Vec::with_capacity(lookup_tables.len());
+            let mut loaded_addresses = LoadedAddresses::default();
+            for lookup_table in lookup_tables.iter() {
+                if let Some((lookup, loaded)) =
+                    compiled_keys.try_extract_table_lookup(lookup_table)?
+                {
+                    address_table_lookups.push(lookup);
+                    loaded_addresses.writable.extend(loaded.writable);
+                    loaded_addresses.readonly.extend(loaded.readonly);
+                }
+            }
+
+            let (message_header, account_keys) = compiled_keys.try_into_message_components()?;
+
+            // Account indexes point to the concatenation of the static account keys,
+            // the writable and then the readonly loaded addresses.
+            let all_keys: Vec<_> = account_keys
+                .iter()
+                .chain(loaded_addresses.writable.iter())
+                .chain(loaded_addresses.readonly.iter())
+                .copied()
+                .collect();
+            let compiled_instructions = compile_instructions(&instructions, &all_keys)?;
+
             Ok(VersionedMessage::V0(v0::Message {
                 header: message_header,
                 account_keys,
                 recent_blockhash: self.recent_blockhash()?.to_bytes(),
                 instructions: compiled_instructions,
-                address_table_lookups: Vec::default(),
+                address_table_lookups,
             }))
         } else {
+            if !lookup_tables.is_empty() {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Address lookup tables can be used in v0 messages only");
+            }
+
+            let (message_header, account_keys) = compiled_keys.try_into_message_components()?;
+            let compiled_instructions = compile_instructions(&instructions, &account_keys)?;
+
             Ok(VersionedMessage::Legacy(legacy::Message {
                 header: message_header,
                 account_keys,
@@ -141,6 +174,18 @@ impl<'a> MessageBuilder<'a> {
             ProtoTransactionType::advance_nonce_account(ref advance_nonce) => {
                 self.advance_nonce_from_proto(advance_nonce)
             },
+            ProtoTransactionType::create_lookup_table(ref create_lookup_table) => {
+                self.create_lookup_table_from_proto(create_lookup_table)
+            },
+            ProtoTransactionType::extend_lookup_table(ref extend_lookup_table) => {
+                self.extend_lookup_table_from_proto(extend_lookup_table)
+            },
+            ProtoTransactionType::deactivate_lookup_table(ref deactivate_lookup_table) => {
+                self.deactivate_lookup_table_from_proto(deactivate_lookup_table)
+            },
+            ProtoTransactionType::close_lookup_table(ref close_lookup_table) => {
+                self.close_lookup_table_from_proto(close_lookup_table)
+            },
             ProtoTransactionType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("No transaction type specified"),
         }
@@ -372,16 +417,42 @@ impl<'a> MessageBuilder<'a> {
             .tw_err(SigningErrorType::Error_invalid_params)
             .context("Invalid token decimals. Expected lower than 256")?;
 
+        if token_transfer.memo_required {
+            require_token_2022_program(token_transfer.token_program_id)
+                .context("Required memo transfers are supported by Token-2022 program only")?;
+            if token_transfer.memo.is_empty() {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Recipient token account requires a memo for incoming transfers");
+            }
+        }
+
         let references = Self::parse_references(&token_transfer.references)?;
-        let transfer_instruction = TokenInstructionBuilder::transfer_checked(
-            sender_token_address,
-            token_mint_address,
-            recipient_token_address,
-            signer,
-            token_transfer.amount,
-            decimals,
-            match_program_id(token_transfer.token_program_id),
-        )
+        let token_program_id = match_program_id(token_transfer.token_program_id);
+        let transfer_instruction = match token_transfer.transfer_fee {
+            Some(ref transfer_fee) => {
+                require_token_2022_program(token_transfer.token_program_id)
+                    .context("Transfer fees are supported by Token-2022 program only")?;
+                TokenInstructionBuilder::transfer_checked_with_fee(
+                    sender_token_address,
+                    token_mint_address,
+                    recipient_token_address,
+                    signer,
+                    token_transfer.amount,
+                    decimals,
+                    transfer_fee.fee,
+                    token_program_id,
+                )
+            },
+            None => TokenInstructionBuilder::transfer_checked(
+                sender_token_address,
+                token_mint_address,
+                recipient_token_address,
+                signer,
+                token_transfer.amount,
+                decimals,
+                token_program_id,
+            ),
+        }
         .with_references(references);
 
         let mut builder = InstructionBuilder::default();
@@ -429,23 +500,40 @@ impl<'a> MessageBuilder<'a> {
             .tw_err(SigningErrorType::Error_invalid_params)
             .context("Invalid token decimals. Expected lower than 256")?;
 
+        let token_program_id = match_program_id(create_and_transfer.token_program_id);
         let create_account_instruction = TokenInstructionBuilder::create_account(
             // Can be different from the actual signer.
             fee_payer,
             recipient_main_address,
             token_mint_address,
             recipient_token_address,
-            match_program_id(create_and_transfer.token_program_id),
+            token_program_id,
         );
-        let transfer_instruction = TokenInstructionBuilder::transfer_checked(
-            sender_token_address,
-            token_mint_address,
-            recipient_token_address,
-            signer,
-            create_and_transfer.amount,
-            decimals,
-            match_program_id(create_and_transfer.token_program_id),
-        )
+        let transfer_instruction = match create_and_transfer.transfer_fee {
+            Some(ref transfer_fee) => {
+                require_token_2022_program(create_and_transfer.token_program_id)
+                    .context("Transfer fees are supported by Token-2022 program only")?;
+                TokenInstructionBuilder::transfer_checked_with_fee(
+                    sender_token_address,
+                    token_mint_address,
+                    recipient_token_address,
+                    signer,
+                    create_and_transfer.amount,
+                    decimals,
+                    transfer_fee.fee,
+                    token_program_id,
+                )
+            },
+            None => TokenInstructionBuilder::transfer_checked(
+                sender_token_address,
+                token_mint_address,
+                recipient_token_address,
+                signer,
+                create_and_transfer.amount,
+                decimals,
+                token_program_id,
+            ),
+        }
         .with_references(references);
 
         let mut builder = InstructionBuilder::default();
@@ -537,6 +625,147 @@ impl<'a> MessageBuilder<'a> {
         Ok(builder.output())
     }
 
+    fn create_lookup_table_from_proto(
+        &self,
+        create_lookup_table: &Proto::CreateLookupTable,
+    ) -> SigningResult<Vec<Instruction>> {
+        let authority = self.signer_address()?;
+        let payer = self.fee_payer()?;
+
+        let (lookup_table, bump_seed) = AddressLookupTableProgram::derive_lookup_table_address(
+            authority,
+            create_lookup_table.recent_slot,
+        )
+        .into_tw()
+        .context("Error deriving lookup table address")?;
+
+        let instruction = AddressLookupTableInstructionBuilder::create_lookup_table(
+            lookup_table,
+            authority,
+            payer,
+            create_lookup_table.recent_slot,
+            bump_seed,
+        );
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, authority)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(instruction);
+        Ok(builder.output())
+    }
+
+    fn extend_lookup_table_from_proto(
+        &self,
+        extend_lookup_table: &Proto::ExtendLookupTable,
+    ) -> SigningResult<Vec<Instruction>> {
+        let authority = self.signer_address()?;
+        let payer = self.fee_payer()?;
+        let lookup_table =
+            Self::parse_lookup_table_address(&extend_lookup_table.lookup_table_address)?;
+
+        if extend_lookup_table.new_addresses.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("At least one address must be added to the lookup table");
+        }
+        let new_addresses = extend_lookup_table
+            .new_addresses
+            .iter()
+            .map(|addr| SolanaAddress::from_str(addr).map_err(SigningError::from))
+            .collect::<SigningResult<Vec<_>>>()
+            .context("Invalid lookup table address(es) to add")?;
+
+        let instruction = AddressLookupTableInstructionBuilder::extend_lookup_table(
+            lookup_table,
+            authority,
+            payer,
+            new_addresses,
+        );
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, authority)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(instruction);
+        Ok(builder.output())
+    }
+
+    fn deactivate_lookup_table_from_proto(
+        &self,
+        deactivate_lookup_table: &Proto::DeactivateLookupTable,
+    ) -> SigningResult<Vec<Instruction>> {
+        let authority = self.signer_address()?;
+        let lookup_table =
+            Self::parse_lookup_table_address(&deactivate_lookup_table.lookup_table_address)?;
+
+        let instruction =
+            AddressLookupTableInstructionBuilder::deactivate_lookup_table(lookup_table, authority);
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, authority)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(instruction);
+        Ok(builder.output())
+    }
+
+    fn close_lookup_table_from_proto(
+        &self,
+        close_lookup_table: &Proto::CloseLookupTable,
+    ) -> SigningResult<Vec<Instruction>> {
+        let authority = self.signer_address()?;
+        let lookup_table =
+            Self::parse_lookup_table_address(&close_lookup_table.lookup_table_address)?;
+
+        let recipient = if close_lookup_table.recipient.is_empty() {
+            authority
+        } else {
+            SolanaAddress::from_str(close_lookup_table.recipient.as_ref())
+                .into_tw()
+                .context("Invalid recipient")?
+        };
+
+        let instruction = AddressLookupTableInstructionBuilder::close_lookup_table(
+            lookup_table,
+            authority,
+            recipient,
+        );
+
+        let mut builder = InstructionBuilder::default();
+        builder
+            .maybe_advance_nonce(self.nonce_account()?, authority)
+            .maybe_priority_fee_price(self.priority_fee_price())
+            .maybe_priority_fee_limit(self.priority_fee_limit())
+            .add_instruction(instruction);
+        Ok(builder.output())
+    }
+
+    fn address_lookup_tables(&self) -> SigningResult<Vec<AddressLookupTableAccount>> {
+        self.input
+            .address_lookup_tables
+            .iter()
+            .map(|table| {
+                let key = Self::parse_lookup_table_address(&table.account_key)?;
+                let addresses = table
+                    .addresses
+                    .iter()
+                    .map(|addr| SolanaAddress::from_str(addr).map_err(SigningError::from))
+                    .collect::<SigningResult<Vec<_>>>()
+                    .context("Invalid address lookup table content")?;
+                Ok(AddressLookupTableAccount { key, addresses })
+            })
+            .collect()
+    }
+
+    fn parse_lookup_table_address(lookup_table: &str) -> SigningResult<SolanaAddress> {
+        SolanaAddress::from_str(lookup_table)
+            .into_tw()
+            .context("Invalid lookup table address")
+    }
+
     fn nonce_account(&self) -> SigningResult<Option<SolanaAddress>> {
         if self.input.nonce_account.is_empty() {
             Ok(None)
@@ -781,3 +1010,12 @@ fn match_program_id(program_id: Proto::TokenProgramId) -> SolanaAddress {
         Proto::TokenProgramId::Token2022Program => *TOKEN_2022_PROGRAM_ID_ADDRESS,
     }
 }
+
+fn require_token_2022_program(program_id: Proto::TokenProgramId) -> SigningResult<()> {
+    match program_id {
+        Proto::TokenProgramId::Token2022Program => Ok(()),
+        Proto::TokenProgramId::TokenProgram => {
+            SigningError::err(SigningErrorType::Error_invalid_params)
+        },
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::*;
+use tw_coin_entry::error::prelude::*;
+
+pub struct AddressLookupTableProgram;
+
+impl AddressLookupTableProgram {
+    /// Derives the address of a lookup table owned by the `authority` and created at the `recent_slot`.
+    /// Returns the address and its bump seed.
+    ///
+    /// https://github.com/solana-labs/solana/blob/4b65cc8eef6ef79cb9b9cbc534a99b4900e58cf7/sdk/program/src/address_lookup_table/instruction.rs#L65-L73
+    pub fn derive_lookup_table_address(
+        authority: SolanaAddress,
+        recent_slot: u64,
+    ) -> AddressResult<(SolanaAddress, u8)> {
+        SolanaAddress::find_program_address_with_bump(
+            &[
+                authority.bytes().as_slice(),
+                recent_slot.to_le_bytes().as_slice(),
+            ],
+            *ADDRESS_LOOKUP_TABLE_PROGRAM_ID_ADDRESS,
+        )
+        .ok_or(AddressError::InvalidInput)
+    }
+}
@@ -2,4 +2,5 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod address_lookup_table_program;
 pub mod stake_program;
@@ -21,6 +21,25 @@ pub struct MessageAddressTableLookup {
     pub readonly_indexes: Vec<u8>,
 }
 
+/// The content of an on-chain address lookup table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressLookupTableAccount {
+    /// Address lookup table account key
+    pub key: SolanaAddress,
+    /// Addresses stored in the lookup table
+    pub addresses: Vec<SolanaAddress>,
+}
+
+/// Collection of addresses loaded from on-chain lookup tables, split
+/// by readonly and writable.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LoadedAddresses {
+    /// List of addresses for writable loaded accounts
+    pub writable: Vec<SolanaAddress>,
+    /// List of addresses for read-only loaded accounts
+    pub readonly: Vec<SolanaAddress>,
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Message {
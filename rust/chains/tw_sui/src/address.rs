@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::signature::SignatureScheme;
 use move_core_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -13,11 +14,6 @@ use tw_hash::blake2::blake2_b;
 use tw_keypair::ed25519;
 use tw_memory::Data;
 
-#[repr(u8)]
-pub enum Scheme {
-    Ed25519 = 0,
-}
-
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct SuiAddress(AccountAddress);
 
@@ -26,11 +22,15 @@ impl SuiAddress {
 
     /// Initializes an address with a `ed25519` public key.
     pub fn with_ed25519_pubkey(pubkey: &ed25519::sha512::PublicKey) -> AddressResult<Self> {
-        const CAPACITY: usize = ed25519::sha512::PublicKey::LEN + 1;
+        Self::with_scheme_data(SignatureScheme::ED25519, pubkey.as_slice())
+    }
 
-        let mut to_hash = Vec::with_capacity(CAPACITY);
-        to_hash.push(Scheme::Ed25519 as u8);
-        to_hash.extend_from_slice(pubkey.as_slice());
+    /// Initializes an address as `blake2b(flag || data)`,
+    /// where `data` is a public key or an authenticator specific data.
+    pub fn with_scheme_data(scheme: SignatureScheme, data: &[u8]) -> AddressResult<Self> {
+        let mut to_hash = Vec::with_capacity(data.len() + 1);
+        to_hash.push(scheme.flag());
+        to_hash.extend_from_slice(data);
         let hashed =
             blake2_b(to_hash.as_slice(), SuiAddress::LENGTH).map_err(|_| AddressError::Internal)?;
 
@@ -2,8 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::modules::tx_builder::{TWTransaction, TWTransactionBuilder};
+use crate::modules::tx_builder::{SignerAccount, TWTransactionBuilder};
 use crate::modules::tx_signer::{TransactionPreimage, TxSigner};
+use crate::multisig::MultisigPublicKey;
 use crate::signature::SuiSignatureInfo;
 use std::borrow::Cow;
 use tw_coin_entry::coin_context::CoinContext;
@@ -12,6 +13,7 @@ use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
 use tw_encoding::base64::{self, STANDARD};
+use tw_hash::H512;
 use tw_keypair::ed25519;
 use tw_proto::Sui::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -33,16 +35,11 @@ impl SuiCompiler {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
         let builder = TWTransactionBuilder::new(input);
-        let tx_to_sign = builder.build()?;
-
         let TransactionPreimage {
             tx_data_to_sign,
             tx_hash_to_sign,
             ..
-        } = match tx_to_sign {
-            TWTransaction::Transaction(tx) => TxSigner::preimage(&tx)?,
-            TWTransaction::SignDirect(tx_data) => TxSigner::preimage_direct(tx_data)?,
-        };
+        } = TxSigner::preimage_tw(builder.build()?)?;
 
         Ok(CompilerProto::PreSigningOutput {
             data: Cow::from(tx_data_to_sign),
@@ -69,15 +66,36 @@ impl SuiCompiler {
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let builder = TWTransactionBuilder::new(input);
-        let tx_to_sign = builder.build()?;
-
+        let signer_account = builder.signer_account()?;
         let TransactionPreimage {
             unsigned_tx_data, ..
-        } = match tx_to_sign {
-            TWTransaction::Transaction(tx) => TxSigner::preimage(&tx),
-            TWTransaction::SignDirect(tx_data) => TxSigner::preimage_direct(tx_data),
-        }?;
+        } = TxSigner::preimage_tw(builder.build()?)?;
+
+        let signature = match signer_account {
+            SignerAccount::Single => {
+                Self::ed25519_signature_info(signatures, public_keys)?.to_base64()
+            },
+            SignerAccount::Multisig(multisig) => {
+                Self::combine_multisig(&multisig, signatures, public_keys)?
+            },
+            SignerAccount::ZkLogin(zk_login) => {
+                let user_signature = Self::ed25519_signature_info(signatures, public_keys)?;
+                zk_login.signature_base64(&user_signature)?
+            },
+        };
+
+        let unsigned_tx = base64::encode(&unsigned_tx_data, STANDARD);
+        Ok(Proto::SigningOutput {
+            unsigned_tx: Cow::from(unsigned_tx),
+            signature: Cow::from(signature),
+            ..Proto::SigningOutput::default()
+        })
+    }
 
+    fn ed25519_signature_info(
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<SuiSignatureInfo> {
         let SingleSignaturePubkey {
             signature: raw_signature,
             public_key: public_key_bytes,
@@ -86,13 +104,38 @@ impl SuiCompiler {
         let signature = ed25519::Signature::try_from(raw_signature.as_slice())?;
         let public_key = ed25519::sha512::PublicKey::try_from(public_key_bytes.as_slice())?;
 
-        let signature_info = SuiSignatureInfo::ed25519(&signature, &public_key);
+        Ok(SuiSignatureInfo::ed25519(&signature, &public_key))
+    }
 
-        let unsigned_tx = base64::encode(&unsigned_tx_data, STANDARD);
-        Ok(Proto::SigningOutput {
-            unsigned_tx: Cow::from(unsigned_tx),
-            signature: Cow::from(signature_info.to_base64()),
-            ..Proto::SigningOutput::default()
-        })
+    /// Combines signatures of the multisig members.
+    /// Every public key must be either a 32-byte `ed25519` or a 33-byte compressed `ecdsa` key.
+    fn combine_multisig(
+        multisig: &MultisigPublicKey,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<String> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Number of signatures and public keys must be equal");
+        }
+
+        let member_signatures = signatures
+            .into_iter()
+            .zip(public_keys)
+            .map(|(signature, public_key)| {
+                let member = multisig
+                    .members()
+                    .iter()
+                    .find(|member| member.public_key.to_bytes() == public_key)
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Public key is not a member of the multisig")?;
+                let signature = H512::try_from(signature.as_slice())
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Expected a 64-byte signature")?;
+                Ok((member.public_key.clone(), signature))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        multisig.combine(member_signatures)?.to_base64()
     }
 }
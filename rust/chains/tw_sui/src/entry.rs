@@ -5,7 +5,9 @@
 use crate::address::SuiAddress;
 use crate::compiler::SuiCompiler;
 use crate::modules::message_signer::SuiMessageSigner;
+use crate::modules::transaction_decoder::SuiTransactionDecoder;
 use crate::modules::transaction_util::SuiTransactionUtil;
+use crate::modules::wallet_connect::connector::SuiWalletConnector;
 use crate::signer::SuiSigner;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
@@ -14,8 +16,6 @@ use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Sui::Proto;
@@ -34,8 +34,8 @@ impl CoinEntry for SuiEntry {
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = SuiMessageSigner;
-    type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type WalletConnector = SuiWalletConnector;
+    type TransactionDecoder = SuiTransactionDecoder;
     type TransactionUtil = SuiTransactionUtil;
 
     #[inline]
@@ -97,6 +97,16 @@ impl CoinEntry for SuiEntry {
         Some(SuiMessageSigner)
     }
 
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(SuiWalletConnector)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(SuiTransactionDecoder)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(SuiTransactionUtil)
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::signature::SignatureScheme;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::sha2::sha256;
+use tw_hash::{concat, H256, H512};
+use tw_keypair::ecdsa::{nist256p1, secp256k1};
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_keypair::{ed25519, KeyPairError, KeyPairResult};
+use tw_memory::Data;
+use tw_number::U256;
+
+/// Order of the `secp256r1` curve.
+const SECP256R1_ORDER: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+/// A public key of one of the schemes supported by Sui.
+#[derive(Clone)]
+pub enum SuiPublicKey {
+    Ed25519(ed25519::sha512::PublicKey),
+    Secp256k1(secp256k1::PublicKey),
+    Secp256r1(nist256p1::PublicKey),
+}
+
+impl SuiPublicKey {
+    /// Parses a public key. `ecdsa` public keys are expected to be compressed.
+    pub fn new(scheme: SignatureScheme, bytes: &[u8]) -> KeyPairResult<SuiPublicKey> {
+        match scheme {
+            SignatureScheme::ED25519 => {
+                ed25519::sha512::PublicKey::try_from(bytes).map(SuiPublicKey::Ed25519)
+            },
+            SignatureScheme::SECP256K1 if bytes.len() == secp256k1::PublicKey::COMPRESSED => {
+                secp256k1::PublicKey::try_from(bytes).map(SuiPublicKey::Secp256k1)
+            },
+            SignatureScheme::SECP256R1 if bytes.len() == nist256p1::PublicKey::COMPRESSED => {
+                nist256p1::PublicKey::try_from(bytes).map(SuiPublicKey::Secp256r1)
+            },
+            _ => Err(KeyPairError::InvalidPublicKey),
+        }
+    }
+
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            SuiPublicKey::Ed25519(_) => SignatureScheme::ED25519,
+            SuiPublicKey::Secp256k1(_) => SignatureScheme::SECP256K1,
+            SuiPublicKey::Secp256r1(_) => SignatureScheme::SECP256R1,
+        }
+    }
+
+    /// Returns 32 bytes of an `ed25519` key, or 33 bytes of a compressed `ecdsa` key.
+    pub fn to_bytes(&self) -> Data {
+        match self {
+            SuiPublicKey::Ed25519(public) => public.to_bytes().into_vec(),
+            SuiPublicKey::Secp256k1(public) => public.compressed().into_vec(),
+            SuiPublicKey::Secp256r1(public) => public.compressed().into_vec(),
+        }
+    }
+}
+
+impl PartialEq for SuiPublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.scheme() == other.scheme() && self.to_bytes() == other.to_bytes()
+    }
+}
+
+/// A key pair of one of the schemes supported by Sui.
+pub enum SuiKeyPair {
+    Ed25519(ed25519::sha512::KeyPair),
+    Secp256k1(secp256k1::KeyPair),
+    Secp256r1(nist256p1::KeyPair),
+}
+
+impl SuiKeyPair {
+    pub fn new(scheme: SignatureScheme, private_key: &[u8]) -> KeyPairResult<SuiKeyPair> {
+        match scheme {
+            SignatureScheme::ED25519 => {
+                ed25519::sha512::KeyPair::try_from(private_key).map(SuiKeyPair::Ed25519)
+            },
+            SignatureScheme::SECP256K1 => {
+                secp256k1::KeyPair::try_from(private_key).map(SuiKeyPair::Secp256k1)
+            },
+            SignatureScheme::SECP256R1 => {
+                nist256p1::KeyPair::try_from(private_key).map(SuiKeyPair::Secp256r1)
+            },
+            SignatureScheme::MULTISIG | SignatureScheme::ZKLOGIN => {
+                Err(KeyPairError::InvalidSecretKey)
+            },
+        }
+    }
+
+    pub fn public(&self) -> SuiPublicKey {
+        match self {
+            SuiKeyPair::Ed25519(key_pair) => SuiPublicKey::Ed25519(key_pair.public().clone()),
+            SuiKeyPair::Secp256k1(key_pair) => SuiPublicKey::Secp256k1(key_pair.public().clone()),
+            SuiKeyPair::Secp256r1(key_pair) => SuiPublicKey::Secp256r1(key_pair.public().clone()),
+        }
+    }
+
+    /// Signs the `blake2b` transaction digest.
+    /// `ecdsa` schemes sign `sha256(digest)` and return a 64-byte `r || s` signature with a low `s`.
+    pub fn sign(&self, digest: &H256) -> SigningResult<H512> {
+        match self {
+            SuiKeyPair::Ed25519(key_pair) => Ok(key_pair.sign(digest.to_vec())?.to_bytes()),
+            SuiKeyPair::Secp256k1(key_pair) => {
+                // `secp256k1` signatures are already normalized.
+                let signature = key_pair.sign(Self::ecdsa_prehash(digest)?)?;
+                Ok(concat(signature.r(), signature.s()))
+            },
+            SuiKeyPair::Secp256r1(key_pair) => {
+                let signature = key_pair.sign(Self::ecdsa_prehash(digest)?)?;
+                let s = normalize_s(signature.s(), H256::from(SECP256R1_ORDER));
+                Ok(concat(signature.r(), s))
+            },
+        }
+    }
+
+    fn ecdsa_prehash(digest: &H256) -> SigningResult<H256> {
+        H256::try_from(sha256(digest.as_slice()).as_slice())
+            .tw_err(SigningErrorType::Error_internal)
+    }
+}
+
+/// Returns `n - s` if `s` is greater than a half of the curve order `n`.
+fn normalize_s(s: H256, order: H256) -> H256 {
+    let s = U256::from_big_endian(s);
+    let order = U256::from_big_endian(order);
+    if s > order >> 1 {
+        (order - s).to_big_endian()
+    } else {
+        s.to_big_endian()
+    }
+}
@@ -6,7 +6,10 @@ pub mod address;
 pub mod compiler;
 pub mod constants;
 pub mod entry;
+pub mod key;
 pub mod modules;
+pub mod multisig;
 pub mod signature;
 pub mod signer;
 pub mod transaction;
+pub mod zklogin;
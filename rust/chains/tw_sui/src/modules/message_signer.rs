@@ -99,7 +99,7 @@ impl MessageSigner for SuiMessageSigner {
         ));
 
         // Check if the public key in the signature matches the public key in the input.
-        if signature_info.public_key.ne(&public_key.to_bytes()) {
+        if signature_info.public_key.as_slice() != public_key.to_bytes().as_slice() {
             return false;
         }
         let signature = try_or_false!(ed25519::Signature::try_from(
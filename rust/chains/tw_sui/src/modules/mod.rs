@@ -4,6 +4,9 @@
 
 pub mod intent;
 pub mod message_signer;
+pub mod programmable_tx_builder;
+pub mod transaction_decoder;
 pub mod transaction_util;
 pub mod tx_builder;
 pub mod tx_signer;
+pub mod wallet_connect;
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_builder::TWTransactionBuilder;
+use crate::transaction::command::{Argument, Command};
+use crate::transaction::programmable_transaction::ProgrammableTransaction;
+use crate::transaction::sui_types::{CallArg, ObjectArg, ObjectID, SequenceNumber};
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::TypeTag;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_proto::Sui::Proto;
+use Proto::mod_Argument::OneOfkind as ArgumentKind;
+use Proto::mod_CallArg::OneOfarg as CallArgType;
+use Proto::mod_Command::OneOfcommand as CommandType;
+
+/// Builds a [`ProgrammableTransaction`] from the typed list of inputs and commands.
+/// Checks that every [`Argument`] refers to an existing input or a preceding command.
+pub struct ProgrammableTxBuilder {
+    inputs_len: usize,
+    /// Index of the command being built.
+    command_index: usize,
+}
+
+impl ProgrammableTxBuilder {
+    pub fn build(pt: &Proto::ProgrammableTransaction) -> SigningResult<ProgrammableTransaction> {
+        if pt.commands.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Programmable transaction must contain at least one command");
+        }
+
+        let inputs = pt
+            .inputs
+            .iter()
+            .map(Self::call_arg)
+            .collect::<SigningResult<Vec<_>>>()
+            .context("Invalid programmable transaction input")?;
+
+        let mut builder = ProgrammableTxBuilder {
+            inputs_len: inputs.len(),
+            command_index: 0,
+        };
+        let mut commands = Vec::with_capacity(pt.commands.len());
+        for command in pt.commands.iter() {
+            let command = builder
+                .command(command)
+                .with_context(|| format!("Invalid command #{}", builder.command_index))?;
+            commands.push(command);
+            builder.command_index += 1;
+        }
+
+        Ok(ProgrammableTransaction { inputs, commands })
+    }
+
+    fn call_arg(arg: &Proto::CallArg) -> SigningResult<CallArg> {
+        match arg.arg {
+            CallArgType::pure(ref bytes) => Ok(CallArg::Pure(bytes.to_vec())),
+            CallArgType::imm_or_owned_object(ref object) => Ok(CallArg::Object(
+                ObjectArg::ImmOrOwnedObject(TWTransactionBuilder::build_coin(object)?),
+            )),
+            CallArgType::shared_object(ref shared) => {
+                Ok(CallArg::Object(ObjectArg::SharedObject {
+                    id: ObjectID::from_str(&shared.object_id).context("Invalid Object ID")?,
+                    initial_shared_version: SequenceNumber(shared.initial_shared_version),
+                    mutable: shared.mutable,
+                }))
+            },
+            CallArgType::receiving(ref object) => Ok(CallArg::Object(ObjectArg::Receiving(
+                TWTransactionBuilder::build_coin(object)?,
+            ))),
+            CallArgType::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No input argument specified"),
+        }
+    }
+
+    fn command(&self, command: &Proto::Command) -> SigningResult<Command> {
+        match command.command {
+            CommandType::move_call(ref move_call) => {
+                let package =
+                    ObjectID::from_str(&move_call.package).context("Failed to parse package ID")?;
+                let module =
+                    Self::identifier(&move_call.module).context("Failed to parse module")?;
+                let function =
+                    Self::identifier(&move_call.function).context("Failed to parse function")?;
+                let type_arguments = move_call
+                    .type_arguments
+                    .iter()
+                    .map(|tag| Self::type_tag(tag))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                Ok(Command::move_call(
+                    package,
+                    module,
+                    function,
+                    type_arguments,
+                    self.arguments(&move_call.arguments)?,
+                ))
+            },
+            CommandType::transfer_objects(ref transfer) => Ok(Command::TransferObjects(
+                self.arguments(&transfer.objects)?,
+                self.require_argument(&transfer.address)
+                    .context("No 'address' specified")?,
+            )),
+            CommandType::split_coins(ref split) => Ok(Command::SplitCoins(
+                self.require_argument(&split.coin)
+                    .context("No 'coin' specified")?,
+                self.arguments(&split.amounts)?,
+            )),
+            CommandType::merge_coins(ref merge) => Ok(Command::MergeCoins(
+                self.require_argument(&merge.destination)
+                    .context("No 'destination' specified")?,
+                self.arguments(&merge.sources)?,
+            )),
+            CommandType::publish(ref publish) => Ok(Command::Publish(
+                publish
+                    .modules
+                    .iter()
+                    .map(|module| module.to_vec())
+                    .collect(),
+                Self::object_ids(&publish.dependencies)?,
+            )),
+            CommandType::make_move_vec(ref make_move_vec) => {
+                let type_tag = if make_move_vec.type_tag.is_empty() {
+                    None
+                } else {
+                    Some(Self::type_tag(&make_move_vec.type_tag)?)
+                };
+                Ok(Command::MakeMoveVec(
+                    type_tag,
+                    self.arguments(&make_move_vec.elements)?,
+                ))
+            },
+            CommandType::upgrade(ref upgrade) => Ok(Command::Upgrade(
+                upgrade
+                    .modules
+                    .iter()
+                    .map(|module| module.to_vec())
+                    .collect(),
+                Self::object_ids(&upgrade.dependencies)?,
+                ObjectID::from_str(&upgrade.package_id).context("Failed to parse package ID")?,
+                self.require_argument(&upgrade.ticket)
+                    .context("No 'ticket' specified")?,
+            )),
+            CommandType::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No command specified"),
+        }
+    }
+
+    fn arguments(&self, args: &[Proto::Argument]) -> SigningResult<Vec<Argument>> {
+        args.iter().map(|arg| self.argument(arg)).collect()
+    }
+
+    fn require_argument(&self, arg: &Option<Proto::Argument>) -> SigningResult<Argument> {
+        let arg = arg
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)?;
+        self.argument(arg)
+    }
+
+    fn argument(&self, arg: &Proto::Argument) -> SigningResult<Argument> {
+        match arg.kind {
+            ArgumentKind::gas_coin(_) => Ok(Argument::GasCoin),
+            ArgumentKind::input(index) => {
+                let index = Self::index(index)?;
+                if index as usize >= self.inputs_len {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .with_context(|| format!("Input #{index} does not exist"));
+                }
+                Ok(Argument::Input(index))
+            },
+            ArgumentKind::result(index) => Ok(Argument::Result(self.result_index(index)?)),
+            ArgumentKind::nested_result(ref nested) => Ok(Argument::NestedResult(
+                self.result_index(nested.index)?,
+                Self::index(nested.result_index)?,
+            )),
+            ArgumentKind::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No argument kind specified"),
+        }
+    }
+
+    /// A command can only use results of the preceding commands.
+    fn result_index(&self, index: u32) -> SigningResult<u16> {
+        let index = Self::index(index)?;
+        if index as usize >= self.command_index {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!(
+                    "Command can only refer to results of the preceding commands, found #{index}"
+                )
+            });
+        }
+        Ok(index)
+    }
+
+    fn index(index: u32) -> SigningResult<u16> {
+        u16::try_from(index)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("Argument index is too large: {index}"))
+    }
+
+    fn identifier(s: &str) -> SigningResult<Identifier> {
+        Identifier::from_str(s).tw_err(SigningErrorType::Error_invalid_params)
+    }
+
+    fn type_tag(s: &str) -> SigningResult<TypeTag> {
+        TypeTag::from_str(s)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("Failed to parse type tag: {s}"))
+    }
+
+    fn object_ids(ids: &[Cow<'_, str>]) -> SigningResult<Vec<ObjectID>> {
+        ids.iter()
+            .map(|id| ObjectID::from_str(id).context("Failed to parse object ID"))
+            .collect()
+    }
+}
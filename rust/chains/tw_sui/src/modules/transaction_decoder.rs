@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::command::{Argument, Command};
+use crate::transaction::programmable_transaction::ProgrammableTransaction;
+use crate::transaction::sui_types::{CallArg, ObjectArg, ObjectRef, TransactionExpiration};
+use crate::transaction::transaction_data::{TransactionData, TransactionKind};
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::bcs;
+use tw_proto::Sui::Proto;
+use Proto::mod_Argument::OneOfkind as ArgumentKind;
+use Proto::mod_CallArg::OneOfarg as CallArgType;
+use Proto::mod_Command::OneOfcommand as CommandType;
+
+pub struct SuiTransactionDecoder;
+
+impl TransactionDecoder for SuiTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl SuiTransactionDecoder {
+    /// Decodes BCS encoded `TransactionData`, e.g. `SignDirect.unsigned_tx_msg` decoded from base64.
+    fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx_data: TransactionData = bcs::decode(tx)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error decoding TransactionData")?;
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(Self::decoded_transaction(tx_data)),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    fn decoded_transaction(tx_data: TransactionData) -> Proto::DecodedTransaction<'static> {
+        let TransactionData::V1(tx) = tx_data;
+
+        let expiration_epoch = match tx.expiration {
+            TransactionExpiration::None => 0,
+            TransactionExpiration::Epoch(epoch) => epoch,
+        };
+        let TransactionKind::ProgrammableTransaction(pt) = tx.kind;
+
+        Proto::DecodedTransaction {
+            sender: tx.sender.to_string().into(),
+            gas_owner: tx.gas_data.owner.to_string().into(),
+            gas_budget: tx.gas_data.budget,
+            gas_price: tx.gas_data.price,
+            expiration_epoch,
+            programmable_transaction: Some(Self::programmable_transaction(pt, tx.gas_data.payment)),
+        }
+    }
+
+    fn programmable_transaction(
+        pt: ProgrammableTransaction,
+        gas_payment: Vec<ObjectRef>,
+    ) -> Proto::ProgrammableTransaction<'static> {
+        Proto::ProgrammableTransaction {
+            inputs: pt.inputs.into_iter().map(Self::call_arg).collect(),
+            commands: pt.commands.into_iter().map(Self::command).collect(),
+            gas_payment: gas_payment.iter().map(Self::object_ref).collect(),
+        }
+    }
+
+    fn call_arg(arg: CallArg) -> Proto::CallArg<'static> {
+        let arg = match arg {
+            CallArg::Pure(bytes) => CallArgType::pure(bytes.into()),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(object)) => {
+                CallArgType::imm_or_owned_object(Self::object_ref(&object))
+            },
+            CallArg::Object(ObjectArg::SharedObject {
+                id,
+                initial_shared_version,
+                mutable,
+            }) => CallArgType::shared_object(Proto::mod_CallArg::SharedObject {
+                object_id: id.to_string().into(),
+                initial_shared_version: initial_shared_version.0,
+                mutable,
+            }),
+            CallArg::Object(ObjectArg::Receiving(object)) => {
+                CallArgType::receiving(Self::object_ref(&object))
+            },
+        };
+        Proto::CallArg { arg }
+    }
+
+    fn command(command: Command) -> Proto::Command<'static> {
+        let command = match command {
+            Command::MoveCall(move_call) => CommandType::move_call(Proto::mod_Command::MoveCall {
+                package: move_call.package.to_string().into(),
+                module: move_call.module.to_string().into(),
+                function: move_call.function.to_string().into(),
+                type_arguments: move_call
+                    .type_arguments
+                    .iter()
+                    .map(|tag| tag.to_string().into())
+                    .collect(),
+                arguments: Self::arguments(move_call.arguments),
+            }),
+            Command::TransferObjects(objects, address) => {
+                CommandType::transfer_objects(Proto::mod_Command::TransferObjects {
+                    objects: Self::arguments(objects),
+                    address: Some(Self::argument(address)),
+                })
+            },
+            Command::SplitCoins(coin, amounts) => {
+                CommandType::split_coins(Proto::mod_Command::SplitCoins {
+                    coin: Some(Self::argument(coin)),
+                    amounts: Self::arguments(amounts),
+                })
+            },
+            Command::MergeCoins(destination, sources) => {
+                CommandType::merge_coins(Proto::mod_Command::MergeCoins {
+                    destination: Some(Self::argument(destination)),
+                    sources: Self::arguments(sources),
+                })
+            },
+            Command::Publish(modules, dependencies) => {
+                CommandType::publish(Proto::mod_Command::Publish {
+                    modules: modules.into_iter().map(Into::into).collect(),
+                    dependencies: dependencies
+                        .iter()
+                        .map(|id| id.to_string().into())
+                        .collect(),
+                })
+            },
+            Command::MakeMoveVec(type_tag, elements) => {
+                CommandType::make_move_vec(Proto::mod_Command::MakeMoveVec {
+                    type_tag: type_tag
+                        .map(|tag| tag.to_string())
+                        .unwrap_or_default()
+                        .into(),
+                    elements: Self::arguments(elements),
+                })
+            },
+            Command::Upgrade(modules, dependencies, package_id, ticket) => {
+                CommandType::upgrade(Proto::mod_Command::Upgrade {
+                    modules: modules.into_iter().map(Into::into).collect(),
+                    dependencies: dependencies
+                        .iter()
+                        .map(|id| id.to_string().into())
+                        .collect(),
+                    package_id: package_id.to_string().into(),
+                    ticket: Some(Self::argument(ticket)),
+                })
+            },
+        };
+        Proto::Command { command }
+    }
+
+    fn arguments(args: Vec<Argument>) -> Vec<Proto::Argument> {
+        args.into_iter().map(Self::argument).collect()
+    }
+
+    fn argument(arg: Argument) -> Proto::Argument {
+        let kind = match arg {
+            Argument::GasCoin => ArgumentKind::gas_coin(true),
+            Argument::Input(index) => ArgumentKind::input(index as u32),
+            Argument::Result(index) => ArgumentKind::result(index as u32),
+            Argument::NestedResult(index, result_index) => {
+                ArgumentKind::nested_result(Proto::mod_Argument::NestedResult {
+                    index: index as u32,
+                    result_index: result_index as u32,
+                })
+            },
+        };
+        Proto::Argument { kind }
+    }
+
+    fn object_ref(object: &ObjectRef) -> Proto::ObjectRef<'static> {
+        let (id, version, digest) = object;
+        Proto::ObjectRef {
+            object_id: id.to_string().into(),
+            version: version.0,
+            object_digest: digest.to_string().into(),
+        }
+    }
+}
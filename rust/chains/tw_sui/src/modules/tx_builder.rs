@@ -3,9 +3,14 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::SuiAddress;
+use crate::key::SuiKeyPair;
+use crate::modules::programmable_tx_builder::ProgrammableTxBuilder;
+use crate::multisig::MultisigPublicKey;
+use crate::signature::SignatureScheme;
 use crate::transaction::sui_types::{ObjectDigest, ObjectID, ObjectRef, SequenceNumber};
 use crate::transaction::transaction_builder::TransactionBuilder;
 use crate::transaction::transaction_data::TransactionData;
+use crate::zklogin::ZkLogin;
 use std::borrow::Cow;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
@@ -21,6 +26,14 @@ pub enum TWTransaction {
     SignDirect(Data),
 }
 
+/// The account the transaction is signed on behalf of.
+pub enum SignerAccount {
+    /// An account controlled by a single `ed25519` key.
+    Single,
+    Multisig(MultisigPublicKey),
+    ZkLogin(ZkLogin),
+}
+
 pub struct TWTransactionBuilder<'a> {
     input: Proto::SigningInput<'a>,
 }
@@ -35,6 +48,40 @@ impl<'a> TWTransactionBuilder<'a> {
             .map_err(SigningError::from)
     }
 
+    pub fn signer_account(&self) -> SigningResult<SignerAccount> {
+        match (&self.input.multisig, &self.input.zk_login) {
+            (Some(_), Some(_)) => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Only one of 'multisig' or 'zk_login' can be set"),
+            (Some(multisig), None) => MultisigPublicKey::from_proto(multisig)
+                .map(SignerAccount::Multisig)
+                .context("Invalid multisig public key"),
+            (None, Some(zk_login)) => ZkLogin::from_proto(zk_login)
+                .map(SignerAccount::ZkLogin)
+                .context("Invalid zkLogin inputs"),
+            (None, None) => Ok(SignerAccount::Single),
+        }
+    }
+
+    /// Finds the multisig member the private key belongs to.
+    /// Any private key can be interpreted as a key of each scheme, so all of them are checked.
+    pub fn multisig_member_key(&self, multisig: &MultisigPublicKey) -> SigningResult<SuiKeyPair> {
+        let schemes = [
+            SignatureScheme::ED25519,
+            SignatureScheme::SECP256K1,
+            SignatureScheme::SECP256R1,
+        ];
+        for scheme in schemes {
+            let Ok(key_pair) = SuiKeyPair::new(scheme, self.input.private_key.as_ref()) else {
+                continue;
+            };
+            if multisig.member_index(&key_pair.public()).is_some() {
+                return Ok(key_pair);
+            }
+        }
+        SigningError::err(SigningErrorType::Error_missing_private_key)
+            .context("Given private key does not belong to any of the multisig members")
+    }
+
     pub fn build(self) -> SigningResult<TWTransaction> {
         let tx_data = match self.input.transaction_payload {
             TransactionType::sign_direct_message(ref direct) => {
@@ -54,6 +101,9 @@ impl<'a> TWTransactionBuilder<'a> {
                 self.transfer_object_from_proto(transfer_obj)
             },
             TransactionType::raw_json(ref raw_json) => self.raw_json_from_proto(raw_json),
+            TransactionType::programmable_transaction(ref pt) => {
+                self.programmable_transaction_from_proto(pt)
+            },
             TransactionType::None => SigningError::err(SigningErrorType::Error_invalid_params),
         }?;
         Ok(TWTransaction::Transaction(tx_data))
@@ -191,14 +241,36 @@ impl<'a> TWTransactionBuilder<'a> {
         )
     }
 
+    fn programmable_transaction_from_proto(
+        &self,
+        pt: &Proto::ProgrammableTransaction<'_>,
+    ) -> SigningResult<TransactionData> {
+        let signer = self.signer_address()?;
+        let gas_payment = Self::build_coins(&pt.gas_payment)?;
+        let programmable_transaction = ProgrammableTxBuilder::build(pt)?;
+
+        TransactionBuilder::programmable_transaction(
+            signer,
+            programmable_transaction,
+            gas_payment,
+            self.input.gas_budget,
+            self.input.reference_gas_price,
+        )
+    }
+
     fn signer_address(&self) -> SigningResult<SuiAddress> {
-        if self.input.private_key.is_empty() {
-            SuiAddress::from_str(&self.input.signer)
-                .into_tw()
-                .context("Invalid signer address")
-        } else {
-            let keypair = self.signer_key()?;
-            SuiAddress::with_ed25519_pubkey(keypair.public()).map_err(SigningError::from)
+        match self.signer_account()? {
+            SignerAccount::Multisig(multisig) => multisig.address().map_err(SigningError::from),
+            SignerAccount::ZkLogin(zk_login) => zk_login.address(),
+            SignerAccount::Single if self.input.private_key.is_empty() => {
+                SuiAddress::from_str(&self.input.signer)
+                    .into_tw()
+                    .context("Invalid signer address")
+            },
+            SignerAccount::Single => {
+                let keypair = self.signer_key()?;
+                SuiAddress::with_ed25519_pubkey(keypair.public()).map_err(SigningError::from)
+            },
         }
     }
 
@@ -213,7 +285,7 @@ impl<'a> TWTransactionBuilder<'a> {
         Self::build_coin(coin)
     }
 
-    fn build_coin(coin: &Proto::ObjectRef) -> SigningResult<ObjectRef> {
+    pub fn build_coin(coin: &Proto::ObjectRef) -> SigningResult<ObjectRef> {
         let object_id = ObjectID::from_str(coin.object_id.as_ref()).context("Invalid Object ID")?;
         let version = SequenceNumber(coin.version);
         let object_digest = ObjectDigest::from_str(coin.object_digest.as_ref())?;
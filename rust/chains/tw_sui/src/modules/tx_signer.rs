@@ -4,6 +4,7 @@
 
 use crate::address::SuiAddress;
 use crate::modules::intent::Intent;
+use crate::modules::tx_builder::TWTransaction;
 use crate::signature::SuiSignatureInfo;
 use crate::transaction::transaction_data::TransactionData;
 use tw_coin_entry::error::prelude::*;
@@ -53,6 +54,13 @@ impl TxSigner {
         Ok((preimage, signature_info))
     }
 
+    pub fn preimage_tw(tx: TWTransaction) -> SigningResult<TransactionPreimage> {
+        match tx {
+            TWTransaction::Transaction(tx) => Self::preimage(&tx),
+            TWTransaction::SignDirect(tx_data) => Self::preimage_direct(tx_data),
+        }
+    }
+
     pub fn preimage(tx: &TransactionData) -> SigningResult<TransactionPreimage> {
         let unsigned_tx_data = bcs::encode(tx)
             .tw_err(SigningErrorType::Error_internal)
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::wallet_connect::request::SignTransactionRequest;
+use crate::transaction::transaction_data::TransactionData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::{base64, bcs};
+use tw_proto::Sui::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct SuiWalletConnector;
+
+impl WalletConnector for SuiWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl SuiWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::SuiSignTransaction => {
+                Self::parse_sign_transaction_request(coin, request)
+            },
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_transaction_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let sign_request: SignTransactionRequest = serde_json::from_str(&request.payload)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error parsing WalletConnect signing request as JSON")?;
+
+        // Make sure the transaction is valid and is going to be signed by the requested account.
+        let tx_data: TransactionData = bcs::decode(&sign_request.transaction.0)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error decoding TransactionData")?;
+        if tx_data.sender() != sign_request.address {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Transaction sender does not match the requested address");
+        }
+
+        let unsigned_tx_msg = base64::encode(&sign_request.transaction.0, base64::STANDARD);
+        let signing_input = Proto::SigningInput {
+            signer: sign_request.address.to_string().into(),
+            transaction_payload:
+                Proto::mod_SigningInput::OneOftransaction_payload::sign_direct_message(
+                    Proto::SignDirect {
+                        unsigned_tx_msg: unsigned_tx_msg.into(),
+                    },
+                ),
+            ..Proto::SigningInput::default()
+        };
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::sui(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+}
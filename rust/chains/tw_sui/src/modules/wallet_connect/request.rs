@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use serde::Deserialize;
+use tw_encoding::base64::Base64Encoded;
+use tw_misc::serde::as_string;
+
+/// `sui_signTransaction` params.
+/// `transaction` is a base64 encoded BCS `TransactionData`.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/sui-rpc#sui_signtransaction
+#[derive(Deserialize)]
+pub struct SignTransactionRequest {
+    pub transaction: Base64Encoded,
+    #[serde(with = "as_string")]
+    pub address: SuiAddress,
+}
@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::key::SuiPublicKey;
+use crate::signature::SignatureScheme;
+use serde::ser::SerializeTuple;
+use serde::{Serialize, Serializer};
+use std::collections::HashSet;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::bcs;
+use tw_hash::H512;
+use tw_memory::Data;
+use tw_proto::Sui::Proto;
+
+/// Max number of the multisig members.
+pub const MAX_SIGNER_IN_MULTISIG: usize = 10;
+
+/// A weighted member of a multisig account.
+#[derive(Clone)]
+pub struct MultisigMember {
+    pub public_key: SuiPublicKey,
+    pub weight: u8,
+}
+
+/// Multisig account public key.
+/// https://docs.sui.io/concepts/cryptography/transaction-auth/multisig
+#[derive(Clone)]
+pub struct MultisigPublicKey {
+    members: Vec<MultisigMember>,
+    threshold: u16,
+}
+
+impl MultisigPublicKey {
+    pub fn new(members: Vec<MultisigMember>, threshold: u16) -> SigningResult<MultisigPublicKey> {
+        if members.is_empty() || members.len() > MAX_SIGNER_IN_MULTISIG {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("Multisig must have from 1 to {MAX_SIGNER_IN_MULTISIG} members")
+            });
+        }
+        if threshold == 0 {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multisig threshold must be greater than 0");
+        }
+        if members.iter().any(|member| member.weight == 0) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multisig member weight must be greater than 0");
+        }
+
+        let total_weight: u16 = members.iter().map(|member| member.weight as u16).sum();
+        if total_weight < threshold {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Total weight of the multisig members is less than the threshold");
+        }
+
+        let mut unique_keys = HashSet::with_capacity(members.len());
+        for member in members.iter() {
+            if !unique_keys.insert(member.public_key.to_bytes()) {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Multisig members must have unique public keys");
+            }
+        }
+
+        Ok(MultisigPublicKey { members, threshold })
+    }
+
+    pub fn from_proto(multisig: &Proto::MultisigPublicKey) -> SigningResult<MultisigPublicKey> {
+        let members = multisig
+            .members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| {
+                let public_key =
+                    SuiPublicKey::new(scheme_from_proto(member.scheme), &member.public_key)
+                        .into_tw()
+                        .with_context(|| format!("Invalid public key of the member #{i}"))?;
+                let weight = u8::try_from(member.weight)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Weight of the member #{i} is too large"))?;
+                Ok(MultisigMember { public_key, weight })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let threshold = u16::try_from(multisig.threshold)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Multisig threshold is too large")?;
+
+        MultisigPublicKey::new(members, threshold)
+    }
+
+    pub fn members(&self) -> &[MultisigMember] {
+        &self.members
+    }
+
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns an index of the member with the given public key.
+    pub fn member_index(&self, public_key: &SuiPublicKey) -> Option<usize> {
+        self.members
+            .iter()
+            .position(|member| member.public_key == *public_key)
+    }
+
+    /// The multisig address is `blake2b(0x03 || threshold || flag_1 || pk_1 || weight_1 || ... )`.
+    pub fn address(&self) -> AddressResult<SuiAddress> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.threshold.to_le_bytes());
+        for member in self.members.iter() {
+            data.push(member.public_key.scheme().flag());
+            data.extend_from_slice(&member.public_key.to_bytes());
+            data.push(member.weight);
+        }
+        SuiAddress::with_scheme_data(SignatureScheme::MULTISIG, &data)
+    }
+
+    /// Combines signatures of the members into a multisig.
+    /// The total weight of the signers must reach the threshold.
+    pub fn combine(&self, signatures: Vec<(SuiPublicKey, H512)>) -> SigningResult<MultiSig> {
+        let mut indexed_signatures = Vec::with_capacity(signatures.len());
+        let mut bitmap = 0_u16;
+        let mut weight = 0_u16;
+
+        for (public_key, signature) in signatures {
+            let index = self
+                .member_index(&public_key)
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Signature public key is not a member of the multisig")?;
+
+            let bit = 1_u16 << index;
+            if bitmap & bit != 0 {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .with_context(|| format!("Duplicate signature of the member #{index}"));
+            }
+            bitmap |= bit;
+            weight += self.members[index].weight as u16;
+            indexed_signatures.push((index, public_key.scheme(), signature));
+        }
+
+        if weight < self.threshold {
+            return SigningError::err(SigningErrorType::Error_signatures_count).with_context(
+                || {
+                    format!(
+                        "Insufficient weight of the signatures: {weight}, expected at least {}",
+                        self.threshold
+                    )
+                },
+            );
+        }
+
+        // Signatures must be ordered as the corresponding members.
+        indexed_signatures.sort_by_key(|(index, _, _)| *index);
+        let signatures = indexed_signatures
+            .into_iter()
+            .map(|(_, scheme, signature)| (scheme, signature))
+            .collect();
+
+        Ok(MultiSig {
+            signatures,
+            bitmap,
+            public_key: self.clone(),
+        })
+    }
+}
+
+/// Combined signature of the multisig members.
+pub struct MultiSig {
+    signatures: Vec<(SignatureScheme, H512)>,
+    bitmap: u16,
+    public_key: MultisigPublicKey,
+}
+
+impl MultiSig {
+    /// Returns `0x03 || bcs(MultiSig)`.
+    pub fn to_vec(&self) -> SigningResult<Data> {
+        let sigs = self
+            .signatures
+            .iter()
+            .map(|(scheme, signature)| BcsSchemeBytes::new(*scheme, signature.as_slice()))
+            .collect();
+        let public_keys: Vec<_> = self
+            .public_key
+            .members
+            .iter()
+            .map(|member| (member.public_key.scheme(), member.public_key.to_bytes()))
+            .collect();
+        let pk_map = public_keys
+            .iter()
+            .zip(self.public_key.members.iter())
+            .map(|((scheme, bytes), member)| (BcsSchemeBytes::new(*scheme, bytes), member.weight))
+            .collect();
+
+        let multisig = BcsMultiSig {
+            sigs,
+            bitmap: self.bitmap,
+            multisig_pk: BcsMultiSigPublicKey {
+                pk_map,
+                threshold: self.public_key.threshold,
+            },
+        };
+
+        let mut result = vec![SignatureScheme::MULTISIG.flag()];
+        let encoded = bcs::encode(&multisig)
+            .tw_err(SigningErrorType::Error_internal)
+            .context("Error serializing MultiSig")?;
+        result.extend_from_slice(&encoded);
+        Ok(result)
+    }
+
+    pub fn to_base64(&self) -> SigningResult<String> {
+        Ok(base64::encode(&self.to_vec()?, STANDARD))
+    }
+}
+
+pub fn scheme_from_proto(scheme: Proto::SignatureScheme) -> SignatureScheme {
+    match scheme {
+        Proto::SignatureScheme::ED25519 => SignatureScheme::ED25519,
+        Proto::SignatureScheme::SECP256K1 => SignatureScheme::SECP256K1,
+        Proto::SignatureScheme::SECP256R1 => SignatureScheme::SECP256R1,
+    }
+}
+
+/// Mirrors `sui_types::multisig::MultiSig` serialization.
+#[derive(Serialize)]
+struct BcsMultiSig<'a> {
+    sigs: Vec<BcsSchemeBytes<'a>>,
+    bitmap: u16,
+    multisig_pk: BcsMultiSigPublicKey<'a>,
+}
+
+/// Mirrors `sui_types::multisig::MultiSigPublicKey` serialization.
+#[derive(Serialize)]
+struct BcsMultiSigPublicKey<'a> {
+    pk_map: Vec<(BcsSchemeBytes<'a>, u8)>,
+    threshold: u16,
+}
+
+/// Both `CompressedSignature` and `PublicKey` are enums
+/// where the variant index is the scheme flag and the value is a fixed-length byte array.
+#[derive(Serialize)]
+enum BcsSchemeBytes<'a> {
+    Ed25519(FixedBytes<'a>),
+    Secp256k1(FixedBytes<'a>),
+    Secp256r1(FixedBytes<'a>),
+}
+
+impl<'a> BcsSchemeBytes<'a> {
+    fn new(scheme: SignatureScheme, bytes: &'a [u8]) -> BcsSchemeBytes<'a> {
+        match scheme {
+            SignatureScheme::SECP256K1 => BcsSchemeBytes::Secp256k1(FixedBytes(bytes)),
+            SignatureScheme::SECP256R1 => BcsSchemeBytes::Secp256r1(FixedBytes(bytes)),
+            // Multisig members can only be one of the `ed25519`, `secp256k1` or `secp256r1` keys.
+            _ => BcsSchemeBytes::Ed25519(FixedBytes(bytes)),
+        }
+    }
+}
+
+/// Serializes bytes as a fixed-length array, i.e. without a length prefix.
+struct FixedBytes<'a>(&'a [u8]);
+
+impl Serialize for FixedBytes<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(self.0.len())?;
+        for byte in self.0 {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+}
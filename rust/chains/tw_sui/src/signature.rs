@@ -2,22 +2,38 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::key::SuiPublicKey;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::base64::{self, STANDARD};
-use tw_hash::{H256, H512};
+use tw_hash::{H256, H264, H512};
 use tw_keypair::{ed25519, KeyPairError};
 use tw_memory::Data;
 
-#[derive(Clone, Copy)]
+/// Signature scheme flag prepended to public keys and signatures.
+/// https://docs.sui.io/concepts/cryptography/transaction-auth/signatures
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum SignatureScheme {
     ED25519 = 0,
+    SECP256K1 = 1,
+    SECP256R1 = 2,
+    MULTISIG = 3,
+    ZKLOGIN = 5,
 }
 
+impl SignatureScheme {
+    #[inline]
+    pub fn flag(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A single signature serialized as `flag || signature || public_key`.
 pub struct SuiSignatureInfo {
     scheme: SignatureScheme,
     pub signature: H512,
-    pub public_key: H256,
+    /// 32 bytes of an `ed25519` key, or 33 bytes of a compressed `ecdsa` key.
+    pub public_key: Data,
 }
 
 impl SuiSignatureInfo {
@@ -28,18 +44,28 @@ impl SuiSignatureInfo {
         SuiSignatureInfo {
             scheme: SignatureScheme::ED25519,
             signature: signature.to_bytes(),
+            public_key: public_key.to_bytes().into_vec(),
+        }
+    }
+
+    pub fn new(signature: H512, public_key: &SuiPublicKey) -> SuiSignatureInfo {
+        SuiSignatureInfo {
+            scheme: public_key.scheme(),
+            signature,
             public_key: public_key.to_bytes(),
         }
     }
 
+    pub fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+
     pub fn from_base64(encoded: &str) -> SigningResult<SuiSignatureInfo> {
         let data = base64::decode(encoded, STANDARD)?;
-        if data.len() != H512::LEN + H256::LEN + 1 {
-            return SigningError::err(tw_proto::Common::Proto::SigningError::Error_invalid_params)
-                .context("Invalid signature length, expected exactly 97 bytes");
-        }
-        let scheme = match data[0] {
-            0 => SignatureScheme::ED25519,
+        let (scheme, public_key_len) = match data.first() {
+            Some(0) => (SignatureScheme::ED25519, H256::LEN),
+            Some(1) => (SignatureScheme::SECP256K1, H264::LEN),
+            Some(2) => (SignatureScheme::SECP256R1, H264::LEN),
             _ => {
                 return SigningError::err(
                     tw_proto::Common::Proto::SigningError::Error_not_supported,
@@ -47,10 +73,18 @@ impl SuiSignatureInfo {
                 .context("Unsupported signature scheme")
             },
         };
+        if data.len() != H512::LEN + public_key_len + 1 {
+            return SigningError::err(tw_proto::Common::Proto::SigningError::Error_invalid_params)
+                .with_context(|| {
+                    format!(
+                        "Invalid signature length, expected exactly {} bytes",
+                        H512::LEN + public_key_len + 1
+                    )
+                });
+        }
         let signature =
             H512::try_from(&data[1..H512::LEN + 1]).map_err(|_| KeyPairError::InvalidSignature)?;
-        let public_key =
-            H256::try_from(&data[H512::LEN + 1..]).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let public_key = data[H512::LEN + 1..].to_vec();
 
         Ok(SuiSignatureInfo {
             scheme,
@@ -60,10 +94,10 @@ impl SuiSignatureInfo {
     }
 
     pub fn to_vec(&self) -> Data {
-        let mut scheme: Data = Vec::with_capacity(H512::LEN + H256::LEN + 1);
-        scheme.push(self.scheme as u8);
+        let mut scheme: Data = Vec::with_capacity(H512::LEN + self.public_key.len() + 1);
+        scheme.push(self.scheme.flag());
         scheme.extend_from_slice(self.signature.as_slice());
-        scheme.extend_from_slice(self.public_key.as_slice());
+        scheme.extend_from_slice(&self.public_key);
         scheme
     }
 
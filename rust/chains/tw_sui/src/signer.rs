@@ -2,13 +2,17 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::modules::tx_builder::{TWTransaction, TWTransactionBuilder};
-use crate::modules::tx_signer::TxSigner;
+use crate::modules::tx_builder::{SignerAccount, TWTransaction, TWTransactionBuilder};
+use crate::modules::tx_signer::{TransactionPreimage, TxSigner};
+use crate::multisig::MultisigPublicKey;
+use crate::signature::SuiSignatureInfo;
+use crate::zklogin::ZkLogin;
 use std::borrow::Cow;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
 use tw_encoding::base64::{self, STANDARD};
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_proto::Sui::Proto;
 
 pub struct SuiSigner;
@@ -27,6 +31,16 @@ impl SuiSigner {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let builder = TWTransactionBuilder::new(input);
+        match builder.signer_account()? {
+            SignerAccount::Single => Self::sign_single(builder),
+            SignerAccount::Multisig(multisig) => Self::sign_multisig_member(builder, &multisig),
+            SignerAccount::ZkLogin(zk_login) => Self::sign_zk_login(builder, &zk_login),
+        }
+    }
+
+    fn sign_single(
+        builder: TWTransactionBuilder<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
         let signer_key = builder.signer_key()?;
         let tx_to_sign = builder.build()?;
 
@@ -35,11 +49,44 @@ impl SuiSigner {
             TWTransaction::SignDirect(tx_data) => TxSigner::sign_direct(tx_data, &signer_key)?,
         };
 
+        Ok(Self::output(&preimage, signature.to_base64()))
+    }
+
+    /// Returns a partial signature of the multisig member.
+    /// Signatures of the members can be combined by using `TransactionCompiler`.
+    fn sign_multisig_member(
+        builder: TWTransactionBuilder<'_>,
+        multisig: &MultisigPublicKey,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let member_key = builder.multisig_member_key(multisig)?;
+        let preimage = TxSigner::preimage_tw(builder.build()?)?;
+
+        let signature = member_key.sign(&preimage.tx_hash_to_sign)?;
+        let signature_info = SuiSignatureInfo::new(signature, &member_key.public());
+        Ok(Self::output(&preimage, signature_info.to_base64()))
+    }
+
+    fn sign_zk_login(
+        builder: TWTransactionBuilder<'_>,
+        zk_login: &ZkLogin,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let ephemeral_key = builder.signer_key()?;
+        let preimage = TxSigner::preimage_tw(builder.build()?)?;
+
+        let signature = ephemeral_key.sign(preimage.tx_hash_to_sign.to_vec())?;
+        let user_signature = SuiSignatureInfo::ed25519(&signature, ephemeral_key.public());
+        Ok(Self::output(
+            &preimage,
+            zk_login.signature_base64(&user_signature)?,
+        ))
+    }
+
+    fn output(preimage: &TransactionPreimage, signature: String) -> Proto::SigningOutput<'static> {
         let unsigned_tx = base64::encode(&preimage.unsigned_tx_data, STANDARD);
-        Ok(Proto::SigningOutput {
+        Proto::SigningOutput {
             unsigned_tx: Cow::from(unsigned_tx),
-            signature: Cow::from(signature.to_base64()),
+            signature: Cow::from(signature),
             ..Proto::SigningOutput::default()
-        })
+        }
     }
 }
@@ -6,9 +6,11 @@ use crate::address::SuiAddress;
 use crate::constants::{SUI_SYSTEM_STATE_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION};
 use move_core_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::hex;
 use tw_hash::{as_bytes, H256};
 use tw_memory::Data;
 
@@ -32,6 +34,13 @@ impl FromStr for ObjectID {
     }
 }
 
+impl fmt::Display for ObjectID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefixed = true;
+        write!(f, "{}", hex::encode(self.0.as_ref(), prefixed))
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ObjectDigest(#[serde(with = "as_bytes")] pub H256);
 
@@ -49,6 +58,16 @@ impl FromStr for ObjectDigest {
     }
 }
 
+impl fmt::Display for ObjectDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            base58::encode(self.0.as_slice(), Alphabet::Bitcoin)
+        )
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub enum CallArg {
     // contains no structs or objects
@@ -188,6 +188,28 @@ impl TransactionBuilder {
         ))
     }
 
+    /// Builds a transaction from the programmable transaction composed by the caller.
+    pub fn programmable_transaction(
+        signer: SuiAddress,
+        pt: ProgrammableTransaction,
+        gas_payment: Vec<ObjectRef>,
+        gas_budget: u64,
+        gas_price: u64,
+    ) -> SigningResult<TransactionData> {
+        if gas_payment.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No gas payment coins specified");
+        }
+
+        Ok(TransactionData::new_programmable(
+            signer,
+            gas_payment,
+            pt,
+            gas_budget,
+            gas_price,
+        ))
+    }
+
     pub fn raw_json(
         raw_json: &str,
         gas_budget: u64,
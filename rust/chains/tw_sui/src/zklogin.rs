@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::signature::{SignatureScheme, SuiSignatureInfo};
+use serde::Serialize;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::bcs;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Sui::Proto;
+
+const GOOGLE_ISS: &str = "accounts.google.com";
+const GOOGLE_ISS_HTTPS: &str = "https://accounts.google.com";
+
+/// Zero-knowledge proof points. Every element is a decimal string.
+#[derive(Serialize)]
+pub struct ZkLoginProof {
+    pub a: Vec<String>,
+    pub b: Vec<Vec<String>>,
+    pub c: Vec<String>,
+}
+
+/// Base64 encoded `iss` claim of the JWT.
+#[derive(Serialize)]
+pub struct Claim {
+    pub value: String,
+    pub index_mod_4: u8,
+}
+
+/// Mirrors `sui_types::zk_login_authenticator::ZkLoginInputs` serialization.
+#[derive(Serialize)]
+pub struct ZkLoginInputs {
+    pub proof_points: ZkLoginProof,
+    pub iss_base64_details: Claim,
+    pub header_base64: String,
+    /// Decimal string.
+    pub address_seed: String,
+}
+
+/// zkLogin account along with the proof generated for an ephemeral key pair.
+/// https://docs.sui.io/concepts/cryptography/zklogin
+pub struct ZkLogin {
+    pub iss: String,
+    pub inputs: ZkLoginInputs,
+    pub max_epoch: u64,
+}
+
+impl ZkLogin {
+    pub fn from_proto(zk_login: &Proto::ZkLogin) -> SigningResult<ZkLogin> {
+        let proof = zk_login
+            .proof_points
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("No zkLogin 'proof_points' provided")?;
+        let index_mod_4 = u8::try_from(zk_login.iss_base64_details_index_mod_4)
+            .ok()
+            .filter(|index| *index < 4)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid 'iss_base64_details_index_mod_4'")?;
+
+        let zk_login = ZkLogin {
+            iss: zk_login.iss.to_string(),
+            inputs: ZkLoginInputs {
+                proof_points: ZkLoginProof {
+                    a: to_strings(&proof.a),
+                    b: proof.b.iter().map(|b| to_strings(&b.elements)).collect(),
+                    c: to_strings(&proof.c),
+                },
+                iss_base64_details: Claim {
+                    value: zk_login.iss_base64_details_value.to_string(),
+                    index_mod_4,
+                },
+                header_base64: zk_login.header_base64.to_string(),
+                address_seed: zk_login.address_seed.to_string(),
+            },
+            max_epoch: zk_login.max_epoch,
+        };
+        // Validate the address seed.
+        zk_login.address()?;
+        Ok(zk_login)
+    }
+
+    /// The zkLogin address is `blake2b(0x05 || iss_len || iss || address_seed)`,
+    /// where `address_seed` is a 32-byte big-endian number.
+    pub fn address(&self) -> SigningResult<SuiAddress> {
+        // Google issuer can be used with or without the scheme, but it's normalized when hashed.
+        let iss = if self.iss == GOOGLE_ISS {
+            GOOGLE_ISS_HTTPS
+        } else {
+            self.iss.as_str()
+        };
+        let iss_len = u8::try_from(iss.len())
+            .ok()
+            .filter(|len| *len > 0)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid zkLogin 'iss' length")?;
+        let address_seed = U256::from_str(&self.inputs.address_seed)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("zkLogin 'address_seed' must be a decimal string")?;
+
+        let mut data = Vec::with_capacity(iss.len() + 33);
+        data.push(iss_len);
+        data.extend_from_slice(iss.as_bytes());
+        data.extend_from_slice(address_seed.to_big_endian().as_slice());
+        SuiAddress::with_scheme_data(SignatureScheme::ZKLOGIN, &data).map_err(SigningError::from)
+    }
+
+    /// Returns `0x05 || bcs(ZkLoginAuthenticator)` where `user_signature` is signed by the ephemeral key.
+    pub fn signature(&self, user_signature: &SuiSignatureInfo) -> SigningResult<Data> {
+        let authenticator = BcsZkLoginAuthenticator {
+            inputs: &self.inputs,
+            max_epoch: self.max_epoch,
+            user_signature: user_signature.to_vec(),
+        };
+
+        let mut result = vec![SignatureScheme::ZKLOGIN.flag()];
+        let encoded = bcs::encode(&authenticator)
+            .tw_err(SigningErrorType::Error_internal)
+            .context("Error serializing ZkLoginAuthenticator")?;
+        result.extend_from_slice(&encoded);
+        Ok(result)
+    }
+
+    pub fn signature_base64(&self, user_signature: &SuiSignatureInfo) -> SigningResult<String> {
+        Ok(base64::encode(&self.signature(user_signature)?, STANDARD))
+    }
+}
+
+/// Mirrors `sui_types::zk_login_authenticator::ZkLoginAuthenticator` serialization.
+#[derive(Serialize)]
+struct BcsZkLoginAuthenticator<'a> {
+    inputs: &'a ZkLoginInputs,
+    max_epoch: u64,
+    /// `flag || signature || public_key` of the ephemeral key.
+    user_signature: Data,
+}
+
+fn to_strings<S: AsRef<str>>(items: &[S]) -> Vec<String> {
+    items.iter().map(|item| item.as_ref().to_string()).collect()
+}
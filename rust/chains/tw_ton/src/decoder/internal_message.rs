@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::decoder::payload::DecodedPayload;
+use tw_coin_entry::error::prelude::*;
+use tw_number::U256;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::cell_slice::CellSlice;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::{CellError, CellErrorType, CellResult};
+
+/// Decoded internal message sent by a wallet contract.
+pub struct DecodedInternalMessage {
+    /// https://docs.ton.org/develop/smart-contracts/messages#message-modes
+    pub mode: u8,
+    /// Destination address with the `bounceable` flag set according to the message header.
+    pub dest: TonAddress,
+    pub value: U256,
+    pub state_init: Option<CellArc>,
+    pub payload: DecodedPayload,
+}
+
+impl DecodedInternalMessage {
+    /// Decodes `MessageRelaxed CommonMsgInfoRelaxed` cell with the `int_msg_info$0` header.
+    pub fn decode(mode: u8, message: &Cell) -> CellResult<DecodedInternalMessage> {
+        let mut slice = message.slice();
+
+        let is_external = slice.load_bit()?; // bit0
+        if is_external {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Expected an internal message");
+        }
+        let _ihr_disabled = slice.load_bit()?;
+        let bounce = slice.load_bit()?;
+        let _bounced = slice.load_bit()?;
+        let _src_addr = slice.load_address()?;
+        let dest = TonAddress::with_address_data(slice.load_address()?).set_bounceable(bounce);
+        let value = slice.load_coins()?;
+        if slice.load_maybe_reference()?.is_some() {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Extra currencies are not supported");
+        }
+        let _ihr_fees = slice.load_coins()?;
+        let _fwd_fees = slice.load_coins()?;
+        let _created_lt = slice.load_u64(64)?;
+        let _created_at = slice.load_u32(32)?;
+
+        // (Maybe (Either StateInit ^StateInit))
+        let state_init = load_maybe_state_init(&mut slice)?;
+
+        // (Either X ^X)
+        let body = if slice.load_bit()? {
+            let body = slice.load_reference()?.clone();
+            slice.ensure_empty()?;
+            body
+        } else {
+            slice.load_remaining()?.into_arc()
+        };
+
+        Ok(DecodedInternalMessage {
+            mode,
+            dest,
+            value,
+            state_init,
+            payload: DecodedPayload::decode(body),
+        })
+    }
+}
+
+/// Loads `Maybe (Either StateInit ^StateInit)`.
+pub(crate) fn load_maybe_state_init(slice: &mut CellSlice) -> CellResult<Option<CellArc>> {
+    if !slice.load_bit()? {
+        return Ok(None);
+    }
+
+    if slice.load_bit()? {
+        return slice.load_reference().cloned().map(Some);
+    }
+
+    load_inline_state_init(slice)
+        .map(|state_init| Some(state_init.into_arc()))
+        .context("Error loading inline StateInit")
+}
+
+/// Copies an inline `StateInit` into a separate cell.
+/// https://github.com/ton-blockchain/ton/blob/24dc184a2ea67f9c47042b4104bbb4d82289fac1/crypto/block/block.tlb#L141
+fn load_inline_state_init(slice: &mut CellSlice) -> CellResult<Cell> {
+    let mut builder = CellBuilder::new();
+
+    // split_depth:(Maybe (## 5))
+    let has_split_depth = slice.load_bit()?;
+    builder.store_bit(has_split_depth)?;
+    if has_split_depth {
+        builder.store_u8(5, slice.load_u8(5)?)?;
+    }
+
+    // special:(Maybe TickTock)
+    let has_special = slice.load_bit()?;
+    builder.store_bit(has_special)?;
+    if has_special {
+        builder.store_u8(2, slice.load_u8(2)?)?;
+    }
+
+    // code:(Maybe ^Cell) data:(Maybe ^Cell) library:(Maybe ^Cell)
+    for _ in 0..3 {
+        let reference = slice.load_maybe_reference()?;
+        builder.store_bit(reference.is_some())?;
+        if let Some(reference) = reference {
+            builder.store_reference(reference)?;
+        }
+    }
+
+    builder.build()
+}
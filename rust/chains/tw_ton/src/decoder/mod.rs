@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::decoder::internal_message::{load_maybe_state_init, DecodedInternalMessage};
+use crate::message::external_message::wallet_v5::V5R1OpCode;
+use crate::message::out_list::out_action::OutActionType;
+use crate::transaction::INCOMING_EXTERNAL_TRANSACTION;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H512;
+use tw_ton_sdk::cell::cell_slice::CellSlice;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::{CellError, CellErrorType, CellResult};
+
+pub mod internal_message;
+pub mod payload;
+
+/// `signature (512) + wallet_id (32) + expire_at (32) + seqno (32) + op (8)`
+const WALLET_V4R2_HEADER_BITS: usize = 616;
+/// Each internal message is preceded by its `mode (8)`.
+const WALLET_V4R2_MODE_BITS: usize = 8;
+/// `opcode (32) + wallet_id (32) + expire_at (32) + seqno (32) + has_actions (1) + has_extended_actions (1) + signature (512)`
+const WALLET_V5R1_BODY_BITS: usize = 642;
+/// The only operation supported by WalletCore for V4R2 wallet.
+const WALLET_V4R2_SIMPLE_SEND_OP: u8 = 0;
+/// V5R1 wallet can hold at most 255 actions in a single request.
+const WALLET_V5R1_MAX_ACTIONS: usize = 255;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecodedWalletVersion {
+    V4R2,
+    V5R1,
+}
+
+/// Decoded signed body of an external message sent to a wallet contract.
+pub struct DecodedWalletMessage {
+    pub version: DecodedWalletVersion,
+    pub wallet_id: i32,
+    pub expire_at: u32,
+    pub seqno: u32,
+    pub signature: H512,
+    pub internal_messages: Vec<DecodedInternalMessage>,
+}
+
+impl DecodedWalletMessage {
+    /// Decodes the signed body. The wallet version is detected by the body layout.
+    pub fn decode(body: &Cell) -> CellResult<DecodedWalletMessage> {
+        let mut slice = body.slice();
+        let is_v5r1_body = body.bit_len() == WALLET_V5R1_BODY_BITS
+            && slice.load_u32(32)? == V5R1OpCode::AuthSignedExternal.to_ser_tag();
+
+        if is_v5r1_body {
+            Self::decode_v5r1(slice)
+        } else if body.bit_len()
+            == WALLET_V4R2_HEADER_BITS + WALLET_V4R2_MODE_BITS * body.references().len()
+        {
+            Self::decode_v4r2(body.slice())
+        } else {
+            CellError::err(CellErrorType::CellParserError)
+                .context("Unknown wallet version: only V4R2 and V5R1 wallets are supported")
+        }
+    }
+
+    /// Decodes the body built by [`crate::message::signed_message::signed_message_v4::SignedMessageV4`].
+    fn decode_v4r2(mut slice: CellSlice) -> CellResult<DecodedWalletMessage> {
+        let mut signature = H512::default();
+        slice.load_slice(signature.as_mut_slice())?;
+
+        let wallet_id = slice.load_i32(32)?;
+        let expire_at = slice.load_u32(32)?;
+        let seqno = slice.load_u32(32)?;
+
+        let op = slice.load_u8(8)?;
+        if op != WALLET_V4R2_SIMPLE_SEND_OP {
+            return CellError::err(CellErrorType::CellParserError)
+                .context(format!("Unsupported V4R2 wallet operation: {op}"));
+        }
+
+        let mut internal_messages = Vec::with_capacity(slice.remaining_references());
+        while slice.remaining_references() > 0 {
+            let mode = slice.load_u8(8)?;
+            let message = slice.load_reference()?;
+            internal_messages.push(DecodedInternalMessage::decode(mode, message)?);
+        }
+        slice.ensure_empty()?;
+
+        Ok(DecodedWalletMessage {
+            version: DecodedWalletVersion::V4R2,
+            wallet_id,
+            expire_at,
+            seqno,
+            signature,
+            internal_messages,
+        })
+    }
+
+    /// Decodes the body built by [`crate::message::signed_message::signed_message_v5::SignedMessageV5`].
+    /// Expects the opcode to be read already.
+    fn decode_v5r1(mut slice: CellSlice) -> CellResult<DecodedWalletMessage> {
+        let wallet_id = slice.load_i32(32)?;
+        let expire_at = slice.load_u32(32)?;
+        let seqno = slice.load_u32(32)?;
+
+        let internal_messages = match slice.load_maybe_reference()? {
+            Some(out_list) => decode_out_list(out_list)?,
+            None => Vec::default(),
+        };
+
+        let has_extended_actions = slice.load_bit()?;
+        if has_extended_actions {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Extended actions are not supported");
+        }
+
+        let mut signature = H512::default();
+        slice.load_slice(signature.as_mut_slice())?;
+        slice.ensure_empty()?;
+
+        Ok(DecodedWalletMessage {
+            version: DecodedWalletVersion::V5R1,
+            wallet_id,
+            expire_at,
+            seqno,
+            signature,
+            internal_messages,
+        })
+    }
+}
+
+/// Decoded external message sent to a wallet contract.
+pub struct DecodedExternalMessage {
+    /// The wallet contract address.
+    pub dest: TonAddress,
+    /// The wallet `StateInit`. Present if the message deploys the wallet contract.
+    pub state_init: Option<CellArc>,
+    pub body: DecodedWalletMessage,
+}
+
+impl DecodedExternalMessage {
+    /// Decodes the cell built by [`crate::transaction::SignedTransaction`].
+    pub fn decode(message: &Cell) -> CellResult<DecodedExternalMessage> {
+        let mut slice = message.slice();
+
+        let tag = slice.load_u8(2)?;
+        if tag != INCOMING_EXTERNAL_TRANSACTION {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Expected an incoming external message");
+        }
+        let _src_addr = slice.load_address()?;
+        let dest = TonAddress::with_address_data(slice.load_address()?);
+        let _import_fee = slice.load_coins()?;
+
+        // (Maybe (Either StateInit ^StateInit))
+        let state_init = load_maybe_state_init(&mut slice)?;
+
+        // (Either X ^X)
+        let body = if slice.load_bit()? {
+            let body = slice.load_reference()?.clone();
+            slice.ensure_empty()?;
+            body
+        } else {
+            slice.load_remaining()?.into_arc()
+        };
+
+        Ok(DecodedExternalMessage {
+            dest,
+            state_init,
+            body: DecodedWalletMessage::decode(&body)?,
+        })
+    }
+}
+
+/// Decodes the list built by [`crate::message::out_list::build_out_list`].
+/// Each cell of the list is `out_list$_ prev:^(OutList n) action:OutAction`.
+fn decode_out_list(out_list: &Cell) -> CellResult<Vec<DecodedInternalMessage>> {
+    let mut messages = Vec::new();
+    let mut current = out_list.slice();
+
+    while !current.is_empty() {
+        if messages.len() == WALLET_V5R1_MAX_ACTIONS {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Maximum number of actions in a single request is 255");
+        }
+
+        let prev = current.load_reference()?;
+
+        let action_tag = current.load_u32(32)?;
+        if action_tag != OutActionType::SendMsg.to_ser_tag() {
+            return CellError::err(CellErrorType::CellParserError)
+                .context(format!("Unsupported out action: {action_tag:#x}"));
+        }
+        let mode = current.load_u8(8)?;
+        let message = current.load_reference()?;
+        current.ensure_empty()?;
+
+        messages.push(DecodedInternalMessage::decode(mode, message)?);
+        current = prev.slice();
+    }
+
+    Ok(messages)
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::message::payload::jetton_transfer::JETTON_TRANSFER;
+use tw_coin_entry::error::prelude::*;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_ton_sdk::cell::cell_slice::CellSlice;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::{CellError, CellErrorType, CellResult};
+
+const COMMENT_OP: u32 = 0;
+
+/// Decoded Jetton transfer message payload.
+pub struct DecodedJettonTransfer {
+    pub query_id: u64,
+    pub jetton_amount: U256,
+    pub destination: TonAddress,
+    pub response_destination: TonAddress,
+    pub custom_payload: Option<CellArc>,
+    pub forward_ton_amount: U256,
+    /// Comment stored as the `forward_payload`.
+    pub comment: Option<String>,
+}
+
+/// Decoded internal message body.
+pub enum DecodedPayload {
+    /// The message body is empty.
+    Empty,
+    /// Text comment.
+    Comment(String),
+    /// Jetton transfer, supported only if `forward_payload` is empty or a comment.
+    JettonTransfer(DecodedJettonTransfer),
+    /// Unknown message body.
+    Custom(CellArc),
+}
+
+impl DecodedPayload {
+    /// Recognizes the message body.
+    /// Never fails, but returns [`DecodedPayload::Custom`] if the body is not known.
+    pub fn decode(body: CellArc) -> DecodedPayload {
+        let mut slice = body.slice();
+        if slice.is_empty() {
+            return DecodedPayload::Empty;
+        }
+
+        let payload = match slice.load_u32(32) {
+            Ok(COMMENT_OP) => load_snake_string(&mut slice).map(DecodedPayload::Comment),
+            Ok(JETTON_TRANSFER) => {
+                decode_jetton_transfer(&mut slice).map(DecodedPayload::JettonTransfer)
+            },
+            _ => return DecodedPayload::Custom(body.clone()),
+        };
+
+        match payload {
+            Ok(payload) => payload,
+            Err(_) => DecodedPayload::Custom(body.clone()),
+        }
+    }
+}
+
+/// Decodes the `transfer` message defined in TEP-74:
+/// https://github.com/ton-blockchain/TEPs/blob/master/text/0074-jettons-standard.md#1-transfer
+fn decode_jetton_transfer(slice: &mut CellSlice) -> CellResult<DecodedJettonTransfer> {
+    let query_id = slice.load_u64(64)?;
+    let jetton_amount = slice.load_coins()?;
+    let destination = TonAddress::with_address_data(slice.load_address()?);
+    let response_destination = TonAddress::with_address_data(slice.load_address()?);
+    let custom_payload = slice.load_maybe_reference()?.cloned();
+    let forward_ton_amount = slice.load_coins()?;
+
+    // (Either Cell ^Cell)
+    let comment = if slice.load_bit()? {
+        let mut forward_payload = slice.load_reference()?.slice();
+        let comment = load_maybe_comment(&mut forward_payload)?;
+        slice.ensure_empty()?;
+        comment
+    } else {
+        load_maybe_comment(slice)?
+    };
+
+    Ok(DecodedJettonTransfer {
+        query_id,
+        jetton_amount,
+        destination,
+        response_destination,
+        custom_payload,
+        forward_ton_amount,
+        comment,
+    })
+}
+
+/// Loads an optional text comment. Returns an error if the slice contains anything but a comment.
+fn load_maybe_comment(slice: &mut CellSlice) -> CellResult<Option<String>> {
+    if slice.is_empty() {
+        return Ok(None);
+    }
+
+    let op = slice.load_u32(32)?;
+    if op != COMMENT_OP {
+        return CellError::err(CellErrorType::CellParserError).context(format!(
+            "Expected a text comment, found '{op:#x}' operation"
+        ));
+    }
+    load_snake_string(slice).map(Some)
+}
+
+/// Loads a string stored in the snake format:
+/// https://docs.ton.org/develop/dapps/asset-processing/metadata#snake-data-encoding
+fn load_snake_string(slice: &mut CellSlice) -> CellResult<String> {
+    let mut bytes = Data::new();
+    load_snake_bytes(slice, &mut bytes)?;
+    String::from_utf8(bytes).tw_err(CellErrorType::CellParserError)
+}
+
+fn load_snake_bytes(slice: &mut CellSlice, bytes: &mut Data) -> CellResult<()> {
+    let remaining_bits = slice.remaining_bits();
+    if remaining_bits % 8 != 0 {
+        return CellError::err(CellErrorType::CellParserError)
+            .context("String must be stored as whole bytes");
+    }
+    bytes.extend(slice.load_bytes(remaining_bits / 8)?);
+
+    if slice.remaining_references() == 0 {
+        return Ok(());
+    }
+    let next: &Cell = slice.load_reference()?;
+    slice.ensure_empty()?;
+    load_snake_bytes(&mut next.slice(), bytes)
+}
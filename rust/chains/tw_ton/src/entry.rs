@@ -4,7 +4,10 @@
 
 use crate::address::TonAddress;
 use crate::compiler::TheOpenNetworkCompiler;
+use crate::modules::message_signer::TonMessageSigner;
+use crate::modules::transaction_decoder::TonTransactionDecoder;
 use crate::modules::transaction_util::TonTransactionUtil;
+use crate::modules::wallet_connect::connector::TonWalletConnector;
 use crate::signer::TheOpenNetworkSigner;
 use crate::wallet::{wallet_v4, VersionedTonWallet};
 use std::str::FromStr;
@@ -13,10 +16,7 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
 use tw_proto::TheOpenNetwork::Proto;
@@ -34,9 +34,9 @@ impl CoinEntry for TheOpenNetworkEntry {
     // Optional modules:
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
-    type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type MessageSigner = TonMessageSigner;
+    type WalletConnector = TonWalletConnector;
+    type TransactionDecoder = TonTransactionDecoder;
     type TransactionUtil = TonTransactionUtil;
 
     #[inline]
@@ -99,6 +99,21 @@ impl CoinEntry for TheOpenNetworkEntry {
         TheOpenNetworkCompiler::compile(coin, input, signatures, public_keys)
     }
 
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(TonMessageSigner)
+    }
+
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(TonWalletConnector)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(TonTransactionDecoder)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(TonTransactionUtil)
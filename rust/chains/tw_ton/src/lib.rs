@@ -4,6 +4,7 @@
 
 pub mod address;
 pub mod compiler;
+pub mod decoder;
 pub mod entry;
 pub mod message;
 pub mod modules;
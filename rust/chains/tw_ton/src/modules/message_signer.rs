@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::compiler::HAS_CRC32;
+use crate::modules::ton_connect::TonConnectMessage;
+use crate::wallet::VersionedTonWallet;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::{self, STANDARD};
+use tw_keypair::ed25519;
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_misc::try_or_false;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_ton_sdk::address::address_data::AddressData;
+use tw_ton_sdk::address::raw_address::RawAddress;
+use tw_ton_sdk::boc::BagOfCells;
+use tw_ton_sdk::error::cell_to_signing_error;
+
+/// TonConnect message signer. Supports `ton_proof` and `signData` requests.
+pub struct TonMessageSigner;
+
+impl TonMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let wallet = if input.private_key.is_empty() {
+            let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())
+                .into_tw()
+                .context("Expected either 'private_key' or 'public_key' to be set")?;
+            VersionedTonWallet::std_with_public_key(input.wallet_version, public_key)?
+        } else {
+            let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())
+                .into_tw()
+                .context("Invalid private key")?;
+            VersionedTonWallet::std_with_key_pair(input.wallet_version, &key_pair)?
+        };
+
+        let message = Self::signing_message(&input.message_oneof)?;
+        let hash = message.signing_hash(wallet.address().as_ref())?;
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: hash.to_vec().into(),
+            data_hash: hash.to_vec().into(),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())
+            .into_tw()
+            .context("Invalid private key")?;
+        let wallet = VersionedTonWallet::std_with_key_pair(input.wallet_version, &key_pair)?;
+        let address: AddressData = wallet.address().as_ref().clone();
+
+        let message = Self::signing_message(&input.message_oneof)?;
+        let hash = message.signing_hash(&address)?;
+        let signature = key_pair.sign(hash.to_vec())?;
+
+        let state_init = wallet.state_init().map_err(cell_to_signing_error)?;
+        let state_init_boc =
+            BagOfCells::from_root(state_init.to_cell().map_err(cell_to_signing_error)?)
+                .to_base64(HAS_CRC32)
+                .map_err(cell_to_signing_error)?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: base64::encode(signature.to_bytes().as_slice(), STANDARD).into(),
+            address: RawAddress::from(address).to_string().into(),
+            state_init: state_init_boc.into(),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        use Proto::mod_MessageVerifyingInput::OneOfmessage_oneof as VerifyingMessage;
+
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())
+            .into_tw()
+            .context("Invalid public key")?;
+        let signature = base64::decode(input.signature.as_ref(), STANDARD)
+            .ok()
+            .and_then(|signature| ed25519::Signature::try_from(signature.as_slice()).ok())
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid signature")?;
+
+        let message = match input.message_oneof {
+            VerifyingMessage::ton_proof(ref proof) => {
+                TonConnectMessage::from_proto_ton_proof(proof)
+            },
+            VerifyingMessage::sign_data(ref sign_data) => {
+                TonConnectMessage::from_proto_sign_data(sign_data)?
+            },
+            VerifyingMessage::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No message provided")
+            },
+        };
+
+        let wallet =
+            VersionedTonWallet::std_with_public_key(input.wallet_version, public_key.clone())?;
+        let hash = message.signing_hash(wallet.address().as_ref())?;
+
+        Ok(public_key.verify(signature, hash.to_vec()))
+    }
+
+    fn signing_message(
+        message: &Proto::mod_MessageSigningInput::OneOfmessage_oneof<'_>,
+    ) -> SigningResult<TonConnectMessage> {
+        use Proto::mod_MessageSigningInput::OneOfmessage_oneof as SigningMessage;
+
+        match message {
+            SigningMessage::ton_proof(proof) => Ok(TonConnectMessage::from_proto_ton_proof(proof)),
+            SigningMessage::sign_data(sign_data) => {
+                TonConnectMessage::from_proto_sign_data(sign_data)
+            },
+            SigningMessage::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No message provided"),
+        }
+    }
+}
+
+impl MessageSigner for TonMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        try_or_false!(Self::verify_message_impl(input))
+    }
+}
@@ -3,6 +3,10 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod address_converter;
+pub mod message_signer;
 pub mod personal_message_signer;
+pub mod ton_connect;
+pub mod transaction_decoder;
 pub mod transaction_util;
+pub mod wallet_connect;
 pub mod wallet_provider;
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::ton_connect::sign_data::{SignData, SignDataPayload};
+use crate::modules::ton_connect::ton_proof::TonProof;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_ton_sdk::address::address_data::AddressData;
+use tw_ton_sdk::boc::BagOfCells;
+use tw_ton_sdk::error::cell_to_signing_error;
+
+pub mod sign_data;
+pub mod ton_proof;
+
+/// A message signed via TonConnect protocol.
+pub enum TonConnectMessage {
+    TonProof(TonProof),
+    SignData(SignData),
+}
+
+impl TonConnectMessage {
+    pub fn from_proto_ton_proof(proof: &Proto::TonProof) -> TonConnectMessage {
+        TonConnectMessage::TonProof(TonProof {
+            domain: proof.domain.to_string(),
+            timestamp: proof.timestamp,
+            payload: proof.payload.to_string(),
+        })
+    }
+
+    pub fn from_proto_sign_data(sign_data: &Proto::SignData) -> SigningResult<TonConnectMessage> {
+        use Proto::mod_SignData::OneOfpayload as ProtoPayload;
+
+        let payload = match sign_data.payload {
+            ProtoPayload::text(ref text) => SignDataPayload::Text(text.to_string()),
+            ProtoPayload::binary(ref binary) => SignDataPayload::Binary(binary.to_vec()),
+            ProtoPayload::cell(ref cell) => {
+                let cell_boc = BagOfCells::parse_base64(&cell.cell)
+                    .context("Error parsing 'SignData.cell' as BoC")
+                    .map_err(cell_to_signing_error)?;
+                SignDataPayload::Cell {
+                    schema: cell.schema.to_string(),
+                    cell: cell_boc
+                        .single_root()
+                        .map_err(cell_to_signing_error)?
+                        .clone(),
+                }
+            },
+            ProtoPayload::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No 'SignData' payload provided")
+            },
+        };
+
+        Ok(TonConnectMessage::SignData(SignData {
+            domain: sign_data.domain.to_string(),
+            timestamp: sign_data.timestamp,
+            payload,
+        }))
+    }
+
+    /// Returns the hash to be signed on behalf of the wallet `address`.
+    pub fn signing_hash(&self, address: &AddressData) -> SigningResult<H256> {
+        match self {
+            TonConnectMessage::TonProof(proof) => Ok(proof.signing_hash(address)),
+            TonConnectMessage::SignData(sign_data) => sign_data
+                .signing_hash(address)
+                .map_err(cell_to_signing_error),
+        }
+    }
+}
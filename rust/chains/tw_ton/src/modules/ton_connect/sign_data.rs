@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_ton_sdk::address::address_data::AddressData;
+use tw_ton_sdk::address::raw_address::RawAddress;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::crc::CRC_32_ISO_HDLC;
+use tw_ton_sdk::error::CellResult;
+
+pub const SIGN_DATA_PREFIX: &str = "ton-connect/sign-data/";
+pub const SIGN_DATA_CELL_TAG: u32 = 0x75569022;
+const TEXT_PAYLOAD_TYPE: &[u8] = b"txt";
+const BINARY_PAYLOAD_TYPE: &[u8] = b"bin";
+/// Number of bytes stored in a single cell of the snake data.
+const SNAKE_CELL_BYTES: usize = 127;
+
+pub enum SignDataPayload {
+    Text(String),
+    Binary(Data),
+    Cell { schema: String, cell: CellArc },
+}
+
+/// TonConnect `signData` request.
+/// https://github.com/ton-blockchain/ton-connect/blob/main/requests-responses.md#sign-data
+pub struct SignData {
+    pub domain: String,
+    pub timestamp: u64,
+    pub payload: SignDataPayload,
+}
+
+impl SignData {
+    /// Returns the hash the wallet signs on behalf of the given `address`.
+    pub fn signing_hash(&self, address: &AddressData) -> CellResult<H256> {
+        match self.payload {
+            SignDataPayload::Text(ref text) => {
+                Ok(self.bytes_signing_hash(address, TEXT_PAYLOAD_TYPE, text.as_bytes()))
+            },
+            SignDataPayload::Binary(ref binary) => {
+                Ok(self.bytes_signing_hash(address, BINARY_PAYLOAD_TYPE, binary))
+            },
+            SignDataPayload::Cell {
+                ref schema,
+                ref cell,
+            } => self
+                .cell_to_sign(address, schema, cell)
+                .map(|cell| cell.cell_hash()),
+        }
+    }
+
+    /// `sha256(0xffff ++ "ton-connect/sign-data/" ++ Address ++ AppDomain ++ Timestamp ++ Payload)`,
+    /// where all the integers are big endian.
+    fn bytes_signing_hash(
+        &self,
+        address: &AddressData,
+        payload_type: &[u8],
+        payload: &[u8],
+    ) -> H256 {
+        let domain = self.domain.as_bytes();
+
+        let mut message = vec![0xff_u8, 0xff];
+        message.extend_from_slice(SIGN_DATA_PREFIX.as_bytes());
+        message.extend_from_slice(&address.workchain.to_be_bytes());
+        message.extend_from_slice(address.hash_part.as_slice());
+        message.extend_from_slice(&(domain.len() as u32).to_be_bytes());
+        message.extend_from_slice(domain);
+        message.extend_from_slice(&self.timestamp.to_be_bytes());
+        message.extend_from_slice(payload_type);
+        message.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        message.extend_from_slice(payload);
+
+        H256::try_from(sha256(&message).as_slice()).expect("Expected 32 bytes hash")
+    }
+
+    /// Builds the cell that can be verified on-chain:
+    /// ```tlb
+    /// message#75569022 schema_hash:uint32 timestamp:uint64 userAddress:MsgAddress
+    ///                  {n:#} appDomain:^(SnakeData ~n) payload:^Cell = Message;
+    /// ```
+    fn cell_to_sign(
+        &self,
+        address: &AddressData,
+        schema: &str,
+        payload: &CellArc,
+    ) -> CellResult<Cell> {
+        let schema_hash = CRC_32_ISO_HDLC.checksum(schema.as_bytes());
+
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u32(32, SIGN_DATA_CELL_TAG)?
+            .store_u32(32, schema_hash)?
+            .store_u64(64, self.timestamp)?
+            .store_address(RawAddress::from(address.clone()))?
+            .store_child(snake_data(self.domain.as_bytes())?)?
+            .store_reference(payload)?;
+        builder.build()
+    }
+}
+
+/// Stores the bytes in the snake format:
+/// https://docs.ton.org/develop/dapps/asset-processing/metadata#snake-data-encoding
+fn snake_data(bytes: &[u8]) -> CellResult<Cell> {
+    let mut tail: Option<Cell> = None;
+    for chunk in bytes.chunks(SNAKE_CELL_BYTES).rev() {
+        let mut builder = CellBuilder::new();
+        builder.store_slice(chunk)?;
+        if let Some(next) = tail {
+            builder.store_child(next)?;
+        }
+        tail = Some(builder.build()?);
+    }
+
+    match tail {
+        Some(head) => Ok(head),
+        // Empty domain is stored as an empty cell.
+        None => CellBuilder::new().build(),
+    }
+}
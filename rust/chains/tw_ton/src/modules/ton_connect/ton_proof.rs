@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_ton_sdk::address::address_data::AddressData;
+
+pub const TON_PROOF_ITEM_PREFIX: &str = "ton-proof-item-v2/";
+pub const TON_CONNECT_PREFIX: &str = "ton-connect";
+
+/// TonConnect `ton_proof` item.
+/// https://docs.ton.org/develop/dapps/ton-connect/sign#concept-explanation
+pub struct TonProof {
+    pub domain: String,
+    pub timestamp: u64,
+    pub payload: String,
+}
+
+impl TonProof {
+    /// Returns the message the wallet signs on behalf of the given `address`:
+    /// `sha256(0xffff ++ "ton-connect" ++ sha256(message))`, where
+    /// `message = "ton-proof-item-v2/" ++ Address ++ AppDomain ++ Timestamp ++ Payload`.
+    pub fn signing_hash(&self, address: &AddressData) -> H256 {
+        let message_hash = sha256(&self.message(address));
+
+        let mut full_message = vec![0xff_u8, 0xff];
+        full_message.extend_from_slice(TON_CONNECT_PREFIX.as_bytes());
+        full_message.extend_from_slice(&message_hash);
+
+        H256::try_from(sha256(&full_message).as_slice()).expect("Expected 32 bytes hash")
+    }
+
+    fn message(&self, address: &AddressData) -> Data {
+        let domain = self.domain.as_bytes();
+
+        let mut message = Data::new();
+        message.extend_from_slice(TON_PROOF_ITEM_PREFIX.as_bytes());
+        // Address: workchain (32-bit big endian) ++ hash (256 bits).
+        message.extend_from_slice(&address.workchain.to_be_bytes());
+        message.extend_from_slice(address.hash_part.as_slice());
+        // AppDomain: length (32-bit little endian) ++ domain.
+        message.extend_from_slice(&(domain.len() as u32).to_le_bytes());
+        message.extend_from_slice(domain);
+        // Timestamp (64-bit little endian).
+        message.extend_from_slice(&self.timestamp.to_le_bytes());
+        message.extend_from_slice(self.payload.as_bytes());
+        message
+    }
+}
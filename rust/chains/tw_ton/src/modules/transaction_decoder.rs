@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::compiler::HAS_CRC32;
+use crate::decoder::internal_message::DecodedInternalMessage;
+use crate::decoder::payload::{DecodedJettonTransfer, DecodedPayload};
+use crate::decoder::{DecodedExternalMessage, DecodedWalletVersion};
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_number::U256;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_ton_sdk::boc::BagOfCells;
+use tw_ton_sdk::cell::CellArc;
+use tw_ton_sdk::error::cell_to_signing_error;
+use Proto::mod_Transfer::OneOfpayload as PayloadType;
+
+pub struct TonTransactionDecoder;
+
+impl TransactionDecoder for TonTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl TonTransactionDecoder {
+    /// Decodes a signed external message encoded as BoC.
+    fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let boc = BagOfCells::parse(tx)
+            .context("Error parsing transaction as BoC")
+            .map_err(cell_to_signing_error)?;
+        let root = boc.single_root().map_err(cell_to_signing_error)?;
+
+        let external_message = DecodedExternalMessage::decode(root)
+            .context("Error decoding an external message")
+            .map_err(cell_to_signing_error)?;
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(Self::decoded_transaction(external_message)?),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    fn decoded_transaction(
+        external_message: DecodedExternalMessage,
+    ) -> SigningResult<Proto::DecodedTransaction<'static>> {
+        let body = external_message.body;
+
+        let wallet_version = match body.version {
+            DecodedWalletVersion::V4R2 => Proto::WalletVersion::WALLET_V4_R2,
+            DecodedWalletVersion::V5R1 => Proto::WalletVersion::WALLET_V5_R1,
+        };
+
+        let messages = body
+            .internal_messages
+            .into_iter()
+            .map(Self::transfer)
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(Proto::DecodedTransaction {
+            wallet_version,
+            wallet_id: body.wallet_id,
+            sequence_number: body.seqno,
+            expire_at: body.expire_at,
+            wallet_address: external_message.dest.to_string().into(),
+            has_state_init: external_message.state_init.is_some(),
+            signature: body.signature.to_vec().into(),
+            messages,
+        })
+    }
+
+    fn transfer(message: DecodedInternalMessage) -> SigningResult<Proto::Transfer<'static>> {
+        let state_init = message
+            .state_init
+            .map(Self::cell_to_base64)
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut transfer = Proto::Transfer {
+            dest: message.dest.to_string().into(),
+            amount: Self::u64_amount(message.value, "amount")?,
+            mode: message.mode as u32,
+            bounceable: message.dest.bounceable(),
+            state_init: state_init.into(),
+            ..Proto::Transfer::default()
+        };
+
+        match message.payload {
+            DecodedPayload::Empty => (),
+            DecodedPayload::Comment(comment) => transfer.comment = comment.into(),
+            DecodedPayload::JettonTransfer(jetton) => {
+                // Jetton transfer comment is set to `Transfer.comment` the same way it's signed.
+                transfer.comment = jetton.comment.clone().unwrap_or_default().into();
+                transfer.payload = PayloadType::jetton_transfer(Self::jetton_transfer(jetton)?);
+            },
+            DecodedPayload::Custom(body) => {
+                transfer.payload = PayloadType::custom_payload(Self::cell_to_base64(body)?.into());
+            },
+        }
+
+        Ok(transfer)
+    }
+
+    fn jetton_transfer(
+        jetton: DecodedJettonTransfer,
+    ) -> SigningResult<Proto::JettonTransfer<'static>> {
+        let custom_payload = jetton
+            .custom_payload
+            .map(Self::cell_to_base64)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Proto::JettonTransfer {
+            query_id: jetton.query_id,
+            jetton_amount: Self::u64_amount(jetton.jetton_amount, "jetton_amount")?,
+            to_owner: jetton.destination.to_string().into(),
+            response_address: jetton.response_destination.to_string().into(),
+            forward_amount: Self::u64_amount(jetton.forward_ton_amount, "forward_amount")?,
+            custom_payload: custom_payload.into(),
+        })
+    }
+
+    fn cell_to_base64(cell: CellArc) -> SigningResult<String> {
+        BagOfCells { roots: vec![cell] }
+            .to_base64(HAS_CRC32)
+            .map_err(cell_to_signing_error)
+    }
+
+    fn u64_amount(amount: U256, field: &str) -> SigningResult<u64> {
+        u64::try_from(amount)
+            .tw_err(SigningErrorType::Error_not_supported)
+            .with_context(|| format!("'{field}' does not fit uint64"))
+    }
+}
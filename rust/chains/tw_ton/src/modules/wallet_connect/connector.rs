@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::modules::wallet_connect::request::{MessageRequest, SendMessageRequest};
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+use Proto::mod_Transfer::OneOfpayload as PayloadType;
+
+/// Wallet V4R2 supports up to 4 internal messages.
+const MAX_MESSAGES: usize = 4;
+/// TonConnect messages are sent with `PAY_FEES_SEPARATELY | IGNORE_ACTION_PHASE_ERRORS` mode.
+const DEFAULT_SEND_MODE: u32 = Proto::SendMode::PAY_FEES_SEPARATELY as u32
+    | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32;
+
+pub struct TonWalletConnector;
+
+impl WalletConnector for TonWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl TonWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::TonSendMessage => Self::parse_send_message_request(coin, request),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    /// Please note that `sequence_number` and the signer key are not known from the request
+    /// and have to be set by the caller.
+    pub fn parse_send_message_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let send_request: SendMessageRequest = serde_json::from_str(&request.payload)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error parsing WalletConnect signing request as JSON")?;
+
+        if send_request.messages.is_empty() || send_request.messages.len() > MAX_MESSAGES {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .with_context(|| format!("Expected 1 to {MAX_MESSAGES} messages"));
+        }
+
+        let messages = send_request
+            .messages
+            .into_iter()
+            .map(Self::transfer_from_request)
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let signing_input = Proto::SigningInput {
+            messages,
+            expire_at: send_request.valid_until.unwrap_or_default(),
+            wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+            ..Proto::SigningInput::default()
+        };
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ton(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    fn transfer_from_request(message: MessageRequest) -> SigningResult<Proto::Transfer<'static>> {
+        let dest = TonAddress::from_str(&message.address)
+            .into_tw()
+            .context("Invalid message 'address'")?;
+
+        // Raw addresses don't carry the flag, so consider them bounceable
+        // to get the funds back if the destination contract is not deployed.
+        let is_raw_address = message.address.contains(':');
+        let bounceable = is_raw_address || dest.bounceable();
+
+        let payload = match message.payload {
+            Some(payload) => PayloadType::custom_payload(payload.into()),
+            None => PayloadType::None,
+        };
+
+        Ok(Proto::Transfer {
+            dest: message.address.into(),
+            amount: message.amount,
+            mode: DEFAULT_SEND_MODE,
+            bounceable,
+            state_init: message.state_init.unwrap_or_default().into(),
+            payload,
+            ..Proto::Transfer::default()
+        })
+    }
+}
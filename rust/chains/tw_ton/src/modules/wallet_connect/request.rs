@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use tw_misc::serde::as_string;
+
+/// A single internal message of the `ton_sendMessage` request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageRequest {
+    /// Destination address in either raw or user-friendly format.
+    pub address: String,
+    /// Amount to send in nanotons.
+    #[serde(with = "as_string")]
+    pub amount: u64,
+    /// Optional raw one-cell BoC encoded in Base64.
+    pub payload: Option<String>,
+    /// Optional raw one-cell BoC encoded in Base64.
+    pub state_init: Option<String>,
+}
+
+/// `ton_sendMessage` params.
+/// https://github.com/ton-blockchain/ton-connect/blob/main/requests-responses.md#sign-and-send-transaction
+#[derive(Deserialize)]
+pub struct SendMessageRequest {
+    /// Expiration UNIX timestamp.
+    pub valid_until: Option<u32>,
+    pub from: Option<String>,
+    pub messages: Vec<MessageRequest>,
+}
@@ -6,13 +6,12 @@ use crate::address::TonAddress;
 use crate::signing_request::{
     JettonTransferRequest, SigningRequest, TransferPayload, TransferRequest,
 };
-use crate::wallet::{wallet_v4, wallet_v5, VersionedTonWallet};
+use crate::wallet::VersionedTonWallet;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_keypair::ed25519::sha512::{KeyPair, PublicKey};
 use tw_number::U256;
 use tw_proto::TheOpenNetwork::Proto;
-use tw_ton_sdk::error::cell_to_signing_error;
 use Proto::mod_Transfer::OneOfpayload as PayloadType;
 
 const STATE_INIT_EXPIRE_AT: u32 = 0xffffffff;
@@ -52,37 +51,13 @@ impl SigningRequestBuilder {
             let key_pair = KeyPair::try_from(input.private_key.as_ref())
                 .into_tw()
                 .context("Invalid private key")?;
-
-            return match input.wallet_version {
-                Proto::WalletVersion::WALLET_V4_R2 => Ok(VersionedTonWallet::V4R2(
-                    wallet_v4::WalletV4R2::std_with_key_pair(&key_pair)
-                        .map_err(cell_to_signing_error)?,
-                )),
-                Proto::WalletVersion::WALLET_V5_R1 => Ok(VersionedTonWallet::V5R1(
-                    wallet_v5::WalletV5R1::std_with_key_pair(&key_pair)
-                        .map_err(cell_to_signing_error)?,
-                )),
-                _ => SigningError::err(SigningErrorType::Error_not_supported)
-                    .context("Wallet version not supported"),
-            };
+            return VersionedTonWallet::std_with_key_pair(input.wallet_version, &key_pair);
         }
 
         let public_key = PublicKey::try_from(input.public_key.as_ref())
             .into_tw()
             .context("Expected either 'private_key' or 'public_key' to be set")?;
-
-        match input.wallet_version {
-            Proto::WalletVersion::WALLET_V4_R2 => Ok(VersionedTonWallet::V4R2(
-                wallet_v4::WalletV4R2::std_with_public_key(public_key)
-                    .map_err(cell_to_signing_error)?,
-            )),
-            Proto::WalletVersion::WALLET_V5_R1 => Ok(VersionedTonWallet::V5R1(
-                wallet_v5::WalletV5R1::std_with_public_key(public_key)
-                    .map_err(cell_to_signing_error)?,
-            )),
-            _ => SigningError::err(SigningErrorType::Error_not_supported)
-                .context("Wallet version not supported"),
-        }
+        VersionedTonWallet::std_with_public_key(input.wallet_version, public_key)
     }
 
     fn transfer_request(input: &Proto::Transfer) -> SigningResult<TransferRequest> {
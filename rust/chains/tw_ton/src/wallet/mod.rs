@@ -8,9 +8,11 @@ use crate::message::signed_message::signed_message_v4::SignedMessageV4;
 use crate::message::signed_message::signed_message_v5::SignedMessageV5;
 use crate::transaction::SignedTransaction;
 use tw_coin_entry::error::prelude::*;
+use tw_keypair::ed25519::sha512::{KeyPair, PublicKey};
 use tw_keypair::ed25519::Signature;
 use tw_keypair::traits::SigningKeyTrait;
 use tw_number::U256;
+use tw_proto::TheOpenNetwork::Proto;
 use tw_ton_sdk::cell::Cell;
 use tw_ton_sdk::error::{cell_to_signing_error, CellResult};
 use tw_ton_sdk::message::state_init::StateInit;
@@ -27,6 +29,47 @@ pub enum VersionedTonWallet {
 }
 
 impl VersionedTonWallet {
+    /// Creates a standard TON wallet of the given version from the key-pair.
+    /// Currently, V4R2 and V5R1 wallets supported.
+    pub fn std_with_key_pair(
+        version: Proto::WalletVersion,
+        key_pair: &KeyPair,
+    ) -> SigningResult<Self> {
+        match version {
+            Proto::WalletVersion::WALLET_V4_R2 => Ok(Self::V4R2(
+                wallet_v4::WalletV4R2::std_with_key_pair(key_pair)
+                    .map_err(cell_to_signing_error)?,
+            )),
+            Proto::WalletVersion::WALLET_V5_R1 => Ok(Self::V5R1(
+                wallet_v5::WalletV5R1::std_with_key_pair(key_pair)
+                    .map_err(cell_to_signing_error)?,
+            )),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Wallet version not supported"),
+        }
+    }
+
+    /// Creates a standard TON wallet of the given version from the public key.
+    /// Please note when created with public key only, wallet cannot be used to sign messages.
+    /// Currently, V4R2 and V5R1 wallets supported.
+    pub fn std_with_public_key(
+        version: Proto::WalletVersion,
+        public_key: PublicKey,
+    ) -> SigningResult<Self> {
+        match version {
+            Proto::WalletVersion::WALLET_V4_R2 => Ok(Self::V4R2(
+                wallet_v4::WalletV4R2::std_with_public_key(public_key)
+                    .map_err(cell_to_signing_error)?,
+            )),
+            Proto::WalletVersion::WALLET_V5_R1 => Ok(Self::V5R1(
+                wallet_v5::WalletV5R1::std_with_public_key(public_key)
+                    .map_err(cell_to_signing_error)?,
+            )),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Wallet version not supported"),
+        }
+    }
+
     pub fn address(&self) -> &TonAddress {
         match self {
             Self::V4R2(wallet_v4r2) => &wallet_v4r2.address,
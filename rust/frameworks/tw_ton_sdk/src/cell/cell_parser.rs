@@ -46,6 +46,12 @@ impl<'a> CellParser<'a> {
             .tw_err(CellErrorType::CellParserError)
     }
 
+    pub fn load_i32(&mut self, bit_len: usize) -> CellResult<i32> {
+        self.bit_reader
+            .read_i32(bit_len as u8)
+            .tw_err(CellErrorType::CellParserError)
+    }
+
     pub fn load_u64(&mut self, bit_len: usize) -> CellResult<u64> {
         self.bit_reader
             .read_u64(bit_len as u8)
@@ -101,7 +107,8 @@ impl<'a> CellParser<'a> {
             0 => Ok(AddressData::null()),
             2 => {
                 let _res1 = self.load_u8(1)?;
-                let wc = self.load_u8(8)?;
+                // Workchain is a signed 8-bit integer.
+                let wc = self.load_u8(8)? as i8;
                 let mut hash_part = H256::default();
                 self.load_slice(hash_part.as_mut_slice())?;
                 Ok(AddressData::new(wc as i32, hash_part))
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::cell::cell_builder::CellBuilder;
+use crate::cell::cell_parser::CellParser;
+use crate::cell::{Cell, CellArc};
+use crate::error::{CellError, CellErrorType, CellResult};
+use std::ops::{Deref, DerefMut};
+use tw_coin_entry::error::prelude::*;
+
+/// A [`CellParser`] that also keeps track of the cell references.
+/// Allows to read TL-B structures that contain both data bits and references like `Maybe ^Cell`.
+pub struct CellSlice<'a> {
+    parser: CellParser<'a>,
+    references: &'a [CellArc],
+    next_reference: usize,
+}
+
+impl<'a> CellSlice<'a> {
+    pub fn new(cell: &'a Cell) -> Self {
+        CellSlice {
+            parser: cell.parser(),
+            references: cell.references(),
+            next_reference: 0,
+        }
+    }
+
+    pub fn remaining_references(&self) -> usize {
+        self.references.len() - self.next_reference
+    }
+
+    /// Whether there are no bits and no references left.
+    pub fn is_empty(&self) -> bool {
+        self.remaining_bits() == 0 && self.remaining_references() == 0
+    }
+
+    pub fn load_reference(&mut self) -> CellResult<&'a CellArc> {
+        let references = self.references;
+        let reference = references
+            .get(self.next_reference)
+            .or_tw_err(CellErrorType::CellParserError)
+            .with_context(|| {
+                format!(
+                    "Cell contains {} references only, expected more",
+                    references.len()
+                )
+            })?;
+        self.next_reference += 1;
+        Ok(reference)
+    }
+
+    /// Loads `Maybe ^Cell`.
+    pub fn load_maybe_reference(&mut self) -> CellResult<Option<&'a CellArc>> {
+        if self.load_bit()? {
+            self.load_reference().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Copies the remaining bits and references into a new [`Cell`].
+    pub fn load_remaining(&mut self) -> CellResult<Cell> {
+        let mut builder = CellBuilder::new();
+        builder.store_remaining_bits(&mut self.parser)?;
+        while self.remaining_references() > 0 {
+            builder.store_reference(self.load_reference()?)?;
+        }
+        builder.build()
+    }
+
+    pub fn ensure_empty(&self) -> CellResult<()> {
+        self.parser.ensure_empty()?;
+
+        let remaining = self.remaining_references();
+        if remaining == 0 {
+            Ok(())
+        } else {
+            CellError::err(CellErrorType::CellParserError)
+                .context(format!("{remaining} unread references left"))
+        }
+    }
+}
+
+impl<'a> Deref for CellSlice<'a> {
+    type Target = CellParser<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parser
+    }
+}
+
+impl<'a> DerefMut for CellSlice<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parser
+    }
+}
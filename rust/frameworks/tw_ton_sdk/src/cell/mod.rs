@@ -6,6 +6,7 @@
 
 use crate::boc::binary_writer::BinaryWriter;
 use crate::cell::cell_parser::CellParser;
+use crate::cell::cell_slice::CellSlice;
 use std::fmt;
 use std::sync::Arc;
 use tw_coin_entry::error::prelude::*;
@@ -17,6 +18,7 @@ use tw_memory::Data;
 
 pub mod cell_builder;
 pub mod cell_parser;
+pub mod cell_slice;
 pub mod cell_type;
 pub mod level_mask;
 
@@ -114,6 +116,10 @@ impl Cell {
         CellParser::new(&self.data, self.bit_len)
     }
 
+    pub fn slice(&self) -> CellSlice {
+        CellSlice::new(self)
+    }
+
     pub fn parse_fully<F, T>(&self, parse: F) -> Result<T, CellError>
     where
         F: FnOnce(&mut CellParser) -> CellResult<T>,
@@ -12,8 +12,10 @@ use crate::transaction::{
 };
 use std::marker::PhantomData;
 use tw_coin_entry::coin_entry::PublicKeyBytes;
-use tw_coin_entry::error::prelude::SigningResult;
+use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
+use tw_keypair::schnorr;
+use tw_keypair::schnorr::musig2::KeyAggContext;
 
 #[derive(Debug, Clone)]
 pub struct TxPreimage {
@@ -32,6 +34,42 @@ pub struct UtxoSighash {
     pub taproot_tweak: Option<TaprootTweak>,
 }
 
+impl UtxoSighash {
+    /// Returns a MuSig2 key aggregation context to sign the sighash collaboratively by the `participants`,
+    /// whose aggregate public key is the internal key of the spent P2TR output.
+    /// The context is tweaked according to [`UtxoSighash::taproot_tweak`].
+    pub fn musig2_key_agg_ctx(
+        &self,
+        participants: Vec<schnorr::PublicKey>,
+    ) -> SigningResult<KeyAggContext> {
+        if self.signing_method != SigningMethod::Taproot {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("MuSig2 can only be used to sign Taproot sighashes");
+        }
+
+        let key_agg_ctx = KeyAggContext::new(participants)
+            .into_tw()
+            .context("Error aggregating MuSig2 public keys")?;
+        let key_agg_ctx = match self.taproot_tweak {
+            Some(ref tweak) => key_agg_ctx
+                .with_taproot_tweak(tweak.merkle_root)
+                .into_tw()
+                .context("Error tweaking MuSig2 aggregate public key")?,
+            None => key_agg_ctx,
+        };
+
+        let signer_pubkey = schnorr::XOnlyPublicKey::try_from(self.signer_pubkey.as_slice())
+            .into_tw()
+            .context("Expected a valid signer schnorr x-only public key")?;
+        if key_agg_ctx.aggregated_x_only() != signer_pubkey {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(
+                "MuSig2 aggregate public key doesn't correspond to the sighash signer public key",
+            );
+        }
+        Ok(key_agg_ctx)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TaprootTweak {
     /// 32 bytes merkle root of the script tree.
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::any_address::AnyAddress;
+use tw_coin_entry::derivation::{ChildIndex, Derivation, DerivationPath, DerivationWithPath};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::{get_coin_item, CoinItem};
+use tw_hd_wallet::bip32::Bip32Hashers;
+use tw_hd_wallet::HDWallet;
+use tw_keypair::tw::PrivateKey;
+use zeroize::Zeroizing;
+
+/// Derives keys and addresses of almost any blockchain from an [`HDWallet`]
+/// according to the coin derivations listed in `registry.json`.
+pub struct AnyHDWallet;
+
+impl AnyHDWallet {
+    /// Derives a private key of the given `coin` at its default derivation path.
+    #[inline]
+    pub fn get_key_for_coin(wallet: &HDWallet, coin: CoinType) -> SigningResult<PrivateKey> {
+        Self::get_key_by_derivation(wallet, coin, Derivation::Default)
+    }
+
+    /// Derives a private key of the given `coin` at the path of the specified `derivation`.
+    pub fn get_key_by_derivation(
+        wallet: &HDWallet,
+        coin: CoinType,
+        derivation: Derivation,
+    ) -> SigningResult<PrivateKey> {
+        let item = get_coin_item(coin)?;
+        let derivation = find_derivation(item, derivation)?;
+        Ok(wallet.get_key(item.curve, &derivation.path)?)
+    }
+
+    /// Derives an address of the given `coin` at its default derivation path.
+    #[inline]
+    pub fn get_address_for_coin(wallet: &HDWallet, coin: CoinType) -> SigningResult<AnyAddress> {
+        Self::get_address_by_derivation(wallet, coin, Derivation::Default)
+    }
+
+    /// Derives an address of the given `coin` at the path of the specified `derivation`.
+    pub fn get_address_by_derivation(
+        wallet: &HDWallet,
+        coin: CoinType,
+        derivation: Derivation,
+    ) -> SigningResult<AnyAddress> {
+        let item = get_coin_item(coin)?;
+        let private_key = Self::get_key_by_derivation(wallet, coin, derivation)?;
+        let public_key = private_key.get_public_key_by_type(item.public_key_type)?;
+        let address = AnyAddress::with_public_key(coin, public_key, derivation, None)?;
+        Ok(address)
+    }
+
+    /// Derives the default address of the given `coin` from a `mnemonic` phrase and an optional `passphrase`.
+    pub fn derive_address_from_mnemonic(
+        coin: CoinType,
+        mnemonic: &str,
+        passphrase: &str,
+    ) -> SigningResult<String> {
+        let wallet = HDWallet::new(mnemonic, passphrase)?;
+        let address = Self::get_address_for_coin(&wallet, coin)?;
+        Ok(address.description().to_string())
+    }
+
+    /// Derives an account extended private key (e.g. `zprv...`) of the given `coin`.
+    /// The account key is derived as `m/purpose'/coin'/account'`, where `purpose` and `coin`
+    /// are taken from the path of the specified `derivation`.
+    pub fn get_extended_private_key(
+        wallet: &HDWallet,
+        coin: CoinType,
+        derivation: Derivation,
+        account: u32,
+    ) -> SigningResult<Zeroizing<String>> {
+        let item = get_coin_item(coin)?;
+        let derivation = find_derivation(item, derivation)?;
+        let version = derivation
+            .xprv
+            .or_tw_err(SigningErrorType::Error_not_supported)
+            .context("Coin derivation does not support extended private keys")?;
+        let path = account_path(&derivation.path, account)?;
+
+        Ok(wallet.get_extended_private_key(item.curve, &path, version, bip32_hashers(item))?)
+    }
+
+    /// Derives an account extended public key (e.g. `zpub...`) of the given `coin`.
+    /// The account key is derived as `m/purpose'/coin'/account'`, where `purpose` and `coin`
+    /// are taken from the path of the specified `derivation`.
+    pub fn get_extended_public_key(
+        wallet: &HDWallet,
+        coin: CoinType,
+        derivation: Derivation,
+        account: u32,
+    ) -> SigningResult<String> {
+        let item = get_coin_item(coin)?;
+        let derivation = find_derivation(item, derivation)?;
+        let version = derivation
+            .xpub
+            .or_tw_err(SigningErrorType::Error_not_supported)
+            .context("Coin derivation does not support extended public keys")?;
+        let path = account_path(&derivation.path, account)?;
+
+        Ok(wallet.get_extended_public_key(item.curve, &path, version, bip32_hashers(item))?)
+    }
+}
+
+/// Returns the first derivation if [`Derivation::Default`] is requested.
+fn find_derivation(item: &CoinItem, derivation: Derivation) -> SigningResult<&DerivationWithPath> {
+    let found = match derivation {
+        Derivation::Default => item.derivation.first(),
+        _ => item.derivation.iter().find(|der| der.name == derivation),
+    };
+    found
+        .or_tw_err(SigningErrorType::Error_not_supported)
+        .with_context(|| {
+            format!(
+                "'{}' coin does not support the requested derivation",
+                item.id
+            )
+        })
+}
+
+fn account_path(path: &DerivationPath, account: u32) -> SigningResult<DerivationPath> {
+    let [purpose, coin, ..] = path.path() else {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Expected at least 'm/purpose'/coin'' derivation path");
+    };
+    let account = ChildIndex::hardened(account).tw_err(SigningErrorType::Error_invalid_params)?;
+    Ok(DerivationPath::new(vec![*purpose, *coin, account]))
+}
+
+fn bip32_hashers(item: &CoinItem) -> Bip32Hashers {
+    let default = Bip32Hashers::default();
+    Bip32Hashers {
+        public_key_hasher: item.public_key_hasher.unwrap_or(default.public_key_hasher),
+        base58_hasher: item.base58_hasher.unwrap_or(default.base58_hasher),
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod tw_any_address;
 pub mod tw_any_signer;
+pub mod tw_coin_registry;
 pub mod tw_message_signer;
 pub mod tw_transaction_compiler;
 pub mod tw_transaction_decoder;
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_coin_registry::registry::register_coin_json;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_false;
+
+/// Registers an additional EVM or Cosmos chain at runtime.
+/// Once registered, the chain can be used as any other coin type by its `coinId`.
+///
+/// \param json A JSON object of the same format as the `registry.json` items.
+/// \return true if the chain has been registered successfully,
+/// false if the JSON is invalid or the `coinId` is already registered.
+#[no_mangle]
+pub unsafe extern "C" fn tw_coin_registry_register_coin(json: *const TWString) -> bool {
+    let json = try_or_false!(TWString::from_ptr_as_ref(json));
+    let json = try_or_false!(json.as_str());
+    register_coin_json(json).is_ok()
+}
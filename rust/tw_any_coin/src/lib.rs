@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod any_address;
+pub mod any_hd_wallet;
 pub mod any_signer;
 pub mod ffi;
 pub mod message_signer;
@@ -63,7 +63,7 @@ pub fn test_address_derive_with_derivation(
     let any_address = TWAnyAddressHelper::wrap(unsafe {
         tw_any_address_create_with_public_key_derivation(
             public_key.ptr(),
-            coin as u32,
+            u32::from(coin),
             derivation as u32,
         )
     });
@@ -77,7 +77,7 @@ pub fn test_address_normalization(coin: CoinType, denormalized: &str, normalized
     let denormalized = TWStringHelper::create(denormalized);
 
     let any_address = TWAnyAddressHelper::wrap(unsafe {
-        tw_any_address_create_with_string(denormalized.ptr(), coin as u32)
+        tw_any_address_create_with_string(denormalized.ptr(), u32::from(coin))
     });
 
     let normalized = TWStringHelper::wrap(unsafe { tw_any_address_description(any_address.ptr()) });
@@ -86,7 +86,7 @@ pub fn test_address_normalization(coin: CoinType, denormalized: &str, normalized
 
     // Double check if the address is also valid by using `tw_any_address_create_with_string_unchecked`.
     let any_address_unchecked = TWAnyAddressHelper::wrap(unsafe {
-        tw_any_address_create_with_string_unchecked(denormalized.ptr(), coin as u32)
+        tw_any_address_create_with_string_unchecked(denormalized.ptr(), u32::from(coin))
     });
     let normalized_unchecked =
         TWStringHelper::wrap(unsafe { tw_any_address_description(any_address_unchecked.ptr()) });
@@ -98,7 +98,7 @@ pub fn test_address_normalization(coin: CoinType, denormalized: &str, normalized
 pub fn test_address_valid(coin: CoinType, address: &str) {
     let addr = TWStringHelper::create(address);
     assert!(
-        unsafe { tw_any_address_is_valid(addr.ptr(), coin as u32) },
+        unsafe { tw_any_address_is_valid(addr.ptr(), u32::from(coin)) },
         "'{}' expected to be valid",
         address
     );
@@ -107,7 +107,7 @@ pub fn test_address_valid(coin: CoinType, address: &str) {
 pub fn test_address_ss58_is_valid(coin: CoinType, address: &str, ss58: u16) {
     let addr = TWStringHelper::create(address);
     assert!(
-        unsafe { tw_any_address_is_valid_ss58(addr.ptr(), coin as u32, ss58) },
+        unsafe { tw_any_address_is_valid_ss58(addr.ptr(), u32::from(coin), ss58) },
         "'{}' expected to be valid",
         address
     );
@@ -116,7 +116,7 @@ pub fn test_address_ss58_is_valid(coin: CoinType, address: &str, ss58: u16) {
 pub fn test_address_invalid(coin: CoinType, address: &str) {
     let addr = TWStringHelper::create(address);
     assert!(
-        !unsafe { tw_any_address_is_valid(addr.ptr(), coin as u32) },
+        !unsafe { tw_any_address_is_valid(addr.ptr(), u32::from(coin)) },
         "'{}' expected to be invalid",
         address
     );
@@ -125,7 +125,7 @@ pub fn test_address_invalid(coin: CoinType, address: &str) {
 pub fn test_address_ss58_is_invalid(coin: CoinType, address: &str, ss58: u16) {
     let addr = TWStringHelper::create(address);
     assert!(
-        !unsafe { tw_any_address_is_valid_ss58(addr.ptr(), coin as u32, ss58) },
+        !unsafe { tw_any_address_is_valid_ss58(addr.ptr(), u32::from(coin), ss58) },
         "'{}' expected to be invalid",
         address
     );
@@ -134,7 +134,7 @@ pub fn test_address_ss58_is_invalid(coin: CoinType, address: &str, ss58: u16) {
 pub fn test_address_get_data(coin: CoinType, address: &str, data_hex: &str) {
     let address_str = TWStringHelper::create(address);
     let any_address = TWAnyAddressHelper::wrap(unsafe {
-        tw_any_address_create_with_string(address_str.ptr(), coin as u32)
+        tw_any_address_create_with_string(address_str.ptr(), u32::from(coin))
     });
 
     let actual_data = TWDataHelper::wrap(unsafe { tw_any_address_data(any_address.ptr()) })
@@ -163,7 +163,11 @@ pub fn test_address_create_bech32_with_public_key(input: AddressCreateBech32With
     let hrp = TWStringHelper::create(input.hrp);
 
     let any_address = TWAnyAddressHelper::wrap(unsafe {
-        tw_any_address_create_bech32_with_public_key(public_key.ptr(), input.coin as u32, hrp.ptr())
+        tw_any_address_create_bech32_with_public_key(
+            public_key.ptr(),
+            u32::from(input.coin),
+            hrp.ptr(),
+        )
     });
 
     let actual = TWStringHelper::wrap(unsafe { tw_any_address_description(any_address.ptr()) });
@@ -180,8 +184,9 @@ pub fn test_address_bech32_is_valid(input: AddressBech32IsValid<'_>) {
     let address_str = TWStringHelper::create(input.address);
     let hrp = TWStringHelper::create(input.hrp);
     // Should be valid even though Osmosis chain has `osmo` default hrp.
-    let result =
-        unsafe { tw_any_address_is_valid_bech32(address_str.ptr(), input.coin as u32, hrp.ptr()) };
+    let result = unsafe {
+        tw_any_address_is_valid_bech32(address_str.ptr(), u32::from(input.coin), hrp.ptr())
+    };
     assert!(result);
 }
 
@@ -200,7 +205,7 @@ pub fn test_address_create_base58_with_public_key(input: AddressCreateBase58With
     let any_address = TWAnyAddressHelper::wrap(unsafe {
         tw_any_address_create_base58_with_public_key(
             public_key.ptr(),
-            input.coin as u32,
+            u32::from(input.coin),
             input.p2pkh,
             input.p2sh,
         )
@@ -221,7 +226,12 @@ pub fn test_address_base58_is_valid(input: AddressBase58IsValid<'_>) {
     // First, check if the address is valid.
     let addr_str = TWStringHelper::create(input.address);
     let is_valid = unsafe {
-        tw_any_address_is_valid_base58(addr_str.ptr(), input.coin as u32, input.p2pkh, input.p2sh)
+        tw_any_address_is_valid_base58(
+            addr_str.ptr(),
+            u32::from(input.coin),
+            input.p2pkh,
+            input.p2sh,
+        )
     };
     assert!(is_valid, "!tw_any_address_is_valid_base58");
 }
@@ -238,7 +248,11 @@ pub fn test_address_create_ss58_with_public_key(input: AddressCreateSS58WithPubl
     let public_key = TWPublicKeyHelper::with_hex(input.public_key, input.public_key_type);
 
     let any_address = TWAnyAddressHelper::wrap(unsafe {
-        tw_any_address_create_ss58_with_public_key(public_key.ptr(), input.coin as u32, input.ss58)
+        tw_any_address_create_ss58_with_public_key(
+            public_key.ptr(),
+            u32::from(input.coin),
+            input.ss58,
+        )
     });
 
     let actual = TWStringHelper::wrap(unsafe { tw_any_address_description(any_address.ptr()) });
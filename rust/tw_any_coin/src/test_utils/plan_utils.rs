@@ -19,10 +19,11 @@ impl<'a, Output: MessageRead<'a>> AnyPlannerHelper<'a, Output> {
     pub fn plan<Input: MessageWrite>(&'a mut self, coin_type: CoinType, input: Input) -> Output {
         let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
-        self.output_data =
-            TWDataHelper::wrap(unsafe { tw_any_signer_plan(input_data.ptr(), coin_type as u32) })
-                .to_vec()
-                .expect("!tw_any_signer_plan returned nullptr");
+        self.output_data = TWDataHelper::wrap(unsafe {
+            tw_any_signer_plan(input_data.ptr(), u32::from(coin_type))
+        })
+        .to_vec()
+        .expect("!tw_any_signer_plan returned nullptr");
 
         let output: Output = deserialize(&self.output_data).unwrap();
         output
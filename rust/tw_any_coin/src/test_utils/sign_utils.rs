@@ -23,10 +23,11 @@ impl<'a, Output: MessageRead<'a>> AnySignerHelper<'a, Output> {
     pub fn sign<Input: MessageWrite>(&'a mut self, coin_type: CoinType, input: Input) -> Output {
         let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
-        self.output_data =
-            TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), coin_type as u32) })
-                .to_vec()
-                .expect("!tw_any_signer_sign returned nullptr");
+        self.output_data = TWDataHelper::wrap(unsafe {
+            tw_any_signer_sign(input_data.ptr(), u32::from(coin_type))
+        })
+        .to_vec()
+        .expect("!tw_any_signer_sign returned nullptr");
 
         let output: Output = deserialize(&self.output_data).unwrap();
         output
@@ -48,7 +49,7 @@ impl<'a, Output: MessageRead<'a>> PreImageHelper<'a, Output> {
         let input_data = TWDataHelper::create(serialize(input).unwrap());
 
         self.output_data = TWDataHelper::wrap(unsafe {
-            tw_transaction_compiler_pre_image_hashes(coin_type as u32, input_data.ptr())
+            tw_transaction_compiler_pre_image_hashes(u32::from(coin_type), input_data.ptr())
         })
         .to_vec()
         .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
@@ -79,7 +80,7 @@ impl<'a, Output: MessageRead<'a>> CompilerHelper<'a, Output> {
 
         self.output_data = TWDataHelper::wrap(unsafe {
             tw_transaction_compiler_compile(
-                coin_type as u32,
+                u32::from(coin_type),
                 input_data.ptr(),
                 signatures.ptr(),
                 public_keys.ptr(),
@@ -13,7 +13,7 @@ impl TransactionUtilHelper {
         let tx_data = TWStringHelper::create(tx);
 
         TWStringHelper::wrap(unsafe {
-            tw_transaction_util_calc_tx_hash(coin_type as u32, tx_data.ptr())
+            tw_transaction_util_calc_tx_hash(u32::from(coin_type), tx_data.ptr())
         })
         .to_string()
         .expect("!tw_transaction_util_calc_tx_hash returned nullptr")
@@ -20,7 +20,7 @@ impl<'a, Output: MessageRead<'a>> TransactionDecoderHelper<'a, Output> {
         let tx_data = TWDataHelper::create(tx);
 
         self.output_data = TWDataHelper::wrap(unsafe {
-            tw_transaction_decoder_decode(coin_type as u32, tx_data.ptr())
+            tw_transaction_decoder_decode(u32::from(coin_type), tx_data.ptr())
         })
         .to_vec()
         .expect("!tw_transaction_decoder_decode returned nullptr");
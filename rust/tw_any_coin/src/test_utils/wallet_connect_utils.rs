@@ -22,7 +22,7 @@ impl WalletConnectRequestHelper {
         let input_data = TWDataHelper::create(serialize(input).unwrap());
 
         self.output_data = TWDataHelper::wrap(unsafe {
-            tw_wallet_connect_request_parse(coin_type as u32, input_data.ptr())
+            tw_wallet_connect_request_parse(u32::from(coin_type), input_data.ptr())
         })
         .to_vec()
         .expect("!tw_wallet_connect_request_parse returned nullptr");
@@ -0,0 +1,604 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::error::{CandidError, CandidResult};
+use crate::types::{opcode, CandidType, Label};
+use crate::value::CandidValue;
+use crate::{MAGIC, MAX_DEPTH, MAX_PRINCIPAL_LEN, MAX_TYPE_NODES};
+
+/// Zero-sized values (e.g. `vec null`) don't consume any input, so their number has to be limited explicitly.
+const MAX_ZERO_SIZED_ELEMENTS: usize = 1 << 16;
+
+/// Decodes a Candid argument sequence.
+/// Note that record and variant labels of the decoded values are always [`Label::Id`].
+pub fn decode_args(data: &[u8]) -> CandidResult<Vec<CandidValue>> {
+    let mut decoder = Decoder {
+        data,
+        pos: 0,
+        type_nodes: NodeBudget::default(),
+    };
+
+    if decoder.read_bytes(MAGIC.len())? != MAGIC {
+        return Err(CandidError::InvalidMagic);
+    }
+
+    let type_table = decoder.read_type_table()?;
+
+    let args_len = decoder.read_len()?;
+    let mut arg_types = Vec::new();
+    for _ in 0..args_len {
+        let type_ref = decoder.read_sleb128()?;
+        arg_types.push(type_table.resolve(type_ref, &mut decoder.type_nodes)?);
+    }
+
+    let args = arg_types
+        .iter()
+        .map(|ty| decoder.read_value(ty))
+        .collect::<CandidResult<Vec<_>>>()?;
+
+    if decoder.pos != data.len() {
+        return Err(CandidError::TrailingBytes);
+    }
+    Ok(args)
+}
+
+/// Type table entry as it's encoded, where nested types are references.
+enum RawType {
+    Opt(i64),
+    Vec(i64),
+    Record(Vec<(u32, i64)>),
+    Variant(Vec<(u32, i64)>),
+}
+
+/// Counts the type nodes created while decoding a message up to [`MAX_TYPE_NODES`].
+#[derive(Default)]
+struct NodeBudget {
+    used: usize,
+}
+
+impl NodeBudget {
+    fn charge(&mut self, nodes: usize) -> CandidResult<()> {
+        self.used = self.used.saturating_add(nodes);
+        if self.used > MAX_TYPE_NODES {
+            return Err(CandidError::TypeTooLarge);
+        }
+        Ok(())
+    }
+
+    /// Clones the given type once its nodes are charged.
+    fn clone_type(&mut self, ty: &CandidType) -> CandidResult<CandidType> {
+        self.charge(type_nodes(ty))?;
+        Ok(ty.clone())
+    }
+}
+
+struct TypeTable {
+    entries: Vec<RawType>,
+}
+
+impl TypeTable {
+    fn resolve(&self, type_ref: i64, budget: &mut NodeBudget) -> CandidResult<CandidType> {
+        self.resolve_impl(type_ref, &mut Vec::new(), budget)
+    }
+
+    /// `visiting` contains indexes of the types being resolved to detect recursive types.
+    fn resolve_impl(
+        &self,
+        type_ref: i64,
+        visiting: &mut Vec<usize>,
+        budget: &mut NodeBudget,
+    ) -> CandidResult<CandidType> {
+        // Every reference is expanded into a separate subtree.
+        budget.charge(1)?;
+        if type_ref < 0 {
+            return CandidType::from_primitive_opcode(type_ref);
+        }
+
+        let index = type_ref as usize;
+        let entry = self
+            .entries
+            .get(index)
+            .ok_or(CandidError::InvalidType(type_ref))?;
+        if visiting.contains(&index) {
+            return Err(CandidError::UnsupportedType);
+        }
+        if visiting.len() >= MAX_DEPTH {
+            return Err(CandidError::TooDeep);
+        }
+
+        visiting.push(index);
+        let ty = match entry {
+            RawType::Opt(inner) => CandidType::opt(self.resolve_impl(*inner, visiting, budget)?),
+            RawType::Vec(element) => {
+                CandidType::vec(self.resolve_impl(*element, visiting, budget)?)
+            },
+            RawType::Record(fields) => {
+                CandidType::Record(self.resolve_fields(fields, visiting, budget)?)
+            },
+            RawType::Variant(fields) => {
+                CandidType::Variant(self.resolve_fields(fields, visiting, budget)?)
+            },
+        };
+        visiting.pop();
+
+        Ok(ty)
+    }
+
+    fn resolve_fields(
+        &self,
+        fields: &[(u32, i64)],
+        visiting: &mut Vec<usize>,
+        budget: &mut NodeBudget,
+    ) -> CandidResult<Vec<(Label, CandidType)>> {
+        fields
+            .iter()
+            .map(|(id, type_ref)| {
+                let field_type = self.resolve_impl(*type_ref, visiting, budget)?;
+                Ok((Label::Id(*id), field_type))
+            })
+            .collect()
+    }
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+    /// Decoded values hold copies of their types, which are charged as well.
+    type_nodes: NodeBudget,
+}
+
+impl<'a> Decoder<'a> {
+    fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn read_bytes(&mut self, len: usize) -> CandidResult<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(CandidError::UnexpectedEof);
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> CandidResult<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        Ok(bytes.try_into().expect("Expected exactly N bytes"))
+    }
+
+    fn read_byte(&mut self) -> CandidResult<u8> {
+        let [byte] = self.read_array::<1>()?;
+        Ok(byte)
+    }
+
+    fn read_uleb128(&mut self) -> CandidResult<u128> {
+        let mut result = 0_u128;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_byte()?;
+            let low_bits = (byte & 0x7f) as u128;
+            if shift >= 128 || (shift > 0 && low_bits >> (128 - shift) != 0) {
+                return Err(CandidError::NumberOverflow);
+            }
+            result |= low_bits << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn read_sleb128(&mut self) -> CandidResult<i64> {
+        let value = self.read_sleb128_i128()?;
+        i64::try_from(value).map_err(|_| CandidError::NumberOverflow)
+    }
+
+    fn read_sleb128_i128(&mut self) -> CandidResult<i128> {
+        let mut result = 0_i128;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_byte()?;
+            if shift >= 128 {
+                return Err(CandidError::NumberOverflow);
+            }
+            result |= ((byte & 0x7f) as i128) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                // Sign-extend the result.
+                if shift < 128 && byte & 0x40 != 0 {
+                    result |= -1_i128 << shift;
+                }
+                return Ok(result);
+            }
+        }
+    }
+
+    fn read_len(&mut self) -> CandidResult<usize> {
+        let len = self.read_uleb128()?;
+        usize::try_from(len).map_err(|_| CandidError::NumberOverflow)
+    }
+
+    fn read_type_table(&mut self) -> CandidResult<TypeTable> {
+        let len = self.read_len()?;
+        // Each entry takes at least one byte.
+        if len > self.remaining() {
+            return Err(CandidError::UnexpectedEof);
+        }
+
+        let entries = (0..len)
+            .map(|_| self.read_raw_type())
+            .collect::<CandidResult<Vec<_>>>()?;
+        Ok(TypeTable { entries })
+    }
+
+    fn read_raw_type(&mut self) -> CandidResult<RawType> {
+        match self.read_sleb128()? {
+            opcode::OPT => Ok(RawType::Opt(self.read_sleb128()?)),
+            opcode::VEC => Ok(RawType::Vec(self.read_sleb128()?)),
+            opcode::RECORD => Ok(RawType::Record(self.read_raw_fields()?)),
+            opcode::VARIANT => Ok(RawType::Variant(self.read_raw_fields()?)),
+            opcode::FUNC | opcode::SERVICE => Err(CandidError::UnsupportedType),
+            other => Err(CandidError::InvalidType(other)),
+        }
+    }
+
+    fn read_raw_fields(&mut self) -> CandidResult<Vec<(u32, i64)>> {
+        let len = self.read_len()?;
+        if len > self.remaining() {
+            return Err(CandidError::UnexpectedEof);
+        }
+
+        let mut fields: Vec<(u32, i64)> = Vec::with_capacity(len);
+        for _ in 0..len {
+            let id =
+                u32::try_from(self.read_uleb128()?).map_err(|_| CandidError::NumberOverflow)?;
+            // Fields must be sorted by their IDs strictly.
+            if fields.last().is_some_and(|(prev_id, _)| *prev_id >= id) {
+                return Err(CandidError::InvalidFieldOrder);
+            }
+            let type_ref = self.read_sleb128()?;
+            fields.push((id, type_ref));
+        }
+        Ok(fields)
+    }
+
+    fn read_value(&mut self, ty: &CandidType) -> CandidResult<CandidValue> {
+        let value = match ty {
+            CandidType::Null => CandidValue::Null,
+            CandidType::Bool => match self.read_byte()? {
+                0 => CandidValue::Bool(false),
+                1 => CandidValue::Bool(true),
+                _ => return Err(CandidError::InvalidBool),
+            },
+            CandidType::Nat => CandidValue::Nat(self.read_uleb128()?),
+            CandidType::Int => CandidValue::Int(self.read_sleb128_i128()?),
+            CandidType::Nat8 => CandidValue::Nat8(self.read_byte()?),
+            CandidType::Nat16 => CandidValue::Nat16(u16::from_le_bytes(self.read_array()?)),
+            CandidType::Nat32 => CandidValue::Nat32(u32::from_le_bytes(self.read_array()?)),
+            CandidType::Nat64 => CandidValue::Nat64(u64::from_le_bytes(self.read_array()?)),
+            CandidType::Int8 => CandidValue::Int8(i8::from_le_bytes(self.read_array()?)),
+            CandidType::Int16 => CandidValue::Int16(i16::from_le_bytes(self.read_array()?)),
+            CandidType::Int32 => CandidValue::Int32(i32::from_le_bytes(self.read_array()?)),
+            CandidType::Int64 => CandidValue::Int64(i64::from_le_bytes(self.read_array()?)),
+            CandidType::Float32 => CandidValue::Float32(f32::from_le_bytes(self.read_array()?)),
+            CandidType::Float64 => CandidValue::Float64(f64::from_le_bytes(self.read_array()?)),
+            CandidType::Text => {
+                let len = self.read_len()?;
+                let bytes = self.read_bytes(len)?;
+                let text = std::str::from_utf8(bytes).map_err(|_| CandidError::InvalidUtf8)?;
+                CandidValue::Text(text.to_string())
+            },
+            CandidType::Reserved => CandidValue::Reserved,
+            CandidType::Empty => return Err(CandidError::EmptyValue),
+            CandidType::Principal => {
+                // Only transparent principal references are supported.
+                if self.read_byte()? != 1 {
+                    return Err(CandidError::InvalidPrincipal);
+                }
+                let len = self.read_len()?;
+                if len > MAX_PRINCIPAL_LEN {
+                    return Err(CandidError::InvalidPrincipal);
+                }
+                CandidValue::Principal(self.read_bytes(len)?.to_vec())
+            },
+            CandidType::Opt(inner_type) => {
+                let inner = match self.read_byte()? {
+                    0 => None,
+                    1 => Some(self.read_value(inner_type)?),
+                    _ => return Err(CandidError::InvalidOptTag),
+                };
+                CandidValue::opt(self.type_nodes.clone_type(inner_type)?, inner)
+            },
+            CandidType::Vec(element_type) if **element_type == CandidType::Nat8 => {
+                let len = self.read_len()?;
+                CandidValue::Blob(self.read_bytes(len)?.to_vec())
+            },
+            CandidType::Vec(element_type) => {
+                let len = self.read_len()?;
+                let max_len = if is_zero_sized(element_type) {
+                    MAX_ZERO_SIZED_ELEMENTS
+                } else {
+                    self.remaining()
+                };
+                if len > max_len {
+                    return Err(CandidError::UnexpectedEof);
+                }
+                // Zero-sized elements don't consume any input, so their values are charged as well.
+                if is_zero_sized(element_type) {
+                    self.type_nodes
+                        .charge(len.saturating_mul(type_nodes(element_type)))?;
+                }
+
+                let elements = (0..len)
+                    .map(|_| self.read_value(element_type))
+                    .collect::<CandidResult<Vec<_>>>()?;
+                CandidValue::Vec(self.type_nodes.clone_type(element_type)?, elements)
+            },
+            CandidType::Record(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(label, field_type)| Ok((label.clone(), self.read_value(field_type)?)))
+                    .collect::<CandidResult<Vec<_>>>()?;
+                CandidValue::Record(fields)
+            },
+            CandidType::Variant(alternatives) => {
+                let index = self.read_len()?;
+                let (label, value_type) =
+                    alternatives.get(index).ok_or(CandidError::UnknownVariant)?;
+                let value = self.read_value(value_type)?;
+                self.type_nodes
+                    .charge(alternatives.iter().map(|(_, ty)| type_nodes(ty)).sum())?;
+                CandidValue::Variant {
+                    alternatives: alternatives.clone(),
+                    label: label.clone(),
+                    value: Box::new(value),
+                }
+            },
+        };
+        Ok(value)
+    }
+}
+
+/// Returns the number of nodes in the type tree.
+fn type_nodes(ty: &CandidType) -> usize {
+    match ty {
+        CandidType::Opt(inner) | CandidType::Vec(inner) => 1 + type_nodes(inner),
+        CandidType::Record(fields) | CandidType::Variant(fields) => {
+            1 + fields
+                .iter()
+                .map(|(_, field_type)| type_nodes(field_type))
+                .sum::<usize>()
+        },
+        _ => 1,
+    }
+}
+
+/// Whether values of the given type are encoded as zero bytes.
+fn is_zero_sized(ty: &CandidType) -> bool {
+    match ty {
+        CandidType::Null | CandidType::Reserved => true,
+        CandidType::Record(fields) => fields
+            .iter()
+            .all(|(_, field_type)| is_zero_sized(field_type)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::encode_args;
+    use crate::leb128::{write_sleb128, write_uleb128};
+    use tw_encoding::hex;
+
+    fn decode_hex(encoded: &str) -> CandidResult<Vec<CandidValue>> {
+        decode_args(&hex::decode(encoded).unwrap())
+    }
+
+    #[test]
+    fn test_decode_primitives() {
+        let args =
+            decode_hex("4449444c00067c7a75727f70c0bb780102feffffff000000000000f83f").unwrap();
+        assert_eq!(
+            args,
+            [
+                CandidValue::Int(-123456),
+                CandidValue::Nat16(513),
+                CandidValue::Int32(-2),
+                CandidValue::Float64(1.5),
+                CandidValue::Null,
+                CandidValue::Reserved,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_record() {
+        let args = decode_hex(
+            "4449444c036c02b3b0dac30368ad86ca8305016e026d7b0100010a0000000000000002010100",
+        )
+        .unwrap();
+        assert_eq!(args.len(), 1);
+
+        let record = &args[0];
+        assert_eq!(
+            record.field("owner"),
+            Some(&CandidValue::Principal(vec![0, 0, 0, 0, 0, 0, 0, 2, 1, 1]))
+        );
+        assert_eq!(
+            record.field("subaccount"),
+            Some(&CandidValue::opt_blob(None))
+        );
+    }
+
+    #[test]
+    fn test_decode_variant() {
+        let args = decode_hex("4449444c016b02bc8a017dc5fed20171010001046661696c").unwrap();
+        assert_eq!(args[0].field("Err"), Some(&CandidValue::text("fail")));
+        assert_eq!(args[0].field("Ok"), None);
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let value = CandidValue::record([
+            (
+                "transfers",
+                CandidValue::Vec(
+                    CandidType::Record(vec![
+                        (Label::from("to"), CandidType::Principal),
+                        (Label::from("amount"), CandidType::Nat),
+                    ]),
+                    vec![CandidValue::record([
+                        ("to", CandidValue::Principal(vec![4])),
+                        ("amount", CandidValue::Nat(u64::MAX as u128 + 1)),
+                    ])],
+                ),
+            ),
+            ("memo", CandidValue::opt_blob(Some(&[1, 2, 3]))),
+            (
+                "created_at_time",
+                CandidValue::opt_nat64(Some(1_691_709_940)),
+            ),
+            ("note", CandidValue::text("ICRC")),
+        ]);
+
+        let encoded = encode_args(&[value.clone(), CandidValue::Int8(-1)]).unwrap();
+        let decoded = decode_args(&encoded).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].candid_type(), value.candid_type());
+        assert_eq!(
+            decoded[0].field("transfers").map(CandidValue::candid_type),
+            value.field("transfers").map(CandidValue::candid_type)
+        );
+        assert_eq!(decoded[0].field("memo"), value.field("memo"));
+        assert_eq!(decoded[1], CandidValue::Int8(-1));
+
+        // Field order is normalized after decoding, so the encoding must be the same.
+        assert_eq!(encode_args(&decoded).unwrap(), encoded);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert_eq!(decode_hex("4449444d0000"), Err(CandidError::InvalidMagic));
+        assert_eq!(
+            decode_hex("4449444c00017d"),
+            Err(CandidError::UnexpectedEof)
+        );
+        assert_eq!(
+            decode_hex("4449444c00017d0100"),
+            Err(CandidError::TrailingBytes)
+        );
+        assert_eq!(
+            decode_hex("4449444c00017e02"),
+            Err(CandidError::InvalidBool)
+        );
+        assert_eq!(decode_hex("4449444c00016f"), Err(CandidError::EmptyValue));
+        assert_eq!(
+            decode_hex("4449444c000101"),
+            Err(CandidError::InvalidType(1))
+        );
+        assert_eq!(
+            decode_hex("4449444c00017102c328"),
+            Err(CandidError::InvalidUtf8)
+        );
+    }
+
+    #[test]
+    fn test_decode_unsupported_types() {
+        // `type List = opt record { head : int; tail : List }`.
+        assert_eq!(
+            decode_hex("4449444c026e016c02a0d2aca8047c90eddae704000100"),
+            Err(CandidError::UnsupportedType)
+        );
+        // `service {}`.
+        assert_eq!(
+            decode_hex("4449444c0169000100"),
+            Err(CandidError::UnsupportedType)
+        );
+    }
+
+    #[test]
+    fn test_decode_too_deep() {
+        // `opt opt ... opt nat` nested deeper than allowed.
+        let depth = MAX_DEPTH + 1;
+        let mut encoded = b"DIDL".to_vec();
+        write_uleb128(&mut encoded, depth as u128);
+        for index in 1..depth {
+            write_sleb128(&mut encoded, opcode::OPT as i128);
+            write_sleb128(&mut encoded, index as i128);
+        }
+        encoded.extend_from_slice(&[0x6e, 0x7d, 0x01, 0x00, 0x00]);
+        assert_eq!(decode_args(&encoded), Err(CandidError::TooDeep));
+    }
+
+    #[test]
+    fn test_decode_type_too_large() {
+        // `T_i = record { 0 : T_{i+1}; 1 : T_{i+1} }` and `T_40 = nat` expand into 2^41 nodes.
+        let levels = 40;
+        let mut encoded = b"DIDL".to_vec();
+        write_uleb128(&mut encoded, levels);
+        for index in 1..=levels {
+            let field_type = if index == levels {
+                opcode::NAT as i128
+            } else {
+                index as i128
+            };
+            write_sleb128(&mut encoded, opcode::RECORD as i128);
+            encoded.push(2);
+            for id in 0..2 {
+                encoded.push(id);
+                write_sleb128(&mut encoded, field_type);
+            }
+        }
+        encoded.extend_from_slice(&[0x01, 0x00]);
+        assert_eq!(decode_args(&encoded), Err(CandidError::TypeTooLarge));
+    }
+
+    #[test]
+    fn test_decode_type_copies_too_large() {
+        // `vec opt T` where `T` has 2^16 nodes: every `opt` value holds a copy of `T`.
+        let levels = 15;
+        let mut encoded = b"DIDL".to_vec();
+        write_uleb128(&mut encoded, levels + 2);
+        write_sleb128(&mut encoded, opcode::VEC as i128);
+        write_sleb128(&mut encoded, 1);
+        write_sleb128(&mut encoded, opcode::OPT as i128);
+        write_sleb128(&mut encoded, 2);
+        for index in 2..levels + 2 {
+            let field_type = if index == levels + 1 {
+                opcode::NAT as i128
+            } else {
+                index as i128 + 1
+            };
+            write_sleb128(&mut encoded, opcode::RECORD as i128);
+            encoded.push(2);
+            for id in 0..2 {
+                encoded.push(id);
+                write_sleb128(&mut encoded, field_type);
+            }
+        }
+        // A single argument of type `0` with 64 `null` elements.
+        encoded.extend_from_slice(&[0x01, 0x00, 0x40]);
+        encoded.extend_from_slice(&[0x00; 0x40]);
+        assert_eq!(decode_args(&encoded), Err(CandidError::TypeTooLarge));
+    }
+
+    #[test]
+    fn test_decode_too_many_zero_sized_elements() {
+        // `vec record {}` with 131072 elements.
+        assert_eq!(
+            decode_hex("4449444c026d016c000100808008"),
+            Err(CandidError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_decode_unsorted_fields() {
+        // Record fields `1` and `0` aren't sorted.
+        assert_eq!(
+            decode_hex("4449444c016c02017d007d0100"),
+            Err(CandidError::InvalidFieldOrder)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::error::{CandidError, CandidResult};
+use crate::leb128::{write_sleb128, write_uleb128};
+use crate::types::{opcode, sorted_fields, CandidType};
+use crate::value::CandidValue;
+use crate::{MAGIC, MAX_PRINCIPAL_LEN};
+use tw_memory::Data;
+
+/// Encodes the given values as a Candid argument sequence.
+pub fn encode_args(args: &[CandidValue]) -> CandidResult<Data> {
+    let mut type_table = TypeTable::default();
+    let arg_types = args
+        .iter()
+        .map(|arg| type_table.index_of(&arg.candid_type()))
+        .collect::<CandidResult<Vec<_>>>()?;
+
+    let mut out = MAGIC.to_vec();
+    write_uleb128(&mut out, type_table.entries.len() as u128);
+    type_table
+        .entries
+        .iter()
+        .for_each(|entry| out.extend_from_slice(entry));
+
+    write_uleb128(&mut out, arg_types.len() as u128);
+    arg_types
+        .into_iter()
+        .for_each(|arg_type| write_sleb128(&mut out, arg_type as i128));
+
+    for arg in args {
+        encode_value(&mut out, arg)?;
+    }
+    Ok(out)
+}
+
+fn encode_value(out: &mut Data, value: &CandidValue) -> CandidResult<()> {
+    match value {
+        CandidValue::Null | CandidValue::Reserved => (),
+        CandidValue::Bool(value) => out.push(*value as u8),
+        CandidValue::Nat(value) => write_uleb128(out, *value),
+        CandidValue::Int(value) => write_sleb128(out, *value),
+        CandidValue::Nat8(value) => out.push(*value),
+        CandidValue::Nat16(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Nat32(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Nat64(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Int8(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Int16(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Int32(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Int64(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Float32(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Float64(value) => out.extend_from_slice(&value.to_le_bytes()),
+        CandidValue::Text(text) => write_bytes(out, text.as_bytes()),
+        CandidValue::Principal(bytes) => {
+            if bytes.len() > MAX_PRINCIPAL_LEN {
+                return Err(CandidError::InvalidPrincipal);
+            }
+            // Only transparent principal references (`0x01`) are allowed.
+            out.push(1);
+            write_bytes(out, bytes);
+        },
+        CandidValue::Opt(inner_type, inner) => match inner {
+            Some(inner) => {
+                check_type(inner, inner_type)?;
+                out.push(1);
+                encode_value(out, inner)?;
+            },
+            None => out.push(0),
+        },
+        CandidValue::Vec(element_type, elements) => {
+            write_uleb128(out, elements.len() as u128);
+            for element in elements {
+                check_type(element, element_type)?;
+                encode_value(out, element)?;
+            }
+        },
+        CandidValue::Blob(bytes) => write_bytes(out, bytes),
+        CandidValue::Record(fields) => {
+            for (_, field) in sorted_fields(fields)? {
+                encode_value(out, field)?;
+            }
+        },
+        CandidValue::Variant {
+            alternatives,
+            label,
+            value,
+        } => {
+            let sorted = sorted_fields(alternatives)?;
+            let index = sorted
+                .iter()
+                .position(|(id, _)| *id == label.id())
+                .ok_or(CandidError::UnknownVariant)?;
+            check_type(value, sorted[index].1)?;
+
+            write_uleb128(out, index as u128);
+            encode_value(out, value)?;
+        },
+    }
+    Ok(())
+}
+
+fn check_type(value: &CandidValue, expected: &CandidType) -> CandidResult<()> {
+    if value.candid_type() != *expected {
+        return Err(CandidError::TypeMismatch);
+    }
+    Ok(())
+}
+
+fn write_bytes(out: &mut Data, bytes: &[u8]) {
+    write_uleb128(out, bytes.len() as u128);
+    out.extend_from_slice(bytes);
+}
+
+/// Composite types referenced by the arguments, where each type is encoded once.
+#[derive(Default)]
+struct TypeTable {
+    types: Vec<CandidType>,
+    entries: Vec<Data>,
+}
+
+impl TypeTable {
+    /// Returns either an opcode of a primitive type or an index of a composite type in the table.
+    fn index_of(&mut self, ty: &CandidType) -> CandidResult<i64> {
+        if let Some(opcode) = ty.primitive_opcode() {
+            return Ok(opcode);
+        }
+        if let Some(index) = self.types.iter().position(|known| known == ty) {
+            return Ok(index as i64);
+        }
+
+        // Reserve the index before encoding nested types.
+        let index = self.types.len();
+        self.types.push(ty.clone());
+        self.entries.push(Data::new());
+
+        let mut entry = Data::new();
+        match ty {
+            CandidType::Opt(inner) => {
+                write_sleb128(&mut entry, opcode::OPT as i128);
+                let inner_index = self.index_of(inner)?;
+                write_sleb128(&mut entry, inner_index as i128);
+            },
+            CandidType::Vec(element) => {
+                write_sleb128(&mut entry, opcode::VEC as i128);
+                let element_index = self.index_of(element)?;
+                write_sleb128(&mut entry, element_index as i128);
+            },
+            CandidType::Record(fields) | CandidType::Variant(fields) => {
+                let opcode = match ty {
+                    CandidType::Record(_) => opcode::RECORD,
+                    _ => opcode::VARIANT,
+                };
+                write_sleb128(&mut entry, opcode as i128);
+                write_uleb128(&mut entry, fields.len() as u128);
+                for (field_id, field_type) in sorted_fields(fields)? {
+                    write_uleb128(&mut entry, field_id as u128);
+                    let field_index = self.index_of(field_type)?;
+                    write_sleb128(&mut entry, field_index as i128);
+                }
+            },
+            _ => unreachable!("Primitive types are handled above"),
+        }
+
+        self.entries[index] = entry;
+        Ok(index as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Label;
+    use tw_encoding::hex;
+
+    fn encode_hex(args: &[CandidValue]) -> String {
+        hex::encode(encode_args(args).unwrap(), false)
+    }
+
+    #[test]
+    fn test_encode_no_args() {
+        assert_eq!(encode_hex(&[]), "4449444c0000");
+    }
+
+    #[test]
+    fn test_encode_primitives() {
+        assert_eq!(
+            encode_hex(&[
+                CandidValue::Nat(624485),
+                CandidValue::text("Hi"),
+                CandidValue::Bool(true),
+            ]),
+            "4449444c00037d717ee58e2602486901"
+        );
+        assert_eq!(
+            encode_hex(&[
+                CandidValue::Int(-123456),
+                CandidValue::Nat16(513),
+                CandidValue::Int32(-2),
+                CandidValue::Float64(1.5),
+                CandidValue::Null,
+                CandidValue::Reserved,
+            ]),
+            "4449444c00067c7a75727f70c0bb780102feffffff000000000000f83f"
+        );
+    }
+
+    #[test]
+    fn test_encode_record() {
+        let owner = vec![0, 0, 0, 0, 0, 0, 0, 2, 1, 1];
+        assert_eq!(
+            encode_hex(&[CandidValue::record([
+                ("owner", CandidValue::Principal(owner)),
+                ("subaccount", CandidValue::opt_blob(None)),
+            ])]),
+            "4449444c036c02b3b0dac30368ad86ca8305016e026d7b0100010a0000000000000002010100"
+        );
+    }
+
+    #[test]
+    fn test_encode_variant() {
+        let alternatives = vec![
+            (Label::from("Ok"), CandidType::Nat),
+            (Label::from("Err"), CandidType::Text),
+        ];
+        let value = CandidValue::Variant {
+            alternatives,
+            label: Label::from("Err"),
+            value: Box::new(CandidValue::text("fail")),
+        };
+        assert_eq!(
+            encode_hex(&[value]),
+            "4449444c016b02bc8a017dc5fed20171010001046661696c"
+        );
+    }
+
+    #[test]
+    fn test_encode_vec() {
+        let value = CandidValue::Vec(
+            CandidType::Nat16,
+            vec![CandidValue::Nat16(1), CandidValue::Nat16(2)],
+        );
+        assert_eq!(encode_hex(&[value]), "4449444c016d7a01000201000200");
+    }
+
+    #[test]
+    fn test_encode_type_mismatch() {
+        let value = CandidValue::Vec(CandidType::Nat16, vec![CandidValue::Nat8(1)]);
+        assert_eq!(encode_args(&[value]), Err(CandidError::TypeMismatch));
+
+        let value = CandidValue::opt(CandidType::Text, Some(CandidValue::Nat(1)));
+        assert_eq!(encode_args(&[value]), Err(CandidError::TypeMismatch));
+    }
+
+    #[test]
+    fn test_encode_unknown_variant() {
+        let value = CandidValue::Variant {
+            alternatives: vec![(Label::from("Ok"), CandidType::Null)],
+            label: Label::from("Err"),
+            value: Box::new(CandidValue::Null),
+        };
+        assert_eq!(encode_args(&[value]), Err(CandidError::UnknownVariant));
+    }
+
+    #[test]
+    fn test_encode_principal_too_long() {
+        let value = CandidValue::Principal(vec![0; MAX_PRINCIPAL_LEN + 1]);
+        assert_eq!(encode_args(&[value]), Err(CandidError::InvalidPrincipal));
+    }
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub type CandidResult<T> = Result<T, CandidError>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CandidError {
+    /// The message doesn't start with `DIDL`.
+    InvalidMagic,
+    UnexpectedEof,
+    TrailingBytes,
+    /// Composite types are nested deeper than [`crate::MAX_DEPTH`].
+    TooDeep,
+    /// The types are expanded into more than [`crate::MAX_TYPE_NODES`] nodes.
+    TypeTooLarge,
+    /// Unknown type opcode or a reference out of the type table.
+    InvalidType(i64),
+    /// `func`, `service` and recursive types are not supported.
+    UnsupportedType,
+    /// Record or variant fields are not sorted, or there are duplicates.
+    InvalidFieldOrder,
+    /// The value doesn't match the declared type.
+    TypeMismatch,
+    /// The selected variant alternative is not declared in the variant type.
+    UnknownVariant,
+    InvalidBool,
+    InvalidOptTag,
+    InvalidPrincipal,
+    InvalidUtf8,
+    NumberOverflow,
+    /// The `empty` type has no values.
+    EmptyValue,
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub(crate) fn write_uleb128(out: &mut Vec<u8>, mut value: u128) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+pub(crate) fn write_sleb128(out: &mut Vec<u8>, mut value: i128) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let sign_bit_clear = byte & 0x40 == 0;
+        if (value == 0 && sign_bit_clear) || (value == -1 && !sign_bit_clear) {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uleb128(value: u128) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_uleb128(&mut out, value);
+        out
+    }
+
+    fn sleb128(value: i128) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_sleb128(&mut out, value);
+        out
+    }
+
+    #[test]
+    fn test_uleb128() {
+        assert_eq!(uleb128(0), [0x00]);
+        assert_eq!(uleb128(127), [0x7f]);
+        assert_eq!(uleb128(128), [0x80, 0x01]);
+        assert_eq!(uleb128(624485), [0xe5, 0x8e, 0x26]);
+    }
+
+    #[test]
+    fn test_sleb128() {
+        assert_eq!(sleb128(0), [0x00]);
+        assert_eq!(sleb128(-1), [0x7f]);
+        assert_eq!(sleb128(63), [0x3f]);
+        assert_eq!(sleb128(64), [0xc0, 0x00]);
+        assert_eq!(sleb128(-123456), [0xc0, 0xbb, 0x78]);
+    }
+}
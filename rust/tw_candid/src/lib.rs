@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Candid is the interface description language of the Internet Computer.
+//! This crate implements the binary format of Candid values without any dependency on a particular canister:
+//! https://github.com/dfinity/candid/blob/master/spec/Candid.md#binary-format
+//!
+//! Note that `func` and `service` references, as well as recursive types, are not supported.
+
+pub mod decode;
+pub mod encode;
+pub mod error;
+mod leb128;
+pub mod types;
+pub mod value;
+
+pub use decode::decode_args;
+pub use encode::encode_args;
+pub use error::{CandidError, CandidResult};
+pub use types::{CandidType, Label};
+pub use value::CandidValue;
+
+/// The prefix of every Candid message.
+pub const MAGIC: &[u8] = b"DIDL";
+
+/// The maximum nesting depth of composite types.
+pub const MAX_DEPTH: usize = 64;
+
+/// The maximum total number of type nodes created while decoding a message.
+/// Type table entries are expanded into trees, so a short message may describe an exponentially large type.
+pub const MAX_TYPE_NODES: usize = 1 << 20;
+
+/// The maximum length of a principal in bytes.
+pub const MAX_PRINCIPAL_LEN: usize = 29;
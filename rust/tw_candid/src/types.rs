@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::error::{CandidError, CandidResult};
+use std::fmt;
+
+pub(crate) mod opcode {
+    pub const NULL: i64 = -1;
+    pub const BOOL: i64 = -2;
+    pub const NAT: i64 = -3;
+    pub const INT: i64 = -4;
+    pub const NAT8: i64 = -5;
+    pub const NAT16: i64 = -6;
+    pub const NAT32: i64 = -7;
+    pub const NAT64: i64 = -8;
+    pub const INT8: i64 = -9;
+    pub const INT16: i64 = -10;
+    pub const INT32: i64 = -11;
+    pub const INT64: i64 = -12;
+    pub const FLOAT32: i64 = -13;
+    pub const FLOAT64: i64 = -14;
+    pub const TEXT: i64 = -15;
+    pub const RESERVED: i64 = -16;
+    pub const EMPTY: i64 = -17;
+    pub const OPT: i64 = -18;
+    pub const VEC: i64 = -19;
+    pub const RECORD: i64 = -20;
+    pub const VARIANT: i64 = -21;
+    pub const FUNC: i64 = -22;
+    pub const SERVICE: i64 = -23;
+    pub const PRINCIPAL: i64 = -24;
+}
+
+/// Hash of a field name used as a record or variant field ID.
+pub fn idl_hash(name: &str) -> u32 {
+    name.bytes().fold(0_u32, |hash, byte| {
+        hash.wrapping_mul(223).wrapping_add(byte as u32)
+    })
+}
+
+/// Record or variant field label.
+/// Field names are not transmitted, so decoded fields are always labeled by their IDs.
+#[derive(Clone, Debug)]
+pub enum Label {
+    Named(String),
+    Id(u32),
+}
+
+impl Label {
+    pub fn id(&self) -> u32 {
+        match self {
+            Label::Named(name) => idl_hash(name),
+            Label::Id(id) => *id,
+        }
+    }
+}
+
+impl From<&str> for Label {
+    fn from(name: &str) -> Self {
+        Label::Named(name.to_string())
+    }
+}
+
+impl From<u32> for Label {
+    fn from(id: u32) -> Self {
+        Label::Id(id)
+    }
+}
+
+/// Labels are equal if their IDs match, so `Label::Named("owner")` equals to `Label::Id(idl_hash("owner"))`.
+impl PartialEq for Label {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Label {}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Label::Named(name) => write!(f, "{name}"),
+            Label::Id(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum CandidType {
+    Null,
+    Bool,
+    Nat,
+    Int,
+    Nat8,
+    Nat16,
+    Nat32,
+    Nat64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Text,
+    Reserved,
+    Empty,
+    Principal,
+    Opt(Box<CandidType>),
+    Vec(Box<CandidType>),
+    Record(Vec<(Label, CandidType)>),
+    Variant(Vec<(Label, CandidType)>),
+}
+
+impl CandidType {
+    pub fn opt(inner: CandidType) -> CandidType {
+        CandidType::Opt(Box::new(inner))
+    }
+
+    pub fn vec(element: CandidType) -> CandidType {
+        CandidType::Vec(Box::new(element))
+    }
+
+    /// `vec nat8`, also known as `blob`.
+    pub fn blob() -> CandidType {
+        CandidType::vec(CandidType::Nat8)
+    }
+
+    /// Returns an opcode of the primitive type, or `None` if the type is composite.
+    pub(crate) fn primitive_opcode(&self) -> Option<i64> {
+        let opcode = match self {
+            CandidType::Null => opcode::NULL,
+            CandidType::Bool => opcode::BOOL,
+            CandidType::Nat => opcode::NAT,
+            CandidType::Int => opcode::INT,
+            CandidType::Nat8 => opcode::NAT8,
+            CandidType::Nat16 => opcode::NAT16,
+            CandidType::Nat32 => opcode::NAT32,
+            CandidType::Nat64 => opcode::NAT64,
+            CandidType::Int8 => opcode::INT8,
+            CandidType::Int16 => opcode::INT16,
+            CandidType::Int32 => opcode::INT32,
+            CandidType::Int64 => opcode::INT64,
+            CandidType::Float32 => opcode::FLOAT32,
+            CandidType::Float64 => opcode::FLOAT64,
+            CandidType::Text => opcode::TEXT,
+            CandidType::Reserved => opcode::RESERVED,
+            CandidType::Empty => opcode::EMPTY,
+            CandidType::Principal => opcode::PRINCIPAL,
+            CandidType::Opt(_)
+            | CandidType::Vec(_)
+            | CandidType::Record(_)
+            | CandidType::Variant(_) => return None,
+        };
+        Some(opcode)
+    }
+
+    pub(crate) fn from_primitive_opcode(code: i64) -> CandidResult<CandidType> {
+        let ty = match code {
+            opcode::NULL => CandidType::Null,
+            opcode::BOOL => CandidType::Bool,
+            opcode::NAT => CandidType::Nat,
+            opcode::INT => CandidType::Int,
+            opcode::NAT8 => CandidType::Nat8,
+            opcode::NAT16 => CandidType::Nat16,
+            opcode::NAT32 => CandidType::Nat32,
+            opcode::NAT64 => CandidType::Nat64,
+            opcode::INT8 => CandidType::Int8,
+            opcode::INT16 => CandidType::Int16,
+            opcode::INT32 => CandidType::Int32,
+            opcode::INT64 => CandidType::Int64,
+            opcode::FLOAT32 => CandidType::Float32,
+            opcode::FLOAT64 => CandidType::Float64,
+            opcode::TEXT => CandidType::Text,
+            opcode::RESERVED => CandidType::Reserved,
+            opcode::EMPTY => CandidType::Empty,
+            opcode::PRINCIPAL => CandidType::Principal,
+            opcode::FUNC | opcode::SERVICE => return Err(CandidError::UnsupportedType),
+            other => return Err(CandidError::InvalidType(other)),
+        };
+        Ok(ty)
+    }
+}
+
+/// Record and variant types are equal regardless of the field order.
+impl PartialEq for CandidType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CandidType::Opt(a), CandidType::Opt(b)) | (CandidType::Vec(a), CandidType::Vec(b)) => {
+                a == b
+            },
+            (CandidType::Record(a), CandidType::Record(b))
+            | (CandidType::Variant(a), CandidType::Variant(b)) => fields_eq(a, b),
+            (a, b) => {
+                a.primitive_opcode().is_some() && a.primitive_opcode() == b.primitive_opcode()
+            },
+        }
+    }
+}
+
+impl Eq for CandidType {}
+
+fn fields_eq(a: &[(Label, CandidType)], b: &[(Label, CandidType)]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut a: Vec<_> = a.iter().map(|(label, ty)| (label.id(), ty)).collect();
+    let mut b: Vec<_> = b.iter().map(|(label, ty)| (label.id(), ty)).collect();
+    a.sort_by_key(|(id, _)| *id);
+    b.sort_by_key(|(id, _)| *id);
+    a == b
+}
+
+/// Returns fields sorted by their IDs, or an error if there are duplicate IDs.
+pub(crate) fn sorted_fields<T>(fields: &[(Label, T)]) -> CandidResult<Vec<(u32, &T)>> {
+    let mut sorted: Vec<_> = fields
+        .iter()
+        .map(|(label, item)| (label.id(), item))
+        .collect();
+    sorted.sort_by_key(|(id, _)| *id);
+
+    if sorted.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return Err(CandidError::InvalidFieldOrder);
+    }
+    Ok(sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idl_hash() {
+        assert_eq!(idl_hash("owner"), 947296307);
+        assert_eq!(idl_hash("subaccount"), 1349681965);
+        assert_eq!(idl_hash(""), 0);
+    }
+
+    #[test]
+    fn test_label_eq() {
+        assert_eq!(Label::from("owner"), Label::Id(947296307));
+        assert_ne!(Label::from("owner"), Label::from("subaccount"));
+    }
+
+    #[test]
+    fn test_record_type_eq() {
+        let record = CandidType::Record(vec![
+            (Label::from("owner"), CandidType::Principal),
+            (
+                Label::from("subaccount"),
+                CandidType::opt(CandidType::blob()),
+            ),
+        ]);
+        let reordered = CandidType::Record(vec![
+            (Label::Id(1349681965), CandidType::opt(CandidType::blob())),
+            (Label::Id(947296307), CandidType::Principal),
+        ]);
+        assert_eq!(record, reordered);
+        assert_ne!(CandidType::Record(vec![]), CandidType::Variant(vec![]));
+        assert_ne!(CandidType::Nat, CandidType::Int);
+    }
+
+    #[test]
+    fn test_sorted_fields_duplicate() {
+        let fields = [
+            (Label::from("owner"), CandidType::Principal),
+            (Label::Id(947296307), CandidType::Nat),
+        ];
+        assert_eq!(sorted_fields(&fields), Err(CandidError::InvalidFieldOrder));
+    }
+}
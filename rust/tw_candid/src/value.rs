@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::types::{CandidType, Label};
+use tw_memory::Data;
+
+/// Candid value.
+/// Optional values, vectors and variants carry their types,
+/// so the type table can be built even if they are empty.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CandidValue {
+    Null,
+    Bool(bool),
+    Nat(u128),
+    Int(i128),
+    Nat8(u8),
+    Nat16(u16),
+    Nat32(u32),
+    Nat64(u64),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+    Text(String),
+    Reserved,
+    /// Raw principal bytes.
+    Principal(Data),
+    Opt(CandidType, Option<Box<CandidValue>>),
+    Vec(CandidType, Vec<CandidValue>),
+    /// `vec nat8` value.
+    Blob(Data),
+    Record(Vec<(Label, CandidValue)>),
+    Variant {
+        alternatives: Vec<(Label, CandidType)>,
+        label: Label,
+        value: Box<CandidValue>,
+    },
+}
+
+impl CandidValue {
+    pub fn opt(inner_type: CandidType, value: Option<CandidValue>) -> CandidValue {
+        CandidValue::Opt(inner_type, value.map(Box::new))
+    }
+
+    pub fn opt_nat(value: Option<u128>) -> CandidValue {
+        CandidValue::opt(CandidType::Nat, value.map(CandidValue::Nat))
+    }
+
+    pub fn opt_nat64(value: Option<u64>) -> CandidValue {
+        CandidValue::opt(CandidType::Nat64, value.map(CandidValue::Nat64))
+    }
+
+    pub fn opt_blob(value: Option<&[u8]>) -> CandidValue {
+        CandidValue::opt(
+            CandidType::blob(),
+            value.map(|bytes| CandidValue::Blob(bytes.to_vec())),
+        )
+    }
+
+    pub fn text<S: Into<String>>(text: S) -> CandidValue {
+        CandidValue::Text(text.into())
+    }
+
+    /// Creates a record from the given named fields.
+    pub fn record<I>(fields: I) -> CandidValue
+    where
+        I: IntoIterator<Item = (&'static str, CandidValue)>,
+    {
+        CandidValue::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| (Label::from(name), value))
+                .collect(),
+        )
+    }
+
+    /// Returns a record field or a variant value by its label.
+    pub fn field<L: Into<Label>>(&self, label: L) -> Option<&CandidValue> {
+        let label = label.into();
+        match self {
+            CandidValue::Record(fields) => fields
+                .iter()
+                .find(|(field_label, _)| *field_label == label)
+                .map(|(_, value)| value),
+            CandidValue::Variant {
+                label: selected,
+                value,
+                ..
+            } if *selected == label => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn candid_type(&self) -> CandidType {
+        match self {
+            CandidValue::Null => CandidType::Null,
+            CandidValue::Bool(_) => CandidType::Bool,
+            CandidValue::Nat(_) => CandidType::Nat,
+            CandidValue::Int(_) => CandidType::Int,
+            CandidValue::Nat8(_) => CandidType::Nat8,
+            CandidValue::Nat16(_) => CandidType::Nat16,
+            CandidValue::Nat32(_) => CandidType::Nat32,
+            CandidValue::Nat64(_) => CandidType::Nat64,
+            CandidValue::Int8(_) => CandidType::Int8,
+            CandidValue::Int16(_) => CandidType::Int16,
+            CandidValue::Int32(_) => CandidType::Int32,
+            CandidValue::Int64(_) => CandidType::Int64,
+            CandidValue::Float32(_) => CandidType::Float32,
+            CandidValue::Float64(_) => CandidType::Float64,
+            CandidValue::Text(_) => CandidType::Text,
+            CandidValue::Reserved => CandidType::Reserved,
+            CandidValue::Principal(_) => CandidType::Principal,
+            CandidValue::Opt(inner_type, _) => CandidType::opt(inner_type.clone()),
+            CandidValue::Vec(element_type, _) => CandidType::vec(element_type.clone()),
+            CandidValue::Blob(_) => CandidType::blob(),
+            CandidValue::Record(fields) => CandidType::Record(
+                fields
+                    .iter()
+                    .map(|(label, value)| (label.clone(), value.candid_type()))
+                    .collect(),
+            ),
+            CandidValue::Variant { alternatives, .. } => CandidType::Variant(alternatives.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_field() {
+        let record = CandidValue::record([
+            ("owner", CandidValue::Principal(vec![1, 2, 3])),
+            ("amount", CandidValue::Nat(100)),
+        ]);
+        assert_eq!(record.field("amount"), Some(&CandidValue::Nat(100)));
+        assert_eq!(record.field("fee"), None);
+
+        let record_type = record.candid_type();
+        assert_eq!(
+            record_type,
+            CandidType::Record(vec![
+                (Label::from("owner"), CandidType::Principal),
+                (Label::from("amount"), CandidType::Nat),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_blob_type() {
+        assert_eq!(
+            CandidValue::Blob(vec![1, 2]).candid_type(),
+            CandidType::vec(CandidType::Nat8)
+        );
+    }
+}
@@ -5,6 +5,7 @@
 use serde::de::Error as DeError;
 use serde::{Deserialize, Deserializer};
 use std::str::FromStr;
+use tw_hd_wallet::HDVersion;
 
 pub use derivation_path::{ChildIndex, DerivationPath};
 
@@ -14,6 +15,12 @@ pub struct DerivationWithPath {
     pub name: Derivation,
     #[serde(deserialize_with = "deserialize_der_path")]
     pub path: DerivationPath,
+    /// Version of the extended public key, e.g. `zpub`.
+    #[serde(default)]
+    pub xpub: Option<HDVersion>,
+    /// Version of the extended private key, e.g. `zprv`.
+    #[serde(default)]
+    pub xprv: Option<HDVersion>,
 }
 
 /// Extend this enum.
@@ -5,6 +5,7 @@
 use crate::error::address_error::AddressError;
 use crate::error::prelude::*;
 use tw_encoding::EncodingError;
+use tw_hd_wallet::HDWalletError;
 use tw_keypair::KeyPairError;
 use tw_number::NumberError;
 use tw_proto::ProtoError;
@@ -54,6 +55,16 @@ impl From<KeyPairError> for SigningError {
     }
 }
 
+impl From<HDWalletError> for SigningError {
+    fn from(err: HDWalletError) -> Self {
+        match err {
+            HDWalletError::UnsupportedCurve => TWError::new(SigningErrorType::Error_not_supported),
+            HDWalletError::KeyPairError(e) => SigningError::from(e),
+            _ => TWError::new(SigningErrorType::Error_invalid_params),
+        }
+    }
+}
+
 impl From<ProtoError> for SigningError {
     fn from(_e: ProtoError) -> Self {
         TWError::new(SigningErrorType::Error_input_parse)
@@ -8,7 +8,7 @@ use std::io::Write;
 use std::path::PathBuf;
 use std::{env, fs};
 
-/// We're only interested in `id` and `name` of the coin to generate `CoinType` enum.
+/// We're only interested in `id` and `name` of the coin to generate `CoinType` constants.
 #[derive(Deserialize)]
 struct CoinItem {
     #[serde(rename = "coinId")]
@@ -24,45 +24,104 @@ fn format_name(name: &str) -> String {
 
 fn generate_coin_type(coins: &[CoinItem]) -> String {
     const RAW_TYPE: &str = "u32";
-    const ENUM_NAME: &str = "CoinType";
+    const TYPE_NAME: &str = "CoinType";
 
-    let coin_types_variants = coins
+    let coin_types_consts = coins
         .iter()
-        .map(|coin| format!("\t{} = {},\n", format_name(&coin.name), coin.coin_id))
+        .map(|coin| {
+            format!(
+                "\tpub const {}: {TYPE_NAME} = {TYPE_NAME}({});\n",
+                format_name(&coin.name),
+                coin.coin_id
+            )
+        })
         .join("");
 
+    let coin_types_list = coins
+        .iter()
+        .map(|coin| format!("\t\t{TYPE_NAME}::{},\n", format_name(&coin.name)))
+        .join("");
+
+    let coin_types_names = coins
+        .iter()
+        .map(|coin| {
+            format!(
+                "\t\t\t{} => \"{}\",\n",
+                coin.coin_id,
+                format_name(&coin.name)
+            )
+        })
+        .join("");
+
+    let coins_count = coins.len();
+
     format!(
-        r#"#[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-#[derive(strum_macros::EnumIter, strum_macros::FromRepr)]
-#[repr({RAW_TYPE})]
-pub enum CoinType {{
-{coin_types_variants}
+        r#"/// Coin type is either one of the coins listed in `registry.json`,
+/// or a coin registered at runtime via [`crate::registry::register_coin_item`].
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct {TYPE_NAME}({RAW_TYPE});
+
+#[allow(non_upper_case_globals)]
+impl {TYPE_NAME} {{
+{coin_types_consts}
 }}
 
-impl {ENUM_NAME} {{
-    pub fn iter() -> impl IntoIterator<Item = {ENUM_NAME}> {{
-        use strum::IntoEnumIterator;
+impl {TYPE_NAME} {{
+    const BUILTIN: [{TYPE_NAME}; {coins_count}] = [
+{coin_types_list}
+    ];
+
+    /// Iterates over the coins listed in `registry.json`.
+    pub fn iter() -> impl IntoIterator<Item = {TYPE_NAME}> {{
+        {TYPE_NAME}::BUILTIN
+    }}
 
-        <Self as IntoEnumIterator>::iter()
+    /// Whether the coin is listed in `registry.json`.
+    pub fn is_builtin(&self) -> bool {{
+        {TYPE_NAME}::BUILTIN.contains(self)
+    }}
+
+    fn builtin_name(&self) -> Option<&'static str> {{
+        let name = match self.0 {{
+{coin_types_names}
+            _ => return None,
+        }};
+        Some(name)
+    }}
+}}
+
+impl From<{TYPE_NAME}> for {RAW_TYPE} {{
+    fn from(coin: {TYPE_NAME}) -> {RAW_TYPE} {{
+        coin.0
     }}
 }}
 
-impl TryFrom<{RAW_TYPE}> for {ENUM_NAME} {{
+/// Succeeds if the coin is either listed in `registry.json` or registered at runtime.
+impl TryFrom<{RAW_TYPE}> for {TYPE_NAME} {{
     type Error = ();
 
-    fn try_from(num: {RAW_TYPE}) -> Result<{ENUM_NAME}, ()> {{
-        {ENUM_NAME}::from_repr(num).ok_or(())
+    fn try_from(num: {RAW_TYPE}) -> Result<{TYPE_NAME}, ()> {{
+        let coin = {TYPE_NAME}(num);
+        crate::registry::get_coin_item(coin).map(|_| coin).map_err(|_| ())
+    }}
+}}
+
+impl std::fmt::Debug for {TYPE_NAME} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        match self.builtin_name() {{
+            Some(name) => f.write_str(name),
+            None => write!(f, "{TYPE_NAME}({{}})", self.0),
+        }}
     }}
 }}
 
-impl<'de> serde::Deserialize<'de> for {ENUM_NAME} {{
+impl<'de> serde::Deserialize<'de> for {TYPE_NAME} {{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::de::Deserializer<'de>,
     {{
         let num_value: {RAW_TYPE} = {RAW_TYPE}::deserialize(deserializer)?;
-        {ENUM_NAME}::try_from(num_value).map_err(|_| serde::de::Error::custom("Invalid `CoinType` value"))
+        Ok({TYPE_NAME}(num_value))
     }}
 }}
 "#
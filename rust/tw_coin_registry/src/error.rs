@@ -10,6 +10,8 @@ pub type RegistryResult<T> = Result<T, RegistryError>;
 pub enum RegistryError {
     UnknownCoinType,
     Unsupported,
+    InvalidCoinItem,
+    AlreadyRegistered,
 }
 
 impl From<RegistryError> for SigningError {
@@ -22,6 +24,14 @@ impl From<RegistryError> for SigningError {
             },
             RegistryError::Unsupported => SigningError::new(SigningErrorType::Error_internal)
                 .context("Requested coin type is not supported in Rust yet"),
+            RegistryError::InvalidCoinItem => {
+                SigningError::new(SigningErrorType::Error_invalid_params)
+                    .context("Invalid coin item")
+            },
+            RegistryError::AlreadyRegistered => {
+                SigningError::new(SigningErrorType::Error_invalid_params)
+                    .context("Coin type is already registered")
+            },
         }
     }
 }
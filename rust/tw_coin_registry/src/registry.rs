@@ -8,11 +8,13 @@ use crate::error::{RegistryError, RegistryResult};
 use lazy_static::lazy_static;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tw_coin_entry::derivation::DerivationWithPath;
 use tw_hash::hasher::Hasher;
-use tw_keypair::tw::PublicKeyType;
+use tw_keypair::tw::{Curve, PublicKeyType};
 
 type RegistryMap = HashMap<CoinType, CoinItem>;
+type RuntimeRegistryMap = HashMap<CoinType, &'static CoinItem>;
 
 /// cbindgen:ignore
 pub const REGISTRY_JSON: &str =
@@ -20,6 +22,7 @@ pub const REGISTRY_JSON: &str =
 
 lazy_static! {
     static ref REGISTRY: RegistryMap = parse_registry_json();
+    static ref RUNTIME_REGISTRY: RwLock<RuntimeRegistryMap> = RwLock::default();
 }
 
 /// Extend this structure according to `registry.json`.
@@ -29,24 +32,51 @@ pub struct CoinItem {
     pub id: String,
     pub name: String,
     pub coin_id: CoinType,
+    pub symbol: String,
+    pub decimals: u8,
     pub blockchain: BlockchainType,
     pub derivation: Vec<DerivationWithPath>,
+    pub curve: Curve,
     pub public_key_type: PublicKeyType,
+    pub chain_id: Option<String>,
     pub address_hasher: Option<Hasher>,
+    pub public_key_hasher: Option<Hasher>,
+    pub base58_hasher: Option<Hasher>,
     pub hrp: Option<String>,
     pub p2pkh_prefix: Option<u8>,
     pub p2sh_prefix: Option<u8>,
     pub ss58_prefix: Option<u16>,
+    pub explorer: Option<CoinExplorer>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinExplorer {
+    pub url: String,
+    pub tx_path: String,
+    pub account_path: String,
+    pub sample_tx: Option<String>,
+    pub sample_account: Option<String>,
 }
 
 #[inline]
 pub fn get_coin_item(coin: CoinType) -> RegistryResult<&'static CoinItem> {
-    REGISTRY.get(&coin).ok_or(RegistryError::UnknownCoinType)
+    if let Some(item) = REGISTRY.get(&coin) {
+        return Ok(item);
+    }
+    runtime_registry_read()
+        .get(&coin)
+        .copied()
+        .ok_or(RegistryError::UnknownCoinType)
 }
 
 #[inline]
 pub fn registry_iter() -> impl Iterator<Item = &'static CoinItem> {
-    REGISTRY.iter().map(|(_coin_type, item)| item)
+    let runtime_items: Vec<_> = runtime_registry_read().values().copied().collect();
+    REGISTRY
+        .iter()
+        .map(|(_coin_type, item)| item)
+        .chain(runtime_items)
 }
 
 #[inline]
@@ -61,8 +91,68 @@ pub fn coin_items_by_blockchain(
     registry_iter().filter(move |item| item.blockchain == blockchain)
 }
 
+/// Registers an additional EVM or Cosmos chain at runtime, so it can be used
+/// as any other coin listed in `registry.json`.
+///
+/// A coin cannot override a coin listed in `registry.json` or a coin previously registered
+/// at runtime with the same `coinId`, because registered items are never deallocated.
+pub fn register_coin_item(item: CoinItem) -> RegistryResult<CoinType> {
+    let coin = item.coin_id;
+    if coin.is_builtin() {
+        return Err(RegistryError::InvalidCoinItem);
+    }
+    match item.blockchain {
+        BlockchainType::Ethereum => {
+            if item.public_key_type != PublicKeyType::Secp256k1Extended {
+                return Err(RegistryError::InvalidCoinItem);
+            }
+        },
+        BlockchainType::Cosmos => {
+            let public_key_type_valid = matches!(
+                item.public_key_type,
+                PublicKeyType::Secp256k1 | PublicKeyType::Secp256k1Extended
+            );
+            if item.hrp.is_none() || !public_key_type_valid {
+                return Err(RegistryError::InvalidCoinItem);
+            }
+        },
+        _ => return Err(RegistryError::Unsupported),
+    }
+    if item.curve != Curve::Secp256k1 || item.derivation.is_empty() {
+        return Err(RegistryError::InvalidCoinItem);
+    }
+
+    let mut runtime_registry = runtime_registry_write();
+    if runtime_registry.contains_key(&coin) {
+        return Err(RegistryError::AlreadyRegistered);
+    }
+    let item: &'static CoinItem = Box::leak(Box::new(item));
+    runtime_registry.insert(coin, item);
+    Ok(coin)
+}
+
+/// Registers an additional EVM or Cosmos chain at runtime from a JSON object
+/// of the same format as the `registry.json` items.
+pub fn register_coin_json(json: &str) -> RegistryResult<CoinType> {
+    let item: CoinItem = serde_json::from_str(json).map_err(|_| RegistryError::InvalidCoinItem)?;
+    register_coin_item(item)
+}
+
 fn parse_registry_json() -> RegistryMap {
     let items: Vec<CoinItem> =
         serde_json::from_str(REGISTRY_JSON).expect("registry.json expected to be valid");
     items.into_iter().map(|item| (item.coin_id, item)).collect()
 }
+
+fn runtime_registry_read() -> RwLockReadGuard<'static, RuntimeRegistryMap> {
+    // The map is never left in an inconsistent state, so it's safe to ignore poisoning.
+    RUNTIME_REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+fn runtime_registry_write() -> RwLockWriteGuard<'static, RuntimeRegistryMap> {
+    RUNTIME_REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+}
@@ -8,3 +8,4 @@ pub mod serializer;
 pub mod signer;
 pub mod transaction_util;
 pub mod tx_builder;
+pub mod wallet_connect;
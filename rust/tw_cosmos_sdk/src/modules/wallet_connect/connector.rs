@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::wallet_connect::request::SignDirectRequest;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Cosmos::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct CosmosWalletConnector;
+
+impl WalletConnector for CosmosWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl CosmosWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::CosmosSignDirect => Self::parse_sign_direct_request(coin, request),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_direct_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let sign_request: SignDirectRequest = serde_json::from_str(&request.payload)
+            .tw_err(SigningErrorType::Error_input_parse)
+            .context("Error parsing WalletConnect signing request as JSON")?;
+        let sign_doc = sign_request.sign_doc;
+
+        if sign_doc.body_bytes.0.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("'bodyBytes' must not be empty");
+        }
+
+        let sign_direct = Proto::mod_Message::SignDirect {
+            body_bytes: sign_doc.body_bytes.0.into(),
+            auth_info_bytes: sign_doc.auth_info_bytes.0.into(),
+        };
+
+        let signing_input = Proto::SigningInput {
+            signing_mode: Proto::SigningMode::Protobuf,
+            account_number: sign_doc.account_number,
+            chain_id: sign_doc.chain_id.into(),
+            messages: vec![Proto::Message {
+                message_oneof: Proto::mod_Message::OneOfmessage_oneof::sign_direct_message(
+                    sign_direct,
+                ),
+            }],
+            ..Proto::SigningInput::default()
+        };
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::cosmos(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use tw_encoding::base64::Base64Encoded;
+use tw_misc::serde::as_string;
+
+/// A `SignDoc` as it is passed in the `cosmos_signDirect` request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignDirectDoc {
+    pub chain_id: String,
+    #[serde(with = "as_string")]
+    pub account_number: u64,
+    pub auth_info_bytes: Base64Encoded,
+    pub body_bytes: Base64Encoded,
+}
+
+/// `cosmos_signDirect` params.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/cosmos-rpc#cosmos_signdirect
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignDirectRequest {
+    pub signer_address: String,
+    pub sign_doc: SignDirectDoc,
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::contract::Contract;
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use lazy_static::lazy_static;
+use tw_hash::H256;
+use tw_memory::Data;
+
+/// Generated via https://remix.ethereum.org
+/// https://github.com/ethereum/consensus-specs/blob/dev/solidity_deposit_contract/deposit_contract.sol
+const DEPOSIT_CONTRACT_ABI: &str = include_str!("resource/deposit_contract.abi.json");
+
+lazy_static! {
+    static ref DEPOSIT_CONTRACT: Contract = serde_json::from_str(DEPOSIT_CONTRACT_ABI).unwrap();
+}
+
+pub struct DepositArgs {
+    pub pubkey: Data,
+    pub withdrawal_credentials: Data,
+    pub signature: Data,
+    pub deposit_data_root: H256,
+}
+
+/// The beacon-chain deposit contract.
+pub struct DepositContract;
+
+impl DepositContract {
+    pub fn deposit(args: DepositArgs) -> AbiResult<Data> {
+        let func = DEPOSIT_CONTRACT.function("deposit")?;
+        let deposit_data_root = NonEmptyBytes::new(args.deposit_data_root.into_vec())?;
+        func.encode_input(&[
+            Token::Bytes(args.pubkey),
+            Token::Bytes(args.withdrawal_credentials),
+            Token::Bytes(args.signature),
+            Token::FixedBytes(deposit_data_root),
+        ])
+    }
+}
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod deposit_contract;
 pub mod erc1155;
 pub mod erc20;
 pub mod erc4337;
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::beacon::ssz::{container_root, HashTreeRoot};
+use tw_hash::{H256, H32};
+
+pub type Domain = H256;
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/beacon-chain.md#domain-types
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DomainType {
+    VoluntaryExit,
+    Deposit,
+    BlsToExecutionChange,
+}
+
+impl DomainType {
+    pub fn to_bytes(self) -> H32 {
+        match self {
+            DomainType::VoluntaryExit => H32::from("04000000"),
+            DomainType::Deposit => H32::from("03000000"),
+            DomainType::BlsToExecutionChange => H32::from("0a000000"),
+        }
+    }
+}
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/beacon-chain.md#compute_fork_data_root
+pub fn compute_fork_data_root(fork_version: H32, genesis_validators_root: H256) -> H256 {
+    container_root(&[
+        fork_version.hash_tree_root(),
+        genesis_validators_root.hash_tree_root(),
+    ])
+}
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/beacon-chain.md#compute_domain
+pub fn compute_domain(
+    domain_type: DomainType,
+    fork_version: H32,
+    genesis_validators_root: H256,
+) -> Domain {
+    let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root);
+
+    let mut domain = Domain::default();
+    domain[..4].copy_from_slice(domain_type.to_bytes().as_slice());
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Deposits are valid across forks, so their domain is computed
+/// with the genesis fork version and an empty genesis validators root.
+pub fn compute_deposit_domain(genesis_fork_version: H32) -> Domain {
+    compute_domain(DomainType::Deposit, genesis_fork_version, H256::default())
+}
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/beacon-chain.md#compute_signing_root
+pub fn compute_signing_root<T: HashTreeRoot>(object: &T, domain: Domain) -> H256 {
+    container_root(&[object.hash_tree_root(), domain.hash_tree_root()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beacon::network::BeaconNetwork;
+
+    #[test]
+    fn test_compute_deposit_domain() {
+        let domain = compute_deposit_domain(BeaconNetwork::mainnet().genesis_fork_version);
+        assert_eq!(
+            domain,
+            Domain::from("03000000f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a9")
+        );
+    }
+}
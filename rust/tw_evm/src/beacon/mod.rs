@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Ethereum beacon-chain (consensus layer) messages signed with validator BLS keys:
+//! deposits, voluntary exits and BLS-to-execution withdrawal credential changes.
+
+pub mod domain;
+pub mod network;
+pub mod signer;
+pub mod ssz;
+pub mod types;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::{H256, H32};
+
+/// Beacon-chain network parameters that are required to compute signing domains.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeaconNetwork {
+    /// The network name as used in `deposit_data.json`.
+    pub name: &'static str,
+    pub genesis_fork_version: H32,
+    pub genesis_validators_root: H256,
+    /// Voluntary exits are signed with the Capella fork version since EIP-7044.
+    pub capella_fork_version: H32,
+}
+
+impl BeaconNetwork {
+    pub fn mainnet() -> BeaconNetwork {
+        BeaconNetwork {
+            name: "mainnet",
+            genesis_fork_version: H32::from("00000000"),
+            genesis_validators_root: H256::from(
+                "4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95",
+            ),
+            capella_fork_version: H32::from("03000000"),
+        }
+    }
+
+    pub fn sepolia() -> BeaconNetwork {
+        BeaconNetwork {
+            name: "sepolia",
+            genesis_fork_version: H32::from("90000069"),
+            genesis_validators_root: H256::from(
+                "d8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078",
+            ),
+            capella_fork_version: H32::from("90000072"),
+        }
+    }
+
+    pub fn holesky() -> BeaconNetwork {
+        BeaconNetwork {
+            name: "holesky",
+            genesis_fork_version: H32::from("01017000"),
+            genesis_validators_root: H256::from(
+                "9143aa7c615a7f7115e2b6aac319c03529df8242ae705fba9df39b79c59fa8b1",
+            ),
+            capella_fork_version: H32::from("04017000"),
+        }
+    }
+
+    /// Finds a known network by its name.
+    pub fn from_name(name: &str) -> Option<BeaconNetwork> {
+        match name {
+            "mainnet" => Some(BeaconNetwork::mainnet()),
+            "sepolia" => Some(BeaconNetwork::sepolia()),
+            "holesky" => Some(BeaconNetwork::holesky()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::abi_to_signing_error;
+use crate::abi::prebuild::deposit_contract::{DepositArgs, DepositContract};
+use crate::address::Address;
+use crate::beacon::domain::{compute_deposit_domain, compute_domain, compute_signing_root};
+use crate::beacon::domain::{Domain, DomainType};
+use crate::beacon::network::BeaconNetwork;
+use crate::beacon::ssz::HashTreeRoot;
+use crate::beacon::types::{
+    BlsPubkey, BlsSignature, BlsToExecutionChange, DepositData, DepositMessage, Epoch, Gwei,
+    ValidatorIndex, VoluntaryExit, MIN_DEPOSIT_AMOUNT,
+};
+use serde::Serialize;
+use serde_json::{json, Value as Json};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::ToHex;
+use tw_hash::{H256, H32};
+use tw_keypair::bls;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+
+/// The `staking-deposit-cli` version that `deposit_data.json` files are compatible with.
+pub const DEPOSIT_CLI_VERSION: &str = "2.7.0";
+
+#[derive(Clone, Debug)]
+pub struct SignedDeposit {
+    pub data: DepositData,
+    pub deposit_message_root: H256,
+    pub deposit_data_root: H256,
+    pub fork_version: H32,
+    pub network_name: &'static str,
+}
+
+impl SignedDeposit {
+    /// Returns the deposit contract `deposit(pubkey, withdrawal_credentials, signature, deposit_data_root)` call data.
+    /// The transaction must be sent with `amount` Gwei as a value.
+    pub fn deposit_call_data(&self) -> SigningResult<Data> {
+        DepositContract::deposit(DepositArgs {
+            pubkey: self.data.pubkey.to_vec(),
+            withdrawal_credentials: self.data.withdrawal_credentials.to_vec(),
+            signature: self.data.signature.to_vec(),
+            deposit_data_root: self.deposit_data_root,
+        })
+        .map_err(abi_to_signing_error)
+    }
+
+    /// Returns an entry of `deposit_data.json` as generated by `staking-deposit-cli`.
+    pub fn to_deposit_data_json(&self) -> DepositDataJson {
+        DepositDataJson {
+            pubkey: self.data.pubkey.to_hex(),
+            withdrawal_credentials: self.data.withdrawal_credentials.to_hex(),
+            amount: self.data.amount,
+            signature: self.data.signature.to_hex(),
+            deposit_message_root: self.deposit_message_root.to_hex(),
+            deposit_data_root: self.deposit_data_root.to_hex(),
+            fork_version: self.fork_version.to_hex(),
+            network_name: self.network_name.to_string(),
+            deposit_cli_version: DEPOSIT_CLI_VERSION.to_string(),
+        }
+    }
+}
+
+/// An entry of `deposit_data.json`. All hex values are not prefixed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct DepositDataJson {
+    pub pubkey: String,
+    pub withdrawal_credentials: String,
+    pub amount: Gwei,
+    pub signature: String,
+    pub deposit_message_root: String,
+    pub deposit_data_root: String,
+    pub fork_version: String,
+    pub network_name: String,
+    pub deposit_cli_version: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct SignedVoluntaryExit {
+    pub message: VoluntaryExit,
+    pub signature: BlsSignature,
+}
+
+impl SignedVoluntaryExit {
+    /// Returns the message in the Beacon API format
+    /// as accepted by `POST /eth/v1/beacon/pool/voluntary_exits`.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "message": {
+                "epoch": self.message.epoch.to_string(),
+                "validator_index": self.message.validator_index.to_string(),
+            },
+            "signature": self.signature.to_hex_prefixed(),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SignedBlsToExecutionChange {
+    pub message: BlsToExecutionChange,
+    pub signature: BlsSignature,
+}
+
+impl SignedBlsToExecutionChange {
+    /// Returns the message in the Beacon API format
+    /// as accepted by `POST /eth/v1/beacon/pool/bls_to_execution_changes`.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "message": {
+                "validator_index": self.message.validator_index.to_string(),
+                "from_bls_pubkey": self.message.from_bls_pubkey.to_hex_prefixed(),
+                "to_execution_address": self.message.to_execution_address.to_hex_prefixed(),
+            },
+            "signature": self.signature.to_hex_prefixed(),
+        })
+    }
+}
+
+/// Signs beacon-chain messages with validator or withdrawal BLS keys.
+/// Messages are always signed according to the proof-of-possession scheme.
+pub struct BeaconSigner;
+
+impl BeaconSigner {
+    /// Signs a deposit of `amount` Gwei for the validator with the given `validator_key`.
+    pub fn sign_deposit(
+        validator_key: &bls::PrivateKey,
+        withdrawal_credentials: H256,
+        amount: Gwei,
+        network: &BeaconNetwork,
+    ) -> SigningResult<SignedDeposit> {
+        if amount < MIN_DEPOSIT_AMOUNT {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Deposit amount must be at least 1 ETH");
+        }
+
+        let message = DepositMessage {
+            pubkey: Self::bls_pubkey(validator_key)?,
+            withdrawal_credentials,
+            amount,
+        };
+        let domain = compute_deposit_domain(network.genesis_fork_version);
+        let signature = Self::sign(validator_key, &message, domain)?;
+
+        let data = DepositData {
+            pubkey: message.pubkey,
+            withdrawal_credentials: message.withdrawal_credentials,
+            amount: message.amount,
+            signature,
+        };
+        Ok(SignedDeposit {
+            deposit_message_root: message.hash_tree_root(),
+            deposit_data_root: data.hash_tree_root(),
+            data,
+            fork_version: network.genesis_fork_version,
+            network_name: network.name,
+        })
+    }
+
+    /// Signs a voluntary exit of the validator with the given `validator_key`.
+    /// The exit is valid at `epoch` or later.
+    pub fn sign_voluntary_exit(
+        validator_key: &bls::PrivateKey,
+        validator_index: ValidatorIndex,
+        epoch: Epoch,
+        network: &BeaconNetwork,
+    ) -> SigningResult<SignedVoluntaryExit> {
+        let message = VoluntaryExit {
+            epoch,
+            validator_index,
+        };
+        // Voluntary exits are signed with the Capella fork version forever (EIP-7044).
+        let domain = compute_domain(
+            DomainType::VoluntaryExit,
+            network.capella_fork_version,
+            network.genesis_validators_root,
+        );
+        let signature = Self::sign(validator_key, &message, domain)?;
+        Ok(SignedVoluntaryExit { message, signature })
+    }
+
+    /// Signs a change of `0x00` BLS withdrawal credentials to `0x01` credentials
+    /// with the given `to_execution_address`.
+    pub fn sign_bls_to_execution_change(
+        withdrawal_key: &bls::PrivateKey,
+        validator_index: ValidatorIndex,
+        to_execution_address: Address,
+        network: &BeaconNetwork,
+    ) -> SigningResult<SignedBlsToExecutionChange> {
+        let message = BlsToExecutionChange {
+            validator_index,
+            from_bls_pubkey: Self::bls_pubkey(withdrawal_key)?,
+            to_execution_address: to_execution_address.bytes(),
+        };
+        let domain = compute_domain(
+            DomainType::BlsToExecutionChange,
+            network.genesis_fork_version,
+            network.genesis_validators_root,
+        );
+        let signature = Self::sign(withdrawal_key, &message, domain)?;
+        Ok(SignedBlsToExecutionChange { message, signature })
+    }
+
+    fn sign<T: HashTreeRoot>(
+        private: &bls::PrivateKey,
+        message: &T,
+        domain: Domain,
+    ) -> SigningResult<BlsSignature> {
+        let signing_root = compute_signing_root(message, domain);
+        let signature = private
+            .clone()
+            .with_scheme(bls::Scheme::ProofOfPossession)
+            .sign(signing_root.to_vec())
+            .tw_err(SigningErrorType::Error_signing)?;
+        BlsSignature::try_from(signature.to_vec().as_slice())
+            .tw_err(SigningErrorType::Error_internal)
+    }
+
+    fn bls_pubkey(private: &bls::PrivateKey) -> SigningResult<BlsPubkey> {
+        BlsPubkey::try_from(private.public().to_vec().as_slice())
+            .tw_err(SigningErrorType::Error_internal)
+    }
+}
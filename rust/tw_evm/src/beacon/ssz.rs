@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A minimal subset of the `SimpleSerialize` (SSZ) merkleization
+//! that is enough to compute hash tree roots of fixed-size beacon-chain containers.
+//! https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md#merkleization
+
+use tw_hash::sha2::sha256;
+use tw_hash::{Hash, H256};
+
+/// The number of bytes in a chunk.
+pub const BYTES_PER_CHUNK: usize = 32;
+
+pub trait HashTreeRoot {
+    fn hash_tree_root(&self) -> H256;
+}
+
+impl HashTreeRoot for u64 {
+    /// `uint64` is serialized as little-endian and padded to a single chunk.
+    fn hash_tree_root(&self) -> H256 {
+        let mut chunk = H256::default();
+        chunk[..8].copy_from_slice(&self.to_le_bytes());
+        chunk
+    }
+}
+
+impl<const N: usize> HashTreeRoot for Hash<N> {
+    /// `ByteVector[N]` is packed into chunks and merkleized.
+    fn hash_tree_root(&self) -> H256 {
+        merkleize(&pack(self.as_slice()))
+    }
+}
+
+/// Returns the hash tree root of a container with the given field roots.
+pub fn container_root(field_roots: &[H256]) -> H256 {
+    merkleize(field_roots)
+}
+
+/// Splits `bytes` into 32-byte chunks, padding the last one with zeros.
+pub fn pack(bytes: &[u8]) -> Vec<H256> {
+    bytes
+        .chunks(BYTES_PER_CHUNK)
+        .map(|chunk| {
+            let mut padded = H256::default();
+            padded[..chunk.len()].copy_from_slice(chunk);
+            padded
+        })
+        .collect()
+}
+
+/// Merkleizes `chunks` padded with zero chunks to the next power of two.
+pub fn merkleize(chunks: &[H256]) -> H256 {
+    let width = chunks.len().next_power_of_two();
+
+    let mut layer = chunks.to_vec();
+    layer.resize(width, H256::default());
+
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+    layer.pop().unwrap_or_default()
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+    let mut data = [0; BYTES_PER_CHUNK * 2];
+    data[..BYTES_PER_CHUNK].copy_from_slice(left.as_slice());
+    data[BYTES_PER_CHUNK..].copy_from_slice(right.as_slice());
+    H256::try_from(sha256(&data).as_slice()).expect("sha256 must return 32 bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_hash::H160;
+
+    #[test]
+    fn test_uint64_root() {
+        assert_eq!(
+            32_000_000_000_u64.hash_tree_root(),
+            H256::from("0040597307000000000000000000000000000000000000000000000000000000")
+        );
+    }
+
+    #[test]
+    fn test_byte_vector_root() {
+        // A vector fitting a single chunk is padded with zeros.
+        let address = H160::from("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(
+            address.hash_tree_root(),
+            H256::from("d8da6bf26964af9d7eed9e03e53415d37aa96045000000000000000000000000")
+        );
+
+        // Two chunks are hashed together.
+        let zero = Hash::<48>::default();
+        assert_eq!(
+            zero.hash_tree_root(),
+            H256::from("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b")
+        );
+    }
+
+    #[test]
+    fn test_merkleize_padding() {
+        let zero = H256::default();
+        // Three chunks are padded to four.
+        assert_eq!(merkleize(&[zero; 3]), merkleize(&[zero; 4]));
+        assert_eq!(merkleize(&[zero]), zero);
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Beacon-chain containers.
+//! https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/beacon-chain.md#containers
+
+use crate::address::Address;
+use crate::beacon::ssz::{container_root, HashTreeRoot};
+use tw_hash::sha2::sha256;
+use tw_hash::{Hash, H160, H256};
+
+pub type BlsPubkey = Hash<48>;
+pub type BlsSignature = Hash<96>;
+pub type Gwei = u64;
+pub type Epoch = u64;
+pub type ValidatorIndex = u64;
+
+/// 1 ETH is the minimum amount the deposit contract accepts.
+pub const MIN_DEPOSIT_AMOUNT: Gwei = 1_000_000_000;
+/// 32 ETH is the amount required to activate a validator.
+pub const MAX_EFFECTIVE_BALANCE: Gwei = 32_000_000_000;
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/phase0/validator.md#withdrawal-credentials
+pub struct WithdrawalCredentials;
+
+impl WithdrawalCredentials {
+    pub const BLS_WITHDRAWAL_PREFIX: u8 = 0x00;
+    pub const ETH1_ADDRESS_WITHDRAWAL_PREFIX: u8 = 0x01;
+    pub const COMPOUNDING_WITHDRAWAL_PREFIX: u8 = 0x02;
+
+    /// Withdrawals are controlled by the given BLS withdrawal key.
+    pub fn bls(withdrawal_pubkey: &BlsPubkey) -> H256 {
+        let mut credentials = H256::try_from(sha256(withdrawal_pubkey.as_slice()).as_slice())
+            .expect("sha256 must return 32 bytes");
+        credentials[0] = Self::BLS_WITHDRAWAL_PREFIX;
+        credentials
+    }
+
+    /// Withdrawals are sent to the given execution layer address.
+    pub fn eth1_address(address: &Address) -> H256 {
+        Self::with_address(Self::ETH1_ADDRESS_WITHDRAWAL_PREFIX, address)
+    }
+
+    /// Withdrawals are sent to the given execution layer address,
+    /// and the validator balance is allowed to grow above 32 ETH (EIP-7251).
+    pub fn compounding(address: &Address) -> H256 {
+        Self::with_address(Self::COMPOUNDING_WITHDRAWAL_PREFIX, address)
+    }
+
+    fn with_address(prefix: u8, address: &Address) -> H256 {
+        let mut credentials = H256::default();
+        credentials[0] = prefix;
+        credentials[12..].copy_from_slice(address.as_slice());
+        credentials
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositMessage {
+    pub pubkey: BlsPubkey,
+    pub withdrawal_credentials: H256,
+    pub amount: Gwei,
+}
+
+impl HashTreeRoot for DepositMessage {
+    fn hash_tree_root(&self) -> H256 {
+        container_root(&[
+            self.pubkey.hash_tree_root(),
+            self.withdrawal_credentials.hash_tree_root(),
+            self.amount.hash_tree_root(),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositData {
+    pub pubkey: BlsPubkey,
+    pub withdrawal_credentials: H256,
+    pub amount: Gwei,
+    pub signature: BlsSignature,
+}
+
+impl DepositData {
+    pub fn message(&self) -> DepositMessage {
+        DepositMessage {
+            pubkey: self.pubkey,
+            withdrawal_credentials: self.withdrawal_credentials,
+            amount: self.amount,
+        }
+    }
+}
+
+impl HashTreeRoot for DepositData {
+    fn hash_tree_root(&self) -> H256 {
+        container_root(&[
+            self.pubkey.hash_tree_root(),
+            self.withdrawal_credentials.hash_tree_root(),
+            self.amount.hash_tree_root(),
+            self.signature.hash_tree_root(),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoluntaryExit {
+    pub epoch: Epoch,
+    pub validator_index: ValidatorIndex,
+}
+
+impl HashTreeRoot for VoluntaryExit {
+    fn hash_tree_root(&self) -> H256 {
+        container_root(&[
+            self.epoch.hash_tree_root(),
+            self.validator_index.hash_tree_root(),
+        ])
+    }
+}
+
+/// https://github.com/ethereum/consensus-specs/blob/dev/specs/capella/beacon-chain.md#blstoexecutionchange
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlsToExecutionChange {
+    pub validator_index: ValidatorIndex,
+    pub from_bls_pubkey: BlsPubkey,
+    pub to_execution_address: H160,
+}
+
+impl HashTreeRoot for BlsToExecutionChange {
+    fn hash_tree_root(&self) -> H256 {
+        container_root(&[
+            self.validator_index.hash_tree_root(),
+            self.from_bls_pubkey.hash_tree_root(),
+            self.to_execution_address.hash_tree_root(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdrawal_credentials() {
+        let address = Address::from("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+        assert_eq!(
+            WithdrawalCredentials::eth1_address(&address),
+            H256::from("010000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045")
+        );
+        assert_eq!(
+            WithdrawalCredentials::compounding(&address),
+            H256::from("020000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045")
+        );
+    }
+}
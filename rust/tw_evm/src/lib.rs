@@ -4,6 +4,7 @@
 
 pub mod abi;
 pub mod address;
+pub mod beacon;
 pub mod evm_context;
 pub mod evm_entry;
 pub mod message;
@@ -9,3 +9,4 @@ pub mod rlp_encoder;
 pub mod signer;
 pub mod transaction_util;
 pub mod tx_builder;
+pub mod wallet_connect;
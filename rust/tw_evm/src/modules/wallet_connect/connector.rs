@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::wallet_connect::request::{
+    PersonalSignRequest, SignTransactionRequest, SignTypedDataRequest, TransactionRequest,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value as Json;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct EvmWalletConnector;
+
+impl WalletConnector for EvmWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl EvmWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::EthSignTransaction | WCProto::Method::EthSendTransaction => {
+                Self::parse_sign_transaction_request(coin, request)
+            },
+            WCProto::Method::PersonalSign => Self::parse_personal_sign_request(coin, request),
+            WCProto::Method::EthSignTypedDataV4 => {
+                Self::parse_sign_typed_data_request(coin, request)
+            },
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_transaction_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let (tx,): SignTransactionRequest = parse_payload(&request.payload)?;
+        let signing_input = Self::signing_input_from_request(tx)?;
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ethereum(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_personal_sign_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        // The message is usually hex-encoded, but some dApps send it as a plain text.
+        // Both are handled by `Eip191Message`, so the message is passed as is.
+        let PersonalSignRequest(message, _address) = parse_payload(&request.payload)?;
+
+        let signing_input = Proto::MessageSigningInput {
+            message: message.into(),
+            message_type: Proto::MessageType::MessageType_legacy,
+            ..Proto::MessageSigningInput::default()
+        };
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ethereum_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_sign_typed_data_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let SignTypedDataRequest(_address, typed_data) = parse_payload(&request.payload)?;
+
+        let typed_data = match typed_data {
+            Json::String(typed_data) => typed_data,
+            Json::Object(_) => typed_data.to_string(),
+            _ => {
+                return SigningError::err(SigningErrorType::Error_input_parse)
+                    .context("Typed data is expected to be either a JSON object or a string")
+            },
+        };
+
+        let signing_input = Proto::MessageSigningInput {
+            message: typed_data.into(),
+            message_type: Proto::MessageType::MessageType_typed,
+            ..Proto::MessageSigningInput::default()
+        };
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ethereum_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    fn signing_input_from_request(
+        tx: TransactionRequest,
+    ) -> SigningResult<Proto::SigningInput<'static>> {
+        let to_address = tx.to.unwrap_or_default();
+        let amount = parse_u256(tx.value.as_deref(), "value")?;
+        let data = match tx.data {
+            Some(data) => hex::decode(&data)
+                .tw_err(SigningErrorType::Error_input_parse)
+                .context("Invalid transaction data")?,
+            None => Data::default(),
+        };
+
+        // EIP-1559 transaction if the corresponding fee fields are set.
+        let tx_mode = if tx.max_fee_per_gas.is_some() || tx.max_priority_fee_per_gas.is_some() {
+            Proto::TransactionMode::Enveloped
+        } else {
+            Proto::TransactionMode::Legacy
+        };
+
+        // Consider the transaction a contract call if the payload is not empty.
+        let transaction_oneof = if data.is_empty() {
+            Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer {
+                    amount: amount.into(),
+                    data: Cow::default(),
+                },
+            )
+        } else {
+            Proto::mod_Transaction::OneOftransaction_oneof::contract_generic(
+                Proto::mod_Transaction::ContractGeneric {
+                    amount: amount.into(),
+                    data: data.into(),
+                },
+            )
+        };
+
+        Ok(Proto::SigningInput {
+            chain_id: parse_u256(tx.chain_id.as_deref(), "chainId")?.into(),
+            nonce: parse_u256(tx.nonce.as_deref(), "nonce")?.into(),
+            tx_mode,
+            gas_price: parse_u256(tx.gas_price.as_deref(), "gasPrice")?.into(),
+            gas_limit: parse_u256(tx.gas.as_deref(), "gas")?.into(),
+            max_inclusion_fee_per_gas: parse_u256(
+                tx.max_priority_fee_per_gas.as_deref(),
+                "maxPriorityFeePerGas",
+            )?
+            .into(),
+            max_fee_per_gas: parse_u256(tx.max_fee_per_gas.as_deref(), "maxFeePerGas")?.into(),
+            to_address: to_address.into(),
+            transaction: Some(Proto::Transaction { transaction_oneof }),
+            ..Proto::SigningInput::default()
+        })
+    }
+}
+
+fn parse_payload<T: DeserializeOwned>(payload: &str) -> SigningResult<T> {
+    serde_json::from_str(payload)
+        .tw_err(SigningErrorType::Error_input_parse)
+        .context("Error parsing WalletConnect signing request as JSON")
+}
+
+/// Parses an optional hex or decimal number, and returns it as a compact big-endian encoded bytes.
+/// Returns an empty array if the value is not set, so it can be completed by the caller.
+fn parse_u256(value: Option<&str>, field: &str) -> SigningResult<Data> {
+    match value {
+        Some(value) => U256::from_str(value)
+            .map(|num| num.to_big_endian_compact())
+            .tw_err(SigningErrorType::Error_input_parse)
+            .with_context(|| format!("Invalid '{field}' value")),
+        None => Ok(Data::default()),
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use serde_json::Value as Json;
+
+/// A transaction object of the `eth_signTransaction` and `eth_sendTransaction` requests.
+/// Numeric values are either hex (`0x` prefixed) or decimal strings.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/ethereum-rpc#eth_signtransaction
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRequest {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    #[serde(alias = "gasLimit")]
+    pub gas: Option<String>,
+    pub gas_price: Option<String>,
+    pub max_fee_per_gas: Option<String>,
+    pub max_priority_fee_per_gas: Option<String>,
+    pub value: Option<String>,
+    #[serde(alias = "input")]
+    pub data: Option<String>,
+    pub nonce: Option<String>,
+    pub chain_id: Option<String>,
+}
+
+/// `eth_signTransaction` and `eth_sendTransaction` params: a single transaction object.
+pub type SignTransactionRequest = (TransactionRequest,);
+
+/// `personal_sign` params: a hex-encoded message followed by the signer address.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/ethereum-rpc#personal_sign
+#[derive(Deserialize)]
+pub struct PersonalSignRequest(pub String, pub String);
+
+/// `eth_signTypedData_v4` params: the signer address followed by the typed data.
+/// The typed data is either a JSON object or a string containing a JSON object.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/ethereum-rpc#eth_signtypeddata_v4
+#[derive(Deserialize)]
+pub struct SignTypedDataRequest(pub String, pub Json);
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::ToHex;
+use tw_evm::address::Address;
+use tw_evm::beacon::domain::{
+    compute_deposit_domain, compute_domain, compute_signing_root, DomainType,
+};
+use tw_evm::beacon::network::BeaconNetwork;
+use tw_evm::beacon::signer::BeaconSigner;
+use tw_evm::beacon::types::{WithdrawalCredentials, MAX_EFFECTIVE_BALANCE};
+use tw_hash::H256;
+use tw_keypair::bls;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_misc::traits::ToBytesVec;
+
+const VALIDATOR_KEY: &str = "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3";
+const WITHDRAWAL_KEY: &str = "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138";
+const EXECUTION_ADDRESS: &str = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+
+fn verify(public: &bls::PublicKey, signing_root: H256, signature: &[u8]) -> bool {
+    let signature = bls::Signature::try_from(signature).unwrap();
+    public.verify(signature, signing_root.to_vec())
+}
+
+#[test]
+fn test_sign_deposit() {
+    let validator_key = bls::PrivateKey::try_from(VALIDATOR_KEY).unwrap();
+    let credentials = WithdrawalCredentials::eth1_address(&Address::from(EXECUTION_ADDRESS));
+    let network = BeaconNetwork::mainnet();
+
+    let deposit =
+        BeaconSigner::sign_deposit(&validator_key, credentials, MAX_EFFECTIVE_BALANCE, &network)
+            .unwrap();
+
+    let deposit_json = serde_json::to_value(deposit.to_deposit_data_json()).unwrap();
+    let expected = json!({
+        "pubkey": "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+        "withdrawal_credentials": "010000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045",
+        "amount": 32000000000_u64,
+        "signature": "a9ec0e2a87d5488c3791fdb7a2ea0f61be69f3664637bd6681849b01fa7aff1a43c53445eb4d53fe1f38f3d19a90149117013e73b551df91a36181fbbec4d550f97d6f1e6c356d6ed751c19aa1c1ec3ba860b34d45dbccf024a0b3ff60c28011",
+        "deposit_message_root": "64f40f23fa3bcc5cb390707b1e48ad63c4f69b1c69014533a50b62558f27712a",
+        "deposit_data_root": "a950b270706923b9c09be5dce597849cc36dd279aacaceeabc4a811e9c5ac41d",
+        "fork_version": "00000000",
+        "network_name": "mainnet",
+        "deposit_cli_version": "2.7.0",
+    });
+    assert_eq!(deposit_json, expected);
+
+    let domain = compute_deposit_domain(network.genesis_fork_version);
+    let signing_root = compute_signing_root(&deposit.data.message(), domain);
+    assert!(verify(
+        &validator_key.public(),
+        signing_root,
+        deposit.data.signature.as_slice()
+    ));
+
+    // `deposit(bytes,bytes,bytes,bytes32)`
+    let call_data = deposit.deposit_call_data().unwrap();
+    assert_eq!(
+        call_data.to_hex(),
+        "22895118000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000e00000000000000000000000000000000000000000000000000000000000000120a950b270706923b9c09be5dce597849cc36dd279aacaceeabc4a811e9c5ac41d0000000000000000000000000000000000000000000000000000000000000030a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020010000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa960450000000000000000000000000000000000000000000000000000000000000060a9ec0e2a87d5488c3791fdb7a2ea0f61be69f3664637bd6681849b01fa7aff1a43c53445eb4d53fe1f38f3d19a90149117013e73b551df91a36181fbbec4d550f97d6f1e6c356d6ed751c19aa1c1ec3ba860b34d45dbccf024a0b3ff60c28011"
+    );
+}
+
+#[test]
+fn test_sign_deposit_bls_credentials() {
+    let validator_key = bls::PrivateKey::try_from(VALIDATOR_KEY).unwrap();
+    let withdrawal_key = bls::PrivateKey::try_from(WITHDRAWAL_KEY).unwrap();
+    let withdrawal_pubkey = withdrawal_key.public().to_vec();
+    let credentials = WithdrawalCredentials::bls(&withdrawal_pubkey.as_slice().try_into().unwrap());
+    assert_eq!(credentials[0], WithdrawalCredentials::BLS_WITHDRAWAL_PREFIX);
+
+    let network = BeaconNetwork::holesky();
+    let deposit =
+        BeaconSigner::sign_deposit(&validator_key, credentials, MAX_EFFECTIVE_BALANCE, &network)
+            .unwrap();
+    let deposit_json = deposit.to_deposit_data_json();
+    assert_eq!(deposit_json.fork_version, "01017000");
+    assert_eq!(deposit_json.network_name, "holesky");
+
+    // The deposit domain doesn't depend on the genesis validators root.
+    let domain = compute_deposit_domain(network.genesis_fork_version);
+    let signing_root = compute_signing_root(&deposit.data.message(), domain);
+    assert!(verify(
+        &validator_key.public(),
+        signing_root,
+        deposit.data.signature.as_slice()
+    ));
+}
+
+#[test]
+fn test_sign_deposit_insufficient_amount() {
+    let validator_key = bls::PrivateKey::try_from(VALIDATOR_KEY).unwrap();
+    let credentials = WithdrawalCredentials::eth1_address(&Address::from(EXECUTION_ADDRESS));
+
+    let err = BeaconSigner::sign_deposit(
+        &validator_key,
+        credentials,
+        999_999_999,
+        &BeaconNetwork::mainnet(),
+    )
+    .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_voluntary_exit() {
+    let validator_key = bls::PrivateKey::try_from(VALIDATOR_KEY).unwrap();
+    let network = BeaconNetwork::mainnet();
+
+    let exit = BeaconSigner::sign_voluntary_exit(&validator_key, 123456, 194048, &network).unwrap();
+
+    let expected = json!({
+        "message": {
+            "epoch": "194048",
+            "validator_index": "123456"
+        },
+        "signature": "0x819c3024c615b4ec0ead9d6168016798d631e2f413984fd340e6983eec211a709a317baecf406c81045be152e2480a3e0223eaa249432ff9a384872c366b7c59c50dbb44711c07fbe77a87ed150b6c2868e10dd34bc7e5580fa40564ecb59189"
+    });
+    assert_eq!(exit.to_json(), expected);
+
+    let domain = compute_domain(
+        DomainType::VoluntaryExit,
+        network.capella_fork_version,
+        network.genesis_validators_root,
+    );
+    let signing_root = compute_signing_root(&exit.message, domain);
+    assert!(verify(
+        &validator_key.public(),
+        signing_root,
+        exit.signature.as_slice()
+    ));
+}
+
+#[test]
+fn test_sign_bls_to_execution_change() {
+    let withdrawal_key = bls::PrivateKey::try_from(WITHDRAWAL_KEY).unwrap();
+    let network = BeaconNetwork::mainnet();
+
+    let change = BeaconSigner::sign_bls_to_execution_change(
+        &withdrawal_key,
+        123456,
+        Address::from(EXECUTION_ADDRESS),
+        &network,
+    )
+    .unwrap();
+
+    let expected = json!({
+        "message": {
+            "validator_index": "123456",
+            "from_bls_pubkey": "0xb301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+            "to_execution_address": "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+        },
+        "signature": "0xaebc4dd22a19d0c0ef1f47a2cadff93d27009102f315b95ace5edd19e3380cb0e1b9e38f36bc7ad5583252cdbe8fef3b17b51f303e9bb46847f7e58329583c40496c3b169715deaabd8875152845e4f9dab7108fcc162f5d7bec8fc6918e27d6"
+    });
+    assert_eq!(change.to_json(), expected);
+
+    let domain = compute_domain(
+        DomainType::BlsToExecutionChange,
+        network.genesis_fork_version,
+        network.genesis_validators_root,
+    );
+    let signing_root = compute_signing_root(&change.message, domain);
+    assert!(verify(
+        &withdrawal_key.public(),
+        signing_root,
+        change.signature.as_slice()
+    ));
+}
@@ -2,11 +2,12 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::blake::blake_256;
+use crate::blake::{blake256_d, blake_256};
+use crate::groestl::groestl_512d;
 use crate::ripemd::{blake256_ripemd, sha256_ripemd};
 use crate::sha2::{sha256, sha256_d};
 use crate::sha3::keccak256;
-use crate::{H160, H256};
+use crate::{H160, H256, H512};
 use serde::Deserialize;
 use tw_memory::Data;
 
@@ -95,9 +96,15 @@ pub enum Hasher {
     Sha256ripemd,
     #[serde(rename = "blake256")]
     Blake256,
+    /// BLAKE256 hash of the BLAKE256 hash
+    #[serde(rename = "blake256d")]
+    Blake256d,
     /// ripemd hash of the BLAKE256 hash
     #[serde(rename = "blake256ripemd")]
     Blake256ripemd,
+    /// Groestl512 hash of the Groestl512 hash
+    #[serde(rename = "groestl512d")]
+    Groestl512d,
     #[serde(rename = "tapsighash")]
     TapSighash,
 }
@@ -110,7 +117,9 @@ impl StatefulHasher for Hasher {
             Hasher::Sha256d => sha256_d(data),
             Hasher::Sha256ripemd => sha256_ripemd(data),
             Hasher::Blake256 => blake_256(data),
+            Hasher::Blake256d => blake256_d(data),
             Hasher::Blake256ripemd => blake256_ripemd(data),
+            Hasher::Groestl512d => groestl_512d(data),
             Hasher::TapSighash => tapsighash(data),
         }
     }
@@ -118,8 +127,13 @@ impl StatefulHasher for Hasher {
     /// Returns a corresponding hash len.
     fn hash_len(&self) -> usize {
         match self {
-            Hasher::Sha256 | Hasher::Keccak256 | Hasher::Sha256d | Hasher::Blake256 => H256::len(),
+            Hasher::Sha256
+            | Hasher::Keccak256
+            | Hasher::Sha256d
+            | Hasher::Blake256
+            | Hasher::Blake256d => H256::len(),
             Hasher::Sha256ripemd | Hasher::Blake256ripemd => H160::len(),
+            Hasher::Groestl512d => H512::len(),
             Hasher::TapSighash => H256::len(),
         }
     }
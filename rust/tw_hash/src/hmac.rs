@@ -3,9 +3,10 @@
 // Copyright © 2017 Trust Wallet.
 
 use hmac::{Hmac, Mac};
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
 
 type HmacSha256 = Hmac<Sha256>;
+type HmacSha512 = Hmac<Sha512>;
 
 pub fn hmac_sha256(key: &[u8], input: &[u8]) -> Vec<u8> {
     let mut mac = HmacSha256::new_from_slice(key).unwrap();
@@ -14,3 +15,11 @@ pub fn hmac_sha256(key: &[u8], input: &[u8]) -> Vec<u8> {
     let code_bytes = res.into_bytes();
     code_bytes.to_vec()
 }
+
+pub fn hmac_sha512(key: &[u8], input: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha512::new_from_slice(key).unwrap();
+    mac.update(input);
+    let res = mac.finalize();
+    let code_bytes = res.into_bytes();
+    code_bytes.to_vec()
+}
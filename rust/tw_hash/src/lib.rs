@@ -9,7 +9,9 @@ pub mod ffi;
 pub mod groestl;
 pub mod hasher;
 pub mod hmac;
+pub mod pbkdf2;
 pub mod ripemd;
+pub mod scrypt;
 pub mod sha1;
 pub mod sha2;
 pub mod sha3;
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use sha2::{Sha256, Sha512};
+
+/// Derives a key of `output_len` bytes from the `password` and `salt` using PBKDF2-HMAC-SHA256.
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], rounds: u32, output_len: usize) -> Vec<u8> {
+    let mut output = vec![0; output_len];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, rounds, &mut output);
+    output
+}
+
+/// Derives a key of `output_len` bytes from the `password` and `salt` using PBKDF2-HMAC-SHA512.
+pub fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], rounds: u32, output_len: usize) -> Vec<u8> {
+    let mut output = vec![0; output_len];
+    pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, rounds, &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_pbkdf2_hmac_sha256() {
+        // RFC 7914, section 11.
+        let actual = pbkdf2_hmac_sha256(b"passwd", b"salt", 1, 64);
+        assert_eq!(
+            actual.to_hex(),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_hmac_sha512() {
+        let actual = pbkdf2_hmac_sha512(b"password", b"salt", 1, 64);
+        assert_eq!(
+            actual.to_hex(),
+            "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::{Error, Result};
+
+/// Derives a key of `output_len` bytes from the `password` and `salt` using scrypt.
+/// The CPU/memory cost `n` must be a power of two greater than 1.
+pub fn scrypt(
+    password: &[u8],
+    salt: &[u8],
+    n: u32,
+    r: u32,
+    p: u32,
+    output_len: usize,
+) -> Result<Vec<u8>> {
+    if n < 2 || !n.is_power_of_two() {
+        return Err(Error::InvalidArgument);
+    }
+    let log_n = n.trailing_zeros() as u8;
+    // `len` is only used by the `password-hash` API, so it doesn't limit `output_len`.
+    let params = scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+        .map_err(|_| Error::InvalidArgument)?;
+
+    let mut output = vec![0; output_len];
+    scrypt::scrypt(password, salt, &params, &mut output).map_err(|_| Error::InvalidArgument)?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_scrypt() {
+        // RFC 7914, section 12.
+        let actual = scrypt(b"password", b"NaCl", 1024, 8, 16, 64).unwrap();
+        assert_eq!(
+            actual.to_hex(),
+            "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+        );
+    }
+
+    #[test]
+    fn test_scrypt_invalid_params() {
+        assert!(scrypt(b"password", b"NaCl", 1000, 8, 16, 64).is_err());
+        assert!(scrypt(b"password", b"NaCl", 1, 8, 16, 64).is_err());
+        assert!(scrypt(b"password", b"NaCl", 1024, 8, 16, 0).is_err());
+    }
+}
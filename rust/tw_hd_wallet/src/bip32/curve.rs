@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::{H256, H264};
+
+/// An elliptic curve that supports both private and public BIP-32 child key derivation.
+pub trait Bip32Curve {
+    /// HMAC key used to generate a master key from a seed.
+    const SEED_KEY: &'static [u8];
+
+    /// Checks whether the `key` is a valid non-zero scalar less than the curve order.
+    fn is_valid_private(key: &H256) -> bool;
+
+    /// Returns `key + tweak (mod n)`,
+    /// or `None` if either `key` or `tweak` is not less than the curve order, or the sum is zero.
+    fn add_private_tweak(key: &H256, tweak: &H256) -> Option<H256>;
+
+    /// Returns a compressed public key of the given private `key`.
+    fn public_key(key: &H256) -> Option<H264>;
+
+    /// Returns `public + tweak * G`,
+    /// or `None` if `tweak` is not less than the curve order, or the result is the point at infinity.
+    fn add_public_tweak(public: &H264, tweak: &H256) -> Option<H264>;
+}
+
+macro_rules! impl_bip32_curve {
+    ($name:ident, $curve:ident, $seed_key:literal) => {
+        pub struct $name;
+
+        impl $name {
+            fn scalar(bytes: &H256) -> Option<$curve::Scalar> {
+                use $curve::elliptic_curve::PrimeField;
+
+                let repr = $curve::FieldBytes::clone_from_slice(bytes.as_slice());
+                Option::from($curve::Scalar::from_repr(repr))
+            }
+        }
+
+        impl Bip32Curve for $name {
+            const SEED_KEY: &'static [u8] = $seed_key;
+
+            fn is_valid_private(key: &H256) -> bool {
+                use $curve::elliptic_curve::Field;
+
+                Self::scalar(key).map_or(false, |scalar| !bool::from(Field::is_zero(&scalar)))
+            }
+
+            fn add_private_tweak(key: &H256, tweak: &H256) -> Option<H256> {
+                use $curve::elliptic_curve::{Field, PrimeField};
+
+                let sum = Self::scalar(key)? + Self::scalar(tweak)?;
+                if bool::from(Field::is_zero(&sum)) {
+                    return None;
+                }
+                H256::try_from(sum.to_repr().as_slice()).ok()
+            }
+
+            fn public_key(key: &H256) -> Option<H264> {
+                use $curve::elliptic_curve::sec1::ToEncodedPoint;
+
+                let secret = $curve::SecretKey::from_slice(key.as_slice()).ok()?;
+                let encoded = secret.public_key().to_encoded_point(true);
+                H264::try_from(encoded.as_bytes()).ok()
+            }
+
+            fn add_public_tweak(public: &H264, tweak: &H256) -> Option<H264> {
+                use $curve::elliptic_curve::sec1::ToEncodedPoint;
+
+                let public = $curve::PublicKey::from_sec1_bytes(public.as_slice()).ok()?;
+                let point = public.to_projective()
+                    + $curve::ProjectivePoint::GENERATOR * Self::scalar(tweak)?;
+                let child = $curve::PublicKey::from_affine(point.to_affine()).ok()?;
+                H264::try_from(child.to_encoded_point(true).as_bytes()).ok()
+            }
+        }
+    };
+}
+
+impl_bip32_curve!(Secp256k1, k256, b"Bitcoin seed");
+impl_bip32_curve!(Nist256p1, p256, b"Nist256p1 seed");
@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! BIP-32 hierarchical deterministic keys for the ECDSA curves.
+//! https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+//!
+//! Master key generation and invalid child keys are handled according to SLIP-10,
+//! which produces the same keys as BIP-32 for `secp256k1`.
+//! https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use crate::hd_version::HDVersion;
+use crate::{ChildIndex, DerivationPath, HDWalletError, HDWalletResult};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::hasher::{Hasher, StatefulHasher};
+use tw_hash::hmac::hmac_sha512;
+use tw_hash::{H256, H264, H32};
+use zeroize::{Zeroize, Zeroizing};
+
+pub mod curve;
+
+pub use curve::{Bip32Curve, Nist256p1, Secp256k1};
+
+const SEED_LEN_RANGE: RangeInclusive<usize> = 16..=64;
+/// version(4) || depth(1) || parent fingerprint(4) || child number(4) || chain code(32) || key(33).
+const SERIALIZED_LEN: usize = 78;
+const CHECKSUM_LEN: usize = 4;
+
+/// Hash functions used to serialize extended keys.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bip32Hashers {
+    /// Used to calculate a key fingerprint from its public key.
+    pub public_key_hasher: Hasher,
+    /// Used to calculate a checksum of the serialized extended key.
+    pub base58_hasher: Hasher,
+}
+
+impl Default for Bip32Hashers {
+    fn default() -> Self {
+        Bip32Hashers {
+            public_key_hasher: Hasher::Sha256ripemd,
+            base58_hasher: Hasher::Sha256d,
+        }
+    }
+}
+
+/// BIP-32 extended private key.
+pub struct ExtendedPrivateKey<C: Bip32Curve> {
+    private_key: H256,
+    chain_code: H256,
+    depth: u8,
+    parent_fingerprint: H32,
+    child_number: u32,
+    hashers: Bip32Hashers,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: Bip32Curve> ExtendedPrivateKey<C> {
+    /// Generates a master key from the given `seed`.
+    pub fn new_master(seed: &[u8]) -> HDWalletResult<Self> {
+        if !SEED_LEN_RANGE.contains(&seed.len()) {
+            return Err(HDWalletError::InvalidSeed);
+        }
+
+        let mut i = Zeroizing::new(hmac_sha512(C::SEED_KEY, seed));
+        loop {
+            let (il, ir) = split_hmac(&i);
+            if C::is_valid_private(&il) {
+                return Ok(ExtendedPrivateKey {
+                    private_key: *il,
+                    chain_code: *ir,
+                    depth: 0,
+                    parent_fingerprint: H32::default(),
+                    child_number: 0,
+                    hashers: Bip32Hashers::default(),
+                    _phantom: PhantomData,
+                });
+            }
+            // SLIP-10: the master key is invalid, so repeat with `I` as the HMAC input.
+            i = Zeroizing::new(hmac_sha512(C::SEED_KEY, &i));
+        }
+    }
+
+    /// Sets hash functions used to calculate fingerprints and to serialize the key and its children.
+    pub fn with_hashers(mut self, hashers: Bip32Hashers) -> Self {
+        self.hashers = hashers;
+        self
+    }
+
+    /// Parses an extended private key like `xprv...` or `zprv...`.
+    pub fn from_base58(s: &str, hashers: Bip32Hashers) -> HDWalletResult<Self> {
+        let (version, payload) = decode_extended_key(s, hashers.base58_hasher)?;
+        if !version.is_private() || payload.key[0] != 0 {
+            return Err(HDWalletError::InvalidExtendedKey);
+        }
+
+        let private_key =
+            H256::try_from(&payload.key[1..]).map_err(|_| HDWalletError::InvalidExtendedKey)?;
+        if !C::is_valid_private(&private_key) {
+            return Err(HDWalletError::InvalidExtendedKey);
+        }
+
+        Ok(ExtendedPrivateKey {
+            private_key,
+            chain_code: payload.chain_code,
+            depth: payload.depth,
+            parent_fingerprint: payload.parent_fingerprint,
+            child_number: payload.child_number,
+            hashers,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Serializes the extended private key using the given `version` bytes.
+    pub fn to_base58(&self, version: HDVersion) -> HDWalletResult<Zeroizing<String>> {
+        if !version.is_private() {
+            return Err(HDWalletError::InvalidHDVersion);
+        }
+
+        let mut key = Zeroizing::new([0_u8; H264::LEN]);
+        key[1..].copy_from_slice(self.private_key.as_slice());
+
+        let payload = ExtendedKeyPayload {
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            key: *key,
+        };
+        Ok(encode_extended_key(
+            version,
+            &payload,
+            self.hashers.base58_hasher,
+        ))
+    }
+
+    /// Derives a child key at the given `index`.
+    pub fn derive_child(&self, index: ChildIndex) -> HDWalletResult<Self> {
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(HDWalletError::InvalidDerivationPath)?;
+        let child_number = index.to_bits();
+
+        let mut data = Zeroizing::new(Vec::with_capacity(H264::LEN + 4));
+        if index.is_hardened() {
+            data.push(0);
+            data.extend_from_slice(self.private_key.as_slice());
+        } else {
+            data.extend_from_slice(self.public_key().as_slice());
+        }
+        data.extend_from_slice(&child_number.to_be_bytes());
+
+        loop {
+            let i = Zeroizing::new(hmac_sha512(self.chain_code.as_slice(), &data));
+            let (il, ir) = split_hmac(&i);
+
+            if let Some(private_key) = C::add_private_tweak(&self.private_key, &il) {
+                return Ok(ExtendedPrivateKey {
+                    private_key,
+                    chain_code: *ir,
+                    depth,
+                    parent_fingerprint: self.fingerprint(),
+                    child_number,
+                    hashers: self.hashers,
+                    _phantom: PhantomData,
+                });
+            }
+            // SLIP-10: the child key is invalid, so repeat with `0x01 || IR || ser32(i)`.
+            data.clear();
+            data.push(1);
+            data.extend_from_slice(ir.as_slice());
+            data.extend_from_slice(&child_number.to_be_bytes());
+        }
+    }
+
+    /// Derives a descendant key following the given `path` starting from this key.
+    pub fn derive_path(&self, path: &DerivationPath) -> HDWalletResult<Self> {
+        path.path()
+            .iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the corresponding extended public key.
+    pub fn public(&self) -> ExtendedPublicKey<C> {
+        ExtendedPublicKey {
+            public_key: self.public_key(),
+            chain_code: self.chain_code,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            hashers: self.hashers,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn private_key(&self) -> &H256 {
+        &self.private_key
+    }
+
+    /// Returns the compressed public key.
+    pub fn public_key(&self) -> H264 {
+        C::public_key(&self.private_key).expect("Private key is validated on construction")
+    }
+
+    pub fn chain_code(&self) -> &H256 {
+        &self.chain_code
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    pub fn parent_fingerprint(&self) -> H32 {
+        self.parent_fingerprint
+    }
+
+    /// Returns the first 4 bytes of the public key hash.
+    pub fn fingerprint(&self) -> H32 {
+        fingerprint(&self.public_key(), self.hashers.public_key_hasher)
+    }
+}
+
+impl<C: Bip32Curve> Clone for ExtendedPrivateKey<C> {
+    fn clone(&self) -> Self {
+        ExtendedPrivateKey {
+            private_key: self.private_key,
+            chain_code: self.chain_code,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            hashers: self.hashers,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: Bip32Curve> Drop for ExtendedPrivateKey<C> {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+/// BIP-32 extended public key.
+pub struct ExtendedPublicKey<C: Bip32Curve> {
+    public_key: H264,
+    chain_code: H256,
+    depth: u8,
+    parent_fingerprint: H32,
+    child_number: u32,
+    hashers: Bip32Hashers,
+    _phantom: PhantomData<C>,
+}
+
+impl<C: Bip32Curve> ExtendedPublicKey<C> {
+    /// Parses an extended public key like `xpub...` or `zpub...`.
+    pub fn from_base58(s: &str, hashers: Bip32Hashers) -> HDWalletResult<Self> {
+        let (version, payload) = decode_extended_key(s, hashers.base58_hasher)?;
+        if !version.is_public() {
+            return Err(HDWalletError::InvalidExtendedKey);
+        }
+
+        let public_key = H264::from(payload.key);
+        // Validate the public key by adding a zero tweak.
+        if C::add_public_tweak(&public_key, &H256::default()).is_none() {
+            return Err(HDWalletError::InvalidExtendedKey);
+        }
+
+        Ok(ExtendedPublicKey {
+            public_key,
+            chain_code: payload.chain_code,
+            depth: payload.depth,
+            parent_fingerprint: payload.parent_fingerprint,
+            child_number: payload.child_number,
+            hashers,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Serializes the extended public key using the given `version` bytes.
+    pub fn to_base58(&self, version: HDVersion) -> HDWalletResult<String> {
+        if !version.is_public() {
+            return Err(HDWalletError::InvalidHDVersion);
+        }
+
+        let payload = ExtendedKeyPayload {
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            key: self.public_key.take(),
+        };
+        let encoded = encode_extended_key(version, &payload, self.hashers.base58_hasher);
+        Ok(encoded.to_string())
+    }
+
+    /// Derives a non-hardened child key at the given `index`.
+    pub fn derive_child(&self, index: ChildIndex) -> HDWalletResult<Self> {
+        if index.is_hardened() {
+            return Err(HDWalletError::InvalidDerivationPath);
+        }
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(HDWalletError::InvalidDerivationPath)?;
+        let child_number = index.to_bits();
+
+        let mut data = Vec::with_capacity(H264::LEN + 4);
+        data.extend_from_slice(self.public_key.as_slice());
+        data.extend_from_slice(&child_number.to_be_bytes());
+
+        loop {
+            let i = Zeroizing::new(hmac_sha512(self.chain_code.as_slice(), &data));
+            let (il, ir) = split_hmac(&i);
+
+            if let Some(public_key) = C::add_public_tweak(&self.public_key, &il) {
+                return Ok(ExtendedPublicKey {
+                    public_key,
+                    chain_code: *ir,
+                    depth,
+                    parent_fingerprint: self.fingerprint(),
+                    child_number,
+                    hashers: self.hashers,
+                    _phantom: PhantomData,
+                });
+            }
+            // SLIP-10: the child key is invalid, so repeat with `0x01 || IR || ser32(i)`.
+            data.clear();
+            data.push(1);
+            data.extend_from_slice(ir.as_slice());
+            data.extend_from_slice(&child_number.to_be_bytes());
+        }
+    }
+
+    /// Derives a descendant key following the given non-hardened `path` starting from this key.
+    pub fn derive_path(&self, path: &DerivationPath) -> HDWalletResult<Self> {
+        path.path()
+            .iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the compressed public key.
+    pub fn public_key(&self) -> &H264 {
+        &self.public_key
+    }
+
+    pub fn chain_code(&self) -> &H256 {
+        &self.chain_code
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    pub fn parent_fingerprint(&self) -> H32 {
+        self.parent_fingerprint
+    }
+
+    /// Returns the first 4 bytes of the public key hash.
+    pub fn fingerprint(&self) -> H32 {
+        fingerprint(&self.public_key, self.hashers.public_key_hasher)
+    }
+}
+
+impl<C: Bip32Curve> Clone for ExtendedPublicKey<C> {
+    fn clone(&self) -> Self {
+        ExtendedPublicKey {
+            public_key: self.public_key,
+            chain_code: self.chain_code,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            hashers: self.hashers,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+struct ExtendedKeyPayload {
+    depth: u8,
+    parent_fingerprint: H32,
+    child_number: u32,
+    chain_code: H256,
+    key: [u8; H264::LEN],
+}
+
+impl Drop for ExtendedKeyPayload {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+        self.key.zeroize();
+    }
+}
+
+fn encode_extended_key(
+    version: HDVersion,
+    payload: &ExtendedKeyPayload,
+    base58_hasher: Hasher,
+) -> Zeroizing<String> {
+    let mut data = Zeroizing::new(Vec::with_capacity(SERIALIZED_LEN + CHECKSUM_LEN));
+    data.extend_from_slice(&(version as u32).to_be_bytes());
+    data.push(payload.depth);
+    data.extend_from_slice(payload.parent_fingerprint.as_slice());
+    data.extend_from_slice(&payload.child_number.to_be_bytes());
+    data.extend_from_slice(payload.chain_code.as_slice());
+    data.extend_from_slice(&payload.key);
+
+    let checksum = base58_hasher.hash(&data);
+    data.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+
+    Zeroizing::new(base58::encode(&data, Alphabet::Bitcoin))
+}
+
+fn decode_extended_key(
+    s: &str,
+    base58_hasher: Hasher,
+) -> HDWalletResult<(HDVersion, ExtendedKeyPayload)> {
+    let data = Zeroizing::new(
+        base58::decode(s, Alphabet::Bitcoin).map_err(|_| HDWalletError::InvalidExtendedKey)?,
+    );
+    if data.len() != SERIALIZED_LEN + CHECKSUM_LEN {
+        return Err(HDWalletError::InvalidExtendedKey);
+    }
+
+    let (data, checksum) = data.split_at(SERIALIZED_LEN);
+    if base58_hasher.hash(data)[..CHECKSUM_LEN] != *checksum {
+        return Err(HDWalletError::InvalidExtendedKey);
+    }
+
+    let version = u32::from_be_bytes(data[0..4].try_into().expect("Expected 4 bytes"));
+    let version = HDVersion::from_raw(version).ok_or(HDWalletError::InvalidHDVersion)?;
+
+    let mut key = [0_u8; H264::LEN];
+    key.copy_from_slice(&data[45..SERIALIZED_LEN]);
+
+    let payload = ExtendedKeyPayload {
+        depth: data[4],
+        parent_fingerprint: H32::try_from(&data[5..9]).expect("Expected 4 bytes"),
+        child_number: u32::from_be_bytes(data[9..13].try_into().expect("Expected 4 bytes")),
+        chain_code: H256::try_from(&data[13..45]).expect("Expected 32 bytes"),
+        key,
+    };
+    Ok((version, payload))
+}
+
+fn fingerprint(public_key: &H264, hasher: Hasher) -> H32 {
+    let hash = hasher.hash(public_key.as_slice());
+    H32::try_from(&hash[..H32::LEN]).expect("Public key hash is expected to be at least 4 bytes")
+}
+
+/// Splits a 64 byte HMAC-SHA512 output into the left and right 32 byte halves.
+pub(crate) fn split_hmac(i: &[u8]) -> (Zeroizing<H256>, Zeroizing<H256>) {
+    let (il, ir) = i.split_at(H256::LEN);
+    let il = H256::try_from(il).expect("HMAC-SHA512 output is expected to be 64 bytes");
+    let ir = H256::try_from(ir).expect("HMAC-SHA512 output is expected to be 64 bytes");
+    (Zeroizing::new(il), Zeroizing::new(ir))
+}
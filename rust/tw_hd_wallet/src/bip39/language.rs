@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// The number of words in every BIP-39 word list.
+pub const WORD_LIST_LEN: usize = 2048;
+
+lazy_static! {
+    static ref CHINESE_SIMPLIFIED: WordList =
+        WordList::new(include_str!("wordlists/chinese_simplified.txt"));
+    static ref CHINESE_TRADITIONAL: WordList =
+        WordList::new(include_str!("wordlists/chinese_traditional.txt"));
+    static ref CZECH: WordList = WordList::new(include_str!("wordlists/czech.txt"));
+    static ref ENGLISH: WordList = WordList::new(include_str!("wordlists/english.txt"));
+    static ref FRENCH: WordList = WordList::new(include_str!("wordlists/french.txt"));
+    static ref ITALIAN: WordList = WordList::new(include_str!("wordlists/italian.txt"));
+    static ref JAPANESE: WordList = WordList::new(include_str!("wordlists/japanese.txt"));
+    static ref KOREAN: WordList = WordList::new(include_str!("wordlists/korean.txt"));
+    static ref PORTUGUESE: WordList = WordList::new(include_str!("wordlists/portuguese.txt"));
+    static ref SPANISH: WordList = WordList::new(include_str!("wordlists/spanish.txt"));
+}
+
+/// Languages of the BIP-39 word lists.
+/// https://github.com/bitcoin/bips/blob/master/bip-0039/bip-0039-wordlists.md
+///
+/// Note the words are stored in the NFKD normal form.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Language {
+    #[default]
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Portuguese,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 10] = [
+        Language::English,
+        Language::ChineseSimplified,
+        Language::ChineseTraditional,
+        Language::Czech,
+        Language::French,
+        Language::Italian,
+        Language::Japanese,
+        Language::Korean,
+        Language::Portuguese,
+        Language::Spanish,
+    ];
+
+    /// Returns the word at the given `index`.
+    /// The index is expected to be less than [`WORD_LIST_LEN`].
+    pub fn word(&self, index: u16) -> &'static str {
+        self.word_list().words[index as usize]
+    }
+
+    /// Returns an index of the given `word` if it belongs to the word list.
+    pub fn find_word(&self, word: &str) -> Option<u16> {
+        self.word_list().indexes.get(word).copied()
+    }
+
+    /// Returns the languages whose word lists contain all the given `words`.
+    pub fn candidates<'a>(words: &'a [&'a str]) -> impl Iterator<Item = Language> + 'a {
+        Language::ALL
+            .into_iter()
+            .filter(|language| words.iter().all(|word| language.find_word(word).is_some()))
+    }
+
+    fn word_list(&self) -> &'static WordList {
+        match self {
+            Language::English => &ENGLISH,
+            Language::ChineseSimplified => &CHINESE_SIMPLIFIED,
+            Language::ChineseTraditional => &CHINESE_TRADITIONAL,
+            Language::Czech => &CZECH,
+            Language::French => &FRENCH,
+            Language::Italian => &ITALIAN,
+            Language::Japanese => &JAPANESE,
+            Language::Korean => &KOREAN,
+            Language::Portuguese => &PORTUGUESE,
+            Language::Spanish => &SPANISH,
+        }
+    }
+}
+
+struct WordList {
+    words: Vec<&'static str>,
+    indexes: HashMap<&'static str, u16>,
+}
+
+impl WordList {
+    fn new(content: &'static str) -> WordList {
+        let words: Vec<_> = content.lines().collect();
+        assert_eq!(
+            words.len(),
+            WORD_LIST_LEN,
+            "BIP-39 word list must contain exactly 2048 words"
+        );
+
+        let indexes = words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (*word, index as u16))
+            .collect();
+        WordList { words, indexes }
+    }
+}
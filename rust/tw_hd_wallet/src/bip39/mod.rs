@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! BIP-39 mnemonic phrases.
+//! https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
+
+use crate::{HDWalletError, HDWalletResult};
+use tw_hash::pbkdf2::pbkdf2_hmac_sha512;
+use tw_hash::sha2::sha256;
+use tw_hash::H512;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+pub mod language;
+
+pub use language::Language;
+
+const BITS_PER_WORD: usize = 11;
+/// Allowed entropy lengths in bytes.
+const ENTROPY_LENS: [usize; 5] = [16, 20, 24, 28, 32];
+/// Allowed numbers of words corresponding to [`ENTROPY_LENS`].
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+const SEED_SALT_PREFIX: &str = "mnemonic";
+const SEED_PBKDF2_ROUNDS: u32 = 2048;
+
+/// A valid BIP-39 mnemonic phrase along with the entropy it encodes.
+pub struct Mnemonic {
+    language: Language,
+    /// NFKD normalized words separated by a single space.
+    phrase: Zeroizing<String>,
+    entropy: Zeroizing<Vec<u8>>,
+}
+
+impl Mnemonic {
+    /// Generates an English mnemonic phrase from the given `entropy`.
+    pub fn from_entropy(entropy: &[u8]) -> HDWalletResult<Mnemonic> {
+        Mnemonic::from_entropy_in(Language::English, entropy)
+    }
+
+    /// Generates a mnemonic phrase in the given `language` from the `entropy`.
+    /// The entropy must be 16, 20, 24, 28 or 32 bytes long.
+    pub fn from_entropy_in(language: Language, entropy: &[u8]) -> HDWalletResult<Mnemonic> {
+        if !ENTROPY_LENS.contains(&entropy.len()) {
+            return Err(HDWalletError::InvalidEntropy);
+        }
+
+        // Entropy followed by the checksum byte. Checksum length is at most 8 bits.
+        let mut data = Zeroizing::new(entropy.to_vec());
+        data.push(sha256(entropy)[0]);
+
+        let checksum_bits = entropy.len() * 8 / 32;
+        let word_count = (entropy.len() * 8 + checksum_bits) / BITS_PER_WORD;
+
+        let words: Vec<_> = (0..word_count)
+            .map(|word_idx| {
+                let index = (0..BITS_PER_WORD).fold(0_u16, |index, bit_idx| {
+                    let bit = word_idx * BITS_PER_WORD + bit_idx;
+                    let bit_value = (data[bit / 8] >> (7 - bit % 8)) & 1;
+                    (index << 1) | bit_value as u16
+                });
+                language.word(index)
+            })
+            .collect();
+
+        Ok(Mnemonic {
+            language,
+            phrase: Zeroizing::new(words.join(" ")),
+            entropy: Zeroizing::new(entropy.to_vec()),
+        })
+    }
+
+    /// Parses a mnemonic phrase detecting its language automatically.
+    pub fn parse(phrase: &str) -> HDWalletResult<Mnemonic> {
+        let normalized = Zeroizing::new(phrase.nfkd().collect::<String>());
+        let words: Vec<_> = normalized.split_whitespace().collect();
+
+        // Some words are shared by several word lists (e.g. English and French),
+        // so try every language that contains all the words.
+        let mut result = Err(HDWalletError::InvalidMnemonic);
+        for language in Language::candidates(&words) {
+            result = Mnemonic::parse_words(language, &words);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Parses a mnemonic phrase in the given `language`.
+    pub fn parse_in(language: Language, phrase: &str) -> HDWalletResult<Mnemonic> {
+        let normalized = Zeroizing::new(phrase.nfkd().collect::<String>());
+        let words: Vec<_> = normalized.split_whitespace().collect();
+        Mnemonic::parse_words(language, &words)
+    }
+
+    /// Checks whether the given phrase is a valid mnemonic in any of the supported languages.
+    pub fn is_valid(phrase: &str) -> bool {
+        Mnemonic::parse(phrase).is_ok()
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Returns the normalized mnemonic phrase.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// Generates a 64 byte seed using the optional `passphrase`.
+    pub fn to_seed(&self, passphrase: &str) -> Zeroizing<H512> {
+        let passphrase = Zeroizing::new(passphrase.nfkd().collect::<String>());
+        let salt = Zeroizing::new(format!("{SEED_SALT_PREFIX}{}", passphrase.as_str()));
+
+        let seed = Zeroizing::new(pbkdf2_hmac_sha512(
+            self.phrase.as_bytes(),
+            salt.as_bytes(),
+            SEED_PBKDF2_ROUNDS,
+            H512::LEN,
+        ));
+        Zeroizing::new(
+            H512::try_from(seed.as_slice()).expect("PBKDF2 output is expected to be 64 bytes"),
+        )
+    }
+
+    fn parse_words(language: Language, words: &[&str]) -> HDWalletResult<Mnemonic> {
+        if !WORD_COUNTS.contains(&words.len()) {
+            return Err(HDWalletError::InvalidMnemonic);
+        }
+
+        let total_bits = words.len() * BITS_PER_WORD;
+        let checksum_bits = total_bits / 33;
+        let entropy_len = (total_bits - checksum_bits) / 8;
+
+        let mut data = Zeroizing::new(vec![0_u8; total_bits.div_ceil(8)]);
+        for (word_idx, word) in words.iter().enumerate() {
+            let index = language
+                .find_word(word)
+                .ok_or(HDWalletError::InvalidMnemonic)?;
+            for bit_idx in 0..BITS_PER_WORD {
+                if index & (1 << (BITS_PER_WORD - 1 - bit_idx)) != 0 {
+                    let bit = word_idx * BITS_PER_WORD + bit_idx;
+                    data[bit / 8] |= 1 << (7 - bit % 8);
+                }
+            }
+        }
+
+        let entropy = Zeroizing::new(data[..entropy_len].to_vec());
+        let expected_checksum = sha256(&entropy)[0] >> (8 - checksum_bits);
+        let actual_checksum = data[entropy_len] >> (8 - checksum_bits);
+        if expected_checksum != actual_checksum {
+            return Err(HDWalletError::InvalidMnemonicChecksum);
+        }
+
+        Ok(Mnemonic {
+            language,
+            phrase: Zeroizing::new(words.join(" ")),
+            entropy,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Cardano Icarus master key generation and BIP32-Ed25519 (V2) child key derivation.
+//! https://github.com/cardano-foundation/CIPs/blob/master/CIP-0003/Icarus.md
+
+use crate::bip32::split_hmac;
+use crate::{ChildIndex, DerivationPath, HDWalletError, HDWalletResult};
+use curve25519_dalek::{EdwardsPoint, Scalar};
+use tw_hash::hmac::hmac_sha512;
+use tw_hash::pbkdf2::pbkdf2_hmac_sha512;
+use tw_hash::H256;
+use zeroize::{Zeroize, Zeroizing};
+
+/// The number of bytes in the Icarus master secret: `kL || kR || chain code`.
+pub const ICARUS_SECRET_LEN: usize = 96;
+const ICARUS_PBKDF2_ROUNDS: u32 = 4096;
+
+/// Staking key is derived at `m/1852'/1815'/account'/2/0`.
+const STAKING_CHANGE_INDEX: u32 = 2;
+const STAKING_ADDRESS_INDEX: u32 = 0;
+
+/// Derives a 192 byte extended private key following the given `path`
+/// in the format expected by [`tw_keypair::tw::Curve::Ed25519ExtendedCardano`]:
+/// the spending key (`kL || kR || chain code`) followed by the staking key.
+///
+/// The `path` must have `m/purpose'/coin'/account'/change/address` format with `change` being 0 or 1.
+pub fn derive_extended_key(
+    entropy: &[u8],
+    passphrase: &str,
+    path: &DerivationPath,
+) -> HDWalletResult<Zeroizing<Vec<u8>>> {
+    let staking_path = staking_derivation_path(path)?;
+    let master = CardanoNode::icarus_master(entropy, passphrase);
+
+    let spending = master.derive_path(path);
+    let staking = master.derive_path(&staking_path);
+
+    let mut extended_key = spending.to_zeroizing_vec();
+    extended_key.extend_from_slice(&staking.to_zeroizing_vec());
+    Ok(extended_key)
+}
+
+/// Returns the staking key path corresponding to the given spending key `path`.
+fn staking_derivation_path(path: &DerivationPath) -> HDWalletResult<DerivationPath> {
+    let indexes = path.path();
+    match indexes {
+        [purpose, coin, account, change, _address] if change.to_u32() <= 1 => {
+            let with_same_kind = |index: &ChildIndex, value: u32| match index {
+                ChildIndex::Hardened(_) => ChildIndex::Hardened(value),
+                ChildIndex::Normal(_) => ChildIndex::Normal(value),
+            };
+            Ok(DerivationPath::new(vec![
+                *purpose,
+                *coin,
+                *account,
+                with_same_kind(change, STAKING_CHANGE_INDEX),
+                with_same_kind(&indexes[4], STAKING_ADDRESS_INDEX),
+            ]))
+        },
+        _ => Err(HDWalletError::InvalidDerivationPath),
+    }
+}
+
+#[derive(Clone)]
+struct CardanoNode {
+    /// Left half of the extended secret, the actual `ed25519` scalar.
+    kl: H256,
+    /// Right half of the extended secret.
+    kr: H256,
+    chain_code: H256,
+}
+
+impl CardanoNode {
+    /// Generates a master node from the BIP-39 `entropy` using the Icarus scheme.
+    fn icarus_master(entropy: &[u8], passphrase: &str) -> CardanoNode {
+        let mut secret = Zeroizing::new(pbkdf2_hmac_sha512(
+            passphrase.as_bytes(),
+            entropy,
+            ICARUS_PBKDF2_ROUNDS,
+            ICARUS_SECRET_LEN,
+        ));
+
+        secret[0] &= 0b1111_1000;
+        secret[31] &= 0b0001_1111;
+        secret[31] |= 0b0100_0000;
+
+        CardanoNode {
+            kl: H256::try_from(&secret[0..32]).expect("Expected 32 bytes"),
+            kr: H256::try_from(&secret[32..64]).expect("Expected 32 bytes"),
+            chain_code: H256::try_from(&secret[64..96]).expect("Expected 32 bytes"),
+        }
+    }
+
+    fn derive_path(&self, path: &DerivationPath) -> CardanoNode {
+        path.path()
+            .iter()
+            .fold(self.clone(), |node, index| node.derive_child(*index))
+    }
+
+    fn derive_child(&self, index: ChildIndex) -> CardanoNode {
+        let index_bytes = index.to_bits().to_le_bytes();
+
+        let (z_prefix, chain_code_prefix, mut data) = if index.is_hardened() {
+            let mut data = Zeroizing::new(Vec::with_capacity(1 + 64 + 4));
+            data.push(0);
+            data.extend_from_slice(self.kl.as_slice());
+            data.extend_from_slice(self.kr.as_slice());
+            (0x00, 0x01, data)
+        } else {
+            let mut data = Zeroizing::new(Vec::with_capacity(1 + 32 + 4));
+            data.push(0);
+            data.extend_from_slice(&self.public_key());
+            (0x02, 0x03, data)
+        };
+        data.extend_from_slice(&index_bytes);
+
+        data[0] = z_prefix;
+        let z = Zeroizing::new(hmac_sha512(self.chain_code.as_slice(), &data));
+        let (zl, zr) = split_hmac(&z);
+
+        data[0] = chain_code_prefix;
+        let i = Zeroizing::new(hmac_sha512(self.chain_code.as_slice(), &data));
+        let (_, chain_code) = split_hmac(&i);
+
+        CardanoNode {
+            kl: add_28_mul8(&self.kl, &zl),
+            kr: add_256bits(&self.kr, &zr),
+            chain_code: *chain_code,
+        }
+    }
+
+    /// Returns `kL * B` without clamping and hashing the secret.
+    fn public_key(&self) -> [u8; 32] {
+        let scalar = Scalar::from_bytes_mod_order(self.kl.take());
+        EdwardsPoint::mul_base(&scalar).compress().to_bytes()
+    }
+
+    fn to_zeroizing_vec(&self) -> Zeroizing<Vec<u8>> {
+        let mut res = Zeroizing::new(Vec::with_capacity(ICARUS_SECRET_LEN));
+        res.extend_from_slice(self.kl.as_slice());
+        res.extend_from_slice(self.kr.as_slice());
+        res.extend_from_slice(self.chain_code.as_slice());
+        res
+    }
+}
+
+impl Drop for CardanoNode {
+    fn drop(&mut self) {
+        self.kl.zeroize();
+        self.kr.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+/// Returns `x + 8 * y[0..28]`, where both numbers are little-endian.
+fn add_28_mul8(x: &H256, y: &H256) -> H256 {
+    let mut res = H256::default();
+    let mut carry = 0_u16;
+    for i in 0..28 {
+        let r = x[i] as u16 + ((y[i] as u16) << 3) + carry;
+        res[i] = r as u8;
+        carry = r >> 8;
+    }
+    for i in 28..32 {
+        let r = x[i] as u16 + carry;
+        res[i] = r as u8;
+        carry = r >> 8;
+    }
+    res
+}
+
+/// Returns `x + y (mod 2^256)`, where both numbers are little-endian.
+fn add_256bits(x: &H256, y: &H256) -> H256 {
+    let mut res = H256::default();
+    let mut carry = 0_u16;
+    for i in 0..32 {
+        let r = x[i] as u16 + y[i] as u16 + carry;
+        res[i] = r as u8;
+        carry = r >> 8;
+    }
+    res
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+
+/// Registered HD version bytes.
+/// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HDVersion {
+    // Bitcoin
+    Xpub = 0x0488b21e,
+    Xprv = 0x0488ade4,
+    Ypub = 0x049d7cb2,
+    Yprv = 0x049d7878,
+    Zpub = 0x04b24746,
+    Zprv = 0x04b2430c,
+    Vpub = 0x045f1cf6,
+    Vprv = 0x045f18bc,
+    Tpub = 0x043587cf,
+    Tprv = 0x04358394,
+
+    // Litecoin
+    Ltub = 0x019da462,
+    Ltpv = 0x019d9cfe,
+    Mtub = 0x01b26ef6,
+    Mtpv = 0x01b26792,
+    Ttub = 0x0436f6e1,
+    Ttpv = 0x0436ef7d,
+
+    // Decred
+    Dpub = 0x02fda926,
+    Dprv = 0x02fda4e8,
+
+    // Dogecoin
+    Dgub = 0x02facafd,
+    Dgpv = 0x02fac398,
+}
+
+impl HDVersion {
+    const ALL: [HDVersion; 20] = [
+        HDVersion::Xpub,
+        HDVersion::Xprv,
+        HDVersion::Ypub,
+        HDVersion::Yprv,
+        HDVersion::Zpub,
+        HDVersion::Zprv,
+        HDVersion::Vpub,
+        HDVersion::Vprv,
+        HDVersion::Tpub,
+        HDVersion::Tprv,
+        HDVersion::Ltub,
+        HDVersion::Ltpv,
+        HDVersion::Mtub,
+        HDVersion::Mtpv,
+        HDVersion::Ttub,
+        HDVersion::Ttpv,
+        HDVersion::Dpub,
+        HDVersion::Dprv,
+        HDVersion::Dgub,
+        HDVersion::Dgpv,
+    ];
+
+    /// Returns `None` if the given version bytes are not registered.
+    pub fn from_raw(version: u32) -> Option<HDVersion> {
+        HDVersion::ALL
+            .into_iter()
+            .find(|hd_version| *hd_version as u32 == version)
+    }
+
+    /// Whether the version is used to serialize extended public keys.
+    pub fn is_public(&self) -> bool {
+        matches!(
+            self,
+            HDVersion::Xpub
+                | HDVersion::Ypub
+                | HDVersion::Zpub
+                | HDVersion::Vpub
+                | HDVersion::Tpub
+                | HDVersion::Ltub
+                | HDVersion::Mtub
+                | HDVersion::Ttub
+                | HDVersion::Dpub
+                | HDVersion::Dgub
+        )
+    }
+
+    /// Whether the version is used to serialize extended private keys.
+    pub fn is_private(&self) -> bool {
+        !self.is_public()
+    }
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::bip32::{
+    Bip32Curve, Bip32Hashers, ExtendedPrivateKey, ExtendedPublicKey, Nist256p1, Secp256k1,
+};
+use crate::bip39::Mnemonic;
+use crate::hd_version::HDVersion;
+use crate::{cardano, slip10, DerivationPath, HDWalletError, HDWalletResult};
+use tw_hash::H512;
+use tw_keypair::tw::{Curve, PrivateKey, PublicKey, PublicKeyType};
+use zeroize::Zeroizing;
+
+/// Hierarchical deterministic wallet that derives keys from a BIP-39 mnemonic.
+pub struct HDWallet {
+    mnemonic: Mnemonic,
+    passphrase: Zeroizing<String>,
+    seed: Zeroizing<H512>,
+}
+
+impl HDWallet {
+    /// Creates a wallet from the given `mnemonic` phrase in any of the supported languages
+    /// and an optional `passphrase`.
+    pub fn new(mnemonic: &str, passphrase: &str) -> HDWalletResult<HDWallet> {
+        Ok(HDWallet::with_mnemonic(
+            Mnemonic::parse(mnemonic)?,
+            passphrase,
+        ))
+    }
+
+    /// Creates a wallet from the given `entropy` encoded as an English mnemonic.
+    pub fn with_entropy(entropy: &[u8], passphrase: &str) -> HDWalletResult<HDWallet> {
+        Ok(HDWallet::with_mnemonic(
+            Mnemonic::from_entropy(entropy)?,
+            passphrase,
+        ))
+    }
+
+    pub fn with_mnemonic(mnemonic: Mnemonic, passphrase: &str) -> HDWallet {
+        let seed = mnemonic.to_seed(passphrase);
+        HDWallet {
+            mnemonic,
+            passphrase: Zeroizing::new(passphrase.to_string()),
+            seed,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &str {
+        self.mnemonic.phrase()
+    }
+
+    pub fn passphrase(&self) -> &str {
+        &self.passphrase
+    }
+
+    pub fn entropy(&self) -> &[u8] {
+        self.mnemonic.entropy()
+    }
+
+    pub fn seed(&self) -> &H512 {
+        &self.seed
+    }
+
+    /// Returns the master private key of the given elliptic `curve`.
+    pub fn get_master_key(&self, curve: Curve) -> HDWalletResult<PrivateKey> {
+        self.get_key(curve, &DerivationPath::new(Vec::new()))
+    }
+
+    /// Derives a private key of the given elliptic `curve` following the `path`.
+    pub fn get_key(&self, curve: Curve, path: &DerivationPath) -> HDWalletResult<PrivateKey> {
+        let key_bytes = match curve {
+            Curve::Secp256k1 => self.bip32_private_key::<Secp256k1>(path)?,
+            Curve::Nist256p1 => self.bip32_private_key::<Nist256p1>(path)?,
+            Curve::Ed25519 | Curve::Ed25519Blake2bNano => {
+                let key = slip10::derive_private_key(
+                    slip10::ED25519_SEED_KEY,
+                    self.seed.as_slice(),
+                    path,
+                );
+                Zeroizing::new(key.to_vec())
+            },
+            Curve::Curve25519Waves => {
+                let key = slip10::derive_private_key(
+                    slip10::CURVE25519_SEED_KEY,
+                    self.seed.as_slice(),
+                    path,
+                );
+                Zeroizing::new(key.to_vec())
+            },
+            // Cardano keys are derived from the entropy with an empty passphrase
+            // to produce the same keys as the existing wallets do.
+            Curve::Ed25519ExtendedCardano => {
+                cardano::derive_extended_key(self.entropy(), "", path)?
+            },
+            Curve::Starkex | Curve::Schnorr | Curve::Bls12381 => {
+                return Err(HDWalletError::UnsupportedCurve)
+            },
+        };
+        Ok(PrivateKey::new(key_bytes.to_vec())?)
+    }
+
+    /// Derives an extended private key following the `path` and serializes it with `version`.
+    /// Only `secp256k1` and `nist256p1` curves are supported.
+    pub fn get_extended_private_key(
+        &self,
+        curve: Curve,
+        path: &DerivationPath,
+        version: HDVersion,
+        hashers: Bip32Hashers,
+    ) -> HDWalletResult<Zeroizing<String>> {
+        match curve {
+            Curve::Secp256k1 => self
+                .bip32_extended_key::<Secp256k1>(path, hashers)?
+                .to_base58(version),
+            Curve::Nist256p1 => self
+                .bip32_extended_key::<Nist256p1>(path, hashers)?
+                .to_base58(version),
+            _ => Err(HDWalletError::UnsupportedCurve),
+        }
+    }
+
+    /// Derives an extended public key following the `path` and serializes it with `version`.
+    /// Only `secp256k1` and `nist256p1` curves are supported.
+    pub fn get_extended_public_key(
+        &self,
+        curve: Curve,
+        path: &DerivationPath,
+        version: HDVersion,
+        hashers: Bip32Hashers,
+    ) -> HDWalletResult<String> {
+        match curve {
+            Curve::Secp256k1 => self
+                .bip32_extended_key::<Secp256k1>(path, hashers)?
+                .public()
+                .to_base58(version),
+            Curve::Nist256p1 => self
+                .bip32_extended_key::<Nist256p1>(path, hashers)?
+                .public()
+                .to_base58(version),
+            _ => Err(HDWalletError::UnsupportedCurve),
+        }
+    }
+
+    /// Derives a compressed public key from the given `extended` public key (e.g. `zpub...`)
+    /// following the non-hardened `path` relative to the extended key.
+    pub fn get_public_key_from_extended(
+        extended: &str,
+        curve: Curve,
+        path: &DerivationPath,
+        hashers: Bip32Hashers,
+    ) -> HDWalletResult<PublicKey> {
+        fn derive_public<C: Bip32Curve>(
+            extended: &str,
+            path: &DerivationPath,
+            hashers: Bip32Hashers,
+        ) -> HDWalletResult<Vec<u8>> {
+            let child =
+                ExtendedPublicKey::<C>::from_base58(extended, hashers)?.derive_path(path)?;
+            Ok(child.public_key().to_vec())
+        }
+
+        let (public_key, ty) = match curve {
+            Curve::Secp256k1 => (
+                derive_public::<Secp256k1>(extended, path, hashers)?,
+                PublicKeyType::Secp256k1,
+            ),
+            Curve::Nist256p1 => (
+                derive_public::<Nist256p1>(extended, path, hashers)?,
+                PublicKeyType::Nist256p1,
+            ),
+            _ => return Err(HDWalletError::UnsupportedCurve),
+        };
+        Ok(PublicKey::new(public_key, ty)?)
+    }
+
+    fn bip32_extended_key<C: Bip32Curve>(
+        &self,
+        path: &DerivationPath,
+        hashers: Bip32Hashers,
+    ) -> HDWalletResult<ExtendedPrivateKey<C>> {
+        ExtendedPrivateKey::<C>::new_master(self.seed.as_slice())?
+            .with_hashers(hashers)
+            .derive_path(path)
+    }
+
+    fn bip32_private_key<C: Bip32Curve>(
+        &self,
+        path: &DerivationPath,
+    ) -> HDWalletResult<Zeroizing<Vec<u8>>> {
+        let key = self.bip32_extended_key::<C>(path, Bip32Hashers::default())?;
+        Ok(Zeroizing::new(key.private_key().to_vec()))
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! `tw_hd_wallet` crate implements BIP-39 mnemonic phrases and hierarchical deterministic
+//! key derivation for the elliptic curves supported by [`tw_keypair`].
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! use tw_hd_wallet::{DerivationPath, HDWallet};
+//! use tw_keypair::tw::Curve;
+//!
+//! let wallet = HDWallet::new(YOUR_MNEMONIC, "").unwrap();
+//!
+//! let path = DerivationPath::from_str("m/44'/60'/0'/0/0").unwrap();
+//! let private = wallet.get_key(Curve::Secp256k1, &path).unwrap();
+//! ```
+
+use tw_keypair::KeyPairError;
+
+pub mod bip32;
+pub mod bip39;
+pub mod cardano;
+pub mod hd_version;
+pub mod hd_wallet;
+pub mod slip10;
+
+pub use derivation_path::{ChildIndex, DerivationPath};
+pub use hd_version::HDVersion;
+pub use hd_wallet::HDWallet;
+
+pub type HDWalletResult<T> = Result<T, HDWalletError>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HDWalletError {
+    InvalidEntropy,
+    InvalidMnemonic,
+    InvalidMnemonicChecksum,
+    InvalidSeed,
+    InvalidDerivationPath,
+    InvalidChildKey,
+    InvalidExtendedKey,
+    InvalidHDVersion,
+    UnsupportedCurve,
+    KeyPairError(KeyPairError),
+}
+
+impl From<KeyPairError> for HDWalletError {
+    fn from(e: KeyPairError) -> Self {
+        HDWalletError::KeyPairError(e)
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! SLIP-10 private key derivation for the `ed25519` based curves.
+//! https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use crate::bip32::split_hmac;
+use crate::DerivationPath;
+use tw_hash::hmac::hmac_sha512;
+use tw_hash::H256;
+use zeroize::Zeroizing;
+
+/// HMAC key used to generate `ed25519`, `ed25519-blake2b-nano` master keys.
+pub const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+/// HMAC key used to generate `curve25519` master keys.
+pub const CURVE25519_SEED_KEY: &[u8] = b"curve25519 seed";
+
+/// Derives a private key following the given `path`.
+///
+/// Only hardened derivation is defined for `ed25519`.
+/// Non-hardened indexes are skipped to produce the same keys as the existing wallets do.
+pub fn derive_private_key(seed_key: &[u8], seed: &[u8], path: &DerivationPath) -> Zeroizing<H256> {
+    let i = Zeroizing::new(hmac_sha512(seed_key, seed));
+    let (mut private_key, mut chain_code) = split_hmac(&i);
+
+    for index in path.path().iter().filter(|index| index.is_hardened()) {
+        let mut data = Zeroizing::new(Vec::with_capacity(1 + H256::LEN + 4));
+        data.push(0);
+        data.extend_from_slice(private_key.as_slice());
+        data.extend_from_slice(&index.to_bits().to_be_bytes());
+
+        let i = Zeroizing::new(hmac_sha512(chain_code.as_slice(), &data));
+        (private_key, chain_code) = split_hmac(&i);
+    }
+
+    private_key
+}
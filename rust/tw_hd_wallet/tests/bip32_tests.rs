@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::str::FromStr;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hd_wallet::bip32::{
+    Bip32Hashers, ExtendedPrivateKey, ExtendedPublicKey, Nist256p1, Secp256k1,
+};
+use tw_hd_wallet::{slip10, DerivationPath, HDVersion, HDWalletError};
+
+const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+#[test]
+fn test_bip32_secp256k1() {
+    let seed = SEED.decode_hex().unwrap();
+    let master = ExtendedPrivateKey::<Secp256k1>::new_master(&seed).unwrap();
+    assert_eq!(
+        master.to_base58(HDVersion::Xprv).unwrap().as_str(),
+        "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+    );
+    assert_eq!(
+        master.public().to_base58(HDVersion::Xpub).unwrap(),
+        "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"
+    );
+
+    let path = DerivationPath::from_str("m/0'/1/2'/2/1000000000").unwrap();
+    let child = master.derive_path(&path).unwrap();
+    assert_eq!(child.depth(), 5);
+    assert_eq!(
+        child.to_base58(HDVersion::Xprv).unwrap().as_str(),
+        "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76"
+    );
+    assert_eq!(
+        child.public().to_base58(HDVersion::Xpub).unwrap(),
+        "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy"
+    );
+}
+
+#[test]
+fn test_bip32_public_derivation() {
+    let seed = SEED.decode_hex().unwrap();
+    let account = ExtendedPrivateKey::<Secp256k1>::new_master(&seed)
+        .unwrap()
+        .derive_path(&DerivationPath::from_str("m/0'/1/2'").unwrap())
+        .unwrap();
+    let xpub = account.public().to_base58(HDVersion::Xpub).unwrap();
+
+    let relative_path = DerivationPath::from_str("m/2/1000000000").unwrap();
+    let from_private = account.derive_path(&relative_path).unwrap();
+    let from_public = ExtendedPublicKey::<Secp256k1>::from_base58(&xpub, Bip32Hashers::default())
+        .unwrap()
+        .derive_path(&relative_path)
+        .unwrap();
+    assert_eq!(from_public.public_key(), &from_private.public_key());
+    assert_eq!(
+        from_public.to_base58(HDVersion::Xpub).unwrap(),
+        "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy"
+    );
+
+    // Hardened keys cannot be derived from an extended public key.
+    let hardened_path = DerivationPath::from_str("m/2'").unwrap();
+    assert_eq!(
+        ExtendedPublicKey::<Secp256k1>::from_base58(&xpub, Bip32Hashers::default())
+            .unwrap()
+            .derive_path(&hardened_path)
+            .err(),
+        Some(HDWalletError::InvalidDerivationPath)
+    );
+}
+
+#[test]
+fn test_bip32_extended_key_roundtrip() {
+    let xprv = "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76";
+    let key = ExtendedPrivateKey::<Secp256k1>::from_base58(xprv, Bip32Hashers::default()).unwrap();
+    assert_eq!(key.depth(), 5);
+    assert_eq!(key.child_number(), 1000000000);
+    assert_eq!(key.to_base58(HDVersion::Xprv).unwrap().as_str(), xprv);
+
+    // Wrong version kind.
+    assert_eq!(
+        key.to_base58(HDVersion::Xpub).err(),
+        Some(HDWalletError::InvalidHDVersion)
+    );
+    assert_eq!(
+        ExtendedPublicKey::<Secp256k1>::from_base58(xprv, Bip32Hashers::default()).err(),
+        Some(HDWalletError::InvalidExtendedKey)
+    );
+    // Invalid checksum.
+    let invalid = xprv.replace("Du76", "Du77");
+    assert_eq!(
+        ExtendedPrivateKey::<Secp256k1>::from_base58(&invalid, Bip32Hashers::default()).err(),
+        Some(HDWalletError::InvalidExtendedKey)
+    );
+}
+
+#[test]
+fn test_slip10_nist256p1() {
+    let seed = SEED.decode_hex().unwrap();
+    let master = ExtendedPrivateKey::<Nist256p1>::new_master(&seed).unwrap();
+    assert_eq!(
+        master.private_key().to_hex(),
+        "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2"
+    );
+
+    let path = DerivationPath::from_str("m/0'/1/2'/2/1000000000").unwrap();
+    let child = master.derive_path(&path).unwrap();
+    assert_eq!(
+        child.private_key().to_hex(),
+        "21c4f269ef0a5fd1badf47eeacebeeaa3de22eb8e5b0adcd0f27dd99d34d0119"
+    );
+}
+
+#[test]
+fn test_slip10_ed25519() {
+    let seed = SEED.decode_hex().unwrap();
+
+    let master = slip10::derive_private_key(
+        slip10::ED25519_SEED_KEY,
+        &seed,
+        &DerivationPath::from_str("m").unwrap(),
+    );
+    assert_eq!(
+        master.to_hex(),
+        "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+    );
+
+    let path = DerivationPath::from_str("m/0'/1'/2'/2'/1000000000'").unwrap();
+    let child = slip10::derive_private_key(slip10::ED25519_SEED_KEY, &seed, &path);
+    assert_eq!(
+        child.to_hex(),
+        "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"
+    );
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hd_wallet::bip32::{ExtendedPrivateKey, Secp256k1};
+use tw_hd_wallet::bip39::{Language, Mnemonic};
+use tw_hd_wallet::{HDVersion, HDWalletError};
+
+const BIP39_VECTORS: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../tests/common/HDWallet/bip39_vectors.json"
+));
+const VECTORS_PASSPHRASE: &str = "TREZOR";
+
+#[derive(Deserialize)]
+struct Bip39Vectors {
+    english: Vec<(String, String, String, String)>,
+}
+
+#[test]
+fn test_bip39_vectors() {
+    let vectors: Bip39Vectors = serde_json::from_str(BIP39_VECTORS).unwrap();
+
+    for (entropy, phrase, seed, xprv) in vectors.english {
+        let entropy = entropy.decode_hex().unwrap();
+
+        let mnemonic = Mnemonic::from_entropy(&entropy).unwrap();
+        assert_eq!(mnemonic.phrase(), phrase);
+
+        let mnemonic = Mnemonic::parse(&phrase).unwrap();
+        assert_eq!(mnemonic.language(), Language::English);
+        assert_eq!(mnemonic.entropy(), entropy);
+
+        let actual_seed = mnemonic.to_seed(VECTORS_PASSPHRASE);
+        assert_eq!(actual_seed.to_hex(), seed, "{phrase}");
+
+        let master = ExtendedPrivateKey::<Secp256k1>::new_master(actual_seed.as_slice()).unwrap();
+        assert_eq!(master.to_base58(HDVersion::Xprv).unwrap().as_str(), xprv);
+    }
+}
+
+#[test]
+fn test_mnemonic_spanish() {
+    let phrase = "llanto radical atraer riesgo actuar masa fondo cielo dieta archivo sonrisa mamut";
+
+    let mnemonic = Mnemonic::parse(phrase).unwrap();
+    assert_eq!(mnemonic.language(), Language::Spanish);
+    assert_eq!(mnemonic.phrase(), phrase);
+    assert_eq!(
+        mnemonic.entropy().to_hex(),
+        "81d8185b64f0371457198b43c22b6c43"
+    );
+    assert_eq!(mnemonic.to_seed("").to_hex(), "ec8f8703432fc7d32e699ee056e9d84b1435e6a64a6a40ad63dbde11eab189a276ddcec20f3326d3c6ee39cbd018585b104fc3633b801c011063ae4c318fb9b6");
+
+    // The same phrase is not valid in English.
+    assert_eq!(
+        Mnemonic::parse_in(Language::English, phrase).err(),
+        Some(HDWalletError::InvalidMnemonic)
+    );
+}
+
+#[test]
+fn test_mnemonic_japanese_roundtrip() {
+    let entropy = [0_u8; 16];
+
+    let mnemonic = Mnemonic::from_entropy_in(Language::Japanese, &entropy).unwrap();
+    let expected_last_word = Language::Japanese.word(3);
+    assert!(mnemonic.phrase().ends_with(expected_last_word));
+
+    // Japanese phrases are usually separated with the ideographic space.
+    let ideographic = mnemonic.phrase().replace(' ', "\u{3000}");
+    let parsed = Mnemonic::parse(&ideographic).unwrap();
+    assert_eq!(parsed.language(), Language::Japanese);
+    assert_eq!(parsed.entropy(), entropy);
+    assert_eq!(parsed.phrase(), mnemonic.phrase());
+}
+
+#[test]
+fn test_mnemonic_normalized() {
+    let phrase = "  ripple scissors kick mammal hire column oak again sun\toffer wealth tomorrow wagon turn fatal ";
+    let mnemonic = Mnemonic::parse(phrase).unwrap();
+    assert_eq!(
+        mnemonic.phrase(),
+        "ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatal"
+    );
+    assert_eq!(
+        mnemonic.entropy().to_hex(),
+        "ba5821e8c356c05ba5f025d9532fe0f21f65d594"
+    );
+}
+
+#[test]
+fn test_mnemonic_invalid() {
+    // Invalid checksum.
+    assert_eq!(
+        Mnemonic::parse("ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn turn").err(),
+        Some(HDWalletError::InvalidMnemonicChecksum)
+    );
+    // Unknown word.
+    assert_eq!(
+        Mnemonic::parse("ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatall").err(),
+        Some(HDWalletError::InvalidMnemonic)
+    );
+    // Invalid number of words.
+    assert_eq!(
+        Mnemonic::parse("ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn").err(),
+        Some(HDWalletError::InvalidMnemonic)
+    );
+    assert_eq!(
+        Mnemonic::parse("THIS IS AN INVALID MNEMONIC").err(),
+        Some(HDWalletError::InvalidMnemonic)
+    );
+    assert!(!Mnemonic::is_valid(""));
+}
+
+#[test]
+fn test_mnemonic_invalid_entropy() {
+    for len in [0, 12, 15, 17, 36] {
+        assert_eq!(
+            Mnemonic::from_entropy(&vec![1; len]).err(),
+            Some(HDWalletError::InvalidEntropy)
+        );
+    }
+}
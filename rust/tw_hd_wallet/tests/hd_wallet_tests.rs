@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::str::FromStr;
+use tw_encoding::hex::ToHex;
+use tw_hd_wallet::bip32::Bip32Hashers;
+use tw_hd_wallet::{DerivationPath, HDVersion, HDWallet, HDWalletError};
+use tw_keypair::tw::Curve;
+
+const MNEMONIC: &str =
+    "ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatal";
+const PASSPHRASE: &str = "passphrase";
+
+fn path(s: &str) -> DerivationPath {
+    DerivationPath::from_str(s).unwrap()
+}
+
+#[test]
+fn test_hd_wallet_seed() {
+    let wallet = HDWallet::new(MNEMONIC, "").unwrap();
+    assert_eq!(wallet.mnemonic(), MNEMONIC);
+    assert_eq!(
+        wallet.entropy().to_hex(),
+        "ba5821e8c356c05ba5f025d9532fe0f21f65d594"
+    );
+    assert_eq!(wallet.seed().to_hex(), "354c22aedb9a37407adc61f657a6f00d10ed125efa360215f36c6919abd94d6dbc193a5f9c495e21ee74118661e327e84a5f5f11fa373ec33b80897d4697557d");
+
+    let wallet = HDWallet::new(MNEMONIC, PASSPHRASE).unwrap();
+    assert_eq!(wallet.passphrase(), PASSPHRASE);
+    assert_eq!(wallet.seed().to_hex(), "143cd5fc27ae46eb423efebc41610473f5e24a80f2ca2e2fa7bf167e537f58f4c68310ae487fce82e25bad29bab2530cf77fd724a5ebfc05a45872773d7ee2d6");
+
+    let from_entropy = HDWallet::with_entropy(wallet.entropy(), PASSPHRASE).unwrap();
+    assert_eq!(from_entropy.mnemonic(), MNEMONIC);
+    assert_eq!(from_entropy.seed(), wallet.seed());
+}
+
+#[test]
+fn test_hd_wallet_invalid_mnemonic() {
+    assert_eq!(
+        HDWallet::new("THIS IS AN INVALID MNEMONIC", PASSPHRASE).err(),
+        Some(HDWalletError::InvalidMnemonic)
+    );
+}
+
+#[test]
+fn test_hd_wallet_get_key_by_curve() {
+    let wallet = HDWallet::new(MNEMONIC, "").unwrap();
+    let derivation_path = path("m/44'/539'/0'/0/0");
+
+    let secp256k1 = wallet.get_key(Curve::Secp256k1, &derivation_path).unwrap();
+    assert_eq!(
+        secp256k1.bytes().to_hex(),
+        "4fb8657d6464adcaa086d6758d7f0b6b6fc026c98dc1671fcc6460b5a74abc62"
+    );
+
+    let nist256p1 = wallet.get_key(Curve::Nist256p1, &derivation_path).unwrap();
+    assert_eq!(
+        nist256p1.bytes().to_hex(),
+        "a13df52d5a5b438bbf921bbf86276e4347fe8e2f2ed74feaaee12b77d6d26f86"
+    );
+}
+
+#[test]
+fn test_hd_wallet_get_key_ed25519() {
+    let wallet = HDWallet::new(MNEMONIC, "").unwrap();
+
+    let aptos = wallet
+        .get_key(Curve::Ed25519, &path("m/44'/637'/0'/0'/0'"))
+        .unwrap();
+    assert_eq!(
+        aptos.bytes().to_hex(),
+        "7f2634c0e2414a621e96e39c41d09021700cee12ee43328ed094c5580cd0bd6f"
+    );
+
+    // Non-hardened indexes are skipped.
+    let skipped = wallet
+        .get_key(Curve::Ed25519, &path("m/44'/637'/0'/0'/1/0'/2"))
+        .unwrap();
+    assert_eq!(skipped.bytes(), aptos.bytes());
+}
+
+#[test]
+fn test_hd_wallet_get_key_cardano() {
+    let wallet = HDWallet::new("cost dash dress stove morning robust group affair stomach vacant route volume yellow salute laugh", "").unwrap();
+
+    let key = wallet
+        .get_key(Curve::Ed25519ExtendedCardano, &path("m/1852'/1815'/0'/0/0"))
+        .unwrap();
+    assert_eq!(
+        key.bytes().to_hex(),
+        concat!(
+            "e8c8c5b2df13f3abed4e6b1609c808e08ff959d7e6fc3d849e3f2880550b5744",
+            "37aa559095324d78459b9bb2da069da32337e1cc5da78f48e1bd084670107f31",
+            "10f3245ddf9132ecef98c670272ef39c03a232107733d4a1d28cb53318df26fa",
+            "e0d152bb611cb9ff34e945e4ff627e6fba81da687a601a879759cd76530b5744",
+            "424db69a75edd4780a5fbc05d1a3c84ac4166ff8e424808481dd8e77627ce5f5",
+            "bf2eea84515a4e16c4ff06c92381822d910b5cbf9e9c144e1fb76a6291af7276",
+        )
+    );
+
+    // Cardano keys do not depend on the passphrase.
+    let wallet = HDWallet::new(MNEMONIC, "TREZOR").unwrap();
+    let key = wallet
+        .get_key(Curve::Ed25519ExtendedCardano, &path("m/1852'/1815'/0'/0/0"))
+        .unwrap();
+    assert_eq!(key.bytes().to_hex(), "f8a3b8ad30e62c369b939336c2035aba26d1ffad135e6f346f2a370517a14952e73d20aeadf906bc8b531900fb6c3ed4a05b16973c10ae24650b68b26fae4ee5d97418ba7f3b2707fae963041ff5f174195d1578da09478ad2d17a1ecc00cad478a8ca3be214870accd41f008d70e3b4b59b5981ca933d6d3f389ad317a14952166d8fd329ae3fab4712da739efc2ded9b3eef2b1a8e225dd3dddeb4f065a729b297d9fa76b8852eef235c25aac8f0ff6209ab7251f2a84c83b3b5f1161f7c59");
+
+    // Staking key cannot be derived from this path.
+    assert_eq!(
+        wallet
+            .get_key(Curve::Ed25519ExtendedCardano, &path("m/1852'/1815'/0'/2/0"))
+            .err(),
+        Some(HDWalletError::InvalidDerivationPath)
+    );
+}
+
+#[test]
+fn test_hd_wallet_extended_keys() {
+    let wallet = HDWallet::new(MNEMONIC, "").unwrap();
+    let hashers = Bip32Hashers::default();
+
+    let zprv = wallet
+        .get_extended_private_key(
+            Curve::Secp256k1,
+            &path("m/44'/0'/0'"),
+            HDVersion::Zprv,
+            hashers,
+        )
+        .unwrap();
+    assert_eq!(zprv.as_str(), "zprvAcwsTZNaY1f7rfwsy5GseSDStYBrxwtsBZDkb3iyuQUs4NF6n58BuH7Xj54RuaSCWtU5CiQzuYQgFgqr1HokgKcVAeGeXokhJUAJeP3VmvY");
+
+    let zprv_account_1 = wallet
+        .get_extended_private_key(
+            Curve::Secp256k1,
+            &path("m/44'/0'/1'"),
+            HDVersion::Zprv,
+            hashers,
+        )
+        .unwrap();
+    assert_eq!(zprv_account_1.as_str(), "zprvAcwsTZNaY1f7sifgNNgdNa4P9mPtyg3zRVgwkx2qF9Sn7F255MzP6Zyumn6bgV5xuoS8ZrDvjzE7APcFSacXdzFYpGvyybb1bnAoh5nHxpn");
+
+    let zpub = wallet
+        .get_extended_public_key(
+            Curve::Secp256k1,
+            &path("m/44'/0'/0'"),
+            HDVersion::Zpub,
+            hashers,
+        )
+        .unwrap();
+    assert_eq!(zpub, "zpub6qwDs4uUNPDR5A2M56ot1aABSa2MNQciYn9MPS8bTk1qwAaFKcSST5S1aLidvPp9twqpaumG7vikR2vHhBXjp5oGgHyMvWK3AtUkfeEgyns");
+
+    assert_eq!(
+        wallet
+            .get_extended_public_key(
+                Curve::Ed25519,
+                &path("m/44'/0'/0'"),
+                HDVersion::Zpub,
+                hashers
+            )
+            .err(),
+        Some(HDWalletError::UnsupportedCurve)
+    );
+}
+
+#[test]
+fn test_hd_wallet_derive_xpub_vs_private() {
+    let wallet = HDWallet::new(MNEMONIC, "").unwrap();
+    let hashers = Bip32Hashers::default();
+
+    let zpub = wallet
+        .get_extended_public_key(
+            Curve::Secp256k1,
+            &path("m/84'/0'/0'"),
+            HDVersion::Zpub,
+            hashers,
+        )
+        .unwrap();
+    assert_eq!(zpub, "zpub6rNUNtxSa9Gxvm4Bdxf1MPMwrvkzwDx6vP96Hkzw3jiQKdg3fhXBStxjn12YixQB8h88B3RMSRscRstf9AEVaYr3MAqVBEWBDuEJU4PGaT9");
+
+    for (address_index, expected) in [
+        (
+            0,
+            "02df9ef2a7a5552765178b181e1e1afdefc7849985c7dfe9647706dd4fa40df6ac",
+        ),
+        (
+            2,
+            "031e1f64d2f6768dccb6814545b2e2d58e26ad5f91b7cbaffe881ed572c65060db",
+        ),
+    ] {
+        let from_private = wallet
+            .get_key(
+                Curve::Secp256k1,
+                &path(&format!("m/84'/0'/0'/0/{address_index}")),
+            )
+            .unwrap()
+            .get_public_key_by_type(tw_keypair::tw::PublicKeyType::Secp256k1)
+            .unwrap();
+        assert_eq!(from_private.to_bytes().to_hex(), expected);
+
+        let from_extended = HDWallet::get_public_key_from_extended(
+            &zpub,
+            Curve::Secp256k1,
+            &path(&format!("m/0/{address_index}")),
+            hashers,
+        )
+        .unwrap();
+        assert_eq!(from_extended.to_bytes().to_hex(), expected);
+    }
+}
@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Anti-exfil (sign-to-contract) protocol that prevents a signer from leaking its secret
+//! through biased nonces.
+//!
+//! The ECDSA variant is compatible with the `ecdsa_s2c` module of `libsecp256k1-zkp`.
+//! `libsecp256k1-zkp` has no Schnorr sign-to-contract, so the Schnorr variant is non-standard:
+//! it follows the same steps with BIP-340 nonces and the `s2c/schnorr/*` tags,
+//! and is not interoperable with other implementations.
+//!
+//! 1. The host generates random `host_data` and sends its commitment
+//!    (see [`ecdsa_host_commit`] or [`schnorr_host_commit`]) to the signer.
+//! 2. The signer derives a nonce from the message, its secret and the host commitment,
+//!    and returns the nonce commitment `R0 = k0×G` to the host.
+//! 3. The host reveals `host_data`.
+//! 4. The signer signs the message with the nonce `k0 + H(R0 || host_data)`.
+//! 5. The host checks that the signature is valid and uses the expected nonce
+//!    (see [`ecdsa_verify`] or [`schnorr_verify`]).
+
+use crate::ecdsa::secp256k1;
+use crate::schnorr::{self, tagged_hash};
+use crate::traits::VerifyingKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar, U256};
+use tw_hash::{H256, H264};
+
+/// The compressed nonce point `R = k×G` that a signer commits to.
+pub type NonceCommitment = H264;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Protocol {
+    Ecdsa,
+    Schnorr,
+}
+
+impl Protocol {
+    fn data_tag(self) -> &'static [u8] {
+        match self {
+            Protocol::Ecdsa => b"s2c/ecdsa/data",
+            Protocol::Schnorr => b"s2c/schnorr/data",
+        }
+    }
+
+    fn point_tag(self) -> &'static [u8] {
+        match self {
+            Protocol::Ecdsa => b"s2c/ecdsa/point",
+            Protocol::Schnorr => b"s2c/schnorr/point",
+        }
+    }
+}
+
+/// Returns the commitment to `host_data` that the host sends to an ECDSA signer.
+pub fn ecdsa_host_commit(host_data: &H256) -> H256 {
+    tagged_hash(Protocol::Ecdsa.data_tag(), host_data.as_slice())
+}
+
+/// Returns the commitment to `host_data` that the host sends to a Schnorr signer.
+pub fn schnorr_host_commit(host_data: &H256) -> H256 {
+    tagged_hash(Protocol::Schnorr.data_tag(), host_data.as_slice())
+}
+
+/// Checks that the ECDSA `signature` of the `message` is valid, and that its nonce is
+/// the `nonce_commitment` tweaked with `host_data`.
+pub fn ecdsa_verify(
+    public: &secp256k1::PublicKey,
+    message: H256,
+    signature: &secp256k1::Signature,
+    nonce_commitment: &NonceCommitment,
+    host_data: &H256,
+) -> bool {
+    let Ok(nonce_point) = tweak_nonce_point(Protocol::Ecdsa, nonce_commitment, host_data) else {
+        return false;
+    };
+    // `r` is the x-coordinate of the nonce point reduced modulo the curve order.
+    let expected_r = <Scalar as Reduce<U256>>::reduce_bytes(&nonce_point.x());
+    if expected_r.to_bytes().as_slice() != signature.r().as_slice() {
+        return false;
+    }
+    public.verify(signature.to_verify_sig(), message)
+}
+
+/// Checks that the BIP-340 `signature` of the `message` is valid, and that its nonce is
+/// the `nonce_commitment` tweaked with `host_data`.
+pub fn schnorr_verify(
+    public: &schnorr::PublicKey,
+    message: H256,
+    signature: &schnorr::Signature,
+    nonce_commitment: &NonceCommitment,
+    host_data: &H256,
+) -> bool {
+    let Ok(nonce_point) = tweak_nonce_point(Protocol::Schnorr, nonce_commitment, host_data) else {
+        return false;
+    };
+    // The signature commits to the x-coordinate of the nonce point only.
+    if nonce_point.x().as_slice() != &signature.bytes()[..32] {
+        return false;
+    }
+    public.verify(schnorr::Signature::from(signature.signature), message)
+}
+
+/// Returns the scalar `H(R0 || host_data)` the committed nonce is tweaked with.
+pub(crate) fn nonce_tweak(
+    protocol: Protocol,
+    nonce_commitment: &AffinePoint,
+    host_data: &H256,
+) -> KeyPairResult<Scalar> {
+    let mut data = nonce_commitment.to_encoded_point(true).as_bytes().to_vec();
+    data.extend_from_slice(host_data.as_slice());
+
+    let tweak = tagged_hash(protocol.point_tag(), &data);
+    Option::from(Scalar::from_repr(tweak.take().into())).ok_or(KeyPairError::SigningError)
+}
+
+/// Returns the compressed representation of the given nonce point.
+pub(crate) fn nonce_commitment(point: &AffinePoint) -> NonceCommitment {
+    NonceCommitment::try_from(point.to_encoded_point(true).as_bytes())
+        .expect("Expected 33 byte array compressed point")
+}
+
+fn tweak_nonce_point(
+    protocol: Protocol,
+    nonce_commitment: &NonceCommitment,
+    host_data: &H256,
+) -> KeyPairResult<AffinePoint> {
+    let encoded = EncodedPoint::from_bytes(nonce_commitment.as_slice())
+        .map_err(|_| KeyPairError::InvalidPublicKey)?;
+    let committed: AffinePoint = Option::from(AffinePoint::from_encoded_point(&encoded))
+        .ok_or(KeyPairError::InvalidPublicKey)?;
+
+    let tweak = nonce_tweak(protocol, &committed, host_data)?;
+    let point = (ProjectivePoint::from(committed) + ProjectivePoint::GENERATOR * tweak).to_affine();
+    if bool::from(point.is_identity()) {
+        return Err(KeyPairError::InvalidPublicKey);
+    }
+    Ok(point)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_hash::sha3::keccak256;
+
+    const ECDSA_SECRET: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+    const SCHNORR_SECRET: &str = "ba0828d5734b65e3bcc2c51c93dfc26dd71bd666cc0273adee77d73d9a322035";
+    const HOST_DATA: &str = "0202020202020202020202020202020202020202020202020202020202020202";
+
+    fn message() -> H256 {
+        H256::try_from(keccak256(b"hello").as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_ecdsa_anti_exfil() {
+        let private = secp256k1::PrivateKey::try_from(ECDSA_SECRET).unwrap();
+        let host_data = H256::from(HOST_DATA);
+
+        let host_commitment = ecdsa_host_commit(&host_data);
+        assert_eq!(
+            host_commitment,
+            H256::from("a61382543ecc57de38f56f52da8ef0820d1dc33c419013487096d69107dc8140")
+        );
+
+        let nonce_commitment = private
+            .anti_exfil_commit(message(), host_commitment)
+            .unwrap();
+        assert_eq!(
+            nonce_commitment,
+            NonceCommitment::from(
+                "0257f5cbecfe5b9e8334b7c1fc77ee9e10d8ac1f1d21bacc4d985c4f08c409be77"
+            )
+        );
+
+        let signature = private.anti_exfil_sign(message(), host_data).unwrap();
+        assert_eq!(
+            signature.to_bytes(),
+            tw_hash::H520::from("68e99a175a3c2392f124906afb8a13d43d1c8890dd9245f1d3f78ccba91aa940033d0664521d470b0d89a697d8656e87c2e41cdaf801d08fc4fc3968299e8bd001")
+        );
+
+        let public = private.public();
+        assert!(ecdsa_verify(
+            &public,
+            message(),
+            &signature,
+            &nonce_commitment,
+            &host_data
+        ));
+
+        // The signer didn't use the host data.
+        let other_data =
+            H256::from("0303030303030303030303030303030303030303030303030303030303030303");
+        assert!(!ecdsa_verify(
+            &public,
+            message(),
+            &signature,
+            &nonce_commitment,
+            &other_data
+        ));
+        // The regular signature doesn't match the commitment.
+        let (regular, _) = private.sign_with_nonce_data(message(), None).unwrap();
+        assert!(!ecdsa_verify(
+            &public,
+            message(),
+            &regular,
+            &nonce_commitment,
+            &host_data
+        ));
+    }
+
+    /// `test_ecdsa_s2c_fixed_vectors` of `libsecp256k1-zkp`.
+    /// https://github.com/BlockstreamResearch/secp256k1-zkp/blob/master/src/modules/ecdsa_s2c/tests_impl.h
+    #[test]
+    fn test_ecdsa_anti_exfil_zkp_vectors() {
+        let private = secp256k1::PrivateKey::try_from([0x55; 32].as_slice()).unwrap();
+        let message = H256::from([0x88; 32]);
+
+        let s2c_data =
+            H256::from("1bf6fb42f41eb876c4d7aa0d67242b00baab99dc2084493e4e63277fa1f77f22");
+        // `secp256k1_ecdsa_s2c_sign` derives the nonce from the hashed `s2c_data`.
+        let s2c_opening = private
+            .anti_exfil_commit(message, ecdsa_host_commit(&s2c_data))
+            .unwrap();
+        assert_eq!(
+            s2c_opening,
+            NonceCommitment::from(
+                "03f030def3188c0f56fcea87435b307643f45dafe22cbc82fd56034fae97417d3a"
+            )
+        );
+        let signature = private.anti_exfil_sign(message, s2c_data).unwrap();
+        assert!(ecdsa_verify(
+            &private.public(),
+            message,
+            &signature,
+            &s2c_opening,
+            &s2c_data
+        ));
+        // `secp256k1_ecdsa_anti_exfil_signer_commit` takes `s2c_data` as a host commitment.
+        assert_eq!(
+            private.anti_exfil_commit(message, s2c_data).unwrap(),
+            NonceCommitment::from(
+                "02df63755d1f3292bffed82986b106497c93b1f8bdc0454b6b0b0a4779c0ef7188"
+            )
+        );
+
+        let s2c_data =
+            H256::from("35199a8fbf84ad6ef69a184c1b19285befbe06e60b6264e6d373893f6855e24a");
+        assert_eq!(
+            private
+                .anti_exfil_commit(message, ecdsa_host_commit(&s2c_data))
+                .unwrap(),
+            NonceCommitment::from(
+                "03901717ce7c7484a2ce1b7dc7403b14e0354971393ec092a7f3e0c8e4e2d2639d"
+            )
+        );
+    }
+
+    #[test]
+    fn test_schnorr_anti_exfil() {
+        let private = schnorr::PrivateKey::try_from(SCHNORR_SECRET).unwrap();
+        let host_data = H256::from(HOST_DATA);
+
+        let host_commitment = schnorr_host_commit(&host_data);
+        assert_eq!(
+            host_commitment,
+            H256::from("eb7633b9577260249333e0782d393ab17b2fdc069ea31b995e542295935f2216")
+        );
+
+        let nonce_commitment = private
+            .anti_exfil_commit(message(), host_commitment)
+            .unwrap();
+        assert_eq!(
+            nonce_commitment,
+            NonceCommitment::from(
+                "02b80425eef5d43a4e5f9198fa934d853d9c76ed42cc5157c8cb1d46de975529ff"
+            )
+        );
+
+        let signature = private.anti_exfil_sign(message(), host_data).unwrap();
+        assert_eq!(
+            signature.bytes(),
+            tw_hash::H512::from("bd49869eac432988760ec0c5befe5517903d8cf78e12697d61d9efea3f0ce77dff4172ebe8357febc4df24761a9c94f6ccd868763e1730bc5aea22192e487d35")
+        );
+
+        let public = private.public();
+        assert!(schnorr_verify(
+            &public,
+            message(),
+            &signature,
+            &nonce_commitment,
+            &host_data
+        ));
+
+        let other_data =
+            H256::from("0303030303030303030303030303030303030303030303030303030303030303");
+        assert!(!schnorr_verify(
+            &public,
+            message(),
+            &signature,
+            &nonce_commitment,
+            &other_data
+        ));
+    }
+
+    #[test]
+    fn test_schnorr_anti_exfil_tweaked() {
+        let tweak = H256::try_from(keccak256(b"tweak").as_slice()).unwrap();
+        let private = schnorr::PrivateKey::try_from(SCHNORR_SECRET)
+            .unwrap()
+            .tweak(Some(tweak));
+        let host_data = H256::from(HOST_DATA);
+
+        let nonce_commitment = private
+            .anti_exfil_commit(message(), schnorr_host_commit(&host_data))
+            .unwrap();
+        let signature = private.anti_exfil_sign(message(), host_data).unwrap();
+        assert!(schnorr_verify(
+            &private.public(),
+            message(),
+            &signature,
+            &nonce_commitment,
+            &host_data
+        ));
+    }
+}
@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! `BLS12-381` signatures of the minimal-pubkey-size variant:
+//! public keys are G1 points (48 bytes), and signatures are G2 points (96 bytes).
+
+mod keypair;
+mod private;
+mod public;
+mod signature;
+
+pub use keypair::KeyPair;
+pub use private::PrivateKey;
+pub use public::PublicKey;
+pub use signature::Signature;
+
+/// Domain separation tag of the basic scheme, used by Filecoin.
+pub const DST_BASIC: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+/// Domain separation tag of the proof-of-possession scheme, used by Ethereum consensus layer.
+pub const DST_POP: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Domain separation tag of the proofs of possession.
+pub const DST_POP_PROOF: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// BLS signature scheme, which determines the domain separation tag messages are signed with.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Scheme {
+    /// Messages must be distinct to aggregate signatures safely.
+    Basic,
+    /// Public keys must be proven to aggregate signatures safely.
+    #[default]
+    ProofOfPossession,
+}
+
+impl Scheme {
+    pub fn dst(self) -> &'static [u8] {
+        match self {
+            Scheme::Basic => DST_BASIC,
+            Scheme::ProofOfPossession => DST_POP,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+    use tw_encoding::hex::{DecodeHex, ToHex};
+    use tw_misc::traits::ToBytesVec;
+
+    const SECRET_1: &str = "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3";
+    const SECRET_2: &str = "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138";
+
+    #[test]
+    fn test_public_key() {
+        let key_pair = KeyPair::try_from(SECRET_1).unwrap();
+        assert_eq!(
+            key_pair.public().to_vec().to_hex(),
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a"
+        );
+
+        // The public key of `1` is the G1 generator.
+        let one = PrivateKey::try_from(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )
+        .unwrap();
+        assert_eq!(
+            one.public().to_vec().to_hex(),
+            "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+        );
+    }
+
+    /// Test vectors from the Ethereum consensus specs.
+    #[test]
+    fn test_sign_verify() {
+        let tests = [
+            (
+                SECRET_1,
+                "5656565656565656565656565656565656565656565656565656565656565656",
+                "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb",
+            ),
+            (
+                SECRET_2,
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "b23c46be3a001c63ca711f87a005c200cc550b9429d5f4eb38d74322144f1b63926da3388979e5321012fb1a0526bcd100b5ef5fe72628ce4cd5e904aeaa3279527843fae5ca9ca675f4f51ed8f83bbf7155da9ecc9663100a885d5dc6df96d9",
+            ),
+        ];
+
+        for (secret, message, expected) in tests {
+            let key_pair = KeyPair::try_from(secret).unwrap();
+            let message = message.decode_hex().unwrap();
+
+            let signature = key_pair.sign(message.clone()).unwrap();
+            assert_eq!(signature.to_vec().to_hex(), expected);
+            assert!(key_pair.verify(signature, message));
+        }
+    }
+
+    #[test]
+    fn test_verify_invalid() {
+        let key_pair = KeyPair::try_from(SECRET_2).unwrap();
+        let message = b"Hello, BLS".to_vec();
+        let signature = key_pair.sign(message.clone()).unwrap();
+
+        let other = KeyPair::try_from(SECRET_1).unwrap();
+        assert!(!other.verify(signature.clone(), message));
+        assert!(!key_pair.verify(signature, b"Hello, BLS!".to_vec()));
+    }
+
+    #[test]
+    fn test_sign_basic_scheme() {
+        let message = b"Hello, BLS".to_vec();
+        let key_pair = KeyPair::try_from(SECRET_1)
+            .unwrap()
+            .with_scheme(Scheme::Basic);
+
+        let signature = key_pair.sign(message.clone()).unwrap();
+        assert!(key_pair.verify(signature.clone(), message.clone()));
+
+        // The signature is not valid according to the proof-of-possession scheme.
+        let pop_public = key_pair
+            .public()
+            .clone()
+            .with_scheme(Scheme::ProofOfPossession);
+        assert!(!pop_public.verify(signature, message));
+    }
+
+    #[test]
+    fn test_aggregate_same_message() {
+        let message = b"Hello, BLS".to_vec();
+        let key_pairs = [
+            KeyPair::try_from(SECRET_1).unwrap(),
+            KeyPair::try_from(SECRET_2).unwrap(),
+        ];
+
+        let signatures: Vec<_> = key_pairs
+            .iter()
+            .map(|key_pair| key_pair.sign(message.clone()).unwrap())
+            .collect();
+        let public_keys: Vec<_> = key_pairs
+            .iter()
+            .map(|key_pair| key_pair.public().clone())
+            .collect();
+
+        let aggregated = Signature::aggregate(&signatures).unwrap();
+        assert!(aggregated.fast_aggregate_verify(&message, &public_keys));
+        assert!(!aggregated.fast_aggregate_verify(&message, &public_keys[..1]));
+
+        let aggregated_public = PublicKey::aggregate(&public_keys).unwrap();
+        assert!(aggregated_public.verify(aggregated, message));
+
+        assert!(Signature::aggregate(&[]).is_err());
+        assert!(PublicKey::aggregate(&[]).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_distinct_messages() {
+        let messages: [&[u8]; 2] = [b"message 1", b"message 2"];
+        let key_pairs = [
+            KeyPair::try_from(SECRET_1)
+                .unwrap()
+                .with_scheme(Scheme::Basic),
+            KeyPair::try_from(SECRET_2)
+                .unwrap()
+                .with_scheme(Scheme::Basic),
+        ];
+
+        let signatures: Vec<_> = key_pairs
+            .iter()
+            .zip(messages)
+            .map(|(key_pair, message)| key_pair.sign(message.to_vec()).unwrap())
+            .collect();
+        let public_keys: Vec<_> = key_pairs
+            .iter()
+            .map(|key_pair| key_pair.public().clone())
+            .collect();
+
+        let aggregated = Signature::aggregate(&signatures).unwrap();
+        assert!(aggregated.aggregate_verify(&messages, &public_keys, Scheme::Basic));
+        assert!(!aggregated.aggregate_verify(&messages, &public_keys, Scheme::ProofOfPossession));
+
+        let reversed = [messages[1], messages[0]];
+        assert!(!aggregated.aggregate_verify(&reversed, &public_keys, Scheme::Basic));
+
+        // The basic scheme requires messages to be distinct.
+        let same_message = key_pairs[1].sign(messages[0].to_vec()).unwrap();
+        let aggregated = Signature::aggregate(&[signatures[0].clone(), same_message]).unwrap();
+        let duplicated = [messages[0], messages[0]];
+        assert!(!aggregated.aggregate_verify(&duplicated, &public_keys, Scheme::Basic));
+    }
+
+    #[test]
+    fn test_proof_of_possession() {
+        let private_1 = PrivateKey::try_from(SECRET_1).unwrap();
+        let private_2 = PrivateKey::try_from(SECRET_2).unwrap();
+
+        let proof = private_1.prove_possession();
+        assert!(private_1.public().verify_possession(&proof));
+        assert!(!private_2.public().verify_possession(&proof));
+    }
+
+    #[test]
+    fn test_key_gen() {
+        let ikm = [7; 32];
+        let private = PrivateKey::key_gen(&ikm).unwrap();
+        assert_eq!(
+            private.public().to_vec(),
+            PrivateKey::key_gen(&ikm).unwrap().public().to_vec()
+        );
+
+        // `ikm` must be at least 32 bytes long.
+        assert!(PrivateKey::key_gen(&[7; 31]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        // Zero.
+        assert!(PrivateKey::try_from(
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        )
+        .is_err());
+        // The curve order.
+        assert!(PrivateKey::try_from(
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+        )
+        .is_err());
+
+        // Point at infinity.
+        let infinity = format!("c0{}", "00".repeat(47));
+        assert!(PublicKey::try_from(infinity.as_str()).is_err());
+        // Uncompressed public keys are not supported.
+        let public = PrivateKey::try_from(SECRET_1).unwrap().public();
+        let uncompressed = public.inner().serialize();
+        assert!(PublicKey::try_from(uncompressed.as_slice()).is_err());
+    }
+}
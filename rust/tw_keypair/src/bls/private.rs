@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::bls::public::PublicKey;
+use crate::bls::signature::Signature;
+use crate::bls::{Scheme, DST_POP_PROOF};
+use crate::traits::SigningKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use tw_encoding::hex;
+use tw_misc::traits::ToBytesZeroizing;
+use zeroize::Zeroizing;
+
+/// Represents a `BLS12-381` private key.
+#[derive(Clone)]
+pub struct PrivateKey {
+    secret: blst::min_pk::SecretKey,
+    scheme: Scheme,
+}
+
+impl PrivateKey {
+    /// Generates a private key from the input keying material `ikm` (at least 32 bytes)
+    /// using `KeyGen` defined in the IETF BLS signature draft.
+    pub fn key_gen(ikm: &[u8]) -> KeyPairResult<PrivateKey> {
+        let secret = blst::min_pk::SecretKey::key_gen(ikm, &[])
+            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+        Ok(PrivateKey {
+            secret,
+            scheme: Scheme::default(),
+        })
+    }
+
+    /// Returns a private key that signs messages according to the given `scheme`.
+    pub fn with_scheme(mut self, scheme: Scheme) -> PrivateKey {
+        self.scheme = scheme;
+        self
+    }
+
+    pub fn public(&self) -> PublicKey {
+        PublicKey::new(self.secret.sk_to_pk(), self.scheme)
+    }
+
+    /// Proves the possession of the private key by signing the public key.
+    /// The proof is required to aggregate public keys safely.
+    pub fn prove_possession(&self) -> Signature {
+        let public = self.secret.sk_to_pk().compress();
+        Signature::from(self.secret.sign(&public, DST_POP_PROOF, &[]))
+    }
+}
+
+impl SigningKeyTrait for PrivateKey {
+    type SigningMessage = Vec<u8>;
+    type Signature = Signature;
+
+    fn sign(&self, message: Self::SigningMessage) -> KeyPairResult<Self::Signature> {
+        Ok(Signature::from(self.secret.sign(
+            &message,
+            self.scheme.dst(),
+            &[],
+        )))
+    }
+}
+
+impl ToBytesZeroizing for PrivateKey {
+    fn to_zeroizing_vec(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.secret.to_bytes().to_vec())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PrivateKey {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let secret = blst::min_pk::SecretKey::from_bytes(bytes)
+            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+        Ok(PrivateKey {
+            secret,
+            scheme: Scheme::default(),
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PrivateKey {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        Self::try_from(bytes.as_slice())
+    }
+}
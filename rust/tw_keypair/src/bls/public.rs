@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::bls::signature::Signature;
+use crate::bls::{Scheme, DST_POP_PROOF};
+use crate::traits::VerifyingKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use blst::BLST_ERROR;
+use tw_encoding::hex;
+use tw_misc::traits::ToBytesVec;
+
+/// Represents a `BLS12-381` public key, a compressed G1 point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    public: blst::min_pk::PublicKey,
+    scheme: Scheme,
+}
+
+impl PublicKey {
+    /// The number of bytes in a compressed public key.
+    pub const LEN: usize = 48;
+
+    pub(crate) fn new(public: blst::min_pk::PublicKey, scheme: Scheme) -> PublicKey {
+        PublicKey { public, scheme }
+    }
+
+    /// Returns a public key that verifies signatures according to the given `scheme`.
+    pub fn with_scheme(mut self, scheme: Scheme) -> PublicKey {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Aggregates the given public keys into one.
+    /// Please note that the possession of every key must be proven beforehand
+    /// to prevent rogue key attacks. See [`PublicKey::verify_possession`].
+    pub fn aggregate(public_keys: &[PublicKey]) -> KeyPairResult<PublicKey> {
+        let first = public_keys.first().ok_or(KeyPairError::InvalidPublicKey)?;
+        let public_keys: Vec<_> = public_keys.iter().map(PublicKey::inner).collect();
+        // Public keys are validated on creation.
+        let aggregated = blst::min_pk::AggregatePublicKey::aggregate(&public_keys, false)
+            .map_err(|_| KeyPairError::InvalidPublicKey)?;
+        Ok(PublicKey::new(aggregated.to_public_key(), first.scheme))
+    }
+
+    /// Verifies the proof of possession produced by [`crate::bls::PrivateKey::prove_possession`].
+    pub fn verify_possession(&self, proof: &Signature) -> bool {
+        let public = self.public.compress();
+        let res = proof
+            .inner()
+            .verify(true, &public, DST_POP_PROOF, &[], &self.public, false);
+        res == BLST_ERROR::BLST_SUCCESS
+    }
+
+    pub(crate) fn inner(&self) -> &blst::min_pk::PublicKey {
+        &self.public
+    }
+}
+
+impl VerifyingKeyTrait for PublicKey {
+    type SigningMessage = Vec<u8>;
+    type VerifySignature = Signature;
+
+    fn verify(&self, signature: Self::VerifySignature, message: Self::SigningMessage) -> bool {
+        let res =
+            signature
+                .inner()
+                .verify(true, &message, self.scheme.dst(), &[], &self.public, false);
+        res == BLST_ERROR::BLST_SUCCESS
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PublicKey {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != PublicKey::LEN {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        // Also checks if the point is not infinity and belongs to the G1 subgroup.
+        let public = blst::min_pk::PublicKey::key_validate(bytes)
+            .map_err(|_| KeyPairError::InvalidPublicKey)?;
+        Ok(PublicKey::new(public, Scheme::default()))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PublicKey {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl ToBytesVec for PublicKey {
+    fn to_vec(&self) -> Vec<u8> {
+        self.public.compress().to_vec()
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::bls::public::PublicKey;
+use crate::bls::{Scheme, DST_POP};
+use crate::{KeyPairError, KeyPairResult};
+use blst::BLST_ERROR;
+use tw_misc::traits::ToBytesVec;
+
+/// Represents a `BLS12-381` signature, a compressed G2 point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Signature {
+    signature: blst::min_pk::Signature,
+}
+
+impl Signature {
+    /// The number of bytes in a compressed signature.
+    pub const LEN: usize = 96;
+
+    /// Aggregates the given signatures into one.
+    pub fn aggregate(signatures: &[Signature]) -> KeyPairResult<Signature> {
+        let signatures: Vec<_> = signatures.iter().map(Signature::inner).collect();
+        let aggregated = blst::min_pk::AggregateSignature::aggregate(&signatures, true)
+            .map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok(Signature::from(aggregated.to_signature()))
+    }
+
+    /// Verifies an aggregated signature of the same `message` signed by every of `public_keys`.
+    /// The signatures must be produced according to [`Scheme::ProofOfPossession`],
+    /// and the possession of every public key must be proven beforehand.
+    pub fn fast_aggregate_verify(&self, message: &[u8], public_keys: &[PublicKey]) -> bool {
+        let public_keys: Vec<_> = public_keys.iter().map(PublicKey::inner).collect();
+        let res = self
+            .signature
+            .fast_aggregate_verify(true, message, DST_POP, &public_keys);
+        res == BLST_ERROR::BLST_SUCCESS
+    }
+
+    /// Verifies an aggregated signature of `messages`, where every message is signed
+    /// by the public key at the same position, according to the given `scheme`.
+    /// Please note that [`Scheme::Basic`] requires all messages to be distinct.
+    pub fn aggregate_verify(
+        &self,
+        messages: &[&[u8]],
+        public_keys: &[PublicKey],
+        scheme: Scheme,
+    ) -> bool {
+        if scheme == Scheme::Basic && !are_distinct(messages) {
+            return false;
+        }
+        let public_keys: Vec<_> = public_keys.iter().map(PublicKey::inner).collect();
+        let res =
+            self.signature
+                .aggregate_verify(true, messages, scheme.dst(), &public_keys, false);
+        res == BLST_ERROR::BLST_SUCCESS
+    }
+
+    pub(crate) fn inner(&self) -> &blst::min_pk::Signature {
+        &self.signature
+    }
+}
+
+impl ToBytesVec for Signature {
+    fn to_vec(&self) -> Vec<u8> {
+        self.signature.compress().to_vec()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Signature {
+    type Error = KeyPairError;
+
+    /// Please note that the signature is checked to belong to the G2 subgroup on verification.
+    fn try_from(sig: &'a [u8]) -> Result<Self, Self::Error> {
+        if sig.len() != Signature::LEN {
+            return Err(KeyPairError::InvalidSignature);
+        }
+        let signature =
+            blst::min_pk::Signature::from_bytes(sig).map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok(Signature { signature })
+    }
+}
+
+impl From<blst::min_pk::Signature> for Signature {
+    fn from(signature: blst::min_pk::Signature) -> Self {
+        Signature { signature }
+    }
+}
+
+fn are_distinct(messages: &[&[u8]]) -> bool {
+    messages
+        .iter()
+        .enumerate()
+        .all(|(i, msg)| !messages[..i].contains(msg))
+}
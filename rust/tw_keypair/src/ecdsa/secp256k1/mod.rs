@@ -172,4 +172,34 @@ mod tests {
             H264::from("0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1");
         assert_eq!(actual.compressed(), expected_compressed);
     }
+
+    #[test]
+    fn test_sign_with_nonce_data() {
+        let private = PrivateKey::try_from(
+            "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5",
+        )
+        .unwrap();
+        let hash_to_sign = H256::try_from(keccak256(b"hello").as_slice()).unwrap();
+
+        // Without extra entropy, the signature is the same as the regular RFC6979 one.
+        let (signature, nonce_point) = private.sign_with_nonce_data(hash_to_sign, None).unwrap();
+        assert_eq!(
+            signature.to_bytes(),
+            private.sign(hash_to_sign).unwrap().to_bytes()
+        );
+        assert_eq!(nonce_point[1..], *signature.r());
+
+        // Generated by `libsecp256k1` with the same `ndata`.
+        let nonce_data =
+            H256::from("0101010101010101010101010101010101010101010101010101010101010101");
+        let (signature, nonce_point) = private
+            .sign_with_nonce_data(hash_to_sign, Some(nonce_data))
+            .unwrap();
+        let expected = H520::from("f08a3c9b2569fcfab3aea2d5d102c10a4a50ffcf9958814061ff703c653b3dc75d4378e49d197bce9f29dceb791d8fcd4407acd2a597ddbb6685a8040be90feb01");
+        assert_eq!(signature.to_bytes(), expected);
+        assert_eq!(nonce_point[1..], *signature.r());
+        assert!(private
+            .public()
+            .verify(signature.to_verify_sig(), hash_to_sign));
+    }
 }
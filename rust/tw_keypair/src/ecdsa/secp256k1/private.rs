@@ -2,13 +2,16 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::anti_exfil::{self, NonceCommitment, Protocol};
 use crate::ecdsa::secp256k1::public::PublicKey;
 use crate::ecdsa::secp256k1::Signature;
 use crate::traits::SigningKeyTrait;
 use crate::{KeyPairError, KeyPairResult};
+use ecdsa::hazmat::SignPrimitive;
 use k256::ecdsa::{SigningKey, VerifyingKey};
 use k256::elliptic_curve::sec1::ToEncodedPoint;
-use k256::{AffinePoint, ProjectivePoint};
+use k256::elliptic_curve::{Curve, FieldBytesEncoding, PrimeField};
+use k256::{AffinePoint, FieldBytes, ProjectivePoint, Scalar, Secp256k1};
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesZeroizing;
@@ -38,6 +41,71 @@ impl PrivateKey {
         let shared_secret_hash = tw_hash::sha2::sha256(shared_secret_compressed.as_bytes());
         H256::try_from(shared_secret_hash.as_slice()).expect("Expected 32 byte array sha256 hash")
     }
+
+    /// Signs the `message` with a nonce derived according to RFC6979 with the optional
+    /// extra entropy `nonce_data` (`ndata` in `libsecp256k1`).
+    /// Returns the signature and the nonce point `R = k×G` it was produced with.
+    ///
+    /// Please note that the signature is normalized to a low `s`,
+    /// so it may correspond to the negated nonce `-R` with the same x-coordinate.
+    pub fn sign_with_nonce_data(
+        &self,
+        message: H256,
+        nonce_data: Option<H256>,
+    ) -> KeyPairResult<(Signature, NonceCommitment)> {
+        let nonce_data = nonce_data.as_ref().map(H256::as_slice).unwrap_or_default();
+        let nonce = self.rfc6979_nonce(message, nonce_data)?;
+        let signature = self.sign_with_nonce(message, &nonce)?;
+
+        let nonce_point = (ProjectivePoint::GENERATOR * *nonce).to_affine();
+        Ok((signature, anti_exfil::nonce_commitment(&nonce_point)))
+    }
+
+    /// Returns the nonce commitment `R0` of the anti-exfil protocol
+    /// for the given `message` and `host_commitment`.
+    /// See [`anti_exfil::ecdsa_host_commit`].
+    pub fn anti_exfil_commit(
+        &self,
+        message: H256,
+        host_commitment: H256,
+    ) -> KeyPairResult<NonceCommitment> {
+        let nonce = self.rfc6979_nonce(message, host_commitment.as_slice())?;
+        let nonce_point = (ProjectivePoint::GENERATOR * *nonce).to_affine();
+        Ok(anti_exfil::nonce_commitment(&nonce_point))
+    }
+
+    /// Signs the `message` according to the anti-exfil protocol with the `host_data`
+    /// revealed by the host after receiving the [`PrivateKey::anti_exfil_commit`] result.
+    pub fn anti_exfil_sign(&self, message: H256, host_data: H256) -> KeyPairResult<Signature> {
+        let host_commitment = anti_exfil::ecdsa_host_commit(&host_data);
+        let committed_nonce = self.rfc6979_nonce(message, host_commitment.as_slice())?;
+        let committed_point = (ProjectivePoint::GENERATOR * *committed_nonce).to_affine();
+
+        let tweak = anti_exfil::nonce_tweak(Protocol::Ecdsa, &committed_point, &host_data)?;
+        self.sign_with_nonce(message, &Zeroizing::new(*committed_nonce + tweak))
+    }
+
+    fn rfc6979_nonce(&self, message: H256, nonce_data: &[u8]) -> KeyPairResult<Zeroizing<Scalar>> {
+        let secret = Zeroizing::new(self.secret.as_nonzero_scalar().to_repr());
+        let nonce = Zeroizing::new(rfc6979::generate_k::<sha2::Sha256, _>(
+            &secret,
+            &FieldBytesEncoding::<Secp256k1>::encode_field_bytes(&Secp256k1::ORDER),
+            &FieldBytes::clone_from_slice(message.as_slice()),
+            nonce_data,
+        ));
+        Option::from(Scalar::from_repr(*nonce))
+            .map(Zeroizing::new)
+            .ok_or(KeyPairError::SigningError)
+    }
+
+    fn sign_with_nonce(&self, message: H256, nonce: &Scalar) -> KeyPairResult<Signature> {
+        let secret: &Scalar = self.secret.as_nonzero_scalar().as_ref();
+        let (signature, recovery_id) = secret
+            .try_sign_prehashed(*nonce, &FieldBytes::clone_from_slice(message.as_slice()))
+            .map_err(|_| KeyPairError::SigningError)?;
+        let recovery_id = recovery_id.ok_or(KeyPairError::SigningError)?;
+        Ok(Signature::new(signature, recovery_id))
+    }
 }
 
 /// This method is inspired by [elliptic_curve::ecdh::diffie_hellman](https://github.com/RustCrypto/traits/blob/f0dbe44fea56d4c17e625ababacb580fec842137/elliptic-curve/src/ecdh.rs#L60-L70)
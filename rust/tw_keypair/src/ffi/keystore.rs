@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::ffi::privkey::TWPrivateKey;
+use crate::keystore::{Keystore, KeystoreKdf};
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Encrypts the given data (e.g. a private key or a mnemonic) with the password,
+/// and returns a Web3 Secret Storage (keystore v3) JSON.
+///
+/// \param password *non-null* pointer to the password.
+/// \param data *non-null* pointer to the data to be encrypted.
+/// \param kdf KDF settings: 0 - scrypt (standard), 1 - scrypt (light), 2 - pbkdf2.
+/// \return *nullable* pointer to the keystore JSON.
+#[no_mangle]
+pub unsafe extern "C" fn tw_keystore_encrypt(
+    password: *const TWData,
+    data: *const TWData,
+    kdf: u32,
+) -> *mut TWString {
+    let password = try_or_else!(TWData::from_ptr_as_ref(password), std::ptr::null_mut);
+    let data = try_or_else!(TWData::from_ptr_as_ref(data), std::ptr::null_mut);
+    let kdf = try_or_else!(KeystoreKdf::from_raw(kdf), std::ptr::null_mut);
+
+    let keystore = try_or_else!(
+        Keystore::encrypt(password.as_slice(), data.as_slice(), kdf.params()),
+        std::ptr::null_mut
+    );
+    keystore
+        .to_json()
+        .map(TWString::from)
+        .map(TWString::into_ptr)
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Decrypts the data stored in a Web3 Secret Storage (keystore v3) JSON.
+///
+/// \param json *non-null* pointer to the keystore JSON.
+/// \param password *non-null* pointer to the password.
+/// \return *nullable* pointer to the decrypted data. Null if the password is incorrect.
+#[no_mangle]
+pub unsafe extern "C" fn tw_keystore_decrypt(
+    json: *const TWString,
+    password: *const TWData,
+) -> *mut TWData {
+    let keystore = try_or_else!(parse_keystore(json), std::ptr::null_mut);
+    let password = try_or_else!(TWData::from_ptr_as_ref(password), std::ptr::null_mut);
+
+    keystore
+        .decrypt(password.as_slice())
+        .map(|data| TWData::from(data.to_vec()))
+        .map(TWData::into_ptr)
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Decrypts a private key stored in a Web3 Secret Storage (keystore v3) JSON.
+///
+/// \param json *non-null* pointer to the keystore JSON.
+/// \param password *non-null* pointer to the password.
+/// \note Should be deleted with \tw_private_key_delete.
+/// \return *nullable* pointer to the private key. Null if the password is incorrect.
+#[no_mangle]
+pub unsafe extern "C" fn tw_keystore_decrypt_private_key(
+    json: *const TWString,
+    password: *const TWData,
+) -> *mut TWPrivateKey {
+    let keystore = try_or_else!(parse_keystore(json), std::ptr::null_mut);
+    let password = try_or_else!(TWData::from_ptr_as_ref(password), std::ptr::null_mut);
+
+    keystore
+        .decrypt_private_key(password.as_slice())
+        .map(|private| TWPrivateKey(private).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Decrypts a mnemonic phrase stored in a Web3 Secret Storage (keystore v3) JSON.
+///
+/// \param json *non-null* pointer to the keystore JSON.
+/// \param password *non-null* pointer to the password.
+/// \return *nullable* pointer to the mnemonic phrase. Null if the password is incorrect.
+#[no_mangle]
+pub unsafe extern "C" fn tw_keystore_decrypt_mnemonic(
+    json: *const TWString,
+    password: *const TWData,
+) -> *mut TWString {
+    let keystore = try_or_else!(parse_keystore(json), std::ptr::null_mut);
+    let password = try_or_else!(TWData::from_ptr_as_ref(password), std::ptr::null_mut);
+
+    keystore
+        .decrypt_mnemonic(password.as_slice())
+        .map(|mnemonic| TWString::from(mnemonic.to_string()))
+        .map(TWString::into_ptr)
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+unsafe fn parse_keystore(json: *const TWString) -> Option<Keystore> {
+    let json = TWString::from_ptr_as_ref(json)?.as_str()?;
+    Keystore::from_json(json).ok()
+}
@@ -4,5 +4,6 @@
 
 pub mod asn;
 pub mod crypto_box;
+pub mod keystore;
 pub mod privkey;
 pub mod pubkey;
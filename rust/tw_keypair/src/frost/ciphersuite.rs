@@ -0,0 +1,488 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! FROST ciphersuites as specified in RFC 9591 section 6.
+//! https://www.rfc-editor.org/rfc/rfc9591.html#name-ciphersuites
+
+use crate::rand::OsRng;
+use crate::schnorr::tagged_hash;
+use crate::{KeyPairError, KeyPairResult};
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::traits::Identity;
+use k256::elliptic_curve::bigint::U512;
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, U256};
+use rand_core::RngCore;
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+use tw_hash::sha2::{sha256, sha512};
+use tw_hash::{Hash, H256, H512};
+use tw_memory::Data;
+use zeroize::Zeroize;
+
+/// The prime-order group, its scalar field and the hash functions a FROST ciphersuite is built on.
+pub trait Ciphersuite: Clone + Copy + Debug + PartialEq + Eq {
+    type Scalar: Clone
+        + Copy
+        + Debug
+        + Eq
+        + Zeroize
+        + Add<Output = Self::Scalar>
+        + Sub<Output = Self::Scalar>
+        + Mul<Output = Self::Scalar>
+        + Neg<Output = Self::Scalar>;
+    type Point: Clone
+        + Copy
+        + Debug
+        + Eq
+        + Add<Output = Self::Point>
+        + Sub<Output = Self::Point>
+        + Neg<Output = Self::Point>
+        + Mul<Self::Scalar, Output = Self::Point>;
+
+    const CONTEXT_STRING: &'static [u8];
+    /// The length of a serialized point.
+    const POINT_LEN: usize;
+
+    fn zero() -> Self::Scalar;
+
+    fn one() -> Self::Scalar;
+
+    fn scalar_from_u16(value: u16) -> Self::Scalar;
+
+    /// Returns `None` if the given scalar is zero.
+    fn invert(scalar: &Self::Scalar) -> Option<Self::Scalar>;
+
+    /// Generates a uniformly random non-zero scalar.
+    fn random_scalar() -> Self::Scalar;
+
+    fn serialize_scalar(scalar: &Self::Scalar) -> H256;
+
+    /// Parses a scalar failing if it's not canonically encoded.
+    fn deserialize_scalar(bytes: &[u8]) -> KeyPairResult<Self::Scalar>;
+
+    fn identity() -> Self::Point;
+
+    fn generator() -> Self::Point;
+
+    fn serialize_point(point: &Self::Point) -> Data;
+
+    /// Parses a point failing if it's the identity element or not of the prime order.
+    fn deserialize_point(bytes: &[u8]) -> KeyPairResult<Self::Point>;
+
+    /// Hashes the binding factor input `rho`.
+    fn h1(message: &[u8]) -> Self::Scalar;
+
+    /// Hashes the signature challenge.
+    fn h2(message: &[u8]) -> Self::Scalar;
+
+    /// Hashes the nonce input.
+    fn h3(message: &[u8]) -> Self::Scalar;
+
+    /// Hashes the message to be signed.
+    fn h4(message: &[u8]) -> Data;
+
+    /// Hashes the encoded commitment list.
+    fn h5(message: &[u8]) -> Data;
+
+    /// Hashes the DKG proof of knowledge challenge.
+    fn hdkg(message: &[u8]) -> Self::Scalar;
+
+    /// Whether the point can be used as is as a nonce or a public key.
+    /// Ciphersuites that use x-only encoding negate the points with an odd y-coordinate
+    /// along with the corresponding scalars.
+    fn has_even_y(_point: &Self::Point) -> bool {
+        true
+    }
+
+    /// Computes the signature challenge of the `message` signed with the `group_commitment` nonce.
+    fn challenge(
+        group_commitment: &Self::Point,
+        verifying_key: &Self::Point,
+        message: &[u8],
+    ) -> Self::Scalar {
+        let mut preimage = Self::serialize_point(group_commitment);
+        preimage.extend_from_slice(&Self::serialize_point(verifying_key));
+        preimage.extend_from_slice(message);
+        Self::h2(&preimage)
+    }
+
+    fn serialize_signature(r: &Self::Point, z: &Self::Scalar) -> Data {
+        let mut bytes = Self::serialize_point(r);
+        bytes.extend_from_slice(Self::serialize_scalar(z).as_slice());
+        bytes
+    }
+
+    fn deserialize_signature(bytes: &[u8]) -> KeyPairResult<(Self::Point, Self::Scalar)> {
+        if bytes.len() != Self::POINT_LEN + H256::LEN {
+            return Err(KeyPairError::InvalidSignature);
+        }
+        let (r, z) = bytes.split_at(Self::POINT_LEN);
+        let r = Self::deserialize_point(r).map_err(|_| KeyPairError::InvalidSignature)?;
+        let z = Self::deserialize_scalar(z).map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok((r, z))
+    }
+}
+
+/// FROST(Ed25519, SHA-512) that produces standard Ed25519 signatures.
+/// https://www.rfc-editor.org/rfc/rfc9591.html#name-frosted25519-sha-512
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ed25519;
+
+impl Ed25519 {
+    fn hash_to_scalar(parts: &[&[u8]]) -> curve25519_dalek::Scalar {
+        let hash = H512::try_from(sha512(&parts.concat()).as_slice())
+            .expect("Expected 64 byte array sha512 hash");
+        curve25519_dalek::Scalar::from_bytes_mod_order_wide(&hash.take())
+    }
+}
+
+impl Ciphersuite for Ed25519 {
+    type Scalar = curve25519_dalek::Scalar;
+    type Point = EdwardsPoint;
+
+    const CONTEXT_STRING: &'static [u8] = b"FROST-ED25519-SHA512-v1";
+    const POINT_LEN: usize = 32;
+
+    fn zero() -> Self::Scalar {
+        curve25519_dalek::Scalar::ZERO
+    }
+
+    fn one() -> Self::Scalar {
+        curve25519_dalek::Scalar::ONE
+    }
+
+    fn scalar_from_u16(value: u16) -> Self::Scalar {
+        curve25519_dalek::Scalar::from(value)
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Option<Self::Scalar> {
+        if *scalar == Self::zero() {
+            return None;
+        }
+        Some(scalar.invert())
+    }
+
+    fn random_scalar() -> Self::Scalar {
+        loop {
+            let mut bytes = H512::default();
+            OsRng.fill_bytes(bytes.as_mut_slice());
+            let scalar = curve25519_dalek::Scalar::from_bytes_mod_order_wide(&bytes.take());
+            bytes.zeroize();
+            if scalar != Self::zero() {
+                return scalar;
+            }
+        }
+    }
+
+    fn serialize_scalar(scalar: &Self::Scalar) -> H256 {
+        H256::from(scalar.to_bytes())
+    }
+
+    fn deserialize_scalar(bytes: &[u8]) -> KeyPairResult<Self::Scalar> {
+        let bytes = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        Option::from(curve25519_dalek::Scalar::from_canonical_bytes(bytes.take()))
+            .ok_or(KeyPairError::InvalidSecretKey)
+    }
+
+    fn identity() -> Self::Point {
+        EdwardsPoint::identity()
+    }
+
+    fn generator() -> Self::Point {
+        ED25519_BASEPOINT_POINT
+    }
+
+    fn serialize_point(point: &Self::Point) -> Data {
+        point.compress().to_bytes().to_vec()
+    }
+
+    fn deserialize_point(bytes: &[u8]) -> KeyPairResult<Self::Point> {
+        let point = CompressedEdwardsY::from_slice(bytes)
+            .map_err(|_| KeyPairError::InvalidPublicKey)?
+            .decompress()
+            .ok_or(KeyPairError::InvalidPublicKey)?;
+        // Non-canonical encodings are rejected along with the points of a small or mixed order.
+        if point.compress().as_bytes().as_slice() != bytes
+            || point == Self::identity()
+            || !point.is_torsion_free()
+        {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        Ok(point)
+    }
+
+    fn h1(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_scalar(&[Self::CONTEXT_STRING, b"rho", message])
+    }
+
+    fn h2(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_scalar(&[message])
+    }
+
+    fn h3(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_scalar(&[Self::CONTEXT_STRING, b"nonce", message])
+    }
+
+    fn h4(message: &[u8]) -> Data {
+        sha512(&[Self::CONTEXT_STRING, b"msg", message].concat())
+    }
+
+    fn h5(message: &[u8]) -> Data {
+        sha512(&[Self::CONTEXT_STRING, b"com", message].concat())
+    }
+
+    fn hdkg(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_scalar(&[Self::CONTEXT_STRING, b"dkg", message])
+    }
+}
+
+/// FROST(secp256k1, SHA-256) adapted to produce BIP-340 signatures,
+/// compatible with the `frost-secp256k1-tr` implementation by the Zcash Foundation.
+/// https://www.rfc-editor.org/rfc/rfc9591.html#name-frostsecp256k1-sha-256
+///
+/// The nonces and the signing shares are negated when the group commitment or
+/// the group public key has an odd y-coordinate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1Taproot;
+
+impl Secp256k1Taproot {
+    /// `hash_to_field` from RFC 9380 with `expand_message_xmd` and SHA-256.
+    fn hash_to_field(dst: &[&[u8]], message: &[u8]) -> k256::Scalar {
+        // `L = ceil((ceil(log2(n)) + k) / 8)` where `k = 128` is the security level.
+        const L: usize = 48;
+
+        let uniform = expand_message_xmd(&dst.concat(), message, L);
+        let mut wide = H512::default();
+        wide[H512::LEN - L..].copy_from_slice(&uniform);
+        <k256::Scalar as Reduce<U512>>::reduce(U512::from_be_slice(wide.as_slice()))
+    }
+
+    fn reduce_hash(hash: H256) -> k256::Scalar {
+        <k256::Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(hash.take()))
+    }
+
+    fn x_bytes(point: &ProjectivePoint) -> [u8; 32] {
+        point.to_affine().x().into()
+    }
+}
+
+impl Ciphersuite for Secp256k1Taproot {
+    type Scalar = k256::Scalar;
+    type Point = ProjectivePoint;
+
+    const CONTEXT_STRING: &'static [u8] = b"FROST-secp256k1-SHA256-TR-v1";
+    const POINT_LEN: usize = 33;
+
+    fn zero() -> Self::Scalar {
+        k256::Scalar::ZERO
+    }
+
+    fn one() -> Self::Scalar {
+        k256::Scalar::ONE
+    }
+
+    fn scalar_from_u16(value: u16) -> Self::Scalar {
+        k256::Scalar::from(value as u64)
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Option<Self::Scalar> {
+        Option::from(scalar.invert())
+    }
+
+    fn random_scalar() -> Self::Scalar {
+        loop {
+            let mut bytes = H512::default();
+            OsRng.fill_bytes(bytes.as_mut_slice());
+            let scalar =
+                <k256::Scalar as Reduce<U512>>::reduce(U512::from_be_slice(bytes.as_slice()));
+            bytes.zeroize();
+            if scalar != Self::zero() {
+                return scalar;
+            }
+        }
+    }
+
+    fn serialize_scalar(scalar: &Self::Scalar) -> H256 {
+        H256::from(<[u8; 32]>::from(scalar.to_bytes()))
+    }
+
+    fn deserialize_scalar(bytes: &[u8]) -> KeyPairResult<Self::Scalar> {
+        let bytes = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        Option::from(k256::Scalar::from_repr(FieldBytes::from(bytes.take())))
+            .ok_or(KeyPairError::InvalidSecretKey)
+    }
+
+    fn identity() -> Self::Point {
+        ProjectivePoint::IDENTITY
+    }
+
+    fn generator() -> Self::Point {
+        ProjectivePoint::GENERATOR
+    }
+
+    fn serialize_point(point: &Self::Point) -> Data {
+        point.to_affine().to_encoded_point(true).as_bytes().to_vec()
+    }
+
+    fn deserialize_point(bytes: &[u8]) -> KeyPairResult<Self::Point> {
+        if bytes.len() != Self::POINT_LEN {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        let encoded =
+            EncodedPoint::from_bytes(bytes).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let point: AffinePoint = Option::from(AffinePoint::from_encoded_point(&encoded))
+            .ok_or(KeyPairError::InvalidPublicKey)?;
+        let point = ProjectivePoint::from(point);
+        if point == Self::identity() {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        Ok(point)
+    }
+
+    fn h1(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_field(&[Self::CONTEXT_STRING, b"rho"], message)
+    }
+
+    fn h2(message: &[u8]) -> Self::Scalar {
+        Self::reduce_hash(tagged_hash(b"BIP0340/challenge", message))
+    }
+
+    fn h3(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_field(&[Self::CONTEXT_STRING, b"nonce"], message)
+    }
+
+    fn h4(message: &[u8]) -> Data {
+        sha256(&[Self::CONTEXT_STRING, b"msg", message].concat())
+    }
+
+    fn h5(message: &[u8]) -> Data {
+        sha256(&[Self::CONTEXT_STRING, b"com", message].concat())
+    }
+
+    fn hdkg(message: &[u8]) -> Self::Scalar {
+        Self::hash_to_field(&[Self::CONTEXT_STRING, b"dkg"], message)
+    }
+
+    fn has_even_y(point: &Self::Point) -> bool {
+        !bool::from(point.to_affine().y_is_odd())
+    }
+
+    /// BIP-340 challenge that commits to the x-coordinates only.
+    fn challenge(
+        group_commitment: &Self::Point,
+        verifying_key: &Self::Point,
+        message: &[u8],
+    ) -> Self::Scalar {
+        let mut preimage = Self::x_bytes(group_commitment).to_vec();
+        preimage.extend_from_slice(&Self::x_bytes(verifying_key));
+        preimage.extend_from_slice(message);
+        Self::h2(&preimage)
+    }
+
+    /// BIP-340 signature `x(R) || z`.
+    fn serialize_signature(r: &Self::Point, z: &Self::Scalar) -> Data {
+        let mut bytes = Self::x_bytes(r).to_vec();
+        bytes.extend_from_slice(Self::serialize_scalar(z).as_slice());
+        bytes
+    }
+
+    fn deserialize_signature(bytes: &[u8]) -> KeyPairResult<(Self::Point, Self::Scalar)> {
+        if bytes.len() != H512::LEN {
+            return Err(KeyPairError::InvalidSignature);
+        }
+        let (r, z) = bytes.split_at(H256::LEN);
+        // BIP-340 nonces always have an even y-coordinate.
+        let mut compressed = Hash::<33>::default();
+        compressed[0] = 0x02;
+        compressed[1..].copy_from_slice(r);
+
+        let r = Self::deserialize_point(compressed.as_slice())
+            .map_err(|_| KeyPairError::InvalidSignature)?;
+        let z = Self::deserialize_scalar(z).map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok((r, z))
+    }
+}
+
+/// `expand_message_xmd` from RFC 9380 with SHA-256.
+/// https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd
+fn expand_message_xmd(dst: &[u8], message: &[u8], len_in_bytes: usize) -> Data {
+    const B_IN_BYTES: usize = 32;
+    const S_IN_BYTES: usize = 64;
+
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255 && dst.len() <= 255 && len_in_bytes <= u16::MAX as usize);
+
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+
+    let mut msg_prime = vec![0; S_IN_BYTES];
+    msg_prime.extend_from_slice(message);
+    msg_prime.extend_from_slice(&(len_in_bytes as u16).to_be_bytes());
+    msg_prime.push(0);
+    msg_prime.extend_from_slice(&dst_prime);
+    let b_0 = sha256(&msg_prime);
+
+    let mut uniform = Vec::with_capacity(ell * B_IN_BYTES);
+    let mut b_i = vec![0; B_IN_BYTES];
+    for i in 1..=ell {
+        let mut input: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        input.push(i as u8);
+        input.extend_from_slice(&dst_prime);
+        b_i = sha256(&input);
+        uniform.extend_from_slice(&b_i);
+    }
+    uniform.truncate(len_in_bytes);
+    uniform
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    /// https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmdsha-256
+    #[test]
+    fn test_expand_message_xmd() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            expand_message_xmd(dst, b"", 0x20).to_hex(),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            expand_message_xmd(dst, b"abc", 0x20).to_hex(),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+    }
+
+    #[test]
+    fn test_ed25519_deserialize_point() {
+        let generator = Ed25519::serialize_point(&Ed25519::generator());
+        assert_eq!(
+            Ed25519::deserialize_point(&generator).unwrap(),
+            Ed25519::generator()
+        );
+
+        // y = p + 1 is a non-canonical encoding of y = 1.
+        let mut non_canonical = [0xff; 32];
+        non_canonical[0] = 0xee;
+        non_canonical[31] = 0x7f;
+        assert_eq!(
+            Ed25519::deserialize_point(&non_canonical).unwrap_err(),
+            KeyPairError::InvalidPublicKey
+        );
+
+        // The "negative zero" x-coordinate.
+        let mut negative_zero = Ed25519::serialize_point(&Ed25519::identity());
+        negative_zero[31] |= 0x80;
+        assert_eq!(
+            Ed25519::deserialize_point(&negative_zero).unwrap_err(),
+            KeyPairError::InvalidPublicKey
+        );
+    }
+}
@@ -0,0 +1,552 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Distributed key generation from the original FROST paper, compatible with the implementation
+//! by the Zcash Foundation. Every participant acts as a dealer of its own random secret,
+//! and the group secret is the sum of these secrets that nobody knows.
+//! https://eprint.iacr.org/2020/852.pdf
+//!
+//! 1. Every participant calls [`part1`] and broadcasts its [`Round1Package`].
+//! 2. Every participant calls [`part2`] with the packages received from the others,
+//!    and sends every other participant its [`Round2Package`] over a confidential channel.
+//! 3. Every participant calls [`part3`] with all the packages received to get its key package.
+//!
+//! # Important
+//!
+//! The round 1 packages must be broadcast so that every participant receives the same ones.
+
+use crate::frost::ciphersuite::Ciphersuite;
+use crate::frost::keys::{
+    evaluate_polynomial, validate_num_of_signers, KeyPackage, PublicKeyPackage,
+    VerifiableSecretSharingCommitment,
+};
+use crate::frost::{even_y, Identifier, Signature};
+use crate::{KeyPairError, KeyPairResult};
+use std::collections::BTreeMap;
+use tw_hash::H256;
+use zeroize::Zeroize;
+
+/// The secret polynomial of a participant kept between [`part1`] and [`part2`].
+pub struct Round1SecretPackage<C: Ciphersuite> {
+    identifier: Identifier,
+    coefficients: Vec<C::Scalar>,
+    commitment: VerifiableSecretSharingCommitment<C>,
+    max_signers: u16,
+}
+
+impl<C: Ciphersuite> Drop for Round1SecretPackage<C> {
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+/// The commitment to the polynomial of a participant along with a proof of knowledge of its secret.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round1Package<C: Ciphersuite> {
+    commitment: VerifiableSecretSharingCommitment<C>,
+    proof_of_knowledge: Signature<C>,
+}
+
+impl<C: Ciphersuite> Round1Package<C> {
+    pub fn new(
+        commitment: VerifiableSecretSharingCommitment<C>,
+        proof_of_knowledge: Signature<C>,
+    ) -> Round1Package<C> {
+        Round1Package {
+            commitment,
+            proof_of_knowledge,
+        }
+    }
+
+    pub fn commitment(&self) -> &VerifiableSecretSharingCommitment<C> {
+        &self.commitment
+    }
+
+    pub fn proof_of_knowledge(&self) -> &Signature<C> {
+        &self.proof_of_knowledge
+    }
+}
+
+/// The own share of a participant kept between [`part2`] and [`part3`].
+pub struct Round2SecretPackage<C: Ciphersuite> {
+    identifier: Identifier,
+    commitment: VerifiableSecretSharingCommitment<C>,
+    signing_share: C::Scalar,
+    max_signers: u16,
+}
+
+impl<C: Ciphersuite> Drop for Round2SecretPackage<C> {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+/// The share `f_i(l)` of the participant's secret sent to the participant `l`.
+#[derive(Clone)]
+pub struct Round2Package<C: Ciphersuite> {
+    signing_share: C::Scalar,
+}
+
+impl<C: Ciphersuite> Round2Package<C> {
+    pub fn signing_share(&self) -> H256 {
+        C::serialize_scalar(&self.signing_share)
+    }
+}
+
+impl<'a, C: Ciphersuite> TryFrom<&'a [u8]> for Round2Package<C> {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Round2Package {
+            signing_share: C::deserialize_scalar(bytes)?,
+        })
+    }
+}
+
+impl<C: Ciphersuite> Drop for Round2Package<C> {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+/// Generates a random secret polynomial of the participant with the given `identifier`,
+/// and proves the knowledge of its secret.
+pub fn part1<C: Ciphersuite>(
+    identifier: Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> KeyPairResult<(Round1SecretPackage<C>, Round1Package<C>)> {
+    validate_num_of_signers(max_signers, min_signers)?;
+
+    let coefficients: Vec<_> = (0..min_signers).map(|_| C::random_scalar()).collect();
+    part1_with_coefficients(identifier, coefficients, max_signers)
+}
+
+fn part1_with_coefficients<C: Ciphersuite>(
+    identifier: Identifier,
+    coefficients: Vec<C::Scalar>,
+    max_signers: u16,
+) -> KeyPairResult<(Round1SecretPackage<C>, Round1Package<C>)> {
+    let commitment = VerifiableSecretSharingCommitment::from_coefficients(&coefficients);
+
+    // `σ = (R, μ)` where `R = k×G`, `μ = k + a_0 × c` and `c = H(i || a_0×G || R)`.
+    let mut k = C::random_scalar();
+    let r = C::generator() * k;
+    if !C::has_even_y(&r) {
+        k = -k;
+    }
+    let r = even_y::<C>(r);
+    let challenge = pok_challenge::<C>(identifier, &commitment.verifying_key(), &r);
+    let proof_of_knowledge = Signature {
+        r,
+        z: k + coefficients[0] * challenge,
+    };
+    k.zeroize();
+
+    let secret_package = Round1SecretPackage {
+        identifier,
+        coefficients,
+        commitment: commitment.clone(),
+        max_signers,
+    };
+    let package = Round1Package {
+        commitment,
+        proof_of_knowledge,
+    };
+    Ok((secret_package, package))
+}
+
+/// The own secret package, and the packages to be sent to the other participants.
+pub type Round2Output<C> = (
+    Round2SecretPackage<C>,
+    BTreeMap<Identifier, Round2Package<C>>,
+);
+
+/// Verifies the packages received from all the other participants,
+/// and computes the shares of the own secret to be sent to them.
+pub fn part2<C: Ciphersuite>(
+    secret_package: Round1SecretPackage<C>,
+    round1_packages: &BTreeMap<Identifier, Round1Package<C>>,
+) -> KeyPairResult<Round2Output<C>> {
+    validate_others(
+        &secret_package.identifier,
+        secret_package.max_signers,
+        round1_packages,
+    )?;
+
+    let mut round2_packages = BTreeMap::new();
+    for (sender, package) in round1_packages.iter() {
+        if package.commitment.min_signers() != secret_package.commitment.min_signers() {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        if !verify_proof_of_knowledge(*sender, package) {
+            return Err(KeyPairError::InvalidSignature);
+        }
+
+        let signing_share = evaluate_polynomial::<C>(*sender, &secret_package.coefficients);
+        round2_packages.insert(*sender, Round2Package { signing_share });
+    }
+
+    let secret_package = Round2SecretPackage {
+        identifier: secret_package.identifier,
+        commitment: secret_package.commitment.clone(),
+        signing_share: evaluate_polynomial::<C>(
+            secret_package.identifier,
+            &secret_package.coefficients,
+        ),
+        max_signers: secret_package.max_signers,
+    };
+    Ok((secret_package, round2_packages))
+}
+
+/// Verifies the shares received from all the other participants,
+/// and derives the key package of the participant and the public key package of the group.
+pub fn part3<C: Ciphersuite>(
+    secret_package: &Round2SecretPackage<C>,
+    round1_packages: &BTreeMap<Identifier, Round1Package<C>>,
+    round2_packages: &BTreeMap<Identifier, Round2Package<C>>,
+) -> KeyPairResult<(KeyPackage<C>, PublicKeyPackage<C>)> {
+    validate_others(
+        &secret_package.identifier,
+        secret_package.max_signers,
+        round1_packages,
+    )?;
+    if !round2_packages.keys().eq(round1_packages.keys()) {
+        return Err(KeyPairError::InvalidSecretKey);
+    }
+
+    let mut signing_share = secret_package.signing_share;
+    for (sender, package) in round2_packages.iter() {
+        let commitment = &round1_packages[sender].commitment;
+        if C::generator() * package.signing_share
+            != commitment.verifying_share(secret_package.identifier)
+        {
+            return Err(KeyPairError::InvalidSecretKey);
+        }
+        signing_share = signing_share + package.signing_share;
+    }
+
+    let group_commitment = VerifiableSecretSharingCommitment::sum(
+        round1_packages
+            .values()
+            .map(|package| &package.commitment)
+            .chain(std::iter::once(&secret_package.commitment)),
+    )?;
+    let identifiers = round1_packages
+        .keys()
+        .copied()
+        .chain(std::iter::once(secret_package.identifier));
+
+    let key_package = KeyPackage::new(
+        secret_package.identifier,
+        signing_share,
+        group_commitment.verifying_key(),
+        group_commitment.min_signers(),
+    );
+    signing_share.zeroize();
+    let public_key_package = PublicKeyPackage::from_commitment(identifiers, &group_commitment);
+    Ok((key_package, public_key_package))
+}
+
+/// Checks that the packages are received from all the other participants.
+fn validate_others<C: Ciphersuite>(
+    identifier: &Identifier,
+    max_signers: u16,
+    round1_packages: &BTreeMap<Identifier, Round1Package<C>>,
+) -> KeyPairResult<()> {
+    if round1_packages.len() != max_signers as usize - 1 || round1_packages.contains_key(identifier)
+    {
+        return Err(KeyPairError::InvalidPublicKey);
+    }
+    Ok(())
+}
+
+fn pok_challenge<C: Ciphersuite>(
+    identifier: Identifier,
+    verifying_key: &C::Point,
+    r: &C::Point,
+) -> C::Scalar {
+    let mut preimage = identifier.serialize::<C>().to_vec();
+    preimage.extend_from_slice(&C::serialize_point(verifying_key));
+    preimage.extend_from_slice(&C::serialize_point(r));
+    C::hdkg(&preimage)
+}
+
+fn verify_proof_of_knowledge<C: Ciphersuite>(
+    identifier: Identifier,
+    package: &Round1Package<C>,
+) -> bool {
+    let verifying_key = package.commitment.verifying_key();
+    let Signature { r, z } = package.proof_of_knowledge;
+    let challenge = pok_challenge::<C>(identifier, &verifying_key, &r);
+    r == C::generator() * z - verifying_key * challenge
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frost::ciphersuite::{Ed25519, Secp256k1Taproot};
+    use tw_encoding::hex::DecodeHex;
+
+    struct Participant {
+        identifier: u16,
+        signing_key: &'static str,
+        coefficient: &'static str,
+        vss_commitments: [&'static str; 2],
+        proof_of_knowledge: &'static str,
+        /// The shares received from the other participants.
+        signing_shares: [(u16, &'static str); 2],
+        verifying_share: &'static str,
+        signing_share: &'static str,
+    }
+
+    fn scalar<C: Ciphersuite>(hex: &str) -> C::Scalar {
+        C::deserialize_scalar(&hex.decode_hex().unwrap()).unwrap()
+    }
+
+    fn id(value: u16) -> Identifier {
+        Identifier::try_from(value).unwrap()
+    }
+
+    fn round1_package<C: Ciphersuite>(participant: &Participant) -> Round1Package<C> {
+        let commitment: Vec<u8> = participant.vss_commitments.concat().decode_hex().unwrap();
+        let proof_of_knowledge = participant.proof_of_knowledge.decode_hex().unwrap();
+        Round1Package::new(
+            VerifiableSecretSharingCommitment::try_from(commitment.as_slice()).unwrap(),
+            Signature::try_from(proof_of_knowledge.as_slice()).unwrap(),
+        )
+    }
+
+    /// Runs the DKG on behalf of every participant with the polynomials and proofs from the test vectors.
+    /// Returns the key and public key packages of every participant.
+    fn run_dkg_vectors<C: Ciphersuite>(
+        participants: &[Participant],
+    ) -> Vec<(KeyPackage<C>, PublicKeyPackage<C>)> {
+        let round1_packages: BTreeMap<_, _> = participants
+            .iter()
+            .map(|participant| (id(participant.identifier), round1_package::<C>(participant)))
+            .collect();
+
+        participants
+            .iter()
+            .map(|participant| {
+                let identifier = id(participant.identifier);
+                let coefficients = vec![
+                    scalar::<C>(participant.signing_key),
+                    scalar::<C>(participant.coefficient),
+                ];
+                let (secret_package, package) =
+                    part1_with_coefficients(identifier, coefficients, 3).unwrap();
+                assert_eq!(package.commitment, round1_packages[&identifier].commitment);
+                assert!(verify_proof_of_knowledge(identifier, &package));
+
+                let mut others = round1_packages.clone();
+                others.remove(&identifier);
+                let (secret_package, round2_packages) = part2(secret_package, &others).unwrap();
+
+                // Check the shares sent to the other participants.
+                for (receiver, package) in round2_packages.iter() {
+                    let receiver = participants
+                        .iter()
+                        .find(|p| p.identifier == receiver.value())
+                        .unwrap();
+                    let (_, expected) = receiver
+                        .signing_shares
+                        .iter()
+                        .find(|(sender, _)| *sender == participant.identifier)
+                        .unwrap();
+                    assert_eq!(package.signing_share(), H256::from(*expected));
+                }
+
+                let received: BTreeMap<_, _> = participant
+                    .signing_shares
+                    .iter()
+                    .map(|(sender, share)| {
+                        let share = share.decode_hex().unwrap();
+                        (
+                            id(*sender),
+                            Round2Package::try_from(share.as_slice()).unwrap(),
+                        )
+                    })
+                    .collect();
+                part3(&secret_package, &others, &received).unwrap()
+            })
+            .collect()
+    }
+
+    fn check_key_packages<C: Ciphersuite>(
+        participants: &[Participant],
+        packages: &[(KeyPackage<C>, PublicKeyPackage<C>)],
+        verifying_key: &str,
+    ) {
+        let verifying_key = verifying_key.decode_hex().unwrap();
+        for (participant, (key_package, public_key_package)) in participants.iter().zip(packages) {
+            assert_eq!(
+                C::serialize_scalar(key_package.signing_share()),
+                H256::from(participant.signing_share)
+            );
+            assert_eq!(
+                C::serialize_point(key_package.verifying_share()),
+                participant.verifying_share.decode_hex().unwrap()
+            );
+            assert_eq!(
+                C::serialize_point(key_package.verifying_key()),
+                verifying_key
+            );
+            assert_eq!(public_key_package.verifying_key_bytes(), verifying_key);
+            assert_eq!(public_key_package, &packages[0].1);
+        }
+    }
+
+    /// https://github.com/ZcashFoundation/frost/blob/main/frost-ed25519/tests/helpers/vectors_dkg.json
+    #[test]
+    fn test_dkg_vectors_ed25519() {
+        let participants = [
+            Participant {
+                identifier: 1,
+                signing_key: "fc36bff380c95d607cd78080f493dd382df50f7007ec1b150b9bfbd8041fd208",
+                coefficient: "2d2c3e2b558e555b1608838e0ded66cd36d8aaa9ed1e39ce8474855d0825b20e",
+                vss_commitments: [
+                    "db67948a73033b0c886ed757d97352428df05ad5803aff256bc388c9a0772bfe",
+                    "6a25b55a80a287826fbc3ef4d978d15f98f4f573f4d96e798a970780c644f14c",
+                ],
+                proof_of_knowledge: "64c41c1d0417aef33576c23a5150de2921d6249d7086b10012f942405fc08ed51a872dd021db2ac01e9f4182e950324c5f563421bd835f3f514a60c975cab70c",
+                signing_shares: [
+                    (2, "ebb85acf4d46173f519a8680eac89f567d3f4a568843b741b251cc2f3d112b05"),
+                    (3, "f088e2c4b3a7f81836968c642721031ed8828d2e3d532fecaa85c481e445000c"),
+                ],
+                verifying_share: "f6b5e87c902796d8dab6369cbd6d342a32e46c136e9f1f5af2fc385aed804de3",
+                signing_share: "2afd4ef9a27f9e636dd627ae56772951b98f929ebaa13b11ede611e82e9baf08",
+            },
+            Participant {
+                identifier: 2,
+                signing_key: "2b76dcb99b75fbada4ffdc213b1ae4ec7c1b2a5da25d9ea85741a3ab376ca307",
+                coefficient: "ad167472cc332ee98237a1018ea89a7e002420f9e5e518995a10298405a5870d",
+                vss_commitments: [
+                    "17194e192408980956a9cfc388d38456b2e9619c42a57196facd7feaf980a3bd",
+                    "0b560ce2f1130dc7066fc47f6e3f1858ef553faf6bbc19d3cd2a502052f95ae5",
+                ],
+                proof_of_knowledge: "4062dc7fc11144e636f4eddd7f2d8d3e02761671ae913674314a12b91f50bf98e9c410d651c45c3a1766c88524fccb7670de191e12686324c59333e74fc93402",
+                signing_shares: [
+                    (1, "7ce74f90f61fe466fcad9757527aeda99aa565c3e2298eb11484069415693606"),
+                    (3, "7fde55b354d5d8dddc940fe932de5d1a6110b9bc4edeba2db7b32c34074d3a0a"),
+                ],
+                verifying_share: "f326b756ed38b43a94bdac698e044d9e3f3a08a40e7c9d2e5346dd5bfaadf2f5",
+                signing_share: "b9ed88cb30a9ddbc00dbdf7c40d6c76f791989cf9f3119bad899287c5f6c2303",
+            },
+            Participant {
+                identifier: 3,
+                signing_key: "61336fd6127a18548f9709e01b64a8214ff561a02bc8a3aa9e575ccfc13ec60d",
+                coefficient: "7c29694bbb90f21c7d9b7a27eab63911898d2b8e118b8b410c2e68b222073a0e",
+                vss_commitments: [
+                    "0e9c22b9edbcd06757eee5ba3cfeb74e4fa6470932aa1ab689c86f1fe3dde70c",
+                    "0ae6e3e6ff1d612adb5cabb425ee51f2be9952467ce9b6d83095322909a42dd7",
+                ],
+                proof_of_knowledge: "37df5a836ceaaf9f5e3b9ae6a485bad5c15788f3883386fe43f2921bbe2cb987d23683ca18973065a00d69bbf74f7961889b820e64f98878df7844a4e4785907",
+                signing_shares: [
+                    (1, "bc3f985e314b276a3c192343816d7562d17d106dd048c77f99f88bf11d8ee804"),
+                    (2, "6b3e57fab1e74e61aacfd93d4926172a7e878a48540fe97367721e38485b3a00"),
+                ],
+                verifying_share: "6bc91a2755902d955ce220ad0df6fbf57162260949d40bcf5a69cfffec9c085a",
+                signing_share: "35b2b8fad8352f6e6a7c8fee082f45a339a37f0085c1f662c44c3f10903d970d",
+            },
+        ];
+
+        let packages = run_dkg_vectors::<Ed25519>(&participants);
+        check_key_packages(
+            &participants,
+            &packages,
+            "3d9a67b93cec3acad2d32349cf7bdffaa23ae031a81174ddadae1c96bb455f4d",
+        );
+    }
+
+    /// https://github.com/ZcashFoundation/frost/blob/main/frost-secp256k1-tr/tests/helpers/vectors_dkg.json
+    /// The expected keys are tweaked with an unspendable script path.
+    #[test]
+    fn test_dkg_vectors_secp256k1_tr() {
+        let participants = [
+            Participant {
+                identifier: 1,
+                signing_key: "68e3f6904c6043973515a36bf7801a71597da35733f21305d75a5234f06e4529",
+                coefficient: "25d2d840a3e2718a431ec69e14ee8a015b000d43c7a9868060f01d5aa52a19d1",
+                vss_commitments: [
+                    "03e7ba4acb164d2bd5eba4f47b3a788109ddb3f88f1181792424fa332123a25ea8",
+                    "037495e920a1f032916193aa80ea97a4c3a611dec9ab47ccc969deb664f5f88bbe",
+                ],
+                proof_of_knowledge: "191a4ef1851286e2fd6cebd483385452cbb12f43386241854939252c4ed8846b8631f9e69be37ccbd3a0b4593a8f63738747e165a22d0b5786eeb74e59a17837",
+                signing_shares: [
+                    (2, "1dd3cb3e2370e6af22917415f0ad584514807b58b3cc40d2230a26e115f02771"),
+                    (3, "dd25ee86acd01f996618aa0d1153f5e8fbc929a8e8a18b8f0a15f91d087217e2"),
+                ],
+                verifying_share: "02a8bf413b5d7af0e692fba967540cde8009f161a4d721f8c88649c1933bbb7531",
+                signing_share: "f1be455a8ec9ab86ef8438f23a5cfdf70153aa2785d4bebba83e0840403e4bf3",
+            },
+            Participant {
+                identifier: 2,
+                signing_key: "2619be8223b23e0453ddc630a4d164e81f7d8a9e07af33c4d4d02190df8bec13",
+                coefficient: "f7ba0cbbffbea8aaceb3ade54bdbf35bafb1cda15b65ad490e0c63dd069a7c9f",
+                vss_commitments: [
+                    "03ef10370a008cd95e179dc51e2cb7828f30b72d254e5166484f927c84ab326582",
+                    "022ce0dac0db217ba326fbbe3e6132d45e2a4bfa0a0c3790d91eacce9a1c2d6a10",
+                ],
+                proof_of_knowledge: "a319dd51cf64b3896c22f54154812d4ae76cfa95f46f53ef69241fd702456fef32da76cc93d3a541ca495b723e793ee90c32440da5f314e2e58a2dc30550314a",
+                signing_shares: [
+                    (1, "b489a711942526abbb5330a8215d2e740f7dbddec3452006993a8cea3ac278cb"),
+                    (3, "20255dc07b1fb78bdf90bd85fd2389c988c8250faee11826656a09142fa9fc97"),
+                ],
+                verifying_share: "029ecb3a4db28a82e7b8d600d42711b02790dde3f063f0ecec6f812c1c5d7dcefc",
+                signing_share: "9131f1241cd8f95f6439b0f5edc2ecb969a2d3db9c85fe5added77116d41d0ce",
+            },
+            Participant {
+                identifier: 3,
+                signing_key: "9a267f4cde8087a6eca0969425846209b41b515b73195ebbeeef8a991103f1ec",
+                coefficient: "42ff6f39ce4f97f279781378ebcf93df47add84d75882cd31b266e83f76e25f6",
+                vss_commitments: [
+                    "02da186c3863c5600b471a2799cb6f15ae4d8315a2f225c177798880e75ac820a0",
+                    "03e6a36e7fa4b117c1aa428886672e3a35d926bb4c585a9b07d8ee9a3387420067",
+                ],
+                proof_of_knowledge: "6e115d9e63fd15d432b380ccf1ec4ed03340fcf96caeae8985aedb5f905b1a65dc422ffe5878988fbbc55454857736c7755d9c8f5ee6822c8833ea21d54dba36",
+                signing_shares: [
+                    (1, "da5c7f5238079835fe71f746364bb8756a7dcb228aeea686fa2aaa44dfec929c"),
+                    (2, "0d47e4b622ee3804bff8cfe088653efefe865cce0c065aecbf7e318182b89e2d"),
+                ],
+                verifying_share: "02c98b3c2e9f4bde4cf90dc9c7be639e5adda6ea09fc605239880a22cb836f7145",
+                signing_share: "30a59cedaae84737d8ef28f9a128db7bd1f1fd8fb3373dfa139ce5e29a4555a9",
+            },
+        ];
+
+        let packages: Vec<_> = run_dkg_vectors::<Secp256k1Taproot>(&participants)
+            .into_iter()
+            .map(|(key_package, public_key_package)| {
+                (key_package.tweak(None), public_key_package.tweak(None))
+            })
+            .collect();
+        check_key_packages(
+            &participants,
+            &packages,
+            "03849089de77b56bd35fcbfc70bf38e73448131090acc75d538a5cea63cc3dcefe",
+        );
+    }
+
+    #[test]
+    fn test_dkg_invalid_proof_of_knowledge() {
+        let (secret_package_1, package_1) = part1::<Ed25519>(id(1), 3, 2).unwrap();
+        let (_, package_2) = part1::<Ed25519>(id(2), 3, 2).unwrap();
+        let (_, package_3) = part1::<Ed25519>(id(3), 3, 2).unwrap();
+
+        // The proof of knowledge is bound to the participant's identifier.
+        let others = BTreeMap::from([(id(2), package_3), (id(3), package_2)]);
+        assert_eq!(
+            part2(secret_package_1, &others).err().unwrap(),
+            KeyPairError::InvalidSignature
+        );
+
+        let (secret_package_2, _) = part1::<Ed25519>(id(2), 3, 2).unwrap();
+        let others = BTreeMap::from([(id(1), package_1)]);
+        assert_eq!(
+            part2(secret_package_2, &others).err().unwrap(),
+            KeyPairError::InvalidPublicKey
+        );
+    }
+}
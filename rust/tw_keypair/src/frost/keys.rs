@@ -0,0 +1,533 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Key shares generated by a trusted dealer via Shamir secret sharing
+//! as specified in RFC 9591 appendix C.
+//! https://www.rfc-editor.org/rfc/rfc9591.html#name-trusted-dealer-key-generati
+
+use crate::frost::ciphersuite::{Ciphersuite, Secp256k1Taproot};
+use crate::frost::{even_y, Identifier};
+use crate::schnorr::tagged_hash;
+use crate::{KeyPairError, KeyPairResult};
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::{FieldBytes, U256};
+use std::collections::BTreeMap;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+use zeroize::Zeroize;
+
+/// The commitment `[a_0×G, ..., a_{t-1}×G]` to the coefficients of a secret sharing polynomial
+/// that allows the participants to verify their shares.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerifiableSecretSharingCommitment<C: Ciphersuite> {
+    coefficients: Vec<C::Point>,
+}
+
+impl<C: Ciphersuite> VerifiableSecretSharingCommitment<C> {
+    pub(crate) fn from_coefficients(coefficients: &[C::Scalar]) -> Self {
+        VerifiableSecretSharingCommitment {
+            coefficients: coefficients
+                .iter()
+                .map(|coefficient| C::generator() * *coefficient)
+                .collect(),
+        }
+    }
+
+    /// Returns the commitment to the secret `a_0×G`.
+    pub fn verifying_key(&self) -> C::Point {
+        self.coefficients[0]
+    }
+
+    /// Returns the number of the coefficients that is the threshold.
+    pub fn min_signers(&self) -> u16 {
+        self.coefficients.len() as u16
+    }
+
+    /// Returns the verifying share `f(i)×G` of the participant with the given `identifier`.
+    pub fn verifying_share(&self, identifier: Identifier) -> C::Point {
+        let x = identifier.to_scalar::<C>();
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(C::identity(), |acc, coefficient| acc * x + *coefficient)
+    }
+
+    /// Sums the commitments of all the participants up to the group commitment.
+    pub(crate) fn sum<'a>(
+        commitments: impl Iterator<Item = &'a VerifiableSecretSharingCommitment<C>>,
+    ) -> KeyPairResult<Self>
+    where
+        C: 'a,
+    {
+        let mut sum: Option<Vec<C::Point>> = None;
+        for commitment in commitments {
+            sum = Some(match sum {
+                None => commitment.coefficients.clone(),
+                Some(sum) if sum.len() == commitment.coefficients.len() => sum
+                    .iter()
+                    .zip(commitment.coefficients.iter())
+                    .map(|(acc, coefficient)| *acc + *coefficient)
+                    .collect(),
+                Some(_) => return Err(KeyPairError::InvalidPublicKey),
+            });
+        }
+        let coefficients = sum.ok_or(KeyPairError::InvalidPublicKey)?;
+        Ok(VerifiableSecretSharingCommitment { coefficients })
+    }
+}
+
+impl<C: Ciphersuite> ToBytesVec for VerifiableSecretSharingCommitment<C> {
+    fn to_vec(&self) -> Vec<u8> {
+        self.coefficients
+            .iter()
+            .flat_map(C::serialize_point)
+            .collect()
+    }
+}
+
+impl<'a, C: Ciphersuite> TryFrom<&'a [u8]> for VerifiableSecretSharingCommitment<C> {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.is_empty() || bytes.len() % C::POINT_LEN != 0 {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        let coefficients = bytes
+            .chunks(C::POINT_LEN)
+            .map(C::deserialize_point)
+            .collect::<KeyPairResult<_>>()?;
+        Ok(VerifiableSecretSharingCommitment { coefficients })
+    }
+}
+
+/// The secret share `f(i)` of a participant sent by the trusted dealer
+/// along with the commitment to the polynomial.
+#[derive(Clone)]
+pub struct SecretShare<C: Ciphersuite> {
+    identifier: Identifier,
+    signing_share: C::Scalar,
+    commitment: VerifiableSecretSharingCommitment<C>,
+}
+
+impl<C: Ciphersuite> SecretShare<C> {
+    pub fn new(
+        identifier: Identifier,
+        signing_share: &[u8],
+        commitment: VerifiableSecretSharingCommitment<C>,
+    ) -> KeyPairResult<SecretShare<C>> {
+        Ok(SecretShare {
+            identifier,
+            signing_share: C::deserialize_scalar(signing_share)?,
+            commitment,
+        })
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    pub fn signing_share(&self) -> H256 {
+        C::serialize_scalar(&self.signing_share)
+    }
+
+    pub fn commitment(&self) -> &VerifiableSecretSharingCommitment<C> {
+        &self.commitment
+    }
+
+    /// Checks that the share is consistent with the commitment.
+    pub fn verify(&self) -> KeyPairResult<()> {
+        if C::generator() * self.signing_share != self.commitment.verifying_share(self.identifier) {
+            return Err(KeyPairError::InvalidSecretKey);
+        }
+        Ok(())
+    }
+}
+
+impl<C: Ciphersuite> Drop for SecretShare<C> {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+/// Everything a participant needs to sign.
+#[derive(Clone)]
+pub struct KeyPackage<C: Ciphersuite> {
+    identifier: Identifier,
+    signing_share: C::Scalar,
+    verifying_share: C::Point,
+    verifying_key: C::Point,
+    min_signers: u16,
+}
+
+impl<C: Ciphersuite> KeyPackage<C> {
+    pub(crate) fn new(
+        identifier: Identifier,
+        signing_share: C::Scalar,
+        verifying_key: C::Point,
+        min_signers: u16,
+    ) -> KeyPackage<C> {
+        KeyPackage {
+            identifier,
+            signing_share,
+            verifying_share: C::generator() * signing_share,
+            verifying_key,
+            min_signers,
+        }
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    pub(crate) fn signing_share(&self) -> &C::Scalar {
+        &self.signing_share
+    }
+
+    /// Returns the public key of the participant's signing share.
+    pub fn verifying_share(&self) -> &C::Point {
+        &self.verifying_share
+    }
+
+    /// Returns the group public key.
+    pub fn verifying_key(&self) -> &C::Point {
+        &self.verifying_key
+    }
+
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    /// Negates the keys if the ciphersuite requires the group public key to have an even y-coordinate.
+    pub(crate) fn into_even_y(mut self) -> KeyPackage<C> {
+        if !C::has_even_y(&self.verifying_key) {
+            self.signing_share = -self.signing_share;
+            self.verifying_share = -self.verifying_share;
+            self.verifying_key = -self.verifying_key;
+        }
+        self
+    }
+}
+
+impl<C: Ciphersuite> TryFrom<SecretShare<C>> for KeyPackage<C> {
+    type Error = KeyPairError;
+
+    /// Verifies the share and derives the key package from it.
+    fn try_from(share: SecretShare<C>) -> Result<Self, Self::Error> {
+        share.verify()?;
+        Ok(KeyPackage::new(
+            share.identifier,
+            share.signing_share,
+            share.commitment.verifying_key(),
+            share.commitment.min_signers(),
+        ))
+    }
+}
+
+impl<C: Ciphersuite> Drop for KeyPackage<C> {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl KeyPackage<Secp256k1Taproot> {
+    /// Tweaks the group key to a taproot output key committing to the given script tree
+    /// `merkle_root`, the same way as [`crate::schnorr::PrivateKey::tweak`] does.
+    /// The group key is considered as the taproot internal key.
+    pub fn tweak(self, merkle_root: Option<H256>) -> KeyPackage<Secp256k1Taproot> {
+        let tweak = taproot_tweak(&self.verifying_key, merkle_root);
+        let mut key_package = self.into_even_y();
+        key_package.signing_share += tweak;
+        key_package.verifying_share += Secp256k1Taproot::generator() * tweak;
+        key_package.verifying_key += Secp256k1Taproot::generator() * tweak;
+        key_package
+    }
+}
+
+/// The public keys of the group and all the participants, used to verify and aggregate the signature shares.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKeyPackage<C: Ciphersuite> {
+    verifying_shares: BTreeMap<Identifier, C::Point>,
+    verifying_key: C::Point,
+}
+
+impl<C: Ciphersuite> PublicKeyPackage<C> {
+    pub fn new(
+        verifying_shares: BTreeMap<Identifier, C::Point>,
+        verifying_key: C::Point,
+    ) -> PublicKeyPackage<C> {
+        PublicKeyPackage {
+            verifying_shares,
+            verifying_key,
+        }
+    }
+
+    pub(crate) fn from_commitment(
+        identifiers: impl Iterator<Item = Identifier>,
+        commitment: &VerifiableSecretSharingCommitment<C>,
+    ) -> PublicKeyPackage<C> {
+        PublicKeyPackage {
+            verifying_shares: identifiers
+                .map(|identifier| (identifier, commitment.verifying_share(identifier)))
+                .collect(),
+            verifying_key: commitment.verifying_key(),
+        }
+    }
+
+    pub fn verifying_shares(&self) -> &BTreeMap<Identifier, C::Point> {
+        &self.verifying_shares
+    }
+
+    /// Returns the group public key.
+    pub fn verifying_key(&self) -> &C::Point {
+        &self.verifying_key
+    }
+
+    /// Returns the serialized group public key.
+    pub fn verifying_key_bytes(&self) -> Data {
+        C::serialize_point(&self.verifying_key)
+    }
+
+    /// Negates the keys if the ciphersuite requires the group public key to have an even y-coordinate.
+    pub(crate) fn into_even_y(mut self) -> PublicKeyPackage<C> {
+        if !C::has_even_y(&self.verifying_key) {
+            self.verifying_key = -self.verifying_key;
+            self.verifying_shares
+                .values_mut()
+                .for_each(|verifying_share| *verifying_share = -*verifying_share);
+        }
+        self
+    }
+}
+
+impl PublicKeyPackage<Secp256k1Taproot> {
+    /// Tweaks the group key the same way as [`KeyPackage::tweak`] does.
+    pub fn tweak(self, merkle_root: Option<H256>) -> PublicKeyPackage<Secp256k1Taproot> {
+        let tweak = Secp256k1Taproot::generator() * taproot_tweak(&self.verifying_key, merkle_root);
+        let mut public_key_package = self.into_even_y();
+        public_key_package.verifying_key += tweak;
+        public_key_package
+            .verifying_shares
+            .values_mut()
+            .for_each(|verifying_share| *verifying_share += tweak);
+        public_key_package
+    }
+}
+
+/// The secret shares to be sent to the participants, and the public key package of the group.
+pub type DealerOutput<C> = (BTreeMap<Identifier, SecretShare<C>>, PublicKeyPackage<C>);
+
+/// Generates a random group secret and splits it into `max_signers` shares,
+/// any `min_signers` of which can sign.
+pub fn generate_with_dealer<C: Ciphersuite>(
+    max_signers: u16,
+    min_signers: u16,
+) -> KeyPairResult<DealerOutput<C>> {
+    let mut secret = C::random_scalar();
+    let shares = split_scalar(&secret, max_signers, min_signers);
+    secret.zeroize();
+    shares
+}
+
+/// Splits the serialized group `secret` scalar into `max_signers` shares,
+/// any `min_signers` of which can sign.
+/// The participants are assigned the identifiers from `1` to `max_signers`.
+///
+/// # Important
+///
+/// An Ed25519 private key is a seed the signing scalar is derived from, so it can't be split as is.
+pub fn split<C: Ciphersuite>(
+    secret: &H256,
+    max_signers: u16,
+    min_signers: u16,
+) -> KeyPairResult<DealerOutput<C>> {
+    let mut secret = C::deserialize_scalar(secret.as_slice())?;
+    let shares = split_scalar(&secret, max_signers, min_signers);
+    secret.zeroize();
+    shares
+}
+
+fn split_scalar<C: Ciphersuite>(
+    secret: &C::Scalar,
+    max_signers: u16,
+    min_signers: u16,
+) -> KeyPairResult<DealerOutput<C>> {
+    validate_num_of_signers(max_signers, min_signers)?;
+    if *secret == C::zero() {
+        return Err(KeyPairError::InvalidSecretKey);
+    }
+
+    let mut coefficients: Vec<_> = (1..min_signers).map(|_| C::random_scalar()).collect();
+    let shares = split_with_coefficients(secret, &coefficients, max_signers);
+    coefficients.zeroize();
+    shares
+}
+
+/// RFC 9591 `secret_share_shard`.
+fn split_with_coefficients<C: Ciphersuite>(
+    secret: &C::Scalar,
+    coefficients: &[C::Scalar],
+    max_signers: u16,
+) -> KeyPairResult<DealerOutput<C>> {
+    let mut polynomial = vec![*secret];
+    polynomial.extend_from_slice(coefficients);
+    let commitment = VerifiableSecretSharingCommitment::from_coefficients(&polynomial);
+
+    let identifiers = (1..=max_signers)
+        .map(Identifier::try_from)
+        .collect::<KeyPairResult<Vec<_>>>()?;
+    let shares = identifiers
+        .iter()
+        .map(|identifier| {
+            let share = SecretShare {
+                identifier: *identifier,
+                signing_share: evaluate_polynomial::<C>(*identifier, &polynomial),
+                commitment: commitment.clone(),
+            };
+            (*identifier, share)
+        })
+        .collect();
+    polynomial.zeroize();
+
+    let public_key_package =
+        PublicKeyPackage::from_commitment(identifiers.into_iter(), &commitment);
+    Ok((shares, public_key_package))
+}
+
+/// Evaluates the polynomial with the given coefficients at the `identifier` via Horner's method.
+pub(crate) fn evaluate_polynomial<C: Ciphersuite>(
+    identifier: Identifier,
+    coefficients: &[C::Scalar],
+) -> C::Scalar {
+    let x = identifier.to_scalar::<C>();
+    coefficients
+        .iter()
+        .rev()
+        .fold(C::zero(), |acc, coefficient| acc * x + *coefficient)
+}
+
+pub(crate) fn validate_num_of_signers(max_signers: u16, min_signers: u16) -> KeyPairResult<()> {
+    if min_signers < 2 || max_signers < min_signers {
+        return Err(KeyPairError::InvalidSecretKey);
+    }
+    Ok(())
+}
+
+/// BIP-341 `TapTweak` of the even y-coordinate internal key.
+fn taproot_tweak(internal_key: &k256::ProjectivePoint, merkle_root: Option<H256>) -> k256::Scalar {
+    let internal_key = even_y::<Secp256k1Taproot>(*internal_key);
+    let mut data: Vec<u8> = internal_key.to_affine().x().to_vec();
+    if let Some(merkle_root) = merkle_root {
+        data.extend_from_slice(merkle_root.as_slice());
+    }
+    let tweak = tagged_hash(b"TapTweak", &data);
+    <k256::Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(tweak.take()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frost::ciphersuite::Ed25519;
+
+    fn scalar<C: Ciphersuite>(hex: &'static str) -> C::Scalar {
+        C::deserialize_scalar(H256::from(hex).as_slice()).unwrap()
+    }
+
+    /// https://www.rfc-editor.org/rfc/rfc9591.html#name-frosted25519-sha-512-2
+    #[test]
+    fn test_split_with_coefficients_ed25519() {
+        let secret =
+            scalar::<Ed25519>("7b1c33d3f5291d85de664833beb1ad469f7fb6025a0ec78b3a790c6e13a98304");
+        let coefficient =
+            scalar::<Ed25519>("178199860edd8c62f5212ee91eff1295d0d670ab4ed4506866bae57e7030b204");
+        let (shares, public_key_package) =
+            split_with_coefficients::<Ed25519>(&secret, &[coefficient], 3).unwrap();
+
+        let expected = [
+            "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509",
+            "a91e66e012e4364ac9aaa405fcafd370402d9859f7b6685c07eed76bf409e80d",
+            "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02",
+        ];
+        for (share, expected) in shares.values().zip(expected) {
+            share.verify().unwrap();
+            assert_eq!(share.signing_share(), H256::from(expected));
+        }
+        assert_eq!(
+            H256::try_from(public_key_package.verifying_key_bytes().as_slice()).unwrap(),
+            H256::from("15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673")
+        );
+    }
+
+    #[test]
+    fn test_split_with_coefficients_secp256k1_tr() {
+        let secret = scalar::<Secp256k1Taproot>(
+            "0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114",
+        );
+        let coefficient = scalar::<Secp256k1Taproot>(
+            "fbf85eadae3058ea14f19148bb72b45e4399c0b16028acaf0395c9b03c823579",
+        );
+        let (shares, public_key_package) =
+            split_with_coefficients::<Secp256k1Taproot>(&secret, &[coefficient], 3).unwrap();
+
+        let expected = [
+            "08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c",
+            "04f0feac2edcedc6ce1253b7fab8c86b856a797f44d83d82a385554e6e401984",
+            "00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc",
+        ];
+        for (share, expected) in shares.values().zip(expected) {
+            share.verify().unwrap();
+            assert_eq!(share.signing_share(), H256::from(expected));
+        }
+        assert_eq!(
+            tw_hash::H264::try_from(public_key_package.verifying_key_bytes().as_slice()).unwrap(),
+            tw_hash::H264::from(
+                "02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f"
+            )
+        );
+    }
+
+    #[test]
+    fn test_secret_share_invalid() {
+        let (shares, _) = generate_with_dealer::<Ed25519>(3, 2).unwrap();
+        let share = &shares[&Identifier::try_from(1).unwrap()];
+        let other = &shares[&Identifier::try_from(2).unwrap()];
+
+        let forged = SecretShare::new(
+            share.identifier(),
+            other.signing_share().as_slice(),
+            share.commitment().clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            KeyPackage::try_from(forged).err(),
+            Some(KeyPairError::InvalidSecretKey)
+        );
+    }
+
+    #[test]
+    fn test_generate_with_dealer_invalid_num_of_signers() {
+        assert!(generate_with_dealer::<Ed25519>(3, 1).is_err());
+        assert!(generate_with_dealer::<Ed25519>(2, 3).is_err());
+    }
+
+    #[test]
+    fn test_tweak_matches_schnorr() {
+        let secret = H256::from("0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114");
+        let (_, public_key_package) = split::<Secp256k1Taproot>(&secret, 3, 2).unwrap();
+        let merkle_root =
+            H256::from("0101010101010101010101010101010101010101010101010101010101010101");
+
+        let expected = crate::schnorr::PrivateKey::try_from(secret.as_slice())
+            .unwrap()
+            .public()
+            .tweak(Some(merkle_root))
+            .x_only();
+        let tweaked = public_key_package.tweak(Some(merkle_root));
+        assert_eq!(
+            &tweaked.verifying_key_bytes()[1..],
+            expected.bytes().as_slice()
+        );
+    }
+}
@@ -0,0 +1,673 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! FROST threshold Schnorr signatures as specified in RFC 9591.
+//! https://www.rfc-editor.org/rfc/rfc9591.html
+//!
+//! Any `min_signers` out of `max_signers` participants can produce a signature that is
+//! indistinguishable from a single-signer one:
+//! * [`ciphersuite::Ed25519`] produces standard Ed25519 signatures.
+//! * [`ciphersuite::Secp256k1Taproot`] produces BIP-340 signatures that can spend a taproot output
+//!   via the key path once the key packages are tweaked with [`keys::KeyPackage::tweak`].
+//!
+//! 1. The key shares are generated by a trusted dealer via [`keys::generate_with_dealer`],
+//!    or by the participants themselves via [`dkg::part1`], [`dkg::part2`] and [`dkg::part3`].
+//! 2. Every signer generates [`round1::SigningNonces`] and shares its [`round1::SigningCommitments`].
+//! 3. The coordinator collects the commitments into a [`SigningPackage`],
+//!    and every signer produces a [`round2::SignatureShare`] via [`round2::sign`].
+//! 4. The coordinator aggregates the signature shares via [`aggregate`].
+
+use crate::frost::ciphersuite::Ciphersuite;
+use crate::frost::keys::PublicKeyPackage;
+use crate::frost::round1::SigningCommitments;
+use crate::frost::round2::SignatureShare;
+use crate::{KeyPairError, KeyPairResult};
+use std::collections::BTreeMap;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+
+pub mod ciphersuite;
+pub mod dkg;
+pub mod keys;
+pub mod round1;
+pub mod round2;
+
+/// A non-zero participant identifier.
+/// The participants are ordered by their identifiers whenever a list of them is encoded.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Identifier(u16);
+
+impl Identifier {
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+
+    pub(crate) fn to_scalar<C: Ciphersuite>(self) -> C::Scalar {
+        C::scalar_from_u16(self.0)
+    }
+
+    pub(crate) fn serialize<C: Ciphersuite>(self) -> H256 {
+        C::serialize_scalar(&self.to_scalar::<C>())
+    }
+}
+
+impl TryFrom<u16> for Identifier {
+    type Error = KeyPairError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        // The share of the zero identifier is the group secret itself.
+        if value == 0 {
+            return Err(KeyPairError::InvalidSecretKey);
+        }
+        Ok(Identifier(value))
+    }
+}
+
+/// The commitments of the signers and the message to be signed,
+/// sent by the coordinator to every signer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningPackage<C: Ciphersuite> {
+    commitments: BTreeMap<Identifier, SigningCommitments<C>>,
+    message: Data,
+}
+
+impl<C: Ciphersuite> SigningPackage<C> {
+    pub fn new(
+        commitments: BTreeMap<Identifier, SigningCommitments<C>>,
+        message: Data,
+    ) -> SigningPackage<C> {
+        SigningPackage {
+            commitments,
+            message,
+        }
+    }
+
+    pub fn commitments(&self) -> &BTreeMap<Identifier, SigningCommitments<C>> {
+        &self.commitments
+    }
+
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// RFC 9591 `encode_group_commitment_list`.
+    fn encode_commitments(&self) -> Data {
+        let mut encoded = Data::new();
+        for (identifier, commitments) in self.commitments.iter() {
+            encoded.extend_from_slice(identifier.serialize::<C>().as_slice());
+            encoded.extend_from_slice(&commitments.to_vec());
+        }
+        encoded
+    }
+}
+
+/// A Schnorr signature `(R, z)` serialized according to the ciphersuite.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Signature<C: Ciphersuite> {
+    r: C::Point,
+    z: C::Scalar,
+}
+
+impl<C: Ciphersuite> Signature<C> {
+    /// Checks the signature of the `message` against the `verifying_key`.
+    pub fn verify(&self, message: &[u8], verifying_key: &C::Point) -> bool {
+        let verifying_key = even_y::<C>(*verifying_key);
+        let challenge = C::challenge(&self.r, &verifying_key, message);
+        C::generator() * self.z == self.r + verifying_key * challenge
+    }
+}
+
+impl<C: Ciphersuite> ToBytesVec for Signature<C> {
+    fn to_vec(&self) -> Vec<u8> {
+        C::serialize_signature(&self.r, &self.z)
+    }
+}
+
+impl<'a, C: Ciphersuite> TryFrom<&'a [u8]> for Signature<C> {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let (r, z) = C::deserialize_signature(bytes)?;
+        Ok(Signature { r, z })
+    }
+}
+
+/// RFC 9591 `verify_signature_share`.
+/// Checks the signature share of the signer with the given `identifier`.
+pub fn verify_signature_share<C: Ciphersuite>(
+    identifier: Identifier,
+    signature_share: &SignatureShare<C>,
+    signing_package: &SigningPackage<C>,
+    public_key_package: &PublicKeyPackage<C>,
+) -> bool {
+    let public_key_package = public_key_package.clone().into_even_y();
+    let Some(verifying_share) = public_key_package.verifying_shares().get(&identifier) else {
+        return false;
+    };
+    let Ok(context) = SigningContext::new(signing_package, public_key_package.verifying_key())
+    else {
+        return false;
+    };
+    context.verify_share(
+        identifier,
+        signature_share,
+        verifying_share,
+        signing_package,
+    )
+}
+
+/// RFC 9591 `aggregate`.
+/// Aggregates the signature shares into a signature valid for the group verifying key.
+///
+/// # Important
+///
+/// The signature shares should be verified beforehand via [`verify_signature_share`]
+/// to find out which signer has misbehaved in case of an error.
+pub fn aggregate<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier, SignatureShare<C>>,
+    public_key_package: &PublicKeyPackage<C>,
+) -> KeyPairResult<Signature<C>> {
+    if !signature_shares
+        .keys()
+        .eq(signing_package.commitments.keys())
+    {
+        return Err(KeyPairError::SigningError);
+    }
+
+    let public_key_package = public_key_package.clone().into_even_y();
+    let verifying_key = public_key_package.verifying_key();
+    let context = SigningContext::new(signing_package, verifying_key)?;
+
+    let z = signature_shares
+        .values()
+        .fold(C::zero(), |z, share| z + share.share);
+    let signature = Signature {
+        r: even_y::<C>(context.group_commitment),
+        z,
+    };
+
+    if !signature.verify(signing_package.message(), verifying_key) {
+        return Err(KeyPairError::SignatureVerifyError);
+    }
+    Ok(signature)
+}
+
+/// The values derived from a [`SigningPackage`] that every signer computes the same way.
+pub(crate) struct SigningContext<C: Ciphersuite> {
+    binding_factors: BTreeMap<Identifier, C::Scalar>,
+    group_commitment: C::Point,
+    challenge: C::Scalar,
+}
+
+impl<C: Ciphersuite> SigningContext<C> {
+    /// `verifying_key` is expected to have an even y-coordinate if the ciphersuite requires so.
+    pub(crate) fn new(
+        signing_package: &SigningPackage<C>,
+        verifying_key: &C::Point,
+    ) -> KeyPairResult<SigningContext<C>> {
+        if signing_package.commitments.is_empty() {
+            return Err(KeyPairError::SigningError);
+        }
+
+        // RFC 9591 `compute_binding_factors`.
+        let mut prefix = C::serialize_point(verifying_key);
+        prefix.extend_from_slice(&C::h4(signing_package.message()));
+        prefix.extend_from_slice(&C::h5(&signing_package.encode_commitments()));
+
+        let binding_factors: BTreeMap<_, _> = signing_package
+            .commitments
+            .keys()
+            .map(|identifier| {
+                let mut input = prefix.clone();
+                input.extend_from_slice(identifier.serialize::<C>().as_slice());
+                (*identifier, C::h1(&input))
+            })
+            .collect();
+
+        // RFC 9591 `compute_group_commitment`.
+        let group_commitment = signing_package.commitments.iter().fold(
+            C::identity(),
+            |group_commitment, (identifier, commitments)| {
+                group_commitment + commitments.share(binding_factors[identifier])
+            },
+        );
+        if group_commitment == C::identity() {
+            return Err(KeyPairError::SigningError);
+        }
+
+        let challenge = C::challenge(
+            &even_y::<C>(group_commitment),
+            verifying_key,
+            signing_package.message(),
+        );
+        Ok(SigningContext {
+            binding_factors,
+            group_commitment,
+            challenge,
+        })
+    }
+
+    pub(crate) fn binding_factor(&self, identifier: &Identifier) -> KeyPairResult<C::Scalar> {
+        self.binding_factors
+            .get(identifier)
+            .copied()
+            .ok_or(KeyPairError::SigningError)
+    }
+
+    pub(crate) fn challenge(&self) -> C::Scalar {
+        self.challenge
+    }
+
+    /// Whether the signers must negate their nonces.
+    pub(crate) fn negate_nonces(&self) -> bool {
+        !C::has_even_y(&self.group_commitment)
+    }
+
+    /// `verifying_share` is expected to be consistent with the even y-coordinate verifying key.
+    pub(crate) fn verify_share(
+        &self,
+        identifier: Identifier,
+        signature_share: &SignatureShare<C>,
+        verifying_share: &C::Point,
+        signing_package: &SigningPackage<C>,
+    ) -> bool {
+        let (Some(commitments), Ok(binding_factor)) = (
+            signing_package.commitments.get(&identifier),
+            self.binding_factor(&identifier),
+        ) else {
+            return false;
+        };
+        let Ok(lambda) = lagrange_coefficient::<C>(identifier, signing_package.commitments.keys())
+        else {
+            return false;
+        };
+
+        let commitment_share = commitments.share(binding_factor);
+        let commitment_share = if self.negate_nonces() {
+            -commitment_share
+        } else {
+            commitment_share
+        };
+        C::generator() * signature_share.share
+            == commitment_share + *verifying_share * (self.challenge * lambda)
+    }
+}
+
+/// RFC 9591 `derive_interpolating_value`.
+/// Returns the Lagrange coefficient of the `identifier` at zero for the given set of `signers`.
+pub(crate) fn lagrange_coefficient<'a, C: Ciphersuite>(
+    identifier: Identifier,
+    signers: impl Iterator<Item = &'a Identifier>,
+) -> KeyPairResult<C::Scalar> {
+    let x_i = identifier.to_scalar::<C>();
+
+    let mut numerator = C::one();
+    let mut denominator = C::one();
+    let mut contains = false;
+    for signer in signers {
+        if *signer == identifier {
+            contains = true;
+            continue;
+        }
+        let x_j = signer.to_scalar::<C>();
+        numerator = numerator * x_j;
+        denominator = denominator * (x_j - x_i);
+    }
+    if !contains {
+        return Err(KeyPairError::SigningError);
+    }
+
+    let inverted = C::invert(&denominator).ok_or(KeyPairError::SigningError)?;
+    Ok(numerator * inverted)
+}
+
+/// Negates the point if the ciphersuite requires an even y-coordinate.
+pub(crate) fn even_y<C: Ciphersuite>(point: C::Point) -> C::Point {
+    if C::has_even_y(&point) {
+        point
+    } else {
+        -point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frost::ciphersuite::{Ed25519, Secp256k1Taproot};
+    use crate::frost::keys::{generate_with_dealer, KeyPackage};
+    use crate::frost::round1::SigningNonces;
+    use crate::traits::VerifyingKeyTrait;
+    use crate::{ed25519, schnorr};
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    struct Signer {
+        identifier: u16,
+        signing_share: &'static str,
+        hiding_nonce_randomness: &'static str,
+        binding_nonce_randomness: &'static str,
+        commitments: &'static str,
+        sig_share: &'static str,
+    }
+
+    struct SigningVector {
+        verifying_key: &'static str,
+        message: &'static str,
+        signers: [Signer; 2],
+        signature: &'static str,
+    }
+
+    fn id(value: u16) -> Identifier {
+        Identifier::try_from(value).unwrap()
+    }
+
+    /// Signs the message by the given key packages via trusted-dealer or DKG.
+    fn sign_with<C: Ciphersuite>(
+        key_packages: &[&KeyPackage<C>],
+        public_key_package: &PublicKeyPackage<C>,
+        message: &[u8],
+    ) -> KeyPairResult<Signature<C>> {
+        let nonces: BTreeMap<_, _> = key_packages
+            .iter()
+            .map(|key_package| {
+                (
+                    key_package.identifier(),
+                    SigningNonces::generate(key_package),
+                )
+            })
+            .collect();
+        let commitments = nonces
+            .iter()
+            .map(|(identifier, nonces)| (*identifier, *nonces.commitments()))
+            .collect();
+        let signing_package = SigningPackage::new(commitments, message.to_vec());
+
+        let mut signature_shares = BTreeMap::new();
+        for (identifier, nonces) in nonces {
+            let key_package = key_packages
+                .iter()
+                .find(|key_package| key_package.identifier() == identifier)
+                .unwrap();
+            let share = round2::sign(&signing_package, nonces, key_package)?;
+            assert!(verify_signature_share(
+                identifier,
+                &share,
+                &signing_package,
+                public_key_package
+            ));
+            signature_shares.insert(identifier, share);
+        }
+        aggregate(&signing_package, &signature_shares, public_key_package)
+    }
+
+    fn check_signing_vector<C: Ciphersuite>(vector: &SigningVector) {
+        let verifying_key =
+            C::deserialize_point(&vector.verifying_key.decode_hex().unwrap()).unwrap();
+        let key_packages: Vec<_> = vector
+            .signers
+            .iter()
+            .map(|signer| {
+                let share =
+                    C::deserialize_scalar(&signer.signing_share.decode_hex().unwrap()).unwrap();
+                KeyPackage::<C>::new(id(signer.identifier), share, verifying_key, 2)
+            })
+            .collect();
+        let public_key_package = PublicKeyPackage::new(
+            key_packages
+                .iter()
+                .map(|key_package| (key_package.identifier(), *key_package.verifying_share()))
+                .collect(),
+            verifying_key,
+        );
+
+        let mut nonces = BTreeMap::new();
+        for (signer, key_package) in vector.signers.iter().zip(key_packages.iter()) {
+            let signer_nonces = SigningNonces::generate_with_rand(
+                H256::from(signer.hiding_nonce_randomness),
+                H256::from(signer.binding_nonce_randomness),
+                key_package,
+            );
+            assert_eq!(
+                signer_nonces.commitments().to_vec().to_hex(),
+                signer.commitments
+            );
+            nonces.insert(key_package.identifier(), signer_nonces);
+        }
+
+        let commitments = nonces
+            .iter()
+            .map(|(identifier, nonces)| (*identifier, *nonces.commitments()))
+            .collect();
+        let message = vector.message.decode_hex().unwrap();
+        let signing_package = SigningPackage::new(commitments, message.clone());
+
+        let mut signature_shares = BTreeMap::new();
+        for (signer, key_package) in vector.signers.iter().zip(key_packages.iter()) {
+            let signer_nonces = nonces.remove(&key_package.identifier()).unwrap();
+            let share = round2::sign(&signing_package, signer_nonces, key_package).unwrap();
+            assert_eq!(share.bytes(), H256::from(signer.sig_share));
+            assert!(verify_signature_share(
+                key_package.identifier(),
+                &share,
+                &signing_package,
+                &public_key_package
+            ));
+            signature_shares.insert(key_package.identifier(), share);
+        }
+
+        let signature =
+            aggregate(&signing_package, &signature_shares, &public_key_package).unwrap();
+        assert_eq!(signature.to_vec().to_hex(), vector.signature);
+        assert!(signature.verify(&message, &verifying_key));
+    }
+
+    /// https://www.rfc-editor.org/rfc/rfc9591.html#name-frosted25519-sha-512-2
+    #[test]
+    fn test_sign_vector_ed25519() {
+        check_signing_vector::<Ed25519>(&SigningVector {
+            verifying_key: "15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673",
+            message: "74657374",
+            signers: [
+                Signer {
+                    identifier: 1,
+                    signing_share: "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509",
+                    hiding_nonce_randomness: "06894e04ee4aceec8619d5f6a0a180e2f47194d2ac306cba586b555e7c48d765",
+                    binding_nonce_randomness: "40d6f879ff22e22409f7d808fed81f37118e7d3e4af71c0f44c60207553bcbce",
+                    commitments: "9b116f12589591a7e23fe8048059ab10ab48e67739e7a2fb3890f61a7999478cc39b66b7dfccb122da24f13587f9a08c4347cae70046ca15169adf90ba65854d",
+                    sig_share: "60997f0142e43e8005027fe5ab7447dac00d22c2d7ddd9571a02613ba7d81c08",
+                },
+                Signer {
+                    identifier: 3,
+                    signing_share: "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02",
+                    hiding_nonce_randomness: "fa5d950626782aade9e33fa781376d4888c2d1de7c37518bc248eb818ed0cdde",
+                    binding_nonce_randomness: "7459a4d14ded0e365b085271be8dc6600d5b88f3978a2174297ffa32001a5afc",
+                    commitments: "e679a2a971748ccfaabead4dbe8ac1def61275c186c79d471e1e45091ad1e687b2a942478453fabb6bd3181c56ba657413447b4136e1daea2484d396d1a516b3",
+                    sig_share: "79390e78bc59699c7af831f8f5fb478ec871a85f561a8641b5670ac4443f720f",
+                },
+            ],
+            signature: "154fb694ee7fcb37bf2381d94488c2a84b03b3352ad085feca81ad26d45852b7ecfe971ce4da95c4a95db93ac376b053897fca212ef85f99cf696bffeb178f07",
+        });
+    }
+
+    /// https://github.com/ZcashFoundation/frost/blob/main/frost-secp256k1-tr/tests/helpers/vectors.json
+    #[test]
+    fn test_sign_vector_secp256k1_tr() {
+        check_signing_vector::<Secp256k1Taproot>(&SigningVector {
+            verifying_key: "02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f",
+            message: "74657374",
+            signers: [
+                Signer {
+                    identifier: 1,
+                    signing_share: "08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c",
+                    hiding_nonce_randomness: "bda8e748e599187762cff956f03dc6ea13fc8e04491a0427b7e6e78600f41c52",
+                    binding_nonce_randomness: "2ca682429bf05df435b9927b8edb1d748278f3e42fa11ef358e49bbf4a1b780d",
+                    commitments: "024e34ab3a7ad6b4563dbfe97e9f1206b3378cceb2502491ed0fb709765e1e5ba803d4b1f3a61dc67e64dfb4abfccabb712f1f6914a6ec9b67749d171370453192cb",
+                    sig_share: "2ffc305d1694fd84108b84d98306a1af807c6ad9bc3a2d8e448a09643202a15b",
+                },
+                Signer {
+                    identifier: 3,
+                    signing_share: "00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc",
+                    hiding_nonce_randomness: "70818dd5170672c4a4285fd593d4f222417f941f3118e1244955e7a1098a35d8",
+                    binding_nonce_randomness: "74ca2da071ed4a2a6cad5087d6758b48a558ab5861c61117fee05757e4b1309e",
+                    commitments: "02d768658a1b94225645401a1512b803657770c7a21bf9ccccccfa09930a44951b034570a4e5217ee8770a28401185f50b4fce4d3f3933a3af9df7ab39b42381d0eb",
+                    sig_share: "a8c392566ea29e852b4080a028bf5547166c87e703e4fb7136d4ebef65f99b3f",
+                },
+            ],
+            signature: "0c776a9516a77808b70a31e74f1464814a6fcf897fb3a6bd84c7a9a9a7a5bcb8d8bfc2b385379c093bcc0579abc5f6f696e8f2c0c01f28ff7b5ef55397fc3c9a",
+        });
+    }
+
+    #[test]
+    fn test_sign_with_dealer_ed25519() {
+        let (shares, public_key_package) = generate_with_dealer::<Ed25519>(3, 2).unwrap();
+        let key_packages: Vec<_> = shares
+            .into_values()
+            .map(|share| KeyPackage::try_from(share).unwrap())
+            .collect();
+        let public = ed25519::sha512::PublicKey::try_from(
+            public_key_package.verifying_key_bytes().as_slice(),
+        )
+        .unwrap();
+
+        let message = b"Hello, FROST!".to_vec();
+        for signers in [[0, 1], [0, 2], [1, 2]] {
+            let signers: Vec<_> = signers.iter().map(|i| &key_packages[*i]).collect();
+            let signature = sign_with(&signers, &public_key_package, &message).unwrap();
+
+            let signature = ed25519::Signature::try_from(signature.to_vec().as_slice()).unwrap();
+            assert!(public.verify(signature, message.clone()));
+        }
+    }
+
+    #[test]
+    fn test_sign_with_dkg_secp256k1_tr() {
+        let identifiers: Vec<_> = (1..=3).map(id).collect();
+        let (secret_packages, round1_packages): (Vec<_>, BTreeMap<_, _>) = identifiers
+            .iter()
+            .map(|identifier| {
+                let (secret_package, package) =
+                    dkg::part1::<Secp256k1Taproot>(*identifier, 3, 2).unwrap();
+                (secret_package, (*identifier, package))
+            })
+            .unzip();
+
+        let others = |identifier: &Identifier| {
+            let mut others = round1_packages.clone();
+            others.remove(identifier);
+            others
+        };
+
+        let mut round2_secret_packages = Vec::new();
+        let mut round2_packages = BTreeMap::new();
+        for (identifier, secret_package) in identifiers.iter().zip(secret_packages) {
+            let (secret_package, packages) =
+                dkg::part2(secret_package, &others(identifier)).unwrap();
+            round2_secret_packages.push(secret_package);
+            for (receiver, package) in packages {
+                round2_packages.insert((*identifier, receiver), package);
+            }
+        }
+
+        let (key_packages, public_key_packages): (Vec<_>, Vec<_>) = identifiers
+            .iter()
+            .zip(round2_secret_packages.iter())
+            .map(|(identifier, secret_package)| {
+                let received = round2_packages
+                    .iter()
+                    .filter(|((_, receiver), _)| receiver == identifier)
+                    .map(|((sender, _), package)| (*sender, package.clone()))
+                    .collect();
+                let (key_package, public_key_package) =
+                    dkg::part3(secret_package, &others(identifier), &received).unwrap();
+                // Spend a taproot output via the key path.
+                (key_package.tweak(None), public_key_package.tweak(None))
+            })
+            .unzip();
+        assert!(public_key_packages
+            .iter()
+            .all(|package| *package == public_key_packages[0]));
+        let public_key_package = &public_key_packages[0];
+
+        let message =
+            H256::from("4d8b47c9b2bc7b3a3f4d24c7e8b41f3cf7b3e3a5ad7b0c2f3e1d3c6a8b9f0e1d");
+        let signature = sign_with(
+            &[&key_packages[2], &key_packages[0]],
+            public_key_package,
+            message.as_slice(),
+        )
+        .unwrap();
+
+        let public =
+            schnorr::PublicKey::try_from(public_key_package.verifying_key_bytes().as_slice())
+                .unwrap();
+        let signature = schnorr::Signature::try_from(signature.to_vec().as_slice()).unwrap();
+        assert!(public.verify(signature, message));
+    }
+
+    #[test]
+    fn test_sign_invalid_signing_package() {
+        let (shares, public_key_package) = generate_with_dealer::<Secp256k1Taproot>(3, 2).unwrap();
+        let key_packages: Vec<_> = shares
+            .into_values()
+            .map(|share| KeyPackage::try_from(share).unwrap())
+            .collect();
+
+        // Not enough signers.
+        let nonces = SigningNonces::generate(&key_packages[0]);
+        let commitments = BTreeMap::from([(key_packages[0].identifier(), *nonces.commitments())]);
+        let signing_package = SigningPackage::new(commitments, b"message".to_vec());
+        assert_eq!(
+            round2::sign(&signing_package, nonces, &key_packages[0]).unwrap_err(),
+            KeyPairError::SigningError
+        );
+
+        // The commitments don't match the nonces.
+        let nonces = SigningNonces::generate(&key_packages[0]);
+        let other_nonces = SigningNonces::generate(&key_packages[0]);
+        let commitments = BTreeMap::from([
+            (key_packages[0].identifier(), *other_nonces.commitments()),
+            (
+                key_packages[1].identifier(),
+                *SigningNonces::generate(&key_packages[1]).commitments(),
+            ),
+        ]);
+        let signing_package = SigningPackage::new(commitments, b"message".to_vec());
+        assert_eq!(
+            round2::sign(&signing_package, nonces, &key_packages[0]).unwrap_err(),
+            KeyPairError::SigningError
+        );
+
+        // A signature share is missing.
+        let nonces_0 = SigningNonces::generate(&key_packages[0]);
+        let nonces_1 = SigningNonces::generate(&key_packages[1]);
+        let commitments = BTreeMap::from([
+            (key_packages[0].identifier(), *nonces_0.commitments()),
+            (key_packages[1].identifier(), *nonces_1.commitments()),
+        ]);
+        let signing_package = SigningPackage::new(commitments, b"message".to_vec());
+        let share = round2::sign(&signing_package, nonces_0, &key_packages[0]).unwrap();
+        let shares = BTreeMap::from([(key_packages[0].identifier(), share)]);
+        assert_eq!(
+            aggregate(&signing_package, &shares, &public_key_package).unwrap_err(),
+            KeyPairError::SigningError
+        );
+
+        // A signature share is invalid.
+        let shares = BTreeMap::from([
+            (key_packages[0].identifier(), share),
+            (key_packages[1].identifier(), share),
+        ]);
+        assert!(!verify_signature_share(
+            key_packages[1].identifier(),
+            &share,
+            &signing_package,
+            &public_key_package
+        ));
+        assert_eq!(
+            aggregate(&signing_package, &shares, &public_key_package).unwrap_err(),
+            KeyPairError::SignatureVerifyError
+        );
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! The first round of signing: every signer commits to a pair of nonces.
+//! https://www.rfc-editor.org/rfc/rfc9591.html#name-round-one-commitment
+
+use crate::frost::ciphersuite::Ciphersuite;
+use crate::frost::keys::KeyPackage;
+use crate::rand::OsRng;
+use crate::KeyPairError;
+use rand_core::RngCore;
+use tw_hash::H256;
+use tw_misc::traits::ToBytesVec;
+use zeroize::Zeroize;
+
+/// The secret hiding and binding nonces of a signer.
+///
+/// # Important
+///
+/// The nonces must be used only once, that's why [`crate::frost::round2::sign`] consumes them,
+/// and they're neither `Clone` nor serializable.
+pub struct SigningNonces<C: Ciphersuite> {
+    hiding: C::Scalar,
+    binding: C::Scalar,
+    commitments: SigningCommitments<C>,
+}
+
+impl<C: Ciphersuite> SigningNonces<C> {
+    /// RFC 9591 `commit`.
+    pub fn generate(key_package: &KeyPackage<C>) -> SigningNonces<C> {
+        let mut hiding_rand = H256::default();
+        let mut binding_rand = H256::default();
+        OsRng.fill_bytes(hiding_rand.as_mut_slice());
+        OsRng.fill_bytes(binding_rand.as_mut_slice());
+
+        let nonces = Self::generate_with_rand(hiding_rand, binding_rand, key_package);
+        hiding_rand.zeroize();
+        binding_rand.zeroize();
+        nonces
+    }
+
+    /// Generates the nonces deterministically from the given randomness.
+    ///
+    /// # Important
+    ///
+    /// `hiding_rand` and `binding_rand` must be uniformly random and must never be reused.
+    /// Prefer [`SigningNonces::generate`].
+    pub fn generate_with_rand(
+        hiding_rand: H256,
+        binding_rand: H256,
+        key_package: &KeyPackage<C>,
+    ) -> SigningNonces<C> {
+        let hiding = nonce_generate::<C>(hiding_rand, key_package.signing_share());
+        let binding = nonce_generate::<C>(binding_rand, key_package.signing_share());
+        SigningNonces {
+            hiding,
+            binding,
+            commitments: SigningCommitments {
+                hiding: C::generator() * hiding,
+                binding: C::generator() * binding,
+            },
+        }
+    }
+
+    /// Returns the commitments to be sent to the coordinator.
+    pub fn commitments(&self) -> &SigningCommitments<C> {
+        &self.commitments
+    }
+
+    pub(crate) fn hiding(&self) -> &C::Scalar {
+        &self.hiding
+    }
+
+    pub(crate) fn binding(&self) -> &C::Scalar {
+        &self.binding
+    }
+}
+
+impl<C: Ciphersuite> Drop for SigningNonces<C> {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+/// The commitments `(d×G, e×G)` to the hiding and binding nonces of a signer
+/// encoded as two serialized points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SigningCommitments<C: Ciphersuite> {
+    hiding: C::Point,
+    binding: C::Point,
+}
+
+impl<C: Ciphersuite> SigningCommitments<C> {
+    pub fn hiding(&self) -> &C::Point {
+        &self.hiding
+    }
+
+    pub fn binding(&self) -> &C::Point {
+        &self.binding
+    }
+
+    /// Returns the signer's share `D + ρ×E` of the group commitment.
+    pub(crate) fn share(&self, binding_factor: C::Scalar) -> C::Point {
+        self.hiding + self.binding * binding_factor
+    }
+}
+
+impl<C: Ciphersuite> ToBytesVec for SigningCommitments<C> {
+    fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = C::serialize_point(&self.hiding);
+        bytes.extend_from_slice(&C::serialize_point(&self.binding));
+        bytes
+    }
+}
+
+impl<'a, C: Ciphersuite> TryFrom<&'a [u8]> for SigningCommitments<C> {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != C::POINT_LEN * 2 {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        let (hiding, binding) = bytes.split_at(C::POINT_LEN);
+        Ok(SigningCommitments {
+            hiding: C::deserialize_point(hiding)?,
+            binding: C::deserialize_point(binding)?,
+        })
+    }
+}
+
+/// RFC 9591 `nonce_generate`.
+/// The secret is hashed along with the randomness to protect against a weak random generator.
+fn nonce_generate<C: Ciphersuite>(rand: H256, secret: &C::Scalar) -> C::Scalar {
+    let mut input = rand.to_vec();
+    input.extend_from_slice(C::serialize_scalar(secret).as_slice());
+    let nonce = C::h3(&input);
+    input.zeroize();
+    nonce
+}
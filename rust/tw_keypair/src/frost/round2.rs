@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! The second round of signing: every signer produces a signature share.
+//! https://www.rfc-editor.org/rfc/rfc9591.html#name-round-two-signature-share-g
+
+use crate::frost::ciphersuite::Ciphersuite;
+use crate::frost::keys::KeyPackage;
+use crate::frost::round1::SigningNonces;
+use crate::frost::{lagrange_coefficient, SigningContext, SigningPackage};
+use crate::{KeyPairError, KeyPairResult};
+use tw_hash::H256;
+
+/// The signature share `z_i` of a signer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SignatureShare<C: Ciphersuite> {
+    pub(crate) share: C::Scalar,
+}
+
+impl<C: Ciphersuite> SignatureShare<C> {
+    pub fn bytes(&self) -> H256 {
+        C::serialize_scalar(&self.share)
+    }
+}
+
+impl<'a, C: Ciphersuite> TryFrom<&'a [u8]> for SignatureShare<C> {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let share = C::deserialize_scalar(bytes).map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok(SignatureShare { share })
+    }
+}
+
+/// RFC 9591 `sign`. The `signing_nonces` are consumed to prevent nonce reuse.
+pub fn sign<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signing_nonces: SigningNonces<C>,
+    key_package: &KeyPackage<C>,
+) -> KeyPairResult<SignatureShare<C>> {
+    let identifier = key_package.identifier();
+    if signing_package.commitments().get(&identifier) != Some(signing_nonces.commitments()) {
+        return Err(KeyPairError::SigningError);
+    }
+    if signing_package.commitments().len() < key_package.min_signers() as usize {
+        return Err(KeyPairError::SigningError);
+    }
+
+    let key_package = key_package.clone().into_even_y();
+    let context = SigningContext::new(signing_package, key_package.verifying_key())?;
+    let binding_factor = context.binding_factor(&identifier)?;
+    let lambda = lagrange_coefficient::<C>(identifier, signing_package.commitments().keys())?;
+
+    let (hiding, binding) = if context.negate_nonces() {
+        (-*signing_nonces.hiding(), -*signing_nonces.binding())
+    } else {
+        (*signing_nonces.hiding(), *signing_nonces.binding())
+    };
+    let share = hiding
+        + binding * binding_factor
+        + lambda * *key_package.signing_share() * context.challenge();
+    let signature_share = SignatureShare { share };
+
+    // Verify the signature share to protect against fault attacks.
+    if !context.verify_share(
+        identifier,
+        &signature_share,
+        key_package.verifying_share(),
+        signing_package,
+    ) {
+        return Err(KeyPairError::SigningError);
+    }
+    Ok(signature_share)
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::keystore::{KeystoreError, KeystoreResult};
+use crate::rand::OsRng;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex;
+use tw_hash::H128;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// AES-128 key is the first 16 bytes of the derived key.
+const AES_128_KEY_LEN: usize = 16;
+
+/// Cipher and its parameters, serialized as `cipher` and `cipherparams` fields.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "cipher", content = "cipherparams")]
+pub enum CipherParams {
+    #[serde(rename = "aes-128-ctr")]
+    Aes128Ctr(Aes128CtrParams),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Aes128CtrParams {
+    #[serde(with = "as_hex")]
+    pub iv: H128,
+}
+
+impl CipherParams {
+    /// Generates AES-128-CTR parameters with a random IV.
+    pub fn aes_128_ctr() -> CipherParams {
+        let mut iv = [0; H128::LEN];
+        OsRng.fill_bytes(&mut iv);
+        CipherParams::Aes128Ctr(Aes128CtrParams {
+            iv: H128::from_array(iv),
+        })
+    }
+
+    /// Encrypts or decrypts the `data` in place with the given `derived_key`.
+    /// CTR mode is symmetric, so the same operation is used for both.
+    pub fn apply_keystream(&self, derived_key: &[u8], data: &mut [u8]) -> KeystoreResult<()> {
+        match self {
+            CipherParams::Aes128Ctr(params) => {
+                let key = derived_key
+                    .get(..AES_128_KEY_LEN)
+                    .ok_or(KeystoreError::InvalidKdfParams)?;
+                let mut cipher = Aes128Ctr::new_from_slices(key, params.iv.as_slice())
+                    .map_err(|_| KeystoreError::InternalError)?;
+                cipher.apply_keystream(data);
+                Ok(())
+            },
+        }
+    }
+}
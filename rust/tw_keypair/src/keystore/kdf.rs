@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::keystore::{KeystoreError, KeystoreResult};
+use crate::rand::OsRng;
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::as_hex;
+use tw_hash::pbkdf2::pbkdf2_hmac_sha256;
+use tw_hash::scrypt::scrypt;
+use tw_memory::Data;
+use zeroize::Zeroizing;
+
+/// The derived key must be at least 32 bytes long:
+/// the first half is used as an encryption key, and the second half is used to compute MAC.
+pub const MIN_DERIVED_KEY_LEN: usize = 32;
+/// Only the first 32 bytes of the derived key are used, so longer keys just waste memory.
+pub const MAX_DERIVED_KEY_LEN: usize = 64;
+/// The maximum memory required by scrypt (`128 * r * n` bytes), equal to the one of [`ScryptParams::standard`].
+/// Prevents exhausting memory on a malicious keystore.
+pub const MAX_SCRYPT_MEMORY: u64 = 256 * 1024 * 1024;
+/// The maximum number of PBKDF2 iterations, 16 times more than [`Pbkdf2Params::standard`].
+pub const MAX_PBKDF2_ITERATIONS: u32 = 1 << 22;
+
+const DEFAULT_DERIVED_KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+
+/// Key derivation function and its parameters, serialized as `kdf` and `kdfparams` fields.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+pub enum KdfParams {
+    Scrypt(ScryptParams),
+    Pbkdf2(Pbkdf2Params),
+}
+
+impl KdfParams {
+    /// Derives a key from the `password`.
+    pub fn derive_key(&self, password: &[u8]) -> KeystoreResult<Zeroizing<Data>> {
+        let derived_key = match self {
+            KdfParams::Scrypt(params) => {
+                params.check()?;
+                scrypt(
+                    password,
+                    &params.salt,
+                    params.n,
+                    params.r,
+                    params.p,
+                    params.dklen,
+                )
+                .map_err(|_| KeystoreError::InvalidKdfParams)?
+            },
+            KdfParams::Pbkdf2(params) => {
+                params.check()?;
+                pbkdf2_hmac_sha256(password, &params.salt, params.c, params.dklen)
+            },
+        };
+        Ok(Zeroizing::new(derived_key))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScryptParams {
+    pub dklen: usize,
+    pub n: u32,
+    pub p: u32,
+    pub r: u32,
+    #[serde(with = "as_hex")]
+    pub salt: Data,
+}
+
+impl ScryptParams {
+    /// Parameters used by Geth and MetaMask by default.
+    pub fn standard() -> ScryptParams {
+        ScryptParams::with_cost(1 << 18, 8, 1)
+    }
+
+    /// Much faster but weaker parameters, the same as Geth uses with `--lightkdf`.
+    pub fn light() -> ScryptParams {
+        ScryptParams::with_cost(1 << 12, 8, 6)
+    }
+
+    fn with_cost(n: u32, r: u32, p: u32) -> ScryptParams {
+        ScryptParams {
+            dklen: DEFAULT_DERIVED_KEY_LEN,
+            n,
+            p,
+            r,
+            salt: random_salt(),
+        }
+    }
+
+    fn check(&self) -> KeystoreResult<()> {
+        let memory = 128 * u64::from(self.r) * u64::from(self.n);
+        if memory > MAX_SCRYPT_MEMORY {
+            return Err(KeystoreError::InvalidKdfParams);
+        }
+        check_dklen(self.dklen)
+    }
+}
+
+/// Pseudorandom function used by PBKDF2.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum Prf {
+    #[serde(rename = "hmac-sha256")]
+    HmacSha256,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Pbkdf2Params {
+    pub c: u32,
+    pub dklen: usize,
+    pub prf: Prf,
+    #[serde(with = "as_hex")]
+    pub salt: Data,
+}
+
+impl Pbkdf2Params {
+    /// Parameters used by the Web3 Secret Storage definition test vectors.
+    pub fn standard() -> Pbkdf2Params {
+        Pbkdf2Params {
+            c: 1 << 18,
+            dklen: DEFAULT_DERIVED_KEY_LEN,
+            prf: Prf::HmacSha256,
+            salt: random_salt(),
+        }
+    }
+
+    fn check(&self) -> KeystoreResult<()> {
+        if self.c == 0 || self.c > MAX_PBKDF2_ITERATIONS {
+            return Err(KeystoreError::InvalidKdfParams);
+        }
+        check_dklen(self.dklen)
+    }
+}
+
+fn check_dklen(dklen: usize) -> KeystoreResult<()> {
+    if !(MIN_DERIVED_KEY_LEN..=MAX_DERIVED_KEY_LEN).contains(&dklen) {
+        return Err(KeystoreError::InvalidKdfParams);
+    }
+    Ok(())
+}
+
+fn random_salt() -> Data {
+    let mut salt = vec![0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    salt
+}
@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Web3 Secret Storage (Ethereum keystore v3) implementation.
+//! Used to encrypt private keys and mnemonics with a password,
+//! and to import keystores exported by Geth, MetaMask and other wallets.
+
+use crate::rand::OsRng;
+use crate::tw::PrivateKey;
+use ecdsa::elliptic_curve::subtle::ConstantTimeEq;
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+use tw_encoding::hex::{as_hex, ToHex};
+use tw_hash::sha3::keccak256;
+use tw_hash::{H128, H256};
+use tw_memory::Data;
+use zeroize::Zeroizing;
+
+pub mod cipher;
+pub mod kdf;
+
+use cipher::CipherParams;
+use kdf::{KdfParams, Pbkdf2Params, ScryptParams, MIN_DERIVED_KEY_LEN};
+
+pub type KeystoreResult<T> = Result<T, KeystoreError>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum KeystoreError {
+    /// The keystore JSON is invalid, or it uses an unsupported cipher or KDF.
+    InvalidJson,
+    UnsupportedVersion,
+    InvalidKdfParams,
+    /// MAC mismatch, i.e. the password is incorrect or the keystore is corrupted.
+    InvalidPassword,
+    /// The keystore was decrypted successfully, but the content is not of the expected type.
+    InvalidEncryptedData,
+    InternalError,
+}
+
+/// Keystore v3.
+const KEYSTORE_VERSION: u32 = 3;
+
+/// Predefined KDF settings.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeystoreKdf {
+    /// scrypt with the parameters used by Geth and MetaMask by default.
+    Scrypt = 0,
+    /// scrypt with the parameters used by Geth with `--lightkdf`.
+    ScryptLight = 1,
+    Pbkdf2 = 2,
+}
+
+impl KeystoreKdf {
+    pub fn from_raw(kdf: u32) -> Option<KeystoreKdf> {
+        match kdf {
+            0 => Some(KeystoreKdf::Scrypt),
+            1 => Some(KeystoreKdf::ScryptLight),
+            2 => Some(KeystoreKdf::Pbkdf2),
+            _ => None,
+        }
+    }
+
+    /// Returns the KDF parameters with a random salt.
+    pub fn params(self) -> KdfParams {
+        match self {
+            KeystoreKdf::Scrypt => KdfParams::Scrypt(ScryptParams::standard()),
+            KeystoreKdf::ScryptLight => KdfParams::Scrypt(ScryptParams::light()),
+            KeystoreKdf::Pbkdf2 => KdfParams::Pbkdf2(Pbkdf2Params::standard()),
+        }
+    }
+}
+
+/// Password-encrypted secret data.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EncryptionParams {
+    #[serde(flatten)]
+    pub cipher: CipherParams,
+    #[serde(with = "as_hex")]
+    pub ciphertext: Data,
+    #[serde(flatten)]
+    pub kdf: KdfParams,
+    #[serde(with = "as_hex")]
+    pub mac: H256,
+}
+
+impl EncryptionParams {
+    /// Encrypts the `data` with a key derived from the `password`.
+    pub fn encrypt(
+        password: &[u8],
+        data: &[u8],
+        cipher: CipherParams,
+        kdf: KdfParams,
+    ) -> KeystoreResult<EncryptionParams> {
+        let derived_key = kdf.derive_key(password)?;
+
+        let mut ciphertext = data.to_vec();
+        cipher.apply_keystream(&derived_key, &mut ciphertext)?;
+        let mac = compute_mac(&derived_key, &ciphertext)?;
+
+        Ok(EncryptionParams {
+            cipher,
+            ciphertext,
+            kdf,
+            mac,
+        })
+    }
+
+    /// Decrypts the data if the `password` is correct.
+    pub fn decrypt(&self, password: &[u8]) -> KeystoreResult<Zeroizing<Data>> {
+        let derived_key = self.kdf.derive_key(password)?;
+        let mac = compute_mac(&derived_key, &self.ciphertext)?;
+        // Compare in constant time not to leak how many bytes of the MAC match.
+        if !bool::from(mac.as_slice().ct_eq(self.mac.as_slice())) {
+            return Err(KeystoreError::InvalidPassword);
+        }
+
+        let mut data = Zeroizing::new(self.ciphertext.clone());
+        self.cipher.apply_keystream(&derived_key, &mut data)?;
+        Ok(data)
+    }
+}
+
+/// Web3 Secret Storage definition, also known as Ethereum keystore v3.
+/// https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Keystore {
+    // Geth used to write `Crypto` in earlier versions.
+    #[serde(alias = "Crypto")]
+    pub crypto: EncryptionParams,
+    #[serde(default)]
+    pub id: String,
+    /// Hex-encoded address without the `0x` prefix, optional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub version: u32,
+}
+
+impl Keystore {
+    /// Parses a keystore JSON.
+    pub fn from_json(json: &str) -> KeystoreResult<Keystore> {
+        serde_json::from_str(json).map_err(|_| KeystoreError::InvalidJson)
+    }
+
+    pub fn to_json(&self) -> KeystoreResult<String> {
+        serde_json::to_string(self).map_err(|_| KeystoreError::InternalError)
+    }
+
+    /// Encrypts arbitrary `data` with the `password` using AES-128-CTR and the given KDF.
+    pub fn encrypt(password: &[u8], data: &[u8], kdf: KdfParams) -> KeystoreResult<Keystore> {
+        let crypto = EncryptionParams::encrypt(password, data, CipherParams::aes_128_ctr(), kdf)?;
+        Ok(Keystore {
+            crypto,
+            id: random_uuid(),
+            address: None,
+            version: KEYSTORE_VERSION,
+        })
+    }
+
+    /// Encrypts the `private_key` with the `password`.
+    pub fn encrypt_private_key(
+        password: &[u8],
+        private_key: &PrivateKey,
+        kdf: KdfParams,
+    ) -> KeystoreResult<Keystore> {
+        Keystore::encrypt(password, private_key.bytes(), kdf)
+    }
+
+    /// Encrypts the `mnemonic` phrase with the `password`.
+    pub fn encrypt_mnemonic(
+        password: &[u8],
+        mnemonic: &str,
+        kdf: KdfParams,
+    ) -> KeystoreResult<Keystore> {
+        Keystore::encrypt(password, mnemonic.as_bytes(), kdf)
+    }
+
+    /// Decrypts the data if the `password` is correct.
+    pub fn decrypt(&self, password: &[u8]) -> KeystoreResult<Zeroizing<Data>> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::UnsupportedVersion);
+        }
+        self.crypto.decrypt(password)
+    }
+
+    /// Decrypts a private key if the `password` is correct.
+    pub fn decrypt_private_key(&self, password: &[u8]) -> KeystoreResult<PrivateKey> {
+        let data = self.decrypt(password)?;
+        PrivateKey::new(data.to_vec()).map_err(|_| KeystoreError::InvalidEncryptedData)
+    }
+
+    /// Decrypts a mnemonic phrase if the `password` is correct.
+    pub fn decrypt_mnemonic(&self, password: &[u8]) -> KeystoreResult<Zeroizing<String>> {
+        let data = self.decrypt(password)?;
+        let mnemonic =
+            String::from_utf8(data.to_vec()).map_err(|_| KeystoreError::InvalidEncryptedData)?;
+        Ok(Zeroizing::new(mnemonic))
+    }
+}
+
+/// Computes `keccak256(derived_key[16..32] ++ ciphertext)`.
+fn compute_mac(derived_key: &[u8], ciphertext: &[u8]) -> KeystoreResult<H256> {
+    let mac_key = derived_key
+        .get(16..MIN_DERIVED_KEY_LEN)
+        .ok_or(KeystoreError::InvalidKdfParams)?;
+    let mac_input: Data = mac_key.iter().chain(ciphertext.iter()).copied().collect();
+    H256::try_from(keccak256(&mac_input).as_slice()).map_err(|_| KeystoreError::InternalError)
+}
+
+/// Generates a random UUID v4.
+fn random_uuid() -> String {
+    let mut bytes = [0; H128::LEN];
+    OsRng.fill_bytes(&mut bytes);
+    // Set the version (4) and the variant (RFC 4122) bits.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = bytes.to_hex();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &[u8] = b"testpassword";
+
+    /// Test vector from the Web3 Secret Storage definition.
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    const SCRYPT_MNEMONIC_KEYSTORE: &str = r#"{
+        "Crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "37e51dfc6ebe6bba1dd0a71b1d8b0bac" },
+            "ciphertext": "362f4d28b52857a4c45945fdb65c795d5db2d2c2ec3678c4998bd03fd2a241a0afebeda677482e2167f9e8e7751d4dd489da8062e162b16d187b3734e7c425ac44f158b578d287dbcf257d6431357880139780c8229a226a7e05ca82",
+            "kdf": "scrypt",
+            "kdfparams": {
+                "dklen": 32,
+                "n": 4096,
+                "p": 6,
+                "r": 8,
+                "salt": "80132842c6cde8f9d04582932ef92c3cad3ba6b41e1296ef681692372886db86"
+            },
+            "mac": "591e0525e2653613b26ac8a64ec1f4b396ead3e4ef508acd880f7031c62106ee"
+        },
+        "id": "e13b209c-3b2f-4327-bab0-3bef2e51630d",
+        "version": 3
+    }"#;
+
+    #[test]
+    fn test_decrypt_pbkdf2_private_key() {
+        let keystore = Keystore::from_json(PBKDF2_KEYSTORE).unwrap();
+        let private_key = keystore.decrypt_private_key(PASSWORD).unwrap();
+        assert_eq!(
+            private_key.bytes().to_hex(),
+            "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d"
+        );
+
+        assert_eq!(
+            keystore.decrypt(b"wrongpassword").unwrap_err(),
+            KeystoreError::InvalidPassword
+        );
+    }
+
+    #[test]
+    fn test_decrypt_scrypt_mnemonic() {
+        let keystore = Keystore::from_json(SCRYPT_MNEMONIC_KEYSTORE).unwrap();
+        let mnemonic = keystore.decrypt_mnemonic(b"password").unwrap();
+        assert_eq!(
+            mnemonic.as_str(),
+            "ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatal"
+        );
+
+        // The mnemonic is not a valid private key.
+        assert_eq!(
+            keystore.decrypt_private_key(b"password").err(),
+            Some(KeystoreError::InvalidEncryptedData)
+        );
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let private_key = PrivateKey::new(
+            H256::from("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5")
+                .into_vec(),
+        )
+        .unwrap();
+
+        for kdf in [KeystoreKdf::ScryptLight, KeystoreKdf::Pbkdf2] {
+            let keystore =
+                Keystore::encrypt_private_key(PASSWORD, &private_key, kdf.params()).unwrap();
+            let json = keystore.to_json().unwrap();
+
+            let keystore = Keystore::from_json(&json).unwrap();
+            assert_eq!(keystore.version, 3);
+            assert_eq!(keystore.id.len(), 36);
+            assert_eq!(&keystore.id[14..15], "4");
+
+            let decrypted = keystore.decrypt_private_key(PASSWORD).unwrap();
+            assert_eq!(decrypted.bytes(), private_key.bytes());
+        }
+    }
+
+    #[test]
+    fn test_keystore_invalid() {
+        let unsupported_cipher = PBKDF2_KEYSTORE.replace("aes-128-ctr", "aes-128-cbc");
+        assert_eq!(
+            Keystore::from_json(&unsupported_cipher).unwrap_err(),
+            KeystoreError::InvalidJson
+        );
+
+        let unsupported_prf = PBKDF2_KEYSTORE.replace("hmac-sha256", "hmac-sha512");
+        assert_eq!(
+            Keystore::from_json(&unsupported_prf).unwrap_err(),
+            KeystoreError::InvalidJson
+        );
+
+        let unsupported_version = PBKDF2_KEYSTORE.replace(r#""version": 3"#, r#""version": 1"#);
+        let keystore = Keystore::from_json(&unsupported_version).unwrap();
+        assert_eq!(
+            keystore.decrypt(PASSWORD).unwrap_err(),
+            KeystoreError::UnsupportedVersion
+        );
+
+        let short_dklen = PBKDF2_KEYSTORE.replace(r#""dklen": 32"#, r#""dklen": 16"#);
+        let keystore = Keystore::from_json(&short_dklen).unwrap();
+        assert_eq!(
+            keystore.decrypt(PASSWORD).unwrap_err(),
+            KeystoreError::InvalidKdfParams
+        );
+
+        let long_dklen = PBKDF2_KEYSTORE.replace(r#""dklen": 32"#, r#""dklen": 4294967295"#);
+        let keystore = Keystore::from_json(&long_dklen).unwrap();
+        assert_eq!(
+            keystore.decrypt(PASSWORD).unwrap_err(),
+            KeystoreError::InvalidKdfParams
+        );
+
+        let too_many_iterations = PBKDF2_KEYSTORE.replace(r#""c": 262144"#, r#""c": 4294967295"#);
+        let keystore = Keystore::from_json(&too_many_iterations).unwrap();
+        assert_eq!(
+            keystore.decrypt(PASSWORD).unwrap_err(),
+            KeystoreError::InvalidKdfParams
+        );
+
+        let too_much_memory = SCRYPT_MNEMONIC_KEYSTORE.replace(r#""n": 4096"#, r#""n": 1048576"#);
+        let keystore = Keystore::from_json(&too_much_memory).unwrap();
+        assert_eq!(
+            keystore.decrypt(b"password").unwrap_err(),
+            KeystoreError::InvalidKdfParams
+        );
+    }
+}
@@ -42,9 +42,13 @@
 //! assert_eq(eth_signature.v, H256::from(EXPECTED_V));
 //! ```
 
+pub mod anti_exfil;
+pub mod bls;
 pub mod ecdsa;
 pub mod ed25519;
 pub mod ffi;
+pub mod frost;
+pub mod keystore;
 pub mod nacl_crypto_box;
 pub mod rand;
 pub mod schnorr;
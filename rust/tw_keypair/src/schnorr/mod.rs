@@ -5,6 +5,7 @@
 use bitcoin::hashes::Hash;
 
 mod keypair;
+pub mod musig2;
 mod private;
 mod public;
 mod signature;
@@ -13,6 +14,7 @@ pub use keypair::KeyPair;
 pub use private::PrivateKey;
 pub use public::{PublicKey, XOnlyPublicKey};
 pub use signature::Signature;
+use tw_hash::sha2::sha256;
 use tw_hash::H256;
 
 pub(crate) fn bitcoin_tweak(tweak: Option<H256>) -> Option<bitcoin::taproot::TapNodeHash> {
@@ -25,6 +27,18 @@ pub(crate) fn bitcoin_tweak(tweak: Option<H256>) -> Option<bitcoin::taproot::Tap
     }
 }
 
+/// BIP-340 tagged hash: `sha256(sha256(tag) || sha256(tag) || data)`.
+pub(crate) fn tagged_hash(tag: &[u8], data: &[u8]) -> H256 {
+    let tag_hash = sha256(tag);
+
+    let mut input = Vec::with_capacity(tag_hash.len() * 2 + data.len());
+    input.extend_from_slice(&tag_hash);
+    input.extend_from_slice(&tag_hash);
+    input.extend_from_slice(data);
+
+    H256::try_from(sha256(&input).as_slice()).expect("Expected 32 byte array sha256 hash")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,4 +137,25 @@ mod tests {
 
         assert_eq!(public_key.tweak(None), tweaked_public);
     }
+
+    #[test]
+    fn test_sign_with_aux_rand() {
+        let private_key = PrivateKey::try_from(SECRET).unwrap();
+        let hash_to_sign = H256::from(MSG);
+        let aux_rand =
+            H256::from("0101010101010101010101010101010101010101010101010101010101010101");
+
+        let (actual, nonce_point) = private_key
+            .sign_with_aux_rand(hash_to_sign, aux_rand)
+            .unwrap();
+
+        let expected = H512::from("048dd1a1361819fbeeae10ac20a6c36e962d00c0f23c901b04f95d5a42f80730075b10ce765bb20eaaec42ea87ae8a4dc2d43fccef8594de41b5c349b35f498e");
+        assert_eq!(actual, Signature::try_from(expected.as_slice()).unwrap());
+        // BIP-340 nonce points have an even y-coordinate.
+        assert_eq!(nonce_point[0], 0x02);
+        assert_eq!(nonce_point[1..], actual.bytes()[..32]);
+
+        let public = private_key.public();
+        assert!(public.verify(actual, hash_to_sign), "Invalid signature");
+    }
 }
@@ -0,0 +1,1058 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! MuSig2 multi-signatures for BIP-340 Schnorr as specified in BIP-327.
+//! https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+//!
+//! The aggregate key and the final signature are indistinguishable from a single-signer key
+//! and a BIP-340 signature, so a taproot output can be spent by several parties via the key path.
+//!
+//! 1. Every signer aggregates the public keys into a [`KeyAggContext`],
+//!    tweaked with [`KeyAggContext::with_taproot_tweak`] when spending a taproot output.
+//! 2. Every signer generates a [`SecretNonce`] and shares its [`PublicNonce`].
+//! 3. The public nonces are aggregated via [`AggregatedNonce::aggregate`],
+//!    and every signer creates a [`Session`] for the message to be signed.
+//! 4. Every signer produces a [`PartialSignature`] via [`Session::sign`].
+//! 5. The partial signatures are verified and aggregated via [`Session::aggregate`].
+
+use crate::rand::OsRng;
+use crate::schnorr::tagged_hash;
+use crate::schnorr::{PrivateKey, PublicKey, Signature, XOnlyPublicKey};
+use crate::traits::VerifyingKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256};
+use rand_core::RngCore;
+use tw_hash::{H256, H264, H512};
+use tw_misc::traits::ToBytesVec;
+use zeroize::Zeroize;
+
+/// The key aggregation context that holds the aggregate public key `Q`
+/// and the tweaks applied to it.
+#[derive(Clone, Debug)]
+pub struct KeyAggContext {
+    pubkeys: Vec<PublicKey>,
+    key_list_hash: H256,
+    second_key: Option<H264>,
+    aggregated_point: AffinePoint,
+    gacc: Scalar,
+    tacc: Scalar,
+}
+
+impl KeyAggContext {
+    /// Aggregates the given public keys in the given order.
+    /// Consider sorting the keys via [`key_sort`] if the order is not agreed by the signers.
+    pub fn new(pubkeys: Vec<PublicKey>) -> KeyPairResult<KeyAggContext> {
+        if pubkeys.is_empty() {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+
+        let compressed: Vec<H264> = pubkeys.iter().map(PublicKey::compressed).collect();
+        let key_list: Vec<u8> = compressed.iter().flat_map(|pubkey| pubkey.take()).collect();
+        let key_list_hash = tagged_hash(b"KeyAgg list", &key_list);
+        // The first key that differs from the first one gets the coefficient of 1.
+        let second_key = compressed
+            .iter()
+            .find(|pubkey| **pubkey != compressed[0])
+            .copied();
+
+        let mut ctx = KeyAggContext {
+            pubkeys,
+            key_list_hash,
+            second_key,
+            aggregated_point: AffinePoint::IDENTITY,
+            gacc: Scalar::ONE,
+            tacc: Scalar::ZERO,
+        };
+
+        let mut aggregated = ProjectivePoint::IDENTITY;
+        for pubkey in ctx.pubkeys.iter() {
+            aggregated += public_point(pubkey)? * ctx.coefficient(&pubkey.compressed());
+        }
+        ctx.aggregated_point = non_identity(aggregated)?;
+        Ok(ctx)
+    }
+
+    /// Returns the public keys the context is created with.
+    pub fn pubkeys(&self) -> &[PublicKey] {
+        &self.pubkeys
+    }
+
+    /// Returns the aggregate public key `Q` with all the tweaks applied.
+    pub fn aggregated_pubkey(&self) -> PublicKey {
+        point_to_public(&self.aggregated_point)
+    }
+
+    /// Returns the x-only aggregate public key `Q` with all the tweaks applied.
+    pub fn aggregated_x_only(&self) -> XOnlyPublicKey {
+        self.aggregated_pubkey().x_only()
+    }
+
+    /// Tweaks the aggregate public key with `tweak×G` as it's done in BIP-32 derivation.
+    pub fn with_plain_tweak(self, tweak: H256) -> KeyPairResult<KeyAggContext> {
+        self.apply_tweak(tweak, false)
+    }
+
+    /// Tweaks the x-only aggregate public key with `tweak×G`.
+    pub fn with_xonly_tweak(self, tweak: H256) -> KeyPairResult<KeyAggContext> {
+        self.apply_tweak(tweak, true)
+    }
+
+    /// Tweaks the aggregate public key to a taproot output key committing to the given
+    /// script tree `merkle_root`, the same way as [`PublicKey::tweak`] does.
+    /// The aggregate key is considered as the taproot internal key.
+    pub fn with_taproot_tweak(self, merkle_root: Option<H256>) -> KeyPairResult<KeyAggContext> {
+        let mut data = x_bytes(&self.aggregated_point).to_vec();
+        if let Some(merkle_root) = merkle_root {
+            data.extend_from_slice(merkle_root.as_slice());
+        }
+        let tweak = tagged_hash(b"TapTweak", &data);
+        self.with_xonly_tweak(tweak)
+    }
+
+    fn apply_tweak(mut self, tweak: H256, is_xonly: bool) -> KeyPairResult<KeyAggContext> {
+        let g = if is_xonly && !has_even_y(&self.aggregated_point) {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+        let tweak = scalar_from_bytes(tweak.as_slice())?;
+
+        let tweaked =
+            ProjectivePoint::from(self.aggregated_point) * g + ProjectivePoint::GENERATOR * tweak;
+        self.aggregated_point = non_identity(tweaked)?;
+        self.gacc *= g;
+        self.tacc = tweak + g * self.tacc;
+        Ok(self)
+    }
+
+    /// Returns the key aggregation coefficient of the given public key.
+    fn coefficient(&self, pubkey: &H264) -> Scalar {
+        if self.second_key.as_ref() == Some(pubkey) {
+            return Scalar::ONE;
+        }
+
+        let mut data = self.key_list_hash.to_vec();
+        data.extend_from_slice(pubkey.as_slice());
+        reduce_hash(tagged_hash(b"KeyAgg coefficient", &data))
+    }
+
+    fn contains(&self, pubkey: &PublicKey) -> bool {
+        self.pubkeys.contains(pubkey)
+    }
+
+    /// Returns `g` as defined in BIP-327 that is `-1` if the aggregate key has an odd y-coordinate.
+    fn parity_factor(&self) -> Scalar {
+        if has_even_y(&self.aggregated_point) {
+            Scalar::ONE
+        } else {
+            -Scalar::ONE
+        }
+    }
+}
+
+/// Sorts the public keys in lexicographical order of their compressed representation.
+pub fn key_sort(pubkeys: &[PublicKey]) -> Vec<PublicKey> {
+    let mut sorted = pubkeys.to_vec();
+    sorted.sort_by_key(PublicKey::compressed);
+    sorted
+}
+
+/// The secret nonce of a signer.
+///
+/// # Important
+///
+/// The nonce must be used only once, that's why [`Session::sign`] consumes it,
+/// and it's neither `Clone` nor serializable.
+pub struct SecretNonce {
+    k1: Scalar,
+    k2: Scalar,
+    public: PublicKey,
+}
+
+impl SecretNonce {
+    /// Generates a nonce for the signer with the given `public` key according to BIP-327 `NonceGen`.
+    /// All the other arguments are optional, but strengthen the nonce if a random generator is weak.
+    pub fn generate(
+        public: &PublicKey,
+        private: Option<&PrivateKey>,
+        aggregated_pubkey: Option<&XOnlyPublicKey>,
+        message: Option<&[u8]>,
+        extra_input: &[u8],
+    ) -> KeyPairResult<SecretNonce> {
+        let mut rand = H256::default();
+        OsRng.fill_bytes(rand.as_mut_slice());
+        let nonce = Self::generate_with_rand(
+            rand,
+            public,
+            private,
+            aggregated_pubkey,
+            message,
+            extra_input,
+        );
+        rand.zeroize();
+        nonce
+    }
+
+    /// Generates a nonce deterministically from the given `rand`.
+    ///
+    /// # Important
+    ///
+    /// `rand` must be uniformly random and must never be reused. Prefer [`SecretNonce::generate`].
+    pub fn generate_with_rand(
+        rand: H256,
+        public: &PublicKey,
+        private: Option<&PrivateKey>,
+        aggregated_pubkey: Option<&XOnlyPublicKey>,
+        message: Option<&[u8]>,
+        extra_input: &[u8],
+    ) -> KeyPairResult<SecretNonce> {
+        let mut rand = match private {
+            Some(private) => {
+                let secret = private.secret_scalar()?.to_bytes();
+                let aux_hash = tagged_hash(b"MuSig/aux", rand.as_slice());
+                let xored: Vec<u8> = secret
+                    .iter()
+                    .zip(aux_hash.iter())
+                    .map(|(secret_byte, aux_byte)| secret_byte ^ aux_byte)
+                    .collect();
+                H256::try_from(xored.as_slice()).expect("Expected 32 byte array")
+            },
+            None => rand,
+        };
+
+        let mut data = rand.to_vec();
+        let public_bytes = public.compressed();
+        data.push(public_bytes.len() as u8);
+        data.extend_from_slice(public_bytes.as_slice());
+        match aggregated_pubkey {
+            Some(aggregated_pubkey) => {
+                let aggregated_bytes = aggregated_pubkey.bytes();
+                data.push(aggregated_bytes.len() as u8);
+                data.extend_from_slice(aggregated_bytes.as_slice());
+            },
+            None => data.push(0),
+        }
+        match message {
+            Some(message) => {
+                data.push(1);
+                data.extend_from_slice(&(message.len() as u64).to_be_bytes());
+                data.extend_from_slice(message);
+            },
+            None => data.push(0),
+        }
+        data.extend_from_slice(&(extra_input.len() as u32).to_be_bytes());
+        data.extend_from_slice(extra_input);
+
+        let nonce = |i: u8| -> KeyPairResult<Scalar> {
+            let mut input = data.clone();
+            input.push(i);
+            let k = reduce_hash(tagged_hash(b"MuSig/nonce", &input));
+            input.zeroize();
+            if bool::from(k.is_zero()) {
+                return Err(KeyPairError::SigningError);
+            }
+            Ok(k)
+        };
+        let nonce = nonce(0).and_then(|k1| {
+            Ok(SecretNonce {
+                k1,
+                k2: nonce(1)?,
+                public: public.clone(),
+            })
+        });
+
+        rand.zeroize();
+        data.zeroize();
+        nonce
+    }
+
+    /// Returns the public nonce `(k1×G, k2×G)` to be shared with the other signers.
+    pub fn public_nonce(&self) -> PublicNonce {
+        PublicNonce {
+            r1: (ProjectivePoint::GENERATOR * self.k1).to_affine(),
+            r2: (ProjectivePoint::GENERATOR * self.k2).to_affine(),
+        }
+    }
+}
+
+impl Drop for SecretNonce {
+    fn drop(&mut self) {
+        self.k1.zeroize();
+        self.k2.zeroize();
+    }
+}
+
+/// The public nonce of a signer encoded as two compressed points (66 bytes).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicNonce {
+    r1: AffinePoint,
+    r2: AffinePoint,
+}
+
+impl ToBytesVec for PublicNonce {
+    fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = compressed_point(&self.r1).to_vec();
+        bytes.extend_from_slice(compressed_point(&self.r2).as_slice());
+        bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PublicNonce {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != H264::LEN * 2 {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        let (r1, r2) = bytes.split_at(H264::LEN);
+        Ok(PublicNonce {
+            r1: point_from_bytes(r1)?,
+            r2: point_from_bytes(r2)?,
+        })
+    }
+}
+
+/// The sum of the public nonces of all signers.
+/// Unlike [`PublicNonce`], its points can be infinity, encoded as 33 zero bytes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregatedNonce {
+    r1: AffinePoint,
+    r2: AffinePoint,
+}
+
+impl AggregatedNonce {
+    /// BIP-327 `NonceAgg`.
+    pub fn aggregate(public_nonces: &[PublicNonce]) -> KeyPairResult<AggregatedNonce> {
+        if public_nonces.is_empty() {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+
+        let (r1, r2) = public_nonces.iter().fold(
+            (ProjectivePoint::IDENTITY, ProjectivePoint::IDENTITY),
+            |(r1, r2), nonce| (r1 + nonce.r1, r2 + nonce.r2),
+        );
+        Ok(AggregatedNonce {
+            r1: r1.to_affine(),
+            r2: r2.to_affine(),
+        })
+    }
+}
+
+impl ToBytesVec for AggregatedNonce {
+    fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = compressed_point_ext(&self.r1).to_vec();
+        bytes.extend_from_slice(compressed_point_ext(&self.r2).as_slice());
+        bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for AggregatedNonce {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != H264::LEN * 2 {
+            return Err(KeyPairError::InvalidPublicKey);
+        }
+        let (r1, r2) = bytes.split_at(H264::LEN);
+        Ok(AggregatedNonce {
+            r1: point_from_bytes_ext(r1)?,
+            r2: point_from_bytes_ext(r2)?,
+        })
+    }
+}
+
+/// A partial signature `s` of a signer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartialSignature {
+    s: Scalar,
+}
+
+impl PartialSignature {
+    pub fn bytes(&self) -> H256 {
+        H256::from(<[u8; 32]>::from(self.s.to_bytes()))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PartialSignature {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let s = scalar_from_bytes(bytes).map_err(|_| KeyPairError::InvalidSignature)?;
+        Ok(PartialSignature { s })
+    }
+}
+
+/// The signing session of a `message` with the given aggregate key and aggregate nonce.
+#[derive(Clone, Debug)]
+pub struct Session {
+    key_agg_ctx: KeyAggContext,
+    message: H256,
+    nonce_coefficient: Scalar,
+    final_nonce: AffinePoint,
+    challenge: Scalar,
+}
+
+impl Session {
+    pub fn new(
+        key_agg_ctx: KeyAggContext,
+        aggregated_nonce: &AggregatedNonce,
+        message: H256,
+    ) -> KeyPairResult<Session> {
+        let aggregated_x = x_bytes(&key_agg_ctx.aggregated_point);
+
+        let mut data = aggregated_nonce.to_vec();
+        data.extend_from_slice(&aggregated_x);
+        data.extend_from_slice(message.as_slice());
+        let nonce_coefficient = reduce_hash(tagged_hash(b"MuSig/noncecoef", &data));
+
+        let final_nonce = ProjectivePoint::from(aggregated_nonce.r1)
+            + ProjectivePoint::from(aggregated_nonce.r2) * nonce_coefficient;
+        // The nonce is replaced with `G` in the unlikely case of infinity to allow the signing to continue.
+        let final_nonce = if final_nonce == ProjectivePoint::IDENTITY {
+            AffinePoint::GENERATOR
+        } else {
+            final_nonce.to_affine()
+        };
+
+        let mut data = x_bytes(&final_nonce).to_vec();
+        data.extend_from_slice(&aggregated_x);
+        data.extend_from_slice(message.as_slice());
+        let challenge = reduce_hash(tagged_hash(b"BIP0340/challenge", &data));
+
+        Ok(Session {
+            key_agg_ctx,
+            message,
+            nonce_coefficient,
+            final_nonce,
+            challenge,
+        })
+    }
+
+    /// Returns the key aggregation context of the session.
+    pub fn key_agg_ctx(&self) -> &KeyAggContext {
+        &self.key_agg_ctx
+    }
+
+    /// BIP-327 `Sign`. The `secret_nonce` is consumed to prevent nonce reuse.
+    pub fn sign(
+        &self,
+        secret_nonce: SecretNonce,
+        private: &PrivateKey,
+    ) -> KeyPairResult<PartialSignature> {
+        let public = private.public();
+        if public != secret_nonce.public || !self.key_agg_ctx.contains(&public) {
+            return Err(KeyPairError::InvalidSecretKey);
+        }
+
+        let (k1, k2) = if has_even_y(&self.final_nonce) {
+            (secret_nonce.k1, secret_nonce.k2)
+        } else {
+            (-secret_nonce.k1, -secret_nonce.k2)
+        };
+
+        let coefficient = self.key_agg_ctx.coefficient(&public.compressed());
+        let secret =
+            self.key_agg_ctx.parity_factor() * self.key_agg_ctx.gacc * private.secret_scalar()?;
+        let s = k1 + self.nonce_coefficient * k2 + self.challenge * coefficient * secret;
+        let partial_signature = PartialSignature { s };
+
+        // Verify the partial signature to protect against fault attacks.
+        if !self.verify_partial_signature(&partial_signature, &secret_nonce.public_nonce(), &public)
+        {
+            return Err(KeyPairError::SigningError);
+        }
+        Ok(partial_signature)
+    }
+
+    /// BIP-327 `PartialSigVerify`.
+    /// Checks the partial signature of the signer with the given `public` key and `public_nonce`.
+    pub fn verify_partial_signature(
+        &self,
+        partial_signature: &PartialSignature,
+        public_nonce: &PublicNonce,
+        public: &PublicKey,
+    ) -> bool {
+        if !self.key_agg_ctx.contains(public) {
+            return false;
+        }
+        let Ok(public_point) = public_point(public) else {
+            return false;
+        };
+
+        let nonce = ProjectivePoint::from(public_nonce.r1)
+            + ProjectivePoint::from(public_nonce.r2) * self.nonce_coefficient;
+        let nonce = if has_even_y(&self.final_nonce) {
+            nonce
+        } else {
+            -nonce
+        };
+
+        let coefficient = self.key_agg_ctx.coefficient(&public.compressed());
+        let g = self.key_agg_ctx.parity_factor() * self.key_agg_ctx.gacc;
+        let expected = nonce + public_point * (self.challenge * coefficient * g);
+        ProjectivePoint::GENERATOR * partial_signature.s == expected
+    }
+
+    /// BIP-327 `PartialSigAgg`.
+    /// Aggregates the partial signatures into a BIP-340 signature valid for
+    /// [`KeyAggContext::aggregated_x_only`].
+    ///
+    /// # Important
+    ///
+    /// The partial signatures should be verified beforehand via [`Session::verify_partial_signature`]
+    /// to find out which signer has misbehaved in case of an error.
+    pub fn aggregate(&self, partial_signatures: &[PartialSignature]) -> KeyPairResult<Signature> {
+        let s = partial_signatures
+            .iter()
+            .fold(Scalar::ZERO, |s, partial| s + partial.s);
+        let s = s + self.challenge * self.key_agg_ctx.parity_factor() * self.key_agg_ctx.tacc;
+
+        let mut sig = H512::default();
+        sig[..32].copy_from_slice(&x_bytes(&self.final_nonce));
+        sig[32..].copy_from_slice(s.to_bytes().as_slice());
+        let signature = Signature::try_from(sig.as_slice())?;
+
+        if !self
+            .key_agg_ctx
+            .aggregated_x_only()
+            .verify(Signature::from(signature.signature), self.message)
+        {
+            return Err(KeyPairError::SignatureVerifyError);
+        }
+        Ok(signature)
+    }
+}
+
+fn has_even_y(point: &AffinePoint) -> bool {
+    !bool::from(point.y_is_odd())
+}
+
+fn x_bytes(point: &AffinePoint) -> [u8; 32] {
+    point.x().into()
+}
+
+fn reduce_hash(hash: H256) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(hash.take()))
+}
+
+/// Parses a scalar failing if it's not less than the curve order.
+fn scalar_from_bytes(bytes: &[u8]) -> KeyPairResult<Scalar> {
+    let bytes = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+    Option::from(Scalar::from_repr(FieldBytes::from(bytes.take())))
+        .ok_or(KeyPairError::InvalidSecretKey)
+}
+
+fn non_identity(point: ProjectivePoint) -> KeyPairResult<AffinePoint> {
+    if point == ProjectivePoint::IDENTITY {
+        return Err(KeyPairError::InvalidPublicKey);
+    }
+    Ok(point.to_affine())
+}
+
+fn public_point(public: &PublicKey) -> KeyPairResult<ProjectivePoint> {
+    point_from_bytes(public.compressed().as_slice()).map(ProjectivePoint::from)
+}
+
+fn point_to_public(point: &AffinePoint) -> PublicKey {
+    PublicKey::try_from(compressed_point(point).as_slice())
+        .expect("Expected a valid non-infinity point")
+}
+
+fn compressed_point(point: &AffinePoint) -> H264 {
+    H264::try_from(point.to_encoded_point(true).as_bytes())
+        .expect("Expected 33 byte array compressed point")
+}
+
+/// Encodes the point as 33 zero bytes if it's infinity.
+fn compressed_point_ext(point: &AffinePoint) -> H264 {
+    if bool::from(point.is_identity()) {
+        return H264::default();
+    }
+    compressed_point(point)
+}
+
+fn point_from_bytes(bytes: &[u8]) -> KeyPairResult<AffinePoint> {
+    if bytes.len() != H264::LEN {
+        return Err(KeyPairError::InvalidPublicKey);
+    }
+    let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| KeyPairError::InvalidPublicKey)?;
+    let point: AffinePoint = Option::from(AffinePoint::from_encoded_point(&encoded))
+        .ok_or(KeyPairError::InvalidPublicKey)?;
+    if bool::from(point.is_identity()) {
+        return Err(KeyPairError::InvalidPublicKey);
+    }
+    Ok(point)
+}
+
+/// Decodes 33 zero bytes as infinity.
+fn point_from_bytes_ext(bytes: &[u8]) -> KeyPairResult<AffinePoint> {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return Ok(AffinePoint::IDENTITY);
+    }
+    point_from_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::{self, ToHex};
+
+    const X1: &str = "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+    const X2: &str = "03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+    const X3: &str = "023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66";
+
+    const ALICE_SECRET: &str = "ba0828d5734b65e3bcc2c51c93dfc26dd71bd666cc0273adee77d73d9a322035";
+    const BOB_SECRET: &str = "26c2566adcc030a1799213bfd546e615f6ab06f72085ec6806ff1761da48d227";
+    const CAROL_SECRET: &str = "12ce558df23528f1aa86f1f51ac7e13a197a06bda27610fa89e13b04c40ee999";
+    const MSG: &str = "71b7098e8150cde90f3ec00280815d3069f81c7cdb6d83bbe2b897b1afbe7cd6";
+
+    fn pubkey(hex: &str) -> PublicKey {
+        PublicKey::try_from(hex::decode(hex).unwrap().as_slice()).unwrap()
+    }
+
+    fn aggregate(pubkeys: &[&str]) -> KeyAggContext {
+        KeyAggContext::new(pubkeys.iter().map(|hex| pubkey(hex)).collect()).unwrap()
+    }
+
+    fn nonce(rand: u8, private: &PrivateKey, key_agg_ctx: &KeyAggContext) -> SecretNonce {
+        SecretNonce::generate_with_rand(
+            H256::from([rand; 32]),
+            &private.public(),
+            Some(private),
+            Some(&key_agg_ctx.aggregated_x_only()),
+            Some(H256::from(MSG).as_slice()),
+            b"extra",
+        )
+        .unwrap()
+    }
+
+    /// Returns `k1 || k2` of the secret nonce.
+    fn secret_nonce_hex(nonce: &SecretNonce) -> String {
+        let mut bytes = nonce.k1.to_bytes().to_vec();
+        bytes.extend_from_slice(nonce.k2.to_bytes().as_slice());
+        bytes.to_hex()
+    }
+
+    /// The secret nonce of `sign_verify_vectors.json`.
+    fn sign_verify_nonce(private: &PrivateKey) -> SecretNonce {
+        let k1 = H256::from("508b81a611f100a6b2b6b29656590898af488bcf2e1f55cf22e5cfb84421fe61");
+        let k2 = H256::from("fa27fd49b1d50085b481285e1ca205d55c82cc1b31ff5cd54a489829355901f7");
+        SecretNonce {
+            k1: scalar_from_bytes(k1.as_slice()).unwrap(),
+            k2: scalar_from_bytes(k2.as_slice()).unwrap(),
+            public: private.public(),
+        }
+    }
+
+    /// https://github.com/bitcoin/bips/blob/master/bip-0327/vectors/key_agg_vectors.json
+    #[test]
+    fn test_key_agg() {
+        let test_cases = [
+            (
+                vec![X1, X2, X3],
+                "90539eede565f5d054f32cc0c220126889ed1e5d193baf15aef344fe59d4610c",
+            ),
+            (
+                vec![X3, X2, X1],
+                "6204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b",
+            ),
+            (
+                vec![X1, X1, X1],
+                "b436e3bad62b8cd409969a224731c193d051162d8c5ae8b109306127da3aa935",
+            ),
+            (
+                vec![X1, X1, X2, X2],
+                "69bc22bfa5d106306e48a20679de1d7389386124d07571d0d872686028c26a3e",
+            ),
+        ];
+        for (pubkeys, expected) in test_cases {
+            let ctx = aggregate(&pubkeys);
+            assert_eq!(ctx.aggregated_x_only().bytes(), H256::from(expected));
+        }
+    }
+
+    /// https://github.com/bitcoin/bips/blob/master/bip-0327/vectors/nonce_gen_vectors.json
+    #[test]
+    fn test_nonce_gen() {
+        let private = PrivateKey::try_from(
+            "0202020202020202020202020202020202020202020202020202020202020202",
+        )
+        .unwrap();
+        let aggregated_pubkey = XOnlyPublicKey::try_from(
+            H256::from("0707070707070707070707070707070707070707070707070707070707070707")
+                .as_slice(),
+        )
+        .unwrap();
+        let extra_input = [0x08; 32];
+
+        let test_cases = [
+            (
+                Some(vec![0x01; 32]),
+                "227243dcb40ef2a13a981db188fa433717b506bdfa14b1ae47d5dc027c9c3b9ef2370b2ad206e724243215137c86365699361126991e6fec816845f837bddac3",
+                "020a25526b002885996358b3ee5092f2f2f197393e59c06cdfc7a92a91931e20c3024c9fecc6795d5d761f96968d871a1f3bac605f6ecc4e52e1ebf49e1ff9208ad0",
+            ),
+            (
+                Some(Vec::new()),
+                "cd0f47fe471d6788ff3243f47345ea0a179aef69476be8348322ef39c2723318870c2065afb52dedf02bf4fdbf6d2f442e608692f50c2374c08fffe57042a61c",
+                "0283d01f92f2b6a8540867ad8c7e725e420bbe27d8a949b67f1602219a3218ede3034edb05e0fcc6a1af733da418d47f863c874ed150b0f92821bf38b9c1835958e5",
+            ),
+            (
+                Some(vec![0x26; 38]),
+                "011f8bc60ef061deef4d72a0a87200d9994b3f0cd9867910085c38d5366e3e6b9ff03bc0124e56b24069e91ec3f162378983f194e8bd0ed89be3059649eae262",
+                "036c9e0851ccc4c93589c870ef67ecad52cf883fbafaa27c1d980199b33407d7d3023afddecc096613b4a8b3288fc7a2918f5014674e9f8a80a24572d68ca5506aa8",
+            ),
+        ];
+        for (message, expected_secnonce, expected_pubnonce) in test_cases {
+            let nonce = SecretNonce::generate_with_rand(
+                H256::default(),
+                &private.public(),
+                Some(&private),
+                Some(&aggregated_pubkey),
+                message.as_deref(),
+                &extra_input,
+            )
+            .unwrap();
+            assert_eq!(secret_nonce_hex(&nonce), expected_secnonce);
+            assert_eq!(nonce.public_nonce().to_vec().to_hex(), expected_pubnonce);
+        }
+
+        // All the optional arguments are absent.
+        let public = pubkey("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9");
+        let nonce =
+            SecretNonce::generate_with_rand(H256::default(), &public, None, None, None, &[])
+                .unwrap();
+        assert_eq!(
+            secret_nonce_hex(&nonce),
+            "890e83616a3bc4640ab9b6374f21c81ff89cdddbafaa7475ae2a102a92e3edb29fd7e874e23342813a60d9646948242646b7951ca046b4b36d7d6078506d3c94"
+        );
+        assert_eq!(
+            nonce.public_nonce().to_vec().to_hex(),
+            "02237a448a2848dd07b3c01c618eb926dfa2f5c294adc68cbaada183f016e1eb0e03ca63e5e8eb6da599c5605fc9340be1afaaafed278500844132b562db2b1e1ed3"
+        );
+    }
+
+    /// https://github.com/bitcoin/bips/blob/master/bip-0327/vectors/sign_verify_vectors.json
+    #[test]
+    fn test_sign_verify() {
+        let private = PrivateKey::try_from(
+            "7fb9e0e687ada1eebf7ecfe2f21e73ebdb51a7d450948dfe8d76d7f2d1007671",
+        )
+        .unwrap();
+        let pubkeys = [
+            "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba661",
+        ];
+        let public_nonces = [
+            "0337c87821afd50a8644d820a8f3e02e499c931865c2360fb43d0a0d20dafe07ea0287bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480",
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f817980279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "032de2662628c90b03f5e720284eb52ff7d71f4284f627b68a853d78c78e1ffe9303e4c5524e83ffe1493b9077cf1ca6beb2090c93d930321071ad40b2f44e599046",
+        ];
+        let public_nonces: Vec<_> = public_nonces
+            .iter()
+            .map(|hex| PublicNonce::try_from(hex::decode(hex).unwrap().as_slice()).unwrap())
+            .collect();
+        let message =
+            H256::from("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+        assert_eq!(private.public(), pubkey(pubkeys[0]));
+
+        let aggregated_nonce = AggregatedNonce::aggregate(&public_nonces).unwrap();
+        assert_eq!(
+            aggregated_nonce.to_vec().to_hex(),
+            "028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9"
+        );
+        // Both halves of the aggregate nonce are infinity.
+        let infinity_nonce = AggregatedNonce::try_from([0; 66].as_slice()).unwrap();
+
+        let test_cases = [
+            (
+                vec![0, 1, 2],
+                &aggregated_nonce,
+                "012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb",
+            ),
+            (
+                vec![1, 0, 2],
+                &aggregated_nonce,
+                "9ff2f7aaa856150cc8819254218d3adeeb0535269051897724f9db3789513a52",
+            ),
+            (
+                vec![1, 2, 0],
+                &aggregated_nonce,
+                "fa23c359f6fac4e7796bb93bc9f0532a95468c539ba20ff86d7c76ed92227900",
+            ),
+            (
+                vec![0, 1],
+                &infinity_nonce,
+                "ae386064b26105404798f75de2eb9af5eda5387b064b83d049cb7c5e08879531",
+            ),
+        ];
+        for (key_indices, aggregated_nonce, expected) in test_cases {
+            let keys: Vec<_> = key_indices.iter().map(|i| pubkeys[*i]).collect();
+            let session = Session::new(aggregate(&keys), aggregated_nonce, message).unwrap();
+
+            let partial_signature = session.sign(sign_verify_nonce(&private), &private).unwrap();
+            assert_eq!(partial_signature.bytes(), H256::from(expected));
+            assert!(session.verify_partial_signature(
+                &partial_signature,
+                &public_nonces[0],
+                &private.public()
+            ));
+        }
+
+        let session = Session::new(aggregate(&pubkeys), &aggregated_nonce, message).unwrap();
+        let partial_signature = session.sign(sign_verify_nonce(&private), &private).unwrap();
+        // Wrong signature, which is equal to the negation of the valid signature.
+        let negated = PartialSignature {
+            s: -partial_signature.s,
+        };
+        assert!(!session.verify_partial_signature(&negated, &public_nonces[0], &private.public()));
+        // Wrong signer.
+        assert!(!session.verify_partial_signature(
+            &partial_signature,
+            &public_nonces[1],
+            &pubkey(pubkeys[1])
+        ));
+        // The signature exceeds the group size.
+        let exceeding =
+            H256::from("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        assert_eq!(
+            PartialSignature::try_from(exceeding.as_slice()).unwrap_err(),
+            KeyPairError::InvalidSignature
+        );
+    }
+
+    /// https://github.com/bitcoin/bips/blob/master/bip-0327/vectors/sig_agg_vectors.json
+    #[test]
+    fn test_sig_agg() {
+        let ctx = aggregate(&[
+            "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+            "02d2dc6f5df7c56acf38c7fa0ae7a759ae30e19b37359dfde015872324c7ef6e05",
+        ]);
+        let public_nonces: Vec<_> = [
+            "036e5ee6e28824029fea3e8a9ddd2c8483f5af98f7177c3af3cb6f47caf8d94ae902dba67e4a1f3680826172da15afb1a8ca85c7c5cc88900905c8dc8c328511b53e",
+            "03e4f798da48a76eec1c9cc5ab7a880ffba201a5f064e627ec9cb0031d1d58fc5103e06180315c5a522b7ec7c08b69dcd721c313c940819296d0a7ab8e8795ac1f00",
+        ]
+        .iter()
+        .map(|hex| PublicNonce::try_from(hex::decode(hex).unwrap().as_slice()).unwrap())
+        .collect();
+        let partial_signatures: Vec<_> = [
+            "b15d2cd3c3d22b04dae438ce653f6b4ecf042f42cfded7c41b64aaf9b4af53fb",
+            "6193d6ac61b354e9105bbdc8937a3454a6d705b6d57322a5a472a02ce99fcb64",
+        ]
+        .iter()
+        .map(|hex| PartialSignature::try_from(H256::from(*hex).as_slice()).unwrap())
+        .collect();
+        let message =
+            H256::from("599c67ea410d005b9da90817cf03ed3b1c868e4da4edf00a5880b0082c237869");
+
+        let aggregated_nonce = AggregatedNonce::aggregate(&public_nonces).unwrap();
+        assert_eq!(
+            aggregated_nonce.to_vec().to_hex(),
+            "0341432722c5cd0268d829c702cf0d1cbce57033eed201fd335191385227c3210c03d377f2d258b64aadc0e16f26462323d701d286046a2ea93365656afd9875982b"
+        );
+
+        let session = Session::new(ctx, &aggregated_nonce, message).unwrap();
+        let signature = session.aggregate(&partial_signatures).unwrap();
+        assert_eq!(
+            signature.bytes(),
+            H512::from("041da22223ce65c92c9a0d6c2cac828aaf1eee56304fec371ddf91ebb2b9ef0912f1038025857fedeb3ff696f8b99fa4bb2c5812f6095a2e0004ec99ce18de1e")
+        );
+    }
+
+    #[test]
+    fn test_key_agg_empty() {
+        assert_eq!(
+            KeyAggContext::new(Vec::new()).unwrap_err(),
+            KeyPairError::InvalidPublicKey
+        );
+    }
+
+    #[test]
+    fn test_key_sort() {
+        let sorted = key_sort(&[pubkey(X1), pubkey(X2), pubkey(X3)]);
+        assert_eq!(sorted, vec![pubkey(X3), pubkey(X1), pubkey(X2)]);
+    }
+
+    #[test]
+    fn test_key_agg_tweaks() {
+        let tweak = H256::from("e8f791ff9225a2af0102afff4a9a723d9612a682a25ebe79802b263cdfcd83bb");
+
+        // The aggregate key has an odd y-coordinate, so x-only and plain tweaks differ.
+        let ctx = aggregate(&[X3, X2, X1]);
+        assert_eq!(
+            ctx.aggregated_pubkey().compressed().to_hex(),
+            "036204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b"
+        );
+
+        let xonly_tweaked = ctx.clone().with_xonly_tweak(tweak).unwrap();
+        assert_eq!(
+            xonly_tweaked.aggregated_pubkey().compressed().to_hex(),
+            "03317d8a78cafe6577afd84dfd841a0c0c0b51f09db4c592182b41ad8271587acc"
+        );
+        let plain_tweaked = ctx.with_plain_tweak(tweak).unwrap();
+        assert_eq!(
+            plain_tweaked.aggregated_pubkey().compressed().to_hex(),
+            "037127b997978587213aebea116e69fad619652d1e3e6079c8b5ad491cf606af06"
+        );
+
+        // A tweak must be less than the curve order.
+        let invalid_tweak =
+            H256::from("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        assert!(aggregate(&[X1, X2])
+            .with_xonly_tweak(invalid_tweak)
+            .is_err());
+    }
+
+    #[test]
+    fn test_key_agg_taproot_tweak() {
+        let merkle_root =
+            H256::from("a4c4b7ee5e5b4e1d9f1e1e3bd5b7dd4c1a3cf2ad8a1c5c8de0a7e0bfc5d2b3f1");
+
+        for merkle_root in [None, Some(merkle_root)] {
+            let ctx = aggregate(&[X1, X2, X3]);
+            let internal_key = ctx.aggregated_pubkey();
+
+            let tweaked = ctx.with_taproot_tweak(merkle_root).unwrap();
+            assert_eq!(
+                tweaked.aggregated_x_only(),
+                internal_key.tweak(merkle_root).x_only()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sign_taproot_key_path() {
+        let alice = PrivateKey::try_from(ALICE_SECRET).unwrap();
+        let bob = PrivateKey::try_from(BOB_SECRET).unwrap();
+        let message = H256::from(MSG);
+
+        let ctx = KeyAggContext::new(vec![alice.public(), bob.public()]).unwrap();
+        assert_eq!(
+            ctx.aggregated_pubkey().compressed().to_hex(),
+            "02e8e640f7b614016798a4f597f18632f0aade65cd4197eb9fd3882a95ca2d4846"
+        );
+        let ctx = ctx.with_taproot_tweak(None).unwrap();
+        assert_eq!(
+            ctx.aggregated_pubkey().compressed().to_hex(),
+            "032907f89015e47e7d99c5c0538be74b9742e5f0c4c883fb4fcf99f5175b09fa17"
+        );
+
+        let alice_nonce = nonce(1, &alice, &ctx);
+        let bob_nonce = nonce(2, &bob, &ctx);
+        let alice_public_nonce = alice_nonce.public_nonce();
+        let bob_public_nonce = bob_nonce.public_nonce();
+        assert_eq!(
+            alice_public_nonce.to_vec().to_hex(),
+            "02f4102b7639882d38c6bfe7a187bd0511a098486c036a4c6ff8becc478c3f621f03b0a5c1f51579339912f2ebda03aea18100f724fce99629314b99d28ad260b0dc"
+        );
+        assert_eq!(
+            bob_public_nonce.to_vec().to_hex(),
+            "0332eb5734bb4deb36a6c5a366bd7eaf51c7b7ee9ce1c308a677b2c30cf153395603b37525aa5ec344526a010258da7908943cea5428e16cb5c9e2984dd457e8cf59"
+        );
+
+        let aggregated_nonce =
+            AggregatedNonce::aggregate(&[alice_public_nonce.clone(), bob_public_nonce.clone()])
+                .unwrap();
+        assert_eq!(
+            aggregated_nonce.to_vec().to_hex(),
+            "03e720c03ec922411775a3abf6792627aa81450dec1f213b00f34a2dbeaec649d403d47caf761445bc496cec58d32ee772d11f7ad94a0d28381d655671b373ec46be"
+        );
+
+        let session = Session::new(ctx, &aggregated_nonce, message).unwrap();
+        let alice_partial = session.sign(alice_nonce, &alice).unwrap();
+        let bob_partial = session.sign(bob_nonce, &bob).unwrap();
+        assert_eq!(
+            alice_partial.bytes(),
+            H256::from("ecb03ea5facd8452f18730b10fdae99ff42c294e1616164c24ba22f991acf84d")
+        );
+        assert_eq!(
+            bob_partial.bytes(),
+            H256::from("f6debbc33afb63db70ab4a3efc2bfae10eae7569e58cbc96bf28f0001c4e4271")
+        );
+
+        assert!(session.verify_partial_signature(
+            &alice_partial,
+            &alice_public_nonce,
+            &alice.public()
+        ));
+        // The partial signature doesn't correspond to Bob's nonce and public key.
+        assert!(!session.verify_partial_signature(
+            &alice_partial,
+            &bob_public_nonce,
+            &bob.public()
+        ));
+
+        let signature = session.aggregate(&[alice_partial, bob_partial]).unwrap();
+        assert_eq!(
+            signature.bytes(),
+            H512::from("d1eccaeb520ee168e9cb3598df432f1757a2174c8935e0277ee3c3c51a8bdcbac8eeff02e59e3a78340166792904f382b70dcd70e5286ddb5c333e8ff10ac50a")
+        );
+        assert!(session
+            .key_agg_ctx()
+            .aggregated_x_only()
+            .verify(signature, message));
+
+        // A signature can't be aggregated without all the partial signatures.
+        assert!(session.aggregate(&[alice_partial]).is_err());
+    }
+
+    #[test]
+    fn test_sign_with_another_key() {
+        let alice = PrivateKey::try_from(ALICE_SECRET).unwrap();
+        let bob = PrivateKey::try_from(BOB_SECRET).unwrap();
+
+        let ctx = KeyAggContext::new(vec![alice.public(), bob.public()]).unwrap();
+        let alice_nonce = nonce(1, &alice, &ctx);
+        let bob_nonce = nonce(2, &bob, &ctx);
+        let aggregated_nonce =
+            AggregatedNonce::aggregate(&[alice_nonce.public_nonce(), bob_nonce.public_nonce()])
+                .unwrap();
+        let session = Session::new(ctx, &aggregated_nonce, H256::from(MSG)).unwrap();
+
+        // The nonce was generated for Alice.
+        assert_eq!(
+            session.sign(alice_nonce, &bob).unwrap_err(),
+            KeyPairError::InvalidSecretKey
+        );
+
+        // The key doesn't take part in the aggregate key.
+        let carol = PrivateKey::try_from(CAROL_SECRET).unwrap();
+        let carol_nonce = nonce(3, &carol, session.key_agg_ctx());
+        assert_eq!(
+            session.sign(carol_nonce, &carol).unwrap_err(),
+            KeyPairError::InvalidSecretKey
+        );
+    }
+
+    #[test]
+    fn test_nonce_encoding() {
+        let alice = PrivateKey::try_from(ALICE_SECRET).unwrap();
+        let ctx = KeyAggContext::new(vec![alice.public()]).unwrap();
+
+        let public_nonce = nonce(1, &alice, &ctx).public_nonce();
+        let bytes = public_nonce.to_vec();
+        assert_eq!(
+            PublicNonce::try_from(bytes.as_slice()).unwrap(),
+            public_nonce
+        );
+
+        // The aggregate nonce may contain infinity points, but a public nonce may not.
+        let infinity = vec![0; 66];
+        assert!(PublicNonce::try_from(infinity.as_slice()).is_err());
+        let aggregated_nonce = AggregatedNonce::try_from(infinity.as_slice()).unwrap();
+        assert_eq!(aggregated_nonce.to_vec(), infinity);
+
+        let negated = PublicNonce {
+            r1: (-ProjectivePoint::from(public_nonce.r1)).to_affine(),
+            r2: (-ProjectivePoint::from(public_nonce.r2)).to_affine(),
+        };
+        let aggregated_nonce = AggregatedNonce::aggregate(&[public_nonce, negated]).unwrap();
+        assert_eq!(aggregated_nonce.to_vec(), infinity);
+    }
+}
@@ -1,12 +1,17 @@
-use crate::schnorr::bitcoin_tweak;
+use crate::anti_exfil::{self, NonceCommitment, Protocol};
 use crate::schnorr::public::PublicKey;
 use crate::schnorr::signature::Signature;
-use crate::traits::SigningKeyTrait;
+use crate::schnorr::{bitcoin_tweak, tagged_hash};
+use crate::traits::{SigningKeyTrait, VerifyingKeyTrait};
 use crate::{KeyPairError, KeyPairResult};
 use bitcoin::key::TapTweak;
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::PrimeField;
+use k256::{FieldBytes, ProjectivePoint, Scalar, U256};
 use secp256k1::SECP256K1;
 use tw_encoding::hex;
-use tw_hash::H256;
+use tw_hash::{H256, H512};
 use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
 use zeroize::{Zeroize, Zeroizing};
 
@@ -42,6 +47,129 @@ impl PrivateKey {
         self.no_aux_rand = true;
         self
     }
+
+    /// Signs the `message` with the given BIP-340 auxiliary random data.
+    /// Returns the signature and the nonce point `R` it was produced with.
+    pub fn sign_with_aux_rand(
+        &self,
+        message: H256,
+        aux_rand: H256,
+    ) -> KeyPairResult<(Signature, NonceCommitment)> {
+        let msg = secp256k1::Message::from_slice(message.as_slice())
+            .map_err(|_| KeyPairError::InvalidSignMessage)?;
+        let sig = SECP256K1.sign_schnorr_with_aux_rand(&msg, &self.key_pair, &aux_rand.take());
+
+        // BIP-340 nonce points always have an even y-coordinate.
+        let mut nonce_commitment = NonceCommitment::default();
+        nonce_commitment[0] = 0x02;
+        nonce_commitment[1..].copy_from_slice(&sig.as_ref()[..32]);
+        Ok((Signature::from(sig), nonce_commitment))
+    }
+
+    /// Returns the nonce commitment `R0` of the anti-exfil protocol
+    /// for the given `message` and `host_commitment`.
+    /// See [`anti_exfil::schnorr_host_commit`].
+    pub fn anti_exfil_commit(
+        &self,
+        message: H256,
+        host_commitment: H256,
+    ) -> KeyPairResult<NonceCommitment> {
+        let nonce = self.bip340_nonce(message, host_commitment)?;
+        let nonce_point = (ProjectivePoint::GENERATOR * *nonce).to_affine();
+        Ok(anti_exfil::nonce_commitment(&nonce_point))
+    }
+
+    /// Signs the `message` according to the anti-exfil protocol with the `host_data`
+    /// revealed by the host after receiving the [`PrivateKey::anti_exfil_commit`] result.
+    pub fn anti_exfil_sign(&self, message: H256, host_data: H256) -> KeyPairResult<Signature> {
+        let host_commitment = anti_exfil::schnorr_host_commit(&host_data);
+        let committed_nonce = self.bip340_nonce(message, host_commitment)?;
+        let committed_point = (ProjectivePoint::GENERATOR * *committed_nonce).to_affine();
+
+        let tweak = anti_exfil::nonce_tweak(Protocol::Schnorr, &committed_point, &host_data)?;
+        self.sign_with_nonce(message, Zeroizing::new(*committed_nonce + tweak))
+    }
+
+    /// Returns the secret key as a scalar.
+    pub(crate) fn secret_scalar(&self) -> KeyPairResult<Scalar> {
+        let secret = FieldBytes::from(self.key_pair.secret_bytes());
+        Option::from(Scalar::from_repr(secret)).ok_or(KeyPairError::InvalidSecretKey)
+    }
+
+    /// Returns the secret scalar negated if necessary to match the x-only public key,
+    /// and the x-only public key.
+    fn bip340_secret(&self) -> KeyPairResult<(Zeroizing<Scalar>, H256)> {
+        let secret = Zeroizing::new(self.secret_scalar()?);
+
+        let public = (ProjectivePoint::GENERATOR * *secret).to_affine();
+        let secret = if bool::from(public.y_is_odd()) {
+            Zeroizing::new(-*secret)
+        } else {
+            secret
+        };
+        Ok((secret, H256::from(<[u8; 32]>::from(public.x()))))
+    }
+
+    /// https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki#default-signing
+    fn bip340_nonce(&self, message: H256, aux_rand: H256) -> KeyPairResult<Zeroizing<Scalar>> {
+        let (secret, public_x) = self.bip340_secret()?;
+
+        let secret_bytes = Zeroizing::new(<[u8; 32]>::from(secret.to_bytes()));
+        let aux_hash = tagged_hash(b"BIP0340/aux", aux_rand.as_slice());
+        let mut data: Zeroizing<Vec<u8>> = Zeroizing::new(
+            secret_bytes
+                .iter()
+                .zip(aux_hash.iter())
+                .map(|(secret_byte, aux_byte)| secret_byte ^ aux_byte)
+                .collect(),
+        );
+        data.extend_from_slice(public_x.as_slice());
+        data.extend_from_slice(message.as_slice());
+
+        let rand = Zeroizing::new(tagged_hash(b"BIP0340/nonce", &data).take());
+        let nonce = Zeroizing::new(<Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(
+            *rand,
+        )));
+        if bool::from(nonce.is_zero()) {
+            return Err(KeyPairError::SigningError);
+        }
+        Ok(nonce)
+    }
+
+    /// Signs the `message` with the given `nonce` according to BIP-340.
+    fn sign_with_nonce(&self, message: H256, nonce: Zeroizing<Scalar>) -> KeyPairResult<Signature> {
+        let (secret, public_x) = self.bip340_secret()?;
+
+        let nonce_point = (ProjectivePoint::GENERATOR * *nonce).to_affine();
+        let nonce = if bool::from(nonce_point.y_is_odd()) {
+            Zeroizing::new(-*nonce)
+        } else {
+            nonce
+        };
+        let nonce_x = nonce_point.x();
+
+        let mut data = nonce_x.to_vec();
+        data.extend_from_slice(public_x.as_slice());
+        data.extend_from_slice(message.as_slice());
+        let challenge = tagged_hash(b"BIP0340/challenge", &data);
+        let challenge = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(challenge.take()));
+
+        let s = *nonce + challenge * *secret;
+
+        let mut sig = H512::default();
+        sig[..32].copy_from_slice(nonce_x.as_slice());
+        sig[32..].copy_from_slice(s.to_bytes().as_slice());
+        let signature = Signature::try_from(sig.as_slice())?;
+
+        // Verify the signature to protect against fault attacks.
+        if !self
+            .public()
+            .verify(Signature::from(signature.signature), message)
+        {
+            return Err(KeyPairError::SigningError);
+        }
+        Ok(signature)
+    }
 }
 
 impl Zeroize for PrivateKey {
@@ -13,19 +13,29 @@ pub use public::PublicKey;
 pub type Signature = Vec<u8>;
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Curve {
+    #[serde(rename = "secp256k1")]
     Secp256k1 = 0,
+    #[serde(rename = "ed25519")]
     Ed25519 = 1,
+    #[serde(rename = "ed25519Blake2bNano")]
     Ed25519Blake2bNano = 2,
     /// Waves blockchain specific `curve25519`.
+    #[serde(rename = "curve25519")]
     Curve25519Waves = 3,
+    #[serde(rename = "nist256p1")]
     Nist256p1 = 4,
     /// Cardano blockchain specific `ed25519` extended key.
+    #[serde(rename = "ed25519ExtendedCardano")]
     Ed25519ExtendedCardano = 5,
+    #[serde(rename = "starkex")]
     Starkex = 6,
+    #[serde(rename = "schnorr")]
     Schnorr = 7,
+    #[serde(rename = "bls12381")]
+    Bls12381 = 8,
 }
 
 impl Curve {
@@ -40,6 +50,7 @@ impl Curve {
             5 => Some(Curve::Ed25519ExtendedCardano),
             6 => Some(Curve::Starkex),
             7 => Some(Curve::Schnorr),
+            8 => Some(Curve::Bls12381),
             _ => None,
         }
     }
@@ -72,6 +83,8 @@ pub enum PublicKeyType {
     Starkex = 8,
     #[serde(rename = "schnorr")]
     Schnorr = 9,
+    #[serde(rename = "bls12381")]
+    Bls12381 = 10,
 }
 
 impl PublicKeyType {
@@ -88,6 +101,7 @@ impl PublicKeyType {
             7 => Some(PublicKeyType::Ed25519ExtendedCardano),
             8 => Some(PublicKeyType::Starkex),
             9 => Some(PublicKeyType::Schnorr),
+            10 => Some(PublicKeyType::Bls12381),
             _ => None,
         }
     }
@@ -108,7 +122,8 @@ mod tests {
             (5, Some(Curve::Ed25519ExtendedCardano)),
             (6, Some(Curve::Starkex)),
             (7, Some(Curve::Schnorr)),
-            (8, None),
+            (8, Some(Curve::Bls12381)),
+            (9, None),
         ];
         for (raw, expected) in tests {
             assert_eq!(Curve::from_raw(raw), expected);
@@ -128,7 +143,8 @@ mod tests {
             (7, Some(PublicKeyType::Ed25519ExtendedCardano)),
             (8, Some(PublicKeyType::Starkex)),
             (9, Some(PublicKeyType::Schnorr)),
-            (10, None),
+            (10, Some(PublicKeyType::Bls12381)),
+            (11, None),
         ];
         for (raw, expected) in tests {
             assert_eq!(PublicKeyType::from_raw(raw), expected);
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::bls;
 use crate::ecdsa::{nist256p1, secp256k1};
 use crate::schnorr;
 use crate::traits::SigningKeyTrait;
@@ -91,6 +92,7 @@ impl PrivateKey {
             },
             Curve::Starkex => starkex::PrivateKey::try_from(&bytes[Self::KEY_RANGE]).is_ok(),
             Curve::Schnorr => schnorr::PrivateKey::try_from(&bytes[Self::KEY_RANGE]).is_ok(),
+            Curve::Bls12381 => bls::PrivateKey::try_from(&bytes[Self::KEY_RANGE]).is_ok(),
         }
     }
 
@@ -116,6 +118,7 @@ impl PrivateKey {
             },
             Curve::Starkex => sign_impl(self.to_starkex_privkey()?, message),
             Curve::Schnorr => sign_impl(self.to_schnorr_privkey()?, message),
+            Curve::Bls12381 => sign_impl(self.to_bls_privkey()?, message),
         }
     }
 
@@ -164,6 +167,10 @@ impl PrivateKey {
                 let privkey = self.to_schnorr_privkey()?;
                 Ok(PublicKey::Schnorr(privkey.public()))
             },
+            PublicKeyType::Bls12381 => {
+                let privkey = self.to_bls_privkey()?;
+                Ok(PublicKey::Bls12381(privkey.public()))
+            },
         }
     }
 
@@ -206,4 +213,9 @@ impl PrivateKey {
     fn to_schnorr_privkey(&self) -> KeyPairResult<schnorr::PrivateKey> {
         schnorr::PrivateKey::try_from(self.key().as_slice())
     }
+
+    /// Tries to convert [`PrivateKey::key`] to [`bls::PrivateKey`].
+    fn to_bls_privkey(&self) -> KeyPairResult<bls::PrivateKey> {
+        bls::PrivateKey::try_from(self.key().as_slice())
+    }
 }
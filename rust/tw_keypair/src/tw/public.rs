@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::bls;
 use crate::ecdsa::{nist256p1, secp256k1};
 use crate::schnorr;
 use crate::traits::VerifyingKeyTrait;
@@ -24,6 +25,7 @@ pub enum PublicKey {
     Ed25519ExtendedCardano(Box<ed25519::cardano::ExtendedPublicKey>),
     Starkex(starkex::PublicKey),
     Schnorr(schnorr::PublicKey),
+    Bls12381(bls::PublicKey),
 }
 
 impl PublicKey {
@@ -76,6 +78,10 @@ impl PublicKey {
                 let pubkey = schnorr::PublicKey::try_from(bytes.as_slice())?;
                 Ok(PublicKey::Schnorr(pubkey))
             },
+            PublicKeyType::Bls12381 => {
+                let pubkey = bls::PublicKey::try_from(bytes.as_slice())?;
+                Ok(PublicKey::Bls12381(pubkey))
+            },
             _ => Err(KeyPairError::InvalidPublicKey),
         }
     }
@@ -114,6 +120,7 @@ impl PublicKey {
             },
             PublicKey::Starkex(stark) => verify_impl(stark, sig, message),
             PublicKey::Schnorr(schnorr) => verify_impl(schnorr, sig, message),
+            PublicKey::Bls12381(bls) => verify_impl(bls, sig, message),
         }
     }
 
@@ -130,6 +137,7 @@ impl PublicKey {
             PublicKey::Ed25519ExtendedCardano(cardano) => cardano.to_vec(),
             PublicKey::Starkex(stark) => stark.to_vec(),
             PublicKey::Schnorr(schnorr) => schnorr.to_vec(),
+            PublicKey::Bls12381(bls) => bls.to_vec(),
         }
     }
 
@@ -158,6 +166,14 @@ impl PublicKey {
         }
     }
 
+    /// Returns a `BLS12-381` public key if the key type is matched.
+    pub fn to_bls12381(&self) -> Option<&bls::PublicKey> {
+        match self {
+            PublicKey::Bls12381(bls) => Some(bls),
+            _ => None,
+        }
+    }
+
     /// Returns a public key type.
     pub fn public_key_type(&self) -> PublicKeyType {
         match self {
@@ -171,6 +187,7 @@ impl PublicKey {
             PublicKey::Ed25519ExtendedCardano(_) => PublicKeyType::Ed25519ExtendedCardano,
             PublicKey::Starkex(_) => PublicKeyType::Starkex,
             PublicKey::Schnorr(_) => PublicKeyType::Schnorr,
+            PublicKey::Bls12381(_) => PublicKeyType::Bls12381,
         }
     }
 }
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ffi::keystore::{
+    tw_keystore_decrypt, tw_keystore_decrypt_mnemonic, tw_keystore_decrypt_private_key,
+    tw_keystore_encrypt,
+};
+use tw_keypair::ffi::privkey::{tw_private_key_bytes, tw_private_key_delete, tw_private_key_size};
+use tw_keypair::keystore::KeystoreKdf;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+
+const PRIVATE_KEY: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+const MNEMONIC: &str =
+    "ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatal";
+
+fn encrypt(password: &str, data: &[u8], kdf: KeystoreKdf) -> TWStringHelper {
+    let password = TWDataHelper::create(password.as_bytes().to_vec());
+    let data = TWDataHelper::create(data.to_vec());
+    TWStringHelper::wrap(unsafe { tw_keystore_encrypt(password.ptr(), data.ptr(), kdf as u32) })
+}
+
+#[test]
+fn test_keystore_encrypt_decrypt_private_key() {
+    let private_key = PRIVATE_KEY.decode_hex().unwrap();
+    let json = encrypt("password", &private_key, KeystoreKdf::ScryptLight);
+    assert!(json.to_string().unwrap().contains(r#""kdf":"scrypt""#));
+
+    let password = TWDataHelper::create(b"password".to_vec());
+    let decrypted = TWDataHelper::wrap(unsafe { tw_keystore_decrypt(json.ptr(), password.ptr()) });
+    assert_eq!(decrypted.to_vec().unwrap(), private_key);
+
+    let tw_private_key = unsafe { tw_keystore_decrypt_private_key(json.ptr(), password.ptr()) };
+    assert!(!tw_private_key.is_null());
+    let actual = unsafe {
+        std::slice::from_raw_parts(
+            tw_private_key_bytes(tw_private_key),
+            tw_private_key_size(tw_private_key),
+        )
+    };
+    assert_eq!(actual.to_hex(), PRIVATE_KEY);
+    unsafe { tw_private_key_delete(tw_private_key) };
+
+    let wrong_password = TWDataHelper::create(b"wrongpassword".to_vec());
+    let decrypted =
+        TWDataHelper::wrap(unsafe { tw_keystore_decrypt(json.ptr(), wrong_password.ptr()) });
+    assert!(decrypted.is_null());
+}
+
+#[test]
+fn test_keystore_encrypt_decrypt_mnemonic() {
+    let json = encrypt("password", MNEMONIC.as_bytes(), KeystoreKdf::Pbkdf2);
+    assert!(json.to_string().unwrap().contains(r#""kdf":"pbkdf2""#));
+
+    let password = TWDataHelper::create(b"password".to_vec());
+    let mnemonic =
+        TWStringHelper::wrap(unsafe { tw_keystore_decrypt_mnemonic(json.ptr(), password.ptr()) });
+    assert_eq!(mnemonic.to_string().unwrap(), MNEMONIC);
+
+    // The mnemonic is not a valid private key.
+    let tw_private_key = unsafe { tw_keystore_decrypt_private_key(json.ptr(), password.ptr()) };
+    assert!(tw_private_key.is_null());
+}
+
+#[test]
+fn test_keystore_invalid() {
+    let password = TWDataHelper::create(b"password".to_vec());
+    let data = TWDataHelper::create(MNEMONIC.as_bytes().to_vec());
+    let json = TWStringHelper::wrap(unsafe { tw_keystore_encrypt(password.ptr(), data.ptr(), 3) });
+    assert!(json.to_string().is_none());
+
+    let invalid_json = TWStringHelper::create("{}");
+    let decrypted =
+        TWDataHelper::wrap(unsafe { tw_keystore_decrypt(invalid_json.ptr(), password.ptr()) });
+    assert!(decrypted.is_null());
+}
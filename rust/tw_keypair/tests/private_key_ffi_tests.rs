@@ -113,6 +113,14 @@ fn test_tw_private_key_sign_starkex() {
     test_sign(Curve::Starkex, secret, msg, sign);
 }
 
+#[test]
+fn test_tw_private_key_sign_bls12381() {
+    let secret = "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3";
+    let msg = "5656565656565656565656565656565656565656565656565656565656565656";
+    let sign = "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb";
+    test_sign(Curve::Bls12381, secret, msg, sign);
+}
+
 #[test]
 fn test_tw_private_key_sign_invalid_hash() {
     let tw_privkey = TWPrivateKeyHelper::with_hex(
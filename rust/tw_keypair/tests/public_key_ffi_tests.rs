@@ -120,3 +120,11 @@ fn test_tw_public_key_verify_ed25519_extended_cardano() {
     let sign = "375df53b6a4931dcf41e062b1c64288ed4ff3307f862d5c1b1c71964ce3b14c99422d0fdfeb2807e9900a26d491d5e8a874c24f98eec141ed694d7a433a90f08";
     test_verify(PublicKeyType::Ed25519ExtendedCardano, public, &msg, sign);
 }
+
+#[test]
+fn test_tw_public_key_verify_bls12381() {
+    let public = "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a";
+    let msg = "5656565656565656565656565656565656565656565656565656565656565656";
+    let sign = "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb";
+    test_verify(PublicKeyType::Bls12381, public, msg, sign);
+}
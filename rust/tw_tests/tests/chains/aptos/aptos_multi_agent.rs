@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::test_cases::transfer_b4d62afd::{
+    aptos_sign_transfer_input, PRIVATE_KEY, RAW_TXN,
+};
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_misc::assert_eq_json;
+use tw_proto::Aptos::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PUBLIC_KEY: &str = "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c";
+
+const FEE_PAYER_PRIVATE_KEY: &str =
+    "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+const FEE_PAYER_PUBLIC_KEY: &str =
+    "4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867";
+pub(super) const FEE_PAYER_ADDRESS: &str =
+    "0x9006fa46f038224e8004bdda97f2e7a60c2c3d135bce7cb15541e5c0aae907a4";
+
+const SECONDARY_PRIVATE_KEY: &str =
+    "4646464646464646464646464646464646464646464646464646464646464646";
+const SECONDARY_PUBLIC_KEY: &str =
+    "ee93a4f66f8d16b819bb9beb9ffccdfcdc1412e87fee6a324c2a99a1e0e67148";
+const SECONDARY_ADDRESS: &str =
+    "0xce2fd04ac9efa74f17595e5785e847a2399d7e637f5e8179244f76191f653276";
+
+pub(super) mod fee_payer {
+    pub const DATA_TO_SIGN: &str = "5efa3c4f02f83a0f4b2d69fc95c607cc02825cc4e7be536ef0992df050d9e67c0107968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021009006fa46f038224e8004bdda97f2e7a60c2c3d135bce7cb15541e5c0aae907a4";
+    pub const SENDER_SIGNATURE: &str = "fad72a56ca46a103a01bdac5db40a3eb58c061487c94d267d6e98a797cf661c5c3bc4f8189c3f62bb3bd9c427f28c338362c537abaa8a5281ae391918d5d0708";
+    pub const FEE_PAYER_SIGNATURE: &str = "e317dfaafa564987e9d3948ceea1ad778990540c576736e3e89d07a6fd9a66cf6e3e79d0d708000e47e17086ad290db04f30363cef83067efb521adbd880a502";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021030020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40fad72a56ca46a103a01bdac5db40a3eb58c061487c94d267d6e98a797cf661c5c3bc4f8189c3f62bb3bd9c427f28c338362c537abaa8a5281ae391918d5d070800009006fa46f038224e8004bdda97f2e7a60c2c3d135bce7cb15541e5c0aae907a400204870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e86740e317dfaafa564987e9d3948ceea1ad778990540c576736e3e89d07a6fd9a66cf6e3e79d0d708000e47e17086ad290db04f30363cef83067efb521adbd880a502";
+}
+
+mod multi_agent {
+    pub const DATA_TO_SIGN: &str = "5efa3c4f02f83a0f4b2d69fc95c607cc02825cc4e7be536ef0992df050d9e67c0007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada000000002101ce2fd04ac9efa74f17595e5785e847a2399d7e637f5e8179244f76191f653276";
+    pub const SENDER_SIGNATURE: &str = "deb51039045d488d8ef1eaca5ebbcc1a502acd27f249212312342fb55b48868a0980b7fee41a7a4341eb57b0ef206c8044a577571cc71e43f381542d1739070f";
+    pub const SECONDARY_SIGNATURE: &str = "22cec37de2f5a65586608e6671c72371f7373ff7ffd0f0091756d73e9917282ee334d4bfc6b925544fef32e71e3f830b859511c60d6c36a7eef36725ae567c09";
+    pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021020020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40deb51039045d488d8ef1eaca5ebbcc1a502acd27f249212312342fb55b48868a0980b7fee41a7a4341eb57b0ef206c8044a577571cc71e43f381542d1739070f01ce2fd04ac9efa74f17595e5785e847a2399d7e637f5e8179244f76191f653276010020ee93a4f66f8d16b819bb9beb9ffccdfcdc1412e87fee6a324c2a99a1e0e671484022cec37de2f5a65586608e6671c72371f7373ff7ffd0f0091756d73e9917282ee334d4bfc6b925544fef32e71e3f830b859511c60d6c36a7eef36725ae567c09";
+}
+
+fn additional_signer(address: &str, private_key: &str) -> Proto::AdditionalSigner<'static> {
+    Proto::AdditionalSigner {
+        address: address.to_string().into(),
+        private_key: private_key.decode_hex().unwrap().into(),
+    }
+}
+
+fn fee_payer_input(private_key: &str, fee_payer_private_key: &str) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        fee_payer: Some(additional_signer(FEE_PAYER_ADDRESS, fee_payer_private_key)),
+        ..aptos_sign_transfer_input()
+    }
+}
+
+fn multi_agent_input(
+    private_key: &str,
+    secondary_private_key: &str,
+) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        secondary_signers: vec![additional_signer(SECONDARY_ADDRESS, secondary_private_key)],
+        ..aptos_sign_transfer_input()
+    }
+}
+
+#[track_caller]
+fn compile(
+    input: &Proto::SigningInput<'_>,
+    data_to_sign: &str,
+    signatures: &[&str],
+    public_keys: &[&str],
+) -> Proto::SigningOutput<'static> {
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Aptos, input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), data_to_sign);
+
+    let signatures = signatures.iter().map(|s| s.decode_hex().unwrap()).collect();
+    let public_keys = public_keys
+        .iter()
+        .map(|pk| pk.decode_hex().unwrap())
+        .collect();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    compiler.compile(CoinType::Aptos, input, signatures, public_keys)
+}
+
+#[test]
+fn test_aptos_sign_fee_payer() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Aptos,
+        fee_payer_input(PRIVATE_KEY, FEE_PAYER_PRIVATE_KEY),
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), fee_payer::ENCODED);
+
+    // The authenticator contains the sender signature.
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        fee_payer::SENDER_SIGNATURE
+    );
+    assert_eq!(authenticator.public_key.to_hex(), PUBLIC_KEY);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq_json!(
+        json["signature"],
+        json!({
+            "sender": {
+                "public_key": format!("0x{PUBLIC_KEY}"),
+                "signature": format!("0x{}", fee_payer::SENDER_SIGNATURE),
+                "type": "ed25519_signature"
+            },
+            "secondary_signer_addresses": [],
+            "secondary_signers": [],
+            "fee_payer_address": FEE_PAYER_ADDRESS,
+            "fee_payer_signer": {
+                "public_key": format!("0x{FEE_PAYER_PUBLIC_KEY}"),
+                "signature": format!("0x{}", fee_payer::FEE_PAYER_SIGNATURE),
+                "type": "ed25519_signature"
+            },
+            "type": "fee_payer_signature"
+        })
+    );
+}
+
+#[test]
+fn test_aptos_compile_fee_payer() {
+    let input = fee_payer_input("", "");
+    let output = compile(
+        &input,
+        fee_payer::DATA_TO_SIGN,
+        &[fee_payer::SENDER_SIGNATURE, fee_payer::FEE_PAYER_SIGNATURE],
+        &[PUBLIC_KEY, FEE_PAYER_PUBLIC_KEY],
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), fee_payer::ENCODED);
+}
+
+#[test]
+fn test_aptos_compile_fee_payer_missing_signature() {
+    let input = fee_payer_input("", "");
+    let output = compile(
+        &input,
+        fee_payer::DATA_TO_SIGN,
+        &[fee_payer::SENDER_SIGNATURE],
+        &[PUBLIC_KEY],
+    );
+
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
+
+#[test]
+fn test_aptos_sign_multi_agent() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Aptos,
+        multi_agent_input(PRIVATE_KEY, SECONDARY_PRIVATE_KEY),
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), multi_agent::ENCODED);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq_json!(
+        json["signature"],
+        json!({
+            "sender": {
+                "public_key": format!("0x{PUBLIC_KEY}"),
+                "signature": format!("0x{}", multi_agent::SENDER_SIGNATURE),
+                "type": "ed25519_signature"
+            },
+            "secondary_signer_addresses": [SECONDARY_ADDRESS],
+            "secondary_signers": [{
+                "public_key": format!("0x{SECONDARY_PUBLIC_KEY}"),
+                "signature": format!("0x{}", multi_agent::SECONDARY_SIGNATURE),
+                "type": "ed25519_signature"
+            }],
+            "type": "multi_agent_signature"
+        })
+    );
+}
+
+#[test]
+fn test_aptos_compile_multi_agent() {
+    let input = multi_agent_input("", "");
+    let output = compile(
+        &input,
+        multi_agent::DATA_TO_SIGN,
+        &[
+            multi_agent::SENDER_SIGNATURE,
+            multi_agent::SECONDARY_SIGNATURE,
+        ],
+        &[PUBLIC_KEY, SECONDARY_PUBLIC_KEY],
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), multi_agent::ENCODED);
+}
+
+#[test]
+fn test_aptos_compile_multi_agent_too_many_signatures() {
+    let input = multi_agent_input("", "");
+    let output = compile(
+        &input,
+        multi_agent::DATA_TO_SIGN,
+        &[
+            multi_agent::SENDER_SIGNATURE,
+            multi_agent::SECONDARY_SIGNATURE,
+            fee_payer::FEE_PAYER_SIGNATURE,
+        ],
+        &[PUBLIC_KEY, SECONDARY_PUBLIC_KEY, FEE_PAYER_PUBLIC_KEY],
+    );
+
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
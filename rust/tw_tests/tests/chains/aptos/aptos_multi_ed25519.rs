@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::test_cases::transfer_b4d62afd::{aptos_sign_transfer_input, PRIVATE_KEY};
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_misc::assert_eq_json;
+use tw_proto::Aptos::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PRIVATE_KEY_1: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+const PRIVATE_KEY_2: &str = "4646464646464646464646464646464646464646464646464646464646464646";
+
+const PUBLIC_KEY_0: &str = "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c";
+const PUBLIC_KEY_1: &str = "4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867";
+const PUBLIC_KEY_2: &str = "ee93a4f66f8d16b819bb9beb9ffccdfcdc1412e87fee6a324c2a99a1e0e67148";
+
+/// Address of the 2-of-3 multi-ed25519 account.
+pub(super) const SENDER: &str =
+    "0x7447e7cab0c820b2bac84a946631ec6be40757a99cb663743a85ffc41e87f1de";
+
+const RAW_TXN: &str = "7447e7cab0c820b2bac84a946631ec6be40757a99cb663743a85ffc41e87f1de63000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021";
+const DATA_TO_SIGN: &str = "b5e97db07fa0bd0e5598aa3643a9bc6f6693bddc1a9fec9e674a461eaa00b1937447e7cab0c820b2bac84a946631ec6be40757a99cb663743a85ffc41e87f1de63000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021";
+const SIGNATURE_0: &str = "aaed466bd2af1e5a70510eae1b3fe0788a92d1356fe49d51c09a0a5fe1d9485f9ebb839b5138c7a0b42f6a977949d980ccae0727840d561d81ad8b22e4c2230e";
+const SIGNATURE_2: &str = "127073d729c1eb009dafa1441c275097570e27ede5abdd970fc69f7cbb49423b314f073e3a077599284ceb3149131da17db4c4b703f7e8f7e8298d775c9a8d0c";
+pub(super) const ENCODED: &str = "7447e7cab0c820b2bac84a946631ec6be40757a99cb663743a85ffc41e87f1de63000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210161ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867ee93a4f66f8d16b819bb9beb9ffccdfcdc1412e87fee6a324c2a99a1e0e67148028401aaed466bd2af1e5a70510eae1b3fe0788a92d1356fe49d51c09a0a5fe1d9485f9ebb839b5138c7a0b42f6a977949d980ccae0727840d561d81ad8b22e4c2230e127073d729c1eb009dafa1441c275097570e27ede5abdd970fc69f7cbb49423b314f073e3a077599284ceb3149131da17db4c4b703f7e8f7e8298d775c9a8d0ca0000000";
+
+fn multi_ed25519_input(private_keys: &[&str]) -> Proto::SigningInput<'static> {
+    let public_keys = [PUBLIC_KEY_0, PUBLIC_KEY_1, PUBLIC_KEY_2]
+        .iter()
+        .map(|pk| pk.decode_hex().unwrap().into())
+        .collect();
+
+    Proto::SigningInput {
+        sender: SENDER.into(),
+        multi_ed25519: Some(Proto::MultiEd25519PublicKey {
+            public_keys,
+            threshold: 2,
+        }),
+        multi_ed25519_private_keys: private_keys
+            .iter()
+            .map(|pk| pk.decode_hex().unwrap().into())
+            .collect(),
+        ..aptos_sign_transfer_input()
+    }
+}
+
+#[test]
+fn test_aptos_sign_multi_ed25519() {
+    // Keys can be passed in any order.
+    let input = multi_ed25519_input(&[PRIVATE_KEY_2, PRIVATE_KEY]);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), ENCODED);
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq_json!(
+        json["signature"],
+        json!({
+            "public_keys": [
+                format!("0x{PUBLIC_KEY_0}"),
+                format!("0x{PUBLIC_KEY_1}"),
+                format!("0x{PUBLIC_KEY_2}"),
+            ],
+            "signatures": [format!("0x{SIGNATURE_0}"), format!("0x{SIGNATURE_2}")],
+            "threshold": 2,
+            "bitmap": "0xa0000000",
+            "type": "multi_ed25519_signature"
+        })
+    );
+}
+
+#[test]
+fn test_aptos_sign_multi_ed25519_insufficient_signatures() {
+    let input = multi_ed25519_input(&[PRIVATE_KEY_1]);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
+
+#[test]
+fn test_aptos_compile_multi_ed25519() {
+    let input = multi_ed25519_input(&[]);
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Aptos, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data.to_hex(), DATA_TO_SIGN);
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Aptos,
+        &input,
+        vec![
+            SIGNATURE_0.decode_hex().unwrap(),
+            SIGNATURE_2.decode_hex().unwrap(),
+        ],
+        vec![
+            PUBLIC_KEY_0.decode_hex().unwrap(),
+            PUBLIC_KEY_2.decode_hex().unwrap(),
+        ],
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded.to_hex(), ENCODED);
+}
+
+#[test]
+fn test_aptos_sign_multi_ed25519_invalid_threshold() {
+    let mut input = multi_ed25519_input(&[PRIVATE_KEY, PRIVATE_KEY_2]);
+    input.multi_ed25519.as_mut().unwrap().threshold = 4;
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::aptos_multi_agent::{fee_payer, FEE_PAYER_ADDRESS};
+use crate::chains::aptos::aptos_multi_ed25519;
+use crate::chains::aptos::test_cases::transfer_b4d62afd::{
+    expected_json, ENCODED, RAW_TXN, SIGNATURE,
+};
+use serde_json::{json, Value as Json};
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_misc::assert_eq_json;
+use tw_proto::Aptos::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const SENDER: &str = "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30";
+const PUBLIC_KEY: &str = "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c";
+
+fn decode<'a>(
+    decoder: &'a mut TransactionDecoderHelper<'a, Proto::DecodingTransactionOutput<'a>>,
+    encoded: &str,
+) -> Proto::DecodedTransaction<'a> {
+    let output = decoder.decode(CoinType::Aptos, encoded.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    output.transaction.unwrap()
+}
+
+#[test]
+fn test_aptos_decode_signed_transaction() {
+    let mut decoder = TransactionDecoderHelper::default();
+    let tx = decode(&mut decoder, ENCODED);
+
+    assert_eq!(tx.sender, SENDER);
+    assert_eq!(tx.sequence_number, 99);
+    assert_eq!(tx.max_gas_amount, 3296766);
+    assert_eq!(tx.gas_unit_price, 100);
+    assert_eq!(tx.expiration_timestamp_secs, 3664390082);
+    assert_eq!(tx.chain_id, 33);
+
+    let entry_function = tx.entry_function.unwrap();
+    assert_eq!(entry_function.function, "0x1::aptos_account::transfer");
+    assert!(entry_function.type_arguments.is_empty());
+    assert_eq!(
+        entry_function
+            .arguments
+            .iter()
+            .map(|arg| arg.to_hex())
+            .collect::<Vec<_>>(),
+        [
+            "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "e803000000000000"
+        ]
+    );
+    assert_eq_json!(entry_function.json_arguments, json!([SENDER, "1000"]));
+
+    let authenticator = tx.authenticator.unwrap();
+    assert_eq!(authenticator.signature.to_hex(), SIGNATURE);
+    assert_eq!(authenticator.public_key.to_hex(), PUBLIC_KEY);
+
+    // The decoded transaction is the same as the signed one.
+    assert_eq_json!(tx.json, expected_json());
+}
+
+#[test]
+fn test_aptos_decode_raw_transaction() {
+    let mut decoder = TransactionDecoderHelper::default();
+    let tx = decode(&mut decoder, RAW_TXN);
+
+    assert_eq!(tx.sender, SENDER);
+    assert_eq!(tx.sequence_number, 99);
+    assert_eq!(tx.chain_id, 33);
+    assert!(tx.authenticator.is_none());
+
+    let mut expected = expected_json();
+    expected.as_object_mut().unwrap().remove("signature");
+    assert_eq_json!(tx.json, expected);
+}
+
+#[test]
+fn test_aptos_decode_nft_offer() {
+    // Signed in `test_aptos_nft_offer`.
+    let mut decoder = TransactionDecoderHelper::default();
+    let tx = decode(&mut decoder, "783135e8b00430253a22ba041d860c373d7a1501ccf7ac2d1ad37a8ed2775aee01000000000000000200000000000000000000000000000000000000000000000000000000000000030f746f6b656e5f7472616e73666572730c6f666665725f73637269707400062007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30209125e4054d884fdc7296b66e12c0d63a7baa0d88c77e8e784987c0a967c670ac0f0e546f70617a2054726f6f706572731514546f70617a2054726f6f70657220233230303638080000000000000000080100000000000000fe4d3200000000006400000000000000c2276ada00000000020020d1d99b67e37b483161a0fa369c46f34a3be4863c20e20fc7cdc669c0826a411340af5c7357a83c69e3f425beb23eaf232f8bb36dea3b7cad4a7ab8d735cee999c8ec5285005adf69dc85a6c34b042dd0308fe92b76dad5d6ac88c7b9259902c10f");
+
+    let entry_function = tx.entry_function.unwrap();
+    assert_eq!(
+        entry_function.function,
+        "0x3::token_transfers::offer_script"
+    );
+    assert_eq_json!(
+        entry_function.json_arguments,
+        json!([
+            "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "0x9125e4054d884fdc7296b66e12c0d63a7baa0d88c77e8e784987c0a967c670ac",
+            "Topaz Troopers",
+            "Topaz Trooper #20068",
+            "0",
+            "1"
+        ])
+    );
+}
+
+#[test]
+fn test_aptos_decode_unknown_entry_function() {
+    // Signed in `test_aptos_tortuga_stake`.
+    let mut decoder = TransactionDecoderHelper::default();
+    let tx = decode(&mut decoder, "f3d7f364dd7705824a5ebda9c7aab6cb3fc7bb5b58718249f12defec240b36cc1300000000000000028f396e4246b2ba87b51c0739ef5ea4f26515a98375308c31ac2ec1e42142a57f0c7374616b655f726f75746572057374616b6500010800e1f50500000000b2150000000000006400000000000000cbd78d630000000001002089e0211d7e19c7d3a8e2030fe16c936a690ca9b95569098c5d2bf1031ff44bc44022d3166c3003f9c24a35fd39c71eb27e0d2bb82541be610822165c9283f56fefe5a9d46421b9caf174995bd8f83141e60ea8cff521ecf4741fe19e6ae9a5680d");
+
+    let entry_function = tx.entry_function.unwrap();
+    assert_eq!(
+        entry_function.function,
+        "0x8f396e4246b2ba87b51c0739ef5ea4f26515a98375308c31ac2ec1e42142a57f::stake_router::stake"
+    );
+    // The argument types are unknown, so the arguments are represented as hex strings.
+    assert_eq_json!(entry_function.json_arguments, json!(["0x00e1f50500000000"]));
+}
+
+#[test]
+fn test_aptos_decode_fee_payer_transaction() {
+    let mut decoder = TransactionDecoderHelper::default();
+    let tx = decode(&mut decoder, fee_payer::ENCODED);
+
+    let authenticator = tx.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        fee_payer::SENDER_SIGNATURE
+    );
+    assert_eq!(authenticator.public_key.to_hex(), PUBLIC_KEY);
+
+    let json: Json = serde_json::from_str(&tx.json).unwrap();
+    assert_eq!(json["signature"]["type"], "fee_payer_signature");
+    assert_eq!(json["signature"]["fee_payer_address"], FEE_PAYER_ADDRESS);
+    assert_eq!(
+        json["signature"]["fee_payer_signer"]["signature"],
+        format!("0x{}", fee_payer::FEE_PAYER_SIGNATURE)
+    );
+}
+
+#[test]
+fn test_aptos_decode_multi_ed25519_transaction() {
+    let mut decoder = TransactionDecoderHelper::default();
+    let tx = decode(&mut decoder, aptos_multi_ed25519::ENCODED);
+
+    assert_eq!(tx.sender, aptos_multi_ed25519::SENDER);
+    let json: Json = serde_json::from_str(&tx.json).unwrap();
+    assert_eq!(json["signature"]["type"], "multi_ed25519_signature");
+    assert_eq!(json["signature"]["threshold"], 2);
+    assert_eq!(json["signature"]["bitmap"], "0xa0000000");
+}
+
+#[test]
+fn test_aptos_decode_transaction_invalid() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    // `RAW_TXN` without the chain id.
+    let output = decoder.decode(
+        CoinType::Aptos,
+        RAW_TXN[..RAW_TXN.len() - 2].decode_hex().unwrap(),
+    );
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
@@ -4,7 +4,10 @@
 
 mod aptos_address;
 mod aptos_compile;
+mod aptos_multi_agent;
+mod aptos_multi_ed25519;
 mod aptos_sign;
+mod aptos_transaction_decoder;
 mod aptos_transaction_util;
 mod test_cases;
 
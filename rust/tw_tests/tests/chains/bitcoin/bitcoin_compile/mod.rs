@@ -5,3 +5,5 @@
 mod brc20;
 mod compile_error;
 mod p2pkh;
+mod p2tr_frost;
+mod p2tr_musig2;
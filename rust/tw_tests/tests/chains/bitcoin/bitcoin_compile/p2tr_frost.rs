@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    btc_info, compile, dust_threshold, input, output, TransactionOneof, DUST, MINER_FEE, ONE_BTC,
+    SIGHASH_ALL,
+};
+use crate::chains::common::frost::{self, SECP256K1_COMMITMENT, SECP256K1_SHARES};
+use tw_any_coin::test_utils::sign_utils::PreImageHelper;
+use tw_bitcoin::modules::compiler::utxo_sighash_from_proto;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::ToHex;
+use tw_keypair::frost::ciphersuite::Secp256k1Taproot;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+/// Two of three FROST participants spend a P2TR output locked with their group key via the key path.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_bitcoin_compile_p2tr_key_path_frost() {
+    let (key_packages, public_key_package) =
+        frost::key_packages::<Secp256k1Taproot>(SECP256K1_COMMITMENT, &SECP256K1_SHARES);
+
+    // The group key is used as an ordinary taproot internal key.
+    let internal_pubkey = public_key_package.verifying_key_bytes();
+    assert_eq!(
+        internal_pubkey.to_hex(),
+        "021010dbdf30e71b80408001fa7b5c3c5bfdf7bd4248f3916a9b65ecf0309cef6b"
+    );
+
+    let txid = "c50563913e5a838f937c94232f5a8fc74e58b629fae41dfdffcc9a70f833b53a";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: ONE_BTC * 50,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2tr_key_path(internal_pubkey.clone()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: ONE_BTC * 50 - MINER_FEE,
+        to_recipient: output::p2tr_key_path(internal_pubkey.clone()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Bitcoin, &signing);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.sighashes.len(), 1);
+
+    let sighash = utxo_sighash_from_proto(&preimage_output.sighashes[0]).unwrap();
+    assert_eq!(
+        sighash.sighash.to_hex(),
+        "69b2b9a5361d974f005a0b5c993d705be44e9552ab5f1ebf9b6d1b3b830b9cf3"
+    );
+
+    // Every participant tweaks its key package the same way as the taproot output key is derived.
+    let key_packages: Vec<_> = key_packages
+        .into_iter()
+        .map(|key_package| key_package.tweak(None))
+        .collect();
+    let public_key_package = public_key_package.tweak(None);
+    // The tweaked output key.
+    assert_eq!(
+        sighash.signer_pubkey.to_hex(),
+        "bfa7407bc37a3149a0ec4e6d9f5b7b076474aab568d79520fedcb4723881136e"
+    );
+    assert_eq!(
+        &public_key_package.verifying_key_bytes()[1..],
+        sighash.signer_pubkey.as_slice()
+    );
+
+    let signature = frost::sign(
+        &key_packages,
+        &public_key_package,
+        sighash.sighash.as_slice(),
+    );
+    assert_eq!(
+        signature.to_vec().to_hex(),
+        "f223ed80f90aecbd0ad16b92aba74c52d10f54606a767fc88260e23fe974c5c210f2ac4cc027d5d78151a83ee9e95b6e38554affac68bac8a76dd4e05dad3df0"
+    );
+
+    compile::BitcoinCompileHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_pubkey_sig(internal_pubkey, signature.to_vec())
+        .compile(compile::Expected {
+            encoded: "020000000001013ab533f8709accfffd1de4fa29b6584ec78f5a2f23947c938f835a3e916305c50000000000ffffffff01c0aff62901000000225120bfa7407bc37a3149a0ec4e6d9f5b7b076474aab568d79520fedcb4723881136e0140f223ed80f90aecbd0ad16b92aba74c52d10f54606a767fc88260e23fe974c5c210f2ac4cc027d5d78151a83ee9e95b6e38554affac68bac8a76dd4e05dad3df000000000",
+            txid: "f83b3c2ac0a8d20709e2a9cb7584d3ec9753e3680baf0d0ea27d95f33850aac0",
+            inputs: vec![ONE_BTC * 50],
+            outputs: vec![ONE_BTC * 50 - MINER_FEE],
+            vsize: 111,
+            weight: 444,
+            fee: MINER_FEE,
+        });
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    btc_info, compile, dust_threshold, input, output, TransactionOneof, DUST, MINER_FEE, ONE_BTC,
+    SIGHASH_ALL,
+};
+use tw_any_coin::test_utils::sign_utils::PreImageHelper;
+use tw_bitcoin::modules::compiler::utxo_sighash_from_proto;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::ToHex;
+use tw_hash::H256;
+use tw_keypair::schnorr;
+use tw_keypair::schnorr::musig2::{
+    key_sort, AggregatedNonce, KeyAggContext, PartialSignature, SecretNonce, Session,
+};
+use tw_misc::traits::ToBytesVec;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const ALICE_PRIVATE_KEY: &str = "12ce558df23528f1aa86f1f51ac7e13a197a06bda27610fa89e13b04c40ee999";
+const BOB_PRIVATE_KEY: &str = "26c2566adcc030a1799213bfd546e615f6ab06f72085ec6806ff1761da48d227";
+
+/// Alice and Bob spend a P2TR output locked with their MuSig2 aggregate key via the key path.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_bitcoin_compile_p2tr_key_path_musig2() {
+    let alice_private_key = schnorr::PrivateKey::try_from(ALICE_PRIVATE_KEY).unwrap();
+    let bob_private_key = schnorr::PrivateKey::try_from(BOB_PRIVATE_KEY).unwrap();
+    let participants = key_sort(&[alice_private_key.public(), bob_private_key.public()]);
+
+    // The aggregate key is used as an ordinary taproot internal key.
+    let internal_pubkey = KeyAggContext::new(participants.clone())
+        .unwrap()
+        .aggregated_pubkey();
+    assert_eq!(
+        internal_pubkey.to_vec().to_hex(),
+        "0369424eb4b4041eb6f6340e4cf6b393a49d34e88d51e7b3b3334ae7e0f893bd49"
+    );
+
+    let txid = "c50563913e5a838f937c94232f5a8fc74e58b629fae41dfdffcc9a70f833b53a";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: ONE_BTC * 50,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2tr_key_path(internal_pubkey.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: ONE_BTC * 50 - MINER_FEE,
+        to_recipient: output::p2tr_key_path(internal_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Bitcoin, &signing);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.sighashes.len(), 1);
+
+    let sighash = utxo_sighash_from_proto(&preimage_output.sighashes[0]).unwrap();
+    assert_eq!(
+        sighash.sighash.to_hex(),
+        "378ff5db4d13d0213e14d7e683f8230c846ba63860030942a24e8c2991ca5f52"
+    );
+    // The tweaked output key.
+    assert_eq!(
+        sighash.signer_pubkey.to_hex(),
+        "63ff87c8e3ef2f66b08133fc2ed818d7ecc5b2f70f85b2ae871a81d90ac6ce5b"
+    );
+
+    // Every participant builds the same tweaked key aggregation context.
+    let key_agg_ctx = sighash.musig2_key_agg_ctx(participants).unwrap();
+
+    // First round: exchange public nonces.
+    // Please note that nonces must be generated with `SecretNonce::generate` in production.
+    let gen_nonce = |rand: u8, private_key: &schnorr::PrivateKey| {
+        SecretNonce::generate_with_rand(
+            H256::from([rand; 32]),
+            &private_key.public(),
+            Some(private_key),
+            Some(&key_agg_ctx.aggregated_x_only()),
+            Some(sighash.sighash.as_slice()),
+            &[],
+        )
+        .unwrap()
+    };
+    let alice_nonce = gen_nonce(1, &alice_private_key);
+    let bob_nonce = gen_nonce(2, &bob_private_key);
+    let bob_public_nonce = bob_nonce.public_nonce();
+
+    // Second round: exchange partial signatures.
+    let aggregated_nonce =
+        AggregatedNonce::aggregate(&[alice_nonce.public_nonce(), bob_public_nonce.clone()])
+            .unwrap();
+    let session = Session::new(key_agg_ctx, &aggregated_nonce, sighash.sighash).unwrap();
+
+    let alice_partial = session.sign(alice_nonce, &alice_private_key).unwrap();
+    let bob_partial = session.sign(bob_nonce, &bob_private_key).unwrap();
+
+    // Partial signatures are usually sent over the network.
+    let bob_partial = PartialSignature::try_from(bob_partial.bytes().as_slice()).unwrap();
+    assert!(session.verify_partial_signature(
+        &bob_partial,
+        &bob_public_nonce,
+        &bob_private_key.public()
+    ));
+
+    let signature = session.aggregate(&[alice_partial, bob_partial]).unwrap();
+    assert_eq!(
+        signature.to_vec().to_hex(),
+        "e6c6cfee43717ce014316206f3b817fd9fa97bec268f0d44541b5b237e3419e9702a8c156bba1338e57d7be97d0a381ee3ce8f114500f2c18f62934ee597f9de"
+    );
+
+    compile::BitcoinCompileHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_pubkey_sig(internal_pubkey.to_vec(), signature.to_vec())
+        .compile(compile::Expected {
+            encoded: "020000000001013ab533f8709accfffd1de4fa29b6584ec78f5a2f23947c938f835a3e916305c50000000000ffffffff01c0aff6290100000022512063ff87c8e3ef2f66b08133fc2ed818d7ecc5b2f70f85b2ae871a81d90ac6ce5b0140e6c6cfee43717ce014316206f3b817fd9fa97bec268f0d44541b5b237e3419e9702a8c156bba1338e57d7be97d0a381ee3ce8f114500f2c18f62934ee597f9de00000000",
+            txid: "c67a50f1e84dffe8ebd53083197ebf1c8ba717bb348956d377b25539955e772a",
+            inputs: vec![ONE_BTC * 50],
+            outputs: vec![ONE_BTC * 50 - MINER_FEE],
+            vsize: 111,
+            weight: 444,
+            fee: MINER_FEE,
+        });
+}
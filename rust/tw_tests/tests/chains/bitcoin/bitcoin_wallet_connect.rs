@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::psbt_sign::{BitcoinPsbtSignHelper, Expected};
+use serde_json::json;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::WalletConnect::Proto as WCProto;
+use WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum;
+
+#[test]
+fn test_bitcoin_wallet_connect_sign_psbt() {
+    // The same PSBT as in `test_bitcoin_sign_psbt_thorchain_swap_witness`, but base64 encoded.
+    let request_params = json!({
+        "account": "bc1qkyu3n8k8jmekl3pwvdl59k5w8enjp25akz2r3z",
+        "psbt": "cHNidP8BALwCAAAAARRwENtfvPYZBnwQkP7GXBMUQ/vID7Sq7r6UDkQgYJjGAAAAAAD/////A2DqAAAAAAAAFgAU8ipwNhcDXvf0kHQ9UPJq4Iww0KcAAAAAAAAAAEJqQD06R0FJQS5BVE9NOmNvc21vczFzc3d5emZtdDZ1OWo3NDd3NTd1NDh0Z3hkZXU5NXN1emZsbXF1dTowOnQ6NTA+EgAAAAAAABYAFLE5GZ7HlvNvxC5jf0Lajj5nIKqdAAAAAAABAR9mAwEAAAAAABYAFLE5GZ7HlvNvxC5jf0Lajj5nIKqdAAAAAA==",
+        "broadcast": false
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::BitcoinSignPsbt,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Bitcoin, &input);
+    assert_eq!(parsing_output.error, SigningError::OK);
+
+    let mut signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::bitcoin(input) => input,
+        _ => unreachable!(),
+    };
+
+    // Set missing private key.
+    let private_key = "f00ffbe44c5c2838c13d2778854ac66b75e04eb6054f0241989e223223ad5e55"
+        .decode_hex()
+        .unwrap();
+    signing_input.private_keys = vec![private_key.into()];
+
+    // Successfully broadcasted: https://mempool.space/tx/634a416e82ac710166725f6a4090ac7b5db69687e86b2d2e38dcb3d91c956c32
+    BitcoinPsbtSignHelper::new(&signing_input)
+        .coin(CoinType::Bitcoin)
+        .sign_psbt(Expected {
+            psbt: "70736274ff0100bc0200000001147010db5fbcf619067c1090fec65c131443fbc80fb4aaeebe940e44206098c60000000000ffffffff0360ea000000000000160014f22a703617035ef7f490743d50f26ae08c30d0a70000000000000000426a403d3a474149412e41544f4d3a636f736d6f7331737377797a666d743675396a373437773537753438746778646575393573757a666c6d7175753a303a743a35303e12000000000000160014b139199ec796f36fc42e637f42da8e3e6720aa9d000000000001011f6603010000000000160014b139199ec796f36fc42e637f42da8e3e6720aa9d01086c02483045022100b1229a008f20691639767bf925d6b8956ea957ccc633ad6b5de3618733a55e6b02205774d3320489b8a57a6f8de07f561de3e660ff8e587f6ac5422c49020cd4dc9101210306d8c664ea8fd2683eebea1d3114d90e0a5429e5783ba49b80ddabce04ff28f300000000",
+            encoded: "02000000000101147010db5fbcf619067c1090fec65c131443fbc80fb4aaeebe940e44206098c60000000000ffffffff0360ea000000000000160014f22a703617035ef7f490743d50f26ae08c30d0a70000000000000000426a403d3a474149412e41544f4d3a636f736d6f7331737377797a666d743675396a373437773537753438746778646575393573757a666c6d7175753a303a743a35303e12000000000000160014b139199ec796f36fc42e637f42da8e3e6720aa9d02483045022100b1229a008f20691639767bf925d6b8956ea957ccc633ad6b5de3618733a55e6b02205774d3320489b8a57a6f8de07f561de3e660ff8e587f6ac5422c49020cd4dc9101210306d8c664ea8fd2683eebea1d3114d90e0a5429e5783ba49b80ddabce04ff28f300000000",
+            txid: "634a416e82ac710166725f6a4090ac7b5db69687e86b2d2e38dcb3d91c956c32",
+            vsize: 216,
+            weight: 862,
+            fee: 1736,
+        });
+}
+
+#[test]
+fn test_bitcoin_wallet_connect_sign_psbt_invalid() {
+    let request_params = json!({
+        // Base64 encoded "Hello, World!".
+        "psbt": "SGVsbG8sIFdvcmxkIQ=="
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::BitcoinSignPsbt,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Bitcoin, &input);
+    assert_eq!(parsing_output.error, SigningError::Error_input_parse);
+}
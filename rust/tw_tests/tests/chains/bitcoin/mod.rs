@@ -7,3 +7,4 @@ mod bitcoin_compile;
 mod bitcoin_plan;
 mod bitcoin_sign;
 mod bitcoin_transaction_util;
+mod bitcoin_wallet_connect;
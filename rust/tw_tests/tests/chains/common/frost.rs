@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::collections::BTreeMap;
+use tw_encoding::hex::DecodeHex;
+use tw_hash::H256;
+use tw_keypair::frost::ciphersuite::Ciphersuite;
+use tw_keypair::frost::keys::{
+    KeyPackage, PublicKeyPackage, SecretShare, VerifiableSecretSharingCommitment,
+};
+use tw_keypair::frost::round1::SigningNonces;
+use tw_keypair::frost::{
+    aggregate, round2, verify_signature_share, Identifier, Signature, SigningPackage,
+};
+
+/// The commitment to the polynomial of a 2-of-3 Ed25519 group generated by a trusted dealer.
+/// The group public key is `989cc09d9a0f7ebf6315ce5dee3bd21abf1689c9d058a847baa03ab9a4b308a0`.
+pub const ED25519_COMMITMENT: &str = "989cc09d9a0f7ebf6315ce5dee3bd21abf1689c9d058a847baa03ab9a4b308a030464f059ddb8a737e3f0202e0e805dcbf9cbc64ac9ee1ab2e504742dd17072e";
+/// The shares of the first and the third participants of the [`ED25519_COMMITMENT`] group.
+pub const ED25519_SHARES: [(u16, &str); 2] = [
+    (
+        1,
+        "8c9ea8eec04256e87cc38cf630850176943fc48b69a484970fe8869640dabe0d",
+    ),
+    (
+        3,
+        "0cd11cd78b7864678b6605925ed7a7d7b65fa227d520ad7f04014dac11d7ea07",
+    ),
+];
+
+/// The commitment to the polynomial of a 2-of-3 secp256k1 group generated by a trusted dealer.
+/// The group public key is `021010dbdf30e71b80408001fa7b5c3c5bfdf7bd4248f3916a9b65ecf0309cef6b`.
+pub const SECP256K1_COMMITMENT: &str = "021010dbdf30e71b80408001fa7b5c3c5bfdf7bd4248f3916a9b65ecf0309cef6b03bfd3c3c814b9461f5f45d1ebc81e13ec414d2b8136fd94cabcf2c6b400923738";
+/// The shares of the first and the third participants of the [`SECP256K1_COMMITMENT`] group.
+pub const SECP256K1_SHARES: [(u16, &str); 2] = [
+    (
+        1,
+        "c85fe449474059576170bdba378bcd8037cd40433a2f428b9c948b64c85cb2a6",
+    ),
+    (
+        3,
+        "7c1ba6d656b5c10adde081b2c6bce390257c1347926583ede6e4e8a2d6dc7414",
+    ),
+];
+
+/// Builds the key packages of the given signers from the shares previously sent by a trusted dealer,
+/// and the public key package restricted to these signers.
+pub fn key_packages<C: Ciphersuite>(
+    commitment: &str,
+    shares: &[(u16, &str)],
+) -> (Vec<KeyPackage<C>>, PublicKeyPackage<C>) {
+    let commitment = VerifiableSecretSharingCommitment::<C>::try_from(
+        commitment.decode_hex().unwrap().as_slice(),
+    )
+    .unwrap();
+
+    let key_packages: Vec<_> = shares
+        .iter()
+        .map(|(identifier, share)| {
+            let identifier = Identifier::try_from(*identifier).unwrap();
+            let share = share.decode_hex().unwrap();
+            let secret_share = SecretShare::new(identifier, &share, commitment.clone()).unwrap();
+            KeyPackage::try_from(secret_share).unwrap()
+        })
+        .collect();
+
+    let verifying_shares = key_packages
+        .iter()
+        .map(|key_package| (key_package.identifier(), *key_package.verifying_share()))
+        .collect();
+    let public_key_package = PublicKeyPackage::new(verifying_shares, commitment.verifying_key());
+    (key_packages, public_key_package)
+}
+
+/// Runs both signing rounds and aggregates the signature shares.
+/// Please note that nonces must be generated with `SigningNonces::generate` in production.
+pub fn sign<C: Ciphersuite>(
+    key_packages: &[KeyPackage<C>],
+    public_key_package: &PublicKeyPackage<C>,
+    message: &[u8],
+) -> Signature<C> {
+    // First round: every signer commits to a pair of nonces.
+    let nonces: Vec<_> = key_packages
+        .iter()
+        .enumerate()
+        .map(|(i, key_package)| {
+            let rand = i as u8 * 2;
+            SigningNonces::generate_with_rand(
+                H256::from([rand + 1; 32]),
+                H256::from([rand + 2; 32]),
+                key_package,
+            )
+        })
+        .collect();
+    let commitments = key_packages
+        .iter()
+        .zip(nonces.iter())
+        .map(|(key_package, nonces)| (key_package.identifier(), *nonces.commitments()))
+        .collect();
+    let signing_package = SigningPackage::new(commitments, message.to_vec());
+
+    // Second round: every signer produces a signature share.
+    let mut signature_shares = BTreeMap::new();
+    for (key_package, nonces) in key_packages.iter().zip(nonces) {
+        let identifier = key_package.identifier();
+        let share = round2::sign(&signing_package, nonces, key_package).unwrap();
+        assert!(verify_signature_share(
+            identifier,
+            &share,
+            &signing_package,
+            public_key_package
+        ));
+        signature_shares.insert(identifier, share);
+    }
+
+    aggregate(&signing_package, &signature_shares, public_key_package).unwrap()
+}
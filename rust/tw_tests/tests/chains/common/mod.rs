@@ -3,3 +3,4 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod bitcoin;
+pub mod frost;
@@ -49,7 +49,7 @@ fn test_any_signer_sign_cosmos() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output = TWDataHelper::wrap(unsafe {
-        tw_any_signer_sign(input_data.ptr(), CoinType::Cosmos as u32)
+        tw_any_signer_sign(input_data.ptr(), u32::from(CoinType::Cosmos))
     })
     .to_vec()
     .expect("!tw_any_signer_sign returned nullptr");
@@ -112,7 +112,7 @@ fn test_any_signer_sign_custom_chain() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output = TWDataHelper::wrap(unsafe {
-        tw_any_signer_sign(input_data.ptr(), CoinType::Cosmos as u32)
+        tw_any_signer_sign(input_data.ptr(), u32::from(CoinType::Cosmos))
     })
     .to_vec()
     .expect("!tw_any_signer_sign returned nullptr");
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Cosmos::Proto;
+use tw_proto::WalletConnect::Proto as WCProto;
+use WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum;
+
+#[test]
+fn test_cosmos_wallet_connect_sign_direct() {
+    let request_params = json!({
+        "signerAddress": "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02",
+        "signDoc": {
+            "chainId": "gaia-13003",
+            "accountNumber": "1037",
+            "bodyBytes": "CokBChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEmkKLWNvc21vczFoc2s2anJ5eXFqZmhwNWRoYzU1dGM5anRja3lneDBlcGg2ZGQwMhItY29zbW9zMXp0NTBhenVwYW5xbGZhbTVhZmh2M2hleHd5dXRudWtlaDRjNTczGgkKBG11b24SATE=",
+            "authInfoBytes": "ClAKRgofL2Nvc21vcy5jcnlwdG8uc2VjcDI1NmsxLlB1YktleRIjCiECVyhuw/N9M1V7u6oACyd0SskCOqmWfK51oYHR/5H6ncUSBAoCCAEYCBIRCgsKBG11b24SAzIwMBDAmgw="
+        }
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::CosmosSignDirect,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Cosmos, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::OK);
+
+    let mut signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::cosmos(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.account_number, 1037);
+    assert_eq!(signing_input.chain_id, "gaia-13003");
+
+    // Set missing private key.
+    signing_input.private_key = "80e81ea269e66a0a05b11236df7919fb7fbeedba87452d667489d7403a02f005"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Cosmos, signing_input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    // Successfully broadcasted: https://www.mintscan.io/cosmos/txs/817101F3D96314AD028733248B28BAFAD535024D7D2C8875D3FE31DC159F096B
+    assert_eq!(
+        output.serialized,
+        r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CowBCokBChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEmkKLWNvc21vczFoc2s2anJ5eXFqZmhwNWRoYzU1dGM5anRja3lneDBlcGg2ZGQwMhItY29zbW9zMXp0NTBhenVwYW5xbGZhbTVhZmh2M2hleHd5dXRudWtlaDRjNTczGgkKBG11b24SATESZQpQCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAlcobsPzfTNVe7uqAAsndErJAjqplnyudaGB0f+R+p3FEgQKAggBGAgSEQoLCgRtdW9uEgMyMDAQwJoMGkD54fQAFlekIAnE62hZYl0uQelh/HLv0oQpCciY5Dn8H1SZFuTsrGdu41PH1Uxa4woptCELi/8Ov9yzdeEFAC9H"}"#
+    );
+    assert_eq!(
+        output.signature.to_hex(),
+        "f9e1f4001657a42009c4eb6859625d2e41e961fc72efd2842909c898e439fc1f549916e4ecac676ee353c7d54c5ae30a29b4210b8bff0ebfdcb375e105002f47"
+    );
+}
+
+#[test]
+fn test_cosmos_wallet_connect_sign_direct_invalid_account_number() {
+    let request_params = json!({
+        "signerAddress": "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02",
+        "signDoc": {
+            "chainId": "gaia-13003",
+            "accountNumber": "not a number",
+            "bodyBytes": "CokBChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEmkKLWNvc21vczFoc2s2anJ5eXFqZmhwNWRoYzU1dGM5anRja3lneDBlcGg2ZGQwMhItY29zbW9zMXp0NTBhenVwYW5xbGZhbTVhZmh2M2hleHd5dXRudWtlaDRjNTczGgkKBG11b24SATE=",
+            "authInfoBytes": ""
+        }
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::CosmosSignDirect,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Cosmos, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::Error_input_parse);
+}
@@ -5,3 +5,4 @@
 mod cosmos_address;
 mod cosmos_sign;
 mod cosmos_transaction_util;
+mod cosmos_wallet_connect;
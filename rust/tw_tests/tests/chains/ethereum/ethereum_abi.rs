@@ -55,7 +55,7 @@ fn test_ethereum_abi_decode_contract_call() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output_data = TWDataHelper::wrap(unsafe {
-        tw_ethereum_abi_decode_contract_call(CoinType::Ethereum as u32, input_data.ptr())
+        tw_ethereum_abi_decode_contract_call(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_ethereum_abi_decode_contract_call returned nullptr");
@@ -95,7 +95,7 @@ fn test_ethereum_abi_decode_params() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output_data = TWDataHelper::wrap(unsafe {
-        tw_ethereum_abi_decode_params(CoinType::Ethereum as u32, input_data.ptr())
+        tw_ethereum_abi_decode_params(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_ethereum_abi_decode_params returned nullptr");
@@ -132,7 +132,7 @@ fn test_ethereum_abi_function_get_type() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let actual = TWStringHelper::wrap(unsafe {
-        tw_ethereum_abi_function_get_type(CoinType::Ethereum as u32, input_data.ptr())
+        tw_ethereum_abi_function_get_type(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_string()
     .expect("!tw_ethereum_abi_function_get_type returned nullptr");
@@ -153,7 +153,7 @@ fn test_ethereum_abi_encode_function() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output_data = TWDataHelper::wrap(unsafe {
-        tw_ethereum_abi_encode_function(CoinType::Ethereum as u32, input_data.ptr())
+        tw_ethereum_abi_encode_function(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_ethereum_abi_encode_function returned nullptr");
@@ -179,7 +179,7 @@ fn test_ethereum_abi_decode_value() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output_data = TWDataHelper::wrap(unsafe {
-        tw_ethereum_abi_decode_value(CoinType::Ethereum as u32, input_data.ptr())
+        tw_ethereum_abi_decode_value(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_ethereum_abi_decode_value returned nullptr");
@@ -199,7 +199,7 @@ fn test_ethereum_abi_get_function_signature() {
     let abi_string = TWStringHelper::create(abi);
 
     let actual = TWStringHelper::wrap(unsafe {
-        tw_ethereum_abi_get_function_signature(CoinType::Ethereum as u32, abi_string.ptr())
+        tw_ethereum_abi_get_function_signature(u32::from(CoinType::Ethereum), abi_string.ptr())
     })
     .to_string()
     .expect("!tw_ethereum_abi_get_function_signature returned nullptr");
@@ -280,7 +280,7 @@ fn test_ethereum_get_function_signature_complex() {
     let abi_string = TWStringHelper::create(abi);
 
     let actual = TWStringHelper::wrap(unsafe {
-        tw_ethereum_abi_get_function_signature(CoinType::Ethereum as u32, abi_string.ptr())
+        tw_ethereum_abi_get_function_signature(u32::from(CoinType::Ethereum), abi_string.ptr())
     })
     .to_string()
     .expect("!tw_ethereum_abi_get_function_signature returned nullptr");
@@ -38,7 +38,7 @@ fn test_transaction_compiler_eth() {
     // Step 2: Obtain preimage hash
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let preimage_data = TWDataHelper::wrap(unsafe {
-        tw_transaction_compiler_pre_image_hashes(CoinType::Ethereum as u32, input_data.ptr())
+        tw_transaction_compiler_pre_image_hashes(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
@@ -65,7 +65,7 @@ fn test_transaction_compiler_eth() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let output_data = TWDataHelper::wrap(unsafe {
         tw_transaction_compiler_compile(
-            CoinType::Ethereum as u32,
+            u32::from(CoinType::Ethereum),
             input_data.ptr(),
             signatures.ptr(),
             public_keys.ptr(),
@@ -98,7 +98,7 @@ fn test_transaction_compiler_plan_not_supported() {
     };
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let plan = TWDataHelper::wrap(unsafe {
-        tw_any_signer_plan(input_data.ptr(), CoinType::Ethereum as u32)
+        tw_any_signer_plan(input_data.ptr(), u32::from(CoinType::Ethereum))
     });
     assert!(
         plan.is_null(),
@@ -20,7 +20,7 @@ fn test_ethereum_rlp() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output_data = TWDataHelper::wrap(unsafe {
-        tw_ethereum_rlp_encode(CoinType::Ethereum as u32, input_data.ptr())
+        tw_ethereum_rlp_encode(u32::from(CoinType::Ethereum), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_ethereum_rlp_encode returned nullptr");
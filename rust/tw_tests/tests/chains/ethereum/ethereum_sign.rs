@@ -40,7 +40,7 @@ fn test_any_signer_sign_eth() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output = TWDataHelper::wrap(unsafe {
-        tw_any_signer_sign(input_data.ptr(), CoinType::Ethereum as u32)
+        tw_any_signer_sign(input_data.ptr(), u32::from(CoinType::Ethereum))
     })
     .to_vec()
     .expect("!tw_any_signer_sign returned nullptr");
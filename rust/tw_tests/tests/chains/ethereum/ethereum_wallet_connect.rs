@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_any_coin::ffi::tw_message_signer::tw_message_signer_pre_image_hashes;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::sha3::keccak256;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::WalletConnect::Proto as WCProto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+use WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum;
+
+const PRIVATE_KEY: &str = "4646464646464646464646464646464646464646464646464646464646464646";
+
+#[test]
+fn test_ethereum_wallet_connect_sign_transaction() {
+    let request_params = json!([{
+        "from": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F",
+        "to": "0x3535353535353535353535353535353535353535",
+        "gas": "0x5208",
+        "gasPrice": "0x4a817c800",
+        "value": "0xde0b6b3a7640000",
+        "nonce": "0x9",
+        "chainId": "0x1"
+    }]);
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::EthSignTransaction,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Ethereum, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::OK);
+
+    let mut signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::ethereum(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.tx_mode, Proto::TransactionMode::Legacy);
+
+    // Set missing private key.
+    signing_input.private_key = PRIVATE_KEY.decode_hex().unwrap().into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Ethereum, signing_input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    assert_eq!(output.encoded.to_hex(), expected);
+}
+
+#[test]
+fn test_ethereum_wallet_connect_send_transaction_eip1559_contract_call() {
+    let request_params = json!([{
+        "from": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F",
+        "to": "0xdAC17F958D2ee523a2206206994597C13D831ec7",
+        "gasLimit": "78009",
+        "maxFeePerGas": "0x9502f9000",
+        "maxPriorityFeePerGas": "0x77359400",
+        "input": "0xa9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000000002710",
+        "nonce": "0x0",
+        "chainId": "0x1"
+    }]);
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::EthSendTransaction,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Ethereum, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::OK);
+
+    let signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::ethereum(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.tx_mode, Proto::TransactionMode::Enveloped);
+    assert_eq!(signing_input.chain_id, U256::encode_be_compact(1));
+    assert!(signing_input.nonce.is_empty());
+    assert_eq!(signing_input.gas_limit, U256::encode_be_compact(78_009));
+    assert_eq!(
+        signing_input.max_fee_per_gas,
+        U256::encode_be_compact(40_000_000_000)
+    );
+    assert_eq!(
+        signing_input.max_inclusion_fee_per_gas,
+        U256::encode_be_compact(2_000_000_000)
+    );
+    assert_eq!(
+        signing_input.to_address,
+        "0xdAC17F958D2ee523a2206206994597C13D831ec7"
+    );
+
+    let transaction = signing_input.transaction.unwrap();
+    let contract_call = match transaction.transaction_oneof {
+        Proto::mod_Transaction::OneOftransaction_oneof::contract_generic(call) => call,
+        _ => unreachable!(),
+    };
+    assert!(contract_call.amount.is_empty());
+    assert_eq!(contract_call.data.to_hex(), "a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000000002710");
+}
+
+fn parse_personal_sign_request(message: &str) -> Proto::MessageSigningInput<'static> {
+    let request_params = json!([message, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"]);
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::PersonalSign,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Ethereum, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::OK);
+
+    let signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::ethereum_message(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        signing_input.message_type,
+        Proto::MessageType::MessageType_legacy
+    );
+    signing_input
+}
+
+/// Returns the EIP-191 hash that is signed for the given message signing input.
+fn personal_sign_pre_image_hash(signing_input: &Proto::MessageSigningInput) -> Vec<u8> {
+    let input_data = TWDataHelper::create(serialize(signing_input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(u32::from(CoinType::Ethereum), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    output.data_hash.to_vec()
+}
+
+fn eip191_hash(message: &[u8]) -> Vec<u8> {
+    let prefixed = [
+        format!("\x19Ethereum Signed Message:\n{}", message.len()).as_bytes(),
+        message,
+    ]
+    .concat();
+    keccak256(&prefixed)
+}
+
+#[test]
+fn test_ethereum_wallet_connect_personal_sign() {
+    let signing_input = parse_personal_sign_request("0x466f6f");
+    assert_eq!(signing_input.message, "0x466f6f");
+    assert_eq!(
+        personal_sign_pre_image_hash(&signing_input),
+        eip191_hash(b"Foo")
+    );
+}
+
+#[test]
+fn test_ethereum_wallet_connect_personal_sign_binary() {
+    // A 32-byte hash that is not a valid UTF-8 string.
+    let message = "0xf8a2ba44e3f0e9b42b4d2c0f1c1e8a65f0a0b7e3e4c2d19ff0dd3e6bf1a2c3d4";
+    let signing_input = parse_personal_sign_request(message);
+    assert_eq!(signing_input.message, message);
+    assert_eq!(
+        personal_sign_pre_image_hash(&signing_input),
+        eip191_hash(&message.decode_hex().unwrap())
+    );
+}
+
+#[test]
+fn test_ethereum_wallet_connect_personal_sign_hex_looking_text() {
+    // Hex-encoded "0xdeadbeef" text must be decoded once only.
+    let signing_input = parse_personal_sign_request("0x30786465616462656566");
+    assert_eq!(signing_input.message, "0x30786465616462656566");
+    assert_eq!(
+        personal_sign_pre_image_hash(&signing_input),
+        eip191_hash(b"0xdeadbeef")
+    );
+}
+
+#[test]
+fn test_ethereum_wallet_connect_personal_sign_plain_text() {
+    let signing_input = parse_personal_sign_request("Foo");
+    assert_eq!(signing_input.message, "Foo");
+    assert_eq!(
+        personal_sign_pre_image_hash(&signing_input),
+        eip191_hash(b"Foo")
+    );
+}
+
+#[test]
+fn test_ethereum_wallet_connect_sign_typed_data_v4() {
+    let typed_data = json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "chainId", "type": "uint256" }
+            ],
+            "Mail": [{ "name": "contents", "type": "string" }]
+        },
+        "primaryType": "Mail",
+        "domain": { "name": "Ether Mail", "chainId": 1 },
+        "message": { "contents": "Hello, Bob!" }
+    });
+    let request_params = json!([
+        "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F",
+        typed_data.to_string()
+    ]);
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::EthSignTypedDataV4,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Ethereum, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::OK);
+
+    let signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::ethereum_message(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.message, typed_data.to_string());
+    assert_eq!(
+        signing_input.message_type,
+        Proto::MessageType::MessageType_typed
+    );
+}
+
+#[test]
+fn test_ethereum_wallet_connect_unsupported_method() {
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SolanaSignTransaction,
+        payload: "{}".into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Ethereum, &input);
+    assert_eq!(parsing_output.error, SigningErrorType::Error_not_supported);
+}
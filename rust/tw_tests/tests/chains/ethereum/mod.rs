@@ -9,3 +9,4 @@ mod ethereum_message_sign;
 mod ethereum_rlp;
 mod ethereum_sign;
 mod ethereum_transaction_util;
+mod ethereum_wallet_connect;
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::InternetComputer::Proto;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::internet_computer::candid::{
+    tw_internet_computer_candid_decode, tw_internet_computer_candid_encode,
+};
+use Proto::mod_CandidType::{OneOftype_oneof as TypeEnum, Primitive};
+use Proto::mod_CandidValue::OneOfvalue_oneof as ValueEnum;
+
+/// `record { owner = principal "ryjl3-tyaaa-aaaaa-aaaba-cai"; subaccount = null : opt blob }`.
+const ACCOUNT_ENCODED: &str =
+    "4449444c036c02b3b0dac30368ad86ca8305016e026d7b0100010a0000000000000002010100";
+const OWNER_ID: u32 = 947296307;
+const SUBACCOUNT_ID: u32 = 1349681965;
+
+fn blob_type() -> Proto::CandidType<'static> {
+    let nat8 = Proto::CandidType {
+        type_oneof: TypeEnum::primitive(Primitive::Nat8),
+    };
+    Proto::CandidType {
+        type_oneof: TypeEnum::vec(Box::new(Proto::mod_CandidType::VecType {
+            element_type: Some(Box::new(nat8)),
+        })),
+    }
+}
+
+fn encode(input: Proto::CandidEncodingInput<'_>) -> Proto::CandidEncodingOutput<'static> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output_data =
+        TWDataHelper::wrap(unsafe { tw_internet_computer_candid_encode(input_data.ptr()) })
+            .to_vec()
+            .expect("!tw_internet_computer_candid_encode returned nullptr");
+    let output: Proto::CandidEncodingOutput = deserialize(&output_data).unwrap();
+    // Detach the output from `output_data`.
+    Proto::CandidEncodingOutput {
+        encoded: Cow::from(output.encoded.to_vec()),
+        error: output.error,
+        error_message: Cow::from(output.error_message.to_string()),
+    }
+}
+
+#[test]
+fn test_internet_computer_candid_encode_account() {
+    let fields = vec![
+        Proto::mod_CandidValue::Field {
+            name: "owner".into(),
+            value: Some(Proto::CandidValue {
+                value_oneof: ValueEnum::principal_value("ryjl3-tyaaa-aaaaa-aaaba-cai".into()),
+            }),
+            ..Proto::mod_CandidValue::Field::default()
+        },
+        Proto::mod_CandidValue::Field {
+            name: "subaccount".into(),
+            value: Some(Proto::CandidValue {
+                value_oneof: ValueEnum::opt_value(Box::new(Proto::mod_CandidValue::OptValue {
+                    inner_type: Some(blob_type()),
+                    value: None,
+                })),
+            }),
+            ..Proto::mod_CandidValue::Field::default()
+        },
+    ];
+    let input = Proto::CandidEncodingInput {
+        args: vec![Proto::CandidValue {
+            value_oneof: ValueEnum::record_value(Proto::mod_CandidValue::RecordValue { fields }),
+        }],
+    };
+
+    let output = encode(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.encoded.to_hex(), ACCOUNT_ENCODED);
+}
+
+#[test]
+fn test_internet_computer_candid_encode_invalid_principal() {
+    let input = Proto::CandidEncodingInput {
+        args: vec![Proto::CandidValue {
+            value_oneof: ValueEnum::principal_value("invalid".into()),
+        }],
+    };
+
+    let output = encode(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_internet_computer_candid_decode_account() {
+    let input = Proto::CandidDecodingInput {
+        encoded: ACCOUNT_ENCODED.decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data =
+        TWDataHelper::wrap(unsafe { tw_internet_computer_candid_decode(input_data.ptr()) })
+            .to_vec()
+            .expect("!tw_internet_computer_candid_decode returned nullptr");
+    let output: Proto::CandidDecodingOutput = deserialize(&output_data).unwrap();
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.args.len(), 1);
+
+    let ValueEnum::record_value(ref record) = output.args[0].value_oneof else {
+        panic!("Expected a record, found: {:?}", output.args[0]);
+    };
+    assert_eq!(record.fields.len(), 2);
+
+    let owner = &record.fields[0];
+    assert_eq!(owner.id, OWNER_ID);
+    assert!(owner.name.is_empty());
+    assert_eq!(
+        owner.value.as_ref().unwrap().value_oneof,
+        ValueEnum::principal_value("ryjl3-tyaaa-aaaaa-aaaba-cai".into())
+    );
+
+    let subaccount = &record.fields[1];
+    assert_eq!(subaccount.id, SUBACCOUNT_ID);
+    let ValueEnum::opt_value(ref opt) = subaccount.value.as_ref().unwrap().value_oneof else {
+        panic!("Expected an optional value, found: {subaccount:?}");
+    };
+    assert_eq!(opt.inner_type, Some(blob_type()));
+    assert_eq!(opt.value, None);
+}
+
+#[test]
+fn test_internet_computer_candid_decode_invalid() {
+    let input = Proto::CandidDecodingInput {
+        encoded: "4449444c00017d".decode_hex().unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data =
+        TWDataHelper::wrap(unsafe { tw_internet_computer_candid_decode(input_data.ptr()) })
+            .to_vec()
+            .expect("!tw_internet_computer_candid_decode returned nullptr");
+    let output: Proto::CandidDecodingOutput = deserialize(&output_data).unwrap();
+
+    assert_eq!(output.error, SigningErrorType::Error_input_parse);
+    assert!(output.args.is_empty());
+}
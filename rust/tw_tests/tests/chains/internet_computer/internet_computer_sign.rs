@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::InternetComputer::Proto;
+use tw_proto::InternetComputer::Proto::mod_Transaction::OneOftransaction_oneof as TransactionType;
+
+const PRIVATE_KEY: &str = "227102911bb99ce7285a55f952800912b7d22ebeeeee59d77fc33a5d7c7080be";
+/// 2023-08-10T23:25:40Z
+const CURRENT_TIMESTAMP_NANOS: u64 = 1_691_709_940_000_000_000;
+const CKBTC_LEDGER_CANISTER_ID: &str = "mxzaz-hqaaa-aaaar-qaada-cai";
+
+fn signing_input(transaction: TransactionType<'static>) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: transaction,
+        }),
+    }
+}
+
+fn icrc1_transfer() -> Proto::mod_Transaction::Icrc1Transfer<'static> {
+    Proto::mod_Transaction::Icrc1Transfer {
+        canister_id: CKBTC_LEDGER_CANISTER_ID.into(),
+        to_account: "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1"
+            .into(),
+        amount: 50_000,
+        fee: 10,
+        memo: Cow::Owned(vec![1, 2, 3, 4]),
+        current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+        ..Proto::mod_Transaction::Icrc1Transfer::default()
+    }
+}
+
+#[test]
+fn test_internet_computer_sign_icrc1_transfer() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc1_transfer(icrc1_transfer())),
+    );
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.signed_transaction.to_hex(), "81826b5452414e53414354494f4e81a266757064617465a367636f6e74656e74a66c726571756573745f747970656463616c6c6e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d026b63616e69737465725f69644a000000000230000601016b6d6574686f645f6e616d656e69637263315f7472616e736665726361726758964449444c066c06fbca0101c6fcb60204ba89e5c20402a2de94eb060282f3f3910c05d8a38ca80d7d6c02b3b0dac30368ad86ca8305026e036d7b6e7d6e780100011db56bf994b37ae8e79f5ce000be1727a6060ae4eef24736b7cc999c3c0201200000000000000000000000000000000000000000000000000000000000000001010a01040102030400010088b2343a297a17d086036d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f736967984018af0218ea04185e185b18a71825187218ad188c181918fa18af18a918d018be15183a181f18f5186c183d184b18c0181f18eb186f18d418af186818dd186b18601713189b18de0218d7185b185318bb187c18ca18c0186b18dd091860187918e2184118be189c182d184918f818fc189f186218ef188618ad6a726561645f7374617465a367636f6e74656e74a46c726571756573745f747970656a726561645f73746174656e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d0265706174687381824e726571756573745f7374617475735820031c6a30e7077fb471896d597589224ac9dbf5906ee257afccd176cbf51d73256d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f7369679840182e18b3183b189d18770418ea0f18a3184d18d21839188e181918de18bd187018fd181818d6182818bb185518d9182518e118eb184318530718630e186e18ae18d218431828184e186218b618c9183818ee187a18e4184118d218f3185618f0187f1869182318e60b18b11831185f14189818e218fd18e511");
+}
+
+#[test]
+fn test_internet_computer_sign_icrc1_transfer_invalid_account() {
+    let transfer = Proto::mod_Transaction::Icrc1Transfer {
+        // Invalid checksum.
+        to_account: "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627j.1"
+            .into(),
+        ..icrc1_transfer()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc1_transfer(transfer)),
+    );
+
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+    assert!(output.signed_transaction.is_empty());
+}
+
+#[test]
+fn test_internet_computer_sign_icrc1_transfer_invalid_canister_id() {
+    let transfer = Proto::mod_Transaction::Icrc1Transfer {
+        canister_id: "mxzaz-hqaaa-aaaar-qaada-caj".into(),
+        ..icrc1_transfer()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc1_transfer(transfer)),
+    );
+
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_internet_computer_sign_icrc1_transfer_invalid_subaccount() {
+    let transfer = Proto::mod_Transaction::Icrc1Transfer {
+        from_subaccount: Cow::Owned(vec![1; 31]),
+        ..icrc1_transfer()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc1_transfer(transfer)),
+    );
+
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_internet_computer_sign_icrc2_approve() {
+    // ICP ledger canister is used by default.
+    let approve = Proto::mod_Transaction::Icrc2Approve {
+        spender_account: "t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe".into(),
+        amount: 500_000_000,
+        expires_at: CURRENT_TIMESTAMP_NANOS + 86_400_000_000_000,
+        current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+        ..Proto::mod_Transaction::Icrc2Approve::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc2_approve(approve)),
+    );
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.signed_transaction.to_hex(), "81826b5452414e53414354494f4e81a266757064617465a367636f6e74656e74a66c726571756573745f747970656463616c6c6e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d026b63616e69737465725f69644a000000000000000201016b6d6574686f645f6e616d656d69637263325f617070726f76656361726758894449444c066c08c6fcb60201ba89e5c20402a2de94eb060282f3f3910c04d8a38ca80d7d919c9cbf0d01dea7f7da0d04cb96dcb40e056e7d6e036d7b6e786c02b3b0dac30368ad86ca8305020100000000010088b2343a297a1780cab5ee010001008801c6ce777a17011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e4879593702006d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f736967984018dc187318f0188f18a4182318d9188d182c18e30618191867181a188b18f61860187918640d18b418c118b7187d1821171894186d184d185418dc18ef0c188a18c506182c1887189c1819185c18251880181b18cf185818760118ff06185b18f9188d0e182b18f518ae18b7181c18db186e18c318c718856a726561645f7374617465a367636f6e74656e74a46c726571756573745f747970656a726561645f73746174656e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d0265706174687381824e726571756573745f7374617475735820aef2c002c138d737d80f0c4256322643b194459369959edab4c4d658d44f5ea26d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f736967984018e8189712184518a1189c188c1859188718dc18e118f3182c189f189518c518f5187b189a18c70d18b41821186b183618b8171618b1187f0b187f187a18f218cc1861185a181c18da0c18f8182218f518e618cd1859189418b4183418ba18fc18bb183b1218a41844189c1819188b18e518c91718e91840");
+}
+
+#[test]
+fn test_internet_computer_sign_icrc2_transfer_from() {
+    let transfer_from = Proto::mod_Transaction::Icrc2TransferFrom {
+        from_account: "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1"
+            .into(),
+        to_account: "hpikg-6exdt-jn33w-ndty3-fc7jc-tl2lr-buih3-cs3y7-tftkp-sfp62-gqe".into(),
+        amount: 100_000_000,
+        current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+        permitted_drift: 120,
+        ..Proto::mod_Transaction::Icrc2TransferFrom::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc2_transfer_from(transfer_from)),
+    );
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.signed_transaction.to_hex(), "81826b5452414e53414354494f4e81a266757064617465a367636f6e74656e74a66c726571756573745f747970656463616c6c6e696e67726573735f6578706972791b177a29641d8890006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d026b63616e69737465725f69644a000000000000000201016b6d6574686f645f6e616d657369637263325f7472616e736665725f66726f6d6361726758b74449444c066c07fbca0101c6fcb60204e185c1940202eaca8a9e0401ba89e5c2040282f3f3910c05d8a38ca80d7d6c02b3b0dac30368ad86ca8305026e036d7b6e7d6e780100011d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d02000000011db56bf994b37ae8e79f5ce000be1727a6060ae4eef24736b7cc999c3c020120000000000000000000000000000000000000000000000000000000000000000100010088b2343a297a1780c2d72f6d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f7369679840181f18e2188218b218fe18a5184918dd18d4186a188f18e60a186900185218d41518f618c418d01819184c182a184818e718c71833183e185e18e118b218381872187618d418861854188d1518410918540b181f18df188f18d9186418ed18a0188418b818b8181c18e7150f1718b4188618dc187218d76a726561645f7374617465a367636f6e74656e74a46c726571756573745f747970656a726561645f73746174656e696e67726573735f6578706972791b177a29641d8890006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d0265706174687381824e726571756573745f7374617475735820e38b157770b1baf2dc945ea532df056fac4b167eff243aa33e309fc62f49850e6d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f736967984018ee18b218a81891021898183c18d118ca18b018d4188a0418fb18640f187318f518431868182f184e186b188518aa1894182412187318f5184718c009187818d5182c18ba188f187e186318fd187118c31854183618bc1824184c189e184f187b18bd187f18b318f118d618e118e718d3187f1893188e08182f");
+}
+
+#[test]
+fn test_internet_computer_sign_icrc2_transfer_from_zero_amount() {
+    let transfer_from = Proto::mod_Transaction::Icrc2TransferFrom {
+        from_account: "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae".into(),
+        to_account: "hpikg-6exdt-jn33w-ndty3-fc7jc-tl2lr-buih3-cs3y7-tftkp-sfp62-gqe".into(),
+        amount: 0,
+        current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+        ..Proto::mod_Transaction::Icrc2TransferFrom::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::icrc2_transfer_from(transfer_from)),
+    );
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::Error_invalid_requested_token_amount
+    );
+}
+
+#[test]
+fn test_internet_computer_sign_canister_call() {
+    let call = Proto::mod_Transaction::Call {
+        canister_id: "rrkah-fqaaa-aaaaa-aaaaq-cai".into(),
+        method_name: "greet".into(),
+        // Candid-encoded `("world")`.
+        arg: "4449444c00017105776f726c64".decode_hex().unwrap().into(),
+        current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+        ..Proto::mod_Transaction::Call::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::call(call)),
+    );
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.signed_transaction.to_hex(), "81826b5452414e53414354494f4e81a266757064617465a367636f6e74656e74a66c726571756573745f747970656463616c6c6e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d026b63616e69737465725f69644a000000000000000101016b6d6574686f645f6e616d65656772656574636172674d4449444c00017105776f726c646d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f736967984018e601184f183a187618fd18ab18c018e918b6183f189c151518d918be182918fe18cb18f4185f18ed18ed18d518ce18410518fe1883184c18f61827182a186b185418a1187218cf187c18ff186c189e183b186d18b818831899189318ba18c418f818d91885188c1823183618f01839186e182a18ec18e2188718746a726561645f7374617465a367636f6e74656e74a46c726571756573745f747970656a726561645f73746174656e696e67726573735f6578706972791b177a297215cfe8006673656e646572581d971cd2ddeecd1cf1b28be914d7a5c43441f6296f1f9966a7c8aff68d0265706174687381824e726571756573745f73746174757358204ed397b014e21a9a22ee1dd84c8b49fe14ed0ccb3271f675f7b3f6ba1a8a3a2b6d73656e6465725f7075626b65799858183018561830100607182a1886184818ce183d02010605182b188104000a0318420004183d18ab183a182118a81838184d184c187e1852188a187e18dc18d8184418ea18cd18c5189518ac188518b518bc181d188515186318bc18e618ab18d2184318d3187c184f18cd18f018de189b18b5181918dd18ef1889187218e71518c40418d4189718881843187218c611182e18cc18e6186b182118630218356a73656e6465725f7369679840186c18d0187618bd18e318cd18ec184018aa183c18820d187118cf18bc184f186e071842081885184818e718cc188b18c818f6185b185e183c18d518da16184818d3187418d218c018ff184418f418e818c403183a18aa18210d181e18c5188e1863185a188f1874184b185e188c18f5185418df04184d18ac");
+}
+
+#[test]
+fn test_internet_computer_sign_canister_call_missing_canister_id() {
+    let call = Proto::mod_Transaction::Call {
+        method_name: "greet".into(),
+        current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+        ..Proto::mod_Transaction::Call::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::InternetComputer,
+        signing_input(TransactionType::call(call)),
+    );
+
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
@@ -3,3 +3,5 @@
 // Copyright © 2017 Trust Wallet.
 
 mod internet_computer_address;
+mod internet_computer_candid_ffi;
+mod internet_computer_sign;
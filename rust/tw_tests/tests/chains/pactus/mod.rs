@@ -5,5 +5,6 @@
 mod pactus_address;
 mod pactus_compile;
 mod pactus_sign;
+mod pactus_transaction_decoder;
 mod pactus_transaction_util;
 mod test_cases;
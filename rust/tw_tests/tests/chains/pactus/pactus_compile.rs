@@ -28,7 +28,7 @@ fn test_pactus_transaction_compile() {
         // Step 2: Obtain preimage hash
         let input_data = TWDataHelper::create(serialize(&input).unwrap());
         let preimage_data = TWDataHelper::wrap(unsafe {
-            tw_transaction_compiler_pre_image_hashes(CoinType::Pactus as u32, input_data.ptr())
+            tw_transaction_compiler_pre_image_hashes(u32::from(CoinType::Pactus), input_data.ptr())
         })
         .to_vec()
         .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
@@ -57,7 +57,7 @@ fn test_pactus_transaction_compile() {
         let input_data = TWDataHelper::create(serialize(&input).unwrap());
         let output_data = TWDataHelper::wrap(unsafe {
             tw_transaction_compiler_compile(
-                CoinType::Pactus as u32,
+                u32::from(CoinType::Pactus),
                 input_data.ptr(),
                 signatures.ptr(),
                 public_keys.ptr(),
@@ -23,7 +23,7 @@ fn test_pactus_sign_transactions() {
         let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
         let output = TWDataHelper::wrap(unsafe {
-            tw_any_signer_sign(input_data.ptr(), CoinType::Pactus as u32)
+            tw_any_signer_sign(input_data.ptr(), u32::from(CoinType::Pactus))
         })
         .to_vec()
         .expect("!tw_any_signer_sign returned nullptr");
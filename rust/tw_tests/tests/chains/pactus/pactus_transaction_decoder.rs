@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::pactus::test_cases::{transfer_test_case, withdraw_test_case, TEST_CASES};
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Pactus::Proto;
+
+const PUBLIC_KEY: &str = "95794161374b22c696dabb98e93f6ca9300b22f3b904921fbf560bb72145f4fa";
+
+#[test]
+fn test_pactus_decode_signed_transactions() {
+    for case in TEST_CASES {
+        let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+        let output = decoder.decode(CoinType::Pactus, case.signed_data.decode_hex().unwrap());
+
+        assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+        assert_eq!(output.transaction, (case.sign_input_fn)().transaction);
+        assert_eq!(output.transaction_id.to_hex(), case.transaction_id);
+        assert_eq!(output.signature.to_hex(), case.signature);
+        assert_eq!(output.public_key.to_hex(), PUBLIC_KEY);
+    }
+}
+
+#[test]
+fn test_pactus_decode_unsigned_transaction() {
+    // Unsigned flag followed by the data to sign.
+    let unsigned_tx = format!("02{}", transfer_test_case::DATA_TO_SIGN);
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Pactus, unsigned_tx.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.transaction,
+        transfer_test_case::sign_input().transaction
+    );
+    assert_eq!(output.signer, "pc1rwzvr8rstdqypr80ag3t6hqrtnss9nwymcxy3lr");
+    assert_eq!(output.transaction_id.to_hex(), transfer_test_case::TX_ID);
+    assert!(output.signature.is_empty());
+    assert!(output.public_key.is_empty());
+}
+
+#[test]
+fn test_pactus_decode_withdraw_signer() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(
+        CoinType::Pactus,
+        withdraw_test_case::SIGNED_DATA.decode_hex().unwrap(),
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Withdrawals are signed by the validator.
+    assert_eq!(output.signer, "pc1p9y5gmu9l002tt60wak9extgvwm69rq3a9ackrl");
+}
+
+#[test]
+fn test_pactus_decode_transaction_invalid() {
+    // Truncated transaction.
+    let signed_data = transfer_test_case::SIGNED_DATA;
+    let truncated = &signed_data[..signed_data.len() - 2];
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Pactus, truncated.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
@@ -173,6 +173,141 @@ pub mod bond_without_public_key_test_case {
     }
 }
 
+pub mod unbond_test_case {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    define_test_data!(
+        "e48507220e8c0988f51c628b199171c03bc2f0e5577b5b4366b8d98affc5a179", // transaction ID
+        "65406f5d68ef846b3718beff4153916d8a6a75532df28128928d077053ef02e3\
+         65a8cd7cff85b1d9887455ed90c50f30d580cd88688b0d129c731f06b76bc806", // Signature
+        "95794161374b22c696dabb98e93f6ca9300b22f3b904921fbf560bb72145f4fa", // PublicKey
+        "01",                                                               // Version
+        "a0b42300",                                                         // LockTime
+        "80ade204",                                                         // Fee
+        "0b77616c6c65742d636f7265",                                         // Memo
+        "04",                                                               // PayloadType
+        "0129288df0bf7bd4b5e9eeed8b932d0c76f451823d",                       // Validator
+    );
+
+    pub fn sign_input() -> Proto::SigningInput<'static> {
+        let unbond_payload = Proto::UnbondPayload {
+            validator: "pc1p9y5gmu9l002tt60wak9extgvwm69rq3a9ackrl".into(),
+        };
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: 2340000,
+            fee: 10000000,
+            memo: "wallet-core".into(),
+            payload: Proto::mod_TransactionMessage::OneOfpayload::unbond(unbond_payload),
+        };
+
+        let private_key_bytes = PRIVATE_KEY.decode_hex().unwrap();
+
+        Proto::SigningInput {
+            transaction: Some(transaction),
+            private_key: private_key_bytes.into(),
+        }
+    }
+}
+
+pub mod withdraw_test_case {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    define_test_data!(
+        "dea147e49401f97ead22e364269113000e506ffaa28ea2e8714f248f80bbeb51", // transaction ID
+        "8cd8a478caf6e2f2d265f0f69f6194e4243ad60d524aaf3231a7cc91a8e39c77\
+         6b7cc0ed9b39f59b6784b2a76381413e6f02c488f407aba213c64f97bc6b5508", // Signature
+        "95794161374b22c696dabb98e93f6ca9300b22f3b904921fbf560bb72145f4fa", // PublicKey
+        "01",                                                               // Version
+        "a0b42300",                                                         // LockTime
+        "80ade204",                                                         // Fee
+        "0b77616c6c65742d636f7265",                                         // Memo
+        "05",                                                               // PayloadType
+        "0129288df0bf7bd4b5e9eeed8b932d0c76f451823d",                       // Validator
+        "037098338e0b6808119dfd4457ab806b9c2059b89b",                       // Account
+        "8094ebdc03",                                                       // Amount
+    );
+
+    pub fn sign_input() -> Proto::SigningInput<'static> {
+        let withdraw_payload = Proto::WithdrawPayload {
+            validator_address: "pc1p9y5gmu9l002tt60wak9extgvwm69rq3a9ackrl".into(),
+            account_address: "pc1rwzvr8rstdqypr80ag3t6hqrtnss9nwymcxy3lr".into(),
+            amount: 1000000000,
+        };
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: 2340000,
+            fee: 10000000,
+            memo: "wallet-core".into(),
+            payload: Proto::mod_TransactionMessage::OneOfpayload::withdraw(withdraw_payload),
+        };
+
+        let private_key_bytes = PRIVATE_KEY.decode_hex().unwrap();
+
+        Proto::SigningInput {
+            transaction: Some(transaction),
+            private_key: private_key_bytes.into(),
+        }
+    }
+}
+
+pub mod batch_transfer_test_case {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    define_test_data!(
+        "d609d87c53b81905f5d8f60875f676664e8684568d3b8d29ef1d3fac839e26a9", // transaction ID
+        "edb4844a30de94c971a3bc7d1c1796a8aee8942b57927098d56f2f346ec1e557\
+         1f81b08aece6b2ba5c65a0ab9091c64da6fd18efafd951cadd1e182a70e11d0e", // Signature
+        "95794161374b22c696dabb98e93f6ca9300b22f3b904921fbf560bb72145f4fa", // PublicKey
+        "01",                                                               // Version
+        "a0b42300",                                                         // LockTime
+        "80ade204",                                                         // Fee
+        "0b77616c6c65742d636f7265",                                         // Memo
+        "06",                                                               // PayloadType
+        "037098338e0b6808119dfd4457ab806b9c2059b89b",                       // Sender
+        "02",                                                               // Recipients count
+        "037a14ae24533816e7faaa6ed28fcdde8e55a7df21",                       // Receiver 1
+        "80c2d72f",                                                         // Amount 1
+        "03000102030405060708090a0b0c0d0e0f00010203",                       // Receiver 2
+        "8084af5f",                                                         // Amount 2
+    );
+
+    pub fn sign_input() -> Proto::SigningInput<'static> {
+        let batch_transfer_payload = Proto::BatchTransferPayload {
+            sender: "pc1rwzvr8rstdqypr80ag3t6hqrtnss9nwymcxy3lr".into(),
+            recipients: vec![
+                Proto::Recipient {
+                    receiver: "pc1r0g22ufzn8qtw0742dmfglnw73e260hep0k3yra".into(),
+                    amount: 100000000,
+                },
+                Proto::Recipient {
+                    receiver: "pc1rqqqsyqcyq5rqwzqfpg9scrgwpuqqzqsr36kkra".into(),
+                    amount: 200000000,
+                },
+            ],
+        };
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: 2340000,
+            fee: 10000000,
+            memo: "wallet-core".into(),
+            payload: Proto::mod_TransactionMessage::OneOfpayload::batch_transfer(
+                batch_transfer_payload,
+            ),
+        };
+
+        let private_key_bytes = PRIVATE_KEY.decode_hex().unwrap();
+
+        Proto::SigningInput {
+            transaction: Some(transaction),
+            private_key: private_key_bytes.into(),
+        }
+    }
+}
+
 pub(crate) struct TestCase {
     pub sign_input_fn: fn() -> Proto::SigningInput<'static>,
     pub transaction_id: &'static str,
@@ -181,7 +316,7 @@ pub(crate) struct TestCase {
     pub signed_data: &'static str,
 }
 
-pub(crate) const TEST_CASES: &[TestCase; 3] = &[
+pub(crate) const TEST_CASES: &[TestCase; 6] = &[
     TestCase {
         sign_input_fn: transfer_test_case::sign_input,
         transaction_id: transfer_test_case::TX_ID,
@@ -203,4 +338,25 @@ pub(crate) const TEST_CASES: &[TestCase; 3] = &[
         data_to_sign: bond_without_public_key_test_case::DATA_TO_SIGN,
         signed_data: bond_without_public_key_test_case::SIGNED_DATA,
     },
+    TestCase {
+        sign_input_fn: unbond_test_case::sign_input,
+        transaction_id: unbond_test_case::TX_ID,
+        signature: unbond_test_case::SIGNATURE,
+        data_to_sign: unbond_test_case::DATA_TO_SIGN,
+        signed_data: unbond_test_case::SIGNED_DATA,
+    },
+    TestCase {
+        sign_input_fn: withdraw_test_case::sign_input,
+        transaction_id: withdraw_test_case::TX_ID,
+        signature: withdraw_test_case::SIGNATURE,
+        data_to_sign: withdraw_test_case::DATA_TO_SIGN,
+        signed_data: withdraw_test_case::SIGNED_DATA,
+    },
+    TestCase {
+        sign_input_fn: batch_transfer_test_case::sign_input,
+        transaction_id: batch_transfer_test_case::TX_ID,
+        signature: batch_transfer_test_case::SIGNATURE,
+        data_to_sign: batch_transfer_test_case::DATA_TO_SIGN,
+        signed_data: batch_transfer_test_case::SIGNED_DATA,
+    },
 ];
@@ -4,4 +4,7 @@
 
 mod ripple_address;
 mod ripple_compile;
+mod ripple_message_sign;
+mod ripple_multisign;
 mod ripple_sign;
+mod ripple_transaction_decoder;
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::{deserialize, serialize, Ripple, TxCompiler};
+
+const CHANNEL_ID: &str = "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198";
+const CLAIM_SIGNATURE: &str = "304402202a0ee124631f0d82525a01e4c12d1bd87a523b801ecb843a704303382dd2814b022055aefce46e5ca1bad1e4898c2241d42cea0d9913a6938bff5a228d141b06ec12";
+
+fn channel_claim(amount: i64) -> Option<Ripple::Proto::ChannelClaim<'static>> {
+    Some(Ripple::Proto::ChannelClaim {
+        channel: CHANNEL_ID.into(),
+        amount,
+    })
+}
+
+#[test]
+fn test_ripple_message_signer_sign_channel_claim() {
+    let input = Ripple::Proto::MessageSigningInput {
+        private_key: "acf1bbf6264e699da0cc65d17ac03fcca6ded1522d19529df7762db46097ff9f"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        channel_claim: channel_claim(1_000_000),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(u32::from(CoinType::XRP), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Ripple::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, CLAIM_SIGNATURE);
+}
+
+#[test]
+fn test_ripple_message_signer_sign_no_claim() {
+    let input = Ripple::Proto::MessageSigningInput {
+        private_key: "acf1bbf6264e699da0cc65d17ac03fcca6ded1522d19529df7762db46097ff9f"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        channel_claim: None,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(u32::from(CoinType::XRP), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Ripple::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.signature.is_empty());
+}
+
+#[test]
+fn test_ripple_message_signer_verify_channel_claim() {
+    let input = Ripple::Proto::MessageVerifyingInput {
+        channel_claim: channel_claim(1_000_000),
+        public_key: "027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature: CLAIM_SIGNATURE.into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(u32::from(CoinType::XRP), input_data.ptr()) };
+    assert!(verified);
+}
+
+#[test]
+fn test_ripple_message_signer_verify_different_amount() {
+    let input = Ripple::Proto::MessageVerifyingInput {
+        channel_claim: channel_claim(2_000_000),
+        public_key: "027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature: CLAIM_SIGNATURE.into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(u32::from(CoinType::XRP), input_data.ptr()) };
+    assert!(!verified);
+}
+
+#[test]
+fn test_ripple_message_signer_pre_image_hashes() {
+    let input = Ripple::Proto::MessageSigningInput {
+        private_key: Default::default(),
+        channel_claim: channel_claim(1_000_000),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(u32::from(CoinType::XRP), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(
+        hex::encode(output.data, false),
+        "434c4d00c1ae6dddeec05cf2978c0bad6fe302948e9533691dc749dcdd3b9e5992ca619800000000000f4240"
+    );
+    assert_eq!(
+        hex::encode(output.data_hash, false),
+        "74ea14116536cd73ddcd6bdb2501611f08fc991a7df562d64b03a8d787921e02"
+    );
+}
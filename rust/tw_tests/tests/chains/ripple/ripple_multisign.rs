@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Ripple::Proto;
+use tw_proto::Ripple::Proto::mod_OperationPayment::OneOfamount_oneof as AmountType;
+use tw_proto::Ripple::Proto::mod_SigningInput::OneOfoperation_oneof as OperationType;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Private key of the `rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq` signer.
+const SIGNER_1_PRIVATE_KEY: &str =
+    "a5576c0f63da10e584568c8d134569ff44017b0a249eb70657127ae04f38cc77";
+/// Private key of the `rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP` signer.
+const SIGNER_2_PRIVATE_KEY: &str =
+    "acf1bbf6264e699da0cc65d17ac03fcca6ded1522d19529df7762db46097ff9f";
+const MULTISIG_ACCOUNT: &str = "rU893viamSnsfP3zjzM2KPxjqZjXSXK6VF";
+
+fn multisign_payment_input() -> Proto::SigningInput<'static> {
+    let payment = Proto::OperationPayment {
+        amount_oneof: AmountType::amount(1_000_000),
+        destination: "rNLpgsBTCwiaZAnHe2ZViAN1GcXZtYW6rg".into(),
+        ..Proto::OperationPayment::default()
+    };
+    Proto::SigningInput {
+        // Multi-signed transaction costs `base_fee * (1 + number_of_signers)`.
+        fee: 30,
+        sequence: 1,
+        last_ledger_sequence: 100,
+        account: MULTISIG_ACCOUNT.into(),
+        multisign: true,
+        operation_oneof: OperationType::op_payment(payment),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_ripple_multisign_payment() {
+    let input = Proto::SigningInput {
+        private_key: SIGNER_1_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..multisign_payment_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000022000000002400000001201b000000646140000000000f424068400000000000001e730081148132e4e20aecf29090ac428a9c43f230a829220d83149232ef60695add51f0f84534cc4084e4fdfc698ef3e010732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74473045022100f126c60b3615edc68e5ee3938dfaf6d602dd97cf07016144823a581a1d905bff02205c4adf7ee91b2a6fb681641340072db16ab02c656ad7ab51e5854e251517667f81144c55f5a78067206507580be7bb2686c8460adff9e1f1"
+    );
+}
+
+#[test]
+fn test_ripple_multisign_payment_compile() {
+    let signer_1 = secp256k1::PrivateKey::try_from(SIGNER_1_PRIVATE_KEY).unwrap();
+    let signer_2 = secp256k1::PrivateKey::try_from(SIGNER_2_PRIVATE_KEY).unwrap();
+
+    // Step 1: Obtain preimage hash of each signer.
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let mut signatures = Vec::new();
+    let mut public_keys = Vec::new();
+    // Signatures are intentionally provided in the reversed order of the signer accounts.
+    for (i, signer) in [&signer_2, &signer_1].into_iter().enumerate() {
+        let public_key = signer.public();
+        let input = Proto::SigningInput {
+            public_key: public_key.compressed().to_vec().into(),
+            ..multisign_payment_input()
+        };
+
+        let preimage_output = pre_imager.pre_image_hashes(CoinType::XRP, &input);
+        assert_eq!(
+            preimage_output.error,
+            SigningError::OK,
+            "{}",
+            preimage_output.error_message
+        );
+
+        // Simulate signature, normally obtained from signature server.
+        let data_hash = H256::try_from(preimage_output.data_hash.as_ref()).unwrap();
+        signatures.push(signer.sign(data_hash).unwrap().to_vec());
+        public_keys.push(public_key.compressed().to_vec());
+
+        if i == 0 {
+            assert_eq!(
+                preimage_output.data.to_hex(),
+                "534d540012000022000000002400000001201b000000646140000000000f424068400000000000001e730081148132e4e20aecf29090ac428a9c43f230a829220d83149232ef60695add51f0f84534cc4084e4fdfc698eaa000c09c692ef1f82787e51e22833149941ea20"
+            );
+            assert_eq!(
+                preimage_output.data_hash.to_hex(),
+                "33ef426455b011fb3632906b00ad29f5d8ea85d545e3c5551b8f4a3314f2d4d7"
+            );
+        }
+    }
+
+    // Step 2: Compile transaction info.
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::XRP,
+        &multisign_payment_input(),
+        signatures,
+        public_keys,
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Signers are sorted by their account IDs.
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000022000000002400000001201b000000646140000000000f424068400000000000001e730081148132e4e20aecf29090ac428a9c43f230a829220d83149232ef60695add51f0f84534cc4084e4fdfc698ef3e010732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74473045022100f126c60b3615edc68e5ee3938dfaf6d602dd97cf07016144823a581a1d905bff02205c4adf7ee91b2a6fb681641340072db16ab02c656ad7ab51e5854e251517667f81144c55f5a78067206507580be7bb2686c8460adff9e1e0107321027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b74473045022100ac2f5b9e3f6b416c83b6fafd57421fb40a4e99f279a51bb272b9c0281b45dece02200e5b20958c6d8a92c77a9bbb2b7c560232a6df5b53051aeec76031f7abc885528114aa000c09c692ef1f82787e51e22833149941ea20e1f1"
+    );
+}
+
+#[test]
+fn test_ripple_multisign_compile_invalid_signature() {
+    let signer = secp256k1::PrivateKey::try_from(SIGNER_1_PRIVATE_KEY).unwrap();
+    // Signature of an arbitrary hash instead of the multi-signing pre-image.
+    let signature = signer.sign(H256::default()).unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::XRP,
+        &multisign_payment_input(),
+        vec![signature.to_vec()],
+        vec![signer.public().compressed().to_vec()],
+    );
+
+    assert_eq!(output.error, SigningError::Error_signing);
+}
+
+#[test]
+fn test_ripple_sign_signer_list_set() {
+    let signer_list_set = Proto::OperationSignerListSet {
+        signer_quorum: 2,
+        signer_entries: vec![
+            Proto::SignerEntry {
+                account: "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP".into(),
+                signer_weight: 1,
+            },
+            Proto::SignerEntry {
+                account: MULTISIG_ACCOUNT.into(),
+                signer_weight: 1,
+            },
+        ],
+    };
+    let input = Proto::SigningInput {
+        fee: 10,
+        sequence: 32_268_249,
+        last_ledger_sequence: 32_268_270,
+        account: "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq".into(),
+        private_key: SIGNER_1_PRIVATE_KEY.decode_hex().unwrap().into(),
+        operation_oneof: OperationType::op_signer_list_set(signer_list_set),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000c22000000002401ec5fd9201b01ec5fee20230000000268400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74473045022100a72fe105913984437dc47cb05ed8057ca9faf7b5236f4868ffc2f0021aff13b902206f0d3636832760988e5c9102ee9108198399a52c531619cdd3bf0e030f7c54f881144c55f5a78067206507580be7bb2686c8460adff9f4eb1300018114aa000c09c692ef1f82787e51e22833149941ea20e1eb13000181148132e4e20aecf29090ac428a9c43f230a829220de1f1"
+    );
+}
+
+#[test]
+fn test_ripple_sign_signer_list_set_unreachable_quorum() {
+    let signer_list_set = Proto::OperationSignerListSet {
+        signer_quorum: 3,
+        signer_entries: vec![Proto::SignerEntry {
+            account: "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP".into(),
+            signer_weight: 2,
+        }],
+    };
+    let input = Proto::SigningInput {
+        fee: 10,
+        sequence: 32_268_249,
+        last_ledger_sequence: 32_268_270,
+        account: "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq".into(),
+        private_key: SIGNER_1_PRIVATE_KEY.decode_hex().unwrap().into(),
+        operation_oneof: OperationType::op_signer_list_set(signer_list_set),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
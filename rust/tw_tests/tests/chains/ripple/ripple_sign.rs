@@ -7,6 +7,8 @@ use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_proto::Common::Proto::SigningError;
 use tw_proto::Ripple::Proto;
+use tw_proto::Ripple::Proto::mod_Amount::OneOfamount_oneof as GenericAmountType;
+use tw_proto::Ripple::Proto::mod_OperationCheckCash::OneOfamount_oneof as CheckCashAmountType;
 use tw_proto::Ripple::Proto::mod_OperationPayment::OneOfamount_oneof as AmountType;
 use tw_proto::Ripple::Proto::mod_SigningInput::OneOfoperation_oneof as OperationType;
 
@@ -798,3 +800,215 @@ fn test_ripple_sign_raw_json() {
     // https://devnet.xrpl.org/transactions/D93B0B6983134BC6C2880B27708E8C1E932CB9E6D9E78773AD31B797741944FF
     assert_eq!(output.encoded.to_hex(), "1200002405995011201b059b290f6140000000000186a068400000000000000a732103df650aab92e1b0a95cbda6a5a0fc3bfdbe991901e5b1cdfcd238b769cb4934a7744730450221008a5dba92a63fa82987a9ec3035febd1d5fe802f9a1baf3760090bb117281684e022056e4feca51231f719ca16cbfe370d312704e2cd7b94a28548f7f7886141d8bb28114023c2b9f15b95198d270b1bf92a4700c40272ca48314e1b799e72e5785c6a84af0f9c01424d4256b14aff9ea7dc1287b2266726f6d546f6b656e223a22307865656565656565656565656565656565656565656565656565656565656565656565656565656565222c22746f546f6b656e223a225452587c783561763039222c2273656e646572223a2272554653613244324a59476e354169525a6951785375705a7856354b6348454347222c2264657374696e6174696f6e223a22544255437a6763323976796b6b7646614547326d675274784b76614b6536736b7758222c226d696e52657475726e416d6f756e74223a2231333239353831303030303030222c2266726f6d416d6f756e74223a22313030303030227de1f1");
 }
+
+const SIGNER_PRIVATE_KEY: &str = "a5576c0f63da10e584568c8d134569ff44017b0a249eb70657127ae04f38cc77";
+const SIGNER_ACCOUNT: &str = "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq";
+const USD_ISSUER: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+fn sign_operation(
+    operation: OperationType<'static>,
+    fee: i64,
+    flags: u32,
+) -> Proto::SigningOutput<'static> {
+    let input = Proto::SigningInput {
+        fee,
+        flags,
+        sequence: 32_268_250,
+        last_ledger_sequence: 32_268_270,
+        account: SIGNER_ACCOUNT.into(),
+        private_key: SIGNER_PRIVATE_KEY.decode_hex().unwrap().into(),
+        operation_oneof: operation,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    signer.sign(CoinType::XRP, input)
+}
+
+fn xrp_amount(drops: i64) -> Option<Proto::Amount<'static>> {
+    Some(Proto::Amount {
+        amount_oneof: GenericAmountType::drops(drops),
+    })
+}
+
+fn usd_amount(value: &'static str) -> Option<Proto::Amount<'static>> {
+    Some(Proto::Amount {
+        amount_oneof: GenericAmountType::currency_amount(Proto::CurrencyAmount {
+            currency: "USD".into(),
+            value: value.into(),
+            issuer: USD_ISSUER.into(),
+        }),
+    })
+}
+
+fn xrp_usd_assets() -> (Option<Proto::Issue<'static>>, Option<Proto::Issue<'static>>) {
+    let xrp = Proto::Issue {
+        currency: "XRP".into(),
+        ..Proto::Issue::default()
+    };
+    let usd = Proto::Issue {
+        currency: "USD".into(),
+        issuer: USD_ISSUER.into(),
+    };
+    (Some(xrp), Some(usd))
+}
+
+#[test]
+fn test_ripple_sign_account_set() {
+    let account_set = Proto::OperationAccountSet {
+        // asfDefaultRipple
+        set_flag: 8,
+        domain: "example.com".into(),
+        transfer_rate: 1_005_000_000,
+        tick_size: 5,
+        ..Proto::OperationAccountSet::default()
+    };
+    let output = sign_operation(OperationType::op_account_set(account_set), 10, 0);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000322000000002401ec5fda2b3be71540201b01ec5fee20210000000868400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f7446304402203ca9b4cb20db9579b5c523242fa1958487c49ed46f7d4d4068a96ec9438f256a02201b80faf201ee82deca284ebed6d59a671dc7b8a52c321120b6cd3cbba033a723770b6578616d706c652e636f6d81144c55f5a78067206507580be7bb2686c8460adff900101005"
+    );
+}
+
+#[test]
+fn test_ripple_sign_account_set_invalid_tick_size() {
+    let account_set = Proto::OperationAccountSet {
+        tick_size: 16,
+        ..Proto::OperationAccountSet::default()
+    };
+    let output = sign_operation(OperationType::op_account_set(account_set), 10, 0);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_ripple_sign_offer_create() {
+    let offer_create = Proto::OperationOfferCreate {
+        taker_gets: xrp_amount(1_000_000),
+        taker_pays: usd_amount("1.5"),
+        expiration: 780_000_000,
+        ..Proto::OperationOfferCreate::default()
+    };
+    let output = sign_operation(OperationType::op_offer_create(offer_create), 10, 0);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000722000000002401ec5fda2a2e7ddb00201b01ec5fee64d485543df729c00000000000000000000000000055534400000000004b4e9c06f24296074f7bc48f92a97916c6dc5ea96540000000000f424068400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f744630440220687338daa4da69baaec636de36cf7160efdcd747c8e934f9239010ad17fd471c02204f0b06c064851f72d19c4ce0108cccecb957f09397382f7b1e4907565de3efca81144c55f5a78067206507580be7bb2686c8460adff9"
+    );
+}
+
+#[test]
+fn test_ripple_sign_check_cash() {
+    let check_cash = Proto::OperationCheckCash {
+        check_id: "838766BA2B995C00744175F69A1B11E32C3DBC40E64801A4056FCBD657F57334".into(),
+        amount_oneof: CheckCashAmountType::amount(xrp_amount(1_000_000).unwrap()),
+    };
+    let output = sign_operation(OperationType::op_check_cash(check_cash), 10, 0);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12001122000000002401ec5fda201b01ec5fee5018838766ba2b995c00744175f69a1b11e32c3dbc40e64801a4056fcbd657f573346140000000000f424068400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f7446304402200b69c3271ac6720f5cfde25ad98f8b69a8e3f1ba07facd920c34131fb32869690220580bc5978b409108145211a248e48b3c818f618f747f3972286c369d10aec51281144c55f5a78067206507580be7bb2686c8460adff9"
+    );
+}
+
+#[test]
+fn test_ripple_sign_payment_channel_claim() {
+    // The claim is signed by the channel source, see `test_ripple_sign_channel_claim`.
+    let channel_claim = Proto::OperationPaymentChannelClaim {
+        channel: "C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198".into(),
+        balance: 1_000_000,
+        amount: 1_000_000,
+        signature: "304402202a0ee124631f0d82525a01e4c12d1bd87a523b801ecb843a704303382dd2814b022055aefce46e5ca1bad1e4898c2241d42cea0d9913a6938bff5a228d141b06ec12".into(),
+        public_key: "027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b".into(),
+    };
+    let output = sign_operation(
+        OperationType::op_payment_channel_claim(channel_claim),
+        10,
+        0,
+    );
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000f22000000002401ec5fda201b01ec5fee5016c1ae6dddeec05cf2978c0bad6fe302948e9533691dc749dcdd3b9e5992ca61986140000000000f42406240000000000f424068400000000000000a7121027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74463044022025cd266f61d380dc57a66caee252d69f58a37b87324e7649cd05f3b4eea72a7502205433c5e2a4c0e9e2c863a5001e54a0e37ebe3847b9f4d761bd4cec37e14d87de7646304402202a0ee124631f0d82525a01e4c12d1bd87a523b801ecb843a704303382dd2814b022055aefce46e5ca1bad1e4898c2241d42cea0d9913a6938bff5a228d141b06ec1281144c55f5a78067206507580be7bb2686c8460adff9"
+    );
+}
+
+#[test]
+fn test_ripple_sign_amm_create() {
+    let amm_create = Proto::OperationAMMCreate {
+        amount: xrp_amount(25_000_000),
+        amount2: usd_amount("250"),
+        trading_fee: 500,
+    };
+    // AMMCreate costs one owner reserve.
+    let output = sign_operation(OperationType::op_amm_create(amm_create), 2_000_000, 0);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "1200231501f422000000002401ec5fda201b01ec5fee6140000000017d78406840000000001e84806bd508e1bc9bf0400000000000000000000000000055534400000000004b4e9c06f24296074f7bc48f92a97916c6dc5ea9732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74473045022100acbfc0eedd7e7f07a4d8f32b7708eb6af188a7e403dbd3bc8556dd542f8d2bb602203af5b24057cde736523e686073d73fd8ffdf57a1301856f4a3a8988d58c7f09381144c55f5a78067206507580be7bb2686c8460adff9"
+    );
+}
+
+#[test]
+fn test_ripple_sign_amm_create_invalid_trading_fee() {
+    let amm_create = Proto::OperationAMMCreate {
+        amount: xrp_amount(25_000_000),
+        amount2: usd_amount("250"),
+        trading_fee: 1001,
+    };
+    let output = sign_operation(OperationType::op_amm_create(amm_create), 2_000_000, 0);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_ripple_sign_amm_deposit_single_asset() {
+    const TF_SINGLE_ASSET: u32 = 0x00080000;
+
+    let (asset, asset2) = xrp_usd_assets();
+    let amm_deposit = Proto::OperationAMMDeposit {
+        asset,
+        asset2,
+        amount: xrp_amount(1_000_000),
+        ..Proto::OperationAMMDeposit::default()
+    };
+    let output = sign_operation(
+        OperationType::op_amm_deposit(amm_deposit),
+        10,
+        TF_SINGLE_ASSET,
+    );
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12002422000800002401ec5fda201b01ec5fee6140000000000f424068400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f7446304402202288efd1fe41320b09ff3d00b5158cb736666795feba44934bc7a2c2c9bf4d3402203303a4281b8fbada8e73a64e6e6961e71ad5336c00d1b96d5b551fb978f6848f81144c55f5a78067206507580be7bb2686c8460adff903180000000000000000000000000000000000000000041800000000000000000000000055534400000000004b4e9c06f24296074f7bc48f92a97916c6dc5ea9"
+    );
+}
+
+#[test]
+fn test_ripple_sign_amm_bid() {
+    let (asset, asset2) = xrp_usd_assets();
+    let amm_bid = Proto::OperationAMMBid {
+        asset,
+        asset2,
+        // LP Token of the XRP/USD AMM.
+        bid_min: Some(Proto::CurrencyAmount {
+            currency: "039C99CD9AB0B70B32ECDA51EAAE471625608EA2".into(),
+            value: "100".into(),
+            issuer: "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S".into(),
+        }),
+        auth_accounts: vec!["rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP".into()],
+        ..Proto::OperationAMMBid::default()
+    };
+    let output = sign_operation(OperationType::op_amm_bid(amm_bid), 10, 0);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12002722000000002401ec5fda201b01ec5fee68400000000000000a6cd5038d7ea4c68000039c99cd9ab0b70b32ecda51eaae471625608ea2a1652b3ccb5bdf6debf2dd197bf844ccf4c4064e732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74463044022063c5dd9fce356ff474fdc6e3c9e0c183329579029c506b8a8c278577100415fd02207225a2fbc3ead6aca42718f1fe2652e0b59230694ade5d541ac2ef0807353b3281144c55f5a78067206507580be7bb2686c8460adff9f019e01b8114aa000c09c692ef1f82787e51e22833149941ea20e1f103180000000000000000000000000000000000000000041800000000000000000000000055534400000000004b4e9c06f24296074f7bc48f92a97916c6dc5ea9"
+    );
+}
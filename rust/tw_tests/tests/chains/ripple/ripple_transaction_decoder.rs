@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::{json, Value as Json};
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Ripple::Proto;
+
+#[test]
+fn test_ripple_decode_transaction_payment() {
+    // Signed in `test_ripple_sign_xrp_payment_0`.
+    let encoded_tx = "12000022000000002401ec5fd8201b01ec5fed61400000000000000a68400000000000000a732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74463044022037d32835c9394f39b2cfd4eaf5b0a80e0db397ace06630fa2b099ff73e425dbc02205288f780330b7a88a1980fa83c647b5908502ad7de9a44500c08f0750b0d9e8481144c55f5a78067206507580be7bb2686c8460adff983148132e4e20aecf29090ac428a9c43f230a829220d"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::XRP, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.transaction_type, "Payment");
+    assert_eq!(tx.account, "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq");
+    assert_eq!(tx.fee, 10);
+    assert_eq!(tx.sequence, 32_268_248);
+    assert_eq!(tx.last_ledger_sequence, 32_268_269);
+    assert_eq!(tx.flags, 0);
+    assert_eq!(
+        tx.signing_pub_key.to_hex(),
+        "03d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f"
+    );
+    assert!(tx.has_signature);
+    assert!(tx.signers.is_empty());
+
+    let expected_json = json!({
+        "Account": "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq",
+        "Amount": "10",
+        "Destination": "rU893viamSnsfP3zjzM2KPxjqZjXSXK6VF",
+        "Fee": "10",
+        "Flags": 0,
+        "LastLedgerSequence": 32268269,
+        "Sequence": 32268248,
+        "SigningPubKey": "03D13E1152965A51A4A9FD9A8B4EA3DD82A4EBA6B25FCAD5F460A2342BB650333F",
+        "TransactionType": "Payment",
+        "TxnSignature": "3044022037D32835C9394F39B2CFD4EAF5B0A80E0DB397ACE06630FA2B099FF73E425DBC02205288F780330B7A88A1980FA83C647B5908502AD7DE9A44500C08F0750B0D9E84"
+    });
+    let actual_json: Json = serde_json::from_str(&tx.json).unwrap();
+    assert_eq!(actual_json, expected_json);
+}
+
+#[test]
+fn test_ripple_decode_transaction_multisigned() {
+    // Compiled in `test_ripple_multisign_payment_compile`.
+    let encoded_tx = "12000022000000002400000001201b000000646140000000000f424068400000000000001e730081148132e4e20aecf29090ac428a9c43f230a829220d83149232ef60695add51f0f84534cc4084e4fdfc698ef3e010732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74473045022100f126c60b3615edc68e5ee3938dfaf6d602dd97cf07016144823a581a1d905bff02205c4adf7ee91b2a6fb681641340072db16ab02c656ad7ab51e5854e251517667f81144c55f5a78067206507580be7bb2686c8460adff9e1e0107321027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b74473045022100ac2f5b9e3f6b416c83b6fafd57421fb40a4e99f279a51bb272b9c0281b45dece02200e5b20958c6d8a92c77a9bbb2b7c560232a6df5b53051aeec76031f7abc885528114aa000c09c692ef1f82787e51e22833149941ea20e1f1"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::XRP, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.transaction_type, "Payment");
+    assert_eq!(tx.account, "rU893viamSnsfP3zjzM2KPxjqZjXSXK6VF");
+    assert_eq!(tx.fee, 30);
+    assert!(tx.signing_pub_key.is_empty());
+    assert!(!tx.has_signature);
+    assert_eq!(
+        tx.signers,
+        [
+            "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq",
+            "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP"
+        ]
+    );
+
+    let actual_json: Json = serde_json::from_str(&tx.json).unwrap();
+    assert_eq!(
+        actual_json["Signers"][1],
+        json!({
+            "Signer": {
+                "Account": "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP",
+                "SigningPubKey": "027EFC5F15071D2AE5E73EE09A0C17456C5D9170A41D67E3297C554829199BE80B",
+                "TxnSignature": "3045022100AC2F5B9E3F6B416C83B6FAFD57421FB40A4E99F279A51BB272B9C0281B45DECE02200E5B20958C6D8A92C77A9BBB2B7C560232A6DF5B53051AEEC76031F7ABC88552"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_ripple_decode_transaction_amm_bid() {
+    // Signed in `test_ripple_sign_amm_bid`.
+    let encoded_tx = "12002722000000002401ec5fda201b01ec5fee68400000000000000a6cd5038d7ea4c68000039c99cd9ab0b70b32ecda51eaae471625608ea2a1652b3ccb5bdf6debf2dd197bf844ccf4c4064e732103d13e1152965a51a4a9fd9a8b4ea3dd82a4eba6b25fcad5f460a2342bb650333f74463044022063c5dd9fce356ff474fdc6e3c9e0c183329579029c506b8a8c278577100415fd02207225a2fbc3ead6aca42718f1fe2652e0b59230694ade5d541ac2ef0807353b3281144c55f5a78067206507580be7bb2686c8460adff9f019e01b8114aa000c09c692ef1f82787e51e22833149941ea20e1f103180000000000000000000000000000000000000000041800000000000000000000000055534400000000004b4e9c06f24296074f7bc48f92a97916c6dc5ea9"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::XRP, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.transaction_type, "AMMBid");
+
+    let expected_json = json!({
+        "Account": "rfxdLwsZnoespnTDDb1Xhvbc8EFNdztaoq",
+        "Asset": {"currency": "XRP"},
+        "Asset2": {"currency": "USD", "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"},
+        "AuthAccounts": [
+            {"AuthAccount": {"Account": "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP"}}
+        ],
+        "BidMin": {
+            "currency": "039c99cd9ab0b70b32ecda51eaae471625608ea2",
+            "issuer": "rE54zDvgnghAoPopCgvtiqWNq3dU5y836S",
+            "value": "100"
+        },
+        "Fee": "10",
+        "Flags": 0,
+        "LastLedgerSequence": 32268270,
+        "Sequence": 32268250,
+        "SigningPubKey": "03D13E1152965A51A4A9FD9A8B4EA3DD82A4EBA6B25FCAD5F460A2342BB650333F",
+        "TransactionType": "AMMBid",
+        "TxnSignature": "3044022063C5DD9FCE356FF474FDC6E3C9E0C183329579029C506B8A8C278577100415FD02207225A2FBC3EAD6ACA42718F1FE2652E0B59230694ADE5D541AC2EF0807353B32"
+    });
+    let actual_json: Json = serde_json::from_str(&tx.json).unwrap();
+    assert_eq!(actual_json, expected_json);
+}
+
+#[test]
+fn test_ripple_decode_transaction_invalid() {
+    // The transaction is truncated in the middle of `Destination` field.
+    let encoded_tx = "12000022000000002401ec5fd8201b01ec5fed61400000000000000a68400000000000000a83148132e4e20aecf290"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::XRP, encoded_tx);
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
@@ -2,12 +2,15 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::chains::common::frost::{self, ED25519_COMMITMENT, ED25519_SHARES};
 use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
 use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::base58::{self, Alphabet};
 use tw_encoding::base64::{self, STANDARD};
 use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::frost::ciphersuite::Ed25519;
+use tw_misc::traits::ToBytesVec;
 use tw_proto::Common::Proto::SigningError;
 use tw_proto::Solana::Proto::{self, mod_SigningInput::OneOftransaction_type as TransactionType};
 
@@ -514,3 +517,46 @@ fn test_solana_compile_with_external_signature() {
     // Successfully broadcasted: https://solscan.io/tx/5NuXtYpE58FbtCfEzgk2cTHZgEdNF69Z76bd8TQhBgmEN1RC98DNGNiWhvp1VSDMPudCgpE3z8jD7BNRuBztUbpM
     assert_eq!(signed_tx_base64, "Atr1azHjqXUEu1rbTGRwnN2CQHAJNdnhyumgWrAy3gS0acweAlh/nG1+VxkuT5x3EXiXqF0VkAcw2Qp/Vd6/aQrDIH0nd1ShqHK9yRATbpF/npAvAl5JPwwb89W0US//4DslDobk5o4g6Wqwm+sk13Bq4ziixdEfuyPXea5bjAcBAgAGDqMrdVJnGyKZfDMMU6yR6mjQRynQy91x5Ik0QW5S7HjblEDFOjfYkcjeiZdpl9opTtGz1XgRDjyGqc4Z5VKuBHI5lg1U5Wnl5tFnrGEI9Y2rG1BNC7tYK5PXpURkpupeap6naP7fZEyKrpuOIYit0GvFUPv3Fsgiuc5jx3g9lS4fsU4N5V6fuoY5br/VSM/4ySAR6se3W6qbLZxqhvWhcUEJ5qP+7PmQMuHB32uXItyzY057jjRAk2vDSwzByOtSH/zRQemDLK8QrZF0lcoPJxtbKTzUcCfqc3AH7UDrOaC9BIo+CMO0lb4X9FQn2JvsW4DH4mlcGGTXZ0PbOb7TRtYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFTlniTAUaihSnsel5fw4Szfp0kCFmUxlaalEqbxZmArjJclj04kifG7PRApFI4NgwtaE5na/xCEBI572Nvp+FkDBkZv5SEXMv/srbpyw5vnvIzlu8X3EmssQ5s6QAAAAAaBTtTK9ooXRnL9rIYDGmPoTqFe+h1EtyKT9tvbABZQBt324ddloZPZy+FGzut5rBy0he1fWzeROoz1hX7/AKnLHFG8r27UznARMamsTdStWltOfL+TLpgyxe4Hfqm8xQYIAgABDAIAAACghgEAAAAAAAoGAAIABggNAQEMCgcJAwECBQIGCA0JDqCGAQAAAAAACwAFAkANAwALAAkDgDgBAAAAAAAIAgAEDAIAAAC0/gAAAAAAAA==")
 }
+
+/// Two of three FROST participants sign a transfer from their group account.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_solana_compile_transfer_frost() {
+    let (key_packages, public_key_package) =
+        frost::key_packages::<Ed25519>(ED25519_COMMITMENT, &ED25519_SHARES);
+    let public_key = public_key_package.verifying_key_bytes();
+    let sender = base58::encode(&public_key, Alphabet::Bitcoin);
+    assert_eq!(sender, "BGjbxRKybqAeAeBkKMCS9sxSj6bBCneo4XZsjnTuTQMD");
+
+    let transfer = Proto::Transfer {
+        recipient: "3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe".into(),
+        value: 1000,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        sender: sender.into(),
+        recent_blockhash: "TPJFTN4CjBn12HiBfAbGUhpD9zGvRSm2RcheFRA4Fyv".into(),
+        transaction_type: TransactionType::transfer_transaction(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Solana, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+
+    // Step 3: Sign the preimage by the participants and compile transaction info
+    let signature = frost::sign(&key_packages, &public_key_package, &preimage_output.data);
+    assert_eq!(signature.to_vec().to_hex(), "9852f14604d8fbf19794baf25652939f747998393f5887038e94a4f083f0a5059aa307f798a12b114616d3a2ee6b6d6095b005d83d7fb7d5e02f48a1a28dbb0d");
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Solana,
+        &input,
+        vec![signature.to_vec()],
+        vec![public_key],
+    );
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded, "5RCLSXZ4qkvwsZgjjfMMLirL1EYxUNijzb4nL7G1qNQQsdTLvgSNYHLBqNrWZAaTcY3VSkDocmNgQ2ujRXkVRRe5goeBBUwKTHqvVkHkJ6NMXgqma7dtrSDw1Wz9XpWUrKWZzoUbgipwE5Zq7oS8rZAt4t4noEUmZ1mH45AWE7fNNWhuSBsZ1aHmoMu1yC5ugJz8m3PxBsPZAngU6eLqFnTTWHFoqdfZQqNdk7QMMkZgXSJ4GkHR87LLyyjN7X4vvTkGuf9hohewVhLCq3eFY3JxUeVrZXJ4HFHVR");
+}
@@ -23,7 +23,7 @@ fn test_solana_message_signer_sign() {
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let output = TWDataHelper::wrap(unsafe {
-        tw_message_signer_sign(CoinType::Solana as u32, input_data.ptr())
+        tw_message_signer_sign(u32::from(CoinType::Solana), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_message_signer_sign returned nullptr");
@@ -49,7 +49,8 @@ fn test_solana_message_signer_verify() {
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
-    let verified = unsafe { tw_message_signer_verify(CoinType::Solana as u32, input_data.ptr()) };
+    let verified =
+        unsafe { tw_message_signer_verify(u32::from(CoinType::Solana), input_data.ptr()) };
     assert!(verified);
 }
 
@@ -67,7 +68,7 @@ fn test_solana_message_signer_pre_image_hashes() {
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let output = TWDataHelper::wrap(unsafe {
-        tw_message_signer_pre_image_hashes(CoinType::Solana as u32, input_data.ptr())
+        tw_message_signer_pre_image_hashes(u32::from(CoinType::Solana), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_message_signer_sign returned nullptr");
@@ -973,3 +973,248 @@ fn test_solana_sign_transfer_token_2022() {
     assert_eq!(output.encoded, "SAXNFUd7dNBu956Gi4XNuvMkKKjS9vp6puz45ErYMHFpMNwC3AQxDxGbweXt4GzY2FnUZ6ubm231NrdwWa8dg9bqgRMaHPLuPiy99YwtvcQ1E6mHxHqq8nL5VaN8wiVnrMU57zCLfHsSsVCHZc5peHHAPXMDE318uMCLLBwgDWuD1FfAvUAyXRSYniXzWG3jtBdDhuDohh13E2TMrtqTcKVv3crejFqFjtsNuW7KCqrZwxCv1ASNiiL2XScQBdHwStyjH2UTqLmT6wjGLiDYy7PZ88Tbz65r8NLr4Vb1aYSTChasfVjMLdybetfNaf4nJuBE4ZuXca7W66txKbHesxQbzrjUCXX12JFbKyaA8KJKBpbgkc9jWJjQkzyn");
     // https://explorer.solana.com/tx/Lg1xWzsC9GatQMu1ZXv23t7snC92RRvbKJe22bsS76GUb8C8a9q3HPkiUnFoK6AWKSoNSsmko1EBnvKkCnL8b7w?cluster=devnet
 }
+
+#[test]
+fn test_solana_sign_transfer_token_2022_with_fee() {
+    let transfer_token = Proto::TokenTransfer {
+        amount: 1000000000,
+        decimals: 9,
+        token_program_id: Proto::TokenProgramId::Token2022Program,
+        token_mint_address: "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+        sender_token_address: "EQxRyhzjyhRX4TJXt7FmQ3HfFdRcu49krjxHMszidQYS".into(),
+        recipient_token_address: "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+        transfer_fee: Some(Proto::TokenTransferFee { fee: 5000000 }),
+        ..Proto::TokenTransfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::token_transfer_transaction(transfer_token),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded, "2KTGasXD4ckwHLNvRDas7ut9adNMw8no65vPKwLLdthCMRSVgWvdiRk7GM1Ent3Yxin4kcR4MC7dWxnkgdFYZE413BehtGnTLT3wks3uteQjJyj4dTzbgm5rJTKQdiGmkSNndZKb5UXZMNXJEbNxmRN7dAUEBi9W2YJUrdXvEBswDhxmosFzgsfjM9DLiwcXnpkkRXFXmzbNSVMBF4zxJVwfGHG6si71vmLmtzZXRiU8oom1XGfJopGQywh26VpN9RFVEAZZKj4woxQhzPPtxDdoDWgvYRT3TnLyufs6RfeM1FR216L7JTjVjHAdCNtUXxRbtRr3fNvS4sPqZwbitCEKZA71fxMfRJckztfjtmft66g5i2coaB1dWV6swEMBKKM5tYssV");
+}
+
+#[test]
+fn test_solana_sign_create_and_transfer_token_2022_with_fee() {
+    let create_transfer_token = Proto::CreateAndTransferToken {
+        recipient_main_address: "EbHdsfVpWzeQV4TceYQ2xENS8meBHyztyTKVSFtgHPUw".into(),
+        token_mint_address: "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+        recipient_token_address: "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+        sender_token_address: "EQxRyhzjyhRX4TJXt7FmQ3HfFdRcu49krjxHMszidQYS".into(),
+        amount: 1000000000,
+        decimals: 9,
+        token_program_id: Proto::TokenProgramId::Token2022Program,
+        transfer_fee: Some(Proto::TokenTransferFee { fee: 5000000 }),
+        ..Proto::CreateAndTransferToken::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "5oba9g5nWnvutTTb935aBMkHBYGXoak1ot4U2p34zEiJ".into(),
+        transaction_type: TransactionType::create_and_transfer_token_transaction(
+            create_transfer_token,
+        ),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.encoded, "7UYieWnLT1Dp9np9scHHPehzxephCTnsveX8yhsfJVxEjDJXd2sNtRceeeGJ3Hst6UbY89Qd6FpAaXFoU4jsmNznBsSpYi38Eka4FNHpEdBR5yzmXcrnJkUvfqQQoA6PyVtMM1w9jtWavHnkHs3fcT6YumkrPc2T4LvDe2fK9KUHaeYqUxpsjn6RuKcWi2arUCnMez4bMYrLgWDZBzc9NQZV5kJLst5eF3KYiCyBZ2Nb9nJBQhe74UFFfhCrhUrEdXSqYo2Lr7JaULpRWudNRXVHecJnJXCoize2snovCKUPvoCpMci2rBrYGvjU4o4TEo7DVKnW1Xzxip5fMESaEqUFjfHb4kPZb64phQbqHidSt4RsmzVNkykN477JERyZEZASUBkWjhDCFCup12RZiqNoftTzMCcwcHLVoyeJqxeqCt8QtdVuLqLouzo5F65smaMSXRv6uxxKn2QmZr8KcrC8U6WSwi8QQaYDnDLxXS6mpbrppgMQYwkC4J8usmWpoCc6pSvhB1E2oqm4nw17qjq4nRHswgQ4mFJ9JduzX2DrdEs1P52pSzyv5fanWND5Bhdzf");
+}
+
+#[test]
+fn test_solana_sign_transfer_token_with_fee_not_token_2022() {
+    let transfer_token = Proto::TokenTransfer {
+        amount: 1000000000,
+        decimals: 9,
+        token_mint_address: "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+        sender_token_address: "EQxRyhzjyhRX4TJXt7FmQ3HfFdRcu49krjxHMszidQYS".into(),
+        recipient_token_address: "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+        transfer_fee: Some(Proto::TokenTransferFee { fee: 5000000 }),
+        ..Proto::TokenTransfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::token_transfer_transaction(transfer_token),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_solana_sign_transfer_token_2022_memo_required() {
+    let transfer_token = Proto::TokenTransfer {
+        amount: 1000000000,
+        decimals: 9,
+        token_program_id: Proto::TokenProgramId::Token2022Program,
+        token_mint_address: "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+        sender_token_address: "EQxRyhzjyhRX4TJXt7FmQ3HfFdRcu49krjxHMszidQYS".into(),
+        recipient_token_address: "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+        memo_required: true,
+        ..Proto::TokenTransfer::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::token_transfer_transaction(transfer_token),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_solana_sign_create_lookup_table() {
+    // The lookup table address is derived as "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".
+    let create_lookup_table = Proto::CreateLookupTable {
+        recent_slot: 290000000,
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::create_lookup_table(create_lookup_table),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded, "2ZYeQ4iD6nYGujTEF7N2fWvq6ZFk29FwFzYDjjsRXLrjcnNBfoQHdrCkYjNd6EnNRWCYRjaZdQGBvbpLrhH25EcqoyFRakqvqG84Q9ckiBhBKT7yv1jDyiVQD6Z8svPvZnKDv923JRRHscWCoMazxQa5urCvZT9iNAgZSpBoebX9b6DViRZwkCZYsHqCC8UQZfFY62vhKwCvuQDF8kXG5eraKdDR3ZZd7oP36vToETTTCoxyXF5hTpM97U82FgotYzzADrb7fM75Rur5yNKwBLZbiYuTDwM3zRmQUQsMb5UrymKUuirtSba2q2xpjv74BU1huCgZwMQRc8TqjjM9m");
+}
+
+#[test]
+fn test_solana_sign_extend_lookup_table() {
+    let extend_lookup_table = Proto::ExtendLookupTable {
+        lookup_table_address: "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".into(),
+        new_addresses: vec![
+            "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+            "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".into(),
+        ],
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::extend_lookup_table(extend_lookup_table),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded, "earMwEnyWK1RpycombrJ4vft7pcWYcPmGsEr4EKX3oX9KDap95HkQJyCEMQscQdKzMDJAvpbrr6cfQh5EQQ11KBwjK7Ac3bUfUN9XxLakZziEBBXPP19uTRrwFaRLYVKNyxJtdRQHiZa4ChJsP3HDsUrhAZ5Y16sq2zMgp4i8GWSFjtX7tMpmUfJLY6Nun6qGhkuCUgMUZRHz1ZoUoPRVRCQGxmst31819TFdhoqZVobaS9D87EwuMp5TVAKVf2rmLKq5MKDADMpk6VYdarNkjBGVTgtKE9FSrFCfPqkKC2f9X9NS235G6Kur7xh6QpkkQddynHd4FxM8fi1mWNAxGDssw8vArzvQZv1FKXEJ6zfPTnsKxXVx7UEa5cz2yn8zrwjn8nqC1p2eEJSsNAjCfJfzXv7JhsMKYMWuUN3HP3hL8kDNmkUanHaiErBFV6mbDCLEozhbwquCca7riuPFN");
+}
+
+#[test]
+fn test_solana_sign_deactivate_lookup_table() {
+    let deactivate_lookup_table = Proto::DeactivateLookupTable {
+        lookup_table_address: "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".into(),
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::deactivate_lookup_table(deactivate_lookup_table),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded, "7rqCd1GQmg17PauBnHML79ggK4c3Dfq86ZH93vfdpNYGrSmjw9W6E2k1rX8MDJH1PuYASshhBPj1W4oiQkkdX7kdEkfEuJkTiCm3aAZiNWgNhih1kjpYgXbo2ZH2b5mH3FCpTgkZyE9Wrejn4i49F2uBE2D1V1qbNNdi5mYgQeJtjBDb2PCcwuedjzzs4ur7E4mwWeKYipEbycUDgGhGEdfkRC16QAhajwEyMqwEVfTSQcPFUXpopunyzqemYparSJKUNzkR9FyQ4HKWVdyX3ybuzw");
+}
+
+#[test]
+fn test_solana_sign_close_lookup_table() {
+    // The reclaimed lamports are sent back to the signer.
+    let close_lookup_table = Proto::CloseLookupTable {
+        lookup_table_address: "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".into(),
+        ..Proto::CloseLookupTable::default()
+    };
+    let input = Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        transaction_type: TransactionType::close_lookup_table(close_lookup_table),
+        ..Proto::SigningInput::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded, "Xpst3XSiJoSQeNspnK7Y6VBAE3eHv74ifiBfwpQUqnevdAPKxdt16eV3ZZZup9oCjKA469uhkHZFpEBkX6G5E2oK1fSj56CQdJmPrVU8283MRLKoVSqwsYZjHww8nXQDobhXZ9YmRbxjywEZMQ7QGabF1h2yKkdJvs8WN7F3GQmgPBiDm6rgKxqLqfhs5L4XXQJSMsNxWwF1iMoTmK75Q5YMukdtq2EwtHhJ9MrZKsEJShTpxYNXzQEgBFWkbNHTH7DoF1DCFuxkthvs78i6YwAKaP1");
+}
+
+fn token_transfer_with_lookup_tables_input(
+    v0_msg: bool,
+    address_lookup_tables: Vec<Proto::AddressLookupTable<'static>>,
+) -> Proto::SigningInput<'static> {
+    let transfer_token = Proto::TokenTransfer {
+        amount: 1000000000,
+        decimals: 9,
+        token_mint_address: "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+        sender_token_address: "EQxRyhzjyhRX4TJXt7FmQ3HfFdRcu49krjxHMszidQYS".into(),
+        recipient_token_address: "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+        ..Proto::TokenTransfer::default()
+    };
+    Proto::SigningInput {
+        private_key: b58("MCyXa2gTJELxTPemyVi5ydDcQ3vVgFyddQYXj6UM3tw"),
+        recent_blockhash: "9U2eTS9b2Essvo1s5hDmwgC1atkSCCUipj2FemLvdWbj".into(),
+        v0_msg,
+        transaction_type: TransactionType::token_transfer_transaction(transfer_token),
+        address_lookup_tables,
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_solana_sign_token_transfer_v0_with_lookup_table() {
+    let lookup_table = Proto::AddressLookupTable {
+        account_key: "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".into(),
+        addresses: vec![
+            // Writable, moved to the lookup.
+            "FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into(),
+            // Not used by the transaction.
+            "3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe".into(),
+            // Readonly, moved to the lookup.
+            "BSQCmMAFB9itonyVSLsUxX92Ne1rgBZFqothBk3q91k6".into(),
+            // Program ID, must be kept in the static account keys.
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".into(),
+        ],
+    };
+    let input = token_transfer_with_lookup_tables_input(true, vec![lookup_table]);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.encoded, "379zDLLZK64XX56EMn3V1pdpGcBgG24jt5MBJ5KwNFBb2u43KjdDthUwQDkbsPYPGHwF5Y3bhGpyEzVywobEa41RdVbxyDge5E1XpcUHeM76PEK557LMErEpd4zL9xNQxmAZfxa1EToMLYC3SpyZh6rUe4gfPjCmb8rerVApS29TRk6LJLR2EyCRFb4roGmMA2CFrAahuHUzAA69gHYEJiFQNE87CA9LQ1eMGfRxEBQ6EJ6UJg1Pv81Pr5iBtmoP8PTGDd9NPh5UHU4NDSjinUW377KPfkAv8WdwpdhaT2dnCsThwEEcn1xtqKvzxeznUmor6j7dJGHGhrWeYBFAVscsK");
+}
+
+#[test]
+fn test_solana_sign_token_transfer_v0_with_unused_lookup_table() {
+    let lookup_table = Proto::AddressLookupTable {
+        account_key: "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".into(),
+        addresses: vec!["3UVYmECPPMZSCqWKfENfuoTv51fTDTWicX9xmBD2euKe".into()],
+    };
+    let input = token_transfer_with_lookup_tables_input(true, vec![lookup_table]);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // No lookups, the same as without lookup tables.
+    assert_eq!(output.encoded, "5p4cZzuNeyGFgKfVdnm67tMRh7UxW2iYfEDWRLX48geuikwtmkj4TADniebnrevNyDHmzmsUBgePTvNXL71mi4mjEtRUntnwgk8XunyjcYayrxvDxtE5WoZJ89zjpGV28G2MMAj9LUc5TNwwszsrVrfZqBxVkaQ9oF3RadRozGZe8zkjABtFaLfcQFqr8HwCEmzNPoWfMo8burkJCE551Uor6aA3HHgq2y5dWYZtQj9s6f7y9hPPJhyqXAaFPU1uMyyxyfjiC7445d9Uc2PpPawKgYj5pNXL7Lv59v1K14uWTLp4y2opzMGA7iGeXEA6vbDaqYTiQ4ce8Zg7cPJa4DnvJiPSHPQqBaWPcaV6MFvedDP2hSueC9jyE4Xd6S3");
+}
+
+#[test]
+fn test_solana_sign_token_transfer_legacy_with_lookup_table() {
+    let lookup_table = Proto::AddressLookupTable {
+        account_key: "KTydrGLan6uz1tR3hQr6XNVjxYuGDs4XevNZ1oDUAiJ".into(),
+        addresses: vec!["FzsLNpzsLMBbm1LWpM6P3W4tKrCkd8KqnMmADNvArW5d".into()],
+    };
+    let input = token_transfer_with_lookup_tables_input(false, vec![lookup_table]);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Solana, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
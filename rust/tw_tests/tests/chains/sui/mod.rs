@@ -7,8 +7,13 @@ use tw_proto::Sui::Proto;
 mod sui_address;
 mod sui_compile;
 mod sui_message_sign;
+mod sui_multisig;
 mod sui_sign;
+mod sui_sign_programmable;
+mod sui_transaction_decoder;
 mod sui_transaction_util;
+mod sui_wallet_connect;
+mod sui_zklogin;
 mod test_cases;
 
 fn object_ref(id: &'static str, version: u64, digest: &'static str) -> Proto::ObjectRef<'static> {
@@ -4,12 +4,17 @@
 
 use std::borrow::Cow;
 
-use crate::chains::sui::test_cases::{transfer_d4ay9tdb, PRIVATE_KEY_54E80D76, SENDER_54E80D76};
+use crate::chains::common::frost::{self, ED25519_COMMITMENT, ED25519_SHARES};
+use crate::chains::sui::test_cases::{
+    transfer_d4ay9tdb, transfer_nft_input, PRIVATE_KEY_54E80D76, SENDER_54E80D76,
+};
 use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
 use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64::{self, STANDARD};
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_keypair::ed25519;
-use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_keypair::frost::ciphersuite::Ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
 use tw_misc::traits::ToBytesVec;
 use tw_proto::Common::Proto::SigningError;
 use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
@@ -113,3 +118,52 @@ fn test_sui_compile_raw_json() {
         signature: "ABl18CtTKml1sbI+HC1ciDlew7NiizEUK2KYfOgEDFVvrCcYbV2TSQI6lBkT710s+L+HrASGVvxVj/igpgB+dAyF69FEH+T5VPvl3GB3vwCOEZpeJpKXxvcIPQAdKsh2/g=="
     });
 }
+
+/// Two of three FROST participants sign a transaction of their group account.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_sui_compile_transfer_nft_frost() {
+    let (key_packages, public_key_package) =
+        frost::key_packages::<Ed25519>(ED25519_COMMITMENT, &ED25519_SHARES);
+    let public_key_bytes = public_key_package.verifying_key_bytes();
+
+    let input = Proto::SigningInput {
+        signer: "0x30cd650fa2bff5e28fa7414d1e1d8c5293f4116a4f1fe0135f2a7b394d68a4b4".into(),
+        ..transfer_nft_input()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+
+    // Step 3: Sign the preimage hash by the participants and compile transaction info
+    let signature = frost::sign(
+        &key_packages,
+        &public_key_package,
+        &preimage_output.data_hash,
+    );
+
+    // The aggregated signature is an ordinary Ed25519 signature of the group public key.
+    let public_key = ed25519::sha512::PublicKey::try_from(public_key_bytes.as_slice()).unwrap();
+    let ed25519_signature = ed25519::Signature::try_from(signature.to_vec().as_slice()).unwrap();
+    assert!(public_key.verify(ed25519_signature, preimage_output.data_hash.to_vec()));
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Sui,
+        &input,
+        vec![signature.to_vec()],
+        vec![public_key_bytes.clone()],
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    // `flag || signature || public_key`
+    let mut expected_signature = vec![0x00];
+    expected_signature.extend_from_slice(&signature.to_vec());
+    expected_signature.extend_from_slice(&public_key_bytes);
+    assert_eq!(
+        output.signature,
+        base64::encode(&expected_signature, STANDARD)
+    );
+}
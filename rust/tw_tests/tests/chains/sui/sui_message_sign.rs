@@ -24,7 +24,7 @@ fn test_sui_message_signer_sign() {
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let output = TWDataHelper::wrap(unsafe {
-        tw_message_signer_sign(CoinType::Sui as u32, input_data.ptr())
+        tw_message_signer_sign(u32::from(CoinType::Sui), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_message_signer_sign returned nullptr");
@@ -50,7 +50,7 @@ fn test_sui_message_signer_verify() {
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
-    let verified = unsafe { tw_message_signer_verify(CoinType::Sui as u32, input_data.ptr()) };
+    let verified = unsafe { tw_message_signer_verify(u32::from(CoinType::Sui), input_data.ptr()) };
     assert!(verified);
 }
 
@@ -66,7 +66,7 @@ fn test_sui_message_signer_verify_different_public_key() {
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
-    let verified = unsafe { tw_message_signer_verify(CoinType::Sui as u32, input_data.ptr()) };
+    let verified = unsafe { tw_message_signer_verify(u32::from(CoinType::Sui), input_data.ptr()) };
     assert_eq!(verified, false);
 }
 
@@ -81,7 +81,7 @@ fn test_sui_message_signer_pre_image_hashes() {
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let output = TWDataHelper::wrap(unsafe {
-        tw_message_signer_pre_image_hashes(CoinType::Sui as u32, input_data.ptr())
+        tw_message_signer_pre_image_hashes(u32::from(CoinType::Sui), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_message_signer_pre_image_hashes returned nullptr");
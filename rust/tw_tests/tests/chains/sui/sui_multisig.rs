@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::test_cases::{transfer_nft_input, PRIVATE_KEY_54E80D76};
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PRIVATE_KEY_SECP256K1: &str =
+    "4646464646464646464646464646464646464646464646464646464646464646";
+
+const PUBLIC_KEY_A: &str = "85ebd1441fe4f954fbe5dc6077bf008e119a5e269297c6f7083d001d2ac876fe";
+const PUBLIC_KEY_B: &str = "ad0e293a56c9fc648d1872a00521d97e6b65724519a2676c2c47cb95d131cf5a";
+const PUBLIC_KEY_SECP256K1: &str =
+    "024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382";
+
+/// The transaction is sent from `0x4d73f841fdb4d5b607a5df144550be83412d4074be9f787d44738f4f6f32e16f` multisig address.
+const UNSIGNED_TX: &str = "AAACACD4h+cHcBdVRRHnNtQ0JDY9qUbYqnSCJfawVGMKCxwK5QEALiNVp+X4V6Z8I30n5aIYT5xoP0J11Uv5Dcxw9hF/SgOxcSAFAAAAACAWqN6yiNss1A1yjjz0hYuYwWdS3Dui2QSHjdKsQz08ZgEBAQEBAAEAAE1z+EH9tNW2B6XfFEVQvoNBLUB0vp94fURzj09vMuFvARAgVLdnakaxuuckE03JYttynzOJrPedPW88J7oBigQEhlwxBQAAAAAgRQo1hDoAiMbl2lgicyjy67PmKIWT5wccUlQMAfu84LxNc/hB/bTVtgel3xRFUL6DQS1AdL6feH1Ec49PbzLhb+4CAAAAAAAAAAk9AAAAAAAA";
+const TX_HASH: &str = "58b398e1e611c8d46e332ca17b996a881886ecd95a40e4bc5fefac7f2f891137";
+
+const SIGNATURE_A: &str = "9f831932d0ae13371050d1644f32999d92a42bbf61c73b509286f11262f3c9deb269d6ad6dc5d7f15881cba08dd09a3aa32bfcb9f6a77388c4735a338801b408";
+const SIGNATURE_B: &str = "b2dae89dbf0f105bdd3f4335c5b2d77aa87fbf5086b58e64bcedb4a10483c3dbcccd07f042abc57d7ce0d4f4126a10ed5200265f28c04e985ea567ee661b7e06";
+const SIGNATURE_SECP256K1: &str = "9f4274c02ae373b5a9720463902b7b855fa74236cf5dd8689023892b8f2ce5c54d3d3b0cc000af9c3782b2c983b2c73e7f07fcfec6b0cc037d7e104df3a6e15e";
+
+fn member(
+    scheme: Proto::SignatureScheme,
+    public_key: &str,
+    weight: u32,
+) -> Proto::MultisigMember<'static> {
+    Proto::MultisigMember {
+        scheme,
+        public_key: public_key.decode_hex().unwrap().into(),
+        weight,
+    }
+}
+
+/// Two `ed25519` members with weight 1 and one `secp256k1` member with weight 2, threshold is 2.
+fn multisig() -> Proto::MultisigPublicKey<'static> {
+    Proto::MultisigPublicKey {
+        members: vec![
+            member(Proto::SignatureScheme::ED25519, PUBLIC_KEY_A, 1),
+            member(Proto::SignatureScheme::ED25519, PUBLIC_KEY_B, 1),
+            member(Proto::SignatureScheme::SECP256K1, PUBLIC_KEY_SECP256K1, 2),
+        ],
+        threshold: 2,
+    }
+}
+
+fn multisig_input(private_key: &str) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        multisig: Some(multisig()),
+        ..transfer_nft_input()
+    }
+}
+
+#[track_caller]
+fn test_compile(signatures: &[&str], public_keys: &[&str]) -> Proto::SigningOutput<'static> {
+    let input = multisig_input("");
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), TX_HASH);
+
+    let signatures = signatures.iter().map(|s| s.decode_hex().unwrap()).collect();
+    let public_keys = public_keys
+        .iter()
+        .map(|pk| pk.decode_hex().unwrap())
+        .collect();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    compiler.compile(CoinType::Sui, &input, signatures, public_keys)
+}
+
+#[test]
+fn test_sui_multisig_sign_ed25519_member() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, multisig_input(PRIVATE_KEY_54E80D76));
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, UNSIGNED_TX);
+    // Partial signature of the member: `flag || signature || public_key`.
+    assert_eq!(output.signature, "AJ+DGTLQrhM3EFDRZE8ymZ2SpCu/Ycc7UJKG8RJi88nesmnWrW3F1/FYgcugjdCaOqMr/Ln2p3OIxHNaM4gBtAiF69FEH+T5VPvl3GB3vwCOEZpeJpKXxvcIPQAdKsh2/g==");
+}
+
+#[test]
+fn test_sui_multisig_sign_secp256k1_member() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, multisig_input(PRIVATE_KEY_SECP256K1));
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, UNSIGNED_TX);
+
+    let signature = base64::decode(&output.signature, STANDARD).unwrap();
+    assert_eq!(signature.len(), 98);
+    assert_eq!(signature[0], 1);
+    assert_eq!(signature[65..].to_vec().to_hex(), PUBLIC_KEY_SECP256K1);
+}
+
+#[test]
+fn test_sui_multisig_sign_not_member() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Sui,
+        multisig_input("a8d2ab5c3e1f4b6d8e9c7a5b3d1f2e4c6b8a9d7e5c3b1a2f4e6d8c9b7a5e3d1f"),
+    );
+
+    assert_eq!(output.error, SigningError::Error_missing_private_key);
+}
+
+#[test]
+fn test_sui_multisig_compile_ed25519_members() {
+    // Signatures can be passed in any order.
+    let output = test_compile(&[SIGNATURE_B, SIGNATURE_A], &[PUBLIC_KEY_B, PUBLIC_KEY_A]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, UNSIGNED_TX);
+    assert_eq!(output.signature, "AwIAn4MZMtCuEzcQUNFkTzKZnZKkK79hxztQkobxEmLzyd6yadatbcXX8ViBy6CN0Jo6oyv8ufanc4jEc1oziAG0CACy2uidvw8QW90/QzXFstd6qH+/UIa1jmS87bShBIPD28zNB/BCq8V9fODU9BJqEO1SACZfKMBOmF6lZ+5mG34GAwADAIXr0UQf5PlU++XcYHe/AI4Rml4mkpfG9wg9AB0qyHb+AQCtDik6Vsn8ZI0YcqAFIdl+a2VyRRmiZ2wsR8uV0THPWgEBAkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOCAgIA");
+}
+
+#[test]
+fn test_sui_multisig_compile_ed25519_secp256k1_members() {
+    let output = test_compile(
+        &[SIGNATURE_SECP256K1, SIGNATURE_A],
+        &[PUBLIC_KEY_SECP256K1, PUBLIC_KEY_A],
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signature, "AwIAn4MZMtCuEzcQUNFkTzKZnZKkK79hxztQkobxEmLzyd6yadatbcXX8ViBy6CN0Jo6oyv8ufanc4jEc1oziAG0CAGfQnTAKuNztalyBGOQK3uFX6dCNs9d2GiQI4krjyzlxU09OwzAAK+cN4KyyYOyxz5/B/z+xrDMA31+EE3zpuFeBQADAIXr0UQf5PlU++XcYHe/AI4Rml4mkpfG9wg9AB0qyHb+AQCtDik6Vsn8ZI0YcqAFIdl+a2VyRRmiZ2wsR8uV0THPWgEBAkvCoxJlFT8H5w4LqwhyTmuF4hf4zWKM62KXQke7STOCAgIA");
+}
+
+#[test]
+fn test_sui_multisig_compile_insufficient_weight() {
+    let output = test_compile(&[SIGNATURE_A], &[PUBLIC_KEY_A]);
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
+
+#[test]
+fn test_sui_multisig_compile_duplicate_signature() {
+    let output = test_compile(&[SIGNATURE_A, SIGNATURE_A], &[PUBLIC_KEY_A, PUBLIC_KEY_A]);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_sui_multisig_unreachable_threshold() {
+    let mut input = multisig_input(PRIVATE_KEY_54E80D76);
+    input.multisig.as_mut().unwrap().threshold = 5;
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
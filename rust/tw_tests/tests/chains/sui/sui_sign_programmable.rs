@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::object_ref;
+use crate::chains::sui::test_cases::{PRIVATE_KEY_54E80D76, SENDER_54E80D76};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
+use Proto::mod_Argument::OneOfkind as ArgumentKind;
+use Proto::mod_CallArg::OneOfarg as CallArgType;
+use Proto::mod_Command::OneOfcommand as CommandType;
+
+fn pure(bytes: Vec<u8>) -> Proto::CallArg<'static> {
+    Proto::CallArg {
+        arg: CallArgType::pure(bytes.into()),
+    }
+}
+
+fn argument(kind: ArgumentKind) -> Proto::Argument {
+    Proto::Argument { kind }
+}
+
+fn nested_result(index: u32, result_index: u32) -> Proto::Argument {
+    argument(ArgumentKind::nested_result(
+        Proto::mod_Argument::NestedResult {
+            index,
+            result_index,
+        },
+    ))
+}
+
+fn command(command: CommandType<'static>) -> Proto::Command<'static> {
+    Proto::Command { command }
+}
+
+/// Split the gas coin into three coins and send them to the same address.
+/// The transaction is the same as built by `PaySui` in `test_sui_sign_split_sui`.
+pub(super) fn split_sui_programmable_tx() -> Proto::ProgrammableTransaction<'static> {
+    let sender = SENDER_54E80D76.decode_hex().unwrap();
+
+    Proto::ProgrammableTransaction {
+        inputs: vec![
+            pure(150_000_u64.to_le_bytes().to_vec()),
+            pure(200_000_u64.to_le_bytes().to_vec()),
+            pure(100_000_u64.to_le_bytes().to_vec()),
+            pure(sender),
+        ],
+        commands: vec![
+            command(CommandType::split_coins(Proto::mod_Command::SplitCoins {
+                coin: Some(argument(ArgumentKind::gas_coin(true))),
+                amounts: vec![
+                    argument(ArgumentKind::input(0)),
+                    argument(ArgumentKind::input(1)),
+                    argument(ArgumentKind::input(2)),
+                ],
+            })),
+            command(CommandType::transfer_objects(
+                Proto::mod_Command::TransferObjects {
+                    objects: vec![
+                        nested_result(0, 0),
+                        nested_result(0, 1),
+                        nested_result(0, 2),
+                    ],
+                    address: Some(argument(ArgumentKind::input(3))),
+                },
+            )),
+        ],
+        gas_payment: vec![object_ref(
+            "0x636020b3a7dc7b11c3aa6f419b17f8a9c12e7f79a31d1bdd2de670b4edd63005",
+            85887685,
+            "GnzkqXxoowwtz1W33JrjwaW63FpnXmVo8DoVVWUwARyx",
+        )],
+    }
+}
+
+#[test]
+fn test_sui_sign_programmable_split_sui() {
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::programmable_transaction(split_sui_programmable_tx()),
+        private_key: PRIVATE_KEY_54E80D76.decode_hex().unwrap().into(),
+        // 0.007 SUI
+        gas_budget: 7000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Successfully broadcasted: https://suiscan.xyz/mainnet/tx/GNoQj54Ra8qGbzbvD25KXEYTsRDKTH5SSjLtHftGNwBM
+    assert_eq!(output.unsigned_tx, "AAAEAAjwSQIAAAAAAAAIQA0DAAAAAAAACKCGAQAAAAAAACBU6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsgICAAMBAAABAQABAgABAwMAAAAAAwAAAQADAAACAAEDAFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ayAWNgILOn3HsRw6pvQZsX+KnBLn95ox0b3S3mcLTt1jAFxYoeBQAAAAAg6qe+uHxDnn7q4cupb3Z1reQK3m4sh6efYtcz8fWA6C9U6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsu4CAAAAAAAAwM9qAAAAAAAA");
+    assert_eq!(output.signature, "AAN/lP/bRRsgdDS/QCSl45D5gHdKv4Aow0Hmkcot6w+84vd2X+nvOgxyYo2BMInBIbsCqlOtnn8t9zo2+dNSegGF69FEH+T5VPvl3GB3vwCOEZpeJpKXxvcIPQAdKsh2/g==");
+}
+
+#[test]
+fn test_sui_sign_programmable_forward_result_reference() {
+    let mut tx = split_sui_programmable_tx();
+    // The first command refers to the result of the second command.
+    tx.commands.swap(0, 1);
+
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::programmable_transaction(tx),
+        private_key: PRIVATE_KEY_54E80D76.decode_hex().unwrap().into(),
+        gas_budget: 7000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_sui_sign_programmable_input_out_of_bounds() {
+    let mut tx = split_sui_programmable_tx();
+    // Remove the recipient input.
+    tx.inputs.pop();
+
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::programmable_transaction(tx),
+        private_key: PRIVATE_KEY_54E80D76.decode_hex().unwrap().into(),
+        gas_budget: 7000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::object_ref;
+use crate::chains::sui::sui_sign_programmable::split_sui_programmable_tx;
+use crate::chains::sui::test_cases::SENDER_54E80D76;
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use Proto::mod_Argument::OneOfkind as ArgumentKind;
+use Proto::mod_CallArg::OneOfarg as CallArgType;
+use Proto::mod_Command::OneOfcommand as CommandType;
+
+const GAS_OWNER: &str = "0xa7175abdd5ed92ebe3ad390db366c6a706478cdf517cde6cf98630065cda377a";
+
+fn argument(kind: ArgumentKind) -> Proto::Argument {
+    Proto::Argument { kind }
+}
+
+#[test]
+fn test_sui_decode_transaction_split_sui() {
+    // Signed in `test_sui_sign_programmable_split_sui`.
+    let encoded_tx = base64::decode("AAAEAAjwSQIAAAAAAAAIQA0DAAAAAAAACKCGAQAAAAAAACBU6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsgICAAMBAAABAQABAgABAwMAAAAAAwAAAQADAAACAAEDAFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ayAWNgILOn3HsRw6pvQZsX+KnBLn95ox0b3S3mcLTt1jAFxYoeBQAAAAAg6qe+uHxDnn7q4cupb3Z1reQK3m4sh6efYtcz8fWA6C9U6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsu4CAAAAAAAAwM9qAAAAAAAA", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Sui, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.sender, SENDER_54E80D76);
+    assert_eq!(tx.gas_owner, SENDER_54E80D76);
+    assert_eq!(tx.gas_budget, 7_000_000);
+    assert_eq!(tx.gas_price, 750);
+    assert_eq!(tx.expiration_epoch, 0);
+    assert_eq!(
+        tx.programmable_transaction,
+        Some(split_sui_programmable_tx())
+    );
+}
+
+#[test]
+fn test_sui_decode_transaction_sponsored_transfer_nft() {
+    // The gas is paid by `GAS_OWNER`, and the transaction expires at epoch 512.
+    let encoded_tx = base64::decode("AAACACD4h+cHcBdVRRHnNtQ0JDY9qUbYqnSCJfawVGMKCxwK5QEALiNVp+X4V6Z8I30n5aIYT5xoP0J11Uv5Dcxw9hF/SgOxcSAFAAAAACAWqN6yiNss1A1yjjz0hYuYwWdS3Dui2QSHjdKsQz08ZgEBAQEBAAEAAFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ayARAgVLdnakaxuuckE03JYttynzOJrPedPW88J7oBigQEhlwxBQAAAAAgRQo1hDoAiMbl2lgicyjy67PmKIWT5wccUlQMAfu84LynF1q91e2S6+OtOQ2zZsanBkeM31F83mz5hjAGXNo3eu4CAAAAAAAAAAk9AAAAAAABAAIAAAAAAAA=", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Sui, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.sender, SENDER_54E80D76);
+    assert_eq!(tx.gas_owner, GAS_OWNER);
+    assert_eq!(tx.gas_budget, 4_000_000);
+    assert_eq!(tx.gas_price, 750);
+    assert_eq!(tx.expiration_epoch, 512);
+
+    let recipient = "0xf887e7077017554511e736d43424363da946d8aa748225f6b054630a0b1c0ae5"
+        .decode_hex()
+        .unwrap();
+    let expected = Proto::ProgrammableTransaction {
+        inputs: vec![
+            Proto::CallArg {
+                arg: CallArgType::pure(recipient.into()),
+            },
+            Proto::CallArg {
+                arg: CallArgType::imm_or_owned_object(object_ref(
+                    "0x2e2355a7e5f857a67c237d27e5a2184f9c683f4275d54bf90dcc70f6117f4a03",
+                    86012337,
+                    "2XTKVJGNZm7i6ZYGQ6ikZowFu855TpTUTip8JJ1jf1ch",
+                )),
+            },
+        ],
+        commands: vec![Proto::Command {
+            command: CommandType::transfer_objects(Proto::mod_Command::TransferObjects {
+                objects: vec![argument(ArgumentKind::input(1))],
+                address: Some(argument(ArgumentKind::input(0))),
+            }),
+        }],
+        gas_payment: vec![object_ref(
+            "0x102054b7676a46b1bae724134dc962db729f3389acf79d3d6f3c27ba018a0404",
+            87121030,
+            "5eWAHWYnidUinZFf3CWNCLSxsUr3c56VVVVofAgaP6bu",
+        )],
+    };
+    assert_eq!(tx.programmable_transaction, Some(expected));
+}
+
+#[test]
+fn test_sui_decode_transaction_invalid() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Sui, "00020000".decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
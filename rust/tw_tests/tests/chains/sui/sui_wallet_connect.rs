@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::test_cases::{PRIVATE_KEY_54E80D76, SENDER_54E80D76};
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use tw_proto::WalletConnect::Proto as WCProto;
+use WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum;
+
+/// Split SUI transaction signed in `test_sui_sign_programmable_split_sui`.
+const SPLIT_SUI_TX: &str = "AAAEAAjwSQIAAAAAAAAIQA0DAAAAAAAACKCGAQAAAAAAACBU6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsgICAAMBAAABAQABAgABAwMAAAAAAwAAAQADAAACAAEDAFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ayAWNgILOn3HsRw6pvQZsX+KnBLn95ox0b3S3mcLTt1jAFxYoeBQAAAAAg6qe+uHxDnn7q4cupb3Z1reQK3m4sh6efYtcz8fWA6C9U6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsu4CAAAAAAAAwM9qAAAAAAAA";
+
+#[test]
+fn test_sui_wallet_connect_sign_transaction() {
+    let request_params = json!({
+        "transaction": SPLIT_SUI_TX,
+        "address": SENDER_54E80D76
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SuiSignTransaction,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Sui, &input);
+    assert_eq!(parsing_output.error, SigningError::OK);
+
+    let mut signing_input = match parsing_output.signing_input_oneof {
+        SigningInputEnum::sui(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.signer, SENDER_54E80D76);
+
+    // Set missing private key.
+    signing_input.private_key = PRIVATE_KEY_54E80D76.decode_hex().unwrap().into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, signing_input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, SPLIT_SUI_TX);
+    assert_eq!(output.signature, "AAN/lP/bRRsgdDS/QCSl45D5gHdKv4Aow0Hmkcot6w+84vd2X+nvOgxyYo2BMInBIbsCqlOtnn8t9zo2+dNSegGF69FEH+T5VPvl3GB3vwCOEZpeJpKXxvcIPQAdKsh2/g==");
+}
+
+#[test]
+fn test_sui_wallet_connect_sign_transaction_sender_mismatch() {
+    let request_params = json!({
+        "transaction": SPLIT_SUI_TX,
+        "address": "0xa7175abdd5ed92ebe3ad390db366c6a706478cdf517cde6cf98630065cda377a"
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SuiSignTransaction,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Sui, &input);
+    assert_eq!(parsing_output.error, SigningError::Error_invalid_params);
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::test_cases::{transfer_nft_input, PRIVATE_KEY_54E80D76};
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Public key of the ephemeral `PRIVATE_KEY_54E80D76` key.
+const EPHEMERAL_PUBLIC_KEY: &str =
+    "85ebd1441fe4f954fbe5dc6077bf008e119a5e269297c6f7083d001d2ac876fe";
+
+/// The transaction is sent from `0xf7badc2b245c7f74d7509a4aa357ecf80a29e7713fb4c44b0e7541ec43885ee1` zkLogin address.
+const UNSIGNED_TX: &str = "AAACACD4h+cHcBdVRRHnNtQ0JDY9qUbYqnSCJfawVGMKCxwK5QEALiNVp+X4V6Z8I30n5aIYT5xoP0J11Uv5Dcxw9hF/SgOxcSAFAAAAACAWqN6yiNss1A1yjjz0hYuYwWdS3Dui2QSHjdKsQz08ZgEBAQEBAAEAAPe63CskXH9011CaSqNX7PgKKedxP7TESw51QexDiF7hARAgVLdnakaxuuckE03JYttynzOJrPedPW88J7oBigQEhlwxBQAAAAAgRQo1hDoAiMbl2lgicyjy67PmKIWT5wccUlQMAfu84Lz3utwrJFx/dNdQmkqjV+z4CinncT+0xEsOdUHsQ4he4e4CAAAAAAAAAAk9AAAAAAAA";
+const TX_HASH: &str = "d22482bdf416ad3795c049d249a250fd7a6393aacc945b5ede8ef917f1f2d255";
+const EPHEMERAL_SIGNATURE: &str = "3eef16788ede4273b99bf128e2c8ad6b1c4c03a445b9dbff98761aadba8c81d092da5dcfb523928f7a3013bd9ca86dc192da8811ec9f998a3b1d363f28c1a501";
+const ZK_LOGIN_SIGNATURE: &str = "BQNMODI0NzIxNTg3NTI5MzQwNjg5MDgyOTgzOTE1Njg5Nzg2Mzc0MjUwNDYxNTE5MTM2MTUxODI4MTA5MTMwMjQ3NTkwNDU1MTExMTAxNkw2ODcyOTgwMzM1NzQ4MjA1OTc5Mzc5MzIxOTgyMjIwNDk4NDg0MjQyMjA5MjI1NzY1Njg2NDcxMDc2MDgxOTQ0MDM0MjkyMTU5NjY2ATEDAk0yMTQxOTY4MDA2NDY0MjA0NzUxMDkxNTE3MTcyMzIzMDYzOTU4ODYzMTg5OTc3NTMxNTc1MDgwMzQxNjcxMzI4Mzc0MDEzNzQwNjgwN00yMTU2NjcxNjkxNTU2MjAzNzczNzY4MTg4ODg1ODM4MjI4NzAzNTcxMjM0MTY1MDY0NzQzOTExOTgyMDgwODEyNzE2MTk0NjMyNTg5MAJNMTc4Njc3MTQ3MTA2ODYzOTQxNTk5MTk5OTg1MDM3MjQyNDAyMTI1MTc4Mzg3MTAzOTkwNDUyODk3ODQzMDcwNzgwODc5MjY0MDQ1NTVNMjE4MTI3Njk4NzU1MDIwMTMxMTMyNTUxNTU4MzY4OTY2MTUxNjQ1NTkyODA5MTE5OTcyMTk5NTgwMzE4NTIyMzk2NDUwNjE4NTQyMjECATEBMANMNzUzMDgyNjgwMzcwMjkyODE5ODM2ODQyMTc4NzI3ODUyNDI1NjYyMzg3MTU2MDc0NjI0MDIxNTU0NzA3NjA5NTkxMTEzMjY1MzIxNE0xNjI0NDU0NzkzNjI0OTk1OTc3MTg2MjQ1NDg1MDQ4NTcyNjg4Mzk3Mjk2OTE3MzkyMTcyNzI1NjE1MTk5MTc1MTg2MDY5NDEyMzk3NgExMXlKcGMzTWlPaUpvZEhSd2N6b3ZMMkZqWTI5MWJuUnpMbWR2YjJkc1pTNWpiMjBpTEMBZmV5SmhiR2NpT2lKU1V6STFOaUlzSW10cFpDSTZJalptTnpJMU5ERXdNV1kxTm1VME1XTm1NelZqT1RreU5tUmxPRFJoTW1RMU5USmlOR00yWmpFaUxDSjBlWEFpT2lKS1YxUWlmUU0xMzMyMjg5NzkzMDE2MzIxODUzMjI2NjQzMDQwOTUxMDM5NDMxNjk4NTI3NDc2OTEyNTY2NzI5MDYwMDMyMTU2NDI1OTQ2NjUxMTcxMQoAAAAAAAAAYQA+7xZ4jt5Cc7mb8SjiyK1rHEwDpEW52/+YdhqtuoyB0JLaXc+1I5KPejATvZyobcGS2ogR7J+ZijsdNj8owaUBhevRRB/k+VT75dxgd78AjhGaXiaSl8b3CD0AHSrIdv4=";
+
+fn elements(elements: &[&'static str]) -> Vec<std::borrow::Cow<'static, str>> {
+    elements.iter().map(|e| (*e).into()).collect()
+}
+
+fn zk_login() -> Proto::ZkLogin<'static> {
+    Proto::ZkLogin {
+        iss: "https://accounts.google.com".into(),
+        address_seed: "13322897930163218532266430409510394316985274769125667290600321564259466511711".into(),
+        proof_points: Some(Proto::ZkLoginProof {
+            a: elements(&[
+                "8247215875293406890829839156897863742504615191361518281091302475904551111016",
+                "6872980335748205979379321982220498484242209225765686471076081944034292159666",
+                "1",
+            ]),
+            b: vec![
+                Proto::mod_ZkLoginProof::Elements {
+                    elements: elements(&[
+                        "21419680064642047510915171723230639588631899775315750803416713283740137406807",
+                        "21566716915562037737681888858382287035712341650647439119820808127161946325890",
+                    ]),
+                },
+                Proto::mod_ZkLoginProof::Elements {
+                    elements: elements(&[
+                        "17867714710686394159919998503724240212517838710399045289784307078087926404555",
+                        "21812769875502013113255155836896615164559280911997219958031852239645061854221",
+                    ]),
+                },
+                Proto::mod_ZkLoginProof::Elements {
+                    elements: elements(&["1", "0"]),
+                },
+            ],
+            c: elements(&[
+                "7530826803702928198368421787278524256623871560746240215547076095911132653214",
+                "16244547936249959771862454850485726883972969173921727256151991751860694123976",
+                "1",
+            ]),
+        }),
+        iss_base64_details_value: "yJpc3MiOiJodHRwczovL2FjY291bnRzLmdvb2dsZS5jb20iLC".into(),
+        iss_base64_details_index_mod_4: 1,
+        header_base64: "eyJhbGciOiJSUzI1NiIsImtpZCI6IjZmNzI1NDEwMWY1NmU0MWNmMzVjOTkyNmRlODRhMmQ1NTJiNGM2ZjEiLCJ0eXAiOiJKV1QifQ".into(),
+        max_epoch: 10,
+    }
+}
+
+fn zk_login_input(private_key: &str) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        zk_login: Some(zk_login()),
+        ..transfer_nft_input()
+    }
+}
+
+#[test]
+fn test_sui_zk_login_sign() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, zk_login_input(PRIVATE_KEY_54E80D76));
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, UNSIGNED_TX);
+    assert_eq!(output.signature, ZK_LOGIN_SIGNATURE);
+}
+
+#[test]
+fn test_sui_zk_login_compile() {
+    let input = zk_login_input("");
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), TX_HASH);
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Sui,
+        &input,
+        vec![EPHEMERAL_SIGNATURE.decode_hex().unwrap()],
+        vec![EPHEMERAL_PUBLIC_KEY.decode_hex().unwrap()],
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, UNSIGNED_TX);
+    assert_eq!(output.signature, ZK_LOGIN_SIGNATURE);
+}
+
+#[test]
+fn test_sui_zk_login_google_iss_without_scheme() {
+    let mut input = zk_login_input(PRIVATE_KEY_54E80D76);
+    input.zk_login.as_mut().unwrap().iss = "accounts.google.com".into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    // The issuer is normalized, so the sender address is the same.
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, UNSIGNED_TX);
+}
+
+#[test]
+fn test_sui_zk_login_invalid_address_seed() {
+    let mut input = zk_login_input(PRIVATE_KEY_54E80D76);
+    input.zk_login.as_mut().unwrap().address_seed = "not a number".into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_sui_zk_login_and_multisig() {
+    let mut input = zk_login_input(PRIVATE_KEY_54E80D76);
+    input.multisig = Some(Proto::MultisigPublicKey::default());
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
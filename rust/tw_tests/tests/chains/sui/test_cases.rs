@@ -42,3 +42,29 @@ pub(super) mod transfer_d4ay9tdb {
         }
     }
 }
+
+/// Transfers an NFT to `0xf887e7077017554511e736d43424363da946d8aa748225f6b054630a0b1c0ae5`.
+/// The payload is taken from `test_sui_sign_transfer_nft`, the transaction sender is expected to be set separately.
+pub(super) fn transfer_nft_input() -> Proto::SigningInput<'static> {
+    let transfer_obj = Proto::TransferObject {
+        object: Some(object_ref(
+            "0x2e2355a7e5f857a67c237d27e5a2184f9c683f4275d54bf90dcc70f6117f4a03",
+            86012337,
+            "2XTKVJGNZm7i6ZYGQ6ikZowFu855TpTUTip8JJ1jf1ch",
+        )),
+        recipient: "0xf887e7077017554511e736d43424363da946d8aa748225f6b054630a0b1c0ae5".into(),
+        gas: Some(object_ref(
+            "0x102054b7676a46b1bae724134dc962db729f3389acf79d3d6f3c27ba018a0404",
+            87121030,
+            "5eWAHWYnidUinZFf3CWNCLSxsUr3c56VVVVofAgaP6bu",
+        )),
+    };
+
+    Proto::SigningInput {
+        transaction_payload: TransactionType::transfer_object(transfer_obj),
+        // 0.004 SUI
+        gas_budget: 4000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    }
+}
@@ -36,7 +36,7 @@ fn test_any_signer_compile_thorchain() {
     // Step 2: Obtain preimage hash
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let preimage_data = TWDataHelper::wrap(unsafe {
-        tw_transaction_compiler_pre_image_hashes(CoinType::THORChain as u32, input_data.ptr())
+        tw_transaction_compiler_pre_image_hashes(u32::from(CoinType::THORChain), input_data.ptr())
     })
     .to_vec()
     .expect("!tw_transaction_compiler_pre_image_hashes returned nullptr");
@@ -73,7 +73,7 @@ fn test_any_signer_compile_thorchain() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
     let output_data = TWDataHelper::wrap(unsafe {
         tw_transaction_compiler_compile(
-            CoinType::THORChain as u32,
+            u32::from(CoinType::THORChain),
             input_data.ptr(),
             signatures.ptr(),
             public_keys.ptr(),
@@ -24,7 +24,7 @@ fn test_any_signer_sign_thorchain() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output = TWDataHelper::wrap(unsafe {
-        tw_any_signer_sign(input_data.ptr(), CoinType::THORChain as u32)
+        tw_any_signer_sign(input_data.ptr(), u32::from(CoinType::THORChain))
     })
     .to_vec()
     .expect("!tw_any_signer_sign returned nullptr");
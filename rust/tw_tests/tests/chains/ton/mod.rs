@@ -6,8 +6,11 @@ mod cell_example;
 mod ton_address;
 mod ton_address_converter;
 mod ton_compile;
+mod ton_connect_message_signer;
 mod ton_message_signer;
 mod ton_sign;
 mod ton_sign_wallet_v5r1;
+mod ton_transaction_decoder;
 mod ton_transaction_util;
 mod ton_wallet;
+mod ton_wallet_connect;
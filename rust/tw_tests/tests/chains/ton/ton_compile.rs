@@ -2,10 +2,13 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::chains::common::frost::{self, ED25519_COMMITMENT, ED25519_SHARES};
 use crate::chains::ton::ton_sign::assert_eq_boc;
 use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::frost::ciphersuite::Ed25519;
+use tw_misc::traits::ToBytesVec;
 use tw_proto::Common::Proto::SigningError;
 use tw_proto::TheOpenNetwork::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -103,3 +106,50 @@ fn test_ton_compile_wallet_v5r1_transfer_and_deploy() {
         "437dae441a95a6bccdcdcea2560c313de24f13dd85c76d5d7ecaab1e70a1e52b"
     );
 }
+
+/// Two of three FROST participants sign a transfer from their group wallet.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_ton_compile_wallet_v4r2_transfer_frost() {
+    let (key_packages, public_key_package) =
+        frost::key_packages::<Ed25519>(ED25519_COMMITMENT, &ED25519_SHARES);
+
+    let transfer = Proto::Transfer {
+        dest: "EQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRR3n0".into(),
+        amount: 10,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+
+    let input = Proto::SigningInput {
+        public_key: public_key_package.verifying_key_bytes().into(),
+        messages: vec![transfer],
+        expire_at: 1671135440,
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        ..Proto::SigningInput::default()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::TON, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data.to_hex(),
+        "9da0ab29120fd96b48b2bb76843e77c0a1770b47c2f78da23299e2c32f50192e"
+    );
+
+    // Step 3: Sign the preimage by the participants and compile transaction info
+    let signature = frost::sign(&key_packages, &public_key_package, &preimage_output.data);
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::TON, &input, vec![signature.to_vec()], vec![]);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq_boc(&output.encoded, "te6cckECGgEAA7IAAkWIAL7wuFUDV+DTSHYeWrb0nzxgwDzGgjVwSuPLPxrDQKHoHgECAgE0AwQBnLICN9SWrY4QISsShb4U2h3v6qByTt8gRdYSMf247CrCGaHZ0cAxaLH6wTSU2Vhd8wTh3DIh3cRpG4Bh3yt1zQMpqaMX/////wAAAAAAAwUBFP8A9KQT9LzyyAsGAFEAAAAAKamjF5icwJ2aD36/YxXOXe470hq/FonJ0FioR7qgOrmkswigQAFiYgBsLf6vJOEq42xW0AoyWX0K+uBMUcXFDLFqmkDg6k1Io4hQAAAAAAAAAAAAAAAAAQcCASAICQAAAgFICgsE+PKDCNcYINMf0x/THwL4I7vyZO1E0NMf0x/T//QE0VFDuvKhUVG68qIF+QFUEGT5EPKj+AAkpMjLH1JAyx9SMMv/UhD0AMntVPgPAdMHIcAAn2xRkyDXSpbTB9QC+wDoMOAhwAHjACHAAuMAAcADkTDjDQOkyMsfEssfy/8MDQ4PAubQAdDTAyFxsJJfBOAi10nBIJJfBOAC0x8hghBwbHVnvSKCEGRzdHK9sJJfBeAD+kAwIPpEAcjKB8v/ydDtRNCBAUDXIfQEMFyBAQj0Cm+hMbOSXwfgBdM/yCWCEHBsdWe6kjgw4w0DghBkc3RyupJfBuMNEBECASASEwBu0gf6ANTUIvkABcjKBxXL/8nQd3SAGMjLBcsCIs8WUAX6AhTLaxLMzMlz+wDIQBSBAQj0UfKnAgBwgQEI1xj6ANM/yFQgR4EBCPRR8qeCEG5vdGVwdIAYyMsFywJQBs8WUAT6AhTLahLLH8s/yXP7AAIAbIEBCNcY+gDTPzBSJIEBCPRZ8qeCEGRzdHJwdIAYyMsFywJQBc8WUAP6AhPLassfEss/yXP7AAAK9ADJ7VQAeAH6APQEMPgnbyIwUAqhIb7y4FCCEHBsdWeDHrFwgBhQBMsFJs8WWPoCGfQAy2kXyx9SYMs/IMmAQPsABgCKUASBAQj0WTDtRNCBAUDXIMgBzxb0AMntVAFysI4jghBkc3Rygx6xcIAYUAXLBVADzxYj+gITy2rLH8s/yYBA+wCSXwPiAgEgFBUAWb0kK29qJoQICga5D6AhhHDUCAhHpJN9KZEM5pA+n/mDeBKAG3gQFImHFZ8xhAIBWBYXABG4yX7UTQ1wsfgAPbKd+1E0IEBQNch9AQwAsjKB8v/ydABgQEI9ApvoTGACASAYGQAZrc52omhAIGuQ64X/wAAZrx32omhAEGuQ64WPwI23Dto=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "f377cc7d1bd123987251746915b686bb60b6d93647929cb3f892219828b0905c"
+    );
+}
@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TheOpenNetwork::Proto::mod_MessageSigningInput::OneOfmessage_oneof as SigningMessage;
+use tw_proto::TheOpenNetwork::Proto::mod_MessageVerifyingInput::OneOfmessage_oneof as VerifyingMessage;
+use tw_proto::TheOpenNetwork::Proto::mod_SignData::OneOfpayload as SignDataPayload;
+use tw_proto::{deserialize, serialize, TxCompiler};
+use tw_ton_sdk::boc::BagOfCells;
+
+const PRIVATE_KEY: &str = "5849481021e305dfdf9f0eaf87e07f15efec3fde8d8ed639c9fcf0bc351d998b";
+const PUBLIC_KEY: &str = "f229a9371fa7c2108b3d90ea22c9be705ff5d0cfeaee9cbb9366ff0171579357";
+/// `UQAACKJfEIfI5vkht_w3NYk8k-OU5Xl_jq9XNmmkcPaUO-tB` V4R2 wallet in the raw format.
+const WALLET_RAW: &str = "0:0008a25f1087c8e6f921b7fc3735893c93e394e5797f8eaf573669a470f6943b";
+const DOMAIN: &str = "ton-connect.github.io";
+const TIMESTAMP: u64 = 1733935412;
+
+fn ton_proof() -> Proto::TonProof<'static> {
+    Proto::TonProof {
+        domain: DOMAIN.into(),
+        timestamp: TIMESTAMP,
+        payload: "E5B4ARS6CdOI2b5e1jz0jnS-x-a3DgfNXprrg_3pec0=".into(),
+    }
+}
+
+fn sign_data(payload: SignDataPayload<'static>) -> Proto::SignData<'static> {
+    Proto::SignData {
+        domain: DOMAIN.into(),
+        timestamp: TIMESTAMP,
+        payload,
+    }
+}
+
+fn comment_cell_payload() -> SignDataPayload<'static> {
+    SignDataPayload::cell(Proto::mod_SignData::Cell {
+        schema: "comment#00000000 text:SnakeData = InMsgBody;".into(),
+        // Comment "Hello, TON!".
+        cell: "te6cckEBAQEAEQAAHgAAAABIZWxsbywgVE9OIb7WCx4=".into(),
+    })
+}
+
+#[track_caller]
+fn test_sign(message_oneof: SigningMessage<'static>, expected_signature: &str) {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        message_oneof,
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(u32::from(CoinType::TON), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.signature, expected_signature);
+    assert_eq!(output.address, WALLET_RAW);
+
+    // The wallet address must be derivable from the returned `StateInit`.
+    let state_init = BagOfCells::parse_base64(&output.state_init).unwrap();
+    let state_init_hash = state_init.single_root().unwrap().cell_hash();
+    assert_eq!(
+        format!("0:{}", hex::encode(state_init_hash, false)),
+        WALLET_RAW
+    );
+}
+
+#[track_caller]
+fn test_verify(message_oneof: VerifyingMessage<'static>, signature: &str, expected: bool) {
+    let input = Proto::MessageVerifyingInput {
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        message_oneof,
+        signature: signature.to_string().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(u32::from(CoinType::TON), input_data.ptr()) };
+    assert_eq!(verified, expected);
+}
+
+#[track_caller]
+fn test_pre_image_hashes(message_oneof: SigningMessage<'static>, expected_hash: &str) {
+    let input = Proto::MessageSigningInput {
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        message_oneof,
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(u32::from(CoinType::TON), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(hex::encode(output.data, false), expected_hash);
+    assert_eq!(hex::encode(output.data_hash, false), expected_hash);
+}
+
+const TON_PROOF_SIGNATURE: &str =
+    "P4dPqmuL72Fr/g+H8bC9o8+YfBv3fm3EE3+rQrCVnS829XiH0FtV20VZPMcey2hwugOeLPgeKUnI/2oChviWBg==";
+
+#[test]
+fn test_ton_connect_sign_ton_proof() {
+    test_sign(SigningMessage::ton_proof(ton_proof()), TON_PROOF_SIGNATURE);
+}
+
+#[test]
+fn test_ton_connect_verify_ton_proof() {
+    test_verify(
+        VerifyingMessage::ton_proof(ton_proof()),
+        TON_PROOF_SIGNATURE,
+        true,
+    );
+
+    // Different payload.
+    let mut proof = ton_proof();
+    proof.payload = "another payload".into();
+    test_verify(
+        VerifyingMessage::ton_proof(proof),
+        TON_PROOF_SIGNATURE,
+        false,
+    );
+}
+
+#[test]
+fn test_ton_connect_ton_proof_pre_image_hashes() {
+    test_pre_image_hashes(
+        SigningMessage::ton_proof(ton_proof()),
+        "171d2466d0b50f92e120a0d74283412e40d81be75fe10a98b2775594a42bb9aa",
+    );
+}
+
+#[test]
+fn test_ton_connect_sign_data_text() {
+    let payload = || SignDataPayload::text("Confirm new 2fa number: +1 *** *** **89".into());
+    let signature =
+        "K52fQ1N8kK4vV52/2ULZn4FLADVEAgN7wFqzolXlARs5H8rlEhYZAsE0n5S2zE3z5pfUHT03M28W2ZFQKPtlAg==";
+
+    test_pre_image_hashes(
+        SigningMessage::sign_data(sign_data(payload())),
+        "9e47a93e98d6def90579e1dcd43ec9a11f0335893ae44f3de256e88e2d7abc5c",
+    );
+    test_sign(SigningMessage::sign_data(sign_data(payload())), signature);
+    test_verify(
+        VerifyingMessage::sign_data(sign_data(payload())),
+        signature,
+        true,
+    );
+}
+
+#[test]
+fn test_ton_connect_sign_data_binary() {
+    let payload = || SignDataPayload::binary("0123456789abcdef".decode_hex().unwrap().into());
+    let signature =
+        "3uDhDXh0Fx2FaeoLvk5NGVEKdCOGJCdHgLziIL0G41pOmxgXud7xnFMsbdBiYqNXHptCJAxRy/kuJKL4V4CBAw==";
+
+    test_pre_image_hashes(
+        SigningMessage::sign_data(sign_data(payload())),
+        "443ad8b5538b36a2cc8a6bfd8bfb750188a73c2f236416845339adee13787be9",
+    );
+    test_sign(SigningMessage::sign_data(sign_data(payload())), signature);
+    test_verify(
+        VerifyingMessage::sign_data(sign_data(payload())),
+        signature,
+        true,
+    );
+
+    // The same bytes signed as a text payload must not be accepted.
+    test_verify(
+        VerifyingMessage::sign_data(sign_data(SignDataPayload::text("0123456789abcdef".into()))),
+        signature,
+        false,
+    );
+}
+
+#[test]
+fn test_ton_connect_sign_data_cell() {
+    let signature =
+        "HrfvNRWev4UyLokPQjmhM1WecYZnaVFbToljThHN0MRyXEeksmn3PhNpvsNJ83YFeCBdfYNdfAtHq1OVXL5fCA==";
+
+    test_pre_image_hashes(
+        SigningMessage::sign_data(sign_data(comment_cell_payload())),
+        "a169543296808fc46286d1babc3ea08faa589ecf91c5960352768bb8cdb3069e",
+    );
+    test_sign(
+        SigningMessage::sign_data(sign_data(comment_cell_payload())),
+        signature,
+    );
+    test_verify(
+        VerifyingMessage::sign_data(sign_data(comment_cell_payload())),
+        signature,
+        true,
+    );
+}
+
+#[test]
+fn test_ton_connect_sign_data_no_payload() {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        message_oneof: SigningMessage::sign_data(sign_data(SignDataPayload::None)),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(u32::from(CoinType::TON), input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.signature.is_empty());
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::hex::ToHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TheOpenNetwork::Proto::mod_Transfer::OneOfpayload as PayloadType;
+
+#[test]
+fn test_ton_decode_transaction_wallet_v4r2_with_comment() {
+    // Signed in `test_ton_sign_transfer_with_ascii_comment`.
+    let encoded_tx = base64::decode("te6ccgICAAQAAQAAAMAAAAFFiAGwt/q8k4SrjbFbQCjJZfQr64ExRxcUMsWqaQODqTUijgwAAQGcY4XlvKqu7spxyjL6vyBSKjbskDgqkHhqBsdTe900RGrzExtpvwc04j94v8HOczEWSMCXjTXk0z+CVUXSL54qCimpoxdkM5WOAAAACgADAAIBYmIAM33x4uAd+uQTyXyCZPxflESlNVHpCeoOECtNsqVW9tmIUAAAAAAAAAAAAAAAAAEAAwAgAAAAAHRlc3QgY29tbWVudA==", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V4_R2);
+    assert_eq!(tx.wallet_id, 0x29a9a317);
+    assert_eq!(tx.sequence_number, 10);
+    assert_eq!(tx.expire_at, 1681102222);
+    assert_eq!(
+        tx.wallet_address,
+        "UQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRRyQx"
+    );
+    assert!(!tx.has_state_init);
+    assert_eq!(tx.signature.to_hex(), "6385e5bcaaaeeeca71ca32fabf20522a36ec90382a90786a06c7537bdd34446af3131b69bf0734e23f78bfc1ce73311648c0978d35e4d33f825545d22f9e2a0a");
+
+    let expected = Proto::Transfer {
+        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        amount: 10,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        comment: "test comment".into(),
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected]);
+}
+
+#[test]
+fn test_ton_decode_transaction_wallet_v4r2_jettons_with_comment() {
+    // Signed in `test_ton_sign_transfer_jettons_with_comment`.
+    let encoded_tx = base64::decode("te6ccgICAAQAAQAAARgAAAFFiAC0UQZVyBNtT/W+jqQKnhYasPiDIdSWnNgo1FPyLHxLKgwAAQGcaIWVosi1XnveAmoG9y0/mPeNUqUu7GY76mdbRAaVeNeDOPDlh5M3BEb26kkc6XoYDekV60o2iOobN+TGS76jBSmpoxdqjgf2AAAAAQADAAIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgL68IAAAAAAAAAAAAAAAAAAEAAwDKD4p+pQAAAAAAAAAAQdzWUAgAC4GWcwteHQM+mcQoV+aZ+myCd1jYqUiawhCzuxMdEzkAFoogyrkCban+t9HUgVPCw1YfEGQ6ktObBRqKfkWPiWVCAgAAAAB0ZXN0IGNvbW1lbnQ=", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V4_R2);
+    assert_eq!(tx.sequence_number, 1);
+    assert_eq!(tx.expire_at, 1787693046);
+    assert_eq!(
+        tx.wallet_address,
+        "UQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llWgh"
+    );
+    assert!(!tx.has_state_init);
+
+    let jetton_transfer = Proto::JettonTransfer {
+        query_id: 0,
+        jetton_amount: 500 * 1000 * 1000,
+        // Jetton addresses are decoded as non-bounceable.
+        to_owner: "UQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnGG5".into(),
+        response_address: "UQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llWgh".into(),
+        forward_amount: 1,
+        ..Proto::JettonTransfer::default()
+    };
+    let expected = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 100 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        comment: "test comment".into(),
+        payload: PayloadType::jetton_transfer(jetton_transfer),
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected]);
+}
+
+#[test]
+fn test_ton_decode_transaction_wallet_v4r2_deploy() {
+    // Signed in `test_ton_sign_transfer_and_deploy_4b1d9f`.
+    let encoded_tx = base64::decode("te6cckECGgEAA7QAAkWIAFLpyT6KaTlAPrZhoz1G7c/wY1KAfkGWAG3ajTKt4CFkHgECAgE0AwQBnPhb8QZRko0VcLtSmZsGLICPVXj7+QmBkrCrCL1R1bdHYWibC86XsAcA2dgem8QSl8xxsChuCd2oVG1FoMVUUgEpqaMX/////wAAAAAAAwUBFP8A9KQT9LzyyAsGAFEAAAAAKamjFyoNBiYB1ihabNBrDwYVwXUbvpGlthGturFziw2Dl/PLQAFmYgAdYQm/0Kcw+xnyxzyhpLIsIGhnyFtKVVNCLKFmg/s0ZRgMNQAAAAAAAAAAAAAAAAABBwIBIAgJAAACAUgKCwT48oMI1xgg0x/TH9MfAvgju/Jk7UTQ0x/TH9P/9ATRUUO68qFRUbryogX5AVQQZPkQ8qP4ACSkyMsfUkDLH1Iwy/9SEPQAye1U+A8B0wchwACfbFGTINdKltMH1AL7AOgw4CHAAeMAIcAC4wABwAORMOMNA6TIyx8Syx/L/wwNDg8C5tAB0NMDIXGwkl8E4CLXScEgkl8E4ALTHyGCEHBsdWe9IoIQZHN0cr2wkl8F4AP6QDAg+kQByMoHy//J0O1E0IEBQNch9AQwXIEBCPQKb6Exs5JfB+AF0z/IJYIQcGx1Z7qSODDjDQOCEGRzdHK6kl8G4w0QEQIBIBITAG7SB/oA1NQi+QAFyMoHFcv/ydB3dIAYyMsFywIizxZQBfoCFMtrEszMyXP7AMhAFIEBCPRR8qcCAHCBAQjXGPoA0z/IVCBHgQEI9FHyp4IQbm90ZXB0gBjIywXLAlAGzxZQBPoCFMtqEssfyz/Jc/sAAgBsgQEI1xj6ANM/MFIkgQEI9Fnyp4IQZHN0cnB0gBjIywXLAlAFzxZQA/oCE8tqyx8Syz/Jc/sAAAr0AMntVAB4AfoA9AQw+CdvIjBQCqEhvvLgUIIQcGx1Z4MesXCAGFAEywUmzxZY+gIZ9ADLaRfLH1Jgyz8gyYBA+wAGAIpQBIEBCPRZMO1E0IEBQNcgyAHPFvQAye1UAXKwjiOCEGRzdHKDHrFwgBhQBcsFUAPPFiP6AhPLassfyz/JgED7AJJfA+ICASAUFQBZvSQrb2omhAgKBrkPoCGEcNQICEekk30pkQzmkD6f+YN4EoAbeBAUiYcVnzGEAgFYFhcAEbjJftRNDXCx+AA9sp37UTQgQFA1yH0BDACyMoHy//J0AGBAQj0Cm+hMYAIBIBgZABmtznaiaEAga5Drhf/AABmvHfaiaEAQa5DrhY/AaTiNAg==", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V4_R2);
+    assert_eq!(tx.sequence_number, 0);
+    assert_eq!(tx.expire_at, 0xffffffff);
+    assert_eq!(
+        tx.wallet_address,
+        "UQApdOSfRTScoB9bMNGeo3bn-DGpQD8gywA27UaZVvAQsrHg"
+    );
+    assert!(tx.has_state_init);
+
+    let expected = Proto::Transfer {
+        dest: "EQA6whN_oU5h9jPljnlDSWRYQNDPkLaUqqaEWULNB_ZoyhZr".into(),
+        amount: 100_000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected]);
+}
+
+#[test]
+fn test_ton_decode_transaction_wallet_v5r1() {
+    // Signed in `test_ton_sign_wallet_v5r1_transfer_ordinary`.
+    let encoded_tx = base64::decode("te6cckEBBQEAuQABRYgAKb0c/JU6mMI04bqdt/4TAI41Ecel4xCwiUKhY0qFRpoMAQGhc2lnbn///xFmug9fAAAAAbsTmIIbTn/5n8piniYykIpNGhw0mstvB4PfGuTjdh7eIY3HAokbPMSdaAoOXq8mTWzT2p3v6XSoqTh7XKacOIDgAgIKDsPIbQMEAwFiYgAvdB2hUksveGcW/OVmLve7kyiMQJS76JcnKXGJFnPvIIhQAAAAAAAAAAAAAAAAAQQAAN6nHJg=", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V5_R1);
+    assert_eq!(tx.wallet_id, 2147483409);
+    assert_eq!(tx.sequence_number, 1);
+    assert_eq!(tx.expire_at, 1723469663);
+    assert_eq!(
+        tx.wallet_address,
+        "UQAU3o5-Sp1MYRpw3U7b_wmARxqI49LxiFhEoVCxpUKjTYXk"
+    );
+    assert!(!tx.has_state_init);
+    assert_eq!(tx.signature.to_hex(), "ec4e62086d39ffe67f298a7898ca4229346870d26b2dbc1e0f7c6b938dd87b7886371c0a246cf31275a028397abc9935b34f6a77bfa5d2a2a4e1ed729a70e203");
+
+    let expected = Proto::Transfer {
+        dest: "EQBe6DtCpJZe8M4t-crMXe93JlEYgSl30S5OUuMSLOfeQfBu".into(),
+        amount: 10,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected]);
+}
+
+#[test]
+fn test_ton_decode_transaction_invalid_boc() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, b"not a boc".to_vec());
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
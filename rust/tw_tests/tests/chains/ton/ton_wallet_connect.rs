@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::ton::cell_example::{comment_cell, doge_chatbot_state_init};
+use crate::chains::ton::ton_sign::assert_eq_boc;
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::WalletConnect::Proto as WCProto;
+use WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum;
+
+fn parse_send_message<'a>(
+    parser: &'a mut WalletConnectRequestHelper,
+    request_params: serde_json::Value,
+) -> Proto::SigningInput<'a> {
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::TonSendMessage,
+        payload: request_params.to_string().into(),
+    };
+
+    let parsing_output = parser.parse(CoinType::TON, &input);
+    assert_eq!(
+        parsing_output.error,
+        SigningError::OK,
+        "{}",
+        parsing_output.error_message
+    );
+
+    match parsing_output.signing_input_oneof {
+        SigningInputEnum::ton(input) => input,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_ton_wallet_connect_send_message() {
+    let request_params = json!({
+        "valid_until": 1671132440,
+        "from": "UQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRRyQx",
+        "messages": [{
+            "address": "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q",
+            "amount": "10"
+        }]
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let mut signing_input = parse_send_message(&mut parser, request_params);
+    assert_eq!(signing_input.expire_at, 1671132440);
+    assert!(signing_input.messages[0].bounceable);
+
+    // Set missing private key and sequence number.
+    signing_input.private_key = "c38f49de2fb13223a9e7d37d5d0ffbdd89a5eb7c8b0ee4d1c299f2cefe7dc4a0"
+        .decode_hex()
+        .unwrap()
+        .into();
+    signing_input.sequence_number = 6;
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, signing_input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Equal to `test_ton_sign_transfer_ordinary`.
+    assert_eq_boc(&output.encoded, "te6ccgICAAQAAQAAALAAAAFFiAGwt/q8k4SrjbFbQCjJZfQr64ExRxcUMsWqaQODqTUijgwAAQGcEUPkil2aZ4s8KKparSep/OKHMC8vuXafFbW2HGp/9AcTRv0J5T4dwyW1G0JpHw+g5Ov6QI3Xo0O9RFr3KidICimpoxdjm3UYAAAABgADAAIBYmIAM33x4uAd+uQTyXyCZPxflESlNVHpCeoOECtNsqVW9tmIUAAAAAAAAAAAAAAAAAEAAwAA");
+    assert_eq!(
+        output.hash.to_hex(),
+        "3908cf8b570c1d3d261c62620c9f368db11f6e821a07614cff64de2e7319f81b"
+    );
+}
+
+#[test]
+fn test_ton_wallet_connect_send_message_bounceable_flags() {
+    let comment_payload = comment_cell("Hi there sir");
+    let doge_state_init = doge_chatbot_state_init(1681102222);
+
+    let request_params = json!({
+        "valid_until": 1681102222,
+        "messages": [
+            {
+                "address": "UQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts4DV",
+                "amount": "1000"
+            },
+            {
+                "address": "0:66fbe3c5c03bf5c82792f904c9f8bf28894a6aa3d213d41c20569b654aaded6d",
+                "amount": "2000",
+                "payload": comment_payload,
+                "stateInit": doge_state_init
+            }
+        ]
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let signing_input = parse_send_message(&mut parser, request_params);
+    assert_eq!(signing_input.messages.len(), 2);
+
+    let non_bounceable = &signing_input.messages[0];
+    assert!(!non_bounceable.bounceable);
+    assert_eq!(non_bounceable.amount, 1000);
+    assert_eq!(
+        non_bounceable.mode,
+        Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32
+    );
+
+    // Raw addresses are considered bounceable.
+    let raw = &signing_input.messages[1];
+    assert!(raw.bounceable);
+    assert_eq!(raw.state_init, doge_state_init);
+    assert_eq!(
+        raw.payload,
+        Proto::mod_Transfer::OneOfpayload::custom_payload(comment_payload.into())
+    );
+}
+
+#[test]
+fn test_ton_wallet_connect_send_message_too_many_messages() {
+    let message = json!({
+        "address": "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q",
+        "amount": "10"
+    });
+    let request_params = json!({
+        "valid_until": 1671132440,
+        "messages": vec![message; 5]
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::TonSendMessage,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::TON, &input);
+    assert_eq!(parsing_output.error, SigningError::Error_invalid_params);
+}
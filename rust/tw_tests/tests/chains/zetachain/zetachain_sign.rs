@@ -54,7 +54,7 @@ fn test_zetachain_sign_msg_send_testnet() {
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
 
     let output = TWDataHelper::wrap(unsafe {
-        tw_any_signer_sign(input_data.ptr(), CoinType::NativeZetaChain as u32)
+        tw_any_signer_sign(input_data.ptr(), u32::from(CoinType::NativeZetaChain))
     })
     .to_vec()
     .expect("!tw_any_signer_sign returned nullptr");
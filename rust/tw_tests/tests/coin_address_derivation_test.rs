@@ -172,7 +172,7 @@ fn test_coin_address_derivation() {
         let any_address = TWAnyAddressHelper::wrap(unsafe {
             tw_any_address_create_with_public_key_derivation(
                 public_key.ptr(),
-                coin as u32,
+                u32::from(coin),
                 TWDerivation::Default as u32,
             )
         });
@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_any_coin::ffi::tw_coin_registry::tw_coin_registry_register_coin;
+use tw_any_coin::test_utils::address_utils::{
+    test_address_derive, test_address_invalid, test_address_valid, KeyType,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::blockchain_type::BlockchainType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::dispatcher::evm_dispatcher;
+use tw_coin_registry::error::RegistryError;
+use tw_coin_registry::registry::{get_coin_item, register_coin_json};
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_number::U256;
+use tw_proto::{deserialize, serialize};
+
+const PRIVATE_KEY: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+
+fn evm_chain_json(coin_id: u32) -> String {
+    format!(
+        r#"{{
+            "id": "myl2",
+            "name": "MyL2",
+            "coinId": {coin_id},
+            "symbol": "ETH",
+            "decimals": 18,
+            "blockchain": "Ethereum",
+            "derivation": [{{ "path": "m/44'/60'/0'/0/0" }}],
+            "curve": "secp256k1",
+            "publicKeyType": "secp256k1Extended",
+            "chainId": "8453111",
+            "addressHasher": "keccak256",
+            "explorer": {{
+                "url": "https://explorer.myl2.org",
+                "txPath": "/tx/",
+                "accountPath": "/address/"
+            }}
+        }}"#
+    )
+}
+
+fn cosmos_chain_json(coin_id: u32, hrp: Option<&str>) -> String {
+    let hrp = hrp
+        .map(|hrp| format!(r#""hrp": "{hrp}","#))
+        .unwrap_or_default();
+    format!(
+        r#"{{
+            "id": "myzone",
+            "name": "MyZone",
+            "coinId": {coin_id},
+            "symbol": "ZONE",
+            "decimals": 6,
+            "blockchain": "Cosmos",
+            "derivation": [{{ "path": "m/44'/118'/0'/0/0" }}],
+            "curve": "secp256k1",
+            "publicKeyType": "secp256k1",
+            "chainId": "myzone-1",
+            {hrp}
+            "addressHasher": "sha256ripemd"
+        }}"#
+    )
+}
+
+#[test]
+fn test_register_evm_chain() {
+    const COIN_ID: u32 = 20_000_101;
+
+    assert!(CoinType::try_from(COIN_ID).is_err());
+
+    let json = TWStringHelper::create(&evm_chain_json(COIN_ID));
+    assert!(unsafe { tw_coin_registry_register_coin(json.ptr()) });
+
+    let coin = CoinType::try_from(COIN_ID).unwrap();
+    assert!(!coin.is_builtin());
+
+    let item = get_coin_item(coin).unwrap();
+    assert_eq!(item.id, "myl2");
+    assert_eq!(item.chain_id.as_deref(), Some("8453111"));
+    assert_eq!(item.explorer.as_ref().unwrap().tx_path, "/tx/");
+    assert!(evm_dispatcher(coin).is_ok());
+
+    test_address_derive(
+        coin,
+        KeyType::PrivateKey(PRIVATE_KEY),
+        "0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309",
+    );
+    test_address_valid(coin, "0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309");
+    test_address_invalid(coin, "cosmos1ten42eesehw0ktddcp0fws7d3ycsqez3lynlqx");
+}
+
+#[test]
+fn test_register_evm_chain_sign() {
+    use tw_proto::Ethereum::Proto;
+
+    const COIN_ID: u32 = 20_000_102;
+    register_coin_json(&evm_chain_json(COIN_ID)).unwrap();
+
+    let private = "0x4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output = TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), COIN_ID) })
+        .to_vec()
+        .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    assert_eq!(output.encoded.to_hex(), expected);
+}
+
+#[test]
+fn test_register_cosmos_chain() {
+    const COIN_ID: u32 = 20_000_201;
+
+    let coin = register_coin_json(&cosmos_chain_json(COIN_ID, Some("osmo"))).unwrap();
+    assert_eq!(u32::from(coin), COIN_ID);
+    assert_eq!(
+        get_coin_item(coin).unwrap().blockchain,
+        BlockchainType::Cosmos
+    );
+
+    test_address_derive(
+        coin,
+        KeyType::PrivateKey(PRIVATE_KEY),
+        "osmo1ten42eesehw0ktddcp0fws7d3ycsqez3hlq0k5",
+    );
+    test_address_valid(coin, "osmo1ten42eesehw0ktddcp0fws7d3ycsqez3hlq0k5");
+    test_address_invalid(coin, "cosmos1ten42eesehw0ktddcp0fws7d3ycsqez3lynlqx");
+
+    // The coin cannot be registered again.
+    assert!(matches!(
+        register_coin_json(&cosmos_chain_json(COIN_ID, Some("cosmos"))),
+        Err(RegistryError::AlreadyRegistered)
+    ));
+    test_address_derive(
+        coin,
+        KeyType::PrivateKey(PRIVATE_KEY),
+        "osmo1ten42eesehw0ktddcp0fws7d3ycsqez3hlq0k5",
+    );
+}
+
+#[test]
+fn test_register_coin_invalid() {
+    // Builtin coins cannot be overridden.
+    let ethereum_id = u32::from(CoinType::Ethereum);
+    assert!(matches!(
+        register_coin_json(&evm_chain_json(ethereum_id)),
+        Err(RegistryError::InvalidCoinItem)
+    ));
+
+    // Cosmos chains require `hrp`.
+    assert!(matches!(
+        register_coin_json(&cosmos_chain_json(20_000_301, None)),
+        Err(RegistryError::InvalidCoinItem)
+    ));
+    assert!(CoinType::try_from(20_000_301_u32).is_err());
+
+    // EVM and Cosmos chains use secp256k1 keys only.
+    let ed25519_curve = evm_chain_json(20_000_303).replace(r#""secp256k1""#, r#""ed25519""#);
+    assert!(matches!(
+        register_coin_json(&ed25519_curve),
+        Err(RegistryError::InvalidCoinItem)
+    ));
+    let ed25519_public_key = cosmos_chain_json(20_000_304, Some("osmo")).replace(
+        r#""publicKeyType": "secp256k1""#,
+        r#""publicKeyType": "ed25519""#,
+    );
+    assert!(matches!(
+        register_coin_json(&ed25519_public_key),
+        Err(RegistryError::InvalidCoinItem)
+    ));
+    // EVM chains use extended public keys.
+    let compressed_public_key =
+        evm_chain_json(20_000_305).replace(r#""secp256k1Extended""#, r#""secp256k1""#);
+    assert!(matches!(
+        register_coin_json(&compressed_public_key),
+        Err(RegistryError::InvalidCoinItem)
+    ));
+
+    // Only EVM and Cosmos chains can be registered at runtime.
+    let bitcoin_like = evm_chain_json(20_000_302).replace(r#""Ethereum""#, r#""Bitcoin""#);
+    assert!(matches!(
+        register_coin_json(&bitcoin_like),
+        Err(RegistryError::Unsupported)
+    ));
+
+    assert!(matches!(
+        register_coin_json("{}"),
+        Err(RegistryError::InvalidCoinItem)
+    ));
+    let json = TWStringHelper::create("{}");
+    assert!(!unsafe { tw_coin_registry_register_coin(json.ptr()) });
+}
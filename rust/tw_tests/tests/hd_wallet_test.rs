@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::any_hd_wallet::AnyHDWallet;
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::ToHex;
+use tw_hd_wallet::HDWallet;
+
+const MNEMONIC: &str =
+    "ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatal";
+const PASSPHRASE: &str = "TREZOR";
+const ABANDON_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+#[test]
+fn test_hd_wallet_derive_bitcoin() {
+    let wallet = HDWallet::new(MNEMONIC, PASSPHRASE).unwrap();
+
+    let address = AnyHDWallet::get_address_for_coin(&wallet, CoinType::Bitcoin).unwrap();
+    assert_eq!(
+        address.description(),
+        "bc1qumwjg8danv2vm29lp5swdux4r60ezptzz7ce85"
+    );
+}
+
+#[test]
+fn test_hd_wallet_derive_ethereum() {
+    let wallet = HDWallet::new(MNEMONIC, PASSPHRASE).unwrap();
+
+    for coin in [CoinType::Ethereum, CoinType::SmartChain] {
+        let address = AnyHDWallet::get_address_for_coin(&wallet, coin).unwrap();
+        assert_eq!(
+            address.description(),
+            "0x27Ef5cDBe01777D62438AfFeb695e33fC2335979"
+        );
+    }
+}
+
+#[test]
+fn test_hd_wallet_derive_address_from_mnemonic() {
+    let address =
+        AnyHDWallet::derive_address_from_mnemonic(CoinType::Bitcoin, MNEMONIC, PASSPHRASE).unwrap();
+    assert_eq!(address, "bc1qumwjg8danv2vm29lp5swdux4r60ezptzz7ce85");
+
+    let err = AnyHDWallet::derive_address_from_mnemonic(CoinType::Bitcoin, "ripple scissors", "")
+        .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_hd_wallet_get_key_by_derivation() {
+    let wallet = HDWallet::new(ABANDON_MNEMONIC, "").unwrap();
+
+    let segwit =
+        AnyHDWallet::get_key_by_derivation(&wallet, CoinType::Bitcoin, Derivation::Segwit).unwrap();
+    let default = AnyHDWallet::get_key_for_coin(&wallet, CoinType::Bitcoin).unwrap();
+    assert_eq!(segwit.bytes().to_hex(), default.bytes().to_hex());
+
+    let legacy =
+        AnyHDWallet::get_address_by_derivation(&wallet, CoinType::Bitcoin, Derivation::Legacy)
+            .unwrap();
+    assert_eq!(legacy.description(), "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+
+    let err = AnyHDWallet::get_key_by_derivation(&wallet, CoinType::Ethereum, Derivation::Taproot)
+        .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_not_supported);
+}
+
+#[test]
+fn test_hd_wallet_extended_keys() {
+    let wallet = HDWallet::new(ABANDON_MNEMONIC, "").unwrap();
+
+    let xprv =
+        AnyHDWallet::get_extended_private_key(&wallet, CoinType::Bitcoin, Derivation::Legacy, 0)
+            .unwrap();
+    assert_eq!(xprv.as_str(), "xprv9xpXFhFpqdQK3TmytPBqXtGSwS3DLjojFhTGht8gwAAii8py5X6pxeBnQ6ehJiyJ6nDjWGJfZ95WxByFXVkDxHXrqu53WCRGypk2ttuqncb");
+    let xpub =
+        AnyHDWallet::get_extended_public_key(&wallet, CoinType::Bitcoin, Derivation::Legacy, 0)
+            .unwrap();
+    assert_eq!(xpub, "xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj");
+
+    let zprv =
+        AnyHDWallet::get_extended_private_key(&wallet, CoinType::Bitcoin, Derivation::Default, 0)
+            .unwrap();
+    assert_eq!(zprv.as_str(), "zprvAdG4iTXWBoARxkkzNpNh8r6Qag3irQB8PzEMkAFeTRXxHpbF9z4QgEvBRmfvqWvGp42t42nvgGpNgYSJA9iefm1yYNZKEm7z6qUWCroSQnE");
+    let zpub =
+        AnyHDWallet::get_extended_public_key(&wallet, CoinType::Bitcoin, Derivation::Segwit, 0)
+            .unwrap();
+    assert_eq!(zpub, "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs");
+
+    let zpub_taproot =
+        AnyHDWallet::get_extended_public_key(&wallet, CoinType::Bitcoin, Derivation::Taproot, 0)
+            .unwrap();
+    assert_eq!(zpub_taproot, "zpub6qLiJCzi7t1C7qRZx8ULcy2eGbXpmbbQZmu3znjqoR4VZADKELunndvcw4iSVaGx2KhviLLXuB3vcyGcdd2q8XXBQJc5T5qrtorRowa1zfs");
+
+    let zpub1 =
+        AnyHDWallet::get_extended_public_key(&wallet, CoinType::Bitcoin, Derivation::Segwit, 1)
+            .unwrap();
+    assert_eq!(zpub1, "zpub6rFR7y4Q2AijF6Gk1bofHLs1d66hKFamhXWdWBup1Em25wfabZqkDqvaieV63fDQFaYmaatCG7jVNUpUiM2hAMo6SAVHcrUpSnHDpNzucB7");
+
+    // Ethereum doesn't define extended key versions.
+    let err =
+        AnyHDWallet::get_extended_public_key(&wallet, CoinType::Ethereum, Derivation::Default, 0)
+            .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_not_supported);
+}
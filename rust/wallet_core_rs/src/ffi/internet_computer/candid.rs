@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_internet_computer::modules::candid_codec::CandidCodec;
+use tw_macros::tw_ffi;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::{Nonnull, NullableMut, RawPtrTrait};
+use tw_misc::try_or_else;
+
+/// Encodes Candid values as a binary argument sequence, e.g. to be used as a canister call argument.
+///
+/// \param input Non-null serialized `InternetComputer::Proto::CandidEncodingInput`.
+/// \return serialized `InternetComputer::Proto::CandidEncodingOutput`.
+#[tw_ffi(ty = static_function, class = TWInternetComputerCandid, name = Encode)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_internet_computer_candid_encode(
+    input: Nonnull<TWData>,
+) -> NullableMut<TWData> {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input = try_or_else!(
+        tw_proto::deserialize(input_data.as_slice()),
+        std::ptr::null_mut
+    );
+
+    let output = CandidCodec::encode(input);
+    let output_proto = try_or_else!(tw_proto::serialize(&output), std::ptr::null_mut);
+    TWData::from(output_proto).into_ptr()
+}
+
+/// Decodes a binary Candid argument sequence, e.g. a canister call reply.
+///
+/// \param input Non-null serialized `InternetComputer::Proto::CandidDecodingInput`.
+/// \return serialized `InternetComputer::Proto::CandidDecodingOutput`.
+#[tw_ffi(ty = static_function, class = TWInternetComputerCandid, name = Decode)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_internet_computer_candid_decode(
+    input: Nonnull<TWData>,
+) -> NullableMut<TWData> {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input = try_or_else!(
+        tw_proto::deserialize(input_data.as_slice()),
+        std::ptr::null_mut
+    );
+
+    let output = CandidCodec::decode(input);
+    let output_proto = try_or_else!(tw_proto::serialize(&output), std::ptr::null_mut);
+    TWData::from(output_proto).into_ptr()
+}
@@ -6,6 +6,8 @@
 pub mod bitcoin;
 #[cfg(feature = "ethereum")]
 pub mod ethereum;
+#[cfg(feature = "internet-computer")]
+pub mod internet_computer;
 #[cfg(feature = "solana")]
 pub mod solana;
 #[cfg(feature = "ton")]